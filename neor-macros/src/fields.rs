@@ -0,0 +1,175 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+pub(super) fn parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let rename_all = serde_attr(&input.attrs, "rename_all");
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => unimplemented!(),
+        },
+        Data::Enum(_) | Data::Union(_) => unimplemented!(),
+    };
+
+    let mut methods = TokenStream::new();
+    let mut names = TokenStream::new();
+    let mut skipped = Vec::new();
+
+    for field in fields {
+        let name = field.ident.as_ref().unwrap();
+        let field_name = name.to_string();
+        let field_name = field_name.trim_start_matches("r#");
+
+        if has_serde_flag(&field.attrs, "skip") || has_serde_flag(&field.attrs, "skip_serializing")
+        {
+            skipped.push(field_name.to_owned());
+            continue;
+        }
+
+        let value = match serde_attr(&field.attrs, "rename") {
+            Some(rename) => rename,
+            None => match &rename_all {
+                Some(rule) => apply_rule(rule, field_name),
+                None => field_name.to_owned(),
+            },
+        };
+
+        methods.extend(quote! {
+            pub fn #name(&self) -> &'static str {
+                #value
+            }
+        });
+        names.extend(quote!(#field_name => #value,));
+    }
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let fields_name = format_ident!("{}Fields", name);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let output = quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis struct #fields_name;
+
+        impl #fields_name {
+            #methods
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn fields() -> #fields_name {
+                #fields_name
+            }
+        }
+
+        // Read by `sel!`
+        impl #impl_generics neor::sel::SerdeFields for #name #ty_generics #where_clause {
+            const SKIPPED: &'static [&'static str] = &[#(#skipped),*];
+
+            fn serde_field(field: &'static str) -> &'static str {
+                match field {
+                    #names
+                    _ => field,
+                }
+            }
+        }
+    };
+
+    output.into()
+}
+
+fn serde_metas(attrs: &[Attribute]) -> Vec<NestedMeta> {
+    metas(attrs, "serde")
+}
+
+pub(super) fn metas(attrs: &[Attribute], name: &str) -> Vec<NestedMeta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(name))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested.into_iter()),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+fn serde_attr(attrs: &[Attribute], key: &str) -> Option<String> {
+    serde_metas(attrs).into_iter().find_map(|meta| match meta {
+        NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident(key) => match value.lit {
+            Lit::Str(lit) => Some(lit.value()),
+            _ => None,
+        },
+        // `rename(serialize = "..")` form
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident(key) => {
+            list.nested.into_iter().find_map(|meta| match meta {
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("serialize") => {
+                    match value.lit {
+                        Lit::Str(lit) => Some(lit.value()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+        }
+        _ => None,
+    })
+}
+
+fn has_serde_flag(attrs: &[Attribute], flag: &str) -> bool {
+    serde_metas(attrs)
+        .into_iter()
+        .any(|meta| matches!(meta, NestedMeta::Meta(Meta::Path(path)) if path.is_ident(flag)))
+}
+
+// Mirrors the `rename_all` rules supported by serde
+fn apply_rule(rule: &str, field: &str) -> String {
+    let words: Vec<&str> = field.split('_').filter(|word| !word.is_empty()).collect();
+    let capitalize = |word: &str| {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+            None => String::new(),
+        }
+    };
+
+    match rule {
+        "lowercase" => field.to_lowercase(),
+        "UPPERCASE" => field.to_uppercase(),
+        "PascalCase" => words.iter().map(|word| capitalize(word)).collect(),
+        "camelCase" => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.to_string()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+        "SCREAMING_SNAKE_CASE" => field.to_uppercase(),
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_uppercase().replace('_', "-"),
+        _ => field.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_rule;
+
+    #[test]
+    fn rename_rules() {
+        assert_eq!(apply_rule("camelCase", "email_address"), "emailAddress");
+        assert_eq!(apply_rule("PascalCase", "email_address"), "EmailAddress");
+        assert_eq!(apply_rule("kebab-case", "email_address"), "email-address");
+        assert_eq!(
+            apply_rule("SCREAMING_SNAKE_CASE", "email_address"),
+            "EMAIL_ADDRESS"
+        );
+        assert_eq!(apply_rule("snake_case", "email_address"), "email_address");
+    }
+}
@@ -1,10 +1,21 @@
 use func::Func;
 use proc_macro::TokenStream;
 
+mod fields;
 mod func;
 mod geometry;
+mod model;
 mod options;
+mod path;
+mod sel;
 
+/// Build a ReQL function from a closure taking any number of `Command` parameters,
+/// e.g. `func!(|acc, row| acc + row("price"))`.
+///
+/// The closure may capture the `Command`s around it, including the parameters
+/// of an enclosing `func!`, to correlate subqueries; add `move` to capture them by value.
+/// Variables are numbered per query when it runs, so the same query always
+/// sends the same terms.
 #[proc_macro]
 pub fn func(input: TokenStream) -> TokenStream {
     Func::new(input.into()).process().into()
@@ -15,6 +26,46 @@ pub fn command_opts(input: TokenStream) -> TokenStream {
     options::parse(input)
 }
 
+/// Generate a `<Struct>Fields` accessor whose methods return the stored
+/// name of each field, honouring serde's `rename` and `rename_all` attributes.
+#[proc_macro_derive(Fields, attributes(serde))]
+pub fn fields(input: TokenStream) -> TokenStream {
+    fields::parse(input)
+}
+
+/// Map a struct to a table: generate `Struct::table()`, `Struct::get(key)`
+/// and `value.insert()` queries.
+///
+/// The struct must also derive [Fields], which generates its `<Struct>Fields`
+/// accessor: `#[derive(Fields, ReqlModel)]`.
+///
+/// The table defaults to the struct name in snake case,
+/// `#[reql(table = "users", db = "app")]` overrides it.
+#[proc_macro_derive(ReqlModel, attributes(serde, reql))]
+pub fn reql_model(input: TokenStream) -> TokenStream {
+    model::parse(input)
+}
+
+/// Build a [FieldPath](../neor/types/struct.FieldPath.html) from a dotted path,
+/// rejecting empty fields at compile time.
+///
+/// `path!("author.name.first")` reads `doc.g("author").g("name").g("first")`.
+#[proc_macro]
+pub fn path(input: TokenStream) -> TokenStream {
+    path::parse(input)
+}
+
+/// Build a nested field selector for `pluck` and `without`,
+/// checking at compile time that every field exists on the struct.
+///
+/// `sel!(User { id, name { first } })` expands to `{"id": true, "name": {"first": true}}`.
+/// The keys follow serde's `rename` and `rename_all` attributes of the
+/// structs deriving [Fields], whose fields skipped by serde fail to compile.
+#[proc_macro]
+pub fn sel(input: TokenStream) -> TokenStream {
+    sel::parse(input)
+}
+
 #[proc_macro_derive(Geometry)]
 pub fn make_document(input: TokenStream) -> TokenStream {
     geometry::parse(input)
@@ -0,0 +1,83 @@
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Attribute, DeriveInput, Lit, Meta, NestedMeta};
+
+use crate::fields;
+
+pub(super) fn parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = &input.ident;
+    let table_name =
+        reql_attr(&input.attrs, "table").unwrap_or_else(|| snake_case(&name.to_string()));
+    let table = match reql_attr(&input.attrs, "db") {
+        Some(db) => quote!(neor::r.db(#db).table(Self::TABLE)),
+        None => quote!(neor::r.table(Self::TABLE)),
+    };
+    // The `<Struct>Fields` accessor comes from the `Fields` derive
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(Self: neor::sel::SerdeFields));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let output = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub const TABLE: &'static str = #table_name;
+
+            pub fn table() -> neor::Command {
+                #table
+            }
+
+            pub fn get(key: impl Into<neor::CommandArg>) -> neor::Command {
+                Self::table().get(key)
+            }
+
+            pub fn insert(&self) -> neor::Command {
+                Self::table().insert(self)
+            }
+        }
+    };
+
+    output.into()
+}
+
+// `#[reql(table = "users", db = "app")]`
+fn reql_attr(attrs: &[Attribute], key: &str) -> Option<String> {
+    fields::metas(attrs, "reql")
+        .into_iter()
+        .find_map(|meta| match meta {
+            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident(key) => {
+                match value.lit {
+                    Lit::Str(lit) => Some(lit.value()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+
+        snake.extend(ch.to_lowercase());
+    }
+
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snake_case;
+
+    #[test]
+    fn table_names() {
+        assert_eq!(snake_case("User"), "user");
+        assert_eq!(snake_case("BlogPost"), "blog_post");
+    }
+}
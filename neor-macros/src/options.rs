@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type, Visibility,
+};
 
 pub(super) fn parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -11,13 +13,18 @@ pub(super) fn parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         Data::Struct(data) => match data.fields {
             Fields::Named(fields) => {
                 for field in fields.named {
+                    // the fields private to the crate are set by the other setters
+                    if !matches!(field.vis, Visibility::Public(_)) {
+                        continue;
+                    }
+
                     let name = field.ident;
                     let mut generics = TokenStream::new();
                     let mut where_clause = TokenStream::new();
                     let mut value = quote!(#name);
                     let mut param = param(&field.ty);
 
-                    if param.is_cow || param.is_db {
+                    if param.is_cow {
                         generics = quote!(<T>);
                         where_clause = quote!(where T: crate::StaticString);
                         param.ty = quote!(T);
@@ -25,7 +32,24 @@ pub(super) fn parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                     }
 
                     if param.is_db {
-                        value = quote!(Db(#value))
+                        // a command other than `r.db(name)` is reported by `run`
+                        methods.extend(quote! {
+                            pub fn #name<T>(mut self, #name: T) -> Self
+                            where
+                                T: TryInto<Db>,
+                                T::Error: std::fmt::Display,
+                            {
+                                match #name.try_into() {
+                                    Ok(#name) => {
+                                        self.#name = Some(#name);
+                                        self.invalid_db = None;
+                                    }
+                                    Err(error) => self.invalid_db = Some(error.to_string()),
+                                }
+                                self
+                            }
+                        });
+                        continue;
                     }
 
                     if param.is_option {
@@ -0,0 +1,18 @@
+use quote::quote;
+use syn::{parse_macro_input, Error, LitStr};
+
+pub(super) fn parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    let value = path.value();
+    let fields: Vec<&str> = value.split('.').collect();
+
+    if fields.iter().any(|field| field.is_empty()) {
+        return Error::new(path.span(), format!("empty field in path `{}`", value))
+            .to_compile_error()
+            .into();
+    }
+
+    let output = quote!(neor::types::FieldPath::from_static(&[#(#fields),*]));
+
+    output.into()
+}
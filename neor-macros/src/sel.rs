@@ -0,0 +1,124 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, parse_macro_input, token, Ident, Path, Token};
+
+pub(super) fn parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let Selector { typ, fields } = parse_macro_input!(input as Selector);
+    let mut checks = TokenStream::new();
+
+    for field in &fields {
+        field.checks(&quote!(doc), &mut checks);
+    }
+
+    let selector = object(&typ, &quote!(doc), quote!(doc), &fields);
+
+    let output = quote!({
+        use neor::sel::{DefaultName as _, StoredName as _};
+
+        // Only type checked, so that unknown fields fail to compile
+        let _ = |doc: &#typ| {
+            #checks
+        };
+        #selector
+    });
+
+    output.into()
+}
+
+// `User { id, name { first } }`
+struct Selector {
+    typ: Path,
+    fields: Punctuated<Field, Token![,]>,
+}
+
+// `id` or `name { first }`
+struct Field {
+    name: Ident,
+    fields: Option<Punctuated<Field, Token![,]>>,
+}
+
+impl Parse for Selector {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let typ = input.parse()?;
+        let content;
+        braced!(content in input);
+        let fields = content.parse_terminated(Field::parse)?;
+
+        Ok(Self { typ, fields })
+    }
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let fields = if input.peek(token::Brace) {
+            let content;
+            braced!(content in input);
+            Some(content.parse_terminated(Field::parse)?)
+        } else {
+            None
+        };
+
+        Ok(Self { name, fields })
+    }
+}
+
+impl Field {
+    fn checks(&self, parent: &TokenStream, checks: &mut TokenStream) {
+        let name = &self.name;
+        let access = quote!(#parent.#name);
+
+        checks.extend(quote!(let _ = &#access;));
+
+        for field in self.fields.iter().flatten() {
+            field.checks(&access, checks);
+        }
+    }
+
+    fn selector(&self, typ: &Path, parent: &TokenStream) -> TokenStream {
+        let name = &self.name;
+        let access = quote!(#parent.#name);
+
+        match &self.fields {
+            Some(fields) => object(typ, &access, quote!(&#access), fields),
+            None => quote!(neor::r.expr(true)),
+        }
+    }
+}
+
+// `{"id": true, "name": {"first": true}}`, keyed by the stored names
+// of the fields when their struct derives `Fields`, which also rejects
+// the fields skipped by serde
+fn object(
+    typ: &Path,
+    access: &TokenStream,
+    accessor: TokenStream,
+    fields: &Punctuated<Field, Token![,]>,
+) -> TokenStream {
+    let pairs = fields.iter().map(|field| {
+        let name = field.name.to_string();
+        let name = name.trim_start_matches("r#");
+        let selector = field.selector(typ, access);
+
+        quote!({
+            struct __SelectedField;
+
+            impl neor::sel::SelectedField for __SelectedField {
+                const NAME: &'static str = #name;
+            }
+
+            [
+                neor::r.expr((&names).stored_name::<__SelectedField>()),
+                #selector,
+            ]
+        })
+    });
+
+    quote!({
+        let names = neor::sel::FieldNames::of(|doc: &#typ| #accessor);
+
+        neor::r.object([#(#pairs),*].into_iter().flatten())
+    })
+}
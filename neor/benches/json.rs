@@ -0,0 +1,68 @@
+//! Serialization of queries and parsing of results.
+//!
+//! `cargo bench --bench json` measures serde_json,
+//! `cargo bench --bench json --features simd` adds simd-json,
+//! which needs `RUSTFLAGS="-C target-cpu=native"` to use SIMD instructions.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use neor::{func, r};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize)]
+struct Post {
+    id: u32,
+    title: String,
+    content: Option<String>,
+    tags: Vec<String>,
+    view: u32,
+}
+
+// A batch of results, like the `r` field of a response
+fn rows(len: u32) -> String {
+    let posts: Vec<_> = (0..len)
+        .map(|id| Post {
+            id,
+            title: format!("title {}", id),
+            content: Some("lorem ipsum dolor sit amet ".repeat(8)),
+            tags: vec!["rust".to_owned(), "reql".to_owned()],
+            view: id * 3,
+        })
+        .collect();
+
+    serde_json::to_string(&posts).unwrap()
+}
+
+fn serialize_query(c: &mut Criterion) {
+    let documents: Vec<_> = (0..1_000)
+        .map(|id| json!({"id": id, "title": format!("title {}", id), "tags": ["rust"]}))
+        .collect();
+    let query = r
+        .table("posts")
+        .insert(documents)
+        .g("generated_keys")
+        .map(func!(|key| key.clone() + "-" + key));
+
+    c.bench_function("serialize query", |b| {
+        b.iter(|| black_box(&query).to_wire_json().unwrap())
+    });
+}
+
+fn parse_rows(c: &mut Criterion) {
+    let rows = rows(1_000);
+
+    c.bench_function("parse rows serde_json", |b| {
+        b.iter(|| serde_json::from_str::<Vec<Post>>(black_box(&rows)).unwrap())
+    });
+
+    #[cfg(feature = "simd")]
+    c.bench_function("parse rows simd-json", |b| {
+        b.iter(|| {
+            let mut rows = black_box(&rows).as_bytes().to_vec();
+            simd_json::serde::from_slice::<Vec<Post>>(&mut rows).unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, serialize_query, parse_rows);
+criterion_main!(benches);
@@ -0,0 +1,515 @@
+//! Suggest the indexes able to replace a `filter` of a table
+//!
+//! A `filter` reads the whole table, while `get`, `get_all` and `between`
+//! only read the documents matching an index. An [IndexSchema] describes
+//! the primary key and the secondary indexes of the tables, and
+//! [advise](IndexSchema::advise) inspects the `filter` terms of a query,
+//! without the server, for the fields compared to constants:
+//! - equal to the primary key, the filter can be a `get`;
+//! - equal to all the fields of an index, a `get_all` on the index;
+//! - compared to bounds on the field of a simple index, a `between` on the index.
+//!
+//! The predicates are recognized when they are objects, e.g.
+//! `json!({"title": "hello"})`, or functions comparing fields of the
+//! document, joined by `and`. An index is described by its fields: the
+//! indexes built with other functions are not suggested.
+//!
+//! An [IndexAdvisor] set with
+//! [index_advisor](crate::types::ConnectionCommand::index_advisor) calls
+//! back with the advice on the queries run by a session, e.g. to log them
+//! during development.
+//!
+//! ```
+//! use neor::advisor::{IndexAdvisor, IndexSchema};
+//! use neor::{r, Result};
+//! use serde_json::json;
+//!
+//! async fn example() -> Result<()> {
+//!     let schema = IndexSchema::new()
+//!         .index("posts", "title", ["title"])
+//!         .index("posts", "author_date", ["author.name", "date"]);
+//!     let conn = r
+//!         .connection()
+//!         .index_advisor(IndexAdvisor::new(schema, |advice| eprintln!("{}", advice)))
+//!         .connect()
+//!         .await?;
+//!
+//!     // prints "`filter` of `posts` can be `get_all` on the index `title`"
+//!     r.table("posts")
+//!         .filter(json!({"title": "hello"}))
+//!         .run(&conn)
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use ql2::term::TermType;
+use serde_json::Value;
+
+use crate::types::FieldPath;
+use crate::Command;
+
+type Callback = Arc<dyn Fn(&IndexAdvice) + Send + Sync>;
+
+/// The keys and indexes of the tables, to compare the filters with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexSchema {
+    tables: HashMap<String, TableIndexes>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct TableIndexes {
+    primary_key: Option<String>,
+    // name and fields of each secondary index
+    indexes: Vec<(String, Vec<FieldPath>)>,
+}
+
+/// What can replace a `filter`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IndexAdviceKind {
+    /// [get](crate::Command::get) the document of a primary key
+    Get,
+    /// [get_all](crate::Command::get_all) the documents of the index
+    GetAll,
+    /// [between](crate::Command::between) bounds of the index
+    Between,
+}
+
+/// A `filter` which can read an index instead of the whole table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IndexAdvice {
+    pub kind: IndexAdviceKind,
+    pub table: String,
+    /// name of the index, or of the primary key for `Get`
+    pub index: String,
+    /// fields of the index compared by the filter
+    pub fields: Vec<FieldPath>,
+}
+
+impl fmt::Display for IndexAdvice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            IndexAdviceKind::Get => write!(
+                f,
+                "`filter` of `{}` can be `get` on the primary key `{}`",
+                self.table, self.index
+            ),
+            IndexAdviceKind::GetAll => write!(
+                f,
+                "`filter` of `{}` can be `get_all` on the index `{}`",
+                self.table, self.index
+            ),
+            IndexAdviceKind::Between => write!(
+                f,
+                "`filter` of `{}` can be `between` on the index `{}`",
+                self.table, self.index
+            ),
+        }
+    }
+}
+
+// How a filter compares a field to a constant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Eq,
+    Bound,
+}
+
+impl IndexSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the primary key of `table`, `id` by default
+    pub fn primary_key(mut self, table: impl Into<String>, key: impl Into<String>) -> Self {
+        self.tables.entry(table.into()).or_default().primary_key = Some(key.into());
+        self
+    }
+
+    /// Add the secondary index `index` of `table`, on the field paths
+    /// `fields`, e.g. `author.name`, compound when there are several
+    ///
+    /// A path with an empty field, such as `author..name`, is never advised.
+    pub fn index<I, S>(
+        mut self,
+        table: impl Into<String>,
+        index: impl Into<String>,
+        fields: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fields = fields
+            .into_iter()
+            .map(|field| segments(&field.as_ref().split('.').collect::<Vec<_>>()))
+            .collect();
+
+        self.tables
+            .entry(table.into())
+            .or_default()
+            .indexes
+            .push((index.into(), fields));
+        self
+    }
+
+    /// The advice on the `filter` terms of `query`
+    ///
+    /// Only the filters of a table of the schema are inspected.
+    pub fn advise(&self, query: &Command) -> Vec<IndexAdvice> {
+        let mut advice = Vec::new();
+
+        if let Ok(term) = query.to_wire_json() {
+            self.push_advice(&term, &mut advice);
+        }
+
+        advice
+    }
+
+    fn push_advice(&self, term: &Value, advice: &mut Vec<IndexAdvice>) {
+        let (typ, args) = match term_args(term) {
+            Some(term) => term,
+            None => return,
+        };
+
+        if typ == TermType::Filter as i64 {
+            if let [sequence, predicate, ..] = args.as_slice() {
+                if let Some((table, indexes)) = self.table_of(sequence) {
+                    indexes.push_advice(table, &comparisons(predicate), advice);
+                }
+            }
+        }
+
+        for arg in args {
+            self.push_advice(arg, advice);
+        }
+    }
+
+    fn table_of(&self, sequence: &Value) -> Option<(&str, &TableIndexes)> {
+        match term_args(sequence)? {
+            (typ, args) if typ == TermType::Table as i64 => {
+                let table = args.last()?.as_str()?;
+
+                self.tables
+                    .get_key_value(table)
+                    .map(|(table, indexes)| (table.as_str(), indexes))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TableIndexes {
+    fn push_advice(
+        &self,
+        table: &str,
+        comparisons: &[(FieldPath, Comparison)],
+        advice: &mut Vec<IndexAdvice>,
+    ) {
+        let compared =
+            |field: &FieldPath, comparison| comparisons.contains(&(field.clone(), comparison));
+        let mut push = |kind, index: &str, fields: Vec<FieldPath>| {
+            advice.push(IndexAdvice {
+                kind,
+                table: table.to_owned(),
+                index: index.to_owned(),
+                fields,
+            })
+        };
+
+        // the primary key is a single field, even with a dot in its name
+        let primary_key = segments(&[self.primary_key.as_deref().unwrap_or("id")]);
+        if compared(&primary_key, Comparison::Eq) {
+            push(
+                IndexAdviceKind::Get,
+                &primary_key.to_string(),
+                vec![primary_key],
+            );
+        }
+
+        for (index, fields) in &self.indexes {
+            if fields.iter().all(|field| compared(field, Comparison::Eq)) {
+                push(IndexAdviceKind::GetAll, index, fields.clone());
+            } else if let [field] = fields.as_slice() {
+                if compared(field, Comparison::Bound) {
+                    push(IndexAdviceKind::Between, index, fields.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Calls back with the advice of an [IndexSchema] on the queries of a session
+///
+/// See [index_advisor](crate::types::ConnectionCommand::index_advisor).
+#[derive(Clone)]
+pub struct IndexAdvisor {
+    schema: IndexSchema,
+    callback: Callback,
+}
+
+impl IndexAdvisor {
+    pub fn new(
+        schema: IndexSchema,
+        callback: impl Fn(&IndexAdvice) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            schema,
+            callback: Arc::new(callback),
+        }
+    }
+
+    pub fn schema(&self) -> &IndexSchema {
+        &self.schema
+    }
+
+    pub(crate) fn inspect(&self, query: &Command) {
+        for advice in self.schema.advise(query) {
+            (self.callback)(&advice);
+        }
+    }
+}
+
+impl Debug for IndexAdvisor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexAdvisor")
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+// The type and the arguments of a term of the wire JSON
+fn term_args(term: &Value) -> Option<(i64, &Vec<Value>)> {
+    match term.as_array()?.as_slice() {
+        [typ, Value::Array(args), ..] => Some((typ.as_i64()?, args)),
+        _ => None,
+    }
+}
+
+// The fields of the document compared to constants by a predicate
+fn comparisons(predicate: &Value) -> Vec<(FieldPath, Comparison)> {
+    let mut comparisons = Vec::new();
+
+    match predicate {
+        Value::Object(_) => push_object_fields(predicate, &[], &mut comparisons),
+        _ => {
+            if let Some((typ, args)) = term_args(predicate) {
+                if let (true, [params, body]) = (typ == TermType::Func as i64, args.as_slice()) {
+                    if let Some(param) = func_param(params) {
+                        push_conditions(body, param, &mut comparisons);
+                    }
+                }
+            }
+        }
+    }
+
+    comparisons
+}
+
+// The fields matched by an object predicate, e.g. `{"author": {"name": "x"}}`
+fn push_object_fields(
+    value: &Value,
+    path: &[&str],
+    comparisons: &mut Vec<(FieldPath, Comparison)>,
+) {
+    match value {
+        Value::Object(fields) => {
+            // an empty field is no path of an index
+            for (field, value) in fields.iter().filter(|(field, _)| !field.is_empty()) {
+                let path = [path, &[field.as_str()]].concat();
+
+                push_object_fields(value, &path, comparisons);
+            }
+        }
+        // arrays are terms, not constants
+        Value::Array(_) => {}
+        _ => comparisons.push((segments(path), Comparison::Eq)),
+    }
+}
+
+// The variable of a function of one argument, `[MAKE_ARRAY, [id]]`
+fn func_param(params: &Value) -> Option<u64> {
+    match term_args(params)? {
+        (typ, args) if typ == TermType::MakeArray as i64 && args.len() == 1 => args[0].as_u64(),
+        _ => None,
+    }
+}
+
+fn push_conditions(body: &Value, param: u64, comparisons: &mut Vec<(FieldPath, Comparison)>) {
+    let (typ, args) = match term_args(body) {
+        Some(term) => term,
+        None => return,
+    };
+
+    if typ == TermType::And as i64 {
+        for arg in args {
+            push_conditions(arg, param, comparisons);
+        }
+        return;
+    }
+
+    let comparison = match typ {
+        typ if typ == TermType::Eq as i64 => Comparison::Eq,
+        typ if [TermType::Lt, TermType::Le, TermType::Gt, TermType::Ge]
+            .iter()
+            .any(|bound| typ == *bound as i64) =>
+        {
+            Comparison::Bound
+        }
+        _ => return,
+    };
+
+    if let [left, right] = args.as_slice() {
+        let field = match (field_path(left, param), field_path(right, param)) {
+            (Some(field), None) if is_constant(right) => field,
+            (None, Some(field)) if is_constant(left) => field,
+            _ => return,
+        };
+
+        if !field.is_empty() && !field.contains(&"") {
+            comparisons.push((segments(&field), comparison));
+        }
+    }
+}
+
+// The fields read from the variable `param`, e.g. `doc.g("author").g("name")`
+fn field_path(term: &Value, param: u64) -> Option<Vec<&str>> {
+    match term_args(term)? {
+        (typ, args) if typ == TermType::Var as i64 => {
+            (args.first()?.as_u64()? == param).then(Vec::new)
+        }
+        (typ, args) if typ == TermType::GetField as i64 || typ == TermType::Bracket as i64 => {
+            match args.as_slice() {
+                [object, field] => {
+                    let mut path = field_path(object, param)?;
+
+                    path.push(field.as_str()?);
+                    Some(path)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// The path of the nested `fields`, which may contain dots
+fn segments(fields: &[&str]) -> FieldPath {
+    fields
+        .iter()
+        .fold(FieldPath::from_static(&[]), |path, field| {
+            path.child(*field)
+        })
+}
+
+fn is_constant(value: &Value) -> bool {
+    !matches!(value, Value::Array(_) | Value::Object(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{IndexAdvice, IndexAdviceKind, IndexSchema};
+    use crate::types::FieldPath;
+    use crate::{r, Func};
+
+    fn schema() -> IndexSchema {
+        IndexSchema::new()
+            .primary_key("users", "email")
+            .primary_key("logs", "request.id")
+            .index("logs", "empty", [""])
+            .index("posts", "title", ["title"])
+            .index("posts", "author_date", ["author.name", "date"])
+            .index("posts", "views", ["views"])
+    }
+
+    fn kinds(advice: Vec<IndexAdvice>) -> Vec<(IndexAdviceKind, String)> {
+        advice
+            .into_iter()
+            .map(|advice| (advice.kind, advice.index))
+            .collect()
+    }
+
+    #[test]
+    fn advise_object_filter() {
+        let posts = r.table("posts");
+        let advice = schema()
+            .advise(&posts.filter(json!({"id": 1, "author": {"name": "john"}, "date": "2024"})));
+
+        assert_eq!(
+            kinds(advice),
+            [
+                (IndexAdviceKind::Get, "id".to_owned()),
+                (IndexAdviceKind::GetAll, "author_date".to_owned()),
+            ]
+        );
+        assert!(schema()
+            .advise(&posts.filter(json!({"author": {"name": "john"}})))
+            .is_empty());
+        assert!(schema()
+            .advise(&r.table("comments").filter(json!({"title": "hello"})))
+            .is_empty());
+    }
+
+    #[test]
+    fn advise_func_filter() {
+        let posts = r.table("posts");
+        let doc = || crate::Command::var(1);
+        let predicate = doc().g("views").ge(10).and(doc().g("title").eq("hello"));
+        let advice = schema().advise(&posts.filter(Func::new(vec![1], predicate)).count(()));
+        let users = r
+            .table("users")
+            .filter(Func::new(vec![1], doc().g("email").eq("a@b.c")));
+
+        assert_eq!(
+            kinds(advice),
+            [
+                (IndexAdviceKind::GetAll, "title".to_owned()),
+                (IndexAdviceKind::Between, "views".to_owned()),
+            ]
+        );
+        assert_eq!(schema().advise(&users)[0].fields, [FieldPath::new("email")]);
+        assert_eq!(
+            schema().advise(&users)[0].to_string(),
+            "`filter` of `users` can be `get` on the primary key `email`"
+        );
+    }
+
+    #[test]
+    fn advise_dotted_field_names() {
+        let posts = r.table("posts");
+        let doc = || crate::Command::var(1);
+        let dotted = json!({"author.name": "john", "date": "2024", "a.": 1});
+        let predicate = doc().g("a.").eq(1).and(doc().g("author.name").eq("john"));
+
+        // a field named `author.name` is not the `name` of the `author`
+        assert!(schema().advise(&posts.filter(dotted)).is_empty());
+        assert!(schema()
+            .advise(&posts.filter(Func::new(vec![1], predicate)))
+            .is_empty());
+    }
+
+    #[test]
+    fn advise_dotted_primary_key() {
+        let logs = r.table("logs");
+        let doc = || crate::Command::var(1);
+        let by_key = Func::new(vec![1], doc().g("request.id").eq(1));
+        let by_empty = Func::new(vec![1], doc().g("").eq(1));
+        let nested = json!({"request": {"id": 1}});
+
+        assert_eq!(
+            kinds(schema().advise(&logs.filter(by_key))),
+            [(IndexAdviceKind::Get, "request.id".to_owned())]
+        );
+        assert!(schema().advise(&logs.filter(by_empty)).is_empty());
+        assert!(schema().advise(&logs.filter(nested)).is_empty());
+    }
+}
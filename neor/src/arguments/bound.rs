@@ -0,0 +1,55 @@
+use crate::{r, Command, CommandArg};
+
+use super::Status;
+
+/// A bound of the range of [between](crate::Command::between),
+/// with whether its key is in the range
+///
+/// ```
+/// use neor::arguments::Bound;
+/// use neor::{r, Command};
+///
+/// let query: Command = r.table("users").between(Bound::closed(10)..Bound::open(20));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bound {
+    key: Command,
+    status: Status,
+}
+
+impl Bound {
+    /// A bound excluding `key` from the range
+    pub fn open(key: impl Into<CommandArg>) -> Self {
+        Self::new(key, Status::Open)
+    }
+
+    /// A bound including `key` in the range
+    pub fn closed(key: impl Into<CommandArg>) -> Self {
+        Self::new(key, Status::Closed)
+    }
+
+    /// The bound less than any key, see [r::min_val](crate::r::min_val)
+    pub fn min() -> Self {
+        Self::closed(r::min_val())
+    }
+
+    /// The bound more than any key, see [r::max_val](crate::r::max_val)
+    pub fn max() -> Self {
+        Self::closed(r::max_val())
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    fn new(key: impl Into<CommandArg>, status: Status) -> Self {
+        Self {
+            key: key.into().to_cmd(),
+            status,
+        }
+    }
+
+    pub(crate) fn into_parts(self) -> (Command, Status) {
+        (self.key, self.status)
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Serialize, Serializer};
+
+use crate::proto::Query;
+use crate::Func;
+
+/// How [union](crate::Command::union) merges its sequences
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Interleave {
+    Bool(bool),
+    FieldName(&'static str),
+    /// Merge-sort on the value returned by the function for each document
+    Func(Func),
+}
+
+impl Serialize for Interleave {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Bool(boolean) => boolean.serialize(serializer),
+            Self::FieldName(field) => field.serialize(serializer),
+            Self::Func(func) => Query(&func.0).serialize(serializer),
+        }
+    }
+}
@@ -3,11 +3,19 @@ use std::{borrow::Cow, collections::HashMap};
 use neor_macros::CommandOptions;
 use serde::{Deserialize, Serialize};
 
+pub use bound::Bound;
+pub use interleave::Interleave;
 pub use options::*;
 pub use return_changes::ReturnChanges;
+pub use squash::Squash;
 
+pub use crate::types::Unit;
+
+mod bound;
+mod interleave;
 mod options;
 mod return_changes;
+mod squash;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Args<T>(pub T);
@@ -113,37 +121,6 @@ pub enum WaitFor {
     AllReplicasReady,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub enum Unit {
-    #[serde(rename = "m")]
-    Meter,
-    #[serde(rename = "km")]
-    Kilometer,
-    #[serde(rename = "mi")]
-    InternationalMile,
-    #[serde(rename = "nm")]
-    NauticalMile,
-    #[serde(rename = "ft")]
-    InternationalFoot,
-}
-
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd)]
-#[serde(untagged)]
-#[non_exhaustive]
-pub enum Interleave {
-    Bool(bool),
-    FieldName(&'static str),
-    // Function
-}
-
-/// Controls how change notifications are batched
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd)]
-#[serde(untagged)]
-pub enum Squash {
-    Bool(bool),
-    Float(f32),
-}
-
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum GeoSystem {
     #[serde(rename = "unit_sphere")]
@@ -151,7 +128,9 @@ pub enum GeoSystem {
     WGS84,
 }
 
+/// Type targeted by [coerce_to](crate::Command::coerce_to)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[serde(rename_all = "lowercase")]
 pub enum CoerceType {
     Array,
     String,
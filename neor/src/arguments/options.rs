@@ -1,12 +1,14 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::time::Duration;
 
 use neor_macros::CommandOptions;
 use serde::{Serialize, Serializer};
 
 use crate::cmd::run::Db;
 use crate::constants::DEFAULT_RETHINKDB_DBNAME;
-use crate::Session;
+use crate::proto::Query;
+use crate::{Func, Session};
 
 use super::*;
 
@@ -34,13 +36,13 @@ pub struct CircleOption {
 #[derive(Debug, Clone, Serialize, Default, PartialEq, PartialOrd, CommandOptions)]
 pub struct ChangesOption {
     /// Controls how change notifications are batched.
-    /// Acceptable values are `Squash::Bool(true)`, `Squash::Bool(false)` and a numeric value
+    /// Acceptable values are `Squash::Bool(true)`, `Squash::Bool(false)` and a duration
     /// - `Squash::Bool(true)`: When multiple changes to the same document occur before a batch
     /// of notifications is sent, the changes are “squashed” into one change.
     /// The client receives a notification that will bring it fully up to date with the server.
     /// - `Squash::Bool(false)`: All changes will be sent to the client verbatim. This is the default.
-    /// - `Squash::Float(n)`: A numeric value (floating point). Similar to `Squash::Bool(true)`, but the
-    /// server will wait `n` seconds to respond in order to squash as many changes together as possible,
+    /// - `Squash::Duration(n)`: Similar to `Squash::Bool(true)`, but the
+    /// server will wait `n` to respond in order to squash as many changes together as possible,
     /// reducing network traffic. The first batch will always be returned immediately.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub squash: Option<Squash>,
@@ -68,7 +70,8 @@ pub struct ChangesOption {
     pub include_states: Option<bool>,
     /// if `true`, a changefeed stream on an `order_by`.
     /// limit changefeed will include `old_offset` and `new_offset`
-    /// fields in status documents that include `old_val` and `new_val`.
+    /// fields in status documents that include `old_val` and `new_val`,
+    /// read in [ChangesResponse](crate::types::ChangesResponse).
     /// This allows applications to maintain ordered lists of the stream’s result set.
     /// If `old_offset` is set and not `None`, the element at `old_offset` is being deleted;
     /// if `new_offset` is set and not `None`, then new_val is being inserted at `new_offset`.
@@ -83,6 +86,17 @@ pub struct ChangesOption {
     pub include_types: Option<bool>,
 }
 
+/// Chunks of [insert_batched](crate::Command::insert_batched)
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, CommandOptions)]
+pub struct BatchOption {
+    /// Number of documents inserted by each query. Defaults to 200.
+    pub chunk_size: Option<usize>,
+    /// Number of queries running at the same time. Defaults to 4.
+    pub parallelism: Option<usize>,
+    /// Conflict resolution of the inserts, see [InsertOption].
+    pub conflict: Option<Conflict>,
+}
+
 ///  If `index` is set to the name of a secondary index,
 /// `between` will return all documents where that index’s
 /// value is in the specified range (it uses the primary key by default).
@@ -101,6 +115,15 @@ pub struct BetweenOption {
     pub right_bound: Option<Status>,
 }
 
+/// Error mode of [default](crate::Command::default),
+/// when no default value is given
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, CommandOptions)]
+pub struct DefaultOption {
+    /// If `true`, non-existence errors evaluate to `null`;
+    /// if `false` (the default), they are raised again while `null` values are kept.
+    pub error_to_null: Option<bool>,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Default, PartialEq, PartialOrd, CommandOptions)]
 pub struct DeleteOption {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -175,12 +198,27 @@ impl FilterOption {
     }
 }
 
-// #[derive(Debug, Clone, Serialize, Default)]
-// #[non_exhaustive]
-// pub struct FoldOption {
-//     pub emit: Option<Command>,
-//     pub final_emit: Option<Command>,
-// }
+/// Second form of [fold](crate::Command::fold), returning a sequence
+#[derive(Debug, Clone, Serialize, Default, CommandOptions)]
+pub struct FoldOption {
+    /// Called with the previous accumulator, the element and the new accumulator,
+    /// returns an array of values appended to the resulting sequence.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "func_term")]
+    pub emit: Option<Func>,
+    /// Called with the last accumulator,
+    /// returns an array of values appended at the end of the sequence.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "func_term")]
+    pub final_emit: Option<Func>,
+}
+
+fn func_term<S>(func: &Option<Func>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    func.as_ref()
+        .map(|func| Query(&func.0))
+        .serialize(serializer)
+}
 
 #[derive(Debug, Clone, Serialize, Default, PartialEq, Eq, PartialOrd, Ord, CommandOptions)]
 pub struct GetAllOption {
@@ -195,6 +233,7 @@ pub struct GetIntersectingOption {
 
 #[derive(Debug, Clone, Serialize, Default, CommandOptions)]
 pub struct GetNearestOption {
+    /// the geospatial index to use, set by the `&str` argument of `get_nearest`
     pub index: Cow<'static, str>,
     /// the maximum number of results to return (default 100).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -202,9 +241,10 @@ pub struct GetNearestOption {
     /// Unit for the distance.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unit: Option<Unit>,
-    /// distance from an object to the specified point (default 100 km).
+    /// distance from an object to the specified point,
+    /// in the `unit` of the query (default 100 km).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_dist: Option<usize>,
+    pub max_dist: Option<f64>,
     /// the reference ellipsoid to use for geographic coordinates.
     /// Possible values are `GeoSystem::WGS84` (the default),
     /// a common standard for Earth’s geometry, or `GeoSystem::UnitSphere`,
@@ -242,10 +282,10 @@ pub struct InsertOption {
     pub durability: Option<Durability>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub return_changes: Option<ReturnChanges>,
+    /// `Conflict::Error` by default, a function can be given
+    /// to [insert](crate::Command::insert) instead
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conflict: Option<Conflict>,
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // pub conflict_func: Command,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ignore_write_hook: Option<bool>,
 }
@@ -400,15 +440,23 @@ pub struct RunOption {
     /// Set this to `Format::Raw` if you want the raw pseudotype.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_format: Option<Format>,
+    /// whether to skip the response of the server (default: `false`).
+    /// `run` returns `None` right after sending the query;
+    /// use [noreply_wait](crate::connection::Session::noreply_wait)
+    /// to wait until the server has processed it.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub noreply: Option<bool>,
-    /// the database to run this query against as a string.
+    /// the database to run this query against, as a string
+    /// or as a [db](crate::r::db) command.
     /// The default is the database specified in
     /// the `db` [connection](crate::connection::Connection)
     /// method (which defaults to `test`).
     /// The database may also be specified with the db command.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub db: Option<Db>,
+    // why the command given as `db` was rejected, reported by `run`
+    #[serde(skip)]
+    pub(crate) invalid_db: Option<String>,
     /// the maximum numbers of array elements
     /// that can be returned by a query (default: 100,000).
     /// This affects all ReQL commands that return arrays.
@@ -444,6 +492,17 @@ pub struct RunOption {
     /// This allows the first batch to return faster.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub first_batch_scaledown_factor: Option<usize>,
+    /// whether to prefix table names with the prefix set by
+    /// [table_prefix](crate::types::ConnectionCommand::table_prefix)
+    /// (default: `true`). This option is not sent to the server.
+    #[serde(skip)]
+    pub table_prefix: Option<bool>,
+    /// maximum time to wait for each response of the query.
+    /// When it expires, the query is stopped on the server and `run`
+    /// returns a [Timeout](crate::err::ReqlDriverError::Timeout) error.
+    /// This option is not sent to the server.
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
 }
 
 impl RunOption {
@@ -532,7 +591,7 @@ pub struct TableOption {
     pub identifier_format: Option<IdentifierFormat>,
 }
 
-#[derive(Debug, Clone, Serialize, Default, PartialEq, PartialOrd, CommandOptions)]
+#[derive(Debug, Clone, Serialize, Default, CommandOptions)]
 pub struct UnionOption {
     /// The optional `interleave` argument controls
     /// how the sequences will be merged:
@@ -542,6 +601,7 @@ pub struct UnionOption {
     /// - `Interleave::Bool(false)`: input sequences will be appended to one another, left to right.
     /// - `Interleave::FieldName(field_name)`: a string will be taken as the name of a field
     /// to perform a merge-sort on. The input sequences must be ordered **before** being passed to `union`.
+    /// - `Interleave::Func(func)`: the sequences are merge-sorted on the value returned by the function.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interleave: Option<Interleave>,
 }
@@ -566,7 +626,9 @@ pub struct WaitOption {
     /// a number indicating maximum time, in seconds,
     /// to wait for the table to be ready.
     /// If this value is exceeded, a ReqlRuntimeError will be thrown.
-    /// A value of0 means no timeout. The default is 0 (no timeout).
+    /// A value of 0 means no timeout. The default is 0 (no timeout).
+    /// With `index_wait`, the query is stopped by the driver
+    /// and a `ReqlDriverError::Timeout` is returned instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout: Option<f64>,
 }
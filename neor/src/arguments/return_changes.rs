@@ -1,9 +1,15 @@
 use serde::{Deserialize, Serialize, Serializer};
 
+/// The `return_changes` option of the writes, whose changes
+/// are read from [MutationResponse](crate::types::MutationResponse)
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, PartialOrd)]
 #[non_exhaustive]
 pub enum ReturnChanges {
+    /// `true` to return the changes of the modified documents
     Bool(bool),
+    /// Return a change for every document the write touches,
+    /// even unchanged ones or those in error, whose change holds
+    /// the same `old_val` and `new_val`
     Always,
 }
 
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+/// Controls how change notifications are batched
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Squash {
+    Bool(bool),
+    /// Squash the changes received during this delay, sent in seconds
+    Duration(Duration),
+}
+
+impl Serialize for Squash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Bool(boolean) => boolean.serialize(serializer),
+            Self::Duration(duration) => duration.as_secs_f64().serialize(serializer),
+        }
+    }
+}
@@ -25,6 +25,7 @@ pub mod config;
 pub mod connect;
 pub mod contains;
 pub mod count;
+pub mod cursor;
 pub mod date;
 pub mod day;
 pub mod day_of_week;
@@ -83,6 +84,7 @@ pub mod info;
 pub mod inner_join;
 pub mod insert;
 pub mod insert_at;
+pub mod insert_batched;
 pub mod intersects;
 pub mod is_empty;
 pub mod iso8601;
@@ -91,15 +93,18 @@ pub mod json;
 pub mod keys;
 pub mod le;
 pub mod limit;
+pub mod limit_changes;
 pub mod line;
 pub mod literal;
 pub mod lt;
 pub mod map;
 pub mod match_;
+pub mod match_type;
 pub mod max;
 pub mod merge;
 pub mod min;
 pub mod minutes;
+pub mod mirror;
 pub mod month;
 pub mod mul;
 pub mod ne;
@@ -111,13 +116,19 @@ pub mod offsets_of;
 pub mod or;
 pub mod order_by;
 pub mod outer_join;
+pub mod paginate;
+pub mod patch;
 pub mod pluck;
 pub mod point;
 pub mod polygon;
 pub mod polygon_sub;
+pub mod prepare;
 pub mod prepend;
+pub mod primary_keys;
 pub mod random;
 pub mod range;
+pub mod raw;
+pub mod read_partitioned;
 pub mod rebalance;
 pub mod reconfigure;
 pub mod reduce;
@@ -164,16 +175,22 @@ pub mod without;
 pub mod year;
 pub mod zip;
 
+use std::hash::Hash;
 use std::ops::{BitAnd, BitOr, BitXor};
 use std::str;
+use std::time::Duration;
 
 use ::time::UtcOffset;
 use futures::stream::Stream;
 use futures::TryStreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::Value;
 
-use crate::arguments::{Permission, ReconfigureOption};
-use crate::{Command, CommandArg, Func, Result};
+use crate::arguments::{Args, CoerceType, Permission, ReadMode, ReconfigureOption};
+use crate::proto::Query;
+use crate::types::{ChangesResponse, Diagnostic, FeedItem, JoinResponse};
+use crate::{r, Command, CommandArg, Func, Result};
 
 impl<'a> Command {
     /// Turn a query into a changefeed, an infinite stream of objects
@@ -301,388 +318,570 @@ impl<'a> Command {
         changes::new(args).with_parent(self)
     }
 
-    /// Create a table.
+    /// Collect the changes of a changefeed for a limited time.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// db.table_create(table_name) → response
-    /// db.table_create(args!(table_name, options)) → response
+    /// stream.collect_changes_for(&session, duration) → Vec<ChangesResponse<T>>
+    /// stream.collect_changes_for(connection, duration) → Vec<ChangesResponse<T>>
+    /// stream.collect_changes_for(args!(&session, options), duration) → Vec<ChangesResponse<T>>
+    /// stream.collect_changes_for(args!(connection, options), duration) → Vec<ChangesResponse<T>>
     /// ```
     ///
     /// Where:
-    /// - table_name: `impl Into<String>` | [Command](crate::Command)
-    /// - options: [TableCreateOption](crate::arguments::TableCreateOption)
-    /// - response: [DbResponse](crate::types::DbResponse)
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    /// - duration: [Duration](std::time::Duration)
     ///
     /// # Description
     ///
-    /// A RethinkDB table is a collection of JSON documents.
-    ///
-    /// If a table with the same name already exists,
-    /// the command throws `ReqlOpFailedError`.
-    ///
-    /// ```text
-    /// Note: Only alphanumeric characters and underscores are valid for the table name.
-    ///
-    /// Invoking table_create without specifying a database using db creates a table in
-    /// the database specified in connect, or test if no database was specified.
-    /// ```
+    /// Subscribe to the changefeed, gather every change received
+    /// during `duration`, then stop the feed on the server and return them.
+    /// The query must be a changefeed (see [changes](Self::changes)).
     ///
-    /// The [data type](https://rethinkdb.com/docs/data-types/) of a primary key is usually a string
-    /// (like a UUID) or a number, but it can also be a time, binary object, boolean or an array.
-    /// Data types can be mixed in the primary key field, but all values must be unique. Using an array
-    /// as a primary key causes the primary key to behave like a compound index; read the documentation on
-    /// [compound secondary indexes](https://rethinkdb.com/docs/secondary-indexes/python/#compound-indexes)
-    /// for more information, as it applies to primary keys as well.
-    /// (Note that the primary index still only covers a single field,
-    /// while compound secondary indexes can cover multiple fields in a single index.)
-    /// Primary keys cannot be objects.
+    /// ## Note
     ///
-    /// Tables will be available for writing when the command returns.
+    /// When the window ends while a batch is still being read,
+    /// that batch is abandoned. Use a dedicated session for the feed
+    /// if other queries must keep running on the same socket.
     ///
     /// ## Examples
     ///
-    /// Create a table named ‘simbad’ with the default settings.
+    /// Gather the current documents of a table and
+    /// the changes made on it during five seconds.
     ///
     /// ```
-    /// use neor::types::DbResponse;
-    /// use neor::{r, Converter, Result};
+    /// use std::time::Duration;
+    ///
+    /// use neor::arguments::ChangesOption;
+    /// use neor::types::ChangesResponse;
+    /// use neor::{r, Result};
+    /// use serde_json::Value;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: DbResponse = r.db("test")
-    ///         .table_create("simbad")
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let changes_options = ChangesOption::default().include_initial(true);
+    ///     let response: Vec<ChangesResponse<Value>> = r.table("simbad")
+    ///         .changes(changes_options)
+    ///         .collect_changes_for(&conn, Duration::from_secs(5))
+    ///         .await?;
+    ///
+    ///     assert!(response.len() > 0);
     ///
-    ///     assert!(response.tables_created > Some(0));
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [changes](Self::changes)
+    /// - [run](Self::run)
+    pub async fn collect_changes_for<T>(
+        &self,
+        args: impl run::RunArg,
+        duration: Duration,
+    ) -> Result<Vec<ChangesResponse<T>>>
+    where
+        T: Unpin + DeserializeOwned,
+    {
+        changes::collect_for(self, args, duration).await
+    }
+
+    /// Run a changefeed and read its notifications one by one.
     ///
-    /// Create a table named ‘simbad’ using the field ‘name’ as primary key.
+    /// # Command syntax
     ///
+    /// ```text
+    /// stream.run_changes(&session) → stream<FeedItem<T>>
+    /// stream.run_changes(connection) → stream<FeedItem<T>>
+    /// stream.run_changes(args!(&session, options)) → stream<FeedItem<T>>
+    /// stream.run_changes(args!(connection, options)) → stream<FeedItem<T>>
     /// ```
-    /// use neor::arguments::TableCreateOption;
-    /// use neor::types::DbResponse;
-    /// use neor::{args, r, Converter, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let table_create_option = TableCreateOption::default().primary_key("name");
-    ///     let conn = r.connection().connect().await?;
-    ///     let response: DbResponse = r.db("test")
-    ///         .table_create(args!("simbad", table_create_option))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    /// Where:
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    /// - stream<FeedItem<T>>: a stream of [FeedItem](crate::types::FeedItem)
     ///
-    ///     assert!(response.tables_created > Some(0));
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// # Description
+    ///
+    /// The batches of the feed are split into typed notifications,
+    /// so the documents don't have to be read from
+    /// [ChangesResponse](crate::types::ChangesResponse) fields.
+    /// The query must be a changefeed (see [changes](Self::changes)).
+    /// Dropping the stream stops the feed on the server.
     ///
     /// ## Examples
     ///
-    /// Create a table set up for two shards and three replicas per shard.
-    /// This requires three available servers.
+    /// Print the changes made on a table.
     ///
     /// ```
-    /// use neor::arguments::{Replicas, TableCreateOption};
-    /// use neor::types::DbResponse;
-    /// use neor::{args, r, Converter, Result};
+    /// use futures::TryStreamExt;
+    /// use neor::arguments::ChangesOption;
+    /// use neor::types::FeedItem;
+    /// use neor::{r, Result};
+    /// use serde_json::Value;
     ///
     /// async fn example() -> Result<()> {
-    ///     let table_create_option = TableCreateOption::default()
-    ///         .shards(2)
-    ///         .replicas(Replicas::Int(3));
     ///     let conn = r.connection().connect().await?;
-    ///     let response: DbResponse = r.db("test")
-    ///         .table_create(args!("simbad", table_create_option))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let changes_options = ChangesOption::default().include_initial(true);
+    ///     let mut feed = r.table("simbad")
+    ///         .changes(changes_options)
+    ///         .run_changes::<Value>(&conn);
+    ///
+    ///     while let Some(item) = feed.try_next().await? {
+    ///         match item {
+    ///             FeedItem::Initial(doc) => println!("current: {}", doc),
+    ///             FeedItem::Change { old, new } => println!("{:?} -> {:?}", old, new),
+    ///             FeedItem::State(state) => println!("feed is {:?}", state),
+    ///             FeedItem::Error(error) => eprintln!("{}", error),
+    ///         }
+    ///     }
     ///
-    ///     assert!(response.tables_created > Some(0));
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// Read [Sharding and replication](https://rethinkdb.com/docs/sharding-and-replication/)
-    /// for a complete discussion of the subject, including advanced topics.
-    ///
     /// # Related commands
-    /// - [table_drop](Self::table_drop)
-    /// - [table_list](Self::table_list)
-    pub fn table_create(&self, args: impl table_create::TableCreateArg) -> Self {
-        table_create::new(args).with_parent(self)
+    /// - [changes](Self::changes)
+    /// - [collect_changes_for](Self::collect_changes_for)
+    pub fn run_changes<T>(&self, args: impl run::RunArg) -> impl Stream<Item = Result<FeedItem<T>>>
+    where
+        T: Unpin + DeserializeOwned,
+    {
+        Box::pin(changes::feed(self.clone(), args))
     }
 
-    /// Drop a table.
+    /// Mirror the documents of a selection in a map kept up to date by a changefeed.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// db.table_drop(table_name) → response
+    /// selection.mirror(&session) → mirror
+    /// selection.mirror(connection) → mirror
+    /// selection.mirror(args!(&session, options)) → mirror
+    /// selection.mirror(args!(connection, options)) → mirror
     /// ```
     ///
     /// Where:
-    /// - table_name: `impl Into<String>` | [Command](crate::Command)
-    /// - response: [DbResponse](crate::types::DbResponse)
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    /// - mirror: [ChangesMirror](crate::cmd::mirror::ChangesMirror)
     ///
     /// # Description
     ///
-    /// The table and all its data will be deleted.
-    ///
-    /// If the given table does not exist in the database,
-    /// the command throws `ReqlRuntimeError`.
+    /// The selection, e.g. `get_all` over a set of keys, is watched with
+    /// `changes` including the initial documents, the states and the types
+    /// of the notifications (see [ChangeEvent](crate::types::ChangeEvent)).
+    /// Each call to `next` applies a batch of notifications to the map,
+    /// whose keys are the primary keys of the documents.
     ///
     /// ## Examples
     ///
-    /// Drop a table named ‘simbad’.
+    /// Watch two users.
     ///
     /// ```
-    /// use neor::types::DbResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
+    /// use serde_json::Value;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: DbResponse = r.db("test")
-    ///         .table_drop("simbad")
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let mut users = r.table("users")
+    ///         .get_all(["malik", "sara"])
+    ///         .mirror::<String, Value>(&conn)?;
+    ///
+    ///     users.wait_ready().await?;
+    ///     println!("{:?}", users.get(&"malik".to_owned()));
+    ///
+    ///     while users.next().await? {
+    ///         println!("{} users", users.len());
+    ///     }
     ///
-    ///     assert!(response.tables_dropped > Some(0));
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [table_create](Self::table_create)
-    /// - [table_list](Self::table_list)
-    pub fn table_drop(&self, table_name: impl Into<CommandArg>) -> Self {
-        table_drop::new(table_name).with_parent(self)
+    /// - [changes](Self::changes)
+    /// - [get_all](Self::get_all)
+    pub fn mirror<K, T>(&self, args: impl run::RunArg) -> Result<mirror::ChangesMirror<K, T>>
+    where
+        K: Eq + Hash + DeserializeOwned,
+        T: DeserializeOwned,
+    {
+        mirror::new(self, args)
     }
 
-    /// List all table names in a database.
+    /// Stream the primary keys of a table, without the rest of the documents.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// db.table_list() → response
+    /// table.primary_keys(&session) → stream
+    /// table.primary_keys(connection) → stream
+    /// table.primary_keys(args!(&session, options)) → stream
+    /// table.primary_keys(args!(connection, options)) → stream
     /// ```
     ///
     /// Where:
-    /// - response: Vec<String>
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    ///
+    /// # Description
+    ///
+    /// The server reads the name of the primary key from the table
+    /// [info](Self::info) and maps each document to its key,
+    /// so only the keys are sent back. The keys come in batches of
+    /// 1000 rows unless `max_batch_rows` is set in the options,
+    /// and the stream yields them one by one.
     ///
     /// ## Examples
     ///
-    /// List all tables of the ‘test’ database.
+    /// Count the users whose key starts with `a`.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use futures::TryStreamExt;
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<String> = r.db("test")
-    ///         .table_list()
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let keys: Vec<String> = r.table("users")
+    ///         .primary_keys(&conn)
+    ///         .try_filter(|key: &String| futures::future::ready(key.starts_with('a')))
+    ///         .try_collect()
+    ///         .await?;
+    ///
+    ///     println!("{} users", keys.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Read the keys in batches of 100 rows.
+    ///
+    /// ```
+    /// use futures::TryStreamExt;
+    /// use neor::arguments::RunOption;
+    /// use neor::{args, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let options = RunOption::default().max_batch_rows(100);
+    ///     let mut keys = Box::pin(r.table("users").primary_keys::<String>(args!(&conn, options)));
+    ///
+    ///     while let Some(key) = keys.try_next().await? {
+    ///         println!("{}", key);
+    ///     }
     ///
-    ///     assert!(response.len() > 0);
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [table_create](Self::table_create)
-    /// - [table_drop](Self::table_drop)
-    pub fn table_list(&self) -> Self {
-        table_list::new().with_parent(self)
+    /// - [pluck](Self::pluck)
+    /// - [map](Self::map)
+    pub fn primary_keys<K>(&self, args: impl run::RunArg) -> impl Stream<Item = Result<K>>
+    where
+        K: Unpin + DeserializeOwned,
+    {
+        primary_keys::new(self, args)
     }
 
-    /// Return all documents in a table.
+    /// Run the query and read its results document by document.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// db.table(name) → table
-    /// db.table(args!(name, options)) → table
-    /// r.table(name) → table
-    /// r.table(args!(name, options)) → table
+    /// query.cursor(&session) → cursor
+    /// query.cursor(connection) → cursor
+    /// query.cursor(args!(&session, options)) → cursor
+    /// query.cursor(args!(connection, options)) → cursor
     /// ```
     ///
     /// Where:
-    /// - name: `impl Into<String>` | [Command](crate::Command)
-    /// - options: [TableOption](crate::arguments::TableOption)
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    /// - cursor: [Cursor](crate::cmd::cursor::Cursor)
     ///
     /// # Description
     ///
-    /// Other commands may be chained after `table` to return a subset of documents
-    /// (such as [get](crate::Command::get) and [filter](crate::Command::filter))
-    /// or perform further processing.
+    /// The cursor is a stream of the documents, which fetches the next
+    /// batch from the server once the current one is consumed.
+    /// `fold_stream`, `count` and `sum_field` aggregate the documents
+    /// on the client in constant memory, for the cases where a server side
+    /// [reduce](Self::reduce) is not possible, e.g. over a changefeed.
     ///
     /// ## Examples
     ///
-    /// Return all documents in the table ‘simbad’ of the default database.
+    /// Sum the views of the posts.
     ///
     /// ```
     /// use neor::{r, Result};
+    /// use serde_json::Value;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("simbad").run(&conn).await?;
+    ///     let views = r.table("posts")
+    ///         .cursor::<Value>(&conn)?
+    ///         .sum_field("views")
+    ///         .await?;
+    ///
+    ///     assert!(views >= 0.);
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// ## Examples
     ///
-    /// Return all documents in the table ‘simbad’ of the database ‘heroes’.
+    /// Find the longest title of the posts.
     ///
     /// ```
     /// use neor::{r, Result};
+    /// use serde::Deserialize;
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.db("heroes").table("simbad").run(&conn).await?;
-    ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
+    /// #[derive(Deserialize)]
+    /// struct Post {
+    ///     title: String,
     /// }
-    /// ```
-    ///
-    /// ## Examples
-    ///
-    /// Allow potentially out-of-date data in exchange for faster reads.
-    ///
-    /// ```
-    /// use neor::arguments::{ReadMode, TableOption};
-    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let table_option = TableOption::default().read_mode(ReadMode::Outdated);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.db("heroes").table(args!("simbad", table_option)).run(&conn).await?;
+    ///     let longest = r.table("posts")
+    ///         .cursor::<Post>(&conn)?
+    ///         .fold_stream(String::new(), |longest, post| {
+    ///             if post.title.len() > longest.len() {
+    ///                 post.title
+    ///             } else {
+    ///                 longest
+    ///             }
+    ///         })
+    ///         .await?;
+    ///
+    ///     println!("{}", longest);
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [filter](Self::filter)
-    /// - [get](Self::get)
-    pub fn table(&self, args: impl table::TableArg) -> Self {
-        table::new(args).with_parent(self)
+    /// - [run](Self::run)
+    /// - [build_query](Self::build_query)
+    pub fn cursor<T>(&self, args: impl run::RunArg) -> Result<cursor::Cursor<T>> {
+        cursor::new(self, args)
     }
 
-    /// Create a new secondary index on a table.
+    /// Read a table page by page in the order of a secondary index.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.index_create(index_name) → response
-    /// table.index_create(args!(index_create, func)) → response
-    /// table.index_create(args!(index_create, options)) → response
-    /// table.index_create(args!(index_create, func, options)) → response
+    /// table.paginate(index, page_size) → paginator
     /// ```
     ///
     /// Where:
-    /// - index_name: `impl Into<String>` | [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - options: [IndexCreateOption](crate::arguments::IndexCreateOption)
-    /// - response: [IndexResponse](crate::types::IndexResponse)
+    /// - index: `impl Into<String>`
+    /// - page_size: usize
+    /// - paginator: [Paginator](crate::cmd::paginate::Paginator)
     ///
     /// # Description
     ///
-    /// Secondary indexes improve the speed of many read queries at the slight
-    /// cost of increased storage space and decreased write performance.
-    /// For more information about secondary indexes,  read the article
-    /// “[Using secondary indexes in RethinkDB](https://rethinkdb.com/docs/secondary-indexes/python/).”
+    /// Each page is read with `between` from the key of the last document
+    /// of the previous page, the cursor, then `order_by` on the index and
+    /// `limit`, so reading a page does not depend on how deep it is.
+    /// The index keys must be unique, otherwise the documents sharing the
+    /// key of a cursor are skipped: use the primary key or a compound index
+    /// ending with it (see [key_fields](crate::cmd::paginate::Paginator::key_fields)).
     ///
-    /// RethinkDB supports different types of secondary indexes:
-    /// - **Simple indexes** based on the value of a single field.
-    /// - **Compound indexes** based on multiple fields.
-    /// - **Multi indexes** based on arrays of values.
-    /// - **Geospatial indexes** based on indexes of geometry objects,
-    /// created when the geo optional argument is true.
-    /// - Indexes based on **arbitrary expressions**.
+    /// ## Examples
     ///
-    /// The `index_function` can be an anonymous function or a binary
-    /// representation obtained from the `function` field of [index_status](Self::index_status).
-    /// The function must be deterministic, and so cannot use a subquery or the `r.js` command.
+    /// Read the users by id, 50 at a time.
     ///
-    /// If successful, `create_index` will return an object of the form `{"created": 1}`.
-    /// If an index by that name already exists on the table, a `ReqlRuntimeError` will be thrown.
+    /// ```
+    /// use neor::{r, Result};
+    /// use serde_json::Value;
     ///
-    /// ```text
-    /// that an index may not be immediately available after creation.
-    /// If your application needs to use indexes immediately after creation,
-    /// use the index_wait command to ensure the indexes are ready before use.
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let users = r.table("users").paginate("id", 50);
+    ///     let mut cursor = None;
+    ///
+    ///     loop {
+    ///         let page = users.page::<Value>(&conn, cursor.as_ref()).await?;
+    ///         println!("{} users", page.items.len());
+    ///
+    ///         match page.next_cursor {
+    ///             Some(next_cursor) => cursor = Some(next_cursor),
+    ///             None => break,
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
     /// ```
     ///
     /// ## Examples
     ///
-    /// Create a simple index based on the field `post_id`.
+    /// Read the latest posts first, with a compound index on
+    /// `[created_at, id]`.
     ///
     /// ```
-    /// use neor::types::IndexResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
+    /// use serde_json::Value;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: IndexResponse = r.table("comments")
-    ///         .index_create("post_id")
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let page = r.table("posts")
+    ///         .paginate("created_at_id", 20)
+    ///         .key_fields(["created_at", "id"])
+    ///         .desc()
+    ///         .page::<Value>(&conn, None)
+    ///         .await?;
+    ///
+    ///     assert!(page.items.len() <= 20);
     ///
-    ///     assert!(response.created > Some(0));
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [between](Self::between)
+    /// - [order_by](Self::order_by)
+    /// - [limit](Self::limit)
+    pub fn paginate(&self, index: impl Into<String>, page_size: usize) -> paginate::Paginator {
+        paginate::new(self, index.into(), page_size)
+    }
+
+    /// Read a table in ranges of an index, in parallel.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// table.read_partitioned(partitions, index) → partitioned_read
+    /// ```
+    ///
+    /// Where:
+    /// - partitions: usize
+    /// - index: `impl Into<String>`
+    /// - partitioned_read: [PartitionedRead](crate::cmd::read_partitioned::PartitionedRead)
+    ///
+    /// # Description
+    ///
+    /// The key space of the index is split in ranges holding about the
+    /// same number of documents, from the keys of a sample of the table,
+    /// and each range is read with `between` as an independent stream,
+    /// e.g. to export a table on all the cores of the client.
+    /// Documents missing a field of the key are not read
+    /// (see [key_fields](crate::cmd::read_partitioned::PartitionedRead::key_fields)).
+    ///
     /// ## Examples
     ///
-    /// Create a simple index based on the nested field `author > name`.
+    /// Count the posts on 4 tasks.
     ///
     /// ```
-    /// use neor::types::IndexResponse;
-    /// use neor::{args, func, r, Converter, Result};
+    /// use futures::TryStreamExt;
+    /// use neor::{r, Result};
+    /// use serde_json::Value;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let session = r.connection().connect().await?;
+    ///     let streams = r.table("posts")
+    ///         .read_partitioned(4, "id")
+    ///         .streams::<Value>(&session)
+    ///         .await?;
+    ///     let tasks: Vec<_> = streams
+    ///         .into_iter()
+    ///         .map(|stream| tokio::spawn(stream.try_fold(0, |count, _| async move { Ok(count + 1) })))
+    ///         .collect();
+    ///     let mut count = 0;
+    ///
+    ///     for task in tasks {
+    ///         count += task.await.unwrap()?;
+    ///     }
+    ///
+    ///     println!("{} posts", count);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [between](Self::between)
+    /// - [sample](Self::sample)
+    /// - [paginate](Self::paginate)
+    pub fn read_partitioned(
+        &self,
+        partitions: usize,
+        index: impl Into<String>,
+    ) -> read_partitioned::PartitionedRead {
+        read_partitioned::new(self, partitions, index.into())
+    }
+
+    /// Create a table.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// db.table_create(table_name) → response
+    /// db.table_create(args!(table_name, options)) → response
+    /// ```
+    ///
+    /// Where:
+    /// - table_name: `impl Into<String>` | [Command](crate::Command)
+    /// - options: [TableCreateOption](crate::arguments::TableCreateOption)
+    /// - response: [DbResponse](crate::types::DbResponse)
+    ///
+    /// # Description
+    ///
+    /// A RethinkDB table is a collection of JSON documents.
+    ///
+    /// If a table with the same name already exists,
+    /// the command throws `ReqlOpFailedError`.
+    ///
+    /// ```text
+    /// Note: Only alphanumeric characters and underscores are valid for the table name.
+    ///
+    /// Invoking table_create without specifying a database using db creates a table in
+    /// the database specified in connect, or test if no database was specified.
+    /// ```
+    ///
+    /// The [data type](https://rethinkdb.com/docs/data-types/) of a primary key is usually a string
+    /// (like a UUID) or a number, but it can also be a time, binary object, boolean or an array.
+    /// Data types can be mixed in the primary key field, but all values must be unique. Using an array
+    /// as a primary key causes the primary key to behave like a compound index; read the documentation on
+    /// [compound secondary indexes](https://rethinkdb.com/docs/secondary-indexes/python/#compound-indexes)
+    /// for more information, as it applies to primary keys as well.
+    /// (Note that the primary index still only covers a single field,
+    /// while compound secondary indexes can cover multiple fields in a single index.)
+    /// Primary keys cannot be objects.
+    ///
+    /// Tables will be available for writing when the command returns.
+    ///
+    /// ## Examples
+    ///
+    /// Create a table named ‘simbad’ with the default settings.
+    ///
+    /// ```
+    /// use neor::types::DbResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: IndexResponse = r.table("comments")
-    ///         .index_create(args!("author_name", func!(|comment| comment.g("author").g("name"))))
+    ///     let response: DbResponse = r.db("test")
+    ///         .table_create("simbad")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.created > Some(0));
+    ///     assert!(response.tables_created > Some(0));
     ///     
     ///     Ok(())
     /// }
@@ -690,119 +889,122 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Create a geospatial index based on the field `location`.
+    /// Create a table named ‘simbad’ using the field ‘name’ as primary key.
     ///
     /// ```
-    /// use neor::arguments::IndexCreateOption;
-    /// use neor::types::IndexResponse;
+    /// use neor::arguments::TableCreateOption;
+    /// use neor::types::DbResponse;
     /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let index_create_option = IndexCreateOption::default().geo(true);
+    ///     let table_create_option = TableCreateOption::default().primary_key("name");
     ///     let conn = r.connection().connect().await?;
-    ///     let response: IndexResponse = r.table("places")
-    ///         .index_create(args!("location", index_create_option))
+    ///     let response: DbResponse = r.db("test")
+    ///         .table_create(args!("simbad", table_create_option))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.created > Some(0));
+    ///     assert!(response.tables_created > Some(0));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// A geospatial index field should contain only geometry objects.
-    /// It will work with geometry ReQL terms
-    /// ([get_intersecting](Self::get_intersecting) and [get_nearest](Self::get_nearest))
-    /// as well as index-specific terms  ([index_status](Self::index_status),
-    /// [index_wait](Self::index_wait), [index_drop](Self::index_drop) and [index_list](Self::index_list)).
-    /// Using terms that rely on non-geometric ordering such as [get_all](Self::get_all),
-    /// [order_by](Self::order_by) and [between](Self::between) will result in an error.
-    ///
     /// ## Examples
     ///
-    /// Create a compound index based on the fields `post_id` and `date`.
+    /// Create a table set up for two shards and three replicas per shard.
+    /// This requires three available servers.
     ///
     /// ```
-    /// use neor::arguments::IndexCreateOption;
-    /// use neor::types::IndexResponse;
+    /// use neor::arguments::{Replicas, TableCreateOption};
+    /// use neor::types::DbResponse;
     /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let index_create_option = IndexCreateOption::default().geo(true);
+    ///     let table_create_option = TableCreateOption::default()
+    ///         .shards(2)
+    ///         .replicas(Replicas::Int(3));
     ///     let conn = r.connection().connect().await?;
-    ///     let response: IndexResponse = r.table("comments")
-    ///         .index_create(args!("post_and_date", index_create_option))
+    ///     let response: DbResponse = r.db("test")
+    ///         .table_create(args!("simbad", table_create_option))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.created > Some(0));
+    ///     assert!(response.tables_created > Some(0));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// Read [Sharding and replication](https://rethinkdb.com/docs/sharding-and-replication/)
+    /// for a complete discussion of the subject, including advanced topics.
+    ///
     /// # Related commands
-    /// - [index_wait](Self::index_wait)
-    /// - [index_status](Self::index_status)
-    /// - [index_list](Self::index_list)
-    /// - [index_drop](Self::index_drop)
-    pub fn index_create(&self, args: impl index_create::IndexCreateArg) -> Self {
-        index_create::new(args).with_parent(self)
+    /// - [table_drop](Self::table_drop)
+    /// - [table_list](Self::table_list)
+    pub fn table_create(&self, args: impl table_create::TableCreateArg) -> Self {
+        table_create::new(args).with_parent(self)
     }
 
-    /// Delete a previously created secondary index of this table.
+    /// Drop a table.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.index_drop(index_name) → response
+    /// db.table_drop(table_name) → response
     /// ```
     ///
     /// Where:
-    /// - index_name: `impl Into<String>` | [Command](crate::Command)
-    /// - response: [IndexResponse](crate::types::IndexResponse)
+    /// - table_name: `impl Into<String>` | [Command](crate::Command)
+    /// - response: [DbResponse](crate::types::DbResponse)
+    ///
+    /// # Description
+    ///
+    /// The table and all its data will be deleted.
+    ///
+    /// If the given table does not exist in the database,
+    /// the command throws `ReqlRuntimeError`.
     ///
     /// ## Examples
     ///
-    /// Drop a secondary index named ‘code_name’.
+    /// Drop a table named ‘simbad’.
     ///
     /// ```
-    /// use neor::types::IndexResponse;
+    /// use neor::types::DbResponse;
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: IndexResponse = r.table("comments")
-    ///         .index_drop("code_name")
+    ///     let response: DbResponse = r.db("test")
+    ///         .table_drop("simbad")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.dropped > Some(0));
+    ///     assert!(response.tables_dropped > Some(0));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [index_create](Self::index_create)
-    /// - [index_list](Self::index_list)
-    pub fn index_drop(&self, index_name: impl Into<CommandArg>) -> Self {
-        index_drop::new(index_name).with_parent(self)
+    /// - [table_create](Self::table_create)
+    /// - [table_list](Self::table_list)
+    pub fn table_drop(&self, table_name: impl Into<CommandArg>) -> Self {
+        table_drop::new(table_name).with_parent(self)
     }
 
-    /// List all the secondary indexes of this table.
+    /// List all table names in a database.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.index_list() → response
+    /// db.table_list() → response
     /// ```
     ///
     /// Where:
@@ -810,15 +1012,15 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// List the available secondary indexes for this table.
+    /// List all tables of the ‘test’ database.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<String> = r.table("comments")
-    ///         .index_list()
+    ///     let response: Vec<String> = r.db("test")
+    ///         .table_list()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
@@ -831,124 +1033,156 @@ impl<'a> Command {
     /// ```
     ///
     /// # Related commands
-    /// - [index_create](Self::index_create)
-    /// - [index_drop](Self::index_drop)
-    pub fn index_list(&self) -> Self {
-        index_list::new().with_parent(self)
+    /// - [table_create](Self::table_create)
+    /// - [table_drop](Self::table_drop)
+    pub fn table_list(&self) -> Self {
+        table_list::new().with_parent(self)
     }
 
-    /// Rename an existing secondary index on a table.
+    /// Return all documents in a table.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.index_rename(args!(old_index_name, new_index_name)) → response
-    /// table.index_rename(args!(old_index_name, new_index_name, options)) → response
+    /// db.table(name) → table
+    /// db.table(args!(name, options)) → table
+    /// r.table(name) → table
+    /// r.table(args!(name, options)) → table
     /// ```
     ///
     /// Where:
-    /// - old_index_name, new_index_name: `impl Into<String>` | [Command](crate::Command)
-    /// - options: [IndexRenameOption](crate::arguments::IndexRenameOption)
-    /// - response: [IndexResponse](crate::types::IndexResponse)
+    /// - name: `impl Into<String>` | [Command](crate::Command)
+    /// - options: [TableOption](crate::arguments::TableOption)
     ///
     /// # Description
     ///
-    /// If the optional argument `overwrite` is specified as `true`,
-    /// a previously existing index with the new name will be deleted
-    /// and the index will be renamed. If `overwrite` is `false` (the default)
-    /// an error will be raised if the new index name already exists.
+    /// Other commands may be chained after `table` to return a subset of documents
+    /// (such as [get](crate::Command::get) and [filter](crate::Command::filter))
+    /// or perform further processing.
     ///
-    /// The return value on success will be an object of the format `{'renamed': 1}`,
-    /// or `{'renamed': 0}` if the old and new names are the same.
+    /// ## Examples
     ///
-    /// An error will be raised if the old index name does not exist,
-    /// if the new index name is already in use and `overwrite` is `false`,
-    /// or if either the old or new index name are the same as the primary key field name.
+    /// Return all documents in the table ‘simbad’ of the default database.
+    ///
+    /// ```
+    /// use neor::{r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("simbad").run(&conn).await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Rename an index on the comments table.
+    /// Return all documents in the table ‘simbad’ of the database ‘heroes’.
     ///
     /// ```
-    /// use neor::types::IndexResponse;
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: IndexResponse = r.table("comments")
-    ///         .index_rename(args!("post_id", "message_id"))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let response = r.db("heroes").table("simbad").run(&conn).await?;
     ///
-    ///     assert!(response.renamed > Some(0));
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Allow potentially out-of-date data in exchange for faster reads.
+    ///
+    /// ```
+    /// use neor::arguments::{ReadMode, TableOption};
+    /// use neor::{args, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let table_option = TableOption::default().read_mode(ReadMode::Outdated);
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.db("heroes").table(args!("simbad", table_option)).run(&conn).await?;
+    ///
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [index_create](Self::index_create)
-    /// - [index_status](Self::index_status)
-    /// - [index_list](Self::index_list)
-    /// - [index_drop](Self::index_drop)
-    pub fn index_rename(&self, args: impl index_rename::IndexRenameArg) -> Self {
-        index_rename::new(args).with_parent(self)
+    /// - [filter](Self::filter)
+    /// - [get](Self::get)
+    pub fn table(&self, args: impl table::TableArg) -> Self {
+        table::new(args).with_parent(self)
     }
 
-    /// Get the status of the specified indexes on this table,
-    /// or the status of all indexes on this table if no indexes are specified.
+    /// Create a new secondary index on a table.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.index_status(()) → response
-    /// table.index_status(index) → response
-    /// table.index_status(args!(indexes)) → response
+    /// table.index_create(index_name) → response
+    /// table.index_create(args!(index_create, func)) → response
+    /// table.index_create(args!(index_create, options)) → response
+    /// table.index_create(args!(index_create, func, options)) → response
     /// ```
     ///
     /// Where:
-    /// - index: `&str`
-    /// - indexes: `impl IntoIterator<Item = impl Into<String>>` |
-    /// `impl IntoIterator<Item = Command>`
-    /// - response: Vec<[IndexStatusResponse](crate::types::IndexStatusResponse)>
+    /// - index_name: `impl Into<String>` | [Command](crate::Command)
+    /// - func: [Func](crate::Func) | [Binary](crate::types::Binary) | [Command](crate::Command)
+    /// - options: [IndexCreateOption](crate::arguments::IndexCreateOption)
+    /// - response: [IndexResponse](crate::types::IndexResponse)
     ///
     /// # Description
     ///
-    /// The `multi` field will be `true` or `false` depending on whether
-    /// this index was created as a multi index
-    /// (see [index_create](Self::index_create) for details).
-    /// The `outdated` field will be true if the index is outdated in
-    /// the current version of RethinkDB and needs to be rebuilt.
-    /// The `progress` field is a float between `0` and `1`,
-    /// indicating how far along the server is in constructing indexes after
-    /// the most recent change to the table that would affect them.
-    /// (`0` indicates no such indexes have been constructed; `1` indicates all of them have.)
+    /// Secondary indexes improve the speed of many read queries at the slight
+    /// cost of increased storage space and decreased write performance.
+    /// For more information about secondary indexes,  read the article
+    /// “[Using secondary indexes in RethinkDB](https://rethinkdb.com/docs/secondary-indexes/python/).”
     ///
-    /// The `function` field is a binary object containing an opaque representation
-    /// of the secondary index (including the `multi` argument if specified).
-    /// It can be passed as the second argument to [index_create](Self::index_create) to create
-    /// a new index with the same function; see `index_create` for more information.
+    /// RethinkDB supports different types of secondary indexes:
+    /// - **Simple indexes** based on the value of a single field.
+    /// - **Compound indexes** based on multiple fields.
+    /// - **Multi indexes** based on arrays of values.
+    /// - **Geospatial indexes** based on indexes of geometry objects,
+    /// created when the geo optional argument is true.
+    /// - Indexes based on **arbitrary expressions**.
+    ///
+    /// The `index_function` can be an anonymous function or a binary
+    /// representation obtained from the `function` field of [index_status](Self::index_status).
+    /// The function must be deterministic, and so cannot use a subquery or the `r.js` command.
+    ///
+    /// If successful, `create_index` will return an object of the form `{"created": 1}`.
+    /// If an index by that name already exists on the table, a `ReqlRuntimeError` will be thrown.
+    ///
+    /// ```text
+    /// that an index may not be immediately available after creation.
+    /// If your application needs to use indexes immediately after creation,
+    /// use the index_wait command to ensure the indexes are ready before use.
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Get the status of all the indexes on `test`:
+    /// Create a simple index based on the field `post_id`.
     ///
     /// ```
-    /// use neor::types::IndexStatusResponse;
+    /// use neor::types::IndexResponse;
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<IndexStatusResponse> = r.table("test")
-    ///         .index_status(())
+    ///     let response: IndexResponse = r.table("comments")
+    ///         .index_create("post_id")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.len() > 0);
+    ///     assert!(response.created > Some(0));
     ///     
     ///     Ok(())
     /// }
@@ -956,22 +1190,22 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Get the status of the `timestamp` index:
+    /// Create a simple index based on the nested field `author > name`.
     ///
     /// ```
-    /// use neor::types::IndexStatusResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::types::IndexResponse;
+    /// use neor::{args, func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<IndexStatusResponse> = r.table("test")
-    ///         .index_status("timestamp")
+    ///     let response: IndexResponse = r.table("comments")
+    ///         .index_create(args!("author_name", func!(|comment| comment.g("author").g("name"))))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.len() > 0);
+    ///     assert!(response.created > Some(0));
     ///     
     ///     Ok(())
     /// }
@@ -979,288 +1213,294 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Save the binary representation of the index:
+    /// Create a geospatial index based on the field `location`.
     ///
     /// ```
-    /// use neor::types::IndexStatusResponse;
-    /// use neor::{r, Result};
+    /// use neor::arguments::IndexCreateOption;
+    /// use neor::types::IndexResponse;
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let index_create_option = IndexCreateOption::default().geo(true);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("test")
-    ///         .index_status("timestamp")
-    ///         .nth(0)
-    ///         .g("function")
+    ///     let response: IndexResponse = r.table("places")
+    ///         .index_create(args!("location", index_create_option))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.created > Some(0));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [index_wait](Self::index_wait)
-    pub fn index_status(&self, args: impl index_status::IndexStatusArg) -> Self {
-        index_status::new(args).with_parent(self)
-    }
-
-    /// Wait for the specified indexes on this table to be ready,
-    /// or for all indexes on this table to be ready if no indexes are specified.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// table.index_wait(()) → response
-    /// table.index_wait(index) → response
-    /// table.index_status(args!(indexes)) → response
-    /// ```
-    ///
-    /// Where:
-    /// - index: `&str`
-    /// - indexes: `impl IntoIterator<Item = impl Into<String>>` |
-    /// `impl IntoIterator<Item = Command>`
-    /// - response: [IndexStatusResponse](crate::types::IndexStatusResponse)
-    ///
     /// ## Examples
     ///
-    /// Wait for all indexes on the table `test` to be ready:
+    /// Copy the `post_id` index of the `comments` table to the `archived_comments` table.
     ///
     /// ```
-    /// use neor::types::IndexStatusResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::types::{IndexResponse, IndexStatusResponse};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: IndexStatusResponse = r.table("test")
-    ///         .index_wait(())
+    ///     let status: Vec<IndexStatusResponse> = r.table("comments")
+    ///         .index_status("post_id")
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///     let response: IndexResponse = r.table("archived_comments")
+    ///         .index_create(args!("post_id", status[0].function.clone()))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.ready);
+    ///     assert!(response.created > Some(0));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// A geospatial index field should contain only geometry objects.
+    /// It will work with geometry ReQL terms
+    /// ([get_intersecting](Self::get_intersecting) and [get_nearest](Self::get_nearest))
+    /// as well as index-specific terms  ([index_status](Self::index_status),
+    /// [index_wait](Self::index_wait), [index_drop](Self::index_drop) and [index_list](Self::index_list)).
+    /// Using terms that rely on non-geometric ordering such as [get_all](Self::get_all),
+    /// [order_by](Self::order_by) and [between](Self::between) will result in an error.
+    ///
     /// ## Examples
     ///
-    /// Wait for the index `timestamp` to be ready:
+    /// Create a compound index based on the fields `post_id` and `date`.
     ///
     /// ```
-    /// use neor::types::IndexStatusResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::arguments::IndexCreateOption;
+    /// use neor::types::IndexResponse;
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let index_create_option = IndexCreateOption::default().geo(true);
     ///     let conn = r.connection().connect().await?;
-    ///     let response: IndexStatusResponse = r.table("test")
-    ///         .index_wait("timestamp")
+    ///     let response: IndexResponse = r.table("comments")
+    ///         .index_create(args!("post_and_date", index_create_option))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.ready);
-    ///     assert_eq!(response.index, "author");
+    ///     assert!(response.created > Some(0));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
+    /// - [index_wait](Self::index_wait)
     /// - [index_status](Self::index_status)
-    pub fn index_wait(&self, args: impl index_wait::IndexWaitArg) -> Self {
-        index_wait::new(args).with_parent(self)
+    /// - [index_list](Self::index_list)
+    /// - [index_drop](Self::index_drop)
+    pub fn index_create(&self, args: impl index_create::IndexCreateArg) -> Self {
+        index_create::new(args).with_parent(self)
     }
 
-    /// Sets the write hook on a table or overwrites it if one already exists.
+    /// Delete a previously created secondary index of this table.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.set_write_hook(params) → response
+    /// table.index_drop(index_name) → response
     /// ```
     ///
     /// Where:
-    /// - params: `Option::<T>::None` | [Func](crate::Func) |
-    /// [Binary](crate::types::Binary) | [Command](crate::Command)
-    /// - response: [SetWriteHookResponse](crate::types::SetWriteHookResponse)
-    ///
-    /// # Description
+    /// - index_name: `impl Into<String>` | [Command](crate::Command)
+    /// - response: [IndexResponse](crate::types::IndexResponse)
     ///
-    /// The `function` can be an anonymous function with the signature
-    /// `(context: object, old_val: object, new_val: object) -> object`
-    /// or a binary representation obtained from the `function` field
-    /// of [get_write_hook](Self::get_write_hook).
-    /// The function must be deterministic,
-    /// and so cannot use a subquery or the `r.js` command.
+    /// ## Examples
     ///
-    /// The first argument, `context`,
-    /// is a ReQL object containing the following properties:
-    /// - `primary_key`: primary key of the document being deleted, inserted, or modified
-    /// - `timestamp`: a ReQL `time` object representing the current query execution time
-    ///
-    /// Whenever a write operation on the table inserts, deletes or modifies a given document,
-    /// the write hook function will be called with the context parameter, the old value of
-    /// the document (or `null` on inserts) and the new value of the document (or `null` on deletes).
-    /// It then returns the value that should actually be inserted and/or replaced instead of `newVal`.
-    /// It can also return `r.error(...)` to abort the write.
-    ///
-    /// For simplicity, the write hook function is allowed
-    /// to return `null` exactly if and only if `newVal` is `null`.
-    /// This is just a safeguard to ensure you don’t accidentally
-    /// turn an insert/update into a deletion, or a deletion into an update.
-    ///
-    /// ## Examples
-    ///
-    /// Create a write hook
+    /// Drop a secondary index named ‘code_name’.
     ///
     /// ```
-    /// use neor::types::SetWriteHookResponse;
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::types::IndexResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: SetWriteHookResponse = r.table("comments")
-    ///         .set_write_hook(func!(|_, _, new_val| new_val))
+    ///     let response: IndexResponse = r.table("comments")
+    ///         .index_drop("code_name")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert_eq!(response.created, Some(1));
+    ///     assert!(response.dropped > Some(0));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [index_create](Self::index_create)
+    /// - [index_list](Self::index_list)
+    pub fn index_drop(&self, index_name: impl Into<CommandArg>) -> Self {
+        index_drop::new(index_name).with_parent(self)
+    }
+
+    /// List all the secondary indexes of this table.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// table.index_list() → response
+    /// ```
+    ///
+    /// Where:
+    /// - response: Vec<String>
+    ///
     /// ## Examples
     ///
-    /// Delete the write hook associated with the comments table.
+    /// List the available secondary indexes for this table.
     ///
     /// ```
-    /// use neor::types::SetWriteHookResponse;
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: SetWriteHookResponse = r.table("comments")
-    ///         .set_write_hook(Option::<u8>::None)
+    ///     let response: Vec<String> = r.table("comments")
+    ///         .index_list()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert_eq!(response.deleted, Some(1));
+    ///     assert!(response.len() > 0);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [get_write_hook](Self::get_write_hook)
-    pub fn set_write_hook(&self, args: impl Into<CommandArg>) -> Self {
-        set_write_hook::new(args).with_parent(self)
+    /// - [index_create](Self::index_create)
+    /// - [index_drop](Self::index_drop)
+    pub fn index_list(&self) -> Self {
+        index_list::new().with_parent(self)
     }
 
-    /// Gets the write hook of this table.
+    /// Rename an existing secondary index on a table.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.get_write_hook() → response
+    /// table.index_rename(args!(old_index_name, new_index_name)) → response
+    /// table.index_rename(args!(old_index_name, new_index_name, options)) → response
     /// ```
     ///
     /// Where:
-    /// - response: [GetWriteHookResponse](crate::types::GetWriteHookResponse)
+    /// - old_index_name, new_index_name: `impl Into<String>` | [Command](crate::Command)
+    /// - options: [IndexRenameOption](crate::arguments::IndexRenameOption)
+    /// - response: [IndexResponse](crate::types::IndexResponse)
     ///
     /// # Description
     ///
-    /// If a write hook exists, the result is an object of the following form:
+    /// If the optional argument `overwrite` is specified as `true`,
+    /// a previously existing index with the new name will be deleted
+    /// and the index will be renamed. If `overwrite` is `false` (the default)
+    /// an error will be raised if the new index name already exists.
     ///
-    /// ```text
-    /// {
-    ///     "function": <binary>,
-    ///     "query": "setWriteHook(function(_var1, _var2, _var3) { return ...; })",
-    /// }
-    /// ```
+    /// The return value on success will be an object of the format `{'renamed': 1}`,
+    /// or `{'renamed': 0}` if the old and new names are the same.
+    ///
+    /// An error will be raised if the old index name does not exist,
+    /// if the new index name is already in use and `overwrite` is `false`,
+    /// or if either the old or new index name are the same as the primary key field name.
     ///
     /// ## Examples
     ///
-    /// Get the write hook for the `comments` table.
+    /// Rename an index on the comments table.
     ///
     /// ```
-    /// use neor::types::GetWriteHookResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::types::IndexResponse;
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: GetWriteHookResponse = r.table("comments")
-    ///         .get_write_hook()
+    ///     let response: IndexResponse = r.table("comments")
+    ///         .index_rename(args!("post_id", "message_id"))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert_eq!(
-    ///         response.query,
-    ///         "setWriteHook(function(var1, var2, var3) { return var3; })"
-    ///     );
+    ///     assert!(response.renamed > Some(0));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [set_write_hook](Self::set_write_hook)
-    pub fn get_write_hook(&self) -> Self {
-        get_write_hook::new().with_parent(self)
+    /// - [index_create](Self::index_create)
+    /// - [index_status](Self::index_status)
+    /// - [index_list](Self::index_list)
+    /// - [index_drop](Self::index_drop)
+    pub fn index_rename(&self, args: impl index_rename::IndexRenameArg) -> Self {
+        index_rename::new(args).with_parent(self)
     }
 
-    /// Insert documents into a table.
+    /// Get the status of the specified indexes on this table,
+    /// or the status of all indexes on this table if no indexes are specified.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.insert(object) → response
-    /// table.insert(args!(object, options)) → response
+    /// table.index_status(()) → response
+    /// table.index_status(index) → response
+    /// table.index_status(args!(indexes)) → response
     /// ```
     ///
     /// Where:
-    /// - object: `impl Serialize` | [Command](crate::Command)
-    /// - options: [InsertOption](crate::arguments::InsertOption)
-    /// - response: [MutationResponse](crate::types::MutationResponse)
+    /// - index: `&str`
+    /// - indexes: `impl IntoIterator<Item = impl Into<String>>` |
+    /// `impl IntoIterator<Item = Command>`
+    /// - response: Vec<[IndexStatusResponse](crate::types::IndexStatusResponse)>
     ///
     /// # Description
     ///
-    /// Accepts a single document or an array of documents.
+    /// The `multi` field will be `true` or `false` depending on whether
+    /// this index was created as a multi index
+    /// (see [index_create](Self::index_create) for details).
+    /// The `outdated` field will be true if the index is outdated in
+    /// the current version of RethinkDB and needs to be rebuilt.
+    /// The `progress` field is a float between `0` and `1`,
+    /// indicating how far along the server is in constructing indexes after
+    /// the most recent change to the table that would affect them.
+    /// (`0` indicates no such indexes have been constructed; `1` indicates all of them have.)
+    ///
+    /// The `function` field is a binary object containing an opaque representation
+    /// of the secondary index (including the `multi` argument if specified).
+    /// It can be passed as the second argument to [index_create](Self::index_create) to create
+    /// a new index with the same function; see `index_create` for more information.
     ///
     /// ## Examples
     ///
-    /// Insert a document into the table `posts`.
+    /// Get the status of all the indexes on `test`:
     ///
     /// ```
-    /// use neor::types::MutationResponse;
+    /// use neor::types::IndexStatusResponse;
     /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .insert(json!({
-    ///             "id": 1,
-    ///             "title": "Lorem ipsum",
-    ///             "content": "Dolor sit amet",
-    ///         }))
+    ///     let response: Vec<IndexStatusResponse> = r.table("test")
+    ///         .index_status(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.inserted == 1);
+    ///     assert!(response.len() > 0);
     ///     
     ///     Ok(())
     /// }
@@ -1268,27 +1508,22 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Insert a document without a defined primary key into
-    /// the table `posts` where the primary key is `id`.
+    /// Get the status of the `timestamp` index:
     ///
     /// ```
-    /// use neor::types::MutationResponse;
+    /// use neor::types::IndexStatusResponse;
     /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .insert(json!({
-    ///             "title": "Lorem ipsum",
-    ///             "content": "Dolor sit amet",
-    ///         }))
+    ///     let response: Vec<IndexStatusResponse> = r.table("test")
+    ///         .index_status("timestamp")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.inserted == 1);
+    ///     assert!(response.len() > 0);
     ///     
     ///     Ok(())
     /// }
@@ -1296,56 +1531,79 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Insert multiple documents into the table `users`.
+    /// Save the binary representation of the index:
     ///
     /// ```
-    /// use neor::types::MutationResponse;
-    /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
+    /// use neor::types::IndexStatusResponse;
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("users")
-    ///         .insert(json!([
-    ///             {"id": "malika", "email": "malika@rethinkdb.com"},
-    ///             {"id": "moussa", "email": "moussa@rethinkdb.com"}
-    ///         ]))
+    ///     let response = r.table("test")
+    ///         .index_status("timestamp")
+    ///         .nth(0)
+    ///         .g("function")
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response.inserted == 1);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [index_wait](Self::index_wait)
+    pub fn index_status(&self, args: impl index_status::IndexStatusArg) -> Self {
+        index_status::new(args).with_parent(self)
+    }
+
+    /// Wait for the specified indexes on this table to be ready,
+    /// or for all indexes on this table to be ready if no indexes are specified.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// table.index_wait(()) → response
+    /// table.index_wait(index) → response
+    /// table.index_wait(args!(indexes)) → response
+    /// table.index_wait(options) → response
+    /// ```
+    ///
+    /// Where:
+    /// - index: `&str`
+    /// - indexes: `impl IntoIterator<Item = impl Into<String>>` |
+    /// `impl IntoIterator<Item = Command>`
+    /// - options: [WaitOption](crate::arguments::WaitOption)
+    /// - response: [IndexStatusResponse](crate::types::IndexStatusResponse)
+    ///
+    /// # Description
+    ///
+    /// Only the `timeout` of the options is used: the server has no
+    /// timeout for this command, so the driver stops the query when it
+    /// expires and `run` returns a
+    /// [Timeout](crate::err::ReqlDriverError::Timeout) error.
+    /// To wait for given indexes, set the
+    /// [timeout](crate::arguments::RunOption::timeout) of `run` instead.
+    ///
     /// ## Examples
     ///
-    /// Insert a document into the table `users`,
-    /// replacing the document if the document already exists.
+    /// Wait for all indexes on the table `test` to be ready:
     ///
     /// ```
-    /// use neor::arguments::{Conflict, InsertOption};
-    /// use neor::types::MutationResponse;
-    /// use neor::{args, r, Converter, Result};
-    /// use serde_json::json;
+    /// use neor::types::IndexStatusResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let insert_option = InsertOption::default().conflict(Conflict::Replace);
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("users")
-    ///         .insert(args!(
-    ///             json!({"id": "malika", "email": "malika@rethinkdb.com"}),
-    ///             insert_option
-    ///         ))
+    ///     let response: IndexStatusResponse = r.table("test")
+    ///         .index_wait(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.inserted == 1);
+    ///     assert!(response.ready);
     ///     
     ///     Ok(())
     /// }
@@ -1353,23 +1611,23 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Copy the documents from `posts` to `posts_backup`.
+    /// Wait for the index `timestamp` to be ready:
     ///
     /// ```
-    /// use neor::types::MutationResponse;
+    /// use neor::types::IndexStatusResponse;
     /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("posts_backup")
-    ///         .insert(r.table("posts"))
+    ///     let response: IndexStatusResponse = r.table("test")
+    ///         .index_wait("timestamp")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.inserted == 1);
+    ///     assert!(response.ready);
+    ///     assert_eq!(response.index, "author");
     ///     
     ///     Ok(())
     /// }
@@ -1377,82 +1635,95 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Get back a copy of the inserted document (with its generated primary key).
+    /// Give up if the index `timestamp` is not ready after 30 seconds.
     ///
     /// ```
-    /// use neor::arguments::{InsertOption, ReturnChanges};
-    /// use neor::types::MutationResponse;
-    /// use neor::{args, r, Converter, Result};
-    /// use serde_json::json;
+    /// use std::time::Duration;
+    ///
+    /// use neor::arguments::RunOption;
+    /// use neor::err::{ReqlDriverError, ReqlError};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let insert_option = InsertOption::default().return_changes(ReturnChanges::Bool(true));
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .insert(args!(
-    ///             json!({"title": "Lorem ipsum", "content": "Dolor sit amet"}),
-    ///             insert_option
-    ///         ))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let opts = RunOption::default().timeout(Duration::from_secs(30));
+    ///     let query = r.table("test").index_wait("timestamp");
+    ///
+    ///     match query.run(args!(&conn, opts)).await {
+    ///         Err(ReqlError::Driver(ReqlDriverError::Timeout(_))) => {
+    ///             eprintln!("the index `timestamp` is still building");
+    ///         }
+    ///         response => {
+    ///             response?;
+    ///         }
+    ///     }
     ///
-    ///     assert!(response.inserted == 1);
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [update](Self::update)
-    /// - [replace](Self::replace)
-    /// - [delete](Self::delete)
-    pub fn insert(&self, args: impl insert::InsertArg) -> Self {
-        insert::new(args).with_parent(self)
+    /// - [index_status](Self::index_status)
+    pub fn index_wait(&self, args: impl index_wait::IndexWaitArg) -> Self {
+        index_wait::new(args).with_parent(self)
     }
 
-    /// Update JSON documents in a table.
+    /// Sets the write hook on a table or overwrites it if one already exists.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.replace(object) → response
-    /// table.replace(func) → response
-    /// table.replace(args!(object, options)) → response
-    /// table.replace(args!(func, options)) → response
+    /// table.set_write_hook(params) → response
     /// ```
     ///
     /// Where:
-    /// - object: `impl Serialize` | [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - options: [ReplaceOption](crate::arguments::ReplaceOption)
-    /// - response: [MutationResponse](crate::types::MutationResponse)
+    /// - params: `Option::<T>::None` | [Func](crate::Func) |
+    /// [Binary](crate::types::Binary) | [Command](crate::Command)
+    /// - response: [SetWriteHookResponse](crate::types::SetWriteHookResponse)
     ///
     /// # Description
     ///
-    /// Accepts a JSON document, a ReQL expression, or a combination of the two.
+    /// The `function` can be an anonymous function with the signature
+    /// `(context: object, old_val: object, new_val: object) -> object`
+    /// or a binary representation obtained from the `function` field
+    /// of [get_write_hook](Self::get_write_hook).
+    /// The function must be deterministic,
+    /// and so cannot use a subquery or the `r.js` command.
+    ///
+    /// The first argument, `context`,
+    /// is a ReQL object containing the following properties:
+    /// - `primary_key`: primary key of the document being deleted, inserted, or modified
+    /// - `timestamp`: a ReQL `time` object representing the current query execution time
+    ///
+    /// Whenever a write operation on the table inserts, deletes or modifies a given document,
+    /// the write hook function will be called with the context parameter, the old value of
+    /// the document (or `null` on inserts) and the new value of the document (or `null` on deletes).
+    /// It then returns the value that should actually be inserted and/or replaced instead of `newVal`.
+    /// It can also return `r.error(...)` to abort the write.
+    ///
+    /// For simplicity, the write hook function is allowed
+    /// to return `null` exactly if and only if `newVal` is `null`.
+    /// This is just a safeguard to ensure you don’t accidentally
+    /// turn an insert/update into a deletion, or a deletion into an update.
     ///
     /// ## Examples
     ///
-    /// Update the status of the post with `id` of `1` to `published`.
+    /// Create a write hook
     ///
     /// ```
-    /// use neor::types::MutationResponse;
-    /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
+    /// use neor::types::SetWriteHookResponse;
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .get(1)
-    ///         .update(json!({"status": "published"}))
+    ///     let response: SetWriteHookResponse = r.table("comments")
+    ///         .set_write_hook(func!(|_, _, new_val| new_val))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 1);
+    ///     assert_eq!(response.created, Some(1));
     ///     
     ///     Ok(())
     /// }
@@ -1460,82 +1731,130 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Update the status of all posts to `published`.
+    /// Delete the write hook associated with the comments table.
     ///
     /// ```
-    /// use neor::types::MutationResponse;
+    /// use neor::types::SetWriteHookResponse;
     /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .update(json!({"status": "published"}))
+    ///     let response: SetWriteHookResponse = r.table("comments")
+    ///         .set_write_hook(Option::<u8>::None)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 100);
+    ///     assert_eq!(response.deleted, Some(1));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [get_write_hook](Self::get_write_hook)
+    pub fn set_write_hook(&self, args: impl Into<CommandArg>) -> Self {
+        set_write_hook::new(args).with_parent(self)
+    }
+
+    /// Gets the write hook of this table.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// table.get_write_hook() → response
+    /// ```
+    ///
+    /// Where:
+    /// - response: [GetWriteHookResponse](crate::types::GetWriteHookResponse)
+    ///
+    /// # Description
+    ///
+    /// If a write hook exists, the result is an object of the following form:
+    ///
+    /// ```text
+    /// {
+    ///     "function": <binary>,
+    ///     "query": "setWriteHook(function(_var1, _var2, _var3) { return ...; })",
+    /// }
+    /// ```
+    ///
     /// ## Examples
     ///
-    /// Update the status of all the posts written by Moussa.
+    /// Get the write hook for the `comments` table.
     ///
     /// ```
-    /// use neor::types::MutationResponse;
+    /// use neor::types::GetWriteHookResponse;
     /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .filter(json!({"author": "Moussa"}))
-    ///         .update(json!({"status": "published"}))
+    ///     let response: GetWriteHookResponse = r.table("comments")
+    ///         .get_write_hook()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 5);
+    ///     assert_eq!(
+    ///         response.query,
+    ///         "setWriteHook(function(var1, var2, var3) { return var3; })"
+    ///     );
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [set_write_hook](Self::set_write_hook)
+    pub fn get_write_hook(&self) -> Self {
+        get_write_hook::new().with_parent(self)
+    }
+
+    /// Insert documents into a table.
+    ///
+    /// # Command syntax
+    ///
     /// ```text
-    /// Note that `filter`, `get_all` and similar operations
-    /// do **not** execute in an atomic fashion with `update`.
-    /// Read [Consistency guarantees](https://rethinkdb.com/docs/consistency) for more details.
-    /// Also, see the example for conditional updates
-    /// below for a solution using `branch` in an `update` clause.
+    /// table.insert(object) → response
+    /// table.insert(args!(object, options)) → response
+    /// table.insert(args!(object, options, func)) → response
     /// ```
     ///
+    /// Where:
+    /// - object: `impl Serialize` | [Command](crate::Command)
+    /// - options: [InsertOption](crate::arguments::InsertOption)
+    /// - func: [Func](crate::Func), resolving the conflicts in place of `InsertOption::conflict`
+    /// - response: [MutationResponse](crate::types::MutationResponse)
+    ///
+    /// # Description
+    ///
+    /// Accepts a single document or an array of documents.
+    ///
     /// ## Examples
     ///
-    /// Increment the field `view` of the post with `id` of `1`.
-    /// This query will throw an error if the field `views` doesn’t exist.
+    /// Insert a document into the table `posts`.
     ///
     /// ```
     /// use neor::types::MutationResponse;
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response: MutationResponse = r.table("posts")
-    ///         .get(1)
-    ///         .update(func!(|post| post.g("views") + r.expr(1)))
+    ///         .insert(json!({
+    ///             "id": 1,
+    ///             "title": "Lorem ipsum",
+    ///             "content": "Dolor sit amet",
+    ///         }))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 1);
+    ///     assert!(response.inserted == 1);
     ///     
     ///     Ok(())
     /// }
@@ -1543,27 +1862,27 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Increment the field view of the post with id of 1.
-    /// If the field views does not exist, it will be set to 0.
+    /// Insert a document without a defined primary key into
+    /// the table `posts` where the primary key is `id`.
     ///
     /// ```
-    /// use std::ops::Add;
-    ///
     /// use neor::types::MutationResponse;
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response: MutationResponse = r.table("posts")
-    ///         .get(1)
-    ///         .update(func!(|post| post.g("views").add(r.expr(1)).default(0)))
+    ///         .insert(json!({
+    ///             "title": "Lorem ipsum",
+    ///             "content": "Dolor sit amet",
+    ///         }))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 1);
+    ///     assert!(response.inserted == 1);
     ///     
     ///     Ok(())
     /// }
@@ -1571,36 +1890,26 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Perform a conditional update.
-    ///
-    /// If the post has more than 100 views,
-    /// set the `type` of a post to `hot`,
-    /// else set it to `normal`.
+    /// Insert multiple documents into the table `users`.
     ///
     /// ```
-    /// use std::ops::Add;
-    ///
     /// use neor::types::MutationResponse;
-    /// use neor::{args, func, r, Converter, Result};
+    /// use neor::{r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .get(1)
-    ///         .update(func!(|post| r.branch(
-    ///             post.g("views").gt(100),
-    ///             args!(
-    ///                 json!({"type": "hot"}),
-    ///                 json!({"type": "normal"})
-    ///             )
-    ///         )))
+    ///     let response: MutationResponse = r.table("users")
+    ///         .insert(json!([
+    ///             {"id": "malika", "email": "malika@rethinkdb.com"},
+    ///             {"id": "moussa", "email": "moussa@rethinkdb.com"}
+    ///         ]))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 1);
+    ///     assert!(response.inserted == 1);
     ///     
     ///     Ok(())
     /// }
@@ -1608,80 +1917,82 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Update the field `num_comments` with the result of a sub-query.
-    /// Because this update is not atomic, you must pass the `non_atomic` flag.
+    /// Insert a document into the table `users`,
+    /// replacing the document if the document already exists.
     ///
     /// ```
-    /// use std::collections::HashMap;
-    ///
-    /// use neor::arguments::UpdateOption;
+    /// use neor::arguments::{Conflict, InsertOption};
     /// use neor::types::MutationResponse;
     /// use neor::{args, r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let update_option = UpdateOption::default().non_atomic(true);
+    ///     let insert_option = InsertOption::default().conflict(Conflict::Replace);
     ///     let conn = r.connection().connect().await?;
-    ///     let mut comments_filtered = HashMap::new();
-    ///     comments_filtered.insert(
-    ///         "num_comments",
-    ///         r.table("comments")
-    ///             .filter(json!({"id_post": 1}))
-    ///             .count(())
-    ///     );
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .get(1)
-    ///         .update(args!(r.hash_map(comments_filtered), update_option))
+    ///     let response: MutationResponse = r.table("users")
+    ///         .insert(args!(
+    ///             json!({"id": "malika", "email": "malika@rethinkdb.com"}),
+    ///             insert_option
+    ///         ))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 1);
+    ///     assert!(response.inserted == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// If you forget to specify the `non_atomic` flag,
-    /// you will get a `ReqlRuntimeError`:
-    ///
-    /// ```text
-    /// ReqlRuntimeError: Could not prove function deterministic.
-    /// Maybe you want to use the non_atomic flag?
-    /// ```
-    ///
     /// ## Examples
     ///
-    /// Update the field `num_comments` with a random value between 0 and 100.
-    /// This update cannot be proven deterministic because of `r.js` (and in fact is not),
-    /// so you must pass the `non_atomic` flag.
+    /// Insert a page view, adding its views to the existing document.
+    /// The function receives the primary key, the old document and the new document.
     ///
     /// ```
-    /// use std::collections::HashMap;
-    ///
-    /// use neor::arguments::UpdateOption;
-    /// use neor::types::MutationResponse;
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::arguments::InsertOption;
+    /// use neor::{args, func, r, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let update_option = UpdateOption::default().non_atomic(true);
     ///     let conn = r.connection().connect().await?;
-    ///     let mut comments_filtered = HashMap::new();
-    ///     comments_filtered.insert(
-    ///         "num_comments",
-    ///         r.js("Math.floor(Math.random()*100)")
-    ///     );
-    ///     let response: MutationResponse = r.table("posts")
-    ///         .get(1)
-    ///         .update(args!(r.hash_map(comments_filtered), update_option))
+    ///     let conflict = func!(|_id, old_doc, new_doc| {
+    ///         old_doc.merge(r.hash_map([("views", old_doc.g("views") + new_doc.g("views"))]))
+    ///     });
+    ///
+    ///     r.table("pages")
+    ///         .insert(args!(
+    ///             json!({"id": "home", "views": 1}),
+    ///             InsertOption::default(),
+    ///             conflict
+    ///         ))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Copy the documents from `posts` to `posts_backup`.
+    ///
+    /// ```
+    /// use neor::types::MutationResponse;
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: MutationResponse = r.table("posts_backup")
+    ///         .insert(r.table("posts"))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 1);
+    ///     assert!(response.inserted == 1);
     ///     
     ///     Ok(())
     /// }
@@ -1689,40 +2000,111 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Update the status of the post with `id` of `1` using soft durability.
+    /// Get back a copy of the inserted document (with its generated primary key).
     ///
     /// ```
-    /// use neor::arguments::{Durability, UpdateOption};
+    /// use neor::arguments::{InsertOption, ReturnChanges};
     /// use neor::types::MutationResponse;
     /// use neor::{args, r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let update_option = UpdateOption::default().durability(Durability::Soft);
+    ///     let insert_option = InsertOption::default().return_changes(ReturnChanges::Bool(true));
     ///     let conn = r.connection().connect().await?;
     ///     let response: MutationResponse = r.table("posts")
-    ///         .get(1)
-    ///         .update(args!(json!({"status": "published"}), update_option))
+    ///         .insert(args!(
+    ///             json!({"title": "Lorem ipsum", "content": "Dolor sit amet"}),
+    ///             insert_option
+    ///         ))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 1);
+    ///     assert!(response.inserted == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [insert](Self::insert)
+    /// - [update](Self::update)
     /// - [replace](Self::replace)
     /// - [delete](Self::delete)
-    pub fn update(&self, args: impl update::UpdateArg) -> Self {
-        update::new(args).with_parent(self)
+    pub fn insert(&self, args: impl insert::InsertArg) -> Self {
+        insert::new(args).with_parent(self)
     }
 
-    /// Replace documents in a table.
+    /// Insert a large sequence of documents in chunks.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// table.insert_batched(documents, options, &session) → response
+    /// table.insert_batched(documents, options, connection) → response
+    /// table.insert_batched(documents, options, args!(&session, run_options)) → response
+    /// ```
+    ///
+    /// Where:
+    /// - documents: an iterator of serializable documents
+    /// - options: [BatchOption](crate::arguments::BatchOption)
+    /// - response: [BatchResponse](crate::cmd::insert_batched::BatchResponse)
+    ///
+    /// # Description
+    ///
+    /// The documents are read lazily and split in chunks of `chunk_size`
+    /// documents (200 by default), each inserted by its own query.
+    /// Up to `parallelism` chunks (4 by default) are in flight at the same
+    /// time on the connection. This is much faster than inserting the
+    /// documents one by one, without building a term too large for the server.
+    ///
+    /// The counters of the responses are summed, and the generated keys
+    /// are kept in the order of the documents. A chunk which fails does not
+    /// stop the others: its error is recorded with the positions
+    /// of its documents.
+    ///
+    /// ## Examples
+    ///
+    /// Insert a million documents, replacing the existing ones.
+    ///
+    /// ```
+    /// use neor::arguments::{BatchOption, Conflict};
+    /// use neor::{r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let documents = (0..1_000_000).map(|id| json!({"id": id, "views": 0}));
+    ///     let options = BatchOption::default()
+    ///         .chunk_size(1000)
+    ///         .parallelism(8)
+    ///         .conflict(Conflict::Replace);
+    ///     let response = r.table("posts")
+    ///         .insert_batched(documents, options, &conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.errors.is_empty());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [insert](Self::insert)
+    pub async fn insert_batched<I, T>(
+        &self,
+        documents: I,
+        options: crate::arguments::BatchOption,
+        args: impl run::RunArg,
+    ) -> Result<insert_batched::BatchResponse>
+    where
+        I: IntoIterator<Item = T>,
+        T: serde::Serialize,
+    {
+        insert_batched::new(self, documents, options, args).await
+    }
+
+    /// Update JSON documents in a table.
     ///
     /// # Command syntax
     ///
@@ -1741,20 +2123,11 @@ impl<'a> Command {
     ///
     /// # Description
     ///
-    /// Accepts a JSON document or a ReQL expression, and replaces
-    /// the original document with the new one.
-    /// The new document must have the same primary key as the original document.
-    ///
-    /// The `replace` command can be used to both insert and delete documents.
-    /// If the “replaced” document has a primary key that doesn’t exist in the table,
-    /// the document will be inserted; if an existing document is replaced with `None`,
-    /// the document will be deleted.
-    /// Since `update` and `replace` operations are performed atomically,
-    /// this allows atomic inserts and deletes as well.
+    /// Accepts a JSON document, a ReQL expression, or a combination of the two.
     ///
     /// ## Examples
     ///
-    /// Replace the document with the primary key `1`.
+    /// Update the status of the post with `id` of `1` to `published`.
     ///
     /// ```
     /// use neor::types::MutationResponse;
@@ -1765,12 +2138,7 @@ impl<'a> Command {
     ///     let conn = r.connection().connect().await?;
     ///     let response: MutationResponse = r.table("posts")
     ///         .get(1)
-    ///         .replace(json!({
-    ///             "id": 1,
-    ///             "title": "Lorem ipsum",
-    ///             "content": "Aleas jacta est",
-    ///             "status": "draft"
-    ///         }))
+    ///         .update(json!({"status": "published"}))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
@@ -1784,22 +2152,23 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Remove the field `status` from all posts.
+    /// Update the status of all posts to `published`.
     ///
     /// ```
     /// use neor::types::MutationResponse;
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response: MutationResponse = r.table("posts")
-    ///         .replace(func!(|post| post.without("status")))
+    ///         .update(json!({"status": "published"}))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.replaced == 5);
+    ///     assert!(response.replaced == 100);
     ///     
     ///     Ok(())
     /// }
@@ -1807,16 +2176,18 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Remove all the fields that are not `id`, `title` or `content`.
+    /// Update the status of all the posts written by Moussa.
     ///
     /// ```
     /// use neor::types::MutationResponse;
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response: MutationResponse = r.table("posts")
-    ///         .replace(func!(|post| post.pluck(["id", "title", "content"])))
+    ///         .filter(json!({"author": "Moussa"}))
+    ///         .update(json!({"status": "published"}))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
@@ -1828,27 +2199,29 @@ impl<'a> Command {
     /// }
     /// ```
     ///
+    /// ```text
+    /// Note that `filter`, `get_all` and similar operations
+    /// do **not** execute in an atomic fashion with `update`.
+    /// Read [Consistency guarantees](https://rethinkdb.com/docs/consistency) for more details.
+    /// Also, see the example for conditional updates
+    /// below for a solution using `branch` in an `update` clause.
+    /// ```
+    ///
     /// ## Examples
     ///
-    /// Replace the document with the primary key `1` using soft durability.
+    /// Increment the field `view` of the post with `id` of `1`.
+    /// This query will throw an error if the field `views` doesn’t exist.
     ///
     /// ```
-    /// use neor::arguments::{Durability, ReplaceOption};
     /// use neor::types::MutationResponse;
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{func, r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let replace_option = ReplaceOption::default().durability(Durability::Soft);
     ///     let conn = r.connection().connect().await?;
     ///     let response: MutationResponse = r.table("posts")
     ///         .get(1)
-    ///         .replace(args!(json!({
-    ///             "id": 1,
-    ///             "title": "Lorem ipsum",
-    ///             "content": "Aleas jacta est",
-    ///             "status": "draft"
-    ///         }), replace_option))
+    ///         .update(func!(|post| post.g("views") + r.expr(1)))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
@@ -1862,74 +2235,64 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Replace the document with the primary key `1` and return the
-    /// values of the document before and after the replace operation.
+    /// Increment the field view of the post with id of 1.
+    /// If the field views does not exist, it will be set to 0.
     ///
     /// ```
-    /// use neor::arguments::{ReturnChanges, ReplaceOption};
-    /// use neor::{args, r, Converter, Result};
+    /// use std::ops::Add;
+    ///
+    /// use neor::types::MutationResponse;
+    /// use neor::{func, r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let replace_option = ReplaceOption::default().return_changes(ReturnChanges::Bool(true));
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
+    ///     let response: MutationResponse = r.table("posts")
     ///         .get(1)
-    ///         .replace(args!(json!({
-    ///             "id": 1,
-    ///             "title": "Lorem ipsum",
-    ///             "content": "Aleas jacta est",
-    ///             "status": "draft"
-    ///         }), replace_option))
+    ///         .update(func!(|post| post.g("views").add(r.expr(1)).default(0)))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.replaced == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [insert](Self::insert)
-    /// - [update](Self::update)
-    /// - [delete](Self::delete)
-    pub fn replace(&self, args: impl replace::ReplaceArg) -> Self {
-        replace::new(args).with_parent(self)
-    }
-
-    /// Delete one or more documents from a table.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// table.delete(()) → response
-    /// table.delete(options) → response
-    /// ```
-    ///
-    /// Where:
-    /// - options: [DeleteOption](crate::arguments::DeleteOption)
-    /// - response: [MutationResponse](crate::types::MutationResponse)
-    ///
     /// ## Examples
     ///
-    /// Delete a single document from the table `comments`.
+    /// Perform a conditional update.
+    ///
+    /// If the post has more than 100 views,
+    /// set the `type` of a post to `hot`,
+    /// else set it to `normal`.
     ///
     /// ```
+    /// use std::ops::Add;
+    ///
     /// use neor::types::MutationResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, func, r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("comments")
-    ///         .get("7eab9e63-73f1-4f33-8ce4-95cbea626f59")
-    ///         .delete(())
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .get(1)
+    ///         .update(func!(|post| r.branch(
+    ///             post.g("views").gt(100),
+    ///             args!(
+    ///                 json!({"type": "hot"}),
+    ///                 json!({"type": "normal"})
+    ///             )
+    ///         )))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.deleted == 1);
+    ///     assert!(response.replaced == 1);
     ///     
     ///     Ok(())
     /// }
@@ -1937,90 +2300,80 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Delete all documents from the table comments.
+    /// Update the field `num_comments` with the result of a sub-query.
+    /// Because this update is not atomic, you must pass the `non_atomic` flag.
     ///
     /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::arguments::UpdateOption;
     /// use neor::types::MutationResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
+    ///     let update_option = UpdateOption::default().non_atomic(true);
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("comments")
-    ///         .delete(())
+    ///     let mut comments_filtered = HashMap::new();
+    ///     comments_filtered.insert(
+    ///         "num_comments",
+    ///         r.table("comments")
+    ///             .filter(json!({"id_post": 1}))
+    ///             .count(())
+    ///     );
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .get(1)
+    ///         .update(args!(r.hash_map(comments_filtered), update_option))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.deleted == 100);
+    ///     assert!(response.replaced == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Delete all comments where the field `id_post` is `3`.
+    /// If you forget to specify the `non_atomic` flag,
+    /// you will get a `ReqlRuntimeError`:
     ///
-    /// ```
-    /// use neor::types::MutationResponse;
-    /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("comments")
-    ///         .filter(json!({"id_post": 3}))
-    ///         .delete(())
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
-    ///
-    ///     assert!(response.deleted == 5);
-    ///     
-    ///     Ok(())
-    /// }
+    /// ```text
+    /// ReqlRuntimeError: Could not prove function deterministic.
+    /// Maybe you want to use the non_atomic flag?
     /// ```
     ///
     /// ## Examples
     ///
-    /// Delete a single document from the table `comments` and return its value.
+    /// Update the field `num_comments` with a random value between 0 and 100.
+    /// This update cannot be proven deterministic because of `r.js` (and in fact is not),
+    /// so you must pass the `non_atomic` flag.
     ///
     /// ```
-    /// use neor::arguments::{ReturnChanges, DeleteOption};
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::arguments::UpdateOption;
     /// use neor::types::MutationResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let expected_data = json!({
-    ///         "id": "7eab9e63-73f1-4f33-8ce4-95cbea626f59",
-    ///         "author": "Moussa",
-    ///         "comment": "Great post",
-    ///         "id_post": 3
-    ///     });
-    ///     let delete_option = DeleteOption::default().return_changes(ReturnChanges::Bool(true));
+    ///     let update_option = UpdateOption::default().non_atomic(true);
     ///     let conn = r.connection().connect().await?;
-    ///     let response: MutationResponse = r.table("comments")
-    ///         .get("7eab9e63-73f1-4f33-8ce4-95cbea626f59")
-    ///         .delete(delete_option)
+    ///     let mut comments_filtered = HashMap::new();
+    ///     comments_filtered.insert(
+    ///         "num_comments",
+    ///         r.js("Math.floor(Math.random()*100)")
+    ///     );
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .get(1)
+    ///         .update(args!(r.hash_map(comments_filtered), update_option))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     let old_val = response
-    ///         .clone()
-    ///         .changes
-    ///         .unwrap()
-    ///         .first()
-    ///         .unwrap()
-    ///         .to_owned()
-    ///         .old_val;
-    ///
-    ///     assert!(response.deleted == 1);
-    ///     assert_eq!(old_val, Some(expected_data));
+    ///     assert!(response.replaced == 1);
     ///     
     ///     Ok(())
     /// }
@@ -2028,22 +2381,26 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Delete all documents from the table `comments` without
-    /// waiting for the operation to be flushed to disk.
+    /// Update the status of the post with `id` of `1` using soft durability.
     ///
     /// ```
-    /// use neor::arguments::{Durability, DeleteOption};
-    /// use neor::{r, Result};
+    /// use neor::arguments::{Durability, UpdateOption};
+    /// use neor::types::MutationResponse;
+    /// use neor::{args, r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let delete_option = DeleteOption::default().durability(Durability::Soft);
+    ///     let update_option = UpdateOption::default().durability(Durability::Soft);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("comments")
-    ///         .delete(delete_option)
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .get(1)
+    ///         .update(args!(json!({"status": "published"}), update_option))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.replaced == 1);
     ///     
     ///     Ok(())
     /// }
@@ -2051,90 +2408,90 @@ impl<'a> Command {
     ///
     /// # Related commands
     /// - [insert](Self::insert)
-    /// - [update](Self::update)
     /// - [replace](Self::replace)
-    pub fn delete(&self, args: impl delete::DeleteArg) -> Self {
-        delete::new(args).with_parent(self)
+    /// - [delete](Self::delete)
+    pub fn update(&self, args: impl update::UpdateArg) -> Self {
+        update::new(args).with_parent(self)
     }
 
-    /// `sync` ensures that writes on a given
-    /// table are written to permanent storage.
+    /// Replace documents in a table.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.sync() → response
+    /// table.replace(object) → response
+    /// table.replace(func) → response
+    /// table.replace(args!(object, options)) → response
+    /// table.replace(args!(func, options)) → response
     /// ```
     ///
     /// Where:
-    /// - response: [SyncResponse](crate::types::SyncResponse)
+    /// - object: `impl Serialize` | [Command](crate::Command)
+    /// - func: [Func](crate::Func)
+    /// - options: [ReplaceOption](crate::arguments::ReplaceOption)
+    /// - response: [MutationResponse](crate::types::MutationResponse)
     ///
     /// # Description
     ///
-    /// Queries that specify soft durability (`durability=Durability::Soft`)
-    /// do not give such guarantees, so `sync` can be used to ensure the state of these queries.
-    /// A call to `sync` does not return until all previous writes to the table are persisted.
+    /// Accepts a JSON document or a ReQL expression, and replaces
+    /// the original document with the new one.
+    /// The new document must have the same primary key as the original document.
+    ///
+    /// The `replace` command can be used to both insert and delete documents.
+    /// If the “replaced” document has a primary key that doesn’t exist in the table,
+    /// the document will be inserted; if an existing document is replaced with `None`,
+    /// the document will be deleted.
+    /// Since `update` and `replace` operations are performed atomically,
+    /// this allows atomic inserts and deletes as well.
     ///
     /// ## Examples
     ///
-    /// After having updated multiple heroes with soft durability,
-    /// we now want to wait until these changes are persisted.
+    /// Replace the document with the primary key `1`.
     ///
     /// ```
-    /// use neor::types::SyncResponse;
+    /// use neor::types::MutationResponse;
     /// use neor::{r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: SyncResponse = r.table("simbad")
-    ///         .sync()
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .get(1)
+    ///         .replace(json!({
+    ///             "id": 1,
+    ///             "title": "Lorem ipsum",
+    ///             "content": "Aleas jacta est",
+    ///             "status": "draft"
+    ///         }))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.synced == 1);
+    ///     assert!(response.replaced == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [noreply_wait](crate::connection::Session::noreply_wait)
-    pub fn sync(&self) -> Self {
-        sync::new().with_parent(self)
-    }
-
-    /// Get a document by primary key.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// table.get(keys) → singleRowSelection
-    /// ```
-    ///
-    /// Where:
-    /// - keys: `impl Into<String>` | `i8, u8, ..., usize` | [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// If no document exists with that primary key, `get` will return `None`.
-    ///
     /// ## Examples
     ///
-    /// Find a document by UUID.
+    /// Remove the field `status` from all posts.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::types::MutationResponse;
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .get("a9849eef-7176-4411-935b-79a6e3c56a74")
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .replace(func!(|post| post.without("status")))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.replaced == 5);
     ///     
     ///     Ok(())
     /// }
@@ -2142,68 +2499,54 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Find a document and merge another document with it.
+    /// Remove all the fields that are not `id`, `title` or `content`.
     ///
     /// ```
-    /// use neor::{r, Result};
-    /// use serde_json::json;
+    /// use neor::types::MutationResponse;
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("heroes")
-    ///         .get(3)
-    ///         .merge(json!({
-    ///             "powers": ["invisibility", "speed"]
-    ///         }))
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .replace(func!(|post| post.pluck(["id", "title", "content"])))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.replaced == 5);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [get_all](Self::get_all)
-    /// - [between](Self::between)
-    /// - [filter](Self::filter)
-    pub fn get(&self, args: impl Into<CommandArg>) -> Self {
-        get::new(args).with_parent(self)
-    }
-
-    /// Get all documents where the given value
-    /// matches the value of the requested index.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// table.get_all(keys) → selection
-    /// table.get_all(args!(keys, options)) → selection
-    /// ```
-    ///
-    /// Where:
-    /// - keys: `impl IntoIterator<Item = impl Into<String>>` | [Command](crate::Command)
-    /// - options: [GetAllOption](crate::arguments::GetAllOption)
-    ///
     /// ## Examples
     ///
-    /// Secondary index keys are not guaranteed to be unique so we cannot
-    /// query via [get](Self::get) when using a secondary index.
+    /// Replace the document with the primary key `1` using soft durability.
     ///
     /// ```
-    /// use neor::arguments::GetAllOption;
-    /// use neor::{args, r, Result};
+    /// use neor::arguments::{Durability, ReplaceOption};
+    /// use neor::types::MutationResponse;
+    /// use neor::{args, r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let get_all_option = GetAllOption::default().index("code_name");
+    ///     let replace_option = ReplaceOption::default().durability(Durability::Soft);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .get_all(args!(["man_of_steel"], get_all_option))
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .get(1)
+    ///         .replace(args!(json!({
+    ///             "id": 1,
+    ///             "title": "Lorem ipsum",
+    ///             "content": "Aleas jacta est",
+    ///             "status": "draft"
+    ///         }), replace_option))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.replaced == 1);
     ///     
     ///     Ok(())
     /// }
@@ -2211,17 +2554,25 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Without an index argument, we default to the primary index.
-    /// While `get` will either return the document or `None` when no document
-    /// with such a primary key value exists, this will return either a one or zero length stream.
+    /// Replace the document with the primary key `1` and return the
+    /// values of the document before and after the replace operation.
     ///
     /// ```
-    /// use neor::{args, r, Result};
+    /// use neor::arguments::{ReturnChanges, ReplaceOption};
+    /// use neor::{args, r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
+    ///     let replace_option = ReplaceOption::default().return_changes(ReturnChanges::Bool(true));
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("dc")
-    ///         .get_all(["superman"])
+    ///     let response = r.table("posts")
+    ///         .get(1)
+    ///         .replace(args!(json!({
+    ///             "id": 1,
+    ///             "title": "Lorem ipsum",
+    ///             "content": "Aleas jacta est",
+    ///             "status": "draft"
+    ///         }), replace_option))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2231,91 +2582,46 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// You can get multiple documents in a single call to get_all.
-    ///
-    /// ```
-    /// use neor::{args, r, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("dc")
-    ///         .get_all(["superman", "ant man"])
-    ///         .run(&conn)
-    ///         .await?;
-    ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
-    ///
-    /// ## Note
-    ///
-    /// ```text
-    /// `get_all` does not perform any de-duplication.
-    /// If you pass the same key more than once, the same document will be returned multiple times.
-    /// ```
-    ///
     /// # Related commands
-    /// - [get](Self::get)
-    /// - [between](Self::between)
-    /// - [filter](Self::filter)
-    pub fn get_all(&self, values: impl get_all::GetAllArg) -> Self {
-        get_all::new(values).with_parent(self)
+    /// - [insert](Self::insert)
+    /// - [update](Self::update)
+    /// - [delete](Self::delete)
+    pub fn replace(&self, args: impl replace::ReplaceArg) -> Self {
+        replace::new(args).with_parent(self)
     }
 
-    /// Get all documents between two keys.
+    /// Delete one or more documents from a table.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.between(args!(lower_key, upper_key)) → table_slice
-    /// table.between(args!(lower_key, upper_key, options)) → table_slice
+    /// table.delete(()) → response
+    /// table.delete(options) → response
     /// ```
     ///
     /// Where:
-    /// - lower_key, upper_key: `impl Into<Serialize>` | [Command](crate::Command)
-    /// - options: [BetweenOption](crate::arguments::BetweenOption)
-    ///
-    /// # Description
-    ///
-    /// You may also use the special constants `r::min_val()` and `r::max_val()` for boundaries,
-    /// which represent “less than any index key” and “more than any index key” respectively.
-    /// For instance, if you use `r::min_val()` as the lower key, then `between` will return
-    /// all documents whose primary keys (or indexes) are less than the specified upper key.
-    ///
-    /// If you use arrays as indexes (compound indexes),
-    /// they will be sorted using
-    /// [lexicographical order](https://en.wikipedia.org/wiki/Lexicographical_order).
-    /// Take the following range as an example:
-    ///
-    /// ```text
-    /// [[1, "c"] ... [5, "e"]]
-    /// ```
-    ///
-    /// This range includes all compound keys:
-    /// - whose first item is 1 and second item is equal or greater than “c”;
-    /// - whose first item is between 1 and 5,
-    /// **regardless of the value of the second item**;
-    /// - whose first item is 5 and second item is less than or equal to “e”.
+    /// - options: [DeleteOption](crate::arguments::DeleteOption)
+    /// - response: [MutationResponse](crate::types::MutationResponse)
     ///
     /// ## Examples
     ///
-    /// Find all users with primary key >= 10 and < 20 (a normal half-open interval).
+    /// Delete a single document from the table `comments`.
     ///
     /// ```
-    /// use neor::{args, r, Result};
+    /// use neor::types::MutationResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .between(args!(10, 20))
+    ///     let response: MutationResponse = r.table("comments")
+    ///         .get("7eab9e63-73f1-4f33-8ce4-95cbea626f59")
+    ///         .delete(())
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.deleted == 1);
     ///     
     ///     Ok(())
     /// }
@@ -2323,21 +2629,22 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Find all users with primary key >= 10 and <= 20 (an interval closed on both sides).
+    /// Delete all documents from the table comments.
     ///
     /// ```
-    /// use neor::arguments::{BetweenOption, Status};
-    /// use neor::{args, r, Result};
+    /// use neor::types::MutationResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let between_option = BetweenOption::default().right_bound(Status::Closed);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .between(args!(10, 20, between_option))
+    ///     let response: MutationResponse = r.table("comments")
+    ///         .delete(())
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.deleted == 100);
     ///     
     ///     Ok(())
     /// }
@@ -2345,19 +2652,24 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Find all users with primary key < 20.
+    /// Delete all comments where the field `id_post` is `3`.
     ///
     /// ```
-    /// use neor::{args, r, Result};
+    /// use neor::types::MutationResponse;
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .between(args!(r::min_val(), 20))
+    ///     let response: MutationResponse = r.table("comments")
+    ///         .filter(json!({"id_post": 3}))
+    ///         .delete(())
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.deleted == 5);
     ///     
     ///     Ok(())
     /// }
@@ -2365,21 +2677,42 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Find all users with primary key > 10.
+    /// Delete a single document from the table `comments` and return its value.
     ///
     /// ```
-    /// use neor::arguments::{BetweenOption, Status};
-    /// use neor::{args, r, Result};
+    /// use neor::arguments::{ReturnChanges, DeleteOption};
+    /// use neor::types::MutationResponse;
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let between_option = BetweenOption::default().right_bound(Status::Open);
+    ///     let expected_data = json!({
+    ///         "id": "7eab9e63-73f1-4f33-8ce4-95cbea626f59",
+    ///         "author": "Moussa",
+    ///         "comment": "Great post",
+    ///         "id_post": 3
+    ///     });
+    ///     let delete_option = DeleteOption::default().return_changes(ReturnChanges::Bool(true));
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .between(args!(10, r::max_val(), between_option))
+    ///     let response: MutationResponse = r.table("comments")
+    ///         .get("7eab9e63-73f1-4f33-8ce4-95cbea626f59")
+    ///         .delete(delete_option)
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     let old_val = response
+    ///         .clone()
+    ///         .changes
+    ///         .unwrap()
+    ///         .first()
+    ///         .unwrap()
+    ///         .to_owned()
+    ///         .old_val;
+    ///
+    ///     assert!(response.deleted == 1);
+    ///     assert_eq!(old_val, Some(expected_data));
     ///     
     ///     Ok(())
     /// }
@@ -2387,18 +2720,18 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Between can be used on secondary indexes too.
-    /// Just pass an optional index argument giving the secondary index to query.
+    /// Delete all documents from the table `comments` without
+    /// waiting for the operation to be flushed to disk.
     ///
     /// ```
-    /// use neor::arguments::BetweenOption;
-    /// use neor::{args, r, Result};
+    /// use neor::arguments::{Durability, DeleteOption};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let between_option = BetweenOption::default().index("code_name");
+    ///     let delete_option = DeleteOption::default().durability(Durability::Soft);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("dc")
-    ///         .between(args!("dark_knight", "man_of_steel", between_option))
+    ///     let response = r.table("comments")
+    ///         .delete(delete_option)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2408,181 +2741,227 @@ impl<'a> Command {
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [insert](Self::insert)
+    /// - [update](Self::update)
+    /// - [replace](Self::replace)
+    pub fn delete(&self, args: impl delete::DeleteArg) -> Self {
+        delete::new(args).with_parent(self)
+    }
+
+    /// Bound the number of changes returned by a write.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// write.limit_changes(max_changes) → response
+    /// write.limit_changes(args!(max_changes, primary_key)) → response
+    /// ```
+    ///
+    /// Where:
+    /// - max_changes: `usize`
+    /// - primary_key: `impl Into<String>` (default `id`)
+    /// - response: [MutationResponse](crate::types::MutationResponse)
+    ///
+    /// # Description
+    ///
+    /// When a write touching many documents is run with
+    /// `return_changes` enabled, the response holds every change made.
+    /// `limit_changes` truncates `changes` on the server to its first
+    /// `max_changes` elements and records the primary keys of
+    /// every changed document in `changed_keys`.
+    /// The remaining documents can then be fetched page by page
+    /// with [get_all](Self::get_all) (see
+    /// [changed_keys_pages](crate::types::MutationResponse::changed_keys_pages)).
+    ///
     /// ## Examples
     ///
-    /// Get all users whose full name is between “John Smith” and “Wade Welles.”
+    /// Update every post but only bring back the first 10 changes,
+    /// then read the updated posts 100 at a time.
     ///
     /// ```
-    /// use neor::arguments::BetweenOption;
-    /// use neor::{args, r, Result};
+    /// use neor::arguments::{ReturnChanges, UpdateOption};
+    /// use neor::types::MutationResponse;
+    /// use neor::{args, r, Converter, Result};
+    /// use serde_json::{json, Value};
     ///
     /// async fn example() -> Result<()> {
-    ///     let between_option = BetweenOption::default().index("full_name");
+    ///     let update_option = UpdateOption::default().return_changes(ReturnChanges::Bool(true));
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("dc")
-    ///         .between(args!(["Smith", "John"], ["Welles", "Wade"], between_option))
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .update(args!(json!({"status": "published"}), update_option))
+    ///         .limit_changes(10)
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.changes.as_ref().unwrap().len() <= 10);
+    ///
+    ///     for keys in response.changed_keys_pages(100) {
+    ///         let posts: Vec<Value> = r.table("posts")
+    ///             .get_all(r.args(keys))
+    ///             .run(&conn)
+    ///             .await?
+    ///             .unwrap()
+    ///             .parse()?;
+    ///
+    ///         assert!(posts.len() <= 100);
+    ///     }
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [get](Self::get)
-    /// - [get_all](Self::get_all)
-    /// - [filter](Self::filter)
-    pub fn between(&self, args: impl between::BetweenArg) -> Self {
-        between::new(args).with_parent(self)
+    /// - [update](Self::update)
+    /// - [replace](Self::replace)
+    /// - [delete](Self::delete)
+    /// - [insert](Self::insert)
+    pub fn limit_changes(&self, args: impl limit_changes::LimitChangesArg) -> Self {
+        self.do_(limit_changes::new(args))
     }
 
-    /// Return all the elements in a sequence for which the given predicate is true.
+    /// Apply a JSON Patch document to a document.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// selection.filter(func) → selection
-    /// selection.filter(predicate) → selection
-    /// selection.filter(args!(func, options)) → selection
-    /// selection.filter(args!(predicate, options)) → selection
+    /// singleSelection.patch(json_patch) → response
+    /// selection.patch(json_patch) → response
     /// ```
     ///
     /// Where:
-    /// - predicate: `impl Serialize` | [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - options: [FilterOption](crate::arguments::FilterOption)
+    /// - json_patch: `impl Serialize`, an array of operations
+    /// - response: [MutationResponse](crate::types::MutationResponse)
     ///
     /// # Description
     ///
-    /// Return all the elements in a sequence for which the given predicate is true.
-    /// The return value of `filter` will be the same as the input (sequence, stream, or array).
-    /// Documents can be filtered in a variety of ways—ranges, nested values, boolean conditions,
-    /// and the results of anonymous functions.
-    ///
-    /// By default, `filter` will silently skip documents with missing fields:
-    /// if the predicate tries to access a field that doesn’t exist
-    /// (for instance, the predicate `{"age": 30}` applied to a document with no `age` field),
-    /// that document will not be returned in the result set, and no error will be generated.
-    /// This behavior can be changed with the default optional argument
-    /// [FilterOption](crate::arguments::FilterOption).
-    ///
-    /// ## Note
-    ///
-    /// `filter` does not use secondary indexes.
-    /// For retrieving documents via secondary indexes, consider
-    /// [get_all](Self::get_all), [between](Self::between) and [eq_join](Self::eq_join).
+    /// The operations of the patch (RFC 6902), e.g. the body of an
+    /// HTTP `PATCH` request, are translated into a [replace](Self::replace)
+    /// with a function of the document: a field is set with
+    /// [merge](Self::merge) and [literal](crate::r::literal), removed with
+    /// [without](Self::without), and an array element is set, inserted or
+    /// removed with [change_at](Self::change_at), [insert_at](Self::insert_at)
+    /// and [delete_at](Self::delete_at). The path tokens made of digits
+    /// address the elements of arrays and the fields of objects, as found
+    /// when the query runs, and `-` the end of an array.
     ///
-    /// # Basic predicates
+    /// The operations are applied in order, atomically. A failing `test`
+    /// operation, or a `remove` or `replace` of a missing value, leaves the
+    /// document unchanged and is reported in the `first_error` of the response. An invalid patch fails the query
+    /// before it is sent.
     ///
     /// ## Examples
     ///
-    /// Get all users who are 30 years old.
+    /// Rename the title of a post and add a tag.
     ///
     /// ```
-    /// use neor::{args, r, Result};
+    /// use neor::types::MutationResponse;
+    /// use neor::{r, Converter, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(json!({"age": 30}))
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .get(1)
+    ///         .patch(json!([
+    ///             {"op": "test", "path": "/status", "value": "draft"},
+    ///             {"op": "replace", "path": "/title", "value": "Lorem ipsum"},
+    ///             {"op": "add", "path": "/tags/-", "value": "news"}
+    ///         ]))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.replaced + response.errors <= 1);
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// The predicate `{"age": 30}` selects documents in the `users` table
-    /// with an `age` field whose value is 30. Documents with an `age` field
-    /// set to any other value or with no `age` field present are skipped.
+    /// # Related commands
+    /// - [update](Self::update)
+    /// - [replace](Self::replace)
+    pub fn patch(&self, json_patch: impl Serialize) -> Self {
+        self.replace(patch::new(json_patch))
+    }
+
+    /// `sync` ensures that writes on a given
+    /// table are written to permanent storage.
     ///
-    /// While the `{"field": value}` style of predicate is useful for exact matches,
-    /// a more general way to write a predicate is to use an anonymous function that
-    /// returns `true` or `false`.
+    /// # Command syntax
     ///
+    /// ```text
+    /// table.sync() → response
     /// ```
-    /// use neor::{args, func, r, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("age").eq(30)))
-    ///         .run(&conn)
-    ///         .await?;
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// Where:
+    /// - response: [SyncResponse](crate::types::SyncResponse)
     ///
-    /// In this case, the function returns `true` if the field `age` is equal to 30.
+    /// # Description
     ///
-    /// Predicates to filter are evaluated on the server, and must use ReQL expressions.
-    /// You cannot use standard Java comparison operators such as `==`, `<` / `>` and `||` / `&&`.
+    /// Queries that specify soft durability (`durability=Durability::Soft`)
+    /// do not give such guarantees, so `sync` can be used to ensure the state of these queries.
+    /// A call to `sync` does not return until all previous writes to the table are persisted.
     ///
     /// ## Examples
     ///
-    /// Get all users who are more than 18 years old.
+    /// After having updated multiple heroes with soft durability,
+    /// we now want to wait until these changes are persisted.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::types::SyncResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("age").gt(18)))
+    ///     let response: SyncResponse = r.table("simbad")
+    ///         .sync()
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.synced == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [noreply_wait](crate::connection::Session::noreply_wait)
+    pub fn sync(&self) -> Self {
+        sync::new().with_parent(self)
+    }
+
+    /// Get a document by primary key.
     ///
-    /// Get all users who are less than 18 years old and more than 13 years old.
+    /// # Command syntax
     ///
+    /// ```text
+    /// table.get(keys) → singleRowSelection
     /// ```
-    /// use neor::{args, func, r, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("age").lt(18).and(
-    ///             user.g("age").gt(13)
-    ///         )))
-    ///         .run(&conn)
-    ///         .await?;
+    /// Where:
+    /// - keys: `impl Into<String>` | `i8, u8, ..., usize` | [Command](crate::Command)
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// # Description
+    ///
+    /// If no document exists with that primary key, `get` will return `None`.
     ///
     /// ## Examples
     ///
-    /// Get all users who are more than 18 years old or have their parental consent.
+    /// Find a document by UUID.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user
-    ///             .g("age")
-    ///             .ge(18)
-    ///             .or(user.g("hasParentalConsent"))
-    ///         ))
+    ///     let response = r.table("posts")
+    ///         .get("a9849eef-7176-4411-935b-79a6e3c56a74")
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2594,19 +2973,19 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Get all users who are more than 18 years old or have their parental consent.
+    /// Find a document and merge another document with it.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user
-    ///             .g("age")
-    ///             .ge(18)
-    ///             .or(user.g("hasParentalConsent"))
-    ///         ))
+    ///     let response = r.table("heroes")
+    ///         .get(3)
+    ///         .merge(json!({
+    ///             "powers": ["invisibility", "speed"]
+    ///         }))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2616,46 +2995,50 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// # More complex predicates
-    ///
-    /// ## Examples
+    /// # Related commands
+    /// - [get_all](Self::get_all)
+    /// - [between](Self::between)
+    /// - [filter](Self::filter)
+    pub fn get(&self, args: impl Into<CommandArg>) -> Self {
+        get::new(args).with_parent(self)
+    }
+
+    /// Get all documents where the given value
+    /// matches the value of the requested index.
     ///
-    /// Retrieve all users who subscribed between January
-    /// 1st, 2012 (included) and January 1st, 2013 (excluded).
+    /// # Command syntax
     ///
+    /// ```text
+    /// table.get_all(keys) → selection
+    /// table.get_all(args!(keys, options)) → selection
     /// ```
-    /// use neor::{args, func, r, Result};
-    /// use time::macros::{date, offset};
-    /// use time::UtcOffset;
     ///
-    /// async fn example() -> Result<()> {
-    ///     let timezone = UtcOffset::UTC;
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("subscription_date").during(args!(
-    ///             r.time(args!(date!(2012 - 1 - 1), timezone)),
-    ///             r.time(args!(date!(2015 - 1 - 1), timezone))
-    ///         ))))
-    ///         .run(&conn)
-    ///         .await?;
+    /// Where:
+    /// - keys: `impl IntoIterator<Item = impl Serialize>` | [Command](crate::Command)
+    /// - options: [GetAllOption](crate::arguments::GetAllOption)
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// # Description
+    ///
+    /// Each key is serialized, so the keys of a compound index are
+    /// tuples or arrays. Keys of different types are mixed with
+    /// [IndexKey](crate::types::IndexKey). Keys holding commands, e.g.
+    /// `("Smith", r::min_val())`, are built with [into_term](crate::IntoTerm::into_term)
+    /// and given with [r.args](crate::r::args).
     ///
     /// ## Examples
     ///
-    /// Retrieve all users who have a gmail account (whose field email ends with @gmail.com).
+    /// Secondary index keys are not guaranteed to be unique so we cannot
+    /// query via [get](Self::get) when using a secondary index.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::arguments::GetAllOption;
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let get_all_option = GetAllOption::default().index("code_name");
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("email").match_("@gmail.com$")))
+    ///     let response = r.table("marvel")
+    ///         .get_all(args!(["man_of_steel"], get_all_option))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2667,26 +3050,17 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Filter based on the presence of a value in an array.
-    ///
-    /// Given this schema for the `users` table.
-    ///
-    /// ```text
-    /// {
-    ///     "name": String,
-    ///     "places_visited": Vec<String>,
-    /// }
-    /// ```
-    ///
-    /// Retrieve all users whose field `places_visited` contains `Cameroon`.
+    /// Without an index argument, we default to the primary index.
+    /// While `get` will either return the document or `None` when no document
+    /// with such a primary key value exists, this will return either a one or zero length stream.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("places_visited").contains("Cameroon")))
+    ///     let response = r.table("dc")
+    ///         .get_all(["superman"])
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2698,38 +3072,15 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Filter based on nested fields.
-    ///
-    /// Given this schema for the `users` table.
-    ///
-    /// ```text
-    /// {
-    ///     "id": String,
-    ///     "name": {
-    ///         "first": String,
-    ///         "middle": String,
-    ///         "last": String,
-    ///     },
-    /// }
-    /// ```
-    ///
-    /// Retrieve all users named “Moussa Ibrahim
-    /// (first name “Moussa”, last name “Ibrahim”),
-    /// with any middle name.
+    /// You can get multiple documents in a single call to get_all.
     ///
     /// ```
     /// use neor::{args, r, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(json!({
-    ///             "name": {
-    ///                 "first": "Moussa",
-    ///                 "last": "Ibrahim",
-    ///             }
-    ///         }))
+    ///     let response = r.table("dc")
+    ///         .get_all(["superman", "ant man"])
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2739,102 +3090,122 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// If you want an exact match for a field that is an object,
-    /// you will have to use anonymous functions.
+    /// ## Examples
+    ///
+    /// Get the authors by their last and first names,
+    /// with a compound index on both fields.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::arguments::GetAllOption;
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user
-    ///             .g("name")
-    ///             .g("first")
-    ///             .eq("Moussa")
-    ///             .and(user.g("name").g("last").eq("Ibrahim"))
+    ///     let response = r.table("authors")
+    ///         .get_all(args!(
+    ///             [("Smith", "John"), ("Welles", "Wade")],
+    ///             GetAllOption::default().index("full_name")
     ///         ))
     ///         .run(&conn)
     ///         .await?;
     ///
     ///     assert!(response.is_some());
-    ///     
+    ///
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// ## Examples
+    ///
+    /// Get the posts whose `code` is either a number or a string.
+    ///
     /// ```
-    /// use neor::{args, func, r, Result};
-    /// use serde_json::json;
+    /// use neor::arguments::GetAllOption;
+    /// use neor::types::IndexKey;
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user
-    ///             .g("name")
-    ///             .eq(r.expr(json!({
-    ///                 "first": "Moussa",
-    ///                 "last": "Ibrahim",
-    ///             })))
-    ///         ))
+    ///     let keys = [IndexKey::from(42), IndexKey::from("A42")];
+    ///     let response = r.table("posts")
+    ///         .get_all(args!(keys, GetAllOption::default().index("code")))
     ///         .run(&conn)
     ///         .await?;
     ///
     ///     assert!(response.is_some());
-    ///     
+    ///
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [get](Self::get)
-    /// - [get_all](Self::get_all)
-    /// - [between](Self::between)
-    pub fn filter(&self, args: impl filter::FilterArg) -> Self {
-        filter::new(args).with_parent(self)
+    /// ## Note
+    ///
+    /// ```text
+    /// `get_all` does not perform any de-duplication.
+    /// If you pass the same key more than once, the same document will be returned multiple times.
+    /// ```
+    ///
+    /// # Related commands
+    /// - [get](Self::get)
+    /// - [between](Self::between)
+    /// - [filter](Self::filter)
+    pub fn get_all(&self, values: impl get_all::GetAllArg) -> Self {
+        get_all::new(values).with_parent(self)
     }
 
-    /// Returns an inner join of two sequences.
+    /// Get all documents between two keys.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.inner_join(other_sequence, func) → response
+    /// table.between(args!(lower_key, upper_key)) → table_slice
+    /// table.between(args!(lower_key, upper_key, options)) → table_slice
+    /// table.between(lower_bound..upper_bound) → table_slice
+    /// table.between(args!(lower_bound, upper_bound, options)) → table_slice
     /// ```
     ///
     /// Where:
-    /// - other_sequence: [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - response: [Vec<JoinResponse<Left, Right>>](crate::types::JoinResponse)
+    /// - lower_key, upper_key: `impl Into<Serialize>` | [Command](crate::Command)
+    /// - lower_bound, upper_bound: [Bound](crate::arguments::Bound)
+    /// - options: [BetweenOption](crate::arguments::BetweenOption)
     ///
     /// # Description
     ///
-    /// The returned sequence represents an intersection of the left-hand sequence
-    /// and the right-hand sequence: each row of the left-hand sequence will be
-    /// compared with each row of the right-hand sequence to find all pairs of rows
-    /// which satisfy the predicate. In most cases, you will want to follow the join
-    /// with [zip](Self::zip) to combine the left and right results.
+    /// You may also use the special constants `r::min_val()` and `r::max_val()` for boundaries,
+    /// which represent “less than any index key” and “more than any index key” respectively.
+    /// For instance, if you use `r::min_val()` as the lower key, then `between` will return
+    /// all documents whose primary keys (or indexes) are less than the specified upper key.
+    ///
+    /// If you use arrays as indexes (compound indexes),
+    /// they will be sorted using
+    /// [lexicographical order](https://en.wikipedia.org/wiki/Lexicographical_order).
+    /// Take the following range as an example:
     ///
     /// ```text
-    /// Note that `inner_join` is slower and much less efficient than using `concat_map`
-    /// with `get_all`. You should avoid using `inner_join` in commands when possible.
+    /// [[1, "c"] ... [5, "e"]]
     /// ```
     ///
+    /// This range includes all compound keys:
+    /// - whose first item is 1 and second item is equal or greater than “c”;
+    /// - whose first item is between 1 and 5,
+    /// **regardless of the value of the second item**;
+    /// - whose first item is 5 and second item is less than or equal to “e”.
+    ///
+    /// A range of [Bound](crate::arguments::Bound) gives each key with
+    /// whether it is in the range, instead of the `left_bound` and
+    /// `right_bound` of the options, which it overrides.
+    ///
     /// ## Examples
     ///
-    /// Return a list of all matchups between Marvel and DC heroes
-    /// in which the DC hero could beat the Marvel hero in a fight.
+    /// Find all users with primary key >= 10 and < 20 (a normal half-open interval).
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .inner_join(
-    ///             r.table("dc"),
-    ///             func!(|marvel, dc| marvel.g("strength").lt(dc.g("strength")))
-    ///         )
+    ///     let response = r.table("users")
+    ///         .between(args!(10, 20))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2844,59 +3215,19 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// (Compare this to an [outer_join](Self::outer_join) with
-    /// the same inputs and predicate, which would return a list
-    /// of **all** Marvel heroes along with any DC heroes with a higher strength.)
-    ///
-    /// # Related commands
-    /// - [eq_join](Self::eq_join)
-    /// - [outer_join](Self::outer_join)
-    /// - [zip](Self::zip)
-    pub fn inner_join(&self, other_sequence: Command, func: Func) -> Self {
-        inner_join::new(other_sequence, func).with_parent(self)
-    }
-
-    /// Returns a left outer join of two sequences.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// sequence.outer_join(other_sequence, func) → response
-    /// ```
-    ///
-    /// Where:
-    /// - other_sequence: [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - response: [Vec<JoinResponse<Left, Right>>](crate::types::JoinResponse)
-    ///
-    /// # Description
-    ///
-    /// The returned sequence represents a union of the left-hand sequence and the
-    /// right-hand sequence: all documents in the left-hand sequence will be returned,
-    /// each matched with a document in the right-hand sequence if one satisfies the
-    /// predicate condition. In most cases, you will want to follow the join with
-    /// [zip](Self::zip) to combine the left and right results.
-    ///
-    /// ```text
-    /// Note that `outer_join` is slower and much less efficient than using `concat_map`
-    /// with `get_all`. You should avoid using `outer_join` in commands when possible.
-    /// ```
-    ///
     /// ## Examples
     ///
-    /// Return a list of all Marvel heroes, paired with
-    /// any DC heroes who could beat them in a fight.
+    /// Find all users with primary key >= 10 and <= 20 (an interval closed on both sides).
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::arguments::{BetweenOption, Status};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let between_option = BetweenOption::default().right_bound(Status::Closed);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .outer_join(
-    ///             r.table("dc"),
-    ///             func!(|marvel, dc| marvel.g("strength").lt(dc.g("strength")))
-    ///         )
+    ///     let response = r.table("users")
+    ///         .between(args!(10, 20, between_option))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2906,87 +3237,38 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// (Compare this to an [inner_join](Self::inner_join) with
-    /// the same inputs and predicate, which would return a list
-    /// only of the matchups in which the DC hero has the higher strength.)
-    ///
-    /// # Related commands
-    /// - [eq_join](Self::eq_join)
-    /// - [inner_join](Self::inner_join)
-    /// - [zip](Self::zip)
-    pub fn outer_join(&self, other_sequence: Command, func: Func) -> Self {
-        outer_join::new(other_sequence, func).with_parent(self)
-    }
-
-    /// Join tables using a field or function on the left-hand sequence
-    /// matching primary keys or secondary indexes on the right-hand table.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Find all users with primary key > 10 and <= 20.
     ///
-    /// ```text
-    /// sequence.eq_join(args!(left_field, right_table)) → response
-    /// sequence.eq_join(args!(func, right_table)) → response
-    /// sequence.eq_join(args!(left_field, right_table, options)) → response
-    /// sequence.eq_join(args!(func, right_table, options)) → response
     /// ```
+    /// use neor::arguments::Bound;
+    /// use neor::{r, Result};
     ///
-    /// Where:
-    /// - left_field: `impl Into<String>` | [Command](crate::Command)
-    /// - right_table: [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - options: [EqJoinOption](crate::arguments::EqJoinOption)
-    /// - response: [Vec<JoinResponse<Left, Right>>](crate::types::JoinResponse)
-    ///
-    /// # Description
-    ///
-    /// `eq_join` is more efficient than other ReQL join types, and operates much faster.
-    /// Documents in the result set consist of pairs of left-hand and right-hand documents,
-    /// matched when the field on the left-hand side exists and is non-null and an entry
-    /// with that field’s value exists in the specified index on the right-hand side.
-    ///
-    /// The result set of `eq_join` is a stream or array of
-    /// [JoinResponse<LeftDocument, RightDocument>](crate::types::JoinResponse).
-    /// Each object in the returned set will be an object of the form
-    /// `{ left: <LeftDocument>, right: <RightDocument> }`, where the values of `left` and
-    /// `right` will be the joined documents.
-    /// Use the [zip](Self::zip) command to merge the `left` and `right` fields together.
-    ///
-    /// The results from `eq_join` are, by default, not ordered.
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .between(Bound::open(10)..Bound::closed(20))
+    ///         .run(&conn)
+    ///         .await?;
     ///
-    /// Suppose the players table contains these documents:
+    ///     assert!(response.is_some());
     ///
-    /// ```text
-    /// [
-    ///     { "id": 1, "player": "Moussa", "game_id": 1 },
-    ///     { "id": 2, "player": "Fatima", "game_id": 3 },
-    ///     { "id": 3, "player": "Abessolo", "game_id": 2 },
-    ///     { "id": 4, "player": "Kamga", "game_id": 2 },
-    ///     { "id": 5, "player": "Malika", "game_id": 1 },
-    ///     { "id": 6, "player": "Ibrahim", "game_id": 3 }
-    /// ]
+    ///     Ok(())
+    /// }
     /// ```
     ///
-    /// The games table contains these documents:
-    ///
-    /// [
-    ///     { "id": 1, "field": "Aurion" },
-    ///     { "id": 2, "field": "Adventures of Nyangi" },
-    ///     { "id": 3, "field": "Gazkar" }
-    /// ]
-    ///
     /// ## Examples
     ///
-    /// Match players with the games they’ve played against one another.
-    ///
-    /// Join these tables using `game_id` on the player table and `id` on the games table:
+    /// Find all users with primary key < 20.
     ///
     /// ```
     /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .eq_join(args!("game_id", r.table("games")))
+    ///     let response = r.table("users")
+    ///         .between(args!(r::min_val(), 20))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -2996,27 +3278,19 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// For more information, see
-    /// [Table joins in RethinkDB](https://rethinkdb.com/docs/table-joins/).
-    ///
     /// ## Examples
     ///
-    /// Use a secondary index on the right table rather than the primary key.
-    /// If players have a secondary index on their cities, we can get a list
-    /// of arenas with players in the same area.
+    /// Find all users with primary key > 10.
     ///
     /// ```
-    /// use neor::arguments::EqJoinOption;
+    /// use neor::arguments::{BetweenOption, Status};
     /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let between_option = BetweenOption::default().right_bound(Status::Open);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .eq_join(args!(
-    ///             "city_id",
-    ///             r.table("arenas"),
-    ///             EqJoinOption::default().index("city_id")
-    ///         ))
+    ///     let response = r.table("users")
+    ///         .between(args!(10, r::max_val(), between_option))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3028,26 +3302,18 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Use a function instead of a field to join on a more complicated expression.
-    /// Suppose the players have lists of favorite games ranked in order in a field
-    /// such as "favorites": [3, 2, 1]. Get a list of players and their top favorite:
+    /// Between can be used on secondary indexes too.
+    /// Just pass an optional index argument giving the secondary index to query.
     ///
     /// ```
-    /// use neor::arguments::EqJoinOption;
-    /// use neor::{args, func, r, Result};
-    /// use serde_json::json;
+    /// use neor::arguments::BetweenOption;
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let between_option = BetweenOption::default().index("code_name");
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .eq_join(args!(
-    ///             func!(|player| player.g("favorites").nth(0)),
-    ///             r.table("games")
-    ///         ))
-    ///         .without(json!([
-    ///             { "left": ["favorites", "game_id", "id"] },
-    ///             { "right": "id" }
-    ///         ]))
+    ///     let response = r.table("dc")
+    ///         .between(args!("dark_knight", "man_of_steel", between_option))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3057,36 +3323,19 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [inner_join](Self::inner_join)
-    /// - [outer_join](Self::outer_join)
-    /// - [without](Self::without)
-    /// - [zip](Self::zip)
-    pub fn eq_join(&self, args: impl eq_join::EqJoinArg) -> Self {
-        eq_join::new(args).with_parent(self)
-    }
-
-    /// Used to ‘zip’ up the result of a join by merging the ‘right’
-    /// fields into ‘left’ fields of each member of the sequence.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// stream.zip() → stream
-    /// ```
-    ///
     /// ## Examples
     ///
-    /// ‘zips up’ the sequence by merging the left and right fields produced by a join.
+    /// Get all users whose full name is between “John Smith” and “Wade Welles.”
     ///
     /// ```
+    /// use neor::arguments::BetweenOption;
     /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let between_option = BetweenOption::default().index("full_name");
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .eq_join(args!("user_id", r.table("users")))
-    ///         .zip()
+    ///     let response = r.table("dc")
+    ///         .between(args!(["Smith", "John"], ["Welles", "Wade"], between_option))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3097,113 +3346,112 @@ impl<'a> Command {
     /// ```
     ///
     /// # Related commands
-    /// - [eq_join](Self::eq_join)
-    /// - [inner_join](Self::inner_join)
-    /// - [outer_join](Self::outer_join)
-    pub fn zip(&self) -> Self {
-        zip::new().with_parent(self)
+    /// - [get](Self::get)
+    /// - [get_all](Self::get_all)
+    /// - [filter](Self::filter)
+    pub fn between(&self, args: impl between::BetweenArg) -> Self {
+        between::new(args).with_parent(self)
     }
 
-    /// Transform each element of one or more sequences
-    /// by applying a mapping function to them.
+    /// Return all the elements in a sequence for which the given predicate is true.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.map(func) → stream
-    /// sequence.map(sequence, func) → stream
-    /// sequence.map(sequences, func) → stream
-    /// r.map(sequence, func) → stream
-    /// r.map(sequence, sequence, func) → stream
-    /// r.map(sequence, sequences, func) → stream
+    /// selection.filter(func) → selection
+    /// selection.filter(predicate) → selection
+    /// selection.filter(args!(func, options)) → selection
+    /// selection.filter(args!(predicate, options)) → selection
     /// ```
     ///
     /// Where:
+    /// - predicate: `impl Serialize` | [Command](crate::Command)
     /// - func: [Func](crate::Func)
-    /// - sequence: [Command](crate::Command)
-    /// - sequences: `impl IntoIterator<Item = Command>`
+    /// - options: [FilterOption](crate::arguments::FilterOption)
     ///
     /// # Description
     ///
-    /// If `map` is run with two or more sequences, it will iterate
-    /// for as many items as there are in the shortest sequence.
+    /// Return all the elements in a sequence for which the given predicate is true.
+    /// The return value of `filter` will be the same as the input (sequence, stream, or array).
+    /// Documents can be filtered in a variety of ways—ranges, nested values, boolean conditions,
+    /// and the results of anonymous functions.
     ///
-    /// Note that `map` can only be applied to sequences, not single values.
-    /// If you wish to apply a function to a single value/selection (including an array),
-    /// use the [do_](Self::do_) command.
+    /// By default, `filter` will silently skip documents with missing fields:
+    /// if the predicate tries to access a field that doesn’t exist
+    /// (for instance, the predicate `{"age": 30}` applied to a document with no `age` field),
+    /// that document will not be returned in the result set, and no error will be generated.
+    /// This behavior can be changed with the default optional argument
+    /// [FilterOption](crate::arguments::FilterOption).
+    ///
+    /// ## Note
+    ///
+    /// `filter` does not use secondary indexes.
+    /// For retrieving documents via secondary indexes, consider
+    /// [get_all](Self::get_all), [between](Self::between) and [eq_join](Self::eq_join).
+    ///
+    /// # Basic predicates
     ///
     /// ## Examples
     ///
-    /// Return the first five squares.
+    /// Get all users who are 30 years old.
     ///
     /// ```
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{args, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<u8> = r.expr([1, 2, 3, 4, 5])
-    ///         .map(func!(|val| val.clone() * val))
+    ///     let response = r.table("users")
+    ///         .filter(json!({"age": 30}))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert_eq!(response, [1, 4, 9, 16, 25]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// The predicate `{"age": 30}` selects documents in the `users` table
+    /// with an `age` field whose value is 30. Documents with an `age` field
+    /// set to any other value or with no `age` field present are skipped.
     ///
-    /// Sum the elements of three sequences.
+    /// While the `{"field": value}` style of predicate is useful for exact matches,
+    /// a more general way to write a predicate is to use an anonymous function that
+    /// returns `true` or `false`.
     ///
     /// ```
-    /// use neor::{args, func, r, Converter, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let sequence1 = r.expr([100, 200, 300, 400]);
-    ///     let sequence2 = r.expr([10, 20, 30, 40]);
-    ///     let sequence3 = r.expr([1, 2, 3, 4]);
-    ///
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<u32> = r.map(sequence1, args!(
-    ///             [sequence2, sequence3],
-    ///             func!(|val1, val2, val3| val1 + val2 + val3)
-    ///         ))
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("age").eq(30)))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert_eq!(response, [111, 222, 333, 444]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// In this case, the function returns `true` if the field `age` is equal to 30.
     ///
-    /// Rename a field when retrieving documents
-    /// using `map` and [merge](Self::merge).
+    /// Predicates to filter are evaluated on the server, and must use ReQL expressions.
+    /// You cannot use standard Java comparison operators such as `==`, `<` / `>` and `||` / `&&`.
     ///
-    /// This example renames the field `id` to `user_id`
-    /// when retrieving documents from the table `users`.
+    /// ## Examples
     ///
-    /// ```
-    /// use std::collections::HashMap;
+    /// Get all users who are more than 18 years old.
     ///
+    /// ```
     /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response = r.table("users")
-    ///         .map(func!(|doc| {
-    ///             let mut user = HashMap::new();
-    ///             user.insert("user_id", doc.g("id"));
-    ///             
-    ///             doc.merge(r.hash_map(user)).without("id")
-    ///         }))
+    ///         .filter(func!(|user| user.g("age").gt(18)))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3215,22 +3463,17 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Assign every superhero an archenemy.
+    /// Get all users who are less than 18 years old and more than 13 years old.
     ///
     /// ```
-    /// use std::collections::HashMap;
-    ///
     /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("heroes")
-    ///         .map(args!(r.table("villains"), func!(|hero, villain| {
-    ///             let mut villain_obj = HashMap::new();
-    ///             villain_obj.insert("villain", villain);
-    ///
-    ///             hero.merge(r.hash_map(villain_obj))
-    ///         })))
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("age").lt(18).and(
+    ///             user.g("age").gt(13)
+    ///         )))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3240,143 +3483,94 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [concat_map](Self::concat_map)
-    /// - [reduce](Self::reduce)
-    /// - [do_](Self::do_)
-    pub fn map(&self, args: impl map::MapArg) -> Self {
-        map::new(args).with_parent(self)
-    }
-
-    /// Plucks one or more attributes from a sequence of objects, filtering
-    /// out any objects in the sequence that do not have the specified fields.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// sequence.with_fields(selector) → stream
-    /// ```
-    ///
-    /// Where:
-    /// - selector: `impl Into<String>` |
-    /// `impl IntoIterator<Item = impl Into<String>>` | [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// Functionally, this is identical to [has_fields](Self::has_fields)
-    /// followed by [pluck](Self::pluck) on a sequence.
-    ///
     /// ## Examples
     ///
-    /// Get a list of users and their posts, excluding any users who have not made any posts.
-    ///
-    /// Existing table structure:
-    ///
-    /// ```text
-    /// [
-    ///     { "id": 1, "user": "bob", "email": "bob@foo.com", "posts": [ 1, 4, 5 ] },
-    ///     { "id": 2, "user": "george", "email": "george@foo.com" },
-    ///     { "id": 3, "user": "jane", "email": "jane@foo.com", "posts": [ 2, 3, 6 ] }
-    /// ]
-    /// ```
-    ///
-    /// Command and output:
+    /// Get all users who are more than 18 years old or have their parental consent.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response = r.table("users")
-    ///         .with_fields(["id", "user", "posts"])
+    ///         .filter(func!(|user| user
+    ///             .g("age")
+    ///             .ge(18)
+    ///             .or(user.g("hasParentalConsent"))
+    ///         ))
     ///         .run(&conn)
     ///         .await?;
     ///
     ///     assert!(response.is_some());
-    ///
-    ///     // [
-    ///     //      { "id": 1, "user": "bob", "posts": [ 1, 4, 5 ] },
-    ///     //      { "id": 3, "user": "jane", "posts": [ 2, 3, 6 ] }
-    ///     // ]
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [has_fields](Self::has_fields)
-    /// - [pluck](Self::pluck)
-    /// - [without](Self::without)
-    pub fn with_fields(&self, fields: impl Into<CommandArg>) -> Self {
-        with_fields::new(fields).with_parent(self)
-    }
-
-    /// Concatenate one or more elements into
-    /// a single sequence using a mapping function.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Get all users who are more than 18 years old or have their parental consent.
     ///
-    /// ```text
-    /// stream.concat_map(func) → stream
     /// ```
+    /// use neor::{args, func, r, Result};
     ///
-    /// Where:
-    /// - func: [Func](crate::Func)
-    ///
-    /// # Description
-    ///
-    /// `concat_map` works in a similar fashion to [map](Self::map),
-    /// applying the given function to each element in a sequence,
-    /// but it will always return a single sequence.
-    /// If the mapping function returns a sequence,
-    /// `map` would produce a sequence of sequences:
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user
+    ///             .g("age")
+    ///             .ge(18)
+    ///             .or(user.g("hasParentalConsent"))
+    ///         ))
+    ///         .run(&conn)
+    ///         .await?;
     ///
-    /// ```ignore
-    /// r.expr([1, 2, 3]).map(func!(|x| r.array(x.clone(), x * 2))).run(&conn)
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
     /// ```
     ///
-    /// Result :
+    /// # More complex predicates
     ///
-    /// ```text
-    /// [[1, 2], [2, 4], [3, 6]]
-    /// ```
+    /// ## Examples
     ///
-    /// Whereas `concat_map` with the same mapping
-    /// function would merge those sequences into one:
+    /// Retrieve all users who subscribed between January
+    /// 1st, 2012 (included) and January 1st, 2013 (excluded).
     ///
     /// ```
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{args, func, r, Result};
+    /// use time::macros::{date, offset};
+    /// use time::UtcOffset;
     ///
     /// async fn example() -> Result<()> {
+    ///     let timezone = UtcOffset::UTC;
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<u8> = r
-    ///         .expr([1, 2, 3])
-    ///         .concat_map(func!(|x| r.array([x.clone(), x * 2])))
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("subscription_date").during(args!(
+    ///             r.time(args!(date!(2012 - 1 - 1), timezone)),
+    ///             r.time(args!(date!(2015 - 1 - 1), timezone))
+    ///         ))))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
-    ///     
-    ///     assert!(response == vec![1, 2, 2, 4, 3, 6]);
+    ///         .await?;
     ///
+    ///     assert!(response.is_some());
+    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// The return value, array or stream, will be the same type as the input.
-    ///
     /// ## Examples
     ///
-    /// Construct a sequence of all monsters defeated by Marvel heroes.
-    /// The field “defeatedMonsters” is an array of one or more monster names.
+    /// Retrieve all users who have a gmail account (whose field email ends with @gmail.com).
     ///
     /// ```
     /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .concat_map(func!(|hero| hero.g("defeatedMonsters")))
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("email").match_("@gmail.com$")))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3386,94 +3580,28 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [map](Self::map)
-    /// - [reduce](Self::reduce)
-    pub fn concat_map(&self, func: Func) -> Command {
-        concat_map::new(func).with_parent(self)
-    }
-
-    /// Sort the sequence by document values of the given key(s).
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// table.order_by(index) → table_slice
-    /// table.order_by(args!(predicate, index)) → table_slice
-    /// sequence.order_by(predicate) → array
-    /// sequence.order_by(predicates) → array
-    /// ```
-    ///
-    /// Where:
-    /// - index: [index](crate::r::index)
-    /// - predicate: `impl Into<String>` | [Func](crate::Func) | [Command](crate::Command)
-    /// - predicates: `[predicate; N]`
-    ///
-    /// # Description
-    ///
-    /// To specify the ordering, wrap the attribute with
-    /// either `r.asc` or `r.desc` (defaults to ascending).
-    ///
-    /// ## Note
-    ///
-    /// RethinkDB uses byte-wise ordering for `order_by` and does not support
-    /// Unicode collations; non-ASCII characters will be sorted by UTF-8 codepoint.
-    /// For more information on RethinkDB’s sorting order, read the section in
-    /// [ReQL data types](https://rethinkdb.com/docs/data-types/#sorting-order).
-    ///
-    ///
-    /// Sorting without an index requires the server to hold the sequence in memory,
-    /// and is limited to 100,000 documents
-    /// (or the setting of the `array_limit` option for [run](Self::run)).
-    /// Sorting with an index can be done on arbitrarily large tables,
-    /// or after a [between](Self::between) command using the same index.
-    /// This applies to both secondary indexes and the primary key
-    /// (e.g., `r.index("id")`).
-    ///
-    /// Sorting functions passed to `order_by` must be deterministic.
-    /// You cannot, for instance, order rows using the [random](crate::r::random) command.
-    /// Using a non-deterministic function with `order_by` will raise a `ReqlQueryLogicError`.
-    ///
     /// ## Examples
     ///
-    /// Order all the posts using the index `date`.
-    ///
-    /// ```
-    /// use neor::{r, Result};
+    /// Filter based on the presence of a value in an array.
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .order_by(r.index("date"))
-    ///         .run(&conn)
-    ///         .await?;
+    /// Given this schema for the `users` table.
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
+    /// ```text
+    /// {
+    ///     "name": String,
+    ///     "places_visited": Vec<String>,
     /// }
     /// ```
     ///
-    /// The index must either be the primary key or have
-    /// been previously created with [index_create](Self::index_create).
-    ///
-    /// ```ignore
-    /// r.table("posts").index_create("date").run(&conn)
-    /// ```
-    ///
-    /// ## Examples
-    ///
-    /// Order a sequence without an index.
+    /// Retrieve all users whose field `places_visited` contains `Cameroon`.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .get(1)
-    ///         .g("comments")
-    ///         .order_by("date")
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("places_visited").contains("Cameroon")))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3483,34 +3611,40 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// You can also select a descending ordering:
+    /// ## Examples
     ///
-    /// ```ignore
-    /// r.table("posts").get(1).g("comments").order_by(r.desc("date")).run(&conn)
-    /// ```
+    /// Filter based on nested fields.
     ///
-    /// If you’re doing ad-hoc analysis and know your table won’t have more then 100,000 elements
-    /// (or you’ve changed the setting of the `array_limit` option for [run](Self::run))
-    /// you can run `order_by` without an index:
+    /// Given this schema for the `users` table.
     ///
-    /// ```ignore
-    /// r.table("small_table").order_by("date").run(&conn)
+    /// ```text
+    /// {
+    ///     "id": String,
+    ///     "name": {
+    ///         "first": String,
+    ///         "middle": String,
+    ///         "last": String,
+    ///     },
+    /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// You can efficiently order using multiple fields by using a
-    /// [compound index](https://rethinkdb.com/docs/secondary-indexes/python/).
-    ///
-    /// Order by date and title.
+    /// Retrieve all users named “Moussa Ibrahim
+    /// (first name “Moussa”, last name “Ibrahim”),
+    /// with any middle name.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{args, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .order_by(r.index("date_and_title"))
+    ///     let response = r.table("users")
+    ///         .filter(json!({
+    ///             "name": {
+    ///                 "first": "Moussa",
+    ///                 "last": "Ibrahim",
+    ///             }
+    ///         }))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3520,32 +3654,21 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// The index must either be the primary key or have been
-    /// previously created with [index_create](Self::index_create).
-    ///
-    /// ```ignore
-    /// r.table("posts").index_create(
-    ///     "date_and_title",
-    ///     func!(|post| r.array([post.g("date"), post.g("title")]))
-    /// ).run(&conn)
-    /// ```
-    ///
-    /// **Note:** You cannot specify multiple orders in a compound index.
-    /// See [issue #2306](https://github.com/rethinkdb/rethinkdb/issues/2306)
-    /// to track progress.
-    ///
-    /// ## Examples
-    ///
-    /// If you have a sequence with fewer documents than the `array_limit`,
-    /// you can order it by multiple fields without an index.
+    /// If you want an exact match for a field that is an object,
+    /// you will have to use anonymous functions.
     ///
     /// ```
-    /// use neor::{args, r, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("small_table")
-    ///         .order_by(args!([r.expr("date"), r.desc("title")]))
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user
+    ///             .g("name")
+    ///             .g("first")
+    ///             .eq("Moussa")
+    ///             .and(user.g("name").g("last").eq("Ibrahim"))
+    ///         ))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3555,19 +3678,20 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Notice that an index ordering always has highest precedence.
-    /// The following query orders posts by date, and if multiple posts
-    /// were published on the same date, they will be ordered by title.
-    ///
     /// ```
-    /// use neor::{args, r, Result};
+    /// use neor::{args, func, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .order_by(args!("title", r.index("date")))
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user
+    ///             .g("name")
+    ///             .eq(r.expr(json!({
+    ///                 "first": "Moussa",
+    ///                 "last": "Ibrahim",
+    ///             })))
+    ///         ))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3577,40 +3701,56 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [get](Self::get)
+    /// - [get_all](Self::get_all)
+    /// - [between](Self::between)
+    pub fn filter(&self, args: impl filter::FilterArg) -> Self {
+        filter::new(args).with_parent(self)
+    }
+
+    /// Returns an inner join of two sequences.
     ///
-    /// You can use
-    /// [nested field](https://rethinkdb.com/docs/cookbook/python/#filtering-based-on-nested-fields)
-    /// syntax to sort on fields from subdocuments.
-    /// (You can also create indexes on nested fields using this syntax with `index_create`.)
+    /// # Command syntax
     ///
+    /// ```text
+    /// sequence.inner_join(other_sequence, predicate) → response
     /// ```
-    /// use neor::{func, r, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .order_by(func!(|user| user.g("group").g("id")))
-    ///         .run(&conn)
-    ///         .await?;
+    /// Where:
+    /// - other_sequence: [Command](crate::Command)
+    /// - predicate: [Func](crate::Func) | [Command](crate::Command),
+    ///   see [JoinPredicate](crate::cmd::inner_join::JoinPredicate)
+    /// - response: [Vec<JoinResponse<Left, Right>>](crate::types::JoinResponse)
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
+    /// # Description
+    ///
+    /// The returned sequence represents an intersection of the left-hand sequence
+    /// and the right-hand sequence: each row of the left-hand sequence will be
+    /// compared with each row of the right-hand sequence to find all pairs of rows
+    /// which satisfy the predicate. In most cases, you will want to follow the join
+    /// with [zip](Self::zip) to combine the left and right results.
+    ///
+    /// ```text
+    /// Note that `inner_join` is slower and much less efficient than using `concat_map`
+    /// with `get_all`. You should avoid using `inner_join` in commands when possible.
     /// ```
     ///
     /// ## Examples
     ///
-    /// You can efficiently order data on arbitrary expressions using indexes.
+    /// Return a list of all matchups between Marvel and DC heroes
+    /// in which the DC hero could beat the Marvel hero in a fight.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .order_by(r.index("votes"))
+    ///     let response = r.table("marvel")
+    ///         .inner_join(
+    ///             r.table("dc"),
+    ///             func!(|marvel, dc| marvel.g("strength").lt(dc.g("strength")))
+    ///         )
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3620,111 +3760,86 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// The index must have been previously created with index_create.
-    ///
-    /// ```ignore
-    /// r.table("posts").index_create("votes", func!(|post| post.g("upvotes") - post.g("downvotes")))
-    /// ```
+    /// (Compare this to an [outer_join](Self::outer_join) with
+    /// the same inputs and predicate, which would return a list
+    /// of **all** Marvel heroes along with any DC heroes with a higher strength.)
     ///
     /// ## Examples
     ///
-    /// If you have a sequence with fewer documents than the `array_limit`,
-    /// you can order it with an arbitrary function directly.
+    /// Match the heroes on a field chosen at run time, with a predicate
+    /// built beforehand.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::{func, r, Command, Result};
     ///
-    /// async fn example() -> Result<()> {
+    /// async fn example(field: &str) -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("small_table")
-    ///         .order_by(func!(|doc| doc.g("upvotes") - doc.g("downvotes")))
+    ///     let predicate: Command = func!(move |marvel, dc| marvel.g(field).eq(dc.g(field))).into();
+    ///     let response = r.table("marvel")
+    ///         .inner_join(r.table("dc"), predicate)
     ///         .run(&conn)
     ///         .await?;
     ///
     ///     assert!(response.is_some());
-    ///     
+    ///
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// You can also select a descending ordering:
-    ///
-    /// ```ignore
-    /// r.table("small_table").order_by(r.desc(func!(|doc| doc.g("upvotes") - doc.g("downvotes"))))
-    /// ```
-    ///
     /// # Related commands
-    /// - [skip](Self::skip)
-    /// - [limit](Self::limit)
-    /// - [slice](Self::slice)
-    pub fn order_by(&self, args: impl order_by::OrderByArg) -> Self {
-        order_by::new(args).with_parent(self)
+    /// - [eq_join](Self::eq_join)
+    /// - [outer_join](Self::outer_join)
+    /// - [zip](Self::zip)
+    pub fn inner_join(
+        &self,
+        other_sequence: Command,
+        predicate: impl inner_join::JoinPredicate,
+    ) -> Self {
+        inner_join::new(other_sequence, predicate).with_parent(self)
     }
 
-    /// Skip a number of elements from the head of the sequence.
+    /// Returns a left outer join of two sequences.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.skip(n) → stream
+    /// sequence.outer_join(other_sequence, predicate) → response
     /// ```
     ///
     /// Where:
-    /// - n: `u8, u16, ..., usize`
-    ///
-    /// ## Examples
-    ///
-    /// Only so many can fit in our Pantheon of heroes.
-    ///
-    /// ```
-    /// use neor::{r, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .order_by(r.index("age"))
-    ///         .skip(10)
-    ///         .run(&conn)
-    ///         .await?;
-    ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// - other_sequence: [Command](crate::Command)
+    /// - predicate: [Func](crate::Func) | [Command](crate::Command),
+    ///   see [JoinPredicate](crate::cmd::inner_join::JoinPredicate)
+    /// - response: [Vec<JoinResponse<Left, Right>>](crate::types::JoinResponse)
     ///
-    /// # Related commands
-    /// - [order_by](Self::order_by)
-    /// - [limit](Self::limit)
-    /// - [slice](Self::slice)
-    /// - [nth](Self::nth)
-    pub fn skip(&self, n: impl Into<CommandArg>) -> Self {
-        skip::new(n).with_parent(self)
-    }
-
-    /// End the sequence after the given numbers of elements.
+    /// # Description
     ///
-    /// # Command syntax
+    /// The returned sequence represents a union of the left-hand sequence and the
+    /// right-hand sequence: all documents in the left-hand sequence will be returned,
+    /// each matched with a document in the right-hand sequence if one satisfies the
+    /// predicate condition. In most cases, you will want to follow the join with
+    /// [zip](Self::zip) to combine the left and right results.
     ///
     /// ```text
-    /// sequence.limit(number) → stream
+    /// Note that `outer_join` is slower and much less efficient than using `concat_map`
+    /// with `get_all`. You should avoid using `outer_join` in commands when possible.
     /// ```
     ///
-    /// Where:
-    /// - number: `u8, u16, ..., usize`
-    ///
     /// ## Examples
     ///
-    /// Only so many can fit in our Pantheon of heroes.
+    /// Return a list of all Marvel heroes, paired with
+    /// any DC heroes who could beat them in a fight.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .order_by(r.index("age"))
-    ///         .limit(10)
+    ///     let response = r.table("marvel")
+    ///         .outer_join(
+    ///             r.table("dc"),
+    ///             func!(|marvel, dc| marvel.g("strength").lt(dc.g("strength")))
+    ///         )
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3734,68 +3849,86 @@ impl<'a> Command {
     /// }
     /// ```
     ///
+    /// (Compare this to an [inner_join](Self::inner_join) with
+    /// the same inputs and predicate, which would return a list
+    /// only of the matchups in which the DC hero has the higher strength.)
+    ///
     /// # Related commands
-    /// - [order_by](Self::order_by)
-    /// - [skip](Self::skip)
-    /// - [slice](Self::slice)
-    /// - [nth](Self::nth)
-    pub fn limit(&self, number: impl Into<CommandArg>) -> Self {
-        limit::new(number).with_parent(self)
+    /// - [eq_join](Self::eq_join)
+    /// - [inner_join](Self::inner_join)
+    /// - [zip](Self::zip)
+    pub fn outer_join(
+        &self,
+        other_sequence: Command,
+        predicate: impl inner_join::JoinPredicate,
+    ) -> Self {
+        outer_join::new(other_sequence, predicate).with_parent(self)
     }
 
-    /// Return the elements of a sequence within the specified range.
+    /// Join tables using a field or function on the left-hand sequence
+    /// matching primary keys or secondary indexes on the right-hand table.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// selection.slice(start_offset) → selection
-    /// selection.slice(args!(start_offset, options)) → selection
-    /// selection.slice(args!(start_offset, end_offset)) → selection
-    /// selection.slice(args!(start_offset, end_offset, options)) → selection
+    /// sequence.eq_join(args!(left_field, right_table)) → response
+    /// sequence.eq_join(args!(func, right_table)) → response
+    /// sequence.eq_join(args!(left_field, right_table, options)) → response
+    /// sequence.eq_join(args!(func, right_table, options)) → response
     /// ```
     ///
     /// Where:
-    /// - start_offset, end_offset: `i8, u8, ..., isize, usize` |
-    /// [Command](crate::Command)
-    /// - options: [SliceOption](crate::arguments::SliceOption)
+    /// - left_field: `impl Into<String>` | [Command](crate::Command)
+    /// - right_table: [Command](crate::Command)
+    /// - func: [Func](crate::Func)
+    /// - options: [EqJoinOption](crate::arguments::EqJoinOption)
+    /// - response: [Vec<JoinResponse<Left, Right>>](crate::types::JoinResponse)
     ///
     /// # Description
     ///
-    /// `slice` returns the range between `start_offset` and `end_offset`.
-    /// If only `start_offset` is specified, `slice` returns the range from
-    /// that index to the end of the sequence. Specify `left_bound` or `right_bound`
-    /// option as `Status::Open` or `Status::Closed` to indicate whether
-    /// to include that endpoint of the range by default:
-    /// `Status::Closed` returns that endpoint, while `Status::Open` does not.
-    /// By default, left_bound is `Status::Closed` and right_bound is `Status::Open`, so
-    /// the range `(10,13)` will return the tenth, eleventh and twelfth elements in the sequence.
+    /// `eq_join` is more efficient than other ReQL join types, and operates much faster.
+    /// Documents in the result set consist of pairs of left-hand and right-hand documents,
+    /// matched when the field on the left-hand side exists and is non-null and an entry
+    /// with that field’s value exists in the specified index on the right-hand side.
     ///
-    /// If `end_offset` is past the end of the sequence,
-    /// all elements from `start_offset` to the end of the sequence will be returned.
-    /// If `start_offset` is past the end of the sequence or `end_offset` is less
-    /// than `start_offset`, a zero-element sequence will be returned.
+    /// The result set of `eq_join` is a stream or array of
+    /// [JoinResponse<LeftDocument, RightDocument>](crate::types::JoinResponse).
+    /// Each object in the returned set will be an object of the form
+    /// `{ left: <LeftDocument>, right: <RightDocument> }`, where the values of `left` and
+    /// `right` will be the joined documents.
+    /// Use the [zip](Self::zip) command to merge the `left` and `right` fields together.
     ///
-    /// Negative `start_offset` and `end_offset` values are allowed with arrays;
-    /// in that case, the returned range counts back from the array’s end.
-    /// That is, the range `(-2)` returns the last two elements, and the range of `(2,-1)`
-    /// returns the second element through the next-to-last element of the range.
-    /// An error will be raised on a negative `start_offset` or `end_offset` with non-arrays.
-    /// (An `end_offset` of −1 is allowed with a stream if right_bound is closed;
-    /// this behaves as if no `end_offset` was specified.)
+    /// The left and right documents are deserialized by
+    /// [run_joined](Self::run_joined), e.g. `run_joined::<Player, Game>(&conn)`.
     ///
-    /// If `slice` is used with a [binary](crate::r::binary) object,
-    /// the indexes refer to byte positions within the object.
-    /// That is, the range `(10,20)` will refer to the 10th byte through the 19th byte.
+    /// The results from `eq_join` are, by default, not ordered.
     ///
-    /// With a string, `slice` behaves similarly, with the indexes referring to Unicode codepoints.
-    /// String indexes start at `0`.
-    /// (Note that [combining codepoints](https://en.wikipedia.org/wiki/Combining_character)
-    /// are counted separately.)
+    /// Suppose the players table contains these documents:
+    ///
+    /// ```text
+    /// [
+    ///     { "id": 1, "player": "Moussa", "game_id": 1 },
+    ///     { "id": 2, "player": "Fatima", "game_id": 3 },
+    ///     { "id": 3, "player": "Abessolo", "game_id": 2 },
+    ///     { "id": 4, "player": "Kamga", "game_id": 2 },
+    ///     { "id": 5, "player": "Malika", "game_id": 1 },
+    ///     { "id": 6, "player": "Ibrahim", "game_id": 3 }
+    /// ]
+    /// ```
+    ///
+    /// The games table contains these documents:
+    ///
+    /// [
+    ///     { "id": 1, "field": "Aurion" },
+    ///     { "id": 2, "field": "Adventures of Nyangi" },
+    ///     { "id": 3, "field": "Gazkar" }
+    /// ]
     ///
     /// ## Examples
     ///
-    /// Return the fourth, fifth and sixth youngest players.
-    /// (The youngest player is at index 0, so those are elements 3-5.)
+    /// Match players with the games they’ve played against one another.
+    ///
+    /// Join these tables using `game_id` on the player table and `id` on the games table:
     ///
     /// ```
     /// use neor::{args, r, Result};
@@ -3803,8 +3936,7 @@ impl<'a> Command {
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response = r.table("players")
-    ///         .order_by(r.index("age"))
-    ///         .slice(args!(3, 6))
+    ///         .eq_join(args!("game_id", r.table("games")))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3814,19 +3946,27 @@ impl<'a> Command {
     /// }
     /// ```
     ///
+    /// For more information, see
+    /// [Table joins in RethinkDB](https://rethinkdb.com/docs/table-joins/).
+    ///
     /// ## Examples
     ///
-    /// Return all but the top three playerss who have a red flag.
+    /// Use a secondary index on the right table rather than the primary key.
+    /// If players have a secondary index on their cities, we can get a list
+    /// of arenas with players in the same area.
     ///
     /// ```
+    /// use neor::arguments::EqJoinOption;
     /// use neor::{args, r, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response = r.table("players")
-    ///         .filter(json!({"flag": "red"}))
-    ///         .slice(3)
+    ///         .eq_join(args!(
+    ///             "city_id",
+    ///             r.table("arenas"),
+    ///             EqJoinOption::default().index("city_id")
+    ///         ))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3838,22 +3978,26 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Return the holders of tickets `X` through `Y`,
-    /// assuming tickets are numbered sequentially.
-    /// We want to include ticket `Y`.
+    /// Use a function instead of a field to join on a more complicated expression.
+    /// Suppose the players have lists of favorite games ranked in order in a field
+    /// such as "favorites": [3, 2, 1]. Get a list of players and their top favorite:
     ///
     /// ```
-    /// use neor::arguments::{SliceOption, Status};
-    /// use neor::{args, r, Result};
+    /// use neor::arguments::EqJoinOption;
+    /// use neor::{args, func, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
-    ///     let x = 3;
-    ///     let y = 6;
-    ///     let slice_options = SliceOption::default().right_bound(Status::Closed);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .order_by(r.index("ticket"))
-    ///         .slice(args!(x, y, slice_options))
+    ///     let response = r.table("players")
+    ///         .eq_join(args!(
+    ///             func!(|player| player.g("favorites").nth(0)),
+    ///             r.table("games")
+    ///         ))
+    ///         .without(json!([
+    ///             { "left": ["favorites", "game_id", "id"] },
+    ///             { "right": "id" }
+    ///         ]))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3863,92 +4007,126 @@ impl<'a> Command {
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [inner_join](Self::inner_join)
+    /// - [outer_join](Self::outer_join)
+    /// - [without](Self::without)
+    /// - [zip](Self::zip)
+    pub fn eq_join(&self, args: impl eq_join::EqJoinArg) -> Self {
+        eq_join::new(args).with_parent(self)
+    }
+
+    /// Used to ‘zip’ up the result of a join by merging the ‘right’
+    /// fields into ‘left’ fields of each member of the sequence.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// stream.zip() → stream
+    /// ```
+    ///
     /// ## Examples
     ///
-    /// Return the elements of an array from the second through
-    /// two from the end (that is, not including the last two).
+    /// ‘zips up’ the sequence by merging the left and right fields produced by a join.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<u8> = r.expr([0, 1, 2, 3, 4, 5])
-    ///         .slice(args!(2, -2))
+    ///     let response = r.table("posts")
+    ///         .eq_join(args!("user_id", r.table("users")))
+    ///         .zip()
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert_eq!(response, vec![2, 3]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [eq_join](Self::eq_join)
+    /// - [inner_join](Self::inner_join)
+    /// - [outer_join](Self::outer_join)
+    pub fn zip(&self) -> Self {
+        zip::new().with_parent(self)
+    }
+
+    /// Transform each element of one or more sequences
+    /// by applying a mapping function to them.
     ///
-    /// Return the thirds through fifth characters of a string.
+    /// # Command syntax
     ///
+    /// ```text
+    /// sequence.map(func) → stream
+    /// sequence.map(sequence, func) → stream
+    /// sequence.map(sequences, func) → stream
+    /// r.map(sequence, func) → stream
+    /// r.map(sequence, sequence, func) → stream
+    /// r.map(sequence, sequences, func) → stream
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    ///
+    /// Where:
+    /// - func: [Func](crate::Func)
+    /// - sequence: [Command](crate::Command)
+    /// - sequences: `impl IntoIterator<Item = Command>`
+    ///
+    /// # Description
+    ///
+    /// If `map` is run with two or more sequences, it will iterate
+    /// for as many items as there are in the shortest sequence.
+    ///
+    /// Note that `map` can only be applied to sequences, not single values.
+    /// If you wish to apply a function to a single value/selection (including an array),
+    /// use the [do_](Self::do_) command.
+    ///
+    /// ## Examples
+    ///
+    /// Return the first five squares.
+    ///
+    /// ```
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: String = r.expr("I love africa.")
-    ///         .slice(args!(-7, -1))
+    ///     let response: Vec<u8> = r.expr([1, 2, 3, 4, 5])
+    ///         .map(func!(|val| val.clone() * val))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert_eq!(response, "africa");
+    ///     assert_eq!(response, [1, 4, 9, 16, 25]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [order_by](Self::order_by)
-    /// - [skip](Self::skip)
-    /// - [limit](Self::limit)
-    /// - [nth](Self::nth)
-    pub fn slice(&self, args: impl slice::SliceArg) -> Self {
-        slice::new(args).with_parent(self)
-    }
-
-    /// Get the **nth** element of a sequence, counting from zero.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// sequence.nth(index) → object
-    /// ```
-    ///
-    /// Where:
-    /// - index: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// If the argument is negative, count from the last element.
-    ///
     /// ## Examples
     ///
-    /// Select the second element in the array.
+    /// Sum the elements of three sequences.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let sequence1 = r.expr([100, 200, 300, 400]);
+    ///     let sequence2 = r.expr([10, 20, 30, 40]);
+    ///     let sequence3 = r.expr([1, 2, 3, 4]);
+    ///
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.expr([1, 2, 3])
-    ///         .nth(1)
+    ///     let response: Vec<u32> = r.map(sequence1, args!(
+    ///             [sequence2, sequence3],
+    ///             func!(|val1, val2, val3| val1 + val2 + val3)
+    ///         ))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 2);
+    ///     assert_eq!(response, [111, 222, 333, 444]);
     ///     
     ///     Ok(())
     /// }
@@ -3956,16 +4134,26 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Select the bronze medalist from the competitors.
+    /// Rename a field when retrieving documents
+    /// using `map` and [merge](Self::merge).
+    ///
+    /// This example renames the field `id` to `user_id`
+    /// when retrieving documents from the table `users`.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .order_by(r.desc("score"))
-    ///         .nth(3)
+    ///     let response = r.table("users")
+    ///         .map(func!(|doc| {
+    ///             let mut user = HashMap::new();
+    ///             user.insert("user_id", doc.g("id"));
+    ///             
+    ///             doc.merge(r.hash_map(user)).without("id")
+    ///         }))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3977,16 +4165,22 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Select the last place competitors.
+    /// Assign every superhero an archenemy.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .order_by(r.desc("score"))
-    ///         .nth(-1)
+    ///     let response = r.table("heroes")
+    ///         .map(args!(r.table("villains"), func!(|hero, villain| {
+    ///             let mut villain_obj = HashMap::new();
+    ///             villain_obj.insert("villain", villain);
+    ///
+    ///             hero.merge(r.hash_map(villain_obj))
+    ///         })))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -3997,425 +4191,290 @@ impl<'a> Command {
     /// ```
     ///
     /// # Related commands
-    /// - [order_by](Self::order_by)
-    /// - [skip](Self::skip)
-    /// - [limit](Self::limit)
-    /// - [bracket](Self::bracket)
-    /// - [slice](Self::slice)
-    pub fn nth(&self, index: impl Into<CommandArg>) -> Self {
-        nth::new(index).with_parent(self)
+    /// - [concat_map](Self::concat_map)
+    /// - [reduce](Self::reduce)
+    /// - [do_](Self::do_)
+    pub fn map(&self, args: impl map::MapArg) -> Self {
+        map::new(args).with_parent(self)
     }
 
-    /// Get the indexes of an element in a sequence.
+    /// Transform each element of a sequence with a function
+    /// of the element and of its position.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.offsets_of(datum) → array
-    /// sequence.offsets_of(func!(...)) → array
+    /// sequence.map_with_index(func) → stream
+    /// array.map_with_index(func) → array
     /// ```
     ///
     /// Where:
-    /// - datum: `impl Serialize` | [Command](crate::Command) | [Func](crate::Func)
+    /// - func: [Func](crate::Func) of the element and of its index, from `0`
     ///
     /// # Description
     ///
-    /// If the argument is a predicate, get
-    /// the indexes of all elements matching it.
+    /// The sequence is mapped together with the infinite stream of
+    /// [range](crate::r::range), i.e. `sequence.map(args!(r.range(()), func))`,
+    /// so the elements, whatever their types, are numbered on the server.
+    /// The documents of a table have no order: use [order_by](Self::order_by)
+    /// first to number them in a known order.
     ///
     /// ## Examples
     ///
-    /// Find the position of the letter ‘c’.
+    /// Number the elements of an array.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{func, r, Converter, Result};
+    /// use serde_json::{json, Value};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Vec<usize> = r.expr(['a','b','c'])
-    ///         .offsets_of('c')
+    ///     let response: Vec<Value> = r.expr(json!(["a", 2, {"b": 3}]))
+    ///         .map_with_index(func!(|val, index| r.array([index, val])))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert_eq!(response.first(), Some(&2));
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
-    ///
-    /// ## Examples
-    ///
-    /// Find the popularity ranking of invisible heroes.
-    ///
-    /// ```
-    /// use neor::{func, r, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .union(r.table("dc"))
-    ///         .order_by("popularity")
-    ///         .offsets_of(func!(|hero| hero.g("superpowers").contains("invisibility")))
-    ///         .run(&conn)
-    ///         .await?;
+    ///     assert_eq!(response, [json!([0, "a"]), json!([1, 2]), json!([2, {"b": 3}])]);
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn offsets_of(&self, args: impl Into<CommandArg>) -> Self {
-        offsets_of::new(args).with_parent(self)
+    ///
+    /// # Related commands
+    /// - [map](Self::map)
+    /// - [range](crate::r::range)
+    pub fn map_with_index(&self, func: Func) -> Self {
+        self.map(Args((r.range(()), func)))
     }
 
-    /// Test if a sequence is empty.
+    /// Plucks one or more attributes from a sequence of objects, filtering
+    /// out any objects in the sequence that do not have the specified fields.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.is_empty() → bool
+    /// sequence.with_fields(selector) → stream
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Are there any documents in the simbad table?
+    /// Where:
+    /// - selector: `impl Into<String>` |
+    /// `impl IntoIterator<Item = impl Into<String>>` | [Command](crate::Command)
     ///
-    /// ```
-    /// use neor::{r, Converter, Result};
+    /// # Description
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("simbad")
-    ///         .is_empty()
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    /// Functionally, this is identical to [has_fields](Self::has_fields)
+    /// followed by [pluck](Self::pluck) on a sequence.
     ///
-    ///     assert!(response);
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// ## Examples
     ///
-    /// # Related commands
-    /// - [offsets_of](Self::offsets_of)
-    pub fn is_empty(&self) -> Self {
-        is_empty::new().with_parent(self)
-    }
-
-    /// Merge two or more sequences.
+    /// Get a list of users and their posts, excluding any users who have not made any posts.
     ///
-    /// # Command syntax
+    /// Existing table structure:
     ///
     /// ```text
-    /// stream.union(sequence) → stream
-    /// stream.union(sequences) → stream
-    /// stream.union(args!(sequence, options)) → stream
-    /// stream.union(args!(sequences, options)) → stream
+    /// [
+    ///     { "id": 1, "user": "bob", "email": "bob@foo.com", "posts": [ 1, 4, 5 ] },
+    ///     { "id": 2, "user": "george", "email": "george@foo.com" },
+    ///     { "id": 3, "user": "jane", "email": "jane@foo.com", "posts": [ 2, 3, 6 ] }
+    /// ]
     /// ```
     ///
-    /// Where:
-    /// - sequence: [Command](crate::Command)
-    /// - sequences: `impl IntoIterator<Command>`
-    /// - options: [UnionOption](crate::arguments::UnionOption)
-    ///
-    /// ## Examples
-    ///
-    /// Construct a stream of all characters.
+    /// Command and output:
     ///
     /// ```
     /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("simbad")
-    ///         .union(r.table("kirikou"))
+    ///     let response = r.table("users")
+    ///         .with_fields(["id", "user", "posts"])
     ///         .run(&conn)
     ///         .await?;
     ///
     ///     assert!(response.is_some());
+    ///
+    ///     // [
+    ///     //      { "id": 1, "user": "bob", "posts": [ 1, 4, 5 ] },
+    ///     //      { "id": 3, "user": "jane", "posts": [ 2, 3, 6 ] }
+    ///     // ]
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn union(&self, args: impl union::UnionArg) -> Self {
-        union::new(args).with_parent(self)
+    ///
+    /// # Related commands
+    /// - [has_fields](Self::has_fields)
+    /// - [pluck](Self::pluck)
+    /// - [without](Self::without)
+    pub fn with_fields(&self, fields: impl Into<CommandArg>) -> Self {
+        with_fields::new(fields).with_parent(self)
     }
 
-    /// Select a given number of elements from
-    /// a sequence with uniform random distribution.
+    /// Concatenate one or more elements into
+    /// a single sequence using a mapping function.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.sample(number) → selection
-    /// stream.sample(number) → array
-    /// array.sample(number) → array
+    /// stream.concat_map(func) → stream
     /// ```
     ///
     /// Where:
-    /// - number: `u8, u16, ..., usize`
+    /// - func: [Func](crate::Func)
     ///
     /// # Description
     ///
-    /// Select a given number of elements from a
-    /// sequence with uniform random distribution.
-    /// Selection is done without replacement.
-    ///
-    /// If the sequence has less than the requested
-    /// number of elements (i.e., calling `sample(10)`
-    /// on a sequence with only five elements), `sample`
-    /// will return the entire sequence in a random order.
-    ///
-    /// ## Examples
-    ///
-    /// Select 3 random heroes.
+    /// `concat_map` works in a similar fashion to [map](Self::map),
+    /// applying the given function to each element in a sequence,
+    /// but it will always return a single sequence.
+    /// If the mapping function returns a sequence,
+    /// `map` would produce a sequence of sequences:
     ///
+    /// ```ignore
+    /// r.expr([1, 2, 3]).map(func!(|x| r.array(x.clone(), x * 2))).run(&conn)
     /// ```
-    /// use neor::{r, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("simbad")
-    ///         .sample(3)
-    ///         .run(&conn)
-    ///         .await?;
+    /// Result :
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
+    /// ```text
+    /// [[1, 2], [2, 4], [3, 6]]
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Select and stratify 3 random heroes by belovedness.
+    /// Whereas `concat_map` with the same mapping
+    /// function would merge those sequences into one:
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("simbad")
-    ///         .group("belovedness")
-    ///         .sample(3)
+    ///     let response: Vec<u8> = r
+    ///         .expr([1, 2, 3])
+    ///         .concat_map(func!(|x| r.array([x.clone(), x * 2])))
     ///         .run(&conn)
-    ///         .await?;
-    ///
-    ///     assert!(response.is_some());
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///     
+    ///     assert!(response == vec![1, 2, 2, 4, 3, 6]);
+    ///
     ///     Ok(())
     /// }
     /// ```
-    pub fn sample(&self, number: impl Into<CommandArg>) -> Self {
-        sample::new(number).with_parent(self)
-    }
-
-    /// Takes a stream and partitions it into multiple
-    /// groups based on the fields or functions provided.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// sequence.group(field) → grouped_stream
-    /// sequence.group(func) → grouped_stream
-    /// sequence.group(args!(field, options)) → grouped_stream
-    /// sequence.group(args!(func, options)) → grouped_stream
-    /// r.group(sequence, field) → grouped_stream
-    /// r.group(sequence, func) → grouped_stream
-    /// r.group(sequence, args!(field, options)) → grouped_stream
-    /// r.group(sequence, args!(func, options)) → grouped_stream
-    /// ```
-    ///
-    /// Where:
-    /// - field: `&str | [&str; N]`
-    /// - func: [Func](crate::Func) | [Func; N]
-    /// - grouped_stream: [GroupedStream](crate::types::GroupedStream)
-    /// - sequence: [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// With the `multi` flag single documents can be assigned to multiple groups,
-    /// similar to the behavior of
-    /// [multi-indexes](https://rethinkdb.com/docs/secondary-indexes/javascript).
-    /// When `multi` is `true` and the grouping value is an array, documents
-    /// will be placed in each group that corresponds to the elements of the array.
-    /// If the array is empty the row will be ignored.
-    ///
-    /// Suppose that the table games has the following data:
     ///
-    /// ```text
-    /// [
-    ///     {id: 2, player: "Moussa", points: 15, class: "ranked"},
-    ///     {id: 5, player: "Fatou", points: 7, class: "free"},
-    ///     {id: 11, player: "Moussa", points: 10, class: "free"},
-    ///     {id: 12, player: "Fatou", points: 2, class: "free"}
-    /// ]
-    /// ```
+    /// The return value, array or stream, will be the same type as the input.
     ///
     /// ## Examples
     ///
-    /// Group games by player.
+    /// Construct a sequence of all monsters defeated by Marvel heroes.
+    /// The field “defeatedMonsters” is an array of one or more monster names.
     ///
     /// ```
-    /// use neor::types::{GroupedItem, GroupedStream};
-    /// use neor::{args, r, Converter, Result};
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// #[derive(Serialize, Deserialize, PartialEq, Eq)]
-    /// struct Player {
-    ///     id: u8,
-    ///     player: String,
-    ///     points: u8,
-    ///     class: String,
-    /// }
-    ///
-    /// impl Player {
-    ///     fn new(id: u8, player: &str, points: u8, class: &str) -> Self {
-    ///         Self {
-    ///             id,
-    ///             points,
-    ///             player: player.to_owned(),
-    ///             class: class.to_owned(),
-    ///         }
-    ///     }
-    /// }
+    /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let expected_data = vec![
-    ///         GroupedItem {
-    ///             group: String::from("Fatou"),
-    ///             values: vec![
-    ///                 Player::new(5, "Fatou", 7, "free"),
-    ///                 Player::new(12, "Fatou", 2, "free"),
-    ///             ]
-    ///         },
-    ///         GroupedItem {
-    ///             group: String::from("Moussa"),
-    ///             values: vec![
-    ///                 Player::new(2, "Moussa", 15, "ranked"),
-    ///                 Player::new(11, "Moussa", 10, "free"),
-    ///             ]
-    ///         },
-    ///     ];
     ///     let conn = r.connection().connect().await?;
-    ///     let response: GroupedStream<String, Player> = r.table("games")
-    ///         .group("player")
+    ///     let response = r.table("marvel")
+    ///         .concat_map(func!(|hero| hero.g("defeatedMonsters")))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response.collect() == expected_data);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [ungroup](Self::ungroup)
     /// - [map](Self::map)
     /// - [reduce](Self::reduce)
-    /// - [count](Self::count)
-    /// - [sum](Self::sum)
-    /// - [avg](Self::avg)
-    /// - [min](Self::min)
-    /// - [max](Self::max)
-    pub fn group(&self, args: impl group::GroupArg) -> Self {
-        group::new(args).with_parent(self)
+    pub fn concat_map(&self, func: Func) -> Command {
+        concat_map::new(func).with_parent(self)
     }
 
-    /// Takes a grouped stream or grouped data and turns it
-    /// into an array of objects representing the groups.
+    /// Sort the sequence by document values of the given key(s).
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// grouped_stream.ungroup() → array
-    /// grouped_data.ungroup() → array
+    /// table.order_by(index) → table_slice
+    /// table.order_by(args!(predicate, index)) → table_slice
+    /// sequence.order_by(predicate) → array
+    /// sequence.order_by(predicates) → array
     /// ```
     ///
     /// Where:
-    /// - grouped_stream, grouped_data: [Command](crate::Command)
+    /// - index: [index](crate::r::index)
+    /// - predicate: `impl Into<String>` | [Func](crate::Func) | [Command](crate::Command)
+    /// - predicates: `[predicate; N]`
     ///
     /// # Description
     ///
-    /// Any commands chained after `ungroup` will operate on this array,
-    /// rather than operating on each group individually. This is useful
-    /// if you want to e.g. order the groups by the value of their reduction.
+    /// To specify the ordering, wrap the attribute with
+    /// either `r.asc` or `r.desc` (defaults to ascending).
     ///
-    /// The format of the array returned by `ungroup` is the same as
-    /// the default native format of grouped data in the javascript driver and data explorer.
+    /// ## Note
     ///
-    /// Suppose that the table games has the following data:
+    /// RethinkDB uses byte-wise ordering for `order_by` and does not support
+    /// Unicode collations; non-ASCII characters will be sorted by UTF-8 codepoint.
+    /// For more information on RethinkDB’s sorting order, read the section in
+    /// [ReQL data types](https://rethinkdb.com/docs/data-types/#sorting-order).
     ///
-    /// ```text
-    /// [
-    ///     {id: 2, player: "Moussa", points: 15, type: "ranked"},
-    ///     {id: 5, player: "Fatou", points: 7, type: "free"},
-    ///     {id: 11, player: "Ibrahim", points: 10, type: "free"},
-    ///     {id: 12, player: "Abess", points: 2, type: "free"}
-    /// ]
-    /// ```
+    ///
+    /// Sorting without an index requires the server to hold the sequence in memory,
+    /// and is limited to 100,000 documents
+    /// (or the setting of the `array_limit` option for [run](Self::run)).
+    /// Sorting with an index can be done on arbitrarily large tables,
+    /// or after a [between](Self::between) command using the same index.
+    /// This applies to both secondary indexes and the primary key
+    /// (e.g., `r.index("id")`).
+    ///
+    /// Sorting functions passed to `order_by` must be deterministic.
+    /// You cannot, for instance, order rows using the [random](crate::r::random) command.
+    /// Using a non-deterministic function with `order_by` will raise a `ReqlQueryLogicError`.
     ///
     /// ## Examples
     ///
-    /// What is the maximum number of points scored by each player, with the highest scorers first?
+    /// Order all the posts using the index `date`.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
-    /// use serde_json::json;
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let expected_data = json!([
-    ///         {
-    ///             "group": "Bob",
-    ///             "reduction": 15
-    ///         },
-    ///         {
-    ///             "group": "Alice",
-    ///             "reduction": 7
-    ///         }
-    ///     ]);
-    ///     let response = r.table("games")
-    ///         .group("player")
-    ///         .max("points")
-    ///         .g("points")
-    ///         .ungroup()
-    ///         .order_by(r.desc("reduction"))
+    ///     let response = r.table("posts")
+    ///         .order_by(r.index("date"))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap();
+    ///         .await?;
     ///
-    ///     assert!(response == expected_data);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// A shorter way to execute this query is to use [count](Self::count).
+    /// The index must either be the primary key or have
+    /// been previously created with [index_create](Self::index_create).
+    ///
+    /// ```ignore
+    /// r.table("posts").index_create("date").run(&conn)
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Suppose that each `post` has a field `comments` that is an array of comments.
-    /// Return the maximum number comments per post.
+    /// Order a sequence without an index.
     ///
     /// ```
-    /// use neor::{args, func, r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response = r.table("posts")
-    ///         .map(func!(|post| post.g("comments").count(())))
-    ///         .reduce(func!(|left, right| r.branch(
-    ///             left.gt(&right),
-    ///             args!(left, right)
-    ///         )))
-    ///         .default(0)
+    ///         .get(1)
+    ///         .g("comments")
+    ///         .order_by("date")
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -4425,60 +4484,69 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// A shorter way to execute this query is to use [max](Self::max).
+    /// You can also select a descending ordering:
     ///
-    /// # Related commands
-    /// - [group](Self::group)
-    pub fn ungroup(&self) -> Self {
-        ungroup::new().with_parent(self)
-    }
-
-    /// Produce a single value from a sequence through
-    /// repeated application of a reduction function.
+    /// ```ignore
+    /// r.table("posts").get(1).g("comments").order_by(r.desc("date")).run(&conn)
+    /// ```
     ///
-    /// # Command syntax
+    /// If you’re doing ad-hoc analysis and know your table won’t have more then 100,000 elements
+    /// (or you’ve changed the setting of the `array_limit` option for [run](Self::run))
+    /// you can run `order_by` without an index:
     ///
-    /// ```text
-    /// sequence.reduce(func) → value
+    /// ```ignore
+    /// r.table("small_table").order_by("date").run(&conn)
     /// ```
     ///
-    /// Where:
-    /// - func: [Func](crate::Func)
-    /// - sequence: [Command](crate::Command)
+    /// ## Examples
     ///
-    /// # Description
+    /// You can efficiently order using multiple fields by using a
+    /// [compound index](https://rethinkdb.com/docs/secondary-indexes/python/).
     ///
-    /// The reduction function can be called on:
+    /// Order by date and title.
     ///
-    /// - two elements of the sequence
-    /// - one element of the sequence and one result of a previous reduction
-    /// - two results of previous reductions
+    /// ```
+    /// use neor::{r, Result};
     ///
-    /// The reduction function can be called on the results of
-    /// two previous reductions because the `reduce` command is
-    /// distributed and parallelized across shards and CPU cores.
-    /// A common mistaken when using the `reduce` command is to
-    /// suppose that the reduction is executed from left to right.
-    /// [Read the map-reduce in RethinkDB](https://rethinkdb.com/docs/map-reduce/)
-    /// article to see an example.
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("posts")
+    ///         .order_by(r.index("date_and_title"))
+    ///         .run(&conn)
+    ///         .await?;
     ///
-    /// If the sequence is empty, the server will produce a
-    /// `ReqlRuntimeError` that can be caught with default.
-    /// If the sequence has only one element, the first element will be returned.
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// The index must either be the primary key or have been
+    /// previously created with [index_create](Self::index_create).
+    ///
+    /// ```ignore
+    /// r.table("posts").index_create(
+    ///     "date_and_title",
+    ///     func!(|post| r.array([post.g("date"), post.g("title")]))
+    /// ).run(&conn)
+    /// ```
+    ///
+    /// **Note:** You cannot specify multiple orders in a compound index.
+    /// See [issue #2306](https://github.com/rethinkdb/rethinkdb/issues/2306)
+    /// to track progress.
     ///
     /// ## Examples
     ///
-    /// Return the number of documents in the table posts.
+    /// If you have a sequence with fewer documents than the `array_limit`,
+    /// you can order it by multiple fields without an index.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .map(func!(|| r.expr(1)))
-    ///         .reduce(func!(|left, right| left + right))
-    ///         .default(0)
+    ///     let response = r.table("small_table")
+    ///         .order_by(args!([r.expr("date"), r.desc("title")]))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -4488,25 +4556,19 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// A shorter way to execute this query is to use [count](Self::count).
-    ///
     /// ## Examples
     ///
-    /// Suppose that each `post` has a field `comments` that is an array of comments.
-    /// Return the maximum number comments per post.
+    /// Notice that an index ordering always has highest precedence.
+    /// The following query orders posts by date, and if multiple posts
+    /// were published on the same date, they will be ordered by title.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///     let response = r.table("posts")
-    ///         .map(func!(|post| post.g("comments").count(())))
-    ///         .reduce(func!(|left, right| r.branch(
-    ///             left.gt(&right),
-    ///             args!(left, right)
-    ///         )))
-    ///         .default(0)
+    ///         .order_by(args!("title", r.index("date")))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -4516,68 +4578,67 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// A shorter way to execute this query is to use [max](Self::max).
+    /// ## Examples
     ///
-    /// # Related commands
-    /// - [group](Self::group)
-    /// - [map](Self::map)
-    /// - [concat_map](Self::concat_map)
-    /// - [sum](Self::sum)
-    /// - [avg](Self::avg)
-    /// - [min](Self::min)
-    /// - [max](Self::max)
-    pub fn reduce(&self, func: Func) -> Self {
-        reduce::new(func).with_parent(self)
-    }
-
-    /// Apply a function to a sequence in order,
-    /// maintaining state via an accumulator.
+    /// You can use
+    /// [nested field](https://rethinkdb.com/docs/cookbook/python/#filtering-based-on-nested-fields)
+    /// syntax to sort on fields from subdocuments.
+    /// (You can also create indexes on nested fields using this syntax with `index_create`.)
     ///
-    /// # Command syntax
+    /// ```
+    /// use neor::{func, r, Result};
     ///
-    /// ```text
-    /// sequence.fold(base, func) → value
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .order_by(func!(|user| user.g("group").g("id")))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
     /// ```
     ///
-    /// Where:
-    /// - base: `impl Serialize` | [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - sequence: [Command](crate::Command)
+    /// ## Examples
     ///
-    /// # Description
+    /// You can efficiently order data on arbitrary expressions using indexes.
     ///
-    /// The `fold` command returns either a single value or a new sequence.
+    /// ```
+    /// use neor::{r, Result};
     ///
-    /// In its first form, `fold` operates like [reduce](Self::reduce), returning a value
-    /// by applying a combining function to each element in a sequence.
-    /// The combining function takes two parameters: the previous reduction
-    /// result (the accumulator) and the current element. However, `fold` has
-    /// the following differences from `reduce`:
-    /// - it is guaranteed to proceed through the sequence from first element to last.
-    /// - it passes an initial base value to the function with the first element in
-    /// place of the previous reduction result.
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("posts")
+    ///         .order_by(r.index("votes"))
+    ///         .run(&conn)
+    ///         .await?;
     ///
-    /// ```text
-    /// combining_function(accumulator | base, element) → new_accumulator
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// The index must have been previously created with index_create.
+    ///
+    /// ```ignore
+    /// r.table("posts").index_create("votes", func!(|post| post.g("upvotes") - post.g("downvotes")))
     /// ```
     ///
     /// ## Examples
     ///
-    /// Concatenate words from a list.
+    /// If you have a sequence with fewer documents than the `array_limit`,
+    /// you can order it with an arbitrary function directly.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("words")
-    ///         .order_by("id")
-    ///         .fold(
-    ///             "",
-    ///             func!(|acc, word| acc.clone()
-    ///                 + r.branch(acc.eq(""), args!("", ", "))
-    ///                 + word),
-    ///         )
+    ///     let response = r.table("small_table")
+    ///         .order_by(func!(|doc| doc.g("upvotes") - doc.g("downvotes")))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -4587,60 +4648,43 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// (This example could be implemented with `reduce`,
-    /// but `fold` will preserve the order when `words` is
-    /// a RethinkDB table or other stream, which is
-    /// not guaranteed with `reduce`.)
+    /// You can also select a descending ordering:
+    ///
+    /// ```ignore
+    /// r.table("small_table").order_by(r.desc(func!(|doc| doc.g("upvotes") - doc.g("downvotes"))))
+    /// ```
     ///
     /// # Related commands
-    /// - [reduce](Self::reduce)
-    /// - [concat_map](Self::concat_map)
-    pub fn fold(&self, base: impl Into<CommandArg>, func: Func) -> Self {
-        fold::new(base, func).with_parent(self)
+    /// - [skip](Self::skip)
+    /// - [limit](Self::limit)
+    /// - [slice](Self::slice)
+    pub fn order_by(&self, args: impl order_by::OrderByArg) -> Self {
+        order_by::new(args).with_parent(self)
     }
 
-    /// Count the number of elements in sequence or key/value pairs in an object,
-    /// or returns the size of a string or binary object.
+    /// Skip a number of elements from the head of the sequence.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// binary.count(()) → number
-    /// string.count(()) → number
-    /// object.count(()) → number
-    /// sequence.count(()) → number
-    /// sequence.count(args!(value)) → number
-    /// sequence.count(func) → number
-    /// r.count(query_cmd) → number
-    /// r.count(query_cmd, args!(value)) → number
-    /// r.count(query_cmd, func) → number
+    /// sequence.skip(n) → stream
     /// ```
     ///
     /// Where:
-    /// - value: `impl Serialize`
-    /// - func: [Func](crate::Func)
-    /// - sequence, binary, string, object, query_cmd: [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// When `count` is called on a sequence with a predicate value or function,
-    /// it returns the number of elements in the sequence equal to that value or
-    /// where the function returns `true`. On a [binary](crate::r::binary) object, `count`
-    /// returns the size of the object in bytes; on strings, `count` returns the string’s length.
-    /// This is determined by counting the number of Unicode codepoints in the string,
-    /// counting combining codepoints separately.
+    /// - n: `u8, u16, ..., usize` | [Command](crate::Command)
     ///
     /// ## Examples
     ///
-    /// Count the number of users.
+    /// Only so many can fit in our Pantheon of heroes.
     ///
     /// ```
     /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .count(())
+    ///     let response = r.table("players")
+    ///         .order_by(r.index("age"))
+    ///         .skip(10)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -4650,18 +4694,38 @@ impl<'a> Command {
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [order_by](Self::order_by)
+    /// - [limit](Self::limit)
+    /// - [slice](Self::slice)
+    /// - [nth](Self::nth)
+    pub fn skip(&self, n: impl Into<CommandArg>) -> Self {
+        skip::new(n).with_parent(self)
+    }
+
+    /// End the sequence after the given numbers of elements.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// sequence.limit(number) → stream
+    /// ```
+    ///
+    /// Where:
+    /// - number: `u8, u16, ..., usize` | [Command](crate::Command)
+    ///
     /// ## Examples
     ///
-    /// Count the number of 18 year old users.
+    /// Only so many can fit in our Pantheon of heroes.
     ///
     /// ```
-    /// use neor::{args, r, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .g("age")
-    ///         .count(args!(18))
+    ///     let response = r.table("players")
+    ///         .order_by(r.index("age"))
+    ///         .limit(10)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -4673,42 +4737,100 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Count the number of users over 18.
+    /// Read the number of results from a document.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .g("age")
-    ///         .count(func!(|age| age.gt(18)))
+    ///     let response = r.table("players")
+    ///         .order_by(r.index("age"))
+    ///         .limit(r.table("config").get("page_size").g("value"))
     ///         .run(&conn)
     ///         .await?;
     ///
     ///     assert!(response.is_some());
-    ///     
+    ///
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [order_by](Self::order_by)
+    /// - [skip](Self::skip)
+    /// - [slice](Self::slice)
+    /// - [nth](Self::nth)
+    pub fn limit(&self, number: impl Into<CommandArg>) -> Self {
+        limit::new(number).with_parent(self)
+    }
+
+    /// Return the elements of a sequence within the specified range.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// selection.slice(start_offset) → selection
+    /// selection.slice(args!(start_offset, options)) → selection
+    /// selection.slice(args!(start_offset, end_offset)) → selection
+    /// selection.slice(args!(start_offset, end_offset, options)) → selection
+    /// ```
+    ///
+    /// Where:
+    /// - start_offset, end_offset: `i8, u8, ..., isize, usize` |
+    /// [Command](crate::Command)
+    /// - options: [SliceOption](crate::arguments::SliceOption)
+    ///
+    /// # Description
+    ///
+    /// `slice` returns the range between `start_offset` and `end_offset`.
+    /// If only `start_offset` is specified, `slice` returns the range from
+    /// that index to the end of the sequence. Specify `left_bound` or `right_bound`
+    /// option as `Status::Open` or `Status::Closed` to indicate whether
+    /// to include that endpoint of the range by default:
+    /// `Status::Closed` returns that endpoint, while `Status::Open` does not.
+    /// By default, left_bound is `Status::Closed` and right_bound is `Status::Open`, so
+    /// the range `(10,13)` will return the tenth, eleventh and twelfth elements in the sequence.
+    ///
+    /// If `end_offset` is past the end of the sequence,
+    /// all elements from `start_offset` to the end of the sequence will be returned.
+    /// If `start_offset` is past the end of the sequence or `end_offset` is less
+    /// than `start_offset`, a zero-element sequence will be returned.
+    ///
+    /// Negative `start_offset` and `end_offset` values are allowed with arrays;
+    /// in that case, the returned range counts back from the array’s end.
+    /// That is, the range `(-2)` returns the last two elements, and the range of `(2,-1)`
+    /// returns the second element through the next-to-last element of the range.
+    /// An error will be raised on a negative `start_offset` or `end_offset` with non-arrays.
+    /// (An `end_offset` of −1 is allowed with a stream if right_bound is closed;
+    /// this behaves as if no `end_offset` was specified.)
+    ///
+    /// If `slice` is used with a [binary](crate::r::binary) object,
+    /// the indexes refer to byte positions within the object.
+    /// That is, the range `(10,20)` will refer to the 10th byte through the 19th byte.
+    ///
+    /// With a string, `slice` behaves similarly, with the indexes referring to Unicode codepoints.
+    /// String indexes start at `0`.
+    /// (Note that [combining codepoints](https://en.wikipedia.org/wiki/Combining_character)
+    /// are counted separately.)
+    ///
     /// ## Examples
     ///
-    /// Return the length of a Unicode string.
+    /// Return the fourth, fifth and sixth youngest players.
+    /// (The youngest player is at index 0, so those are elements 3-5.)
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: usize = r.expr("こんにちは")
-    ///         .count(())
+    ///     let response = r.table("players")
+    ///         .order_by(r.index("age"))
+    ///         .slice(args!(3, 6))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == 5);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
@@ -4716,83 +4838,48 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Return the length of an array.
+    /// Return all but the top three playerss who have a red flag.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{args, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: usize = r.expr(['0','1','2'])
-    ///         .count(())
+    ///     let response = r.table("players")
+    ///         .filter(json!({"flag": "red"}))
+    ///         .slice(3)
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == 3);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [map](Self::map)
-    /// - [reduce](Self::reduce)
-    /// - [sum](Self::sum)
-    /// - [avg](Self::avg)
-    /// - [min](Self::min)
-    /// - [max](Self::max)
-    /// - [group](Self::group)
-    pub fn count(&self, args: impl count::CountArg) -> Self {
-        count::new(args).with_parent(self)
-    }
-
-    /// Sum all the elements of sequence.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// sequence.sum(()) → number
-    /// sequence.sum(field) → number
-    /// sequence.sum(func) → number
-    /// r.sum(sequence) → number
-    /// r.sum(sequence, field) → number
-    /// r.sum(sequence, func) → number
-    /// ```
-    ///
-    /// Where:
-    /// - field: `&str` | `String` | [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - sequence: [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// If called with a field name, sums all the values of that field in
-    /// the sequence, skipping elements of the sequence that lack that field.
-    /// If called with a function, calls that function on every element of the
-    /// sequence and sums the results, skipping elements of the sequence
-    /// where that function returns `None` or non-existence error.
-    ///
-    /// Returns `0` when called on an empty sequence.
-    ///
     /// ## Examples
     ///
-    /// What's 3 + 5 + 7?
+    /// Return the holders of tickets `X` through `Y`,
+    /// assuming tickets are numbered sequentially.
+    /// We want to include ticket `Y`.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::arguments::{SliceOption, Status};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
+    ///     let x = 3;
+    ///     let y = 6;
+    ///     let slice_options = SliceOption::default().right_bound(Status::Closed);
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.expr([3, 5, 7])
-    ///         .sum(())
+    ///     let response = r.table("users")
+    ///         .order_by(r.index("ticket"))
+    ///         .slice(args!(x, y, slice_options))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == 15);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
@@ -4800,19 +4887,22 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// How many points have been scored across all games?
+    /// Return the elements of an array from the second through
+    /// two from the end (that is, not including the last two).
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("games")
-    ///         .sum("points")
+    ///     let response: Vec<u8> = r.expr([0, 1, 2, 3, 4, 5])
+    ///         .slice(args!(2, -2))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert_eq!(response, vec![2, 3]);
     ///     
     ///     Ok(())
     /// }
@@ -4820,82 +4910,67 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// How many points have been scored across all games, counting bonus points?
+    /// Return the thirds through fifth characters of a string.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("games")
-    ///         .sum(func!(|game| game.g("points") + game.g("bonus_points")))
+    ///     let response: String = r.expr("I love africa.")
+    ///         .slice(args!(-7, -1))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert_eq!(response, "africa");
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [map](Self::map)
-    /// - [reduce](Self::reduce)
-    /// - [count](Self::count)
-    /// - [avg](Self::avg)
-    /// - [min](Self::min)
-    /// - [max](Self::max)
-    /// - [group](Self::group)
-    pub fn sum(&self, args: impl sum::SumArg) -> Self {
-        sum::new(args).with_parent(self)
+    /// - [order_by](Self::order_by)
+    /// - [skip](Self::skip)
+    /// - [limit](Self::limit)
+    /// - [nth](Self::nth)
+    pub fn slice(&self, args: impl slice::SliceArg) -> Self {
+        slice::new(args).with_parent(self)
     }
 
-    /// Averages all the elements of sequence.
+    /// Get the **nth** element of a sequence, counting from zero.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.avg(()) → Option<f64>
-    /// sequence.avg(field) → Option<f64>
-    /// sequence.avg(func) → Option<f64>
-    /// r.avg(sequence) → Option<f64>
-    /// r.avg(sequence, field) → Option<f64>
-    /// r.avg(sequence, func) → Option<f64>
+    /// sequence.nth(index) → object
     /// ```
     ///
     /// Where:
-    /// - field: `&str` | `String` | [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - sequence: [Command](crate::Command)
+    /// - index: `i8, u8, ..., isize, usize` | [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// If called with a field name, averages all the values of that field in
-    /// the sequence, skipping elements of the sequence that lack that field.
-    /// If called with a function, calls that function on every element of the
-    /// sequence and averages the results, skipping elements of the sequence
-    /// where that function returns `None` or non-existence error.
-    ///
-    /// Produces a non-existence error when called on an empty sequence.
-    /// You can handle this case with `default`.
+    /// If the argument is negative, count from the last element.
     ///
     /// ## Examples
     ///
-    /// What's the average of 3, 5 and 7?
+    /// Select the second element in the array.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: Option<f64> = r.expr([3, 5, 7])
-    ///         .avg(())
+    ///     let response: u8 = r.expr([1, 2, 3])
+    ///         .nth(1)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == Some(5.));
+    ///     assert!(response == 2);
     ///     
     ///     Ok(())
     /// }
@@ -4903,15 +4978,16 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// What's the average number of points scored in a games?
+    /// Select the bronze medalist from the competitors.
     ///
     /// ```
     /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("games")
-    ///         .avg("points")
+    ///     let response = r.table("players")
+    ///         .order_by(r.desc("score"))
+    ///         .nth(3)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -4923,15 +4999,16 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// What's the average number of points scored in a games, counting bonus points?
+    /// Select the last place competitors.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("games")
-    ///         .avg(func!(|game| game.g("points") + game.g("bonus_points")))
+    ///     let response = r.table("players")
+    ///         .order_by(r.desc("score"))
+    ///         .nth(-1)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -4942,92 +5019,49 @@ impl<'a> Command {
     /// ```
     ///
     /// # Related commands
-    /// - [map](Self::map)
-    /// - [reduce](Self::reduce)
-    /// - [count](Self::count)
-    /// - [sum](Self::sum)
-    /// - [min](Self::min)
-    /// - [max](Self::max)
-    /// - [group](Self::group)
-    pub fn avg(&self, args: impl avg::AvgArg) -> Self {
-        avg::new(args).with_parent(self)
-    }
+    /// - [order_by](Self::order_by)
+    /// - [skip](Self::skip)
+    /// - [limit](Self::limit)
+    /// - [bracket](Self::bracket)
+    /// - [slice](Self::slice)
+    pub fn nth(&self, index: impl Into<CommandArg>) -> Self {
+        nth::new(index).with_parent(self)
+    }
 
-    /// Finds the minimum element of a sequence.
+    /// Get the indexes of an element in a sequence.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.min(()) → element
-    /// sequence.min(field) → element
-    /// sequence.min(func) → element
-    /// sequence.min(options) → element
-    /// r.min(sequence) → element
-    /// r.min(sequence, field) → element
-    /// r.min(sequence, func) → element
-    /// r.min(sequence, options) → element
+    /// sequence.offsets_of(datum) → array
+    /// sequence.offsets_of(func!(...)) → array
     /// ```
     ///
     /// Where:
-    /// - field: `&str` | `String` | [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - options: [MinOption](crate::arguments::MinOption)
-    /// - sequence: [Command](crate::Command)
+    /// - datum: `impl Serialize` | [Command](crate::Command) | [Func](crate::Func)
     ///
     /// # Description
     ///
-    /// The `min` command can be called with:
-    /// - a `field name`, to return the element of the sequence
-    /// with the largest value in that field;
-    /// - a `function`, to apply the function to every element within the sequence
-    /// and return the element which returns the largest value from the function,
-    /// ignoring any elements where the function produces a non-existence error;
-    /// - an `index` (the primary key or a secondary index), to return the element
-    /// of the sequence with the largest value in that index;
-    ///
-    /// For more information on RethinkDB’s sorting order, read the section in
-    /// [ReQL data types](https://rethinkdb.com/docs/data-types/#sorting-order).
-    ///
-    /// Calling `min` on an empty sequence will throw a non-existence error;
-    /// this can be handled using the [default](Self::default) command.
+    /// If the argument is a predicate, get
+    /// the indexes of all elements matching it.
     ///
     /// ## Examples
     ///
-    /// Return the minimum value in the list [3, 5, 7].
+    /// Find the position of the letter ‘c’.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.expr([3, 5, 7])
-    ///         .min(())
+    ///     let response: Vec<usize> = r.expr(['a','b','c'])
+    ///         .offsets_of('c')
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 3);
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
-    ///
-    /// ## Examples
-    ///
-    /// Return the user who has scored the fewest points.
-    ///
-    /// ```
-    /// use neor::{args, r, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .min("points")
-    ///         .run(&conn)
-    ///         .await?;
-    ///
-    ///     assert!(response.is_some());
+    ///     assert_eq!(response.first(), Some(&2));
     ///     
     ///     Ok(())
     /// }
@@ -5035,16 +5069,17 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// The same as above, but using a secondary index on the `points` field.
+    /// Find the popularity ranking of invisible heroes.
     ///
     /// ```
-    /// use neor::arguments::MinOption;
-    /// use neor::{args, r, Result};
+    /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .min(MinOption::default().index("points"))
+    ///     let response = r.table("marvel")
+    ///         .union(r.table("dc"))
+    ///         .order_by("popularity")
+    ///         .offsets_of(func!(|hero| hero.g("superpowers").contains("invisibility")))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5053,156 +5088,121 @@ impl<'a> Command {
     ///     Ok(())
     /// }
     /// ```
+    pub fn offsets_of(&self, args: impl Into<CommandArg>) -> Self {
+        offsets_of::new(args).with_parent(self)
+    }
+
+    /// Test if a sequence is empty.
     ///
-    /// ## Examples
-    ///
-    /// Return the user who has scored the fewest points,
-    /// adding in bonus points from a separate field using a function.
-    ///
-    /// ```
-    /// use neor::{args, func, r, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .min(func!(|user| user.g("points") + user.g("bonus_points")))
-    ///         .run(&conn)
-    ///         .await?;
+    /// # Command syntax
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
+    /// ```text
+    /// sequence.is_empty() → bool
     /// ```
     ///
     /// ## Examples
     ///
-    /// Return the highest number of points any user has ever scored.
-    /// This returns the value of that `points` field, not a document.
+    /// Are there any documents in the simbad table?
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.table("users")
-    ///         .min("points")
-    ///         .g("points")
+    ///     let response: bool = r.table("simbad")
+    ///         .is_empty()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 2);
+    ///     assert!(response);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [map](Self::map)
-    /// - [reduce](Self::reduce)
-    /// - [count](Self::count)
-    /// - [sum](Self::sum)
-    /// - [avg](Self::avg)
-    /// - [max](Self::max)
-    /// - [group](Self::group)
-    pub fn min(&self, args: impl min::MinArg) -> Self {
-        min::new(args).with_parent(self)
+    /// - [offsets_of](Self::offsets_of)
+    pub fn is_empty(&self) -> Self {
+        is_empty::new().with_parent(self)
     }
 
-    /// Finds the maximum element of a sequence.
+    /// Merge two or more sequences.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.max(()) → element
-    /// sequence.max(field) → element
-    /// sequence.max(func) → element
-    /// sequence.max(options) → element
-    /// r.max(sequence) → element
-    /// r.max(sequence, field) → element
-    /// r.max(sequence, func) → element
-    /// r.max(sequence, options) → element
+    /// stream.union(sequence) → stream
+    /// stream.union(sequences) → stream
+    /// stream.union(args!(sequence, options)) → stream
+    /// stream.union(args!(sequences, options)) → stream
     /// ```
     ///
     /// Where:
-    /// - field: `&str` | `String` | [Command](crate::Command)
-    /// - func: [Func](crate::Func)
-    /// - options: [MaxOption](crate::arguments::MaxOption)
     /// - sequence: [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// The `max` command can be called with:
-    /// - a `field name`, to return the element of the sequence
-    /// with the largest value in that field;
-    /// - a `function`, to apply the function to every element within the sequence
-    /// and return the element which returns the largest value from the function,
-    /// ignoring any elements where the function produces a non-existence error;
-    /// - an `index` (the primary key or a secondary index), to return the element
-    /// of the sequence with the largest value in that index;
-    ///
-    /// For more information on RethinkDB’s sorting order, read the section in
-    /// [ReQL data types](https://rethinkdb.com/docs/data-types/#sorting-order).
-    ///
-    /// Calling `max` on an empty sequence will throw a non-existence error;
-    /// this can be handled using the [default](Self::default) command.
+    /// - sequences: `impl IntoIterator<Command>`
+    /// - options: [UnionOption](crate::arguments::UnionOption)
     ///
     /// ## Examples
     ///
-    /// Return the maximum value in the list [3, 5, 7].
+    /// Construct a stream of all characters.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.expr([3, 5, 7])
-    ///         .max(())
+    ///     let response = r.table("simbad")
+    ///         .union(r.table("kirikou"))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == 7);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
+    pub fn union(&self, args: impl union::UnionArg) -> Self {
+        union::new(args).with_parent(self)
+    }
+
+    /// Select a given number of elements from
+    /// a sequence with uniform random distribution.
     ///
-    /// ## Examples
-    ///
-    /// Return the user who has scored the most points.
+    /// # Command syntax
     ///
+    /// ```text
+    /// sequence.sample(number) → selection
+    /// stream.sample(number) → array
+    /// array.sample(number) → array
     /// ```
-    /// use neor::{args, r, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .max("points")
-    ///         .run(&conn)
-    ///         .await?;
+    /// Where:
+    /// - number: `u8, u16, ..., usize` | [Command](crate::Command)
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// # Description
+    ///
+    /// Select a given number of elements from a
+    /// sequence with uniform random distribution.
+    /// Selection is done without replacement.
+    ///
+    /// If the sequence has less than the requested
+    /// number of elements (i.e., calling `sample(10)`
+    /// on a sequence with only five elements), `sample`
+    /// will return the entire sequence in a random order.
     ///
     /// ## Examples
     ///
-    /// The same as above, but using a secondary index on the `points` field.
+    /// Select 3 random heroes.
     ///
     /// ```
-    /// use neor::arguments::MaxOption;
-    /// use neor::{args, r, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .max(MaxOption::default().index("points"))
+    ///     let response = r.table("simbad")
+    ///         .sample(3)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5214,16 +5214,16 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Return the user who has scored the most points,
-    /// adding in bonus points from a separate field using a function.
+    /// Select and stratify 3 random heroes by belovedness.
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .max(func!(|user| user.g("points") + user.g("bonus_points")))
+    ///     let response = r.table("simbad")
+    ///         .group("belovedness")
+    ///         .sample(3)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5232,122 +5232,241 @@ impl<'a> Command {
     ///     Ok(())
     /// }
     /// ```
+    pub fn sample(&self, number: impl Into<CommandArg>) -> Self {
+        sample::new(number).with_parent(self)
+    }
+
+    /// Takes a stream and partitions it into multiple
+    /// groups based on the fields or functions provided.
     ///
-    /// ## Examples
-    ///
-    /// Return the highest number of points any user has ever scored.
-    /// This returns the value of that `points` field, not a document.
+    /// # Command syntax
     ///
+    /// ```text
+    /// sequence.group(field) → grouped_stream
+    /// sequence.group(func) → grouped_stream
+    /// sequence.group(args!(field, options)) → grouped_stream
+    /// sequence.group(args!(func, options)) → grouped_stream
+    /// sequence.group(options) → grouped_stream
+    /// r.group(sequence, field) → grouped_stream
+    /// r.group(sequence, func) → grouped_stream
+    /// r.group(sequence, args!(field, options)) → grouped_stream
+    /// r.group(sequence, args!(func, options)) → grouped_stream
     /// ```
+    ///
+    /// Where:
+    /// - field: `&str | [&str; N]` | [Command](crate::Command) | `[Command; N]`
+    /// - func: [Func](crate::Func) | [Func; N]
+    /// - options: [GroupOption](crate::arguments::GroupOption), grouping by its `index` alone
+    /// - grouped_stream: [GroupedStream](crate::types::GroupedStream) | [GroupedData](crate::types::GroupedData)
+    /// - sequence: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// With the `multi` flag single documents can be assigned to multiple groups,
+    /// similar to the behavior of
+    /// [multi-indexes](https://rethinkdb.com/docs/secondary-indexes/javascript).
+    /// When `multi` is `true` and the grouping value is an array, documents
+    /// will be placed in each group that corresponds to the elements of the array.
+    /// If the array is empty the row will be ignored.
+    ///
+    /// Suppose that the table games has the following data:
+    ///
+    /// ```text
+    /// [
+    ///     {id: 2, player: "Moussa", points: 15, class: "ranked"},
+    ///     {id: 5, player: "Fatou", points: 7, class: "free"},
+    ///     {id: 11, player: "Moussa", points: 10, class: "free"},
+    ///     {id: 12, player: "Fatou", points: 2, class: "free"}
+    /// ]
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Group games by player.
+    ///
+    /// ```
+    /// use neor::types::{GroupedItem, GroupedStream};
     /// use neor::{args, r, Converter, Result};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Serialize, Deserialize, PartialEq, Eq)]
+    /// struct Player {
+    ///     id: u8,
+    ///     player: String,
+    ///     points: u8,
+    ///     class: String,
+    /// }
+    ///
+    /// impl Player {
+    ///     fn new(id: u8, player: &str, points: u8, class: &str) -> Self {
+    ///         Self {
+    ///             id,
+    ///             points,
+    ///             player: player.to_owned(),
+    ///             class: class.to_owned(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let expected_data = vec![
+    ///         GroupedItem {
+    ///             group: String::from("Fatou"),
+    ///             values: vec![
+    ///                 Player::new(5, "Fatou", 7, "free"),
+    ///                 Player::new(12, "Fatou", 2, "free"),
+    ///             ]
+    ///         },
+    ///         GroupedItem {
+    ///             group: String::from("Moussa"),
+    ///             values: vec![
+    ///                 Player::new(2, "Moussa", 15, "ranked"),
+    ///                 Player::new(11, "Moussa", 10, "free"),
+    ///             ]
+    ///         },
+    ///     ];
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: GroupedStream<String, Player> = r.table("games")
+    ///         .group("player")
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.collect() == expected_data);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Count the games of each player and class.
+    /// A reduction after `group` returns a [GroupedData](crate::types::GroupedData)
+    /// holding a value for each group.
+    ///
+    /// ```
+    /// use neor::types::GroupedData;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.table("users")
-    ///         .max("points")
-    ///         .g("points")
+    ///     let response: GroupedData<(String, String), usize> = r.table("games")
+    ///         .group(["player", "class"])
+    ///         .count(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
+    ///     let counts = response.into_hash_map();
     ///
-    ///     assert!(response == 15);
+    ///     assert!(counts[&("Fatou".to_owned(), "free".to_owned())] == 2);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
+    /// - [ungroup](Self::ungroup)
     /// - [map](Self::map)
     /// - [reduce](Self::reduce)
     /// - [count](Self::count)
     /// - [sum](Self::sum)
     /// - [avg](Self::avg)
     /// - [min](Self::min)
-    /// - [group](Self::group)
-    pub fn max(&self, args: impl max::MaxArg) -> Self {
-        max::new(args).with_parent(self)
+    /// - [max](Self::max)
+    pub fn group(&self, args: impl group::GroupArg) -> Self {
+        group::new(args).with_parent(self)
     }
 
-    /// Removes duplicate elements from a sequence.
+    /// Takes a grouped stream or grouped data and turns it
+    /// into an array of objects representing the groups.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.distinct(()) → array
-    /// table.distinct(options) → stream
-    /// r.distinct(sequence) → array
-    /// r.distinct(table, options) → stream
+    /// grouped_stream.ungroup() → array
+    /// grouped_data.ungroup() → array
     /// ```
     ///
     /// Where:
-    /// - options: [DistinctOption](crate::arguments::DistinctOption)
-    /// - sequence: [Command](crate::Command)
+    /// - grouped_stream, grouped_data: [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// The `distinct` command can be called on any sequence or table with an index.
+    /// Any commands chained after `ungroup` will operate on this array,
+    /// rather than operating on each group individually. This is useful
+    /// if you want to e.g. order the groups by the value of their reduction.
+    ///
+    /// The format of the array returned by `ungroup` is the same as
+    /// the default native format of grouped data in the javascript driver and data explorer.
+    ///
+    /// Suppose that the table games has the following data:
     ///
     /// ```text
-    /// While `distinct` can be called on a table without an index,
-    /// the only effect will be to convert the table into a stream;
-    /// the content of the stream will not be affected.
+    /// [
+    ///     {id: 2, player: "Moussa", points: 15, type: "ranked"},
+    ///     {id: 5, player: "Fatou", points: 7, type: "free"},
+    ///     {id: 11, player: "Ibrahim", points: 10, type: "free"},
+    ///     {id: 12, player: "Abess", points: 2, type: "free"}
+    /// ]
     /// ```
     ///
     /// ## Examples
     ///
-    /// Which unique villains have been vanquished by Marvel heroes?
+    /// What is the maximum number of points scored by each player, with the highest scorers first?
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .concat_map(func!(|hero| hero.g("villain_list")))
-    ///         .distinct(())
+    ///     let expected_data = json!([
+    ///         {
+    ///             "group": "Bob",
+    ///             "reduction": 15
+    ///         },
+    ///         {
+    ///             "group": "Alice",
+    ///             "reduction": 7
+    ///         }
+    ///     ]);
+    ///     let response = r.table("games")
+    ///         .group("player")
+    ///         .max("points")
+    ///         .g("points")
+    ///         .ungroup()
+    ///         .order_by(r.desc("reduction"))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap();
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response == expected_data);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Topics in a table of messages have a secondary index on them,
-    /// and more than one message can have the same topic.
-    /// What are the unique topics in the table?
-    ///
-    /// ```
-    /// use neor::arguments::DistinctOption;
-    /// use neor::{r, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("messages")
-    ///         .distinct(DistinctOption::default().index("topics"))
-    ///         .run(&conn)
-    ///         .await?;
+    /// A shorter way to execute this query is to use [count](Self::count).
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// ## Examples
     ///
-    /// The above structure is functionally identical to:
+    /// Suppose that each `post` has a field `comments` that is an array of comments.
+    /// Return the maximum number comments per post.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{args, func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("messages")
-    ///         .g("topics")
-    ///         .distinct(())
+    ///     let response = r.table("posts")
+    ///         .map(func!(|post| post.g("comments").count(())))
+    ///         .reduce(func!(|left, right| r.branch(
+    ///             left.gt(&right),
+    ///             args!(left, right)
+    ///         )))
+    ///         .default(0)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5357,191 +5476,161 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// However, the first form (passing the index as an argument to `distinct`) is faster,
-    /// and won’t run into array limit issues since it’s returning a stream.
+    /// A shorter way to execute this query is to use [max](Self::max).
     ///
     /// # Related commands
-    /// - [map](Self::map)
-    /// - [concat_map](Self::concat_map)
     /// - [group](Self::group)
-    pub fn distinct(&self, args: impl distinct::DistinctArg) -> Self {
-        distinct::new(args).with_parent(self)
+    pub fn ungroup(&self) -> Self {
+        ungroup::new().with_parent(self)
     }
 
-    /// When called with values, returns `true`
-    /// if a sequence contains all the specified values.
+    /// Produce a single value from a sequence through
+    /// repeated application of a reduction function.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.contains(value) → bool
-    /// query.contains(args!(values)) → bool
-    /// r.contains(sequence, value) → bool
-    /// r.contains(sequence, args!(values)) → bool
+    /// sequence.reduce(func) → value
     /// ```
     ///
     /// Where:
-    /// - value: `impl Serialize` | [Command](crate::Command) | [Func](crate::Func)
-    /// - values: `impl IntoIterator<Item = T>` | `impl IntoIterator<Item = Command>`
+    /// - func: [Func](crate::Func)
     /// - sequence: [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// When called with predicate functions, returns `true`
-    /// if for each predicate there exists at least one element
-    /// of the stream where that predicate returns `true`.
+    /// The reduction function can be called on:
     ///
-    /// Values and predicates may be mixed freely in the argument list.
+    /// - two elements of the sequence
+    /// - one element of the sequence and one result of a previous reduction
+    /// - two results of previous reductions
+    ///
+    /// The reduction function can be called on the results of
+    /// two previous reductions because the `reduce` command is
+    /// distributed and parallelized across shards and CPU cores.
+    /// A common mistaken when using the `reduce` command is to
+    /// suppose that the reduction is executed from left to right.
+    /// [Read the map-reduce in RethinkDB](https://rethinkdb.com/docs/map-reduce/)
+    /// article to see an example.
+    ///
+    /// If the sequence is empty, the server will produce a
+    /// `ReqlRuntimeError` that can be caught with default.
+    /// If the sequence has only one element, the first element will be returned.
     ///
     /// ## Examples
     ///
-    /// Has Iron Man ever fought Superman?
+    /// Return the number of documents in the table posts.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("marvel")
-    ///         .get("ironman")
-    ///         .g("opponents")
-    ///         .contains("superman")
+    ///     let response = r.table("posts")
+    ///         .map(func!(|| r.expr(1)))
+    ///         .reduce(func!(|left, right| left + right))
+    ///         .default(0)
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Has Iron Man ever defeated Superman in battle?
-    ///
-    /// ```
-    /// use neor::{func, r, Converter, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("marvel")
-    ///         .get("ironman")
-    ///         .g("battles")
-    ///         .contains(func!(|battle| battle.g("winner").eq("ironman").and(
-    ///             battle.g("loser").eq("superman")
-    ///         )))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
-    ///
-    ///     assert!(response);
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// A shorter way to execute this query is to use [count](Self::count).
     ///
     /// ## Examples
     ///
-    /// Return all heroes who have fought both Loki and the Hulk.
+    /// Suppose that each `post` has a field `comments` that is an array of comments.
+    /// Return the maximum number comments per post.
     ///
     /// ```
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("marvel")
-    ///         .filter(func!(|hero| hero.g("opponents").contains(["loki", "hulk"])))
+    ///     let response = r.table("posts")
+    ///         .map(func!(|post| post.g("comments").count(())))
+    ///         .reduce(func!(|left, right| r.branch(
+    ///             left.gt(&right),
+    ///             args!(left, right)
+    ///         )))
+    ///         .default(0)
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Use contains with a predicate function to simulate an or.
-    /// Return the Marvel superheroes who live in Detroit, Chicago or Hoboken.
-    ///
-    /// ```
-    /// use neor::{func, r, Converter, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("marvel")
-    ///         .filter(func!(|hero| r.expr(["Detroit", "Chicago", "Hoboken"]).contains(hero.g("city"))))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
-    ///
-    ///     assert!(response);
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// A shorter way to execute this query is to use [max](Self::max).
     ///
     /// # Related commands
+    /// - [group](Self::group)
     /// - [map](Self::map)
     /// - [concat_map](Self::concat_map)
-    /// - [group](Self::group)
-    pub fn contains(&self, value: impl contains::ContainsArg) -> Self {
-        contains::new(value).with_parent(self)
+    /// - [sum](Self::sum)
+    /// - [avg](Self::avg)
+    /// - [min](Self::min)
+    /// - [max](Self::max)
+    pub fn reduce(&self, func: Func) -> Self {
+        reduce::new(func).with_parent(self)
     }
 
-    /// Plucks out one or more attributes from either
-    /// an object or a sequence of objects (projection).
+    /// Apply a function to a sequence in order,
+    /// maintaining state via an accumulator.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.pluck(selectors) → any
+    /// sequence.fold(base, func) → value
+    /// sequence.fold(base, args!(func, options)) → sequence
     /// ```
     ///
     /// Where:
-    /// - selectors: `impl Serialize` | [Command](crate::Command) |
-    /// `args!(impl IntoIterator<Item = Command>)`
-    ///
-    /// ## Examples
+    /// - base: `impl Serialize` | [Command](crate::Command)
+    /// - func: [Func](crate::Func)
+    /// - sequence: [Command](crate::Command)
+    /// - options: [FoldOption](crate::arguments::FoldOption)
     ///
-    /// We just need information about IronMan’s
-    /// reactor and not the rest of the document.
+    /// # Description
     ///
-    /// ```
-    /// use neor::{r, Result};
+    /// The `fold` command returns either a single value or a new sequence.
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .get("IronMan")
-    ///         .pluck(["reactorState", "reactorPower"])
-    ///         .run(&conn)
-    ///         .await?;
+    /// In its first form, `fold` operates like [reduce](Self::reduce), returning a value
+    /// by applying a combining function to each element in a sequence.
+    /// The combining function takes two parameters: the previous reduction
+    /// result (the accumulator) and the current element. However, `fold` has
+    /// the following differences from `reduce`:
+    /// - it is guaranteed to proceed through the sequence from first element to last.
+    /// - it passes an initial base value to the function with the first element in
+    /// place of the previous reduction result.
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
+    /// ```text
+    /// combining_function(accumulator | base, element) → new_accumulator
     /// ```
     ///
     /// ## Examples
     ///
-    /// For the hero beauty contest we only care about certain qualities.
+    /// Concatenate words from a list.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .pluck(["beauty", "muscleTone", "charm"])
+    ///     let response = r.table("words")
+    ///         .order_by("id")
+    ///         .fold(
+    ///             "",
+    ///             func!(|acc, word| acc.clone()
+    ///                 + r.branch(acc.eq(""), args!("", ", "))
+    ///                 + word),
+    ///         )
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5551,91 +5640,104 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Pluck can also be used on nested objects.
-    ///
-    /// ```
-    /// use neor::{r, Result};
-    /// use serde_json::json;
+    /// (This example could be implemented with `reduce`,
+    /// but `fold` will preserve the order when `words` is
+    /// a RethinkDB table or other stream, which is
+    /// not guaranteed with `reduce`.)
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .pluck(json!({
-    ///             "abilities": {"damage": true, "mana_cost": true},
-    ///             "weapons": true
-    ///         }))
-    ///         .run(&conn)
-    ///         .await?;
+    /// In its second form, `fold` returns a new sequence built by the `emit`
+    /// function of [FoldOption](crate::arguments::FoldOption), called after each
+    /// element with the previous accumulator, the element and the new accumulator.
+    /// It returns an array of values appended to the sequence.
+    /// The optional `final_emit` function is called with the last accumulator,
+    /// and its array is appended at the end of the sequence.
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
+    /// ```text
+    /// emit_function(previous_accumulator, element, accumulator) → array
+    /// final_emit_function(accumulator) → array
     /// ```
     ///
     /// ## Examples
     ///
-    /// The nested syntax can quickly become overly
-    /// verbose so there’s a shorthand for it.
+    /// Compute a five-day running average for a weight tracker.
     ///
     /// ```
-    /// use neor::{args, r, Result};
+    /// use neor::arguments::FoldOption;
+    /// use neor::{args, func, r, Result};
     /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .pluck(args!([
-    ///             r.expr(json!({"abilities": ["damage", "mana_cost"]})),
-    ///             r.expr("weapons")
-    ///         ]))
+    ///     let opts = FoldOption::default().emit(func!(|old_acc, _row, new_acc| r.branch(
+    ///         old_acc.count(()).eq(5),
+    ///         args!(r.array([new_acc.avg(())]), json!([])),
+    ///     )));
+    ///     let response = r.table("tracker")
+    ///         .filter(func!(|row| row.g("name").eq("bob")))
+    ///         .order_by("date")
+    ///         .g("weight")
+    ///         .fold(
+    ///             json!([]),
+    ///             args!(func!(|acc, row| (r.array([row]) + acc).limit(5)), opts),
+    ///         )
     ///         .run(&conn)
     ///         .await?;
     ///
     ///     assert!(response.is_some());
-    ///     
+    ///
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// For more information read the
-    /// [nested field documentation](https://rethinkdb.com/docs/nested-fields/python/).
-    ///
     /// # Related commands
-    /// - [without](Self::without)
-    /// - [map](Self::map)
-    pub fn pluck(&self, args: impl pluck::PluckArg) -> Self {
-        pluck::new(args).with_parent(self)
+    /// - [reduce](Self::reduce)
+    /// - [concat_map](Self::concat_map)
+    pub fn fold(&self, base: impl Into<CommandArg>, args: impl fold::FoldArg) -> Self {
+        fold::new(base, args).with_parent(self)
     }
 
-    /// The opposite of pluck; takes an object or a sequence of objects,
-    /// and returns them with the specified paths removed.
+    /// Count the number of elements in sequence or key/value pairs in an object,
+    /// or returns the size of a string or binary object.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.without(selectors) → any
+    /// binary.count(()) → number
+    /// string.count(()) → number
+    /// object.count(()) → number
+    /// sequence.count(()) → number
+    /// sequence.count(args!(value)) → number
+    /// sequence.count(func) → number
+    /// r.count(query_cmd) → number
+    /// r.count(query_cmd, args!(value)) → number
+    /// r.count(query_cmd, func) → number
     /// ```
     ///
     /// Where:
-    /// - selectors: `impl Serialize` | [Command](crate::Command) |
-    /// `args!(impl IntoIterator<Item = Command>)`
+    /// - value: `impl Serialize`
+    /// - func: [Func](crate::Func)
+    /// - sequence, binary, string, object, query_cmd: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// When `count` is called on a sequence with a predicate value or function,
+    /// it returns the number of elements in the sequence equal to that value or
+    /// where the function returns `true`. On a [binary](crate::r::binary) object, `count`
+    /// returns the size of the object in bytes; on strings, `count` returns the string’s length.
+    /// This is determined by counting the number of Unicode codepoints in the string,
+    /// counting combining codepoints separately.
     ///
     /// ## Examples
     ///
-    /// Since we don’t need it for this computation we’ll save bandwidth
-    /// and leave out the list of IronMan’s romantic conquests.
+    /// Count the number of users.
     ///
     /// ```
     /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .get("IronMan")
-    ///         .without("personalVictoriesList")
+    ///     let response = r.table("users")
+    ///         .count(())
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5647,15 +5749,16 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Without their prized weapons, our enemies will quickly be vanquished.
+    /// Count the number of 18 year old users.
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("enemies")
-    ///         .without("weapons")
+    ///     let response = r.table("users")
+    ///         .g("age")
+    ///         .count(args!(18))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5667,20 +5770,16 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Nested objects can be used to remove the damage
-    /// subfield from the weapons and abilities fields.
+    /// Count the number of users over 18.
     ///
     /// ```
-    /// use neor::{r, Result};
-    /// use serde_json::json;
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .without(json!({
-    ///             "weapons": { "damage": true },
-    ///             "abilities": { "damage": true }
-    ///         }))
+    ///     let response = r.table("users")
+    ///         .g("age")
+    ///         .count(func!(|age| age.gt(18)))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5692,102 +5791,105 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// The nested syntax can quickly become overly verbose so there’s a shorthand for it.
+    /// Return the length of a Unicode string.
     ///
     /// ```
-    /// use neor::{r, Result};
-    /// use serde_json::json;
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .without(json!({
-    ///             "weapons": "damage",
-    ///             "abilities": "damage"
-    ///         }))
+    ///     let response: usize = r.expr("こんにちは")
+    ///         .count(())
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response == 5);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return the length of an array.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: usize = r.expr(['0','1','2'])
+    ///         .count(())
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == 3);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [pluck](Self::pluck)
     /// - [map](Self::map)
-    pub fn without(&self, args: impl without::WithoutArg) -> Self {
-        without::new(args).with_parent(self)
+    /// - [reduce](Self::reduce)
+    /// - [sum](Self::sum)
+    /// - [avg](Self::avg)
+    /// - [min](Self::min)
+    /// - [max](Self::max)
+    /// - [group](Self::group)
+    pub fn count(&self, args: impl count::CountArg) -> Self {
+        count::new(args).with_parent(self)
     }
 
-    /// Merge two or more objects together to construct
-    /// a new object with properties from all.
+    /// Sum all the elements of sequence.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.merge(params) → any
+    /// sequence.sum(()) → number
+    /// sequence.sum(field) → number
+    /// sequence.sum(func) → number
+    /// r.sum(sequence) → number
+    /// r.sum(sequence, field) → number
+    /// r.sum(sequence, func) → number
     /// ```
     ///
     /// Where:
-    /// - params: `impl Serialize` | [Func](crate::Func) | [Command](crate::Command) |
-    /// `impl IntoIterator<Item = Command>` | `impl IntoIterator<Item = Func>`
+    /// - field: `&str` | `String` | [Command](crate::Command)
+    /// - func: [Func](crate::Func)
+    /// - sequence: [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// When there is a conflict between field names, preference is
-    /// given to fields in the rightmost object in the argument list
-    /// `merge` also accepts a subquery function that returns an object,
-    /// which will be used similarly to a [map](Self::map) function.
-    ///
-    /// ## Examples
-    ///
-    /// Equip Thor for battle.
-    ///
-    /// ```
-    /// use neor::{args, r, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .get("thor")
-    ///         .merge(args!([
-    ///             r.table("equipment").get("hammer"),
-    ///             r.table("equipment").get("pimento_sandwich"),
-    ///         ]))
-    ///         .run(&conn)
-    ///         .await?;
+    /// If called with a field name, sums all the values of that field in
+    /// the sequence, skipping elements of the sequence that lack that field.
+    /// If called with a function, calls that function on every element of the
+    /// sequence and sums the results, skipping elements of the sequence
+    /// where that function returns `None` or non-existence error.
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// Returns `0` when called on an empty sequence.
     ///
     /// ## Examples
     ///
-    /// Equip every hero for battle, using a subquery
-    /// function to retrieve their weapons.
+    /// What's 3 + 5 + 7?
     ///
     /// ```
-    /// use std::collections::HashMap;
-    ///
-    /// use neor::{args, func, r, Result};
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("marvel")
-    ///         .get("thor")
-    ///         .merge(func!(|hero| {
-    ///             let mut weapons = HashMap::new();
-    ///             weapons.insert("weapons", r.table("weapons").get(hero.g("weapon_id")));
-    ///             r.hash_map(weapons)
-    ///         }))
+    ///     let response: u8 = r.expr([3, 5, 7])
+    ///         .sum(())
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response == 15);
     ///     
     ///     Ok(())
     /// }
@@ -5795,33 +5897,15 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Use `merge` to join each blog post with its comments.
-    ///
-    /// Note that the sequence being merged—in this example,
-    /// the comments—must be coerced from a selection to an array.
-    /// Without `coerce_to` the operation will throw an error
-    /// (“Expected type DATUM but found SELECTION”).
+    /// How many points have been scored across all games?
     ///
     /// ```
-    /// use std::collections::HashMap;
-    ///
-    /// use neor::arguments::GetAllOption;
-    /// use neor::{args, func, r, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .merge(func!(|post| {
-    ///             let mut comments = HashMap::new();
-    ///             comments.insert("comments", r.table("comments")
-    ///                 .get_all(args!(
-    ///                     post.g("id"),
-    ///                     GetAllOption::default().index("title")
-    ///                 ))
-    ///                 .coerce_to("array")
-    ///             );
-    ///             r.hash_map(comments)
-    ///         }))
+    ///     let response = r.table("games")
+    ///         .sum("points")
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5833,29 +5917,15 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Merge can be used recursively to modify object within objects.
+    /// How many points have been scored across all games, counting bonus points?
     ///
     /// ```
-    /// use neor::{r, Result};
-    /// use serde_json::json;
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.expr(json!({
-    ///             "weapons": {
-    ///                 "spectacular graviton beam": {
-    ///                     "dmg": 10,
-    ///                     "cooldown": 20
-    ///                 }
-    ///             }
-    ///         }))
-    ///         .merge(json!({
-    ///             "weapons": {
-    ///                 "spectacular graviton beam": {
-    ///                     "dmg": 10
-    ///                 }
-    ///             }
-    ///         }))
+    ///     let response = r.table("games")
+    ///         .sum(func!(|game| game.g("points") + game.g("bonus_points")))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -5866,550 +5936,579 @@ impl<'a> Command {
     /// ```
     ///
     /// # Related commands
-    /// - [pluck](Self::pluck)
-    /// - [without](Self::without)
     /// - [map](Self::map)
-    pub fn merge(&self, args: impl merge::MergeArg) -> Self {
-        merge::new(args).with_parent(self)
+    /// - [reduce](Self::reduce)
+    /// - [count](Self::count)
+    /// - [avg](Self::avg)
+    /// - [min](Self::min)
+    /// - [max](Self::max)
+    /// - [group](Self::group)
+    pub fn sum(&self, args: impl sum::SumArg) -> Self {
+        sum::new(args).with_parent(self)
     }
 
-    /// Append a value to an array.
+    /// Averages all the elements of sequence.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.append(value) → array
+    /// sequence.avg(()) → Option<f64>
+    /// sequence.avg(field) → Option<f64>
+    /// sequence.avg(func) → Option<f64>
+    /// r.avg(sequence) → Option<f64>
+    /// r.avg(sequence, field) → Option<f64>
+    /// r.avg(sequence, func) → Option<f64>
     /// ```
     ///
     /// Where:
-    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// - field: `&str` | `String` | [Command](crate::Command)
+    /// - func: [Func](crate::Func)
+    /// - sequence: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// If called with a field name, averages all the values of that field in
+    /// the sequence, skipping elements of the sequence that lack that field.
+    /// If called with a function, calls that function on every element of the
+    /// sequence and averages the results, skipping elements of the sequence
+    /// where that function returns `None` or non-existence error.
+    ///
+    /// Produces a non-existence error when called on an empty sequence.
+    /// You can handle this case with `default`.
     ///
     /// ## Examples
     ///
-    /// Retrieve Simon's colours list with the addition of yellow
+    /// What's the average of 3, 5 and 7?
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // ["green", "pink", "red", "blue", "purple"]
-    ///     let response: [String; 6] = r.table("simbad")
-    ///         .get(1)
-    ///         .g("colour")
-    ///         .append("yellow")
+    ///     let response: Option<f64> = r.expr([3, 5, 7])
+    ///         .avg(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == ["green", "pink", "red", "blue", "purple", "yellow"]);
+    ///     assert!(response == Some(5.));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [prepend](Self::prepend)
-    /// - [merge](Self::merge)
-    /// - [insert_at](Self::insert_at)
-    /// - [delete_at](Self::delete_at)
-    /// - [change_at](Self::change_at)
-    pub fn append(&self, args: impl Into<CommandArg>) -> Self {
-        append::new(args).with_parent(self)
-    }
-
-    /// Prepend a value to an array.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// What's the average number of points scored in a games?
     ///
-    /// ```text
-    /// query.prepend(value) → array
     /// ```
+    /// use neor::{r, Result};
     ///
-    /// Where:
-    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("games")
+    ///         .avg("points")
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Retrieve Simon's colours list with the addition of yellow
+    /// What's the average number of points scored in a games, counting bonus points?
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // ["green", "pink", "red", "blue", "purple"]
-    ///     let response: [String; 6] = r.table("simbad")
-    ///         .get(1)
-    ///         .g("colour")
-    ///         .prepend("yellow")
+    ///     let response = r.table("games")
+    ///         .avg(func!(|game| game.g("points") + game.g("bonus_points")))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ["yellow", "green", "pink", "red", "blue", "purple"]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [append](Self::append)
-    /// - [merge](Self::merge)
-    /// - [insert_at](Self::insert_at)
-    /// - [delete_at](Self::delete_at)
-    /// - [change_at](Self::change_at)
-    pub fn prepend(&self, args: impl Into<CommandArg>) -> Self {
-        prepend::new(args).with_parent(self)
-    }
+    /// - [map](Self::map)
+    /// - [reduce](Self::reduce)
+    /// - [count](Self::count)
+    /// - [sum](Self::sum)
+    /// - [min](Self::min)
+    /// - [max](Self::max)
+    /// - [group](Self::group)
+    pub fn avg(&self, args: impl avg::AvgArg) -> Self {
+        avg::new(args).with_parent(self)
+    }
 
-    /// Remove the elements of one array from another array
+    /// Finds the minimum element of a sequence.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.difference(attr) → array
+    /// sequence.min(()) → element
+    /// sequence.min(field) → element
+    /// sequence.min(func) → element
+    /// sequence.min(options) → element
+    /// r.min(sequence) → element
+    /// r.min(sequence, field) → element
+    /// r.min(sequence, func) → element
+    /// r.min(sequence, options) → element
     /// ```
     ///
     /// Where:
-    /// - attr: `impl IntoIterator<Item = T>` | [Command](crate::Command)
+    /// - field: `&str` | `String` | [Command](crate::Command)
+    /// - func: [Func](crate::Func)
+    /// - options: [MinOption](crate::arguments::MinOption)
+    /// - sequence: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// The `min` command can be called with:
+    /// - a `field name`, to return the element of the sequence
+    /// with the largest value in that field;
+    /// - a `function`, to apply the function to every element within the sequence
+    /// and return the element which returns the largest value from the function,
+    /// ignoring any elements where the function produces a non-existence error;
+    /// - an `index` (the primary key or a secondary index), to return the element
+    /// of the sequence with the largest value in that index;
+    ///
+    /// For more information on RethinkDB’s sorting order, read the section in
+    /// [ReQL data types](https://rethinkdb.com/docs/data-types/#sorting-order).
+    ///
+    /// Calling `min` on an empty sequence will throw a non-existence error;
+    /// this can be handled using the [default](Self::default) command.
     ///
     /// ## Examples
     ///
-    /// Retrieve Simon's colour list without pink and purple
+    /// Return the minimum value in the list [3, 5, 7].
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // ["green", "pink", "red", "blue", "purple"]
-    ///     let response: [String; 3] = r.table("simbad")
-    ///         .get(1)
-    ///         .g("colour")
-    ///         .difference(["pink", "purple", "yellow"])
+    ///     let response: u8 = r.expr([3, 5, 7])
+    ///         .min(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == ["green", "red", "blue"]);
+    ///     assert!(response == 3);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [union](Self::union)
-    /// - [set_insert](Self::set_insert)
-    /// - [set_union](Self::set_union)
-    /// - [set_intersection](Self::set_intersection)
-    /// - [set_difference](Self::set_difference)
-    pub fn difference(&self, args: impl Into<CommandArg>) -> Self {
-        difference::new(args).with_parent(self)
-    }
-
-    /// Add a value to an array and return it as a set
-    /// (an array with distinct values).
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Return the user who has scored the fewest points.
     ///
-    /// ```text
-    /// query.set_insert(value) → array
     /// ```
+    /// use neor::{args, r, Result};
     ///
-    /// Where:
-    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .min("points")
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Retrieve Simon's colours list with the addition of yellow
+    /// The same as above, but using a secondary index on the `points` field.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::arguments::MinOption;
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // ["green", "pink", "red", "blue", "purple"]
-    ///     let response: [String; 6] = r.table("simbad")
-    ///         .get(1)
-    ///         .g("colour")
-    ///         .set_insert("yellow")
+    ///     let response = r.table("users")
+    ///         .min(MinOption::default().index("points"))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ["green", "pink", "red", "blue", "purple", "yellow"]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [union](Self::union)
-    /// - [difference](Self::difference)
-    /// - [set_union](Self::set_union)
-    /// - [set_intersection](Self::set_intersection)
-    /// - [set_difference](Self::set_difference)
-    pub fn set_insert(&self, value: impl Into<CommandArg>) -> Self {
-        set_insert::new(value).with_parent(self)
-    }
-
-    /// Add a several values to an array and return
-    /// it as a set (an array with distinct values).
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Return the user who has scored the fewest points,
+    /// adding in bonus points from a separate field using a function.
     ///
-    /// ```text
-    /// query.set_union(attr) → array
     /// ```
+    /// use neor::{args, func, r, Result};
     ///
-    /// Where:
-    /// - attr: `impl IntoIterator<Item = T>` | [Command](crate::Command)
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .min(func!(|user| user.g("points") + user.g("bonus_points")))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Retrieve Simon's colours list with the addition of yellow
+    /// Return the highest number of points any user has ever scored.
+    /// This returns the value of that `points` field, not a document.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // ["green", "pink", "red", "blue", "purple"]
-    ///     let response: [String; 6] = r.table("simbad")
-    ///         .get(1)
-    ///         .g("colour")
-    ///         .set_union(["purple", "pink", "yellow"])
+    ///     let response: u8 = r.table("users")
+    ///         .min("points")
+    ///         .g("points")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == ["green", "pink", "red", "blue", "purple", "yellow"]);
+    ///     assert!(response == 2);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [union](Self::union)
-    /// - [difference](Self::difference)
-    /// - [set_insert](Self::set_insert)
-    /// - [set_intersection](Self::set_intersection)
-    /// - [set_difference](Self::set_difference)
-    pub fn set_union(&self, args: impl Into<CommandArg>) -> Self {
-        set_union::new(args).with_parent(self)
+    /// - [map](Self::map)
+    /// - [reduce](Self::reduce)
+    /// - [count](Self::count)
+    /// - [sum](Self::sum)
+    /// - [avg](Self::avg)
+    /// - [max](Self::max)
+    /// - [group](Self::group)
+    pub fn min(&self, args: impl min::MinArg) -> Self {
+        min::new(args).with_parent(self)
     }
 
-    /// Intersect two arrays returning values that occur in
-    /// both of them as a set (an array with distinct values).
+    /// Finds the maximum element of a sequence.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.set_intersection(attr) → array
+    /// sequence.max(()) → element
+    /// sequence.max(field) → element
+    /// sequence.max(func) → element
+    /// sequence.max(options) → element
+    /// r.max(sequence) → element
+    /// r.max(sequence, field) → element
+    /// r.max(sequence, func) → element
+    /// r.max(sequence, options) → element
     /// ```
     ///
     /// Where:
-    /// - attr: `impl IntoIterator<Item = T>` | [Command](crate::Command)
+    /// - field: `&str` | `String` | [Command](crate::Command)
+    /// - func: [Func](crate::Func)
+    /// - options: [MaxOption](crate::arguments::MaxOption)
+    /// - sequence: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// The `max` command can be called with:
+    /// - a `field name`, to return the element of the sequence
+    /// with the largest value in that field;
+    /// - a `function`, to apply the function to every element within the sequence
+    /// and return the element which returns the largest value from the function,
+    /// ignoring any elements where the function produces a non-existence error;
+    /// - an `index` (the primary key or a secondary index), to return the element
+    /// of the sequence with the largest value in that index;
+    ///
+    /// For more information on RethinkDB’s sorting order, read the section in
+    /// [ReQL data types](https://rethinkdb.com/docs/data-types/#sorting-order).
+    ///
+    /// Calling `max` on an empty sequence will throw a non-existence error;
+    /// this can be handled using the [default](Self::default) command.
     ///
     /// ## Examples
     ///
-    /// Check which colour Simon likes from a fixed list.
+    /// Return the maximum value in the list [3, 5, 7].
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // ["green", "pink", "red", "blue", "purple"]
-    ///     let response: [String; 2] = r.table("simbad")
-    ///         .get(1)
-    ///         .g("colour")
-    ///         .set_intersection(["purple", "pink", "yellow"])
+    ///     let response: u8 = r.expr([3, 5, 7])
+    ///         .max(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == ["purple", "pink"]);
+    ///     assert!(response == 7);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [union](Self::union)
-    /// - [difference](Self::difference)
-    /// - [set_insert](Self::set_insert)
-    /// - [set_union](Self::set_union)
-    /// - [set_difference](Self::set_difference)
-    pub fn set_intersection(&self, args: impl Into<CommandArg>) -> Self {
-        set_intersection::new(args).with_parent(self)
-    }
-
-    /// Remove the elements of one array from another and
-    /// return them as set (an array with distinct values)
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// query.set_difference(attr) → array
-    /// ```
-    ///
-    /// Where:
-    /// - attr: `impl IntoIterator<Item = T>` | [Command](crate::Command)
-    ///
     /// ## Examples
     ///
-    /// Check which colour Simon likes,
-    /// excluding a fixed list.
+    /// Return the user who has scored the most points.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // ["green", "pink", "red", "blue", "purple"]
-    ///     let response: [String; 3] = r.table("simbad")
-    ///         .get(1)
-    ///         .g("colour")
-    ///         .set_difference(["purple", "pink"])
+    ///     let response = r.table("users")
+    ///         .max("points")
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ["green", "red", "blue"]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [union](Self::union)
-    /// - [difference](Self::difference)
-    /// - [set_insert](Self::set_insert)
-    /// - [set_union](Self::set_union)
-    /// - [set_intersection](Self::set_intersection)
-    pub fn set_difference(&self, args: impl Into<CommandArg>) -> Self {
-        set_difference::new(args).with_parent(self)
-    }
-
-    /// Get a single field from an object.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// The same as above, but using a secondary index on the `points` field.
     ///
-    /// ```text
-    /// query.bracket(attr) → value
     /// ```
+    /// use neor::arguments::MaxOption;
+    /// use neor::{args, r, Result};
     ///
-    /// Where:
-    /// - attr: `impl Into<String>` | `u8, u16, ..., usize` | [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// If called on a sequence, gets that field from every object
-    /// in the sequence, skipping objects that lack it.
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .max(MaxOption::default().index("points"))
+    ///         .run(&conn)
+    ///         .await?;
     ///
-    /// ``` text
-    /// Under most circumstances, you’ll want to use [get_field](Self::get_field)
-    /// (or its shorthand g) or [nth](Self::nth) rather than `bracket`.
-    /// The `bracket` term may be useful in situations where you are unsure of the
-    /// data type returned by the term you are calling `bracket` on.
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
     /// ```
     ///
     /// ## Examples
     ///
-    /// How old is Moussa
+    /// Return the user who has scored the most points,
+    /// adding in bonus points from a separate field using a function.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.table("simbad")
-    ///         .get(1)
-    ///         .bracket("age")
+    ///     let response = r.table("users")
+    ///         .max(func!(|user| user.g("points") + user.g("bonus_points")))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == 15);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// The `bracket` command also accepts integer arguments
-    /// as array offsets, like the [nth](Self::nth) command.
-    ///
     /// ## Examples
     ///
-    /// How old is Moussa
+    /// Return the highest number of points any user has ever scored.
+    /// This returns the value of that `points` field, not a document.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.expr([10, 20, 30, 40, 50])
-    ///         .bracket(3)
+    ///     let response: u8 = r.table("users")
+    ///         .max("points")
+    ///         .g("points")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 40);
+    ///     assert!(response == 15);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [get_field](Self::get_field)
-    /// - [nth](Self::nth)
-    pub fn bracket(&self, attr: impl Into<CommandArg>) -> Self {
-        bracket::new(attr).with_parent(self)
+    /// - [map](Self::map)
+    /// - [reduce](Self::reduce)
+    /// - [count](Self::count)
+    /// - [sum](Self::sum)
+    /// - [avg](Self::avg)
+    /// - [min](Self::min)
+    /// - [group](Self::group)
+    pub fn max(&self, args: impl max::MaxArg) -> Self {
+        max::new(args).with_parent(self)
     }
 
-    /// Get a single field from an object.
+    /// Removes duplicate elements from a sequence.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.get_field(attr) → value
+    /// sequence.distinct(()) → array
+    /// table.distinct(options) → stream
+    /// r.distinct(sequence) → array
+    /// r.distinct(table, options) → stream
     /// ```
     ///
     /// Where:
-    /// - attr: `impl Into<String>` | [Command](crate::Command)
+    /// - options: [DistinctOption](crate::arguments::DistinctOption)
+    /// - sequence: [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// If called on a sequence, gets that field from every object
-    /// in the sequence, skipping objects that lack it.
+    /// The `distinct` command can be called on any sequence or table with an index.
     ///
-    /// You may use either `get_field` or its shorthand, `g`.
+    /// ```text
+    /// While `distinct` can be called on a table without an index,
+    /// the only effect will be to convert the table into a stream;
+    /// the content of the stream will not be affected.
+    /// ```
     ///
     /// ## Examples
     ///
-    /// How old is Moussa
+    /// Which unique villains have been vanquished by Marvel heroes?
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.table("simbad")
-    ///         .get(1)
-    ///         .get_field("age")
+    ///     let response = r.table("marvel")
+    ///         .concat_map(func!(|hero| hero.g("villain_list")))
+    ///         .distinct(())
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == 15);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [bracket](Self::bracket)
-    /// - [nth](Self::nth)
-    pub fn get_field(&self, attr: impl Into<CommandArg>) -> Self {
-        get_field::new(attr).with_parent(self)
-    }
-
-    /// Get a single field from an object.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Topics in a table of messages have a secondary index on them,
+    /// and more than one message can have the same topic.
+    /// What are the unique topics in the table?
     ///
-    /// ```text
-    /// query.g(attr) → value
     /// ```
+    /// use neor::arguments::DistinctOption;
+    /// use neor::{r, Result};
     ///
-    /// Where:
-    /// - attr: `impl Into<String>` | [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// If called on a sequence, gets that field from every object
-    /// in the sequence, skipping objects that lack it.
-    ///
-    /// You may use either `get_field` or its shorthand, `g`.
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("messages")
+    ///         .distinct(DistinctOption::default().index("topics"))
+    ///         .run(&conn)
+    ///         .await?;
     ///
-    /// ## Examples
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
-    /// How old is Moussa
+    /// The above structure is functionally identical to:
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.table("simbad")
-    ///         .get(1)
-    ///         .g("age")
+    ///     let response = r.table("messages")
+    ///         .g("topics")
+    ///         .distinct(())
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == 15);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// However, the first form (passing the index as an argument to `distinct`) is faster,
+    /// and won’t run into array limit issues since it’s returning a stream.
+    ///
     /// # Related commands
-    /// - [bracket](Self::bracket)
-    /// - [nth](Self::nth)
-    pub fn g(&self, attr: impl Into<CommandArg>) -> Self {
-        get_field::new(attr).with_parent(self)
+    /// - [map](Self::map)
+    /// - [concat_map](Self::concat_map)
+    /// - [group](Self::group)
+    pub fn distinct(&self, args: impl distinct::DistinctArg) -> Self {
+        distinct::new(args).with_parent(self)
     }
 
-    /// Test if an object has one or more fields.
+    /// When called with values, returns `true`
+    /// if a sequence contains all the specified values.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.has_fields(selector) → response
+    /// query.contains(value) → bool
+    /// query.contains(args!(values)) → bool
+    /// r.contains(sequence, value) → bool
+    /// r.contains(sequence, args!(values)) → bool
     /// ```
     ///
     /// Where:
-    /// - selector: `impl Serialize` | [Command](crate::Command)
-    /// - response: array | bool
+    /// - value: `impl Serialize` | [Command](crate::Command) | [Func](crate::Func)
+    /// - values: `impl IntoIterator<Item = T>` | `impl IntoIterator<Item = Command>`
+    /// - sequence: [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// An object has a field if it has that key and the key has a non-null value.
-    /// For instance, the object `{'a': 1,'b': 2,'c': null}` has the fields `a` and `b`.
+    /// When called with predicate functions, returns `true`
+    /// if for each predicate there exists at least one element
+    /// of the stream where that predicate returns `true`.
     ///
-    /// When applied to a single object, `has_fields` returns `true` if the object has
-    /// the fields and `false` if it does not. When applied to a sequence, it will return
-    /// a new sequence (an array or stream) containing the elements that have the specified fields.
+    /// Values and predicates may be mixed freely in the argument list.
     ///
     /// ## Examples
     ///
-    /// Return the players who have won games.
+    /// Has Iron Man ever fought Superman?
     ///
     /// ```
-    /// use neor::{r, Result};
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .has_fields("games_won")
+    ///     let response: bool = r.table("marvel")
+    ///         .get("ironman")
+    ///         .g("opponents")
+    ///         .contains("superman")
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response);
     ///     
     ///     Ok(())
     /// }
@@ -6417,21 +6516,25 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Return the players who have not won games.
-    /// To do this, use `has_fields` with [not](crate::r::not),
-    /// wrapped with [filter](Self::filter).
+    /// Has Iron Man ever defeated Superman in battle?
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .filter(func!(|player| !player.has_fields("games_won")))
+    ///     let response: bool = r.table("marvel")
+    ///         .get("ironman")
+    ///         .g("battles")
+    ///         .contains(func!(|battle| battle.g("winner").eq("ironman").and(
+    ///             battle.g("loser").eq("superman")
+    ///         )))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response);
     ///     
     ///     Ok(())
     /// }
@@ -6439,16 +6542,15 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Test if a specific player has won any games.
+    /// Return all heroes who have fought both Loki and the Hulk.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("players")
-    ///         .get(1)
-    ///         .has_fields("games_won")
+    ///     let response: bool = r.table("marvel")
+    ///         .filter(func!(|hero| hero.g("opponents").contains(["loki", "hulk"])))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
@@ -6460,36 +6562,63 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// ## Nested Fields
-    ///
-    /// `has_fields` lets you test for nested fields in objects.
-    /// If the value of a field is itself a set of key/value pairs,
-    /// you can test for the presence of specific keys.
-    ///
     /// ## Examples
     ///
-    /// In the `players` table, the `games_won` field contains one
-    /// or more fields for kinds of games won:
+    /// Use contains with a predicate function to simulate an or.
+    /// Return the Marvel superheroes who live in Detroit, Chicago or Hoboken.
     ///
-    /// ```text
-    /// {
-    ///     'games_won': {
-    ///         'playoffs': 2,
-    ///         'championships': 1
-    ///     }
+    /// ```
+    /// use neor::{func, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.table("marvel")
+    ///         .filter(func!(|hero| r.expr(["Detroit", "Chicago", "Hoboken"]).contains(hero.g("city"))))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response);
+    ///     
+    ///     Ok(())
     /// }
     /// ```
     ///
-    /// Return players who have the “championships” field.
+    /// # Related commands
+    /// - [map](Self::map)
+    /// - [concat_map](Self::concat_map)
+    /// - [group](Self::group)
+    pub fn contains(&self, value: impl contains::ContainsArg) -> Self {
+        contains::new(value).with_parent(self)
+    }
+
+    /// Plucks out one or more attributes from either
+    /// an object or a sequence of objects (projection).
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// query.pluck(selectors) → any
+    /// ```
+    ///
+    /// Where:
+    /// - selectors: `impl Serialize` | [Command](crate::Command) |
+    /// `args!(impl IntoIterator<Item = Command>)`
+    ///
+    /// ## Examples
+    ///
+    /// We just need information about IronMan’s
+    /// reactor and not the rest of the document.
     ///
     /// ```
     /// use neor::{r, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .has_fields(json!({"games_won": {"championships": true}}))
+    ///     let response = r.table("marvel")
+    ///         .get("IronMan")
+    ///         .pluck(["reactorState", "reactorPower"])
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -6499,19 +6628,17 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// Note that `true` in the example above is testing for the existence of `championships`
-    /// as a field, not testing to see if the value of the `championships` field is set to `true`.
-    /// There’s a more convenient shorthand form available.
-    /// (See [pluck](Self::pluck) for more details on this.)
+    /// ## Examples
+    ///
+    /// For the hero beauty contest we only care about certain qualities.
     ///
     /// ```
     /// use neor::{r, Result};
-    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .has_fields(json!({"games_won": "championships"}))
+    ///     let response = r.table("marvel")
+    ///         .pluck(["beauty", "muscleTone", "charm"])
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -6521,154 +6648,149 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [get_field](Self::get_field)
-    /// - [with_fields](Self::with_fields)
-    pub fn has_fields(&self, selector: impl Into<CommandArg>) -> Self {
-        has_fields::new(selector).with_parent(self)
-    }
-
-    /// Insert a value in to an array at a given index. Returns the modified array.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Pluck can also be used on nested objects.
     ///
-    /// ```text
-    /// array.insert_at(offset, value) → array
     /// ```
+    /// use neor::{r, Result};
+    /// use serde_json::json;
     ///
-    /// Where:
-    /// - offset: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("marvel")
+    ///         .pluck(json!({
+    ///             "abilities": {"damage": true, "mana_cost": true},
+    ///             "weapons": true
+    ///         }))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Alima decide to join Simbad.
+    /// The nested syntax can quickly become overly
+    /// verbose so there’s a shorthand for it.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: [String; 4] = r.expr(["Moussa", "Ali", "Fati"])
-    ///         .insert_at(1, "Alima")
+    ///     let response = r.table("marvel")
+    ///         .pluck(args!([
+    ///             r.expr(json!({"abilities": ["damage", "mana_cost"]})),
+    ///             r.expr("weapons")
+    ///         ]))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ["Moussa", "Alima", "Ali", "Fati"]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [splice_at](Self::splice_at)
-    /// - [change_at](Self::change_at)
-    /// - [delete_at](Self::delete_at)
-    pub fn insert_at<O, V>(&self, offset: O, value: V) -> Self
-    where
-        O: Into<CommandArg>,
-        V: Into<CommandArg>,
-    {
-        insert_at::new(offset, value).with_parent(self)
-    }
-
-    /// Insert several values into an array at the given index.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Build the nested selector from a struct with [sel](crate::sel),
+    /// a misspelled field fails to compile.
     ///
-    /// ```text
-    /// array.splice_at(offset, values) → array
     /// ```
+    /// use neor::{r, sel, Result};
     ///
-    /// Where:
-    /// - offset: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    /// - values: `impl IntoInterator<Item = T>` | [Command](crate::Command)
+    /// struct Abilities {
+    ///     damage: u32,
+    ///     mana_cost: u32,
+    /// }
+    ///
+    /// struct Hero {
+    ///     abilities: Abilities,
+    ///     weapons: Vec<String>,
+    /// }
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("marvel")
+    ///         .pluck(sel!(Hero { abilities { damage, mana_cost }, weapons }))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Fati and Alima decide to join Simbad.
+    /// Pluck nested fields given as dotted paths with [path](crate::path),
+    /// and sort on one of them.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{path, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: [String; 4] = r.expr(["Moussa", "Ali"])
-    ///         .splice_at(1, ["Fati", "Alima"])
+    ///     let damage = path!("abilities.damage");
+    ///     let response = r.table("marvel")
+    ///         .order_by(damage.func())
+    ///         .pluck(vec![damage, path!("abilities.mana_cost")])
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ["Moussa", "Fati", "Alima", "Ali"]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// For more information read the
+    /// [nested field documentation](https://rethinkdb.com/docs/nested-fields/python/).
+    ///
     /// # Related commands
-    /// - [insert_at](Self::insert_at)
-    /// - [delete_at](Self::delete_at)
-    /// - [change_at](Self::change_at)
-    pub fn splice_at<O, V>(&self, offset: O, values: V) -> Self
-    where
-        O: Into<CommandArg>,
-        V: Into<CommandArg>,
-    {
-        splice_at::new(offset, values).with_parent(self)
+    /// - [without](Self::without)
+    /// - [map](Self::map)
+    pub fn pluck(&self, args: impl pluck::PluckArg) -> Self {
+        pluck::new(args).with_parent(self)
     }
 
-    /// Remove one or more elements from an array at a given index.
-    /// (Note: `delete_at` operates on arrays, not documents;
-    /// to delete documents, see the [delete](Self::delete) command.)
+    /// The opposite of pluck; takes an object or a sequence of objects,
+    /// and returns them with the specified paths removed.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// array.delete_at(offset) → array
-    /// array.delete_at(args!(offset, end_offset)) → array
+    /// query.without(selectors) → any
     /// ```
     ///
     /// Where:
-    /// - offset, end_offset: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// If only `offset` is specified, `delete_at` removes the element at that index.
-    /// If both `offset` and `end_offset` are specified, `delete_at` removes the range
-    /// of elements between `offset` and `end_offset`, inclusive of `offset` but not
-    /// inclusive of `end_offset`.
-    ///
-    /// If `end_offset` is specified, it must not be less than `offset`.
-    /// Both `offset` and `end_offset` must be within the array’s bounds
-    /// (i.e., if the array has 10 elements, an `offset` or `end_offset`
-    /// of 10 or higher is invalid).
-    ///
-    /// By using a negative `offset` you can delete from the end of the array.
-    /// `-1` is the last element in the array, `-2` is the second-to-last element, and so on.
-    /// You may specify a negative `end_offset`, although just as with a positive value,
-    /// this will not be inclusive. The range `(2,-1)` specifies the third element through
-    /// the next-to-last element.
+    /// - selectors: `impl Serialize` | [Command](crate::Command) |
+    /// `args!(impl IntoIterator<Item = Command>)`
     ///
     /// ## Examples
     ///
-    /// Delete the second element of an array.
+    /// Since we don’t need it for this computation we’ll save bandwidth
+    /// and leave out the list of IronMan’s romantic conquests.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: [char; 5] = r.expr(['a', 'b', 'c', 'd', 'e', 'f'])
-    ///         .delete_at(1)
+    ///     let response = r.table("marvel")
+    ///         .get("IronMan")
+    ///         .without("personalVictoriesList")
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ['a', 'c', 'd', 'e', 'f']);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
@@ -6676,21 +6798,19 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Delete the second and third elements of an array.
+    /// Without their prized weapons, our enemies will quickly be vanquished.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: [char; 4] = r.expr(['a', 'b', 'c', 'd', 'e', 'f'])
-    ///         .delete_at(args!(1, 3))
+    ///     let response = r.table("enemies")
+    ///         .without("weapons")
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ['a', 'd', 'e', 'f']);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
@@ -6698,21 +6818,24 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Delete the next-to-last element of an array.
+    /// Nested objects can be used to remove the damage
+    /// subfield from the weapons and abilities fields.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: [char; 5] = r.expr(['a', 'b', 'c', 'd', 'e', 'f'])
-    ///         .delete_at(-2)
+    ///     let response = r.table("marvel")
+    ///         .without(json!({
+    ///             "weapons": { "damage": true },
+    ///             "abilities": { "damage": true }
+    ///         }))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ['a', 'b', 'c', 'd', 'f']);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
@@ -6720,695 +6843,724 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Delete a comment on a post.
-    ///
-    /// Given a post document such as:
-    ///
-    /// ```text
-    ///
-    /// ```
-    /// {
-    ///     "id": 1,
-    ///     "title": "Post title",
-    ///     "author": "Ali",
-    ///     "comments": [
-    ///         { "author": "Agatha", "text": "Comment 1" },
-    ///         { "author": "Fatima", "text": "Comment 2" }
-    ///     ]
-    /// }
-    ///
-    /// The second comment can be deleted by using `update` and `delete_at` together.
+    /// The nested syntax can quickly become overly verbose so there’s a shorthand for it.
     ///
     /// ```
-    /// use std::collections::HashMap;
-    ///
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: [char; 5] = r.table("posts")
-    ///         .get(1)
-    ///         .update(func!(|post| {
-    ///             let mut comments = HashMap::new();
-    ///             comments.insert("comments", post.g("comments").delete_at(1));
-    ///             r.hash_map(comments)
+    ///     let response = r.table("marvel")
+    ///         .without(json!({
+    ///             "weapons": "damage",
+    ///             "abilities": "damage"
     ///         }))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ['a', 'b', 'c', 'd', 'f']);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [insert_at](Self::insert_at)
-    /// - [splice_at](Self::splice_at)
-    /// - [change_at](Self::change_at)
-    pub fn delete_at(&self, args: impl delete_at::DeleteAtArg) -> Self {
-        delete_at::new(args).with_parent(self)
+    /// - [pluck](Self::pluck)
+    /// - [map](Self::map)
+    pub fn without(&self, args: impl without::WithoutArg) -> Self {
+        without::new(args).with_parent(self)
     }
 
-    /// Change a value in an array at a given index.
+    /// Merge two or more objects together to construct
+    /// a new object with properties from all.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// array.change_at(offset, value) → array
+    /// query.merge(params) → any
     /// ```
     ///
     /// Where:
-    /// - offset: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// - params: `impl Serialize` | [Func](crate::Func) | [Command](crate::Command) |
+    /// `impl IntoIterator<Item = Command>` | `impl IntoIterator<Item = Func>`
+    ///
+    /// # Description
+    ///
+    /// When there is a conflict between field names, preference is
+    /// given to fields in the rightmost object in the argument list
+    /// `merge` also accepts a subquery function that returns an object,
+    /// which will be used similarly to a [map](Self::map) function.
     ///
     /// ## Examples
     ///
-    /// Replace Ali by Alima in array.
+    /// Equip Thor for battle.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: [String; 3] = r.expr(["Moussa", "Ali", "Fati"])
-    ///         .change_at(1, "Alima")
+    ///     let response = r.table("marvel")
+    ///         .get("thor")
+    ///         .merge(args!([
+    ///             r.table("equipment").get("hammer"),
+    ///             r.table("equipment").get("pimento_sandwich"),
+    ///         ]))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ["Moussa", "Alima", "Fati"]);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [insert_at](Self::insert_at)
-    /// - [splice_at](Self::splice_at)
-    /// - [delete_at](Self::delete_at)
-    pub fn change_at<O, V>(&self, offset: O, value: V) -> Self
-    where
-        O: Into<CommandArg>,
-        V: Into<CommandArg>,
-    {
-        change_at::new(offset, value).with_parent(self)
-    }
-
-    /// Return an array containing all of an object’s keys.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Equip every hero for battle, using a subquery
+    /// function to retrieve their weapons.
     ///
-    /// ```text
-    /// string.keys() → array
     /// ```
+    /// use std::collections::HashMap;
     ///
-    /// # Description
+    /// use neor::{args, func, r, Result};
     ///
-    /// Note that the keys will be sorted as described in
-    /// [ReQL data types](https://rethinkdb.com/docs/data-types/#sorting-order)
-    /// (for strings, lexicographically).
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("marvel")
+    ///         .get("thor")
+    ///         .merge(func!(|hero| {
+    ///             let mut weapons = HashMap::new();
+    ///             weapons.insert("weapons", r.table("weapons").get(hero.g("weapon_id")));
+    ///             r.hash_map(weapons)
+    ///         }))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Get all the keys from a table row.
+    /// Use `merge` to join each blog post with its comments.
+    ///
+    /// Note that the sequence being merged—in this example,
+    /// the comments—must be coerced from a selection to an array.
+    /// Without `coerce_to` the operation will throw an error
+    /// (“Expected type DATUM but found SELECTION”).
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::arguments::GetAllOption;
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // row: { "id": "1", "mail": "fred@example.com", "name": "fred" }
-    ///     let response: [String; 3] = r.table("users")
-    ///         .get(1)
-    ///         .keys()
+    ///     let response = r.table("posts")
+    ///         .merge(func!(|post| {
+    ///             let mut comments = HashMap::new();
+    ///             comments.insert("comments", r.table("comments")
+    ///                 .get_all(args!(
+    ///                     post.g("id"),
+    ///                     GetAllOption::default().index("title")
+    ///                 ))
+    ///                 .coerce_to("array")
+    ///             );
+    ///             r.hash_map(comments)
+    ///         }))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == ["id", "mail", "name"]);
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Merge can be used recursively to modify object within objects.
+    ///
+    /// ```
+    /// use neor::{r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.expr(json!({
+    ///             "weapons": {
+    ///                 "spectacular graviton beam": {
+    ///                     "dmg": 10,
+    ///                     "cooldown": 20
+    ///                 }
+    ///             }
+    ///         }))
+    ///         .merge(json!({
+    ///             "weapons": {
+    ///                 "spectacular graviton beam": {
+    ///                     "dmg": 10
+    ///                 }
+    ///             }
+    ///         }))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [values](Self::values)
-    pub fn keys(&self) -> Self {
-        keys::new().with_parent(self)
+    /// - [pluck](Self::pluck)
+    /// - [without](Self::without)
+    /// - [map](Self::map)
+    pub fn merge(&self, args: impl merge::MergeArg) -> Self {
+        merge::new(args).with_parent(self)
     }
 
-    /// Return an array containing all of an object’s values.
+    /// Append a value to an array.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// string.values() → array
+    /// query.append(value) → array
     /// ```
     ///
-    /// # Description
-    ///
-    /// `values()` guarantees the values will
-    /// come out in the same order as [keys](Self::keys).
+    /// Where:
+    /// - value: `impl Serialize` | [Command](crate::Command)
     ///
     /// ## Examples
     ///
-    /// Get all of the values from a table row.
+    /// Retrieve Simon's colours list with the addition of yellow
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     // row: { "id": "1", "mail": "fred@example.com", "name": "fred" }
-    ///     let response: [String; 3] = r.table("users")
+    ///     // ["green", "pink", "red", "blue", "purple"]
+    ///     let response: [String; 6] = r.table("simbad")
     ///         .get(1)
-    ///         .values()
+    ///         .g("colour")
+    ///         .append("yellow")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == ["1", "fred@example.com", "fred"]);
+    ///     assert!(response == ["green", "pink", "red", "blue", "purple", "yellow"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [keys](Self::keys)
-    pub fn values(&self) -> Self {
-        values::new().with_parent(self)
+    /// - [prepend](Self::prepend)
+    /// - [merge](Self::merge)
+    /// - [insert_at](Self::insert_at)
+    /// - [delete_at](Self::delete_at)
+    /// - [change_at](Self::change_at)
+    pub fn append(&self, args: impl Into<CommandArg>) -> Self {
+        append::new(args).with_parent(self)
     }
 
-    /// Match a string against a regular expression.
+    /// Prepend a value to an array.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// string.match(regexp) → response
+    /// query.prepend(value) → array
     /// ```
     ///
     /// Where:
-    /// - regexp: `impl Into<String>` | [Command](crate::Command)
-    /// - response: Option<[MatchResponse](crate::types::MatchResponse)>
+    /// - value: `impl Serialize` | [Command](crate::Command)
     ///
     /// ## Examples
     ///
+    /// Retrieve Simon's colours list with the addition of yellow
+    ///
     /// ```
-    /// use neor::types::MatchResponse;
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: String = r.expr("name@domain.com")
-    ///         .match_(".*@(.*)")
-    ///         .g("groups")
-    ///         .nth(0)
-    ///         .g("str")
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
-    ///     let response2: Option<MatchResponse> = r.expr("name[at]domain.com")
-    ///         .match_(".*@(.*)")
+    ///     // ["green", "pink", "red", "blue", "purple"]
+    ///     let response: [String; 6] = r.table("simbad")
+    ///         .get(1)
+    ///         .g("colour")
+    ///         .prepend("yellow")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == "domain.com");
-    ///     assert!(response2 == None);
+    ///     assert!(response == ["yellow", "green", "pink", "red", "blue", "purple"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [upcase](Self::upcase)
-    /// - [downcase](Self::downcase)
-    /// - [split](Self::split)
-    pub fn match_(&self, regexp: impl Into<CommandArg>) -> Self {
-        match_::new(regexp).with_parent(self)
+    /// - [append](Self::append)
+    /// - [merge](Self::merge)
+    /// - [insert_at](Self::insert_at)
+    /// - [delete_at](Self::delete_at)
+    /// - [change_at](Self::change_at)
+    pub fn prepend(&self, args: impl Into<CommandArg>) -> Self {
+        prepend::new(args).with_parent(self)
     }
 
-    /// Split a string into substrings.
+    /// Remove the elements of one array from another array
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// string.split(()) → string
-    /// string.split(separator) → string
-    /// string.split(args!(separator, max_splits)) → string
+    /// query.difference(attr) → array
     /// ```
     ///
     /// Where:
-    /// - separator: `String` | `&str` | [Commmand](crate::Command)
-    /// - max_splits: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// With no arguments, will split on whitespace;
-    /// when called with a string as the first argument,
-    /// will split using that string as a separator.
-    /// A maximum number of splits can also be specified.
+    /// - attr: `impl IntoIterator<Item = T>` | [Command](crate::Command)
     ///
     /// ## Examples
     ///
-    /// Split on whitespace.
+    /// Retrieve Simon's colour list without pink and purple
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let data = [
-    ///         String::from("foo"),
-    ///         String::from("bar"),
-    ///         String::from("bax"),
-    ///     ];
-    ///     let response: [String; 3] = r.expr("foo  bar bax")
-    ///         .split(())
+    ///     // ["green", "pink", "red", "blue", "purple"]
+    ///     let response: [String; 3] = r.table("simbad")
+    ///         .get(1)
+    ///         .g("colour")
+    ///         .difference(["pink", "purple", "yellow"])
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == data);
+    ///     assert!(response == ["green", "red", "blue"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [union](Self::union)
+    /// - [set_insert](Self::set_insert)
+    /// - [set_union](Self::set_union)
+    /// - [set_intersection](Self::set_intersection)
+    /// - [set_difference](Self::set_difference)
+    pub fn difference(&self, args: impl Into<CommandArg>) -> Self {
+        difference::new(args).with_parent(self)
+    }
+
+    /// Add a value to an array and return it as a set
+    /// (an array with distinct values).
     ///
-    /// Split the entries in a CSV file.
+    /// # Command syntax
     ///
+    /// ```text
+    /// query.set_insert(value) → array
     /// ```
-    /// use neor::{r, Converter, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let data = [
-    ///         String::from("12"),
-    ///         String::from("37"),
-    ///         String::new(),
-    ///         String::from("22"),
-    ///         String::new(),
-    ///     ];
-    ///     let response: [String; 5] = r.expr("12,37,,22,")
-    ///         .split(",")
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
     ///
-    ///     assert!(response == data);
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// Where:
+    /// - value: `impl Serialize` | [Command](crate::Command)
     ///
     /// ## Examples
     ///
-    /// Split a string into characters.
+    /// Retrieve Simon's colours list with the addition of yellow
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let data = [
-    ///         String::from("t"),
-    ///         String::from("o"),
-    ///         String::from("t"),
-    ///         String::from("o"),
-    ///     ];
-    ///     let response: [String; 4] = r.expr("toto")
-    ///         .split("")
+    ///     // ["green", "pink", "red", "blue", "purple"]
+    ///     let response: [String; 6] = r.table("simbad")
+    ///         .get(1)
+    ///         .g("colour")
+    ///         .set_insert("yellow")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == data);
+    ///     assert!(response == ["green", "pink", "red", "blue", "purple", "yellow"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [union](Self::union)
+    /// - [difference](Self::difference)
+    /// - [set_union](Self::set_union)
+    /// - [set_intersection](Self::set_intersection)
+    /// - [set_difference](Self::set_difference)
+    pub fn set_insert(&self, value: impl Into<CommandArg>) -> Self {
+        set_insert::new(value).with_parent(self)
+    }
+
+    /// Add a several values to an array and return
+    /// it as a set (an array with distinct values).
     ///
-    /// Split the entries in a CSV file, but only at most 3 times.
+    /// # Command syntax
     ///
+    /// ```text
+    /// query.set_union(attr) → array
     /// ```
-    /// use neor::{args, r, Converter, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let data = [
-    ///         String::from("12"),
-    ///         String::from("37"),
-    ///         String::new(),
-    ///         String::from("22,"),
-    ///     ];
-    ///     let response: [String; 4] = r.expr("12,37,,22,")
-    ///         .split(args!(",", 3))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
     ///
-    ///     assert!(response == data);
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// Where:
+    /// - attr: `impl IntoIterator<Item = T>` | [Command](crate::Command)
     ///
     /// ## Examples
     ///
-    /// Split on whitespace at most once (i.e. get the first word).
+    /// Retrieve Simon's colours list with the addition of yellow
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let data = [
-    ///         String::from("foo"),
-    ///         String::from("bar bax"),
-    ///     ];
-    ///     let response: [String; 2] = r.expr("foo  bar bax")
-    ///         .split(args!(" ", 1))
+    ///     // ["green", "pink", "red", "blue", "purple"]
+    ///     let response: [String; 6] = r.table("simbad")
+    ///         .get(1)
+    ///         .g("colour")
+    ///         .set_union(["purple", "pink", "yellow"])
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == data);
+    ///     assert!(response == ["green", "pink", "red", "blue", "purple", "yellow"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [upcase](Self::upcase)
-    /// - [downcase](Self::downcase)
-    /// - [match](Self::match_)
-    pub fn split(&self, args: impl split::SplitArg) -> Self {
-        split::new(args).with_parent(self)
+    /// - [union](Self::union)
+    /// - [difference](Self::difference)
+    /// - [set_insert](Self::set_insert)
+    /// - [set_intersection](Self::set_intersection)
+    /// - [set_difference](Self::set_difference)
+    pub fn set_union(&self, args: impl Into<CommandArg>) -> Self {
+        set_union::new(args).with_parent(self)
     }
 
-    /// Uppercases a string.
+    /// Intersect two arrays returning values that occur in
+    /// both of them as a set (an array with distinct values).
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// string.upcase() → string
+    /// query.set_intersection(attr) → array
     /// ```
     ///
+    /// Where:
+    /// - attr: `impl IntoIterator<Item = T>` | [Command](crate::Command)
+    ///
     /// ## Examples
     ///
+    /// Check which colour Simon likes from a fixed list.
+    ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: String = r.expr("Sentence about LaTeX.")
-    ///         .upcase()
+    ///     // ["green", "pink", "red", "blue", "purple"]
+    ///     let response: [String; 2] = r.table("simbad")
+    ///         .get(1)
+    ///         .g("colour")
+    ///         .set_intersection(["purple", "pink", "yellow"])
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == "SENTENCE ABOUT LATEX.");
+    ///     assert!(response == ["purple", "pink"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Note
-    ///
-    /// `upcase` and `downcase` only affect ASCII characters.
-    ///
     /// # Related commands
-    /// - [downcase](Self::downcase)
-    /// - [match](Self::match_)
-    /// - [split](Self::split)
-    pub fn upcase(&self) -> Self {
-        upcase::new().with_parent(self)
+    /// - [union](Self::union)
+    /// - [difference](Self::difference)
+    /// - [set_insert](Self::set_insert)
+    /// - [set_union](Self::set_union)
+    /// - [set_difference](Self::set_difference)
+    pub fn set_intersection(&self, args: impl Into<CommandArg>) -> Self {
+        set_intersection::new(args).with_parent(self)
     }
 
-    /// Lowercase a string.
+    /// Remove the elements of one array from another and
+    /// return them as set (an array with distinct values)
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// string.downcase() → string
+    /// query.set_difference(attr) → array
     /// ```
     ///
+    /// Where:
+    /// - attr: `impl IntoIterator<Item = T>` | [Command](crate::Command)
+    ///
     /// ## Examples
     ///
+    /// Check which colour Simon likes,
+    /// excluding a fixed list.
+    ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: String = r.expr("Sentence about LaTeX.")
-    ///         .downcase()
+    ///     // ["green", "pink", "red", "blue", "purple"]
+    ///     let response: [String; 3] = r.table("simbad")
+    ///         .get(1)
+    ///         .g("colour")
+    ///         .set_difference(["purple", "pink"])
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == "sentence about latex.");
+    ///     assert!(response == ["green", "red", "blue"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Note
-    ///
-    /// `upcase` and `downcase` only affect ASCII characters.
-    ///
     /// # Related commands
-    /// - [upcase](Self::upcase)
-    /// - [match](Self::match_)
-    /// - [split](Self::split)
-    pub fn downcase(&self) -> Self {
-        downcase::new().with_parent(self)
+    /// - [union](Self::union)
+    /// - [difference](Self::difference)
+    /// - [set_insert](Self::set_insert)
+    /// - [set_union](Self::set_union)
+    /// - [set_intersection](Self::set_intersection)
+    pub fn set_difference(&self, args: impl Into<CommandArg>) -> Self {
+        set_difference::new(args).with_parent(self)
     }
 
-    /// Compute the logical “and” of one or more values.
+    /// Get a single field from an object.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_value.and(value) → bool
-    /// cmd_value.and(values) → bool
-    /// r.and(values) → bool
+    /// query.bracket(attr) → value
     /// ```
     ///
     /// Where:
-    /// - value: `bool` | [Command](crate::Command)
-    /// - values: `impl IntoIterator<Item = bool>` | [Command](crate::Command)
+    /// - attr: `impl Into<String>` | `u8, u16, ..., usize` | [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// The `and` command can be used as an infix operator after its
-    /// first argument (`r.expr(true).and(false)`) or given all of
-    /// its arguments as parameters (`r.and(args!([true, false]))`).
+    /// If called on a sequence, gets that field from every object
+    /// in the sequence, skipping objects that lack it.
     ///
-    /// Calling `or` with zero arguments will return `false`.
+    /// ``` text
+    /// Under most circumstances, you’ll want to use [get_field](Self::get_field)
+    /// (or its shorthand g) or [nth](Self::nth) rather than `bracket`.
+    /// The `bracket` term may be useful in situations where you are unsure of the
+    /// data type returned by the term you are calling `bracket` on.
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Return whether either true or false evaluate to true.
+    /// How old is Moussa
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.expr(true)
-    ///         .or(false)
+    ///     let response: u8 = r.table("simbad")
+    ///         .get(1)
+    ///         .bracket("age")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == false);
+    ///     assert!(response == 15);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// The `bracket` command also accepts integer arguments
+    /// as array offsets, like the [nth](Self::nth) command.
+    ///
     /// ## Examples
     ///
-    /// Return whether any of true, true or true evaluate to true.
+    /// How old is Moussa
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.or(args!([true, true, true]))
+    ///     let response: u8 = r.expr([10, 20, 30, 40, 50])
+    ///         .bracket(3)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == 40);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [eq](Self::eq)
-    /// - [ne](Self::ne)
-    /// - [or](Self::or)
-    pub fn and(&self, args: impl and::AndArg) -> Self {
-        and::new(args).with_parent(self)
+    /// - [get_field](Self::get_field)
+    /// - [nth](Self::nth)
+    pub fn bracket(&self, attr: impl Into<CommandArg>) -> Self {
+        bracket::new(attr).with_parent(self)
     }
 
-    /// Compute the logical “or” of one or more values.
+    /// Get a single field from an object.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_value.or(value) → bool
-    /// cmd_value.or(args!(values)) → bool
-    /// r.or(args!(values)) → bool
+    /// query.get_field(attr) → value
     /// ```
     ///
     /// Where:
-    /// - value: `bool` | [Command](crate::Command)
-    /// - values: `impl IntoIterator<Item = bool>` | [Command](crate::Command)
+    /// - attr: `impl Into<String>` | [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// The `or` command can be used as an infix operator after
-    /// its first argument (`r.expr(true).or(false)`) or given all
-    /// of its arguments as parameters (`r.or(args!([true, false]))`).
+    /// If called on a sequence, gets that field from every object
+    /// in the sequence, skipping objects that lack it.
     ///
-    /// Calling `or` with zero arguments will return `false`.
+    /// You may use either `get_field` or its shorthand, `g`.
     ///
     /// ## Examples
     ///
-    /// Return whether either true or false evaluate to true.
+    /// How old is Moussa
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.expr(true)
-    ///         .or(false)
+    ///     let response: u8 = r.table("simbad")
+    ///         .get(1)
+    ///         .get_field("age")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == 15);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [bracket](Self::bracket)
+    /// - [nth](Self::nth)
+    pub fn get_field(&self, attr: impl Into<CommandArg>) -> Self {
+        get_field::new(attr).with_parent(self)
+    }
+
+    /// Get a single field from an object.
     ///
-    /// Return whether any of false, false or false evaluate to false.
+    /// # Command syntax
     ///
+    /// ```text
+    /// query.g(attr) → value
     /// ```
-    /// use neor::{args, r, Converter, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.or(args!([false, false, false]))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    /// Where:
+    /// - attr: `impl Into<String>` | [Command](crate::Command)
     ///
-    ///     assert!(response == false);
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// # Description
     ///
-    /// ## Note
+    /// If called on a sequence, gets that field from every object
+    /// in the sequence, skipping objects that lack it.
     ///
-    /// When using `or` inside a `filter` predicate to test the values of
-    /// fields that may not exist on the documents being tested,
-    /// you should use the `default` command with those fields so
-    /// they explicitly return `false`.
+    /// You may use either `get_field` or its shorthand, `g`.
+    ///
+    /// ## Examples
+    ///
+    /// How old is Moussa
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("table")
-    ///         .filter(func!(|post| post.g("category")
-    ///             .default("foo")
-    ///             .eq("article")
-    ///             .or(post.g("genre").default("foo").eq("mystery"))
-    ///         ))
+    ///     let response: u8 = r.table("simbad")
+    ///         .get(1)
+    ///         .g("age")
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response == 15);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [eq](Self::eq)
-    /// - [ne](Self::ne)
-    /// - [and](Self::and)
-    pub fn or(&self, args: impl or::OrArg) -> Self {
-        or::new(args).with_parent(self)
+    /// - [bracket](Self::bracket)
+    /// - [nth](Self::nth)
+    pub fn g(&self, attr: impl Into<CommandArg>) -> Self {
+        get_field::new(attr).with_parent(self)
     }
 
-    /// Test if two or more values are equal.
+    /// Test if an object has one or more fields.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_value.eq(value) → bool
-    /// cmd_value.eq(args!(values)) → bool
-    /// r.eq(args!(values)) → bool
+    /// query.has_fields(selector) → response
     /// ```
     ///
     /// Where:
-    /// - value: `impl Serialize` | [Command](crate::Command)
-    /// - values: `impl IntoIterator<Item = T>`
+    /// - selector: `impl Serialize` | [Command](crate::Command)
+    /// - response: array | bool
+    ///
+    /// # Description
+    ///
+    /// An object has a field if it has that key and the key has a non-null value.
+    /// For instance, the object `{'a': 1,'b': 2,'c': null}` has the fields `a` and `b`.
+    ///
+    /// When applied to a single object, `has_fields` returns `true` if the object has
+    /// the fields and `false` if it does not. When applied to a sequence, it will return
+    /// a new sequence (an array or stream) containing the elements that have the specified fields.
     ///
     /// ## Examples
     ///
-    /// See if a user’s `role` field is set to `administrator`.
+    /// Return the players who have won games.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("users")
-    ///         .get(1)
-    ///         .g("role")
-    ///         .eq("administrator")
+    ///     let response = r.table("players")
+    ///         .has_fields("games_won")
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
@@ -7416,203 +7568,258 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// See if three variables contain equal values.
+    /// Return the players who have not won games.
+    /// To do this, use `has_fields` with [not](crate::r::not),
+    /// wrapped with [filter](Self::filter).
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.eq(args!([20, 10, 15]))
+    ///     let response = r.table("players")
+    ///         .filter(func!(|player| !player.has_fields("games_won")))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == false);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [ne](Self::ne)
-    /// - [and](Self::and)
-    /// - [or](Self::or)
-    pub fn eq(&self, args: impl eq::EqArg) -> Self {
-        eq::new(args).with_parent(self)
-    }
-
-    /// Test if two or more values are not equal.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// cmd_value.ne(value) → bool
-    /// cmd_value.ne(args!(values)) → bool
-    /// r.ne(args!(values)) → bool
-    /// ```
-    ///
-    /// Where:
-    /// - value: `impl Serialize` | [Command](crate::Command)
-    /// - values: `impl IntoIterator<Item = T>`
-    ///
     /// ## Examples
     ///
-    /// See if a user’s `role` field is not set to `administrator`.
+    /// Test if a specific player has won any games.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("users")
+    ///     let response: bool = r.table("players")
     ///         .get(1)
-    ///         .g("role")
-    ///         .ne("administrator")
+    ///         .has_fields("games_won")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// ## Nested Fields
+    ///
+    /// `has_fields` lets you test for nested fields in objects.
+    /// If the value of a field is itself a set of key/value pairs,
+    /// you can test for the presence of specific keys.
+    ///
     /// ## Examples
     ///
-    /// See if three variables do not contain equal values.
+    /// In the `players` table, the `games_won` field contains one
+    /// or more fields for kinds of games won:
+    ///
+    /// ```text
+    /// {
+    ///     'games_won': {
+    ///         'playoffs': 2,
+    ///         'championships': 1
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Return players who have the “championships” field.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.ne(args!([20, 10, 15]))
+    ///     let response = r.table("players")
+    ///         .has_fields(json!({"games_won": {"championships": true}}))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [eq](Self::eq)
-    /// - [and](Self::and)
-    /// - [or](Self::or)
-    pub fn ne(&self, args: impl ne::NeArg) -> Self {
-        ne::new(args).with_parent(self)
+    /// Note that `true` in the example above is testing for the existence of `championships`
+    /// as a field, not testing to see if the value of the `championships` field is set to `true`.
+    /// There’s a more convenient shorthand form available.
+    /// (See [pluck](Self::pluck) for more details on this.)
+    ///
+    /// ```
+    /// use neor::{r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("players")
+    ///         .has_fields(json!({"games_won": "championships"}))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [get_field](Self::get_field)
+    /// - [with_fields](Self::with_fields)
+    pub fn has_fields(&self, selector: impl Into<CommandArg>) -> Self {
+        has_fields::new(selector).with_parent(self)
     }
 
-    /// Compare values, testing if the left-hand value is greater than the right-hand.
+    /// Insert a value in to an array at a given index. Returns the modified array.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_value.gt(value) → bool
-    /// cmd_value.gt(args!(values)) → bool
-    /// r.gt(args!(values)) → bool
+    /// array.insert_at(offset, value) → array
     /// ```
     ///
     /// Where:
+    /// - offset: `i8, u8, ..., isize, usize` | [Command](crate::Command)
     /// - value: `impl Serialize` | [Command](crate::Command)
-    /// - values: `impl IntoIterator<Item = T>`
     ///
     /// ## Examples
     ///
-    /// Test if a player has scored more than 10 points.
+    /// Alima decide to join Simbad.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("players")
-    ///         .get(1)
-    ///         .g("score")
-    ///         .gt(10)
+    ///     let response: [String; 4] = r.expr(["Moussa", "Ali", "Fati"])
+    ///         .insert_at(1, "Alima")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == ["Moussa", "Alima", "Ali", "Fati"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [splice_at](Self::splice_at)
+    /// - [change_at](Self::change_at)
+    /// - [delete_at](Self::delete_at)
+    pub fn insert_at<O, V>(&self, offset: O, value: V) -> Self
+    where
+        O: Into<CommandArg>,
+        V: Into<CommandArg>,
+    {
+        insert_at::new(offset, value).with_parent(self)
+    }
+
+    /// Insert several values into an array at the given index.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// array.splice_at(offset, values) → array
+    /// ```
+    ///
+    /// Where:
+    /// - offset: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    /// - values: `impl IntoInterator<Item = T>` | [Command](crate::Command)
+    ///
     /// ## Examples
     ///
-    /// Test if variables are ordered from lowest to highest,
-    /// with no values being equal to one another.
+    /// Fati and Alima decide to join Simbad.
     ///
     /// ```
     /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.gt(args!([20, 10, 15]))
+    ///     let response: [String; 4] = r.expr(["Moussa", "Ali"])
+    ///         .splice_at(1, ["Fati", "Alima"])
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == ["Moussa", "Fati", "Alima", "Ali"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [eq](Self::eq)
-    /// - [ne](Self::ne)
-    /// - [ge](Self::ge)
-    /// - [lt](Self::lt)
-    /// - [le](Self::le)
-    pub fn gt(&self, args: impl gt::GtArg) -> Self {
-        gt::new(args).with_parent(self)
+    /// - [insert_at](Self::insert_at)
+    /// - [delete_at](Self::delete_at)
+    /// - [change_at](Self::change_at)
+    pub fn splice_at<O, V>(&self, offset: O, values: V) -> Self
+    where
+        O: Into<CommandArg>,
+        V: Into<CommandArg>,
+    {
+        splice_at::new(offset, values).with_parent(self)
     }
 
-    /// Compare values, testing if the left-hand value is greater than the right-hand.
+    /// Remove one or more elements from an array at a given index.
+    /// (Note: `delete_at` operates on arrays, not documents;
+    /// to delete documents, see the [delete](Self::delete) command.)
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_value.ge(value) → bool
-    /// cmd_value.ge(args!(values)) → bool
-    /// r.ge(args!(values)) → bool
+    /// array.delete_at(offset) → array
+    /// array.delete_at(args!(offset, end_offset)) → array
     /// ```
     ///
     /// Where:
-    /// - value: `impl Serialize` | [Command](crate::Command)
-    /// - values: `impl IntoIterator<Item = T>`
+    /// - offset, end_offset: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// If only `offset` is specified, `delete_at` removes the element at that index.
+    /// If both `offset` and `end_offset` are specified, `delete_at` removes the range
+    /// of elements between `offset` and `end_offset`, inclusive of `offset` but not
+    /// inclusive of `end_offset`.
+    ///
+    /// If `end_offset` is specified, it must not be less than `offset`.
+    /// Both `offset` and `end_offset` must be within the array’s bounds
+    /// (i.e., if the array has 10 elements, an `offset` or `end_offset`
+    /// of 10 or higher is invalid).
+    ///
+    /// By using a negative `offset` you can delete from the end of the array.
+    /// `-1` is the last element in the array, `-2` is the second-to-last element, and so on.
+    /// You may specify a negative `end_offset`, although just as with a positive value,
+    /// this will not be inclusive. The range `(2,-1)` specifies the third element through
+    /// the next-to-last element.
     ///
     /// ## Examples
     ///
-    /// Test if a player has scored more than 10 points.
+    /// Delete the second element of an array.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("players")
-    ///         .get(1)
-    ///         .g("score")
-    ///         .ge(10)
+    ///     let response: [char; 5] = r.expr(['a', 'b', 'c', 'd', 'e', 'f'])
+    ///         .delete_at(1)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == ['a', 'c', 'd', 'e', 'f']);
     ///     
     ///     Ok(())
     /// }
@@ -7620,69 +7827,43 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Test if variables are ordered from lowest to highest,
-    /// with no values being equal to one another.
+    /// Delete the second and third elements of an array.
     ///
     /// ```
     /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.ge(args!([20, 10, 15]))
+    ///     let response: [char; 4] = r.expr(['a', 'b', 'c', 'd', 'e', 'f'])
+    ///         .delete_at(args!(1, 3))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == ['a', 'd', 'e', 'f']);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [eq](Self::eq)
-    /// - [ne](Self::ne)
-    /// - [gt](Self::gt)
-    /// - [lt](Self::lt)
-    /// - [le](Self::le)
-    pub fn ge(&self, args: impl ge::GeArg) -> Self {
-        ge::new(args).with_parent(self)
-    }
-
-    /// Compare values, testing if the left-hand value is less than the right-hand.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// cmd_value.lt(value) → bool
-    /// cmd_value.lt(args!(values)) → bool
-    /// r.lt(args!(values)) → bool
-    /// ```
-    ///
-    /// Where:
-    /// - value: `impl Serialize` | [Command](crate::Command)
-    /// - values: `impl IntoIterator<Item = T>`
-    ///
     /// ## Examples
     ///
-    /// Test if a player has scored less than 10 points.
+    /// Delete the next-to-last element of an array.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("players")
-    ///         .get(1)
-    ///         .g("score")
-    ///         .lt(10)
+    ///     let response: [char; 5] = r.expr(['a', 'b', 'c', 'd', 'e', 'f'])
+    ///         .delete_at(-2)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == ['a', 'b', 'c', 'd', 'f']);
     ///     
     ///     Ok(())
     /// }
@@ -7690,799 +7871,2893 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Test if variables are ordered from highest to lowest,
-    /// with no values being equal to one another.
+    /// Delete a comment on a post.
+    ///
+    /// Given a post document such as:
+    ///
+    /// ```text
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// {
+    ///     "id": 1,
+    ///     "title": "Post title",
+    ///     "author": "Ali",
+    ///     "comments": [
+    ///         { "author": "Agatha", "text": "Comment 1" },
+    ///         { "author": "Fatima", "text": "Comment 2" }
+    ///     ]
+    /// }
+    ///
+    /// The second comment can be deleted by using `update` and `delete_at` together.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.lt(args!([20, 10, 15]))
+    ///     let response: [char; 5] = r.table("posts")
+    ///         .get(1)
+    ///         .update(func!(|post| {
+    ///             let mut comments = HashMap::new();
+    ///             comments.insert("comments", post.g("comments").delete_at(1));
+    ///             r.hash_map(comments)
+    ///         }))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == ['a', 'b', 'c', 'd', 'f']);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [eq](Self::eq)
-    /// - [ne](Self::ne)
-    /// - [gt](Self::gt)
-    /// - [ge](Self::ge)
-    /// - [le](Self::le)
-    pub fn lt(&self, args: impl lt::LtArg) -> Self {
-        lt::new(args).with_parent(self)
+    /// - [insert_at](Self::insert_at)
+    /// - [splice_at](Self::splice_at)
+    /// - [change_at](Self::change_at)
+    pub fn delete_at(&self, args: impl delete_at::DeleteAtArg) -> Self {
+        delete_at::new(args).with_parent(self)
     }
 
-    /// Compare values, testing if the left-hand value is
-    /// less than or equal to the right-hand.
+    /// Change a value in an array at a given index.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_value.le(value) → bool
-    /// cmd_value.le(args!(values)) → bool
-    /// r.le(args!(values)) → bool
+    /// array.change_at(offset, value) → array
     /// ```
     ///
     /// Where:
+    /// - offset: `i8, u8, ..., isize, usize` | [Command](crate::Command)
     /// - value: `impl Serialize` | [Command](crate::Command)
-    /// - values: `impl IntoIterator<Item = T>`
     ///
     /// ## Examples
     ///
-    /// Test if a player has scored 10 points or less.
+    /// Replace Ali by Alima in array.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.table("players")
-    ///         .get(1)
-    ///         .g("score")
-    ///         .le(10)
+    ///     let response: [String; 3] = r.expr(["Moussa", "Ali", "Fati"])
+    ///         .change_at(1, "Alima")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == ["Moussa", "Alima", "Fati"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [insert_at](Self::insert_at)
+    /// - [splice_at](Self::splice_at)
+    /// - [delete_at](Self::delete_at)
+    pub fn change_at<O, V>(&self, offset: O, value: V) -> Self
+    where
+        O: Into<CommandArg>,
+        V: Into<CommandArg>,
+    {
+        change_at::new(offset, value).with_parent(self)
+    }
+
+    /// Return an array containing all of an object’s keys.
     ///
-    /// Test if variables are ordered from highest to lowest.
+    /// # Command syntax
     ///
+    /// ```text
+    /// string.keys() → array
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    ///
+    /// # Description
+    ///
+    /// Note that the keys will be sorted as described in
+    /// [ReQL data types](https://rethinkdb.com/docs/data-types/#sorting-order)
+    /// (for strings, lexicographically).
+    ///
+    /// ## Examples
+    ///
+    /// Get all the keys from a table row.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: bool = r.le(args!([20, 10, 15]))
+    ///     // row: { "id": "1", "mail": "fred@example.com", "name": "fred" }
+    ///     let response: [String; 3] = r.table("users")
+    ///         .get(1)
+    ///         .keys()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == true);
+    ///     assert!(response == ["id", "mail", "name"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [eq](Self::eq)
-    /// - [ne](Self::ne)
-    /// - [gt](Self::gt)
-    /// - [ge](Self::ge)
-    /// - [lt](Self::lt)
-    pub fn le(&self, args: impl le::LeArg) -> Self {
-        le::new(args).with_parent(self)
+    /// - [values](Self::values)
+    pub fn keys(&self) -> Self {
+        keys::new().with_parent(self)
     }
 
-    /// Rounds the given value to the nearest whole integer.
+    /// Return an array containing all of an object’s values.
     ///
     /// # Command syntax
+    ///
     /// ```text
-    /// r.round(param_number) → number
-    /// number.round() → number
+    /// string.values() → array
     /// ```
     ///
-    /// Where:
-    /// - param_number: `f32` | `f64` | [Command](crate::Command)
-    ///
     /// # Description
     ///
-    /// For example, values of 1.0 up to but not including 1.5
-    /// will return 1.0, similar to [floor](Self::floor); values
-    /// of 1.5 up to 2.0 will return 2.0, similar to [ceil](Self::ceil).
+    /// `values()` guarantees the values will
+    /// come out in the same order as [keys](Self::keys).
     ///
     /// ## Examples
     ///
-    /// Round 12.345 to the nearest integer.
+    /// Get all of the values from a table row.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.round(12.345)
+    ///     // row: { "id": "1", "mail": "fred@example.com", "name": "fred" }
+    ///     let response: [String; 3] = r.table("users")
+    ///         .get(1)
+    ///         .values()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 12);
+    ///     assert!(response == ["1", "fred@example.com", "fred"]);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// The `round` command can also be chained after an expression.
+    /// # Related commands
+    /// - [keys](Self::keys)
+    pub fn values(&self) -> Self {
+        values::new().with_parent(self)
+    }
+
+    /// Match a string against a regular expression.
     ///
-    /// ## Examples
+    /// # Command syntax
     ///
-    /// Round -12.345 to the nearest integer.
+    /// ```text
+    /// string.match(regexp) → response
+    /// ```
+    ///
+    /// Where:
+    /// - regexp: `impl Into<String>` | [Command](crate::Command)
+    /// - response: Option<[MatchResponse](crate::types::MatchResponse)>
+    ///
+    /// ## Examples
     ///
     /// ```
+    /// use neor::types::MatchResponse;
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.expr(-12.345)
-    ///         .round()
+    ///     let response: String = r.expr("name@domain.com")
+    ///         .match_(".*@(.*)")
+    ///         .g("groups")
+    ///         .nth(0)
+    ///         .g("str")
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///     let response2: Option<MatchResponse> = r.expr("name[at]domain.com")
+    ///         .match_(".*@(.*)")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == -12);
+    ///     assert!(response == "domain.com");
+    ///     assert!(response2 == None);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [ceil](Self::ceil)
-    /// - [round](Self::round)
-    pub fn round(&self) -> Self {
-        round::new(()).with_parent(self)
+    /// - [upcase](Self::upcase)
+    /// - [downcase](Self::downcase)
+    /// - [split](Self::split)
+    pub fn match_(&self, regexp: impl Into<CommandArg>) -> Self {
+        match_::new(regexp).with_parent(self)
     }
 
-    /// Rounds the given value up, returning the smallest integer value
-    /// greater than or equal to the given value (the value’s ceiling).
+    /// Split a string into substrings.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// r.ceil(param_number) → number
-    /// number.ceil() → number
+    /// string.split(()) → string
+    /// string.split(separator) → string
+    /// string.split(args!(separator, max_splits)) → string
     /// ```
     ///
     /// Where:
-    /// - param_number: `f32` | `f64` | [Command](crate::Command)
+    /// - separator: `String` | `&str` | [Commmand](crate::Command)
+    /// - max_splits: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// With no arguments, will split on whitespace;
+    /// when called with a string as the first argument,
+    /// will split using that string as a separator.
+    /// A maximum number of splits can also be specified.
     ///
     /// ## Examples
     ///
-    /// Return the ceiling of 12.345.
+    /// Split on whitespace.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.ceil(12.345)
+    ///     let data = [
+    ///         String::from("foo"),
+    ///         String::from("bar"),
+    ///         String::from("bax"),
+    ///     ];
+    ///     let response: [String; 3] = r.expr("foo  bar bax")
+    ///         .split(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 13);
+    ///     assert!(response == data);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// The `ceil` command can also be chained after an expression.
-    ///
     /// ## Examples
     ///
-    /// Return the ceiling of -12.345.
+    /// Split the entries in a CSV file.
     ///
     /// ```
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.expr(-12.345)
-    ///         .ceil()
+    ///     let data = [
+    ///         String::from("12"),
+    ///         String::from("37"),
+    ///         String::new(),
+    ///         String::from("22"),
+    ///         String::new(),
+    ///     ];
+    ///     let response: [String; 5] = r.expr("12,37,,22,")
+    ///         .split(",")
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == -13);
+    ///     assert!(response == data);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [floor](Self::floor)
-    /// - [round](Self::round)
-    pub fn ceil(&self) -> Self {
-        ceil::new(()).with_parent(self)
-    }
-
-    /// Rounds the given value down, returning the largest integer
-    /// value less than or equal to the given value (the value’s floor).
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Split a string into characters.
     ///
-    /// ```text
-    /// r.floor(param_number) → number
-    /// number.floor() → number
     /// ```
+    /// use neor::{r, Converter, Result};
     ///
-    /// Where:
-    /// - param_number: `f32` | `f64` | [Command](crate::Command)
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let data = [
+    ///         String::from("t"),
+    ///         String::from("o"),
+    ///         String::from("t"),
+    ///         String::from("o"),
+    ///     ];
+    ///     let response: [String; 4] = r.expr("toto")
+    ///         .split("")
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == data);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Return the floor of 12.345.
+    /// Split the entries in a CSV file, but only at most 3 times.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.floor(12.345)
+    ///     let data = [
+    ///         String::from("12"),
+    ///         String::from("37"),
+    ///         String::new(),
+    ///         String::from("22,"),
+    ///     ];
+    ///     let response: [String; 4] = r.expr("12,37,,22,")
+    ///         .split(args!(",", 3))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 13);
+    ///     assert!(response == data);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// The `floor` command can also be chained after an expression.
-    ///
     /// ## Examples
     ///
-    /// Return the floor of -12.345.
+    /// Split on whitespace at most once (i.e. get the first word).
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.expr(-12.345)
-    ///         .floor()
+    ///     let data = [
+    ///         String::from("foo"),
+    ///         String::from("bar bax"),
+    ///     ];
+    ///     let response: [String; 2] = r.expr("foo  bar bax")
+    ///         .split(args!(" ", 1))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == -13);
+    ///     assert!(response == data);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [ceil](Self::ceil)
-    /// - [round](Self::round)
-    pub fn floor(&self) -> Self {
-        floor::new(()).with_parent(self)
+    /// - [upcase](Self::upcase)
+    /// - [downcase](Self::downcase)
+    /// - [match](Self::match_)
+    pub fn split(&self, args: impl split::SplitArg) -> Self {
+        split::new(args).with_parent(self)
     }
 
-    /// Compute the arithmetic "and" of one or more values.
+    /// Uppercases a string.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_number & cmd_number
-    /// number.bit_and(param_number) → number
-    /// r.bit_and(param_number, param_number) → number
+    /// string.upcase() → string
     /// ```
     ///
-    /// Where:
-    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    /// - cmd_number: [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// A bitwise AND is a binary operation that takes two equal-length binary
-    /// representations and performs the logical AND operation on each pair of
-    /// the corresponding bits, which is equivalent to multiplying them.
-    /// Thus, if both bits in the compared position are 1,
-    /// the bit in the resulting binary representation is 1 (1 × 1 = 1);
-    /// otherwise, the result is 0 (1 × 0 = 0 and 0 × 0 = 0).
-    ///
     /// ## Examples
     ///
-    /// Compute the arithmetic "and" of 5 and 3
-    ///
     /// ```
-    /// use std::ops::BitAnd;
-    ///
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.expr(5)
-    ///         .bit_and(3)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
-    ///
-    ///     let response2: i32 = r.bit_and(r.expr(5), 3)
+    ///     let response: String = r.expr("Sentence about LaTeX.")
+    ///         .upcase()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     let response3: i32 = (r.expr(5) & r.expr(3))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     assert!(response == "SENTENCE ABOUT LATEX.");
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
-    ///     let response4: i32 = r.expr(5)
-    ///         .bitand(r.expr(3))
+    /// ## Note
+    ///
+    /// `upcase` and `downcase` only affect ASCII characters.
+    ///
+    /// # Related commands
+    /// - [downcase](Self::downcase)
+    /// - [match](Self::match_)
+    /// - [split](Self::split)
+    pub fn upcase(&self) -> Self {
+        upcase::new().with_parent(self)
+    }
+
+    /// Lowercase a string.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// string.downcase() → string
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: String = r.expr("Sentence about LaTeX.")
+    ///         .downcase()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(
-    ///         response == 1 &&
-    ///         response == response2 &&
-    ///         response == response3 &&
-    ///         response == response4
-    ///     );
+    ///     assert!(response == "sentence about latex.");
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// ## Note
+    ///
+    /// `upcase` and `downcase` only affect ASCII characters.
+    ///
     /// # Related commands
-    /// - [bit_or](Self::bit_or)
-    /// - [bit_not](Self::bit_not)
-    /// - [bit_xor](Self::bit_xor)
-    /// - [bit_sal](Self::bit_sal)
-    /// - [bit_sar](Self::bit_sar)
-    pub fn bit_and(&self, number: impl Into<CommandArg>) -> Self {
-        self.clone().bitand(number)
+    /// - [upcase](Self::upcase)
+    /// - [match](Self::match_)
+    /// - [split](Self::split)
+    pub fn downcase(&self) -> Self {
+        downcase::new().with_parent(self)
     }
 
-    /// Compute the arithmetic "or" of one or more values.
+    /// Compute the logical “and” of one or more values.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_number | cmd_number
-    /// number.bit_or(param_number) → number
-    /// r.bit_or(param_number, param_number) → number
+    /// cmd_value.and(value) → bool
+    /// cmd_value.and(values) → bool
+    /// r.and(values) → bool
     /// ```
     ///
     /// Where:
-    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    /// - cmd_number: [Command](crate::Command)
+    /// - value: `bool` | [Command](crate::Command)
+    /// - values: `impl IntoIterator<Item = bool>` | [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// A bitwise OR is a binary operation that takes two bit patterns
-    /// of equal length and performs the logical inclusive OR operation
-    /// on each pair of corresponding bits. The result in each position
-    /// is 0 if both bits are 0, while otherwise the result is 1.
+    /// The `and` command can be used as an infix operator after its
+    /// first argument (`r.expr(true).and(false)`) or given all of
+    /// its arguments as parameters (`r.and(args!([true, false]))`).
+    ///
+    /// Calling `or` with zero arguments will return `false`.
     ///
     /// ## Examples
     ///
-    /// Compute the arithmetic "or" of 6 and 4
+    /// Return whether either true or false evaluate to true.
     ///
     /// ```
-    /// use std::ops::BitOr;
-    ///
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.expr(5)
-    ///         .bit_or(3)
+    ///     let response: bool = r.expr(true)
+    ///         .or(false)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     let response2: i32 = r.bit_or(r.expr(5), 3)
+    ///     assert!(response == false);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return whether any of true, true or true evaluate to true.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.or(args!([true, true, true]))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     let response3: i32 = (r.expr(5) ^ r.expr(3))
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [eq](Self::eq)
+    /// - [ne](Self::ne)
+    /// - [or](Self::or)
+    pub fn and(&self, args: impl and::AndArg) -> Self {
+        and::new(args).with_parent(self)
+    }
+
+    /// Compute the logical “or” of one or more values.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_value.or(value) → bool
+    /// cmd_value.or(args!(values)) → bool
+    /// r.or(args!(values)) → bool
+    /// ```
+    ///
+    /// Where:
+    /// - value: `bool` | [Command](crate::Command)
+    /// - values: `impl IntoIterator<Item = bool>` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// The `or` command can be used as an infix operator after
+    /// its first argument (`r.expr(true).or(false)`) or given all
+    /// of its arguments as parameters (`r.or(args!([true, false]))`).
+    ///
+    /// Calling `or` with zero arguments will return `false`.
+    ///
+    /// ## Examples
+    ///
+    /// Return whether either true or false evaluate to true.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.expr(true)
+    ///         .or(false)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     let response4: i32 = r.expr(5)
-    ///         .bitor(r.expr(3))
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return whether any of false, false or false evaluate to false.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.or(args!([false, false, false]))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(
-    ///         response == 7 &&
-    ///         response == response2 &&
-    ///         response == response3 &&
-    ///         response == response4
-    ///     );
+    ///     assert!(response == false);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Note
+    ///
+    /// When using `or` inside a `filter` predicate to test the values of
+    /// fields that may not exist on the documents being tested,
+    /// you should use the `default` command with those fields so
+    /// they explicitly return `false`.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("table")
+    ///         .filter(func!(|post| post.g("category")
+    ///             .default("foo")
+    ///             .eq("article")
+    ///             .or(post.g("genre").default("foo").eq("mystery"))
+    ///         ))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [bit_and](Self::bit_and)
-    /// - [bit_not](Self::bit_not)
-    /// - [bit_xor](Self::bit_xor)
-    /// - [bit_sal](Self::bit_sal)
-    /// - [bit_sar](Self::bit_sar)
-    pub fn bit_or(&self, number: impl Into<CommandArg>) -> Self {
-        self.clone().bitor(number)
+    /// - [eq](Self::eq)
+    /// - [ne](Self::ne)
+    /// - [and](Self::and)
+    pub fn or(&self, args: impl or::OrArg) -> Self {
+        or::new(args).with_parent(self)
     }
 
-    /// Compute the arithmetic "and" of one or more values.
+    /// Test if two or more values are equal.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// cmd_number ^ cmd_number
-    /// number.bit_xor(param_number) → number
-    /// r.bit_xor(param_number, param_number) → number
+    /// cmd_value.eq(value) → bool
+    /// cmd_value.eq(args!(values)) → bool
+    /// r.eq(args!(values)) → bool
     /// ```
     ///
     /// Where:
-    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
-    /// - cmd_number: [Command](crate::Command)
-    ///
-    /// # Description
-    ///
-    /// A bitwise XOR is a binary operation that takes two bit patterns
-    /// of equal length and performs the logical exclusive OR operation
-    /// on each pair of corresponding bits. The result in each position
-    /// is 1 if only the first bit is 1 or only the second bit is 1,
-    /// but will be 0 if both are 0 or both are 1.
-    /// In this we perform the comparison of two bits, being 1 if the
-    /// two bits are different, and 0 if they are the same.
+    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// - values: `impl IntoIterator<Item = T>`
     ///
     /// ## Examples
     ///
-    /// Compute the arithmetic "and" of 6 and 4
+    /// See if a user’s `role` field is set to `administrator`.
     ///
     /// ```
-    /// use std::ops::BitXor;
-    ///
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.expr(6)
-    ///         .bit_xor(4)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
-    ///
-    ///     let response2: i32 = r.bit_xor(r.expr(6), 4)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
-    ///
-    ///     let response3: i32 = (r.expr(6) ^ r.expr(4))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let response: bool = r.table("users")
+    ///         .get(1)
+    ///         .g("role")
+    ///         .eq("administrator")
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// See if three variables contain equal values.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.eq(args!([20, 10, 15]))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == false);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [ne](Self::ne)
+    /// - [and](Self::and)
+    /// - [or](Self::or)
+    pub fn eq(&self, args: impl eq::EqArg) -> Self {
+        eq::new(args).with_parent(self)
+    }
+
+    /// Test if two or more values are not equal.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_value.ne(value) → bool
+    /// cmd_value.ne(args!(values)) → bool
+    /// r.ne(args!(values)) → bool
+    /// ```
+    ///
+    /// Where:
+    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// - values: `impl IntoIterator<Item = T>`
+    ///
+    /// ## Examples
+    ///
+    /// See if a user’s `role` field is not set to `administrator`.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.table("users")
+    ///         .get(1)
+    ///         .g("role")
+    ///         .ne("administrator")
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// See if three variables do not contain equal values.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.ne(args!([20, 10, 15]))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [eq](Self::eq)
+    /// - [and](Self::and)
+    /// - [or](Self::or)
+    pub fn ne(&self, args: impl ne::NeArg) -> Self {
+        ne::new(args).with_parent(self)
+    }
+
+    /// Compare values, testing if the left-hand value is greater than the right-hand.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_value.gt(value) → bool
+    /// cmd_value.gt(args!(values)) → bool
+    /// r.gt(args!(values)) → bool
+    /// ```
+    ///
+    /// Where:
+    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// - values: `impl IntoIterator<Item = T>`
+    ///
+    /// ## Examples
+    ///
+    /// Test if a player has scored more than 10 points.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.table("players")
+    ///         .get(1)
+    ///         .g("score")
+    ///         .gt(10)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Test if variables are ordered from lowest to highest,
+    /// with no values being equal to one another.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.gt(args!([20, 10, 15]))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [eq](Self::eq)
+    /// - [ne](Self::ne)
+    /// - [ge](Self::ge)
+    /// - [lt](Self::lt)
+    /// - [le](Self::le)
+    pub fn gt(&self, args: impl gt::GtArg) -> Self {
+        gt::new(args).with_parent(self)
+    }
+
+    /// Compare values, testing if the left-hand value is greater than the right-hand.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_value.ge(value) → bool
+    /// cmd_value.ge(args!(values)) → bool
+    /// r.ge(args!(values)) → bool
+    /// ```
+    ///
+    /// Where:
+    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// - values: `impl IntoIterator<Item = T>`
+    ///
+    /// ## Examples
+    ///
+    /// Test if a player has scored more than 10 points.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.table("players")
+    ///         .get(1)
+    ///         .g("score")
+    ///         .ge(10)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Test if variables are ordered from lowest to highest,
+    /// with no values being equal to one another.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.ge(args!([20, 10, 15]))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [eq](Self::eq)
+    /// - [ne](Self::ne)
+    /// - [gt](Self::gt)
+    /// - [lt](Self::lt)
+    /// - [le](Self::le)
+    pub fn ge(&self, args: impl ge::GeArg) -> Self {
+        ge::new(args).with_parent(self)
+    }
+
+    /// Compare values, testing if the left-hand value is less than the right-hand.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_value.lt(value) → bool
+    /// cmd_value.lt(args!(values)) → bool
+    /// r.lt(args!(values)) → bool
+    /// ```
+    ///
+    /// Where:
+    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// - values: `impl IntoIterator<Item = T>`
+    ///
+    /// ## Examples
+    ///
+    /// Test if a player has scored less than 10 points.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.table("players")
+    ///         .get(1)
+    ///         .g("score")
+    ///         .lt(10)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Test if variables are ordered from highest to lowest,
+    /// with no values being equal to one another.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.lt(args!([20, 10, 15]))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [eq](Self::eq)
+    /// - [ne](Self::ne)
+    /// - [gt](Self::gt)
+    /// - [ge](Self::ge)
+    /// - [le](Self::le)
+    pub fn lt(&self, args: impl lt::LtArg) -> Self {
+        lt::new(args).with_parent(self)
+    }
+
+    /// Compare values, testing if the left-hand value is
+    /// less than or equal to the right-hand.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_value.le(value) → bool
+    /// cmd_value.le(args!(values)) → bool
+    /// r.le(args!(values)) → bool
+    /// ```
+    ///
+    /// Where:
+    /// - value: `impl Serialize` | [Command](crate::Command)
+    /// - values: `impl IntoIterator<Item = T>`
+    ///
+    /// ## Examples
+    ///
+    /// Test if a player has scored 10 points or less.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.table("players")
+    ///         .get(1)
+    ///         .g("score")
+    ///         .le(10)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Test if variables are ordered from highest to lowest.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.le(args!([20, 10, 15]))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == true);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [eq](Self::eq)
+    /// - [ne](Self::ne)
+    /// - [gt](Self::gt)
+    /// - [ge](Self::ge)
+    /// - [lt](Self::lt)
+    pub fn le(&self, args: impl le::LeArg) -> Self {
+        le::new(args).with_parent(self)
+    }
+
+    /// Compute the logical inverse (not) of an expression.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_bool.not_() → bool
+    /// !cmd_bool → bool
+    /// ```
+    ///
+    /// Where:
+    /// - cmd_bool: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// Same as the `!` operator, without having to import `std::ops::Not`
+    /// to call it as a method. All values that are not `false` or `None`
+    /// will be converted to `true`.
+    ///
+    /// ## Examples
+    ///
+    /// Find the players who have not won a game yet.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("players")
+    ///         .filter(func!(|player| player.has_fields("games_won").not_()))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [eq](Self::eq)
+    /// - [ne](Self::ne)
+    /// - [and](Self::and)
+    /// - [or](Self::or)
+    pub fn not_(&self) -> Self {
+        not::new().with_parent(self)
+    }
+
+    /// Rounds the given value to the nearest whole integer.
+    ///
+    /// # Command syntax
+    /// ```text
+    /// r.round(param_number) → number
+    /// number.round() → number
+    /// ```
+    ///
+    /// Where:
+    /// - param_number: `impl Serialize` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// For example, values of 1.0 up to but not including 1.5
+    /// will return 1.0, similar to [floor](Self::floor); values
+    /// of 1.5 up to 2.0 will return 2.0, similar to [ceil](Self::ceil).
+    ///
+    /// ## Examples
+    ///
+    /// Round 12.345 to the nearest integer.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.round(12.345)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == 12);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// The `round` command can also be chained after an expression.
+    ///
+    /// ## Examples
+    ///
+    /// Round -12.345 to the nearest integer.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.expr(-12.345)
+    ///         .round()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == -12);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [ceil](Self::ceil)
+    /// - [round](Self::round)
+    pub fn round(&self) -> Self {
+        round::new().with_parent(self)
+    }
+
+    /// Rounds the given value up, returning the smallest integer value
+    /// greater than or equal to the given value (the value’s ceiling).
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// r.ceil(param_number) → number
+    /// number.ceil() → number
+    /// ```
+    ///
+    /// Where:
+    /// - param_number: `impl Serialize` | [Command](crate::Command)
+    ///
+    /// ## Examples
+    ///
+    /// Return the ceiling of 12.345.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.ceil(12.345)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == 13);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// The `ceil` command can also be chained after an expression.
+    ///
+    /// ## Examples
+    ///
+    /// Return the ceiling of -12.345.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.expr(-12.345)
+    ///         .ceil()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == -13);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [floor](Self::floor)
+    /// - [round](Self::round)
+    pub fn ceil(&self) -> Self {
+        ceil::new().with_parent(self)
+    }
+
+    /// Rounds the given value down, returning the largest integer
+    /// value less than or equal to the given value (the value’s floor).
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// r.floor(param_number) → number
+    /// number.floor() → number
+    /// ```
+    ///
+    /// Where:
+    /// - param_number: `impl Serialize` | [Command](crate::Command)
+    ///
+    /// ## Examples
+    ///
+    /// Return the floor of 12.345.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.floor(12.345)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == 12);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// The `floor` command can also be chained after an expression.
+    ///
+    /// ## Examples
+    ///
+    /// Return the floor of -12.345.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.expr(-12.345)
+    ///         .floor()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == -13);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [ceil](Self::ceil)
+    /// - [round](Self::round)
+    pub fn floor(&self) -> Self {
+        floor::new().with_parent(self)
+    }
+
+    /// Compute the arithmetic "and" of one or more values.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_number & cmd_number
+    /// number.bit_and(param_number) → number
+    /// r.bit_and(param_number, param_number) → number
+    /// ```
+    ///
+    /// Where:
+    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    /// - cmd_number: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// A bitwise AND is a binary operation that takes two equal-length binary
+    /// representations and performs the logical AND operation on each pair of
+    /// the corresponding bits, which is equivalent to multiplying them.
+    /// Thus, if both bits in the compared position are 1,
+    /// the bit in the resulting binary representation is 1 (1 × 1 = 1);
+    /// otherwise, the result is 0 (1 × 0 = 0 and 0 × 0 = 0).
+    ///
+    /// ## Examples
+    ///
+    /// Compute the arithmetic "and" of 5 and 3
+    ///
+    /// ```
+    /// use std::ops::BitAnd;
+    ///
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.expr(5)
+    ///         .bit_and(3)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response2: i32 = r.bit_and(r.expr(5), 3)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response3: i32 = (r.expr(5) & r.expr(3))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response4: i32 = r.expr(5)
+    ///         .bitand(r.expr(3))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(
+    ///         response == 1 &&
+    ///         response == response2 &&
+    ///         response == response3 &&
+    ///         response == response4
+    ///     );
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [bit_or](Self::bit_or)
+    /// - [bit_not](Self::bit_not)
+    /// - [bit_xor](Self::bit_xor)
+    /// - [bit_sal](Self::bit_sal)
+    /// - [bit_sar](Self::bit_sar)
+    pub fn bit_and(&self, number: impl Into<CommandArg>) -> Self {
+        self.clone().bitand(number)
+    }
+
+    /// Compute the arithmetic "or" of one or more values.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_number | cmd_number
+    /// number.bit_or(param_number) → number
+    /// r.bit_or(param_number, param_number) → number
+    /// ```
+    ///
+    /// Where:
+    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    /// - cmd_number: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// A bitwise OR is a binary operation that takes two bit patterns
+    /// of equal length and performs the logical inclusive OR operation
+    /// on each pair of corresponding bits. The result in each position
+    /// is 0 if both bits are 0, while otherwise the result is 1.
+    ///
+    /// ## Examples
+    ///
+    /// Compute the arithmetic "or" of 6 and 4
+    ///
+    /// ```
+    /// use std::ops::BitOr;
+    ///
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.expr(5)
+    ///         .bit_or(3)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response2: i32 = r.bit_or(r.expr(5), 3)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response3: i32 = (r.expr(5) ^ r.expr(3))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response4: i32 = r.expr(5)
+    ///         .bitor(r.expr(3))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(
+    ///         response == 7 &&
+    ///         response == response2 &&
+    ///         response == response3 &&
+    ///         response == response4
+    ///     );
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [bit_and](Self::bit_and)
+    /// - [bit_not](Self::bit_not)
+    /// - [bit_xor](Self::bit_xor)
+    /// - [bit_sal](Self::bit_sal)
+    /// - [bit_sar](Self::bit_sar)
+    pub fn bit_or(&self, number: impl Into<CommandArg>) -> Self {
+        self.clone().bitor(number)
+    }
+
+    /// Compute the arithmetic "and" of one or more values.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// cmd_number ^ cmd_number
+    /// number.bit_xor(param_number) → number
+    /// r.bit_xor(param_number, param_number) → number
+    /// ```
+    ///
+    /// Where:
+    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    /// - cmd_number: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// A bitwise XOR is a binary operation that takes two bit patterns
+    /// of equal length and performs the logical exclusive OR operation
+    /// on each pair of corresponding bits. The result in each position
+    /// is 1 if only the first bit is 1 or only the second bit is 1,
+    /// but will be 0 if both are 0 or both are 1.
+    /// In this we perform the comparison of two bits, being 1 if the
+    /// two bits are different, and 0 if they are the same.
+    ///
+    /// ## Examples
+    ///
+    /// Compute the arithmetic "and" of 6 and 4
+    ///
+    /// ```
+    /// use std::ops::BitXor;
+    ///
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.expr(6)
+    ///         .bit_xor(4)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response2: i32 = r.bit_xor(r.expr(6), 4)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response3: i32 = (r.expr(6) ^ r.expr(4))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response4: i32 = r.expr(6)
+    ///         .bitxor(r.expr(4))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(
+    ///         response == 2 &&
+    ///         response == response2 &&
+    ///         response == response3 &&
+    ///         response == response4
+    ///     );
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [bit_and](Self::bit_and)
+    /// - [bit_not](Self::bit_not)
+    /// - [bit_or](Self::bit_or)
+    /// - [bit_sal](Self::bit_sal)
+    /// - [bit_sar](Self::bit_sar)
+    pub fn bit_xor(&self, number: impl Into<CommandArg>) -> Self {
+        self.clone().bitxor(number)
+    }
+
+    /// Compute the arithmetic inverse (not) of an expression.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// number.bit_not() → number
+    /// r.bit_not(cmd_number) → number
+    /// ```
+    ///
+    /// Where:
+    /// - cmd_number: [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// A bitwise NOT, or complement, is a unary operation that performs logical
+    /// negation on each bit, forming the ones’ complement of the given binary value.
+    /// Bits that are 0 become 1, and those that are 1 become 0.
+    ///
+    /// ## Examples
+    ///
+    /// Negate the arithmetice expression
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: i32 = r.expr(7)
+    ///         .bit_not()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response2: i32 = r.bit_not(r.expr(7))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == -8 && response == response2);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [bit_and](Self::bit_and)
+    /// - [bit_or](Self::bit_or)
+    /// - [bit_sal](Self::bit_sal)
+    /// - [bit_sar](Self::bit_sar)
+    /// - [bit_xor](Self::bit_xor)
+    pub fn bit_not(&self) -> Self {
+        bit_not::new().with_parent(self)
+    }
+
+    /// Compute the left arithmetic shift (left logical shift) of one or more values.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// number.bit_sal(param_number) → number
+    /// r.bit_sal(param_number, param_number) → number
+    /// number << param_number → number
+    /// ```
+    ///
+    /// Where:
+    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// In an arithmetic shift (also referred to as signed shift),
+    /// like a logical shift, the bits that slide off the end disappear
+    /// (except for the last, which goes into the carry flag).
+    /// But in an arithmetic shift, the spaces are filled in such a way
+    /// to preserve the sign of the number being slid. For this reason,
+    /// arithmetic shifts are better suited for signed numbers in two’s
+    /// complement format.
+    ///
+    /// ## Note
+    ///
+    /// SHL and SAL are the same, and differentiation only happens because
+    /// SAR and SHR (right shifting) has differences in their implementation.
+    ///
+    /// ## Examples
+    ///
+    /// Compute the left arithmetic shift of 5 and 4
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: u8 = r.expr(5)
+    ///         .bit_sal(4)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response2: u8 = r.bit_sal(r.expr(5), r.expr(4))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response3: u8 = (r.expr(5) << 4)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == 80 && response == response2 && response == response3);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [bit_and](Self::bit_and)
+    /// - [bit_not](Self::bit_not)
+    /// - [bit_or](Self::bit_or)
+    /// - [bit_sar](Self::bit_sar)
+    /// - [bit_xor](Self::bit_xor)
+    pub fn bit_sal(&self, number: impl Into<CommandArg>) -> Self {
+        bit_sal::new(number).with_parent(self)
+    }
+
+    /// Compute the right arithmetic shift of one or more values.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// number.bit_sar(param_number) → number
+    /// r.bit_sar(param_number, param_number) → number
+    /// number >> param_number → number
+    /// ```
+    ///
+    /// Where:
+    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// In an arithmetic shift (also referred to as signed shift),
+    /// like a logical shift, the bits that slide off the end disappear
+    /// (except for the last, which goes into the carry flag).
+    /// But in an arithmetic shift, the spaces are filled in such
+    /// a way to preserve the sign of the number being slid.
+    /// For this reason, arithmetic shifts are better suited for
+    /// signed numbers in two’s complement format.
+    ///
+    /// ## Examples
+    ///
+    /// Compute the right arithmetic shift of 32 and 3
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: u8 = r.expr(32)
+    ///         .bit_sar(3)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response2: u8 = r.bit_sar(r.expr(32), r.expr(3))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     let response3: u8 = (r.expr(32) >> 3)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == 4 && response == response2 && response == response3);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [bit_and](Self::bit_and)
+    /// - [bit_not](Self::bit_not)
+    /// - [bit_or](Self::bit_or)
+    /// - [bit_sal](Self::bit_sal)
+    /// - [bit_xor](Self::bit_xor)
+    pub fn bit_sar(&self, number: impl Into<CommandArg>) -> Self {
+        bit_sar::new(number).with_parent(self)
+    }
+
+    /// Return a new time object with a different timezone.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.in_timezone(timezone) → time
+    /// ```
+    ///
+    /// Where:
+    /// - timezone: [UtcOffset](UtcOffset)
+    /// - time: [Time](crate::types::Time)
+    ///
+    /// # Description
+    ///
+    /// While the time stays the same, the results returned by methods such
+    /// as hours() will change since they take the timezone into account.
+    /// The timezone argument has to be of the ISO 8601 format.
+    ///
+    /// ## Examples
+    ///
+    /// Hour of the day in San Francisco (UTC/GMT -8, without daylight saving time).
+    ///
+    /// ```
+    /// use neor::types::Time;
+    /// use neor::{r, Converter, Result};
+    /// use time::macros::offset;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let date_time = r.now().in_timezone(offset!(-08:00));
+    ///     let time1: Time = date_time.value();
+    ///     let time2: Time = date_time.cmd()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(time1.is_valid());
+    ///     assert!(time2.is_valid());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [timezone](Self::timezone)
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    pub fn in_timezone(&self, timezone: UtcOffset) -> Self {
+        in_timezone::new(timezone).with_parent(self)
+    }
+
+    /// Return the timezone of the time object.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.timezone() → String
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return all the users in the “-07:00” timezone.
+    ///
+    /// ```
+    /// use neor::{func, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("subscription_date").timezone().lt("-07:00")))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    /// - [in_timezone](Self::in_timezone)
+    pub fn timezone(&self) -> Self {
+        timezone::new().with_parent(self)
+    }
+
+    /// Return whether a time is between two other times.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.during(args!(start_time, end_time)) -> bool
+    /// time.during(args!(start_time, end_time, options)) -> bool
+    /// time.during(range) -> bool
+    /// ```
+    ///
+    /// Where:
+    /// - start_time, end_time: [DateTime](crate::types::DateTime), `OffsetDateTime`, [Command](crate::Command)
+    /// - options: [DuringOption](crate::arguments::DuringOption)
+    /// - range: `impl RangeBounds<OffsetDateTime>`, e.g. `start..end`, `start..=end` or `start..`
+    ///
+    /// The start and end times may have different types, e.g. a time and
+    /// [max_val](crate::r::max_val) for all the times after it.
+    /// A range sets the bounds of `options` from its own, and an unbounded
+    /// end of the range is [min_val](crate::r::min_val) or [max_val](crate::r::max_val).
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve all the posts that were posted between December 1st,
+    /// 2013 (inclusive) and December 10th, 2013 (exclusive).
+    ///
+    /// ```
+    /// use neor::{args, func, r, Result};
+    /// use time::macros::{date, offset};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let start_date = r.time(args!(date!(2013 - 12 - 01), offset!(UTC)));
+    ///     let end_date = r.time(args!(date!(2013 - 12 - 10), offset!(UTC)));
+    ///     let response = r.table("posts")
+    ///         .filter(func!(|post| post.g("date").during(args!(start_date, end_date))))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve all the posts that were posted between December 1st,
+    /// 2013 (exclusive) and December 10th, 2013 (inclusive).
+    ///
+    /// ```
+    /// use neor::arguments::{DuringOption, Status};
+    /// use neor::{args, func, r, Result};
+    /// use time::macros::{date, offset};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let start_date = r.time(args!(date!(2013 - 12 - 01), offset!(UTC)));
+    ///     let end_date = r.time(args!(date!(2013 - 12 - 10), offset!(UTC)));
+    ///     let during_options = DuringOption::default()
+    ///         .left_bound(Status::Open)
+    ///         .right_bound(Status::Closed);
+    ///     let response = r.table("posts")
+    ///         .filter(func!(|post| post.g("date").during(args!(
+    ///             start_date,
+    ///             end_date,
+    ///             during_options
+    ///         ))))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve all the posts that were posted during December 2013,
+    /// including the last second of the month.
+    ///
+    /// ```
+    /// use neor::{r, Result};
+    /// use time::macros::datetime;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let december = datetime!(2013-12-01 0:00 UTC)..=datetime!(2013-12-31 23:59:59 UTC);
+    ///     let response = r.table("posts")
+    ///         .filter(r.row().g("date").during(december))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    /// - [in_timezone](Self::in_timezone)
+    pub fn during(&self, args: impl during::DuringArg) -> Self {
+        during::new(args).with_parent(self)
+    }
+
+    /// Return a new time struct only based on the day,
+    /// month and year (ie. the same day at 00:00).
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.date() → time
+    /// ```
+    ///
+    /// Where:
+    /// - time: [Time](crate::types::Time)
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve all the users whose birthday is today.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("birthdate").date().eq(r.now().cmd().date())))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Note that the [now](crate::r::now) command always returns UTC time, so the
+    /// comparison may fail if `user.g("birthdate")` isn’t also in UTC.
+    /// You can use the [in_timezone](Self::in_timezone) command to adjust for this:
+    ///
+    /// ```
+    /// use time::macros::offset;
+    ///
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("birthdate").date().eq(
+    ///             r.now().cmd().in_timezone(offset!(-08:00)).date()
+    ///         )))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    /// - [in_timezone](Self::in_timezone)
+    pub fn date(&self) -> Self {
+        date::new().with_parent(self)
+    }
+
+    /// Return the number of seconds elapsed since the
+    /// beginning of the day stored in the time object.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.time_of_day() → f64
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve posts that were submitted before noon.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("posts")
+    ///         .filter(func!(|post| post.g("date").time_of_day().le(12*60*60)))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    /// - [in_timezone](Self::in_timezone)
+    pub fn time_of_day(&self) -> Self {
+        time_of_day::new().with_parent(self)
+    }
+
+    /// Return the year of a time object.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.year() → i32
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve all the users born in 1986.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("birthdate").year().eq(r.expr(1986))))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    pub fn year(&self) -> Self {
+        year::new().with_parent(self)
+    }
+
+    /// Return the month of a time object as a number between 1 and 12.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.month() → u8
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve all the users who were born in November.
+    ///
+    /// ```
+    /// use neor::{r, func, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("birthdate").month().eq(r.expr(11))))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    pub fn month(&self) -> Self {
+        month::new().with_parent(self)
+    }
+
+    /// Return the day of a time object as a number between 1 and 31.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.day() → u8
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return the users born on the 24th of any month.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("birthdate").day().eq(r.expr(24))))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    pub fn day(&self) -> Self {
+        day::new().with_parent(self)
+    }
+
+    /// Return the day of week of a time object as a number
+    /// between 1 and 7 (following ISO 8601 standard).
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.day_of_week() → u8
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return today’s day of week.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let day_of_week = r.now().day_of_week();
+    ///     let day_of_week1 = day_of_week.value();
+    ///     let day_of_week2: u8 = day_of_week.cmd()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(day_of_week1 == day_of_week2);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve all the users who were born on a Tuesday.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("birthdate").day_of_week().eq(r.expr(2))))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    pub fn day_of_week(&self) -> Self {
+        day_of_week::new().with_parent(self)
+    }
+
+    /// Return the day of the year of a time object as a number
+    /// between 1 and 366 (following ISO 8601 standard).
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.day_of_year() → u16
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Retrieve all the users who were born the first day of a year.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("birthdate").day_of_year().eq(r.expr(1))))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    pub fn day_of_year(&self) -> Self {
+        day_of_year::new().with_parent(self)
+    }
+
+    /// Return the hour in a time object as a number between 0 and 23.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.hours() → u8
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return all the posts submitted after midnight and before 4am.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("posts")
+    ///         .filter(func!(|post| post.g("date").hours().lt(4)))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    /// - [in_timezone](Self::in_timezone)
+    pub fn hours(&self) -> Self {
+        hours::new().with_parent(self)
+    }
+
+    /// Return the minute in a time object as a number between 0 and 59.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.minutes() → u8
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return all the posts submitted during the first 10 minutes of every hour.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("posts")
+    ///         .filter(func!(|post| post.g("date").minutes().lt(10)))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    /// - [in_timezone](Self::in_timezone)
+    pub fn minutes(&self) -> Self {
+        minutes::new().with_parent(self)
+    }
+
+    /// Return the seconds in a time object as a number between 0 and 59.999.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.seconds() → f64
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return the post submitted during the first 30 seconds of every minute.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("posts")
+    ///         .filter(func!(|post| post.g("date").seconds().lt(30)))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    /// - [in_timezone](Self::in_timezone)
+    pub fn seconds(&self) -> Self {
+        seconds::new().with_parent(self)
+    }
+
+    /// Convert a time object to a string in ISO 8601 format.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.to_iso8601() → String
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return the current ISO 8601 time.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let iso8601 = r.now().to_iso8601();
+    ///     let iso8601_1 = iso8601.value();
+    ///     let iso8601_2: String = iso8601.cmd()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(!iso8601_1.is_empty());
+    ///     assert!(!iso8601_2.is_empty());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    pub fn to_iso8601(&self) -> Self {
+        to_iso8601::new().with_parent(self)
+    }
+
+    /// Convert a time object to its epoch time.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// time.to_epoch_time() → f64
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return the current time in seconds since
+    /// the Unix Epoch with millisecond-precision.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let time = r.now().to_epoch_time();
+    ///     let time1: f64 = time.value();
+    ///     let time2: f64 = time.cmd()
+    ///         .run(&conn)
+    ///         .await?.unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(time1.is_normal());
+    ///     assert!(time2.is_normal());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [now](crate::r::now)
+    /// - [time](crate::r::time)
+    /// - [to_iso8601](Self::to_iso8601)
+    pub fn to_epoch_time(&self) -> Self {
+        to_epoch_time::new().with_parent(self)
+    }
+
+    //// Call an anonymous function using return values
+    /// from other ReQL commands or queries as arguments.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// any.do_(predicate) → any
+    /// r.do_(arg, predicate) → any
+    /// r.do_(args, predicate) → any
+    /// ```
+    /// Where:
+    /// - predicate: [Func](crate::Func) | [Command](crate::Command) | `impl Serialize`
+    /// - arg: [Command](crate::Command)
+    /// - args: `impl IntoIterator<Item = T>`
+    /// - T: `impl Serialize` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// The last argument to `do_` (or, in some forms, the only argument) is an
+    /// expression or an anonymous function which receives values from either
+    /// the previous arguments or from prefixed commands chained before `do_`.
+    /// The `do_` command is essentially a single-element [map](Self::map),
+    /// letting you map a function over just one document.
+    /// This allows you to bind a query result to a
+    /// local variable within the scope of `do_`,
+    /// letting you compute the result just once and reuse it in
+    /// a complex expression or in a series of ReQL commands.
+    ///
+    /// Arguments passed to the `do_` function must be basic data types,
+    /// and cannot be streams or selections.
+    /// ([Read about ReQL data types](https://rethinkdb.com/docs/data-types/).)
+    /// While the arguments will all be evaluated before the function is executed,
+    /// they may be evaluated in any order, so their values should not be dependent on one another.
+    /// The type of `do_`’s result is the type of the value returned from the function or last expression.
+    ///
+    /// ## Examples
+    ///
+    /// Compute a golfer’s net score for a game.
+    ///
+    /// ```
+    /// use neor::{func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("players")
+    ///         .get("86be93eb-a112-48f5-a829-15b2cb49de1d")
+    ///         .do_(func!(|player| player.g("gross_score") - player.g("course_handicap")))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Return the name of the best scoring player in a two-player golf match.
+    ///
+    /// ```
+    /// use neor::{args, func, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let (id1, id2) = (1, 2);
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.do_(
+    ///         [r.table("players").get(id1), r.table("players").get(id2)],
+    ///         func!(|player1, player2| r.branch(
+    ///             player1.g("gross_score").lt(player2.g("gross_score")),
+    ///             args!(player1, player2)
+    ///         ))
+    ///     )
+    ///     .run(&conn)
+    ///     .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Note that `branch`, the ReQL conditional command, must be used instead of `if`.
+    /// See the `branch` [documentation](Self::branch) for more.
+    ///
+    /// ## Examples
+    ///
+    /// Take different actions based on the result of a ReQL insert command.
+    ///
+    /// ```
+    /// use neor::{args, func, r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let new_data = json!({
+    ///         "id": 100,
+    ///         "name": "Agatha",
+    ///         "gross_score": 57,
+    ///         "course_handicap": 4
+    ///     });
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("players")
+    ///     .insert(new_data)
+    ///     .do_(func!(|doc| r.branch(
+    ///         doc.g("inserted").ne(0),
+    ///         args!(
+    ///             r.table("log").insert(json!({"time": r.now(), "result": "ok"})),
+    ///             r.table("log").insert(json!({"time": r.now(), "result": "error"}))
+    ///         )
+    ///     )))
+    ///     .run(&conn)
+    ///     .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [map](Self::map)
+    pub fn do_(&self, expr: impl Into<CommandArg>) -> Self {
+        do_::new(expr).with_arg(self.to_owned())
+    }
+
+    /// Perform a branching conditional equivalent to `if-then-else`.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// r.branch(test, args!(true_action, false_action)) → any
+    /// r.branch(test, args!(true_action, tests, false_action)) → any
+    /// query.branch(args!(true_action, false_action)) -> any
+    /// query.branch(args!(true_action, tests, false_action)) → any
+    /// ```
+    ///
+    /// Where:
+    /// - action, true_action, false_action: `impl Serialize` | [Command](crate::Command)
+    /// - test: `bool` | [Command](crate::Command)
+    /// - tests: `impl IntoIterator<Item = (test, action)>` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// The `branch` command takes 2n+1 arguments: pairs of conditional expressions
+    /// and commands to be executed if the conditionals return any value but `false`
+    /// or `None` i.e., “truthy” values), with a final “else” command to be evaluated
+    /// if all of the conditionals are `false` or `None`.
+    ///
+    /// You may call `branch` infix style on the first test.
+    /// (See the second example for an illustration.)
+    ///
+    /// ```text
+    /// r.branch(test1, args!(val1, [(test2, val2)], elseval))
+    /// ```
+    ///
+    /// is the equivalent of the Rust statement
+    ///
+    /// ```text
+    /// if (test1) {
+    ///     val1
+    /// } else if (test2) {
+    ///     val2
+    /// } else {
+    ///     elseval
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Test the value of x.
+    ///
+    /// ```
+    /// use neor::{args, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let x = 10;
+    ///     let conn = r.connection().connect().await?;
     ///
-    ///     let response4: i32 = r.expr(6)
-    ///         .bitxor(r.expr(4))
+    ///     let response: String = r.branch(x > 5, args!("big", "small"))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(
-    ///         response == 2 &&
-    ///         response == response2 &&
-    ///         response == response3 &&
-    ///         response == response4
-    ///     );
+    ///     assert!(response.eq("big"));
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [bit_and](Self::bit_and)
-    /// - [bit_not](Self::bit_not)
-    /// - [bit_or](Self::bit_or)
-    /// - [bit_sal](Self::bit_sal)
-    /// - [bit_sar](Self::bit_sar)
-    pub fn bit_xor(&self, number: impl Into<CommandArg>) -> Self {
-        self.clone().bitxor(number)
-    }
-
-    /// Compute the arithmetic inverse (not) of an expression.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// As above, infix-style.
     ///
-    /// ```text
-    /// number.bit_not() → number
-    /// r.bit_not(cmd_number) → number
     /// ```
+    /// use neor::{args, r, Converter, Result};
     ///
-    /// Where:
-    /// - cmd_number: [Command](crate::Command)
+    /// async fn example() -> Result<()> {
+    ///     let x = 10;
+    ///     let conn = r.connection().connect().await?;
     ///
-    /// # Description
+    ///     let response: String = r.expr(x > 5)
+    ///         .branch(args!("big", "small"))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    /// A bitwise NOT, or complement, is a unary operation that performs logical
-    /// negation on each bit, forming the ones’ complement of the given binary value.
-    /// Bits that are 0 become 1, and those that are 1 become 0.
+    ///     assert!(response.eq("big"));
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Negate the arithmetice expression
+    /// Categorize heroes by victory counts.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use std::ops::Add;
+    ///
+    /// use neor::{args, func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: i32 = r.expr(7)
-    ///         .bit_not()
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
     ///
-    ///     let response2: i32 = r.bit_not(r.expr(7))
+    ///     let response = r.table("pricings")
+    ///         .map(func!(|offer| r.branch(
+    ///             offer.g("price").gt(100),
+    ///             args!(
+    ///                 offer.g("offer").add("premium"),
+    ///                 [(
+    ///                     offer.g("price").gt(10),
+    ///                     offer.g("offer").add("standard")
+    ///                 )],
+    ///                 offer.g("offer").add("freemium")
+    ///         ))))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(response == -8 && response == response2);
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [bit_and](Self::bit_and)
-    /// - [bit_or](Self::bit_or)
-    /// - [bit_sal](Self::bit_sal)
-    /// - [bit_sar](Self::bit_sar)
-    /// - [bit_xor](Self::bit_xor)
-    pub fn bit_not(&self) -> Self {
-        bit_not::new().with_parent(self)
+    /// - [do_](Self::do_)
+    pub fn branch(&self, args: impl branch::BranchArg) -> Self {
+        branch::new(args).with_parent(self)
     }
 
-    /// Compute the left arithmetic shift (left logical shift) of one or more values.
+    /// Loop over a sequence, evaluating the given write query for each element.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// number.bit_sal(param_number) → number
-    /// r.bit_sal(param_number, param_number) → number
+    /// sequence.for_each(write_function) → response
     /// ```
     ///
     /// Where:
-    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    /// - write_function: [Func](crate::Func)
+    /// - response: [MutationResponse](crate::types::MutationResponse)
     ///
     /// # Description
     ///
-    /// In an arithmetic shift (also referred to as signed shift),
-    /// like a logical shift, the bits that slide off the end disappear
-    /// (except for the last, which goes into the carry flag).
-    /// But in an arithmetic shift, the spaces are filled in such a way
-    /// to preserve the sign of the number being slid. For this reason,
-    /// arithmetic shifts are better suited for signed numbers in two’s
-    /// complement format.
-    ///
-    /// ## Note
-    ///
-    /// SHL and SAL are the same, and differentiation only happens because
-    /// SAR and SHR (right shifting) has differences in their implementation.
+    /// The function returns a write query, or an array of write queries,
+    /// for each element. The whole loop runs on the server and
+    /// the counters of the writes are summed in a single response.
     ///
     /// ## Examples
     ///
-    /// Compute the left arithmetic shift of 5 and 4
+    /// Delete the cars of the models marked as discontinued.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::types::MutationResponse;
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.expr(5)
-    ///         .bit_sar(4)
+    ///
+    ///     let response: MutationResponse = r.table("models")
+    ///         .filter(func!(|model| model.g("discontinued")))
+    ///         .for_each(func!(|model| r.table("cars")
+    ///             .get_all(model.g("id"))
+    ///             .delete(())
+    ///         ))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     let response2: u8 = r.bit_sar(r.expr(5), r.expr(4))
+    ///     assert!(response.deleted == 5);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Archive the posts of a user, then delete them.
+    ///
+    /// ```
+    /// use neor::types::MutationResponse;
+    /// use neor::{func, r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     let response: MutationResponse = r.table("posts")
+    ///         .filter(func!(|post| post.g("author").eq("alice")))
+    ///         .for_each(func!(|post| r.array([
+    ///             r.table("archives").insert(post.clone()),
+    ///             r.table("posts").get(post.g("id")).delete(()),
+    ///         ])))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 80 && response == response2);
+    ///     assert!(response.inserted == response.deleted);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [bit_and](Self::bit_and)
-    /// - [bit_not](Self::bit_not)
-    /// - [bit_or](Self::bit_or)
-    /// - [bit_sar](Self::bit_sar)
-    /// - [bit_xor](Self::bit_xor)
-    pub fn bit_sal(&self, number: impl Into<CommandArg>) -> Self {
-        bit_sal::new(number).with_parent(self)
+    /// - [map](Self::map)
+    pub fn for_each(&self, write_function: Func) -> Self {
+        for_each::new(write_function).with_parent(self)
     }
 
-    /// Compute the right arithmetic shift of one or more values.
+    /// Provide a default value in case of non-existence errors.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// number.bit_sar(param_number) → number
-    /// r.bit_sar(param_number, param_number) → number
+    /// value.default(default_value) → any
+    /// sequence.default(default_value) → any
     /// ```
     ///
     /// Where:
-    /// - param_number: `i8, u8, ..., isize, usize` | [Command](crate::Command)
+    /// - default_value: `impl Serialize` | [Command](crate::Command) | [Func](crate::Func) | [DefaultOption](crate::arguments::DefaultOption)
     ///
     /// # Description
     ///
-    /// In an arithmetic shift (also referred to as signed shift),
-    /// like a logical shift, the bits that slide off the end disappear
-    /// (except for the last, which goes into the carry flag).
-    /// But in an arithmetic shift, the spaces are filled in such
-    /// a way to preserve the sign of the number being slid.
-    /// For this reason, arithmetic shifts are better suited for
-    /// signed numbers in two’s complement format.
+    /// The `default` command evaluates its first argument (the value it’s chained to).
+    /// If that argument returns `None` or a non-existence error is thrown in evaluation,
+    /// then `default` returns its second argument. The second argument is usually a default value,
+    /// but it can be a function that returns a value.
+    /// The function receives the message of the error, or `None` if the value is `None`.
+    ///
+    /// With a [DefaultOption](crate::arguments::DefaultOption), `error_to_null(true)`
+    /// turns the non-existence errors into `None`, e.g. for the aggregations of an
+    /// empty sequence, while `error_to_null(false)` raises them again.
     ///
     /// ## Examples
     ///
-    /// Compute the right arithmetic shift of 32 and 3
+    /// Suppose we want to retrieve the titles and authors of the table posts.
+    /// In the case where the author field is missing or null,
+    /// we want to retrieve the string Anonymous.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::{func, r, Converter, Result};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// struct Post {
+    ///     title: String,
+    ///     author: String,
+    /// }
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.expr(32)
-    ///         .bit_sar(3)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
     ///
-    ///     let response2: u8 = r.bit_sar(r.expr(32), r.expr(3))
+    ///     let response: Vec<Post> = r.table("posts")
+    ///         .map(func!(|doc| {
+    ///             let mut post = HashMap::new();
+    ///             post.insert("title", doc.g("title"));
+    ///             post.insert("author", doc.g("author").default("Anonymous"));
+    ///             r.hash_map(post)
+    ///         }))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 4 && response == response2);
+    ///     assert!(response.len() > 0);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [bit_and](Self::bit_and)
-    /// - [bit_not](Self::bit_not)
-    /// - [bit_or](Self::bit_or)
-    /// - [bit_sal](Self::bit_sal)
-    /// - [bit_xor](Self::bit_xor)
-    pub fn bit_sar(&self, number: impl Into<CommandArg>) -> Self {
-        bit_sar::new(number).with_parent(self)
-    }
-
-    /// Return a new time object with a different timezone.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// The `default` command can also be used to filter documents.
+    /// Suppose we want to retrieve all our users who are not grown-ups or
+    /// whose age is unknown (i.e., the field `age` is missing or equals `None`).
+    /// We can do it with this query:
     ///
-    /// ```text
-    /// time.in_timezone(timezone) → time
     /// ```
+    /// use neor::{func, r, Result};
     ///
-    /// Where:
-    /// - timezone: [UtcOffset](UtcOffset)
-    /// - time: [Time](crate::types::Time)
-    ///
-    /// # Description
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
     ///
-    /// While the time stays the same, the results returned by methods such
-    /// as hours() will change since they take the timezone into account.
-    /// The timezone argument has to be of the ISO 8601 format.
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("age").lt(18).default(true)))
+    ///         .run(&conn)
+    ///         .await?;
     ///
-    /// ## Examples
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
-    /// Hour of the day in San Francisco (UTC/GMT -8, without daylight saving time).
+    /// One more way to write the previous query is
+    /// to set the age to be `-1` when the field is missing.
     ///
     /// ```
-    /// use neor::types::Time;
-    /// use neor::{r, Converter, Result};
-    /// use time::macros::offset;
+    /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let date_time = r.now().in_timezone(offset!(-08:00));
-    ///     let time1: Time = date_time.value();
-    ///     let time2: Time = date_time.cmd()
+    ///
+    ///     let response = r.table("users")
+    ///         .filter(func!(|user| user.g("age").default(-1).lt(18)))
     ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(time1.is_valid());
-    ///     assert!(time2.is_valid());
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [timezone](Self::timezone)
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    pub fn in_timezone(&self, timezone: UtcOffset) -> Self {
-        in_timezone::new(timezone).with_parent(self)
-    }
-
-    /// Return the timezone of the time object.
-    ///
-    /// # Command syntax
+    /// Another way to do the same query is to use hasFields.
     ///
-    /// ```text
-    /// time.timezone() → String
     /// ```
+    /// use std::ops::Not;
     ///
-    /// ## Examples
-    ///
-    /// Return all the users in the “-07:00” timezone.
-    ///
-    /// ```
-    /// use neor::{func, r, Converter, Result};
+    /// use neor::{func, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///
     ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("subscription_date").timezone().lt("-07:00")))
+    ///         .filter(func!(|user| user.has_fields("age").not().or(user.g("age").lt(18))))
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -8492,114 +10767,124 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    /// - [in_timezone](Self::in_timezone)
-    pub fn timezone(&self) -> Self {
-        timezone::new().with_parent(self)
-    }
-
-    /// Return whether a time is between two other times.
-    ///
-    /// # Command syntax
+    /// Another way to do the same query is to use hasFields.
     ///
-    /// ```text
-    /// time.during(args!(start_time, end_time)) -> bool
-    /// time.during(args!(start_time, end_time, options)) -> bool
     /// ```
+    /// use neor::arguments::FilterOption;
+    /// use neor::{args, func, r, Result};
     ///
-    /// Where:
-    /// - start_time, end_time: [DateTime](crate::types::DateTime), [Command](crate::Command)
-    /// - options: [DuringOption](crate::arguments::DuringOption)
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     let response = r.table("users")
+    ///         .filter(args!(
+    ///             func!(|user| user.g("age").lt(18).default(true)),
+    ///             FilterOption::default().default_(true)
+    ///         ))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Retrieve all the posts that were posted between December 1st,
-    /// 2013 (inclusive) and December 10th, 2013 (exclusive).
+    /// Tell apart a missing field from a field set to `None`.
     ///
     /// ```
     /// use neor::{args, func, r, Result};
-    /// use time::macros::{date, offset};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let start_date = r.time(args!(date!(2013 - 12 - 01), offset!(UTC)));
-    ///     let end_date = r.time(args!(date!(2013 - 12 - 10), offset!(UTC)));
+    ///
     ///     let response = r.table("posts")
-    ///         .filter(func!(|post| post.g("date").during(args!(start_date, end_date))))
+    ///         .map(func!(|post| post.g("author").default(func!(|error| r.branch(
+    ///             error.eq(()),
+    ///             args!("null author", "missing author")
+    ///         )))))
     ///         .run(&conn)
     ///         .await?;
     ///
     ///     assert!(response.is_some());
-    ///     
+    ///
     ///     Ok(())
     /// }
     /// ```
     ///
     /// ## Examples
     ///
-    /// Retrieve all the posts that were posted between December 1st,
-    /// 2013 (exclusive) and December 10th, 2013 (inclusive).
+    /// Get `None` instead of an error for the maximum of an empty table.
     ///
     /// ```
-    /// use neor::arguments::{DuringOption, Status};
-    /// use neor::{args, func, r, Result};
-    /// use time::macros::{date, offset};
+    /// use neor::arguments::DefaultOption;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let start_date = r.time(args!(date!(2013 - 12 - 01), offset!(UTC)));
-    ///     let end_date = r.time(args!(date!(2013 - 12 - 10), offset!(UTC)));
-    ///     let during_options = DuringOption::default()
-    ///         .left_bound(Status::Open)
-    ///         .right_bound(Status::Closed);
-    ///     let response = r.table("posts")
-    ///         .filter(func!(|post| post.g("date").during(args!(
-    ///             start_date,
-    ///             end_date,
-    ///             during_options
-    ///         ))))
+    ///
+    ///     let response: Option<u32> = r.table("posts")
+    ///         .max("views")
+    ///         .g("views")
+    ///         .default(DefaultOption::default().error_to_null(true))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.is_none());
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    /// - [in_timezone](Self::in_timezone)
-    pub fn during(&self, args: impl during::DuringArg) -> Self {
-        during::new(args).with_parent(self)
+    pub fn default(&self, default_value: impl default::DefaultArg) -> Self {
+        default::new(default_value).with_parent(self)
     }
 
-    /// Return a new time struct only based on the day,
-    /// month and year (ie. the same day at 00:00).
+    /// Convert a value of one type into another.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// time.date() → time
+    /// sequence.coerce_to('array') → array
+    /// value.coerce_to('string') → string
+    /// string.coerce_to('number') → number
+    /// array.coerce_to('object') → object
+    /// sequence.coerce_to('object') → object
+    /// object.coerce_to('array') → array
+    /// binary.coerce_to('string') → string
+    /// string.coerce_to('binary') → binary
+    ///
+    /// query.coerce_to(type) → response
     /// ```
     ///
     /// Where:
-    /// - time: [Time](crate::types::Time)
+    /// - type: [CoerceType](crate::arguments::CoerceType) | `impl Into<String>` |
+    /// [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// - a sequence, selection or object can be coerced to an array
+    /// - a sequence, selection or an array of key-value pairs can be coerced to an object
+    /// - a string can be coerced to a number
+    /// - any datum (single value) can be coerced to a string
+    /// - a binary object can be coerced to a string and vice-versa
     ///
     /// ## Examples
     ///
-    /// Retrieve all the users whose birthday is today.
+    /// Coerce an array of pairs into an object.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::arguments::CoerceType;
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("birthdate").date().eq(r.now().cmd().date())))
+    ///
+    ///     let response = r.expr([["name", "Malika"], ["genre", "woman"]])
+    ///         .coerce_to(CoerceType::Object)
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -8609,330 +10894,432 @@ impl<'a> Command {
     /// }
     /// ```
     ///
-    /// Note that the [now](crate::r::now) command always returns UTC time, so the
-    /// comparison may fail if `user.g("birthdate")` isn’t also in UTC.
-    /// You can use the [in_timezone](Self::in_timezone) command to adjust for this:
+    /// ## Examples
     ///
-    /// ```
-    /// use time::macros::offset;
+    /// Coerce a number to a string.
     ///
-    /// use neor::{func, r, Result};
+    /// ```
+    /// use neor::arguments::CoerceType;
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("birthdate").date().eq(
-    ///             r.now().cmd().in_timezone(offset!(-08:00)).date()
-    ///         )))
+    ///
+    ///     let response: char = r.expr(1)
+    ///         .coerce_to(CoerceType::String)
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response == '1');
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    /// - [in_timezone](Self::in_timezone)
-    pub fn date(&self) -> Self {
-        date::new().with_parent(self)
+    /// - [object](crate::r::object)
+    pub fn coerce_to(&self, value: impl Into<CommandArg>) -> Self {
+        coerce_to::new(value).with_parent(self)
     }
 
-    /// Return the number of seconds elapsed since the
-    /// beginning of the day stored in the time object.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// time.time_of_day() → f64
-    /// ```
+    /// Coerce a sequence, selection or object to an array,
+    /// shorthand for `coerce_to(CoerceType::Array)`.
     ///
     /// ## Examples
     ///
-    /// Retrieve posts that were submitted before noon.
-    ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .filter(func!(|post| post.g("date").time_of_day().le(12*60*60)))
-    ///         .run(&conn)
-    ///         .await?;
     ///
-    ///     assert!(response.is_some());
-    ///     
+    ///     let response: Vec<u8> = r.range(3).to_array().run(&conn).await?.unwrap().parse()?;
+    ///
+    ///     assert!(response == [0, 1, 2]);
+    ///
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    /// - [in_timezone](Self::in_timezone)
-    pub fn time_of_day(&self) -> Self {
-        time_of_day::new().with_parent(self)
+    /// - [coerce_to](Self::coerce_to)
+    pub fn to_array(&self) -> Self {
+        self.coerce_to(CoerceType::Array)
     }
 
-    /// Return the year of a time object.
+    /// Coerce a datum or a binary object to a string,
+    /// shorthand for `coerce_to(CoerceType::String)`.
+    ///
+    /// # Related commands
+    /// - [coerce_to](Self::coerce_to)
+    /// - [to_json](Self::to_json)
+    pub fn to_string_(&self) -> Self {
+        self.coerce_to(CoerceType::String)
+    }
+
+    /// Coerce a string to a number,
+    /// shorthand for `coerce_to(CoerceType::Number)`.
+    ///
+    /// # Related commands
+    /// - [coerce_to](Self::coerce_to)
+    pub fn to_number(&self) -> Self {
+        self.coerce_to(CoerceType::Number)
+    }
+
+    /// Dispatch on the type of a value on the server.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// time.year() → i32
+    /// any.match_type(cases) → any
     /// ```
     ///
+    /// Where:
+    /// - cases: [TypeMatch](crate::cmd::match_type::TypeMatch)
+    ///
+    /// # Description
+    ///
+    /// The value is evaluated once and given to the case matching
+    /// its [type_of](Self::type_of), the cases are tested in order.
+    /// When no case matches, the `otherwise` case is used,
+    /// or an error is thrown if there is none.
+    ///
     /// ## Examples
     ///
-    /// Retrieve all the users born in 1986.
+    /// Read prices stored as numbers or as strings by legacy clients.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::cmd::match_type::TypeMatch;
+    /// use neor::types::TypeOf;
+    /// use neor::{func, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("birthdate").year().eq(r.expr(1986))))
+    ///     let prices: Vec<f64> = r.table("products")
+    ///         .map(func!(|product| product.g("price").default(r.expr(0)).match_type(
+    ///             TypeMatch::new()
+    ///                 .case(TypeOf::Number, |price| price)
+    ///                 .case(TypeOf::String, |price| price.coerce_to("number"))
+    ///                 .otherwise(|_| r.expr(0))
+    ///         )))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(prices.iter().all(|price| *price >= 0.));
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    pub fn year(&self) -> Self {
-        year::new().with_parent(self)
+    /// - [type_of](Self::type_of)
+    /// - [branch](Self::branch)
+    pub fn match_type(&self, cases: match_type::TypeMatch) -> Self {
+        match_type::new(self, cases)
     }
 
-    /// Return the month of a time object as a number between 1 and 12.
+    /// Gets the type of a ReQL query’s return value.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// time.month() → u8
+    /// any.type_of() → response
     /// ```
     ///
+    /// Where:
+    /// - response: [TypeOf](crate::types::TypeOf)
+    ///
+    /// # Description
+    ///
+    /// Read the article on [ReQL data types](https://rethinkdb.com/docs/data-types/)
+    /// for a more detailed discussion.
+    /// Note that some possible return values from `type_of` are internal values,
+    /// such as `TypeOf::MAXVAL`, and unlikely to be returned from queries in standard practice.
+    ///
     /// ## Examples
     ///
-    /// Retrieve all the users who were born in November.
+    /// Get the type of a TypeOf.
     ///
     /// ```
-    /// use neor::{r, func, Result};
+    /// use neor::types::TypeOf;
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("birthdate").month().eq(r.expr(11))))
+    ///
+    ///     let response: TypeOf = r.expr("foo")
+    ///         .type_of()
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response == TypeOf::String);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    pub fn month(&self) -> Self {
-        month::new().with_parent(self)
+    pub fn type_of(&self) -> Self {
+        type_of::new().with_parent(self)
     }
 
-    /// Return the day of a time object as a number between 1 and 31.
+    /// Get information about a ReQL value.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// time.day() → u8
+    /// any.info() → response
+    /// r.info(any) → response
     /// ```
     ///
+    /// Where:
+    /// - response: [InfoResponse](crate::types::InfoResponse)
+    ///
     /// ## Examples
     ///
-    /// Return the users born on the 24th of any month.
+    /// Get information about a table such as primary key, or cache size.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::types::{InfoResponse, TypeOf};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("birthdate").day().eq(r.expr(24))))
+    ///
+    ///     let response: InfoResponse = r.table("simbad")
+    ///         .info()
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.typ == TypeOf::Table);
+    ///     assert!(response.primary_key.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    pub fn day(&self) -> Self {
-        day::new().with_parent(self)
-    }
-
-    /// Return the day of week of a time object as a number
-    /// between 1 and 7 (following ISO 8601 standard).
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// time.day_of_week() → u8
-    /// ```
-    ///
     /// ## Examples
     ///
-    /// Return today’s day of week.
+    /// Get information about a database.
     ///
     /// ```
+    /// use neor::types::{InfoResponse, TypeOf};
     /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let day_of_week = r.now().day_of_week();
-    ///     let day_of_week1 = day_of_week.value();
-    ///     let day_of_week2: u8 = day_of_week.cmd()
+    ///
+    ///     let response: InfoResponse = r.db("test")
+    ///         .info()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(day_of_week1 == day_of_week2);
+    ///     assert!(response.typ == TypeOf::Db);
+    ///     assert!(response.name == Some("test".into()));
     ///     
     ///     Ok(())
     /// }
     /// ```
+    pub fn info(&self) -> Self {
+        info::new().with_parent(self)
+    }
+
+    /// Convert a ReQL value or object to a JSON string.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// value.to_json() → String
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Retrieve all the users who were born on a Tuesday.
+    /// Get a ReQL document as a JSON string.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("birthdate").day_of_week().eq(r.expr(2))))
+    ///
+    ///     let response: String = r.table("simbad")
+    ///         .get(1)
+    ///         .to_json()
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(!response.is_empty());
     ///     
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    pub fn day_of_week(&self) -> Self {
-        day_of_week::new().with_parent(self)
+    pub fn to_json(&self) -> Self {
+        to_json::new().with_parent(self)
     }
 
-    /// Return the day of the year of a time object as a number
-    /// between 1 and 366 (following ISO 8601 standard).
+    /// Compute the distance between a point and another geometry object.
+    /// At least one of the geometry objects specified must be a point.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// time.day_of_year() → u16
+    /// geometry.distance(geometry) → f64
+    /// geometry.distance(args!(geometry, options)) → f64
+    /// r.distance(geometry, geometry) → f64
+    /// r.distance(geometry, args!(geometry, options)) → f64
     /// ```
     ///
+    /// Where:
+    /// - geometry: [r.point(...)](crate::r::point) |
+    /// [r.line(...)](crate::r::line) |
+    /// [r.polygon(...)](crate::r::polygon)
+    /// - options: [DistanceOption](crate::arguments::DistanceOption)
+    ///
+    /// # Description
+    ///
+    /// If one of the objects is a polygon or a line, the point will be projected
+    /// into the line or polygon assuming a perfect sphere model before the distance
+    /// is computed (using the model specified with `geo_system`).
+    /// As a consequence, if the polygon or line is extremely large compared
+    /// to Earth’s radius and the distance is being computed with the default
+    ///  WGS84 model, the results of `distance` should be considered approximate
+    /// due to the deviation between the ellipsoid and spherical models.
+    ///
+    /// The distance is returned in meters unless `unit` is set,
+    /// [Unit::convert](crate::types::Unit::convert) converts it afterwards.
+    ///
     /// ## Examples
     ///
-    /// Retrieve all the users who were born the first day of a year.
+    /// Compute the distance between two points on the Earth in kilometers.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::arguments::{DistanceOption, Unit};
+    /// use neor::{args, r, Converter, Geometry, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("birthdate").day_of_year().eq(r.expr(1))))
+    ///     let point1 = r.point(-122.423246, 37.779388);
+    ///     let point2 = r.point(-117.220406, 32.719464);
+    ///     let distance_option = DistanceOption::default().unit(Unit::Kilometer);
+    ///
+    ///     let response: f64 = point1
+    ///         .distance(args!(point2, distance_option))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response == 734.125249602186);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    pub fn day_of_year(&self) -> Self {
-        day_of_year::new().with_parent(self)
+    /// - [polygon](crate::r::polygon)
+    /// - [line](crate::r::line)
+    pub fn distance(&self, args: impl distance::DistanceArg) -> Self {
+        distance::new(args).with_parent(self)
     }
 
-    /// Return the hour in a time object as a number between 0 and 23.
+    /// Convert a ReQL geometry object to a [GeoJSON](https://geojson.org/) object.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// time.hours() → u8
+    /// geometry.to_geojson() → response
     /// ```
     ///
+    /// Where:
+    /// - geometry: [r.point(...)](crate::r::point) |
+    /// [r.line(...)](crate::r::line) |
+    /// [r.polygon(...)](crate::r::polygon)
+    /// command
+    /// - response: [GeoJson<T>](crate::types::GeoJson)
+    ///
     /// ## Examples
     ///
-    /// Return all the posts submitted after midnight and before 4am.
+    /// Convert a ReQL geometry object to a GeoJSON object.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::types::{GeoJson, GeoType};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .filter(func!(|post| post.g("date").hours().lt(4)))
+    ///
+    ///     let response: GeoJson<[f64; 2]> = r.table("simbad")
+    ///         .get("sfo")
+    ///         .g("location")
+    ///         .to_geojson()
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.typ == GeoType::Point);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    /// - [in_timezone](Self::in_timezone)
-    pub fn hours(&self) -> Self {
-        hours::new().with_parent(self)
+    /// - [geojson](crate::r::geojson)
+    pub fn to_geojson(&self) -> Self {
+        to_geojson::new().with_parent(self)
     }
 
-    /// Return the minute in a time object as a number between 0 and 59.
+    /// Get all documents where the given geometry object intersects
+    /// the geometry object of the requested geospatial index.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// time.minutes() → u8
+    /// table.get_intersecting(geometry, index) → selection<stream>
     /// ```
     ///
+    /// Where:
+    /// - geometry: [r.point(...)](crate::r::point) |
+    /// [r.line(...)](crate::r::line) |
+    /// [r.polygon(...)](crate::r::polygon)
+    /// command
+    /// - index: `&'static str` | `String`
+    ///
+    /// # Description
+    ///
+    /// The `index` argument is mandatory. This command returns the same
+    ///  results as `|row| row.g(index).intersects(geometry)`.
+    /// The total number of results is limited to the array size limit
+    /// which defaults to 100,000, but can be changed with the `array_limit`
+    /// option to [run](Self::run).
+    ///
     /// ## Examples
     ///
-    /// Return all the posts submitted during the first 10 minutes of every hour.
+    /// Which of the locations in a list of parks intersect `circle`?
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::arguments::{CircleOption, Unit};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .filter(func!(|post| post.g("date").minutes().lt(10)))
+    ///     let point = r.point(-117.220406, 32.719464);
+    ///     let circle_opts = CircleOption::default()
+    ///         .unit(Unit::InternationalMile);
+    ///     let circle = r.circle(args!(point, 10., circle_opts));
+    ///
+    ///     let response = r.table("simbad")
+    ///         .get_intersecting(circle, "area")
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -8943,179 +11330,240 @@ impl<'a> Command {
     /// ```
     ///
     /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    /// - [in_timezone](Self::in_timezone)
-    pub fn minutes(&self) -> Self {
-        minutes::new().with_parent(self)
+    /// - [get_nearest](Self::get_nearest)
+    pub fn get_intersecting(
+        &self,
+        geometry: impl get_intersecting::GetIntersectingArg,
+        index: impl crate::StaticString,
+    ) -> Self {
+        get_intersecting::new(geometry, index).with_parent(self)
     }
 
-    /// Return the seconds in a time object as a number between 0 and 59.999.
+    /// Return a list of documents closest to a
+    /// specified point based on a geospatial index,
+    /// sorted in order of increasing distance.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// table.get_nearest(args!(geometry, index)) → array
+    /// table.get_nearest(args!(geometry, index, options)) → array
+    /// ```
+    ///
+    /// Where:
+    /// - geometry: [r.point(...)](crate::r::point) |
+    /// [r.line(...)](crate::r::line) |
+    /// [r.polygon(...)](crate::r::polygon) |
+    /// command
+    /// - index: `&str`
+    /// - options: [GetNearestOption](crate::arguments::GetNearestOption)
+    /// - array: `Vec<`[NearestResponse](crate::types::NearestResponse)`<T>>`
     ///
-    /// # Command syntax
+    /// # Description
     ///
-    /// ```text
-    /// time.seconds() → f64
-    /// ```
+    /// The return value will be an array of two-item objects
+    /// with the keys `dist` and `doc`, set to the distance
+    /// between the specified point and the document
+    /// (in the units specified with `unit`, defaulting to meters)
+    /// and the document itself, respectively.
+    /// The array will be sorted by the values of `dist`.
     ///
     /// ## Examples
     ///
-    /// Return the post submitted during the first 30 seconds of every minute.
+    /// Return a list of the closest 25 enemy hideouts to the secret base.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::arguments::{GetNearestOption, Unit};
+    /// use neor::types::NearestResponse;
+    /// use neor::{args, r, Converter, Result};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Hideout {
+    ///     id: String,
+    /// }
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("posts")
-    ///         .filter(func!(|post| post.g("date").seconds().lt(30)))
+    ///     let secret_base = r.point(-122.422876, 37.777128);
+    ///     let opts = GetNearestOption::default()
+    ///         .max_results(25)
+    ///         .max_dist(12.5)
+    ///         .unit(Unit::Kilometer);
+    ///
+    ///     let response: Vec<NearestResponse<Hideout>> = r.table("simbad")
+    ///         .get_nearest(args!(secret_base, "location", opts))
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     for hideout in response {
+    ///         println!("{} is {} km away", hideout.doc.id, hideout.dist);
+    ///     }
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    /// - [in_timezone](Self::in_timezone)
-    pub fn seconds(&self) -> Self {
-        seconds::new().with_parent(self)
+    /// - [get_intersecting](Self::get_intersecting)
+    pub fn get_nearest(&self, args: impl get_nearest::GetNearestArg) -> Self {
+        get_nearest::new(args).with_parent(self)
     }
 
-    /// Convert a time object to a string in ISO 8601 format.
+    /// Tests whether a geometry object is completely contained within another.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// time.to_iso8601() → String
+    /// geometry.includes(geometry) → bool
+    /// sequence.includes(geometry) → sequence
     /// ```
     ///
+    /// Where:
+    /// - geometry: [r.point(...)](crate::r::point) |
+    /// [r.line(...)](crate::r::line) |
+    /// [r.polygon(...)](crate::r::polygon) |
+    /// command
+    /// - sequence: command
+    ///
+    /// # Description
+    ///
+    /// When applied to a sequence of geometry objects,
+    /// `includes` acts as a [filter](Self::filter),
+    /// returning a sequence of objects from the sequence
+    /// that include the argument.
+    ///
     /// ## Examples
     ///
-    /// Return the current ISO 8601 time.
+    /// Is `point2` included within a 2000-meter circle around `point1`?
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let iso8601 = r.now().to_iso8601();
-    ///     let iso8601_1 = iso8601.value();
-    ///     let iso8601_2: String = iso8601.cmd()
+    ///     let point1 = r.point(-117.220406, 32.719464);
+    ///     let point2 = r.point(-117.206201, 32.725186);
+    ///
+    ///     let response: bool = r.circle(args!(point1, 2000.))
+    ///         .includes(point2)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(!iso8601_1.is_empty());
-    ///     assert!(!iso8601_2.is_empty());
+    ///     assert!(response);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    pub fn to_iso8601(&self) -> Self {
-        to_iso8601::new().with_parent(self)
-    }
-
-    /// Convert a time object to its epoch time.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Which of the locations in a list of parks include `circle`?
     ///
-    /// ```text
-    /// time.to_epoch_time() → f64
+    /// ```
+    /// use neor::arguments::{CircleOption, Unit};
+    /// use neor::{args, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let point = r.point(-117.220406, 32.719464);
+    ///     let circle_opts = CircleOption::default().unit(Unit::InternationalMile);
+    ///     let circle = r.circle(args!(point, 10., circle_opts));
+    ///
+    ///     let response = r.table("parks")
+    ///         .g("area")
+    ///         .includes(circle)
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///     
+    ///     Ok(())
+    /// }
     /// ```
     ///
     /// ## Examples
     ///
-    /// Return the current time in seconds since
-    /// the Unix Epoch with millisecond-precision.
+    /// Rewrite the previous example with `get_intersecting`.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::arguments::{CircleOption, Unit};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let time = r.now().to_epoch_time();
-    ///     let time1: f64 = time.value();
-    ///     let time2: f64 = time.cmd()
+    ///     let point = r.point(-117.220406, 32.719464);
+    ///     let circle_opts = CircleOption::default()
+    ///         .unit(Unit::InternationalMile);
+    ///     let circle = r.circle(args!(point, 10., circle_opts));
+    ///
+    ///     let response = r.table("parks")
+    ///         .get_intersecting(circle.clone(), "area")
+    ///         .g("area")
+    ///         .includes(circle)
     ///         .run(&conn)
-    ///         .await?.unwrap()
-    ///         .parse()?;
+    ///         .await?;
     ///
-    ///     assert!(time1.is_normal());
-    ///     assert!(time2.is_normal());
+    ///     assert!(response.is_some());
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [now](crate::r::now)
-    /// - [time](crate::r::time)
-    /// - [to_iso8601](Self::to_iso8601)
-    pub fn to_epoch_time(&self) -> Self {
-        to_epoch_time::new().with_parent(self)
+    /// - [intersects](Self::intersects)
+    pub fn includes(&self, args: impl includes::IncludesArg) -> Self {
+        includes::new(args).with_parent(self)
     }
 
-    //// Call an anonymous function using return values
-    /// from other ReQL commands or queries as arguments.
+    /// Tests whether two geometry objects intersect with one another.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// any.do_(predicate) → any
-    /// r.do_(arg, predicate) → any
-    /// r.do_(args, predicate) → any
+    /// geometry.intersects(geometry) → bool
+    /// r.intersects(geometry_command, geometry) → bool
+    /// sequence.intersects(geometry) → sequence_response
+    /// r.intersects(sequence, geometry) → sequence_response
     /// ```
+    ///
     /// Where:
-    /// - predicate: [Func](crate::Func) | [Command](crate::Command) | `impl Serialize`
-    /// - arg: [Command](crate::Command)
-    /// - args: `impl IntoIterator<Item = T>`
-    /// - T: `impl Serialize` | [Command](crate::Command)
+    /// - geometry: [r.point(...)](crate::r::point) |
+    /// [r.line(...)](crate::r::line) |
+    /// [r.polygon(...)](crate::r::polygon) |
+    /// command
+    /// - sequence, geometry_command: [Command](crate::Command)
     ///
     /// # Description
     ///
-    /// The last argument to `do_` (or, in some forms, the only argument) is an
-    /// expression or an anonymous function which receives values from either
-    /// the previous arguments or from prefixed commands chained before `do_`.
-    /// The `do_` command is essentially a single-element [map](Self::map),
-    /// letting you map a function over just one document.
-    /// This allows you to bind a query result to a
-    /// local variable within the scope of `do_`,
-    /// letting you compute the result just once and reuse it in
-    /// a complex expression or in a series of ReQL commands.
-    ///
-    /// Arguments passed to the `do_` function must be basic data types,
-    /// and cannot be streams or selections.
-    /// ([Read about ReQL data types](https://rethinkdb.com/docs/data-types/).)
-    /// While the arguments will all be evaluated before the function is executed,
-    /// they may be evaluated in any order, so their values should not be dependent on one another.
-    /// The type of `do_`’s result is the type of the value returned from the function or last expression.
+    /// When applied to a sequence of geometry objects, `intersects` acts as a
+    /// [filter](Self::filter), returning a sequence of objects from
+    /// the sequence that intersect with the argument.
     ///
     /// ## Examples
     ///
-    /// Compute a golfer’s net score for a game.
+    /// Is `point2` within a 2000-meter circle around `point1`?
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///         .get("86be93eb-a112-48f5-a829-15b2cb49de1d")
-    ///         .do_(func!(|player| player.g("gross_score") - player.g("course_handicap")))
+    ///     let point1 = r.point(-117.220406, 32.719464);
+    ///     let point2 = r.point(-117.206201, 32.725186);
+    ///
+    ///     let response: bool = r.circle(args!(point1, 2000.))
+    ///         .intersects(point2)
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response);
     ///     
     ///     Ok(())
     /// }
@@ -9123,60 +11571,23 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Return the name of the best scoring player in a two-player golf match.
+    /// Which of the locations in a list of parks intersect `circle`?
     ///
     /// ```
-    /// use neor::{args, func, r, Result};
+    /// use neor::arguments::{CircleOption, Unit};
+    /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let (id1, id2) = (1, 2);
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.do_(
-    ///         [r.table("players").get(id1), r.table("players").get(id2)],
-    ///         func!(|player1, player2| r.branch(
-    ///             player1.g("gross_score").lt(player2.g("gross_score")),
-    ///             args!(player1, player2)
-    ///         ))
-    ///     )
-    ///     .run(&conn)
-    ///     .await?;
-    ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
-    ///
-    /// Note that `branch`, the ReQL conditional command, must be used instead of `if`.
-    /// See the `branch` [documentation](Self::branch) for more.
-    ///
-    /// ## Examples
-    ///
-    /// Take different actions based on the result of a ReQL insert command.
-    ///
-    /// ```
-    /// use neor::{args, func, r, Result};
-    /// use serde_json::json;
+    ///     let point = r.point(-117.220406, 32.719464);
+    ///     let circle_opts = CircleOption::default().unit(Unit::InternationalMile);
+    ///     let circle = r.circle(args!(point, 10., circle_opts));
     ///
-    /// async fn example() -> Result<()> {
-    ///     let new_data = json!({
-    ///         "id": 100,
-    ///         "name": "Agatha",
-    ///         "gross_score": 57,
-    ///         "course_handicap": 4
-    ///     });
-    ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("players")
-    ///     .insert(new_data)
-    ///     .do_(func!(|doc| r.branch(
-    ///         doc.g("inserted").ne(0),
-    ///         args!(
-    ///             r.table("log").insert(json!({"time": r.now(), "result": "ok"})),
-    ///             r.table("log").insert(json!({"time": r.now(), "result": "error"}))
-    ///         )
-    ///     )))
-    ///     .run(&conn)
-    ///     .await?;
+    ///     let response = r.table("parks")
+    ///         .g("area")
+    ///         .intersects(circle)
+    ///         .run(&conn)
+    ///         .await?;
     ///
     ///     assert!(response.is_some());
     ///     
@@ -9185,71 +11596,72 @@ impl<'a> Command {
     /// ```
     ///
     /// # Related commands
-    /// - [map](Self::map)
-    pub fn do_(&self, expr: impl Into<CommandArg>) -> Self {
-        do_::new(expr).with_arg(self.to_owned())
+    /// - [includes](Self::includes)
+    /// - [get_intersecting](Self::get_intersecting)
+    pub fn intersects(&self, geometry: impl intersects::IntersectsArg) -> Self {
+        intersects::new(geometry).with_parent(self)
     }
 
-    /// Perform a branching conditional equivalent to `if-then-else`.
+    /// Grant or deny access permissions for a user account,
+    /// globally or on a per-database or per-table basis.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// r.branch(test, args!(true_action, false_action)) → any
-    /// r.branch(test, args!(true_action, tests, false_action)) → any
-    /// query.branch(args!(true_action, false_action)) -> any
-    /// query.branch(args!(true_action, tests, false_action)) → any
+    /// r.grant(username, permission) → response
+    /// table.grant(username, permission) → response
+    /// db.grant(username, permission) → response
     /// ```
     ///
     /// Where:
-    /// - action, true_action, false_action: `impl Serialize` | [Command](crate::Command)
-    /// - test: `bool` | [Command](crate::Command)
-    /// - tests: `impl IntoIterator<Item = (test, action)>` | [Command](crate::Command)
+    /// - username: `impl Serialize`
+    /// - permission: [Permission](crate::arguments::Permission)
+    /// - response: [GrantResponse](crate::types::GrantResponse)
     ///
     /// # Description
     ///
-    /// The `branch` command takes 2n+1 arguments: pairs of conditional expressions
-    /// and commands to be executed if the conditionals return any value but `false`
-    /// or `None` i.e., “truthy” values), with a final “else” command to be evaluated
-    /// if all of the conditionals are `false` or `None`.
-    ///
-    /// You may call `branch` infix style on the first test.
-    /// (See the second example for an illustration.)
+    /// Permissions that are not defined on a local scope will
+    /// be inherited from the next largest scope.
+    /// For example, a write operation on a table will first
+    /// check if `write` permissions are explicitly set to `true` or `false`
+    /// for that table and account combination; if they are not,
+    /// the `write` permissions for the database will be used
+    /// if those are explicitly set; and if neither table nor database
+    /// permissions are set for that account, the global `write`
+    /// permissions for that account will be used.
     ///
-    /// ```text
-    /// r.branch(test1, args!(val1, [(test2, val2)], elseval))
-    /// ```
+    /// ## Note
     ///
-    /// is the equivalent of the Rust statement
+    /// For all accounts other than the special, system-defined `admin` account,
+    /// permissions that are not explicitly set in any scope will effectively be `false`.
+    /// When you create a new user account by inserting a record into the
+    /// [system table](https://rethinkdb.com/docs/system-tables/#users),
+    /// that account will have **no** permissions until they are explicitly granted.
     ///
-    /// ```text
-    /// if (test1) {
-    ///     val1
-    /// } else if (test2) {
-    ///     val2
-    /// } else {
-    ///     elseval
-    /// }
-    /// ```
+    /// For a full description of permissions, read
+    /// [Permissions and user accounts](https://rethinkdb.com/docs/permissions-and-accounts/).
     ///
     /// ## Examples
     ///
-    /// Test the value of x.
+    /// Grant the `alima` user account read and write permissions on the `users` database.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::arguments::Permission;
+    /// use neor::types::GrantResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let x = 10;
     ///     let conn = r.connection().connect().await?;
+    ///     let permission = Permission::default().read(true).write(true);
     ///
-    ///     let response: String = r.branch(x > 5, args!("big", "small"))
+    ///     let response: GrantResponse = r.db("users")
+    ///         .grant("alima", permission)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.eq("big"));
+    ///     assert!(response.granted == 1);
     ///     
     ///     Ok(())
     /// }
@@ -9257,23 +11669,26 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// As above, infix-style.
+    /// Deny write permissions from the `alima` account for the `simbad` table.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::arguments::Permission;
+    /// use neor::types::GrantResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let x = 10;
     ///     let conn = r.connection().connect().await?;
+    ///     let permission = Permission::default().write(false);
     ///
-    ///     let response: String = r.expr(x > 5)
-    ///         .branch(args!("big", "small"))
+    ///     let response: GrantResponse = r.db("users")
+    ///         .table("simbad")
+    ///         .grant("alima", permission)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.eq("big"));
+    ///     assert!(response.granted == 1);
     ///     
     ///     Ok(())
     /// }
@@ -9281,140 +11696,99 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Categorize heroes by victory counts.
+    /// Grant `alima` the ability to use HTTP connections.
     ///
     /// ```
-    /// use std::ops::Add;
-    ///
-    /// use neor::{args, func, r, Result};
+    /// use neor::arguments::Permission;
+    /// use neor::types::GrantResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let permission = Permission::default().connect(true);
     ///
-    ///     let response = r.table("pricings")
-    ///         .map(func!(|offer| r.branch(
-    ///             offer.g("price").gt(100),
-    ///             args!(
-    ///                 offer.g("offer").add("premium"),
-    ///                 [(
-    ///                     offer.g("price").gt(10),
-    ///                     offer.g("offer").add("standard")
-    ///                 )],
-    ///                 offer.g("offer").add("freemium")
-    ///         ))))
+    ///     let response: GrantResponse = r.grant("alima", permission)
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.granted == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [do_](Self::do_)
-    pub fn branch(&self, args: impl branch::BranchArg) -> Self {
-        branch::new(args).with_parent(self)
-    }
-
-    /// Loop over a sequence, evaluating the given write query for each element.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// sequence.for_each(write_function) → response
-    /// ```
-    ///
-    /// Where:
-    /// - write_function: [Func](crate::Func)
-    /// - response: [MutationResponse](crate::types::MutationResponse)
-    ///
     /// ## Examples
     ///
-    /// Get information about a table such as primary key, or cache size.
+    /// Grant a `monitor` account read-only access to all databases.
     ///
     /// ```
-    /// use neor::types::MutationResponse;
-    /// use neor::{args, func, r, Converter, Result};
+    /// use neor::arguments::Permission;
+    /// use neor::types::GrantResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let permission = Permission::default().read(true);
     ///
-    ///     let response: MutationResponse = r.table("models")
-    ///         .for_each(func!(|model| r.table("cars")
-    ///             .get(model.get("car_model"))
-    ///             .delete(())
-    ///         ))
+    ///     let response: GrantResponse = r.grant("monitor", permission)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.deleted == 5);
+    ///     assert!(response.granted == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// # Related commands
-    /// - [map](Self::map)
-    pub fn for_each(&self, write_function: Func) -> Self {
-        for_each::new(write_function).with_parent(self)
+    pub fn grant(&self, username: impl Into<String>, permission: Permission) -> Self {
+        grant::new(username, permission).with_parent(self)
     }
 
-    /// Provide a default value in case of non-existence errors.
+    /// Query (read and/or update) the configurations for individual tables or databases.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// value.default(default_value) → any
-    /// sequence.default(default_value) → any
+    /// table.config() → response
+    /// db.config() → response
     /// ```
     ///
     /// Where:
-    /// - default_value: `impl Serialize` | [Command](crate::Command) | [Func](crate::Func)
+    /// - table: [r.table(...)](crate::r::table) |
+    /// [query.table(...)](Self::table)
+    /// - db: [r.db(...)](crate::r::db)
+    /// - response: [ConfigResponse](crate::types::ConfigResponse)
     ///
     /// # Description
     ///
-    /// The `default` command evaluates its first argument (the value it’s chained to).
-    /// If that argument returns `None` or a non-existence error is thrown in evaluation,
-    /// then `default` returns its second argument. The second argument is usually a default value,
-    /// but it can be a function that returns a value.
+    /// The config command is a shorthand way to access the `table_config` or `db_config`
+    /// [System tables](https://rethinkdb.com/docs/system-tables/#configuration-tables).
+    /// It will return the single row from the system that corresponds to the database
+    /// or table configuration, as if [get](Self::get) had been called on the system
+    /// table with the UUID of the database or table in question.
     ///
     /// ## Examples
     ///
-    /// Suppose we want to retrieve the titles and authors of the table posts.
-    /// In the case where the author field is missing or null,
-    /// we want to retrieve the string Anonymous.
+    /// Rebalance a table.
     ///
     /// ```
-    /// use std::collections::HashMap;
-    ///
-    /// use neor::{func, r, Converter, Result};
-    /// use serde::{Deserialize, Serialize};
-    ///
-    /// #[derive(Debug, Serialize, Deserialize)]
-    /// struct Post {
-    ///     title: String,
-    ///     author: String,
-    /// }
+    /// use neor::types::ConfigResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///
-    ///     let response: Vec<Post> = r.table("posts")
-    ///         .map(func!(|doc| {
-    ///             let mut post = HashMap::new();
-    ///             post.insert("title", doc.g("title"));
-    ///             post.insert("author", doc.g("author").default("Anonymous"));
-    ///             r.hash_map(post)
-    ///         }))
+    ///     let response: ConfigResponse = r.table("simbad")
+    ///         .config()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.len() > 0);
+    ///     assert!(response.name == "simbad");
     ///     
     ///     Ok(())
     /// }
@@ -9422,141 +11796,162 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// The `default` command can also be used to filter documents.
-    /// Suppose we want to retrieve all our users who are not grown-ups or
-    /// whose age is unknown (i.e., the field `age` is missing or equals `None`).
-    /// We can do it with this query:
+    /// Get the configuration of a database.
     ///
     /// ```
-    /// use neor::{func, r, Result};
+    /// use neor::types::ConfigResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("age").lt(18).default(true)))
+    ///     let response: ConfigResponse = r.db("marvel")
+    ///         .config()
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.name == "marvel");
     ///     
     ///     Ok(())
     /// }
     /// ```
+    pub fn config(&self) -> Self {
+        config::new().with_parent(self)
+    }
+
+    /// Rebalances the shards of a table. When called on a database,
+    /// all the tables in that database will be rebalanced.
     ///
-    /// One more way to write the previous query is
-    /// to set the age to be `-1` when the field is missing.
+    /// # Command syntax
     ///
+    /// ```text
+    /// table.rebalance() → response
+    /// db.rebalance() → response
     /// ```
-    /// use neor::{func, r, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.g("age").default(-1).lt(18)))
-    ///         .run(&conn)
-    ///         .await?;
+    /// Where:
+    /// - table: [r.table(...)](crate::r::table) |
+    /// [query.table(...)](Self::table)
+    /// - db: [r.db(...)](crate::r::db)
+    /// - response: [RebalanceResponse](crate::types::RebalanceResponse)
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// # Description
     ///
-    /// Another way to do the same query is to use hasFields.
+    /// The `rebalance` command operates by measuring the distribution of
+    /// primary keys within a table and picking split points that will
+    /// give each shard approximately the same number of documents.
+    /// It won’t change the number of shards within a table,
+    /// or change any other configuration aspect for the table or the database.
     ///
-    /// ```
-    /// use std::ops::Not;
+    /// A table will lose availability temporarily after `rebalance` is called;
+    /// use the [wait](Self::wait) command to wait for the table to become available again,
+    /// or [status](Self::status) to check if the table is available for writing.
     ///
-    /// use neor::{func, r, Result};
+    /// RethinkDB automatically rebalances tables when the number of shards are increased,
+    /// and as long as your documents have evenly distributed primary keys—such as
+    /// the default UUIDs—it is rarely necessary to call `rebalance` manually.
+    /// Cases where `rebalance` may need to be called include:
+    /// - Tables with unevenly distributed primary keys, such as incrementing integers
+    /// - Changing a table’s primary key type
+    /// - Increasing the number of shards on an empty table,
+    /// then using non-UUID primary keys in that table
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
+    /// The [web UI](https://rethinkdb.com/docs/administration-tools/)
+    /// (and the [info](Self::info) command)
+    /// can be used to tell you when a table’s shards need to be rebalanced.
     ///
-    ///     let response = r.table("users")
-    ///         .filter(func!(|user| user.has_fields("age").not().or(user.g("age").lt(18))))
-    ///         .run(&conn)
-    ///         .await?;
+    /// See the [status](Self::status) command for an explanation of
+    /// the objects returned in the `old_val` and `new_val` fields.
     ///
-    ///     assert!(response.is_some());
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// ## Examples
     ///
-    /// Another way to do the same query is to use hasFields.
+    /// Rebalance a table.
     ///
     /// ```
-    /// use neor::arguments::FilterOption;
-    /// use neor::{args, func, r, Result};
+    /// use neor::types::RebalanceResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///
-    ///     let response = r.table("users")
-    ///         .filter(args!(
-    ///             func!(|user| user.g("age").lt(18).default(true)),
-    ///             FilterOption::default().default_(true)
-    ///         ))
+    ///     let response: RebalanceResponse = r.table("simbad")
+    ///         .rebalance()
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.rebalanced == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn default(&self, default_value: impl Into<CommandArg>) -> Self {
-        default::new(default_value).with_parent(self)
+    pub fn rebalance(&self) -> Self {
+        rebalance::new().with_parent(self)
     }
 
-    /// Convert a value of one type into another.
+    /// Reconfigure a table’s sharding and replication.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// sequence.coerce_to('array') → array
-    /// value.coerce_to('string') → string
-    /// string.coerce_to('number') → number
-    /// array.coerce_to('object') → object
-    /// sequence.coerce_to('object') → object
-    /// object.coerce_to('array') → array
-    /// binary.coerce_to('string') → string
-    /// string.coerce_to('binary') → binary
-    ///
-    /// query.coerce_to(type) → response
+    /// table.reconfigure(options) → response
+    /// db.reconfigure(options) → response
     /// ```
     ///
     /// Where:
-    /// - type: [CoerceType](crate::arguments::CoerceType) | `impl Into<String>` |
-    /// [Command](crate::Command)
+    /// - table: [r.table(...)](crate::r::table) |
+    /// [query.table(...)](Self::table)
+    /// - db: [r.db(...)](crate::r::db)
+    /// - options: [ReconfigureOption](crate::arguments::ReconfigureOption)
+    /// - response: [ReconfigureResponse](crate::types::ReconfigureResponse)
     ///
     /// # Description
     ///
-    /// - a sequence, selection or object can be coerced to an array
-    /// - a sequence, selection or an array of key-value pairs can be coerced to an object
-    /// - a string can be coerced to a number
-    /// - any datum (single value) can be coerced to a string
-    /// - a binary object can be coerced to a string and vice-versa
+    /// A table will lose availability temporarily after `reconfigure` is called;
+    /// use the [wait](Self::wait) command to wait for the table to become available again,
+    /// or [status](Self::status) to check if the table is available for writing.
+    ///
+    /// ## Note
+    ///
+    /// Whenever you call `reconfigure`, the write durability will be set to
+    /// `Durability::Hard` and the write
+    /// acknowledgments will be set to `ReadMode::Majority`;
+    /// these can be changed by using the `config` command on the table.
+    ///
+    ///
+    /// If `reconfigure` is called on a database,
+    /// all the tables in the database will have their configurations affected.
+    /// The return value will be an array of the objects described above, one per table.
+    ///
+    /// Read [Sharding and replication](https://rethinkdb.com/docs/sharding-and-replication/)
+    /// for a complete discussion of the subject, including advanced topics.
     ///
     /// ## Examples
     ///
-    /// Coerce an array of pairs into an object.
+    /// Reconfigure a table.
     ///
     /// ```
-    /// use neor::arguments::CoerceType;
-    /// use neor::{args, r, Result};
+    /// use neor::arguments::{ReconfigureOption, Replicas};
+    /// use neor::types::ReconfigureResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let opts = ReconfigureOption::default()
+    ///         .shards(1)
+    ///         .replicas(Replicas::Int(1));
     ///
-    ///     let response = r.expr([["name", "Malika"], ["genre", "woman"]])
-    ///         .coerce_to(CoerceType::Object)
+    ///     let response: ReconfigureResponse = r.table("simbad")
+    ///         .reconfigure(opts)
     ///         .run(&conn)
-    ///         .await?;
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     assert!(response.is_some());
+    ///     assert!(response.reconfigured == 1);
     ///     
     ///     Ok(())
     /// }
@@ -9564,424 +11959,416 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Coerce a number to a string.
+    /// Reconfigure a table, specifying replicas by server tags.
     ///
     /// ```
-    /// use neor::arguments::CoerceType;
-    /// use neor::{args, r, Converter, Result};
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::arguments::{ReconfigureOption, Replicas};
+    /// use neor::types::ReconfigureResponse;
+    /// use neor::{r, Converter, Result, StaticString};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let mut replicas = HashMap::new();
     ///
-    ///     let response: char = r.expr(1)
-    ///         .coerce_to(CoerceType::String)
+    ///     replicas.insert("malika".static_string(), 1);
+    ///     replicas.insert("malika".static_string(), 1);
+    ///
+    ///     let opts = ReconfigureOption::default()
+    ///         .shards(2)
+    ///         .replicas(Replicas::Map {
+    ///             replicas,
+    ///             primary_replica_tag: "malika".static_string()
+    ///         });
+    ///
+    ///     let response: ReconfigureResponse = r.table("simbad")
+    ///         .reconfigure(opts)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == '1');
+    ///     assert!(response.reconfigured == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [object](crate::r::object)
-    pub fn coerce_to(&self, value: impl Into<CommandArg>) -> Self {
-        coerce_to::new(value).with_parent(self)
-    }
-
-    /// Gets the type of a ReQL query’s return value.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// Perform an emergency repair on a table.
     ///
-    /// ```text
-    /// any.type_of() → response
     /// ```
+    /// use neor::arguments::{EmergencyRepair, ReconfigureOption};
+    /// use neor::types::ReconfigureResponse;
+    /// use neor::{r, Converter, Result};
     ///
-    /// Where:
-    /// - response: [TypeOf](crate::types::TypeOf)
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let opts = ReconfigureOption::default()
+    ///         .emergency_repair(EmergencyRepair::UnsafeRollback);
     ///
-    /// # Description
+    ///     let response: ReconfigureResponse = r.table("simbad")
+    ///         .reconfigure(opts)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    /// Read the article on [ReQL data types](https://rethinkdb.com/docs/data-types/)
-    /// for a more detailed discussion.
-    /// Note that some possible return values from `type_of` are internal values,
-    /// such as `TypeOf::MAXVAL`, and unlikely to be returned from queries in standard practice.
+    ///     assert!(response.reconfigured == 1);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Get the type of a TypeOf.
+    /// Review the shard and replica movements of a reconfiguration
+    /// before applying it.
     ///
     /// ```
-    /// use neor::types::TypeOf;
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::arguments::{ReconfigureOption, Replicas};
+    /// use neor::types::ReconfigureResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let opts = ReconfigureOption::default()
+    ///         .shards(2)
+    ///         .replicas(Replicas::Int(1))
+    ///         .dry_run(true);
     ///
-    ///     let response: TypeOf = r.expr("foo")
-    ///         .type_of()
+    ///     let response: ReconfigureResponse = r.table("simbad")
+    ///         .reconfigure(opts)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == TypeOf::String);
+    ///     for table in response.diff() {
+    ///         println!("{}", table);
+    ///     }
+    ///
+    ///     assert!(response.reconfigured == 0);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn type_of(&self) -> Self {
-        type_of::new().with_parent(self)
-    }
-
-    /// Get information about a ReQL value.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// any.info() → response
-    /// r.info(any) → response
-    /// ```
-    ///
-    /// Where:
-    /// - response: [InfoResponse](crate::types::InfoResponse)
     ///
     /// ## Examples
     ///
-    /// Get information about a table such as primary key, or cache size.
+    /// Reconfigure every table of a database, then wait for
+    /// all their replicas to be ready.
     ///
     /// ```
-    /// use neor::types::{InfoResponse, TypeOf};
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::arguments::{ReconfigureOption, Replicas, WaitFor, WaitOption};
+    /// use neor::types::{ReconfigureResponse, WaitResponse};
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let opts = ReconfigureOption::default()
+    ///         .shards(2)
+    ///         .replicas(Replicas::Int(1));
     ///
-    ///     let response: InfoResponse = r.table("simbad")
-    ///         .info()
+    ///     let response: ReconfigureResponse = r.db("marvel")
+    ///         .reconfigure(opts)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.typ == TypeOf::Table);
+    ///     let tables = response.config_changes.len();
+    ///     let opts = WaitOption::default().wait_for(WaitFor::AllReplicasReady);
+    ///
+    ///     let response: WaitResponse = r.db("marvel")
+    ///         .wait(opts)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.ready == tables);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn info(&self) -> Self {
-        info::new().with_parent(self)
+    pub fn reconfigure(&self, opts: ReconfigureOption) -> Self {
+        reconfigure::new(opts).with_parent(self)
     }
 
-    /// Convert a ReQL value or object to a JSON string.
+    /// Return the status of a table.
+    ///
+    /// The return value is an object providing information about
+    /// the table’s shards, replicas and replica readiness states.
+    /// For a more complete discussion of the object fields,
+    /// read about the table_status table in
+    /// [System tables](https://rethinkdb.com/docs/system-tables/#status-tables).
     ///
     /// # Command syntax
     ///
-    /// ```text
-    /// value.to_json() → String
+    /// ```text
+    /// table.status() → response
+    /// ```
+    ///
+    /// Where:
+    /// - table: [r.table(...)](crate::r::table) |
+    /// [query.table(...)](Self::table)
+    /// - response: [StatusResponse](crate::types::StatusResponse)
+    ///
+    /// ## Examples
+    ///
+    /// Get a table’s status.
+    ///
+    /// ```
+    /// use neor::types::StatusResponse;
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     let response: StatusResponse = r.table("simbad")
+    ///         .status()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.name.is_some());
+    ///     
+    ///     Ok(())
+    /// }
     /// ```
     ///
     /// ## Examples
     ///
-    /// Get a ReQL document as a JSON string.
+    /// List the replicas of a table which are not ready.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::types::TableStatusResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///
-    ///     let response: String = r.table("simbad")
-    ///         .get(1)
-    ///         .to_json()
+    ///     let response: TableStatusResponse = r.table("simbad")
+    ///         .status()
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(!response.is_empty());
+    ///     if !response.all_replicas_ready() {
+    ///         for (shard, replica) in response.unready_replicas() {
+    ///             println!("shard {}: {} is {}", shard, replica.server, replica.state);
+    ///         }
+    ///     }
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn to_json(&self) -> Self {
-        to_json::new().with_parent(self)
+    pub fn status(&self) -> Self {
+        status::new().with_parent(self)
     }
 
-    /// Compute the distance between a point and another geometry object.
-    /// At least one of the geometry objects specified must be a point.
+    /// Wait for a table or all the tables in a database to be ready.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// geometry.distance(geometry) → f64
-    /// geometry.distance(args!(geometry, options)) → f64
-    /// r.distance(geometry.cmd(), geometry) → f64
-    /// r.distance(geometry.cmd(), args!(geometry, options)) → f64
+    /// table.wait(()) → response
+    /// db.wait(()) → response
+    /// r.wait(table) → response
+    /// r.wait(database) → response
+    /// table.wait(options) → response
+    /// db.wait(options) → response
+    /// r.wait(args!(table, options)) → response
+    /// r.wait(args!(database, options)) → response
     /// ```
     ///
     /// Where:
-    /// - geometry: [r.point(...)](crate::r::point) |
-    /// [r.line(...)](crate::r::line) |
-    /// [r.polygon(...)](crate::r::polygon)
-    /// - options: [DistanceOption](crate::arguments::DistanceOption)
+    /// - table: [r.table(...)](crate::r::table) |
+    /// [query.table(...)](Self::table)
+    /// - db: [r.db(...)](crate::r::db)
+    /// - options: [WaitOption](crate::arguments::WaitOption)
+    /// - response: [WaitResponse](crate::types::WaitResponse)
     ///
     /// # Description
     ///
-    /// If one of the objects is a polygon or a line, the point will be projected
-    /// into the line or polygon assuming a perfect sphere model before the distance
-    /// is computed (using the model specified with `geo_system`).
-    /// As a consequence, if the polygon or line is extremely large compared
-    /// to Earth’s radius and the distance is being computed with the default
-    ///  WGS84 model, the results of `distance` should be considered approximate
-    /// due to the deviation between the ellipsoid and spherical models.
+    /// A table may be temporarily unavailable after creation,
+    /// rebalancing or reconfiguring.
+    /// The `wait` command blocks until the given
+    /// table (or database) is fully up to date.
+    ///
+    /// With a `timeout` option, the server fails the query with a
+    /// [OpFailed](crate::err::ReqlAvailabilityError::OpFailed) error when it expires.
+    /// In case the server does not answer, the driver also stops the query
+    /// shortly after and `run` returns a
+    /// [Timeout](crate::err::ReqlDriverError::Timeout) error.
     ///
     /// ## Examples
     ///
-    /// Compute the distance between two points on the Earth in kilometers.
+    /// Wait on a table to be ready.
     ///
     /// ```
-    /// use neor::arguments::{DistanceOption, Unit};
-    /// use neor::{args, r, Converter, Geometry, Result};
+    /// use neor::types::WaitResponse;
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let point1 = r.point(-122.423246, 37.779388);
-    ///     let point2 = r.point(-117.220406, 32.719464);
-    ///     let distance_option = DistanceOption::default().unit(Unit::Kilometer);
     ///
-    ///     let response: f64 = point1.cmd()
-    ///         .distance(point2)
+    ///     let response: WaitResponse = r.table("simbad")
+    ///         .wait(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 734.125249602186);
+    ///     assert!(response.ready == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [polygon](crate::r::polygon)
-    /// - [line](crate::r::line)
-    pub fn distance(&self, args: impl distance::DistanceArg) -> Self {
-        distance::new(args).with_parent(self)
-    }
-
-    /// Convert a ReQL geometry object to a [GeoJSON](https://geojson.org/) object.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// geometry.to_geojson() → response
-    /// ```
-    ///
-    /// Where:
-    /// - geometry: [r.point(...)](crate::r::point) |
-    /// [r.line(...)](crate::r::line) |
-    /// [r.polygon(...)](crate::r::polygon)
-    /// command
-    /// - response: [GeoJson<T>](crate::types::GeoJson)
-    ///
     /// ## Examples
     ///
-    /// Convert a ReQL geometry object to a GeoJSON object.
+    /// Wait on a table with timeout to be ready for reads.
     ///
     /// ```
-    /// use neor::types::{GeoJson, GeoType};
+    /// use neor::arguments::{WaitFor, WaitOption};
+    /// use neor::types::WaitResponse;
     /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let table_command = r.db("anim").table("simbad");
+    ///     let opts = WaitOption::default()
+    ///         .wait_for(WaitFor::ReadyForReads)
+    ///         .timeout(8000f64);
     ///
-    ///     let response: GeoJson<[f64; 2]> = r.table("simbad")
-    ///         .get("sfo")
-    ///         .g("location")
-    ///         .to_geojson()
+    ///     let response: WaitResponse =  r.wait(args!(table_command, opts))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.typ == GeoType::Point);
+    ///     assert!(response.ready == 1);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// # Related commands
-    /// - [geojson](crate::r::geojson)
-    pub fn to_geojson(&self) -> Self {
-        to_geojson::new().with_parent(self)
+    pub fn wait(&self, args: impl wait::WaitArg) -> Self {
+        wait::new(args).with_parent(self)
     }
 
-    /// Get all documents where the given geometry object intersects
-    /// the geometry object of the requested geospatial index.
+    /// Run a query on a connection,
+    /// returning either a single JSON result or a cursor,
+    /// depending on the query.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.get_intersecting(geometry, options) → selection<stream>
+    /// query.run(&session) → value
+    /// query.run(connection) → value
+    /// query.run(args!(&session, options)) → value
+    /// query.run(args!(connection, options)) → value
+    /// query.run(&mut session) → value
+    /// query.run(args!(&mut session, options)) → value
     /// ```
     ///
     /// Where:
-    /// - geometry: [r.point(...)](crate::r::point) |
-    /// [r.line(...)](crate::r::line) |
-    /// [r.polygon(...)](crate::r::polygon)
-    /// command
-    /// - sequence: command
-    /// - options: [GetIntersectingOption](crate::arguments::GetIntersectingOption)
-    ///
-    /// # Description
-    ///
-    /// The `index` argument is mandatory. This command returns the same
-    ///  results as `|row| row.g(index).intersects(geometry)`.
-    /// The total number of results is limited to the array size limit
-    /// which defaults to 100,000, but can be changed with the `array_limit`
-    /// option to [run](Self::run).
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    /// - stream: [impl Stream<Item = Result<Value>>](futures::stream::Stream)
     ///
     /// ## Examples
     ///
-    /// Which of the locations in a list of parks intersect `circle`?
+    /// If you are OK with potentially out of date data
+    /// from all the tables involved in this query and
+    /// want potentially faster reads,
+    /// pass a flag allowing out of date data in an options object.
+    /// Settings for individual tables will supercede this global
+    /// setting for all tables in the query.
     ///
     /// ```
-    /// use neor::arguments::{CircleOption, Unit};
+    /// use neor::arguments::{ReadMode, RunOption};
     /// use neor::{args, r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let point = r.point(-117.220406, 32.719464);
-    ///     let circle_opts = CircleOption::default()
-    ///         .unit(Unit::InternationalMile);
-    ///     let circle = r.circle(args!(point, 10., circle_opts));
-    ///
-    ///     let response = r.table("simbad")
-    ///         .get_intersecting(circle, "area")
-    ///         .run(&conn)
-    ///         .await?;
+    ///     let opts = RunOption::default().read_mode(ReadMode::Outdated);
     ///
-    ///     assert!(response.is_some());
+    ///     r.table("simbad").run(args!(&conn, opts)).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [get_nearest](Self::get_nearest)
-    pub fn get_intersecting(
-        &self,
-        geometry: impl get_intersecting::GetIntersectingArg,
-        index: &'static str,
-    ) -> Self {
-        get_intersecting::new(geometry, index).with_parent(self)
-    }
-
-    /// Return a list of documents closest to a
-    /// specified point based on a geospatial index,
-    /// sorted in order of increasing distance.
-    ///
-    /// # Command syntax
-    ///
-    /// ```text
-    /// table.get_nearest(args!(geometry, &str)) → array
-    /// table.get_nearest(args!(geometry, &str, options)) → array
-    /// ```
-    ///
-    /// Where:
-    /// - geometry: [r.point(...)](crate::r::point) |
-    /// [r.line(...)](crate::r::line) |
-    /// [r.polygon(...)](crate::r::polygon) |
-    /// command
-    /// - sequence: command
-    /// - options: [GetNearestOption](crate::arguments::GetNearestOption)
-    ///
-    /// # Description
-    ///
-    /// The return value will be an array of two-item objects
-    /// with the keys `dist` and `doc`, set to the distance
-    /// between the specified point and the document
-    /// (in the units specified with `unit`, defaulting to meters)
-    /// and the document itself, respectively.
-    /// The array will be sorted by the values of `dist`.
-    ///
     /// ## Examples
     ///
-    /// Return a list of the closest 25 enemy hideouts to the secret base.
+    /// If you want to specify whether to wait for a write to be written
+    /// to disk (overriding the table’s default settings),
+    /// you can set `durability` to `Durability::Hard`
+    /// or `Durability::Soft` in the options.
     ///
     /// ```
-    /// use neor::arguments::GetNearestOption;
+    /// use neor::arguments::{Durability, RunOption};
     /// use neor::{args, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let secret_base = r.point(-122.422876, 37.777128);
-    ///     let opts = GetNearestOption::default().max_results(25);
-    ///
-    ///     let response = r.table("simbad")
-    ///         .get_nearest(args!(secret_base, "location"))
-    ///         .run(&conn)
-    ///         .await?;
+    ///     let opts = RunOption::default().durability(Durability::Hard);
+    ///     let data = json!({
+    ///         "name": "Pumba",
+    ///         "live": 5
+    ///     });
     ///
-    ///     assert!(response.is_some());
+    ///     r.table("simbad").insert(data).run(args!(&conn, opts)).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// # Related commands
-    /// - [get_intersecting](Self::get_intersecting)
-    pub fn get_nearest(&self, args: impl get_nearest::GetNearestArg) -> Self {
-        get_nearest::new(args).with_parent(self)
-    }
-
-    /// Tests whether a geometry object is completely contained within another.
+    /// ## Examples
     ///
-    /// # Command syntax
+    /// If you do not want a time object to be converted to a native date object,
+    /// you can pass a time_format flag to prevent it
+    /// (valid flags are `Format::Raw` and `Format::Native`).
+    /// This query returns an object with two fields (epoch_time and $reql_type$)
+    /// instead of a native date object.
     ///
-    /// ```text
-    /// geometry.includes(geometry) → bool
-    /// sequence.includes(geometry) → sequence
     /// ```
+    /// use neor::arguments::{Format, RunOption};
+    /// use neor::{args, r, Result};
+    /// use serde_json::json;
     ///
-    /// Where:
-    /// - geometry: [r.point(...)](crate::r::point) |
-    /// [r.line(...)](crate::r::line) |
-    /// [r.polygon(...)](crate::r::polygon) |
-    /// command
-    /// - sequence: command
-    ///
-    /// # Description
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let opts = RunOption::default().time_format(Format::Raw);
     ///
-    /// When applied to a sequence of geometry objects,
-    /// `includes` acts as a [filter](Self::filter),
-    /// returning a sequence of objects from the sequence
-    /// that include the argument.
+    ///     r.now().cmd().run(args!(&conn, opts)).await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// ## Examples
     ///
-    /// Is `point2` included within a 2000-meter circle around `point1`?
+    /// Specify the database to use for the query.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::arguments::{Format, RunOption};
+    /// use neor::{args, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let point1 = r.point(-117.220406, 32.719464);
-    ///     let point2 = r.point(-117.206201, 32.725186);
-    ///
-    ///     let response: bool = r.circle(args!(point1, 2000.))
-    ///         .includes(point2)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let opts = RunOption::default().db("jikoni");
     ///
-    ///     assert!(response);
+    ///     r.table("simbad").run(args!(&conn, opts)).await?;
     ///     
     ///     Ok(())
     /// }
@@ -9989,510 +12376,466 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Which of the locations in a list of parks include `circle`?
+    /// Change the batching parameters for this query.
     ///
     /// ```
-    /// use neor::arguments::{CircleOption, Unit};
+    /// use neor::arguments::{Format, RunOption};
     /// use neor::{args, r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let point = r.point(-117.220406, 32.719464);
-    ///     let circle_opts = CircleOption::default().unit(Unit::InternationalMile);
-    ///     let circle = r.circle(args!(point, 10., circle_opts));
-    ///
-    ///     let response = r.table("parks")
-    ///         .g("area")
-    ///         .includes(circle)
-    ///         .run(&conn)
-    ///         .await?;
+    ///     let opts = RunOption::default()
+    ///         .max_batch_rows(16)
+    ///         .max_batch_bytes(2048);
     ///
-    ///     assert!(response.is_some());
+    ///     r.table("simbad").run(args!(&conn, opts)).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// ## Cancellation
     ///
-    /// Rewrite the previous example with `get_intersecting`.
+    /// The `run` future can be dropped at any time, e.g. by `tokio::select!`
+    /// or `tokio::time::timeout`. When it is dropped while waiting for the
+    /// server, the response is skipped by the next query of the session.
+    /// When it is dropped in the middle of writing the query or reading
+    /// the response, the socket can no longer be used and the session is
+    /// marked broken: its queries fail with `ReqlDriverError::ConnectionBroken`
+    /// until it is replaced, or reconnected by the
+    /// [ping_interval](crate::types::ConnectionCommand::ping_interval) task.
+    /// Dropping a stream returned by [build_query](Self::build_query)
+    /// before its end stops its cursor on the server.
+    ///
+    /// The [timeout](crate::arguments::RunOption::timeout) option bounds
+    /// the wait for each response, and a query can be cancelled from another
+    /// task with [Session::cancel](crate::connection::Session::cancel).
+    /// In both cases the query is stopped on the server and the session
+    /// stays usable.
     ///
     /// ```
-    /// use neor::arguments::{CircleOption, Unit};
-    /// use neor::{args, r, Result};
+    /// use std::time::Duration;
+    ///
+    /// use neor::{r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let point = r.point(-117.220406, 32.719464);
-    ///     let circle_opts = CircleOption::default()
-    ///         .unit(Unit::InternationalMile);
-    ///     let circle = r.circle(args!(point, 10., circle_opts));
+    ///     let query = r.range(10_000_000).count(());
     ///
-    ///     let response = r.table("parks")
-    ///         .get_intersecting(circle.clone(), "area")
-    ///         .g("area")
-    ///         .includes(circle)
-    ///         .run(&conn)
-    ///         .await?;
+    ///     tokio::select! {
+    ///         _ = query.run(&conn) => {}
+    ///         _ = tokio::time::sleep(Duration::from_millis(10)) => {}
+    ///     }
+    ///
+    ///     let response: u8 = r.expr(1).run(&conn).await?.unwrap().parse()?;
+    ///
+    ///     assert_eq!(response, 1);
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// ## Retries
+    ///
+    /// The queries of a session opened with a
+    /// [retry_policy](crate::types::ConnectionCommand::retry_policy) run again
+    /// when the server is unavailable, see [run_with_retry](Self::run_with_retry).
+    /// So do [run_typed](Self::run_typed) and [run_joined](Self::run_joined),
+    /// but not the streams of [build_query](Self::build_query).
+    ///
     /// # Related commands
-    /// - [intersects](Self::intersects)
-    pub fn includes(&self, args: impl includes::IncludesArg) -> Self {
-        includes::new(args).with_parent(self)
+    /// - [connection](crate::r::connection)
+    /// - [run_with_retry](Self::run_with_retry)
+    pub async fn run(&self, args: impl run::RunArg) -> Result<Option<Value>> {
+        run::retried(self, args, |args| async move {
+            self.build_query(args).try_next().await
+        })
+        .await
     }
 
-    /// Tests whether two geometry objects intersect with one another.
+    /// Run a query, running it again when the server is unavailable.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// geometry.intersects(geometry) → bool
-    /// r.intersects(geometry_command, geometry) → bool
-    /// sequence.intersects(geometry) → sequence_response
-    /// r.intersects(sequence, geometry) → sequence_response
+    /// query.run_with_retry(&session, &policy) → value
+    /// query.run_with_retry(connection, &policy) → value
+    /// query.run_with_retry(args!(&session, options), &policy) → value
+    /// query.run_with_retry(args!(connection, options), &policy) → value
     /// ```
     ///
     /// Where:
-    /// - geometry: [r.point(...)](crate::r::point) |
-    /// [r.line(...)](crate::r::line) |
-    /// [r.polygon(...)](crate::r::polygon) |
-    /// command
-    /// - sequence, geometry_command: [Command](crate::Command)
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    /// - policy: [RetryPolicy](crate::retry::RetryPolicy)
     ///
     /// # Description
     ///
-    /// When applied to a sequence of geometry objects, `intersects` acts as a
-    /// [filter](Self::filter), returning a sequence of objects from
-    /// the sequence that intersect with the argument.
+    /// The query runs like with `run`, and again, after the delay of the
+    /// policy, when it fails with an `OpFailed` error. After an `OpIndeterminate`
+    /// error or a broken connection, which may leave the query applied, only
+    /// the reads and the writes marked [idempotent](Self::idempotent) run
+    /// again, see [Idempotency](crate::retry::Idempotency).
+    /// A broken session is reconnected before the query runs again.
     ///
     /// ## Examples
     ///
-    /// Is `point2` within a 2000-meter circle around `point1`?
+    /// Count the posts, even while a replica of the table is restarting.
     ///
     /// ```
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::retry::RetryPolicy;
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let point1 = r.point(-117.220406, 32.719464);
-    ///     let point2 = r.point(-117.206201, 32.725186);
+    ///     let policy = RetryPolicy::default().max_retries(5);
     ///
-    ///     let response: bool = r.circle(args!(point1, 2000.))
-    ///         .intersects(point2)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     r.table("posts").count(()).run_with_retry(&conn, &policy).await?;
     ///
-    ///     assert!(response);
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [run](Self::run)
+    /// - [idempotent](Self::idempotent)
+    pub async fn run_with_retry(
+        &self,
+        args: impl run::RunArg,
+        policy: &crate::retry::RetryPolicy,
+    ) -> Result<Option<Value>> {
+        let (conn, opts) = args.into_run_opts()?;
+
+        crate::retry::run(self, conn, opts, policy, |args| async move {
+            self.build_query(args).try_next().await
+        })
+        .await
+    }
+
+    /// Run a query, deserializing the result straight into a type.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// query.run_typed::<T>(&session) → value
+    /// query.run_typed::<T>(connection) → value
+    /// query.run_typed::<T>(args!(&session, options)) → value
+    /// query.run_typed::<T>(args!(connection, options)) → value
+    /// ```
+    ///
+    /// Where:
+    /// - T: [DeserializeOwned](serde::de::DeserializeOwned)
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    ///
+    /// # Description
+    ///
+    /// The result is read like with `run`, but deserialized from the bytes
+    /// of the response instead of a `Value` converted with `parse`,
+    /// which saves allocations on large results.
+    /// A sequence is read as `T` by batch, e.g. a `Vec` of documents.
+    ///
     /// ## Examples
     ///
-    /// Which of the locations in a list of parks intersect `circle`?
+    /// Read the documents of a table.
     ///
     /// ```
-    /// use neor::arguments::{CircleOption, Unit};
-    /// use neor::{args, r, Result};
+    /// use neor::{r, Result};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Post {
+    ///     title: String,
+    /// }
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let point = r.point(-117.220406, 32.719464);
-    ///     let circle_opts = CircleOption::default().unit(Unit::InternationalMile);
-    ///     let circle = r.circle(args!(point, 10., circle_opts));
-    ///
-    ///     let response = r.table("parks")
-    ///         .g("area")
-    ///         .intersects(circle)
-    ///         .run(&conn)
-    ///         .await?;
+    ///     let posts = r.table("posts").run_typed::<Vec<Post>>(&conn).await?;
     ///
-    ///     assert!(response.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [includes](Self::includes)
-    /// - [get_intersecting](Self::get_intersecting)
-    pub fn intersects(&self, geometry: impl intersects::IntersectsArg) -> Self {
-        intersects::new(geometry).with_parent(self)
+    /// - [run](Self::run)
+    pub async fn run_typed<T>(&self, args: impl run::RunArg) -> Result<Option<T>>
+    where
+        T: Unpin + DeserializeOwned,
+    {
+        run::retried(self, args, |args| async move {
+            Box::pin(run::new(self.clone(), args)).try_next().await
+        })
+        .await
     }
 
-    /// Grant or deny access permissions for a user account,
-    /// globally or on a per-database or per-table basis.
+    /// Run a join, deserializing the pairs of documents it returns.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// r.grant(username, permission) → response
-    /// table.grant(username, permission) → response
-    /// db.grant(username, permission) → response
+    /// join.run_joined::<L, R>(&session) → response
+    /// join.run_joined::<L, R>(connection) → response
+    /// join.run_joined::<L, R>(args!(&session, options)) → response
+    /// join.run_joined::<L, R>(args!(connection, options)) → response
     /// ```
     ///
     /// Where:
-    /// - username: `impl Serialize`
-    /// - permission: [Permission](crate::arguments::Permission)
-    /// - response: [GrantResponse](crate::types::GrantResponse)
+    /// - L, R: [DeserializeOwned](serde::de::DeserializeOwned)
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
+    /// - response: [Vec<JoinResponse<L, R>>](crate::types::JoinResponse)
     ///
     /// # Description
     ///
-    /// Permissions that are not defined on a local scope will
-    /// be inherited from the next largest scope.
-    /// For example, a write operation on a table will first
-    /// check if `write` permissions are explicitly set to `true` or `false`
-    /// for that table and account combination; if they are not,
-    /// the `write` permissions for the database will be used
-    /// if those are explicitly set; and if neither table nor database
-    /// permissions are set for that account, the global `write`
-    /// permissions for that account will be used.
-    ///
-    /// ## Note
-    ///
-    /// For all accounts other than the special, system-defined `admin` account,
-    /// permissions that are not explicitly set in any scope will effectively be `false`.
-    /// When you create a new user account by inserting a record into the
-    /// [system table](https://rethinkdb.com/docs/system-tables/#users),
-    /// that account will have **no** permissions until they are explicitly granted.
-    ///
-    /// For a full description of permissions, read
-    /// [Permissions and user accounts](https://rethinkdb.com/docs/permissions-and-accounts/).
+    /// A shorthand of `run_typed::<Vec<JoinResponse<L, R>>>` for the results of
+    /// [eq_join](Self::eq_join), [inner_join](Self::inner_join) and
+    /// [outer_join](Self::outer_join), reading all the batches of the stream.
+    /// The `left` documents are read as `L` and the `right` documents as `R`.
+    /// After [zip](Self::zip), the merged documents are read with `run_typed`.
     ///
     /// ## Examples
     ///
-    /// Grant the `alima` user account read and write permissions on the `users` database.
+    /// Read the players with the game they play.
     ///
     /// ```
-    /// use neor::arguments::Permission;
-    /// use neor::types::GrantResponse;
-    /// use neor::{r, Converter, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let permission = Permission::default().read(true).write(true);
-    ///
-    ///     let response: GrantResponse = r.db("users")
-    ///         .grant("alima", permission)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    /// use neor::{args, r, Result};
+    /// use serde::Deserialize;
     ///
-    ///     assert!(response.granted == 1);
-    ///     
-    ///     Ok(())
+    /// #[derive(Deserialize)]
+    /// struct Player {
+    ///     name: String,
     /// }
-    /// ```
     ///
-    /// ## Examples
-    ///
-    /// Deny write permissions from the `alima` account for the `simbad` table.
-    ///
-    /// ```
-    /// use neor::arguments::Permission;
-    /// use neor::types::GrantResponse;
-    /// use neor::{r, Converter, Result};
+    /// #[derive(Deserialize)]
+    /// struct Game {
+    ///     field: String,
+    /// }
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let permission = Permission::default().write(false);
-    ///
-    ///     let response: GrantResponse = r.db("users")
-    ///         .table("simbad")
-    ///         .grant("alima", permission)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let response = r.table("players")
+    ///         .eq_join(args!("game_id", r.table("games")))
+    ///         .run_joined::<Player, Game>(&conn)
+    ///         .await?;
     ///
-    ///     assert!(response.granted == 1);
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [run_typed](Self::run_typed)
+    /// - [eq_join](Self::eq_join)
+    pub async fn run_joined<L, R>(&self, args: impl run::RunArg) -> Result<Vec<JoinResponse<L, R>>>
+    where
+        L: Unpin + DeserializeOwned,
+        R: Unpin + DeserializeOwned,
+    {
+        run::retried(self, args, |args| async move {
+            Box::pin(run::new(self.clone(), args)).try_concat().await
+        })
+        .await
+    }
+
+    /// Read the values in memory on any replica, the fastest but least consistent reads.
     ///
-    /// Grant `alima` the ability to use HTTP connections.
+    /// # Command syntax
     ///
+    /// ```text
+    /// query.outdated() → query
     /// ```
-    /// use neor::arguments::Permission;
-    /// use neor::types::GrantResponse;
-    /// use neor::{r, Converter, Result};
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let permission = Permission::default().connect(true);
     ///
-    ///     let response: GrantResponse = r.grant("alima", permission)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    /// # Description
     ///
-    ///     assert!(response.granted == 1);
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// Sets the `read_mode` sent with the [run options](crate::arguments::RunOption)
+    /// when the query is run, unless they set one, so that
+    /// the consistency of each read is chosen where the query is written.
+    /// The commands chained after `outdated` keep its read mode.
+    /// The read mode sent is reported by the [cursor](Self::cursor) of the
+    /// query (see [Cursor::read_mode](crate::cmd::cursor::Cursor::read_mode))
+    /// and to the [observer](crate::observer::QueryStart::read_mode) of the session.
     ///
     /// ## Examples
     ///
-    /// Grant a `monitor` account read-only access to all databases.
+    /// Count the posts, even if the count may be out of date.
     ///
     /// ```
-    /// use neor::arguments::Permission;
-    /// use neor::types::GrantResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let permission = Permission::default().read(true);
-    ///
-    ///     let response: GrantResponse = r.grant("monitor", permission)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     r.table("posts").outdated().count(()).run(&conn).await?;
     ///
-    ///     assert!(response.granted == 1);
-    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn grant(&self, username: impl Into<String>, permission: Permission) -> Self {
-        grant::new(username, permission).with_parent(self)
+    ///
+    /// # Related commands
+    /// - [single](Self::single)
+    /// - [majority](Self::majority)
+    pub fn outdated(&self) -> Self {
+        self.clone().with_read_mode(ReadMode::Outdated)
     }
 
-    /// Query (read and/or update) the configurations for individual tables or databases.
+    /// Read the values in memory on the primary replica, the default read mode.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.config() → response
-    /// db.config() → response
+    /// query.single() → query
     /// ```
     ///
-    /// Where:
-    /// - table: [r.table(...)](crate::r::table) |
-    /// [query.table(...)](Self::table)
-    /// - db: [r.db(...)](crate::r::db)
-    /// - response: [ConfigResponse](crate::types::ConfigResponse)
-    ///
     /// # Description
     ///
-    /// The config command is a shorthand way to access the `table_config` or `db_config`
-    /// [System tables](https://rethinkdb.com/docs/system-tables/#configuration-tables).
-    /// It will return the single row from the system that corresponds to the database
-    /// or table configuration, as if [get](Self::get) had been called on the system
-    /// table with the UUID of the database or table in question.
+    /// Like [outdated](Self::outdated), with `ReadMode::Single`.
     ///
     /// ## Examples
     ///
-    /// Rebalance a table.
+    /// Get a post from its primary replica.
     ///
     /// ```
-    /// use neor::types::ConfigResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///
-    ///     let response: ConfigResponse = r.table("simbad")
-    ///         .config()
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     r.table("posts").get(1).single().run(&conn).await?;
     ///
-    ///     assert!(response.name == "simbad");
-    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn config(&self) -> Self {
-        config::new().with_parent(self)
+    ///
+    /// # Related commands
+    /// - [outdated](Self::outdated)
+    /// - [majority](Self::majority)
+    pub fn single(&self) -> Self {
+        self.clone().with_read_mode(ReadMode::Single)
     }
 
-    /// Rebalances the shards of a table. When called on a database,
-    /// all the tables in that database will be rebalanced.
+    /// Read the values committed on a majority of replicas, the slowest but most consistent reads.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.rebalance() → response
-    /// db.rebalance() → response
+    /// query.majority() → query
     /// ```
     ///
-    /// Where:
-    /// - table: [r.table(...)](crate::r::table) |
-    /// [query.table(...)](Self::table)
-    /// - db: [r.db(...)](crate::r::db)
-    /// - response: [RebalanceResponse](crate::types::RebalanceResponse)
-    ///
     /// # Description
     ///
-    /// The `rebalance` command operates by measuring the distribution of
-    /// primary keys within a table and picking split points that will
-    /// give each shard approximately the same number of documents.
-    /// It won’t change the number of shards within a table,
-    /// or change any other configuration aspect for the table or the database.
-    ///
-    /// A table will lose availability temporarily after `rebalance` is called;
-    /// use the [wait](Self::wait) command to wait for the table to become available again,
-    /// or [status](Self::status) to check if the table is available for writing.
-    ///
-    /// RethinkDB automatically rebalances tables when the number of shards are increased,
-    /// and as long as your documents have evenly distributed primary keys—such as
-    /// the default UUIDs—it is rarely necessary to call `rebalance` manually.
-    /// Cases where `rebalance` may need to be called include:
-    /// - Tables with unevenly distributed primary keys, such as incrementing integers
-    /// - Changing a table’s primary key type
-    /// - Increasing the number of shards on an empty table,
-    /// then using non-UUID primary keys in that table
-    ///
-    /// The [web UI](https://rethinkdb.com/docs/administration-tools/)
-    /// (and the [info](Self::info) command)
-    /// can be used to tell you when a table’s shards need to be rebalanced.
-    ///
-    /// See the [status](Self::status) command for an explanation of
-    /// the objects returned in the `old_val` and `new_val` fields.
+    /// Like [outdated](Self::outdated), with `ReadMode::Majority`.
     ///
     /// ## Examples
     ///
-    /// Rebalance a table.
+    /// Get the balance of an account before a transfer.
     ///
     /// ```
-    /// use neor::types::RebalanceResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
     ///
-    ///     let response: RebalanceResponse = r.table("simbad")
-    ///         .rebalance()
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     r.table("accounts").get(1).g("balance").majority().run(&conn).await?;
     ///
-    ///     assert!(response.rebalanced == 1);
-    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn rebalance(&self) -> Self {
-        rebalance::new().with_parent(self)
+    ///
+    /// # Related commands
+    /// - [outdated](Self::outdated)
+    /// - [single](Self::single)
+    pub fn majority(&self) -> Self {
+        self.clone().with_read_mode(ReadMode::Majority)
     }
 
-    /// Reconfigure a table’s sharding and replication.
+    /// Mark a write as safe to run twice, so that it is retried.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.reconfigure(options) → response
-    /// db.reconfigure(options) → response
+    /// query.idempotent() → query
     /// ```
     ///
-    /// Where:
-    /// - table: [r.table(...)](crate::r::table) |
-    /// [query.table(...)](Self::table)
-    /// - db: [r.db(...)](crate::r::db)
-    /// - options: [ReconfigureOption](crate::arguments::ReconfigureOption)
-    /// - response: [ReconfigureResponse](crate::types::ReconfigureResponse)
-    ///
     /// # Description
     ///
-    /// A table will lose availability temporarily after `reconfigure` is called;
-    /// use the [wait](Self::wait) command to wait for the table to become available again,
-    /// or [status](Self::wait) to check if the table is available for writing.
-    ///
-    /// ## Note
-    ///
-    /// Whenever you call `reconfigure`, the write durability will be set to
-    /// `Durability::Hard` and the write
-    /// acknowledgments will be set to `ReadMode::Majority`;
-    /// these can be changed by using the `config` command on the table.
-    ///
-    ///
-    /// If `reconfigure` is called on a database,
-    /// all the tables in the database will have their configurations affected.
-    /// The return value will be an array of the objects described above, one per table.
-    ///
-    /// Read [Sharding and replication](https://rethinkdb.com/docs/sharding-and-replication/)
-    /// for a complete discussion of the subject, including advanced topics.
+    /// A write may have been applied when it fails with an `OpIndeterminate`
+    /// error or a broken connection, so [run_with_retry](Self::run_with_retry)
+    /// only runs it again when it is marked idempotent, e.g. a `replace`
+    /// with a whole document or a `delete`. The commands chained after
+    /// `idempotent` keep the mark.
     ///
     /// ## Examples
     ///
-    /// Reconfigure a table.
+    /// Set the title of a post, again if the server was unavailable.
     ///
     /// ```
-    /// use neor::arguments::{ReconfigureOption, Replicas};
-    /// use neor::types::ReconfigureResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::retry::RetryPolicy;
+    /// use neor::{r, Result};
+    /// use serde_json::json;
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let opts = ReconfigureOption::default()
-    ///         .shards(1)
-    ///         .replicas(Replicas::Int(1));
     ///
-    ///     let response: ReconfigureResponse = r.table("simbad")
-    ///         .reconfigure(opts)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     r.table("posts")
+    ///         .get(1)
+    ///         .update(json!({"title": "title"}))
+    ///         .idempotent()
+    ///         .run_with_retry(&conn, &RetryPolicy::default())
+    ///         .await?;
     ///
-    ///     assert!(response.reconfigured == 1);
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [run_with_retry](Self::run_with_retry)
+    pub fn idempotent(&self) -> Self {
+        self.clone().with_idempotent()
+    }
+
+    /// Prepare query for execution
     ///
-    /// Reconfigure a table, specifying replicas by server tags.
+    /// See [run](self::run) for more information.
+    ///
+    /// # Command syntax
     ///
+    /// ```text
+    /// query.build_query(&session) → stream
+    /// query.build_query(connection) → stream
+    /// query.build_query(args!(&session, options)) → stream
+    /// query.build_query(args!(connection, options)) → stream
+    /// query.build_query(&mut session) → stream
+    /// query.build_query(args!(&mut session, options)) → stream
     /// ```
-    /// use std::collections::HashMap;
     ///
-    /// use neor::arguments::{ReconfigureOption, Replicas};
-    /// use neor::types::ReconfigureResponse;
-    /// use neor::{r, Converter, Result, StaticString};
+    /// Where:
+    /// - session: [Session](crate::connection::Session)
+    /// - connection: [Connection](crate::connection::Connection)
+    /// - options: [RunOption](crate::arguments::RunOption)
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let mut replicas = HashMap::new();
+    /// # Description
     ///
-    ///     replicas.insert("malika".static_string(), 1);
-    ///     replicas.insert("malika".static_string(), 1);
+    /// This method has the same parameters as `run`.
+    /// The main difference between `build_query` and `run` is that
+    /// `build_query` can be used to execute multiple requests
     ///
-    ///     let opts = ReconfigureOption::default()
-    ///         .shards(2)
-    ///         .replicas(Replicas::Map {
-    ///             replicas,
-    ///             primary_replica_tag: "malika".static_string()
-    ///         });
+    /// ## Examples
     ///
-    ///     let response: ReconfigureResponse = r.table("simbad")
-    ///         .reconfigure(opts)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    /// You can use `query.build_query` to get the same result than `query.run`
     ///
-    ///     assert!(response.reconfigured == 1);
+    /// ```
+    /// use futures::TryStreamExt;
+    /// use neor::r;
+    ///
+    /// async fn example() -> neor::Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     r.table("simbad").build_query(&conn).try_next().await?;
+    ///     // is same than
+    ///     r.table("simbad").run(&conn).await?;
     ///     
     ///     Ok(())
     /// }
@@ -10500,391 +12843,395 @@ impl<'a> Command {
     ///
     /// ## Examples
     ///
-    /// Perform an emergency repair on a table.
+    /// Run many queries
     ///
     /// ```
-    /// use neor::arguments::{EmergencyRepair, ReconfigureOption};
-    /// use neor::types::ReconfigureResponse;
-    /// use neor::{r, Converter, Result};
+    /// use futures::stream::{select_all, TryStreamExt};
+    /// use neor::{r, Converter};
     ///
-    /// async fn example() -> Result<()> {
+    /// async fn example() -> neor::Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let opts = ReconfigureOption::default()
-    ///         .emergency_repair(EmergencyRepair::UnsafeRollback);
+    ///     let mut streams = Vec::new();
+    ///     let expected_messages: Vec<String> = (0..10_000)
+    ///         .into_iter()
+    ///         .map(|i| format!("message {}", i))
+    ///         .collect();
     ///
-    ///     let response: ReconfigureResponse = r.table("simbad")
-    ///         .reconfigure(opts)
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     for msg in expected_messages.iter() {
+    ///         streams.push(r.expr(msg).build_query(&conn));
+    ///     }
+    ///
+    ///     let mut list = select_all(streams);
+    ///     let mut response = Vec::new();
+    ///
+    ///     while let Some(msg) = list.try_next().await? {
+    ///         let msg: String = msg.parse()?;
+    ///         response.push(msg);
+    ///     }
+    ///
+    ///     assert!(response == expected_messages);
     ///
-    ///     assert!(response.reconfigured == 1);
-    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn reconfigure(&self, opts: ReconfigureOption) -> Self {
-        reconfigure::new(opts).with_parent(self)
+    ///
+    /// # Related commands
+    /// - [run](self::run)
+    pub fn build_query(&self, args: impl run::RunArg) -> impl Stream<Item = Result<Value>> {
+        Box::pin(run::new(self.clone(), args))
     }
 
-    /// Return the status of a table.
-    ///
-    /// The return value is an object providing information about
-    /// the table’s shards, replicas and replica readiness states.
-    /// For a more complete discussion of the object fields,
-    /// read about the table_status table in
-    /// [System tables](https://rethinkdb.com/docs/system-tables/#status-tables).
+    /// Serialize the query once, to run it many times with other parameters.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.status() → response
+    /// query.prepare() → prepared_query
     /// ```
     ///
     /// Where:
-    /// - table: [r.table(...)](crate::r::table) |
-    /// [query.table(...)](Self::table)
-    /// - response: [StatusResponse](crate::types::StatusResponse)
+    /// - prepared_query: [PreparedQuery](crate::cmd::prepare::PreparedQuery)
+    ///
+    /// # Description
+    ///
+    /// The placeholders built with [r.param](crate::r::param) are bound
+    /// to the values given to `run`, by name, and sent as datums.
+    /// Only the values of the parameters are serialized on each run,
+    /// which saves the serialization of large queries on hot paths.
+    /// The table prefix of the session is applied when the query is run.
     ///
     /// ## Examples
     ///
-    /// Get a table’s status.
+    /// Get the posts of a user.
     ///
     /// ```
-    /// use neor::types::StatusResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::arguments::GetAllOption;
+    /// use neor::{args, r, Converter, Result};
+    /// use serde_json::{json, Value};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let posts_of = r.table("posts")
+    ///         .get_all(args!(r.param("user_id"), GetAllOption::default().index("author_id")))
+    ///         .prepare()?;
     ///
-    ///     let response: StatusResponse = r.table("simbad")
-    ///         .status()
-    ///         .run(&conn)
+    ///     let posts: Vec<Value> = posts_of
+    ///         .run(&conn, json!({"user_id": 1}))
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response.name.is_some());
-    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn status(&self) -> Self {
-        status::new().with_parent(self)
+    ///
+    /// # Related commands
+    /// - [run](self::run)
+    /// - [build_query](self::build_query)
+    pub fn prepare(&self) -> Result<prepare::PreparedQuery> {
+        prepare::new(self)
     }
 
-    /// Wait for a table or all the tables in a database to be ready.
+    /// Serialize the query to the JSON sent to the server.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// table.wait(()) → response
-    /// db.wait(()) → response
-    /// r.wait(table) → response
-    /// r.wait(database) → response
-    /// table.wait(options) → response
-    /// db.wait(options) → response
-    /// r.wait(args!(table, options)) → response
-    /// r.wait(args!(database, options)) → response
+    /// query.to_wire_json() → value
     /// ```
     ///
-    /// Where:
-    /// - table: [r.table(...)](crate::r::table) |
-    /// [query.table(...)](Self::table)
-    /// - db: [r.db(...)](crate::r::db)
-    /// - options: [WaitOption](crate::arguments::WaitOption)
-    /// - response: [WaitResponse](crate::types::WaitResponse)
-    ///
     /// # Description
     ///
-    /// A table may be temporarily unavailable after creation,
-    /// rebalancing or reconfiguring.
-    /// The `wait` command blocks until the given
-    /// table (or database) is fully up to date.
+    /// Terms are serialized as `[term_type, [args...], {opts}]` arrays,
+    /// like in the ReQL protocol. The result can be logged, stored
+    /// and turned back into a query with [from_wire_json](Self::from_wire_json).
+    /// The table prefix of the session is not applied,
+    /// it is applied when the replayed query is run.
+    /// The variables of functions are numbered from 1 in each query,
+    /// so the same query always gives the same JSON, which can be
+    /// hashed, cached or compared.
     ///
     /// ## Examples
     ///
-    /// Wait on a table to be ready.
+    /// Log a query.
     ///
     /// ```
-    /// use neor::types::WaitResponse;
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
+    /// use serde_json::json;
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
+    /// fn example() -> Result<()> {
+    ///     let query = r.table("posts").get(1);
     ///
-    ///     let response: WaitResponse = r.table("simbad")
-    ///         .wait(())
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     assert_eq!(query.to_wire_json()?, json!([16, [[15, ["posts"]], 1]]));
     ///
-    ///     assert!(response.ready == 1);
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
-    ///
-    /// Wait on a table with timeout to be ready for reads.
+    /// Compare queries built with functions.
     ///
     /// ```
-    /// use neor::arguments::{WaitFor, WaitOption};
-    /// use neor::types::WaitResponse;
-    /// use neor::{args, r, Converter, Result};
+    /// use neor::{func, r, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let table_command = r.db("anim").table("simbad");
-    ///     let opts = WaitOption::default()
-    ///         .wait_for(WaitFor::ReadyForReads)
-    ///         .timeout(8000f64);
+    /// fn example() -> Result<()> {
+    ///     let query = || r.table("posts").filter(func!(|post| post.g("views").gt(10)));
     ///
-    ///     let response: WaitResponse =  r.wait(args!(table_command, opts))
-    ///         .run(&conn)
-    ///         .await?
-    ///         .unwrap()
-    ///         .parse()?;
+    ///     assert_eq!(query().to_wire_json()?, query().to_wire_json()?);
     ///
-    ///     assert!(response.ready == 1);
-    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn wait(&self, args: impl wait::WaitArg) -> Self {
-        wait::new(args).with_parent(self)
+    ///
+    /// # Related commands
+    /// - [from_wire_json](Self::from_wire_json)
+    /// - [to_reql_string](Self::to_reql_string)
+    pub fn to_wire_json(&self) -> Result<Value> {
+        let query = self.clone().with_query_vars();
+        Ok(serde_json::to_value(Query(&query))?)
     }
 
-    /// Run a query on a connection,
-    /// returning either a single JSON result or a cursor,
-    /// depending on the query.
+    /// Print the query in the syntax of the driver.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.run(&session) → value
-    /// query.run(connection) → value
-    /// query.run(args!(&session, options)) → value
-    /// query.run(args!(connection, options)) → value
-    /// query.run(&mut session) → value
-    /// query.run(args!(&mut session, options)) → value
+    /// query.to_reql_string() → string
     /// ```
     ///
-    /// Where:
-    /// - session: [Session](crate::connection::Session)
-    /// - connection: [Connection](crate::connection::Connection)
-    /// - options: [RunOption](crate::arguments::RunOption)
-    /// - stream: [impl Stream<Item = Result<Value>>](futures::stream::Stream)
+    /// # Description
+    ///
+    /// The query is printed from its wire representation, like the
+    /// queries of the server errors, so a query built by higher-level
+    /// code reads as it would have been written by hand. Functions
+    /// are printed as closures over `var_<n>` arguments.
+    /// See [query_logger](crate::types::ConnectionCommand::query_logger)
+    /// to log every query run by a session.
     ///
     /// ## Examples
     ///
-    /// If you are OK with potentially out of date data
-    /// from all the tables involved in this query and
-    /// want potentially faster reads,
-    /// pass a flag allowing out of date data in an options object.
-    /// Settings for individual tables will supercede this global
-    /// setting for all tables in the query.
+    /// Print a query.
     ///
     /// ```
-    /// use neor::arguments::{ReadMode, RunOption};
-    /// use neor::{args, r, Result};
+    /// use neor::{r, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let opts = RunOption::default().read_mode(ReadMode::Outdated);
+    /// fn example() -> Result<()> {
+    ///     let query = r.table("posts").get(1).g("title");
+    ///
+    ///     assert_eq!(
+    ///         query.to_reql_string()?,
+    ///         r#"r.table("posts").get(1).get_field("title")"#
+    ///     );
     ///
-    ///     r.table("simbad").run(args!(&conn, opts)).await?;
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [to_wire_json](Self::to_wire_json)
+    pub fn to_reql_string(&self) -> Result<String> {
+        Ok(crate::printer::print(&self.to_wire_json()?))
+    }
+
+    /// Find mistakes in a query before running it.
     ///
-    /// If you want to specify whether to wait for a write to be written
-    /// to disk (overriding the table’s default settings),
-    /// you can set `durability` to `Durability::Hard`
-    /// or `Durability::Soft` in the options.
+    /// # Command syntax
     ///
+    /// ```text
+    /// query.validate() → Vec<Diagnostic>
     /// ```
-    /// use neor::arguments::{Durability, RunOption};
-    /// use neor::{args, r, Result};
-    /// use serde_json::json;
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let opts = RunOption::default().durability(Durability::Hard);
-    ///     let data = json!({
-    ///         "name": "Pumba",
-    ///         "live": 5
-    ///     });
+    /// # Description
     ///
-    ///     r.table("simbad").insert(data).run(args!(&conn, opts)).await?;
-    ///     
-    ///     Ok(())
-    /// }
-    /// ```
+    /// The terms of the query are checked, without the server,
+    /// for mistakes which would make it fail on running:
+    /// - aggregations, such as `count` or `order_by`, of the infinite stream of `changes`;
+    /// - `order_by` on another index than the `between` it is chained to;
+    /// - `get_intersecting` and `get_nearest` given a string, e.g. a field name,
+    ///   instead of a geometry, or without their geospatial index.
+    ///
+    /// Each mistake is returned as a [Diagnostic](crate::types::Diagnostic),
+    /// whose [kind](crate::types::DiagnosticKind) can be matched by tests.
+    /// An empty list only means that none of these mistakes were found.
     ///
     /// ## Examples
     ///
-    /// If you do not want a time object to be converted to a native date object,
-    /// you can pass a time_format flag to prevent it
-    /// (valid flags are `Format::Raw` and `Format::Native`).
-    /// This query returns an object with two fields (epoch_time and $reql_type$)
-    /// instead of a native date object.
+    /// Check a query counting the changes of a table.
     ///
     /// ```
-    /// use neor::arguments::{Format, RunOption};
-    /// use neor::{args, r, Result};
-    /// use serde_json::json;
+    /// use neor::types::DiagnosticKind;
+    /// use neor::r;
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let opts = RunOption::default().time_format(Format::Raw);
+    /// fn example() {
+    ///     let diagnostics = r.table("posts").changes(()).count(()).validate();
     ///
-    ///     r.now().cmd().run(args!(&conn, opts)).await?;
-    ///     
-    ///     Ok(())
+    ///     assert_eq!(diagnostics.len(), 1);
+    ///     assert_eq!(diagnostics[0].kind, DiagnosticKind::AggregationOfChangefeed);
+    ///     assert_eq!(diagnostics[0].term, "count");
     /// }
     /// ```
     ///
+    /// # Related commands
+    /// - [to_reql_string](Self::to_reql_string)
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        self.diagnostics()
+    }
+
+    /// Measure the length of the JSON sent to run the query.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// query.estimate_wire_size() → usize
+    /// ```
+    ///
+    /// # Description
+    ///
+    /// The length, in bytes, of the [wire JSON](Self::to_wire_json) of the query.
+    /// It is an estimate of the request sent by [run](Self::run),
+    /// which also holds the run options and applies the table prefix of the session.
+    /// See [max_query_size](crate::types::ConnectionCommand::max_query_size)
+    /// to fail the queries too large for the server before they are sent.
+    ///
     /// ## Examples
     ///
-    /// Specify the database to use for the query.
+    /// Measure a query.
     ///
     /// ```
-    /// use neor::arguments::{Format, RunOption};
-    /// use neor::{args, r, Result};
-    /// use serde_json::json;
+    /// use neor::{r, Result};
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let opts = RunOption::default().db("jikoni");
+    /// fn example() -> Result<()> {
+    ///     // [16,[[15,["posts"]],1]]
+    ///     assert_eq!(r.table("posts").get(1).estimate_wire_size()?, 24);
     ///
-    ///     r.table("simbad").run(args!(&conn, opts)).await?;
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// ## Examples
+    /// # Related commands
+    /// - [term_depth](Self::term_depth)
+    /// - [to_wire_json](Self::to_wire_json)
+    pub fn estimate_wire_size(&self) -> Result<usize> {
+        self.clone().with_query_vars().wire_size()
+    }
+
+    /// Measure the nesting of the terms of the query.
     ///
-    /// Change the batching parameters for this query.
+    /// # Command syntax
     ///
+    /// ```text
+    /// query.term_depth() → usize
     /// ```
-    /// use neor::arguments::{Format, RunOption};
-    /// use neor::{args, r, Result};
-    /// use serde_json::json;
     ///
-    /// async fn example() -> Result<()> {
-    ///     let conn = r.connection().connect().await?;
-    ///     let opts = RunOption::default()
-    ///         .max_batch_rows(16)
-    ///         .max_batch_bytes(2048);
+    /// # Description
     ///
-    ///     r.table("simbad").run(args!(&conn, opts)).await?;
-    ///     
-    ///     Ok(())
+    /// A term without arguments, such as a number, has a depth of 1,
+    /// and each term is one deeper than its deepest argument.
+    /// Arrays and objects count as terms holding their values.
+    /// See [max_term_depth](crate::types::ConnectionCommand::max_term_depth)
+    /// to fail the queries nesting too many terms before they are sent.
+    ///
+    /// ## Examples
+    ///
+    /// Measure a query.
+    ///
+    /// ```
+    /// use neor::r;
+    ///
+    /// fn example() {
+    ///     assert_eq!(r.expr(1).term_depth(), 1);
+    ///     assert_eq!(r.table("posts").get(1).term_depth(), 3);
+    ///     assert_eq!(r.expr([[1]]).term_depth(), 3);
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [connection](crate::r::connection)
-    pub async fn run(&self, args: impl run::RunArg) -> Result<Option<Value>> {
-        self.build_query(args).try_next().await
+    /// - [estimate_wire_size](Self::estimate_wire_size)
+    pub fn term_depth(&self) -> usize {
+        self.depth()
     }
 
-    /// Prepare query for execution
-    ///
-    /// See [run](self::run) for more information.
+    /// Build a query from the JSON sent to the server.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// query.build_query(&session) → stream
-    /// query.build_query(connection) → stream
-    /// query.build_query(args!(&session, options)) → stream
-    /// query.build_query(args!(connection, options)) → stream
-    /// query.build_query(&mut session) → stream
-    /// query.build_query(args!(&mut session, options)) → stream
+    /// Command::from_wire_json(value) → query
     /// ```
     ///
     /// Where:
-    /// - session: [Session](crate::connection::Session)
-    /// - connection: [Connection](crate::connection::Connection)
-    /// - options: [RunOption](crate::arguments::RunOption)
+    /// - value: Value
     ///
     /// # Description
     ///
-    /// This method has the same parameters as `run`.
-    /// The main difference between `build_query` and `run` is that
-    /// `build_query` can be used to execute multiple requests
+    /// Arrays are read as `[term_type, [args...], {opts}]` terms,
+    /// other values as datums, so `to_wire_json` then `from_wire_json`
+    /// gives back an equivalent query, which can be chained like any other.
+    /// Binary pseudo-types, such as the `function` of
+    /// [index_status](Self::index_status), are kept as they are.
     ///
     /// ## Examples
     ///
-    /// You can use `query.build_query` to get the same result than `query.run`
+    /// Replay a stored query.
     ///
     /// ```
-    /// use futures::TryStreamExt;
-    /// use neor::r;
+    /// use neor::{r, Command, Converter, Result};
+    /// use serde_json::json;
     ///
-    /// async fn example() -> neor::Result<()> {
+    /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
+    ///     let stored = r.table("posts").to_wire_json()?;
+    ///
+    ///     let response: usize = Command::from_wire_json(stored)?
+    ///         .count(())
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response > 0);
     ///
-    ///     r.table("simbad").build_query(&conn).try_next().await?;
-    ///     // is same than
-    ///     r.table("simbad").run(&conn).await?;
-    ///     
     ///     Ok(())
     /// }
     /// ```
     ///
     /// ## Examples
     ///
-    /// Run many queries
+    /// Store the creation of an index copied from another table
+    /// and run it later.
     ///
     /// ```
-    /// use futures::stream::{select_all, TryStreamExt};
-    /// use neor::{r, Converter};
+    /// use neor::types::IndexStatusResponse;
+    /// use neor::{args, r, Command, Converter, Result};
     ///
-    /// async fn example() -> neor::Result<()> {
+    /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let mut streams = Vec::new();
-    ///     let expected_messages: Vec<String> = (0..10_000)
-    ///         .into_iter()
-    ///         .map(|i| format!("message {}", i))
-    ///         .collect();
-    ///
-    ///     for msg in expected_messages.iter() {
-    ///         streams.push(r.expr(msg).build_query(&conn));
-    ///     }
-    ///
-    ///     let mut list = select_all(streams);
-    ///     let mut response = Vec::new();
+    ///     let status: Vec<IndexStatusResponse> = r.table("posts")
+    ///         .index_status("author")
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
     ///
-    ///     while let Some(msg) = list.try_next().await? {
-    ///         let msg: String = msg.parse()?;
-    ///         response.push(msg);
-    ///     }
+    ///     let stored = r.table("archives")
+    ///         .index_create(args!("author", status[0].function.clone()))
+    ///         .to_wire_json()?;
     ///
-    ///     assert!(response == expected_messages);
+    ///     Command::from_wire_json(stored)?.run(&conn).await?;
     ///
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
-    /// - [run](self::run)
-    pub fn build_query(&self, args: impl run::RunArg) -> impl Stream<Item = Result<Value>> {
-        Box::pin(run::new(self.clone(), args))
+    /// - [to_wire_json](Self::to_wire_json)
+    /// - [raw](crate::r::raw)
+    pub fn from_wire_json(value: Value) -> Result<Self> {
+        Self::from_wire(value)
     }
 }
 
 // for debug purposes only
-fn bytes_to_string(bytes: &[u8]) -> String {
+pub(crate) fn bytes_to_string(bytes: &[u8]) -> String {
     if let Ok(string) = str::from_utf8(bytes) {
         return string.to_owned();
     }
@@ -33,6 +33,10 @@ where
     /// Note that ReQL will not perform type coercion.
     /// You cannot, for example, `add` a string and a number together.
     ///
+    /// Number literals, `i64`, `f64` and strings can also be on the left
+    /// of the operator, e.g. `1 + r.expr(2)`, and the same holds for
+    /// `-`, `*`, `/` and `%`. Other numbers need `r.expr(number)`.
+    ///
     /// ## Examples
     ///
     /// Create a simple object.
@@ -72,3 +76,5 @@ where
         arg.into().add_to_cmd(TermType::Add).with_parent(&self)
     }
 }
+
+impl_reflected_op!(Add, add, i64, f64, &str, String);
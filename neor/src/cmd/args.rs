@@ -1,12 +1,11 @@
 use ql2::term::TermType;
-use serde::Serialize;
 
-use crate::Command;
+use crate::{Command, CommandArg};
 
-pub(crate) fn new<T, S>(values: T) -> Command
+pub(crate) fn new<S, T>(values: T) -> Command
 where
-    S: Serialize,
-    T: IntoIterator<Item = S> + Serialize,
+    S: Into<CommandArg>,
+    T: IntoIterator<Item = S>,
 {
-    Command::new(TermType::Args).with_arg(Command::from_json(values))
+    Command::new(TermType::Args).with_arg(super::array::new(values))
 }
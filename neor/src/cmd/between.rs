@@ -1,6 +1,8 @@
+use std::ops::Range;
+
 use ql2::term::TermType;
 
-use crate::arguments::{Args, BetweenOption};
+use crate::arguments::{Args, BetweenOption, Bound};
 use crate::{Command, CommandArg};
 
 pub(crate) fn new(args: impl BetweenArg) -> Command {
@@ -35,3 +37,51 @@ where
         (self.0 .0.into(), self.0 .1.into(), self.0 .2)
     }
 }
+
+impl BetweenArg for Range<Bound> {
+    fn into_between_opts(self) -> (CommandArg, CommandArg, BetweenOption) {
+        Args((self.start, self.end)).into_between_opts()
+    }
+}
+
+impl BetweenArg for Args<(Bound, Bound)> {
+    fn into_between_opts(self) -> (CommandArg, CommandArg, BetweenOption) {
+        Args((self.0 .0, self.0 .1, BetweenOption::default())).into_between_opts()
+    }
+}
+
+// The status of each bound overrides the one of the options
+impl BetweenArg for Args<(Bound, Bound, BetweenOption)> {
+    fn into_between_opts(self) -> (CommandArg, CommandArg, BetweenOption) {
+        let Args((lower, upper, opts)) = self;
+        let (min_key, left_bound) = lower.into_parts();
+        let (max_key, right_bound) = upper.into_parts();
+        let opts = opts.left_bound(left_bound).right_bound(right_bound);
+
+        (min_key.into(), max_key.into(), opts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::arguments::{Args, BetweenOption, Bound, Status};
+    use crate::r;
+
+    #[test]
+    fn between_bounds_override_opts() {
+        let opts = BetweenOption::default()
+            .index("age")
+            .right_bound(Status::Open);
+        let query = r
+            .table("users")
+            .between(Args((Bound::open(10), Bound::closed(20), opts)));
+        let wire = query.to_wire_json().unwrap();
+
+        assert_eq!(
+            wire[2],
+            json!({"index": "age", "left_bound": "open", "right_bound": "closed"})
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Shl;
+
 use ql2::term::TermType;
 
 use crate::{Command, CommandArg};
@@ -5,3 +7,14 @@ use crate::{Command, CommandArg};
 pub(crate) fn new(number: impl Into<CommandArg>) -> Command {
     number.into().add_to_cmd(TermType::BitSal)
 }
+
+impl<T> Shl<T> for Command
+where
+    T: Into<CommandArg>,
+{
+    type Output = Self;
+
+    fn shl(self, number: T) -> Self {
+        new(number).with_parent(&self)
+    }
+}
@@ -1,7 +1,16 @@
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::{Stream, TryStreamExt};
 use ql2::term::TermType;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::time::{self, Instant};
 
-use crate::arguments::ChangesOption;
-use crate::Command;
+use crate::arguments::{Args, ChangesOption};
+use crate::cmd::run::{self, RunArg};
+use crate::types::{ChangesResponse, FeedItem};
+use crate::{err, Command, Result};
 
 pub(crate) fn new(args: impl ChangesArg) -> Command {
     let mut command = Command::new(TermType::Changes);
@@ -13,6 +22,69 @@ pub(crate) fn new(args: impl ChangesArg) -> Command {
     command.mark_change_feed()
 }
 
+pub(crate) async fn collect_for<T>(
+    query: &Command,
+    arg: impl RunArg,
+    duration: Duration,
+) -> Result<Vec<ChangesResponse<T>>>
+where
+    T: Unpin + DeserializeOwned,
+{
+    if !query.change_feed() {
+        return Err(err::ReqlDriverError::Other(
+            "collect_changes_for can only be used on a changefeed".to_owned(),
+        )
+        .into());
+    }
+
+    let (conn, opts) = arg.into_run_opts()?;
+    let deadline = Instant::now() + duration;
+    let mut changes = Vec::new();
+    let mut stream = Box::pin(run::new::<_, Value>(
+        query.clone(),
+        Args((conn.clone(), opts)),
+    ));
+
+    let result = loop {
+        let next = time::timeout_at(deadline, stream.try_next()).await;
+
+        match next {
+            Ok(Ok(Some(value))) => match serde_json::from_value::<Vec<ChangesResponse<T>>>(value) {
+                Ok(batch) => changes.extend(batch),
+                Err(error) => break Err(error.into()),
+            },
+            // the feed was closed by the server
+            Ok(Ok(None)) => break Ok(()),
+            Ok(Err(error)) => break Err(error),
+            // the window is over, the feed is stopped before returning
+            Err(_) => break conn.stop_stream(stream).await,
+        }
+    };
+
+    result.map(|_| changes)
+}
+
+pub(crate) fn feed<T>(query: Command, arg: impl RunArg) -> impl Stream<Item = Result<FeedItem<T>>>
+where
+    T: Unpin + DeserializeOwned,
+{
+    try_stream! {
+        if !query.change_feed() {
+            Err(err::ReqlDriverError::Other(
+                "run_changes can only be used on a changefeed".to_owned(),
+            ))?;
+        }
+
+        let mut batches = Box::pin(run::new::<_, Vec<FeedItem<T>>>(query, arg));
+
+        while let Some(batch) = batches.try_next().await? {
+            for item in batch {
+                yield item;
+            }
+        }
+    }
+}
+
 pub trait ChangesArg {
     fn into_changes_opts(self) -> Option<ChangesOption>;
 }
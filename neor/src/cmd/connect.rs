@@ -1,30 +1,35 @@
-#![allow(clippy::unused_io_amount)]
-
 //! Create a new connection to the database server
 
 use std::borrow::Cow;
 use std::fs::File;
 use std::io::Read;
-use std::sync::atomic::{AtomicBool, AtomicU64};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
 use std::time::Duration;
 
-use async_native_tls::{Certificate, TlsConnector};
 use async_net::TcpStream;
 use dashmap::DashMap;
 use futures::channel::oneshot;
 use futures::lock::Mutex;
+use futures::{AsyncRead, AsyncWrite};
+use socket2::{SockRef, TcpKeepalive};
 use tokio::task;
 use tokio::time;
+use tracing::trace;
 
+use crate::advisor::IndexAdvisor;
 use crate::constants::{
     DEFAULT_RETHINKDB_DBNAME, DEFAULT_RETHINKDB_HOSTNAME, DEFAULT_RETHINKDB_PASSWORD,
     DEFAULT_RETHINKDB_PORT, DEFAULT_RETHINKDB_USER, RETHINKDB_DRIVER_NAME,
 };
 use crate::err::ReqlDriverError;
-use crate::{InnerSession, Result, Session, StaticString, TcpStreamConnection};
+use crate::handshake;
+use crate::observer::{QueryLogger, QueryObserver};
+use crate::retry::RetryPolicy;
+use crate::tls::TlsConfig;
+use crate::{Command, InnerSession, Result, Session, StaticString, TcpStreamConnection};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct ConnectionCommand {
     /// Host of the RethinkDB instance. The default value is `localhost`.
@@ -44,7 +49,35 @@ pub struct ConnectionCommand {
 
     timeout: Option<Duration>,
 
-    tls_connector: Option<TlsConnector>,
+    /// Idle time before the OS starts sending TCP keepalive probes.
+    keepalive: Option<Duration>,
+
+    /// Interval between the pings sent on an idle session.
+    ping_interval: Option<Duration>,
+
+    /// Prefix added to every table name used by the queries.
+    table_prefix: Option<Cow<'static, str>>,
+
+    /// Certificates of the TLS connection, which is not encrypted when unset.
+    tls: Option<TlsConfig>,
+
+    /// Called around each request sent to the server.
+    observer: Option<Arc<dyn QueryObserver>>,
+
+    /// Called with the text of each query run by the session.
+    logger: Option<QueryLogger>,
+
+    /// Nesting of terms above which queries fail before being sent.
+    max_term_depth: Option<usize>,
+
+    /// Length of JSON above which queries fail before being sent.
+    max_query_size: Option<usize>,
+
+    /// Retries of the queries failing on an unavailable server.
+    retry_policy: Option<RetryPolicy>,
+
+    /// Advice on the indexes able to replace the filters of the queries.
+    index_advisor: Option<IndexAdvisor>,
 }
 
 #[derive(Debug, Clone)]
@@ -64,12 +97,12 @@ impl ConnectionCommand {
 
             let session = time::timeout(timeout, reciever)
                 .await
-                .unwrap_or_else(|_| {
-                    panic!(
-                        "It took {} seconds to open the connection",
+                .map_err(|_| {
+                    ReqlDriverError::Timeout(format!(
+                        "could not open the connection in {} seconds",
                         timeout.as_secs_f32()
-                    )
-                })
+                    ))
+                })?
                 .expect("The connection has been closed");
 
             session
@@ -113,21 +146,152 @@ impl ConnectionCommand {
         self
     }
 
+    /// Enable TCP keepalive on the socket
+    ///
+    /// The OS starts probing the server once the connection has been idle
+    /// for `idle`, so NAT and firewall timeouts don't silently drop
+    /// long-lived sessions (e.g. a session only used for a changefeed).
+    /// A dead peer then makes pending reads fail and marks the session broken.
+    pub fn keepalive(mut self, idle: Duration) -> Self {
+        self.keepalive = Some(idle);
+        self
+    }
+
+    /// Ping the server every `interval` while the session is idle
+    ///
+    /// Each ping is a server info request which must be answered
+    /// within `interval`. When a ping fails, the session is marked broken
+    /// and the driver opens a new socket to the server, which clears
+    /// the broken state once it succeeds.
+    ///
+    /// Sessions running a changefeed are not pinged, use
+    /// [keepalive](Self::keepalive) to keep them alive.
+    pub fn ping_interval(mut self, interval: Duration) -> Self {
+        self.ping_interval = Some(interval);
+        self
+    }
+
+    /// Prefix the name of every table used by the queries
+    ///
+    /// `r.table("users")` then targets `staging_users` when the prefix
+    /// is `staging_`, so the same code can run against prefixed tables of
+    /// a shared cluster. The prefix is applied when a query is run,
+    /// to `table`, `table_create` and `table_drop`, except on the system
+    /// tables of the `rethinkdb` database. Names returned by the server,
+    /// e.g. by `table_list`, keep the prefix.
+    ///
+    /// Use `RunOption::table_prefix(false)` to opt out for a query.
+    pub fn table_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.table_prefix = Some(prefix.into().static_string());
+        self
+    }
+
+    /// Report each request of the session to `observer`
+    ///
+    /// See [observer](crate::observer) for the reported events.
+    pub fn observer(mut self, observer: impl QueryObserver + 'static) -> Self {
+        self.observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Call `logger` with the text of each query run by the session
+    ///
+    /// The queries are printed by [to_reql_string](crate::Command::to_reql_string),
+    /// after the table prefix is applied, before they are sent.
+    ///
+    /// ```
+    /// use neor::{r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r
+    ///         .connection()
+    ///         .query_logger(|query| eprintln!("running {}", query))
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     r.table("users").count(()).run(&conn).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn query_logger(mut self, logger: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.logger = Some(QueryLogger::new(logger));
+        self
+    }
+
+    /// Fail the queries nesting more than `depth` terms, before they are sent
+    ///
+    /// The depth is measured by [term_depth](crate::Command::term_depth).
+    /// Deeply nested queries, e.g. built by folding many values in a loop,
+    /// otherwise only fail on the server, with a less helpful error.
+    /// The queries are not limited by default.
+    pub fn max_term_depth(mut self, depth: usize) -> Self {
+        self.max_term_depth = Some(depth);
+        self
+    }
+
+    /// Fail the queries whose JSON is longer than `bytes`, before they are sent
+    ///
+    /// The length is measured by [estimate_wire_size](crate::Command::estimate_wire_size),
+    /// after the table prefix is applied. The queries are not limited by default.
+    ///
+    /// ```
+    /// use neor::err::{ReqlDriverError, ReqlError};
+    /// use neor::{r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().max_query_size(1024).connect().await?;
+    ///     let ids: Vec<u32> = (0..1000).collect();
+    ///
+    ///     let error = r.table("users").get_all(r.args(ids)).run(&conn).await.unwrap_err();
+    ///
+    ///     assert!(matches!(
+    ///         error,
+    ///         ReqlError::Driver(ReqlDriverError::QueryTooLarge(_))
+    ///     ));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn max_query_size(mut self, bytes: usize) -> Self {
+        self.max_query_size = Some(bytes);
+        self
+    }
+
+    /// Run the queries of the session again when the server is unavailable
+    ///
+    /// [run](crate::Command::run) and the other methods reading a whole
+    /// result follow `policy`, see [retry](crate::retry).
+    /// The queries are not retried by default.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Report the `filter` terms of the queries which an index could replace
+    ///
+    /// `advisor` inspects each query run by the session before it is sent,
+    /// see [advisor](crate::advisor). The queries are not inspected by default.
+    pub fn index_advisor(mut self, advisor: IndexAdvisor) -> Self {
+        self.index_advisor = Some(advisor);
+        self
+    }
+
     /// This method set ssl connection
-    pub fn ssl_context(mut self, ssl_context: SslContext) -> Self {
+    pub fn ssl_context(self, ssl_context: SslContext) -> Self {
         let mut file = File::open(ssl_context.ca_certs).unwrap();
         let mut certificate = Vec::new();
 
         file.read_to_end(&mut certificate).unwrap();
 
-        let certificate = if let Ok(cert) = Certificate::from_pem(&certificate) {
-            cert
-        } else {
-            Certificate::from_der(&certificate).unwrap()
-        };
-
-        self.tls_connector = Some(TlsConnector::new().add_root_certificate(certificate));
+        self.tls(TlsConfig::new().ca_bundle(certificate))
+    }
 
+    /// Encrypt the connection with TLS
+    ///
+    /// See [tls](crate::tls) for the available backends.
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
         self
     }
 
@@ -169,248 +333,216 @@ impl ConnectionCommand {
     }
 
     async fn create_session(self) -> Result<Session> {
-        let stream = TcpStream::connect((self.host.as_ref(), self.port)).await?;
-        let mut stream = TcpStreamConnection {
-            tls_stream: if let Some(connector) = &self.tls_connector {
-                let stream = connector
-                    .connect(self.host.as_ref(), stream.clone())
-                    .await?;
-                Some(stream)
-            } else {
-                None
-            },
-            stream,
-        };
+        let stream = self.create_stream().await?;
 
-        if let Some(tcp_stream) = stream.tls_stream {
-            stream.tls_stream = Some(tools::handshake(tcp_stream, &self).await?);
-        } else {
-            stream.stream = tools::handshake(stream.stream, &self).await?;
-        }
+        Ok(self.session(stream))
+    }
 
+    // The session of a stream whose handshake is done
+    pub(crate) fn session(self, stream: TcpStreamConnection) -> Session {
+        let server_version = std::sync::Mutex::new(stream.server_version.clone());
         let inner = InnerSession {
             stream: Mutex::new(stream),
-            db: Mutex::new(self.db),
+            db: Mutex::new(self.db.clone()),
             channels: DashMap::new(),
+            cancels: DashMap::new(),
             token: AtomicU64::new(0),
             broken: AtomicBool::new(false),
+            closed: AtomicBool::new(false),
             change_feed: AtomicBool::new(false),
+            outbox: Default::default(),
+            queued: Default::default(),
+            table_prefix: self.table_prefix.clone(),
+            server_version,
+            observer: self.observer.clone(),
+            logger: self.logger.clone(),
+            command: self.clone(),
         };
+        let inner = Arc::new(inner);
 
-        Ok(Session {
-            inner: Arc::new(inner),
-        })
-    }
-}
-
-impl Default for ConnectionCommand {
-    fn default() -> Self {
-        Self {
-            host: DEFAULT_RETHINKDB_HOSTNAME.static_string(),
-            port: DEFAULT_RETHINKDB_PORT,
-            db: DEFAULT_RETHINKDB_DBNAME.static_string(),
-            user: DEFAULT_RETHINKDB_USER.static_string(),
-            password: DEFAULT_RETHINKDB_PASSWORD.static_string(),
-            timeout: None,
-            tls_connector: None,
+        if let Some(interval) = self.ping_interval {
+            task::spawn(keep_alive(Arc::downgrade(&inner), interval));
         }
-    }
-}
-
-mod tools {
-    use futures::io::{AsyncReadExt, AsyncWriteExt};
-    use futures::{AsyncRead, AsyncWrite};
-    use ql2::version_dummy::Version;
-    use scram::client::{ScramClient, ServerFinal, ServerFirst};
-    use serde::{Deserialize, Serialize};
-    use tracing::trace;
 
-    use super::ConnectionCommand;
-    use crate::cmd::bytes_to_string;
-    use crate::constants::{
-        BUFFER_SIZE, DEFAULT_AUTHENTICATION_METHOD, NULL_BYTE, PROTOCOL_VERSION,
-    };
-    use crate::{err, Result};
-
-    // Performs the actual handshake
-    //
-    // This method optimises message exchange as suggested in the RethinkDB
-    // documentation by sending message 3 right after message 1, without waiting
-    // for message 2 first.
-    pub async fn handshake<T>(mut stream: T, opts: &ConnectionCommand) -> Result<T>
-    where
-        T: Unpin + AsyncWrite + AsyncReadExt + AsyncRead + AsyncReadExt,
-    {
-        trace!("sending supported version to RethinkDB");
+        Session { inner }
+    }
 
-        stream
-            .write_all(&(Version::V10 as i32).to_le_bytes())
-            .await?; // message 1
+    pub(crate) fn retries(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
 
-        let scram = ScramClient::new(opts.user.as_ref(), opts.password.as_ref(), None);
-        let (scram, msg) = client_first(scram)?;
-        trace!("sending client first message");
-        stream.write_all(&msg).await?; // message 3
+    pub(crate) fn advisor(&self) -> Option<&IndexAdvisor> {
+        self.index_advisor.as_ref()
+    }
 
-        let mut buf = [0u8; BUFFER_SIZE];
+    // Fail a query exceeding the limits of the session, before it is sent
+    pub(crate) fn check_query(&self, query: &Command) -> Result<()> {
+        if let Some(max_depth) = self.max_term_depth {
+            let depth = query.depth();
+
+            if depth > max_depth {
+                return Err(ReqlDriverError::QueryTooLarge(format!(
+                    "the query nests {} terms, more than the limit of {}",
+                    depth, max_depth
+                ))
+                .into());
+            }
+        }
 
-        trace!("receiving message(s) from RethinkDB");
-        stream.read(&mut buf).await?; // message 2
-        let (len, resp) = bytes(&buf, 0);
-        trace!("received server info; info: {}", bytes_to_string(resp));
-        ServerInfo::validate(resp)?;
+        if let Some(max_size) = self.max_query_size {
+            let size = query.wire_size()?;
 
-        let offset = len + 1;
-        let resp = if offset < BUFFER_SIZE && buf[offset] != NULL_BYTE {
-            bytes(&buf, offset).1
-        } else {
-            trace!("reading auth response");
-            stream.read(&mut buf).await?; // message 4
-            bytes(&buf, 0).1
-        };
-        trace!("received auth response");
-        let info = AuthResponse::from_slice(resp)?;
-        let auth = match info.authentication {
-            Some(auth) => auth,
-            None => {
-                let msg = String::from("server did not send authentication info");
-                return Err(err::ReqlDriverError::Other(msg).into());
+            if size > max_size {
+                return Err(ReqlDriverError::QueryTooLarge(format!(
+                    "the query is {} bytes long, more than the limit of {}",
+                    size, max_size
+                ))
+                .into());
             }
-        };
+        }
 
-        let (scram, msg) = client_final(scram, &auth)?;
-        trace!("sending client final message");
-        stream.write_all(&msg).await?; // message 5
+        Ok(())
+    }
 
-        trace!("reading server final message");
-        stream.read(&mut buf).await?; // message 6
-        let resp = bytes(&buf, 0).1;
-        trace!("received server final message");
-        server_final(scram, resp)?;
+    // Replace the socket of `session` by a new one,
+    // which reopens a closed or broken session
+    pub(crate) async fn reopen(&self, session: &InnerSession) -> Result<()> {
+        trace!("reconnecting to {}:{}", self.host, self.port);
+        let stream = self.create_stream().await?;
 
-        trace!("client connected successfully");
+        *session.server_version.lock().unwrap() = stream.server_version.clone();
+        *session.stream.lock().await = stream;
+        session.broken.store(false, Ordering::SeqCst);
+        session.closed.store(false, Ordering::SeqCst);
+        trace!("reconnected to {}:{}", self.host, self.port);
 
-        Ok(stream)
-    }
-    fn bytes(buf: &[u8], offset: usize) -> (usize, &[u8]) {
-        let len = (&buf[offset..])
-            .iter()
-            .take_while(|x| **x != NULL_BYTE)
-            .count();
-        let max = offset + len;
-        (max, &buf[offset..max])
-    }
-
-    // We are going to use &str for `server_version` because it is safe to do so.
-    // Unfortunately, the other fields that are using String, are doing so because
-    // because they can potentially contain an escaped double quote which is not
-    // supported by serde in &str.
-    #[derive(Serialize, Deserialize, Debug)]
-    struct ServerInfo<'a> {
-        success: bool,
-        min_protocol_version: usize,
-        max_protocol_version: usize,
-        server_version: &'a str,
-    }
-
-    impl ServerInfo<'_> {
-        fn validate(resp: &[u8]) -> Result<()> {
-            let info = serde_json::from_slice::<ServerInfo>(resp)?;
-            if !info.success {
-                return Err(err::ReqlRuntimeError::Internal(bytes_to_string(resp)).into());
-            }
-            #[allow(clippy::absurd_extreme_comparisons)]
-            if PROTOCOL_VERSION < info.min_protocol_version
-                || info.max_protocol_version < PROTOCOL_VERSION
-            {
-                let msg = format!(
-                    "unsupported protocol version {version}, expected between {min} and {max}",
-                    version = PROTOCOL_VERSION,
-                    min = info.min_protocol_version,
-                    max = info.max_protocol_version,
-                );
-                return Err(err::ReqlDriverError::Other(msg).into());
-            }
-            Ok(())
-        }
+        Ok(())
     }
 
-    #[derive(Serialize, Deserialize, Debug)]
-    struct AuthRequest {
-        protocol_version: usize,
-        authentication_method: &'static str,
-        authentication: String,
+    async fn create_stream(&self) -> Result<TcpStreamConnection> {
+        let future = self.open_stream();
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(
+            future,
+            tracing::info_span!("connect", host = %self.host, port = self.port),
+        );
+
+        future.await
     }
 
-    fn client_first(scram: ScramClient<'_>) -> Result<(ServerFirst<'_>, Vec<u8>)> {
-        let (scram, client_first) = scram.client_first();
-        let ar = AuthRequest {
-            protocol_version: PROTOCOL_VERSION,
-            authentication_method: DEFAULT_AUTHENTICATION_METHOD,
-            authentication: client_first,
+    async fn open_stream(&self) -> Result<TcpStreamConnection> {
+        let stream = TcpStream::connect((self.host.as_ref(), self.port)).await?;
+
+        if let Some(idle) = self.keepalive {
+            SockRef::from(&stream).set_tcp_keepalive(&TcpKeepalive::new().with_time(idle))?;
+        }
+
+        let mut stream = TcpStreamConnection {
+            tls_stream: if let Some(tls) = &self.tls {
+                let stream = tls
+                    .connector()?
+                    .connect(self.host.as_ref(), stream.clone())
+                    .await?;
+                Some(stream)
+            } else {
+                None
+            },
+            stream,
+            server_version: String::new(),
         };
-        let mut msg = serde_json::to_vec(&ar)?;
-        msg.push(NULL_BYTE);
-        Ok((scram, msg))
+
+        if let Some(tcp_stream) = stream.tls_stream {
+            let (tls_stream, server_version) = self.handshake(tcp_stream).await?;
+            stream.tls_stream = Some(tls_stream);
+            stream.server_version = server_version;
+        } else {
+            (stream.stream, stream.server_version) = self.handshake(stream.stream).await?;
+        }
+
+        Ok(stream)
     }
 
-    #[derive(Serialize, Deserialize, Debug)]
-    struct AuthConfirmation {
-        authentication: String,
+    async fn handshake<T>(&self, stream: T) -> Result<(T, String)>
+    where
+        T: Unpin + AsyncRead + AsyncWrite,
+    {
+        let future = handshake::authenticate(stream, &self.user, &self.password);
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(
+            future,
+            tracing::info_span!("handshake", user = %self.user),
+        );
+
+        future.await
     }
+}
 
-    fn client_final(scram: ServerFirst<'_>, auth: &str) -> Result<(ServerFinal, Vec<u8>)> {
-        let scram = scram
-            .handle_server_first(auth)
-            .map_err(|x| x.to_string())
-            .map_err(err::ReqlDriverError::Other)?;
-        let (scram, client_final) = scram.client_final();
-        let conf = AuthConfirmation {
-            authentication: client_final,
+// Pings the server while the session is alive and
+// replaces its socket when the server stops answering.
+async fn keep_alive(inner: Weak<InnerSession>, interval: Duration) {
+    loop {
+        time::sleep(interval).await;
+
+        let session = match inner.upgrade() {
+            Some(inner) => Session { inner },
+            None => break,
         };
-        let mut msg = serde_json::to_vec(&conf)?;
-        msg.push(NULL_BYTE);
-        Ok((scram, msg))
-    }
-
-    #[derive(Serialize, Deserialize, Debug)]
-    struct AuthResponse {
-        success: bool,
-        authentication: Option<String>,
-        error_code: Option<usize>,
-        error: Option<String>,
-    }
-
-    impl AuthResponse {
-        fn from_slice(resp: &[u8]) -> Result<Self> {
-            let info = serde_json::from_slice::<AuthResponse>(resp)?;
-            if !info.success {
-                // If error code is between 10 and 20, this is an auth error
-                if let Some(10..=20) = info.error_code {
-                    if let Some(msg) = info.error {
-                        return Err(err::ReqlDriverError::Auth(msg).into());
-                    }
-                }
-                return Err(err::ReqlRuntimeError::Internal(bytes_to_string(resp)).into());
+
+        // until the session is reconnected
+        if session.inner.closed.load(Ordering::SeqCst) {
+            continue;
+        }
+
+        if session.is_open() {
+            // the socket is busy, so the session is not idle
+            if session.inner.is_change_feed() || session.inner.stream.try_lock().is_none() {
+                continue;
+            }
+
+            match time::timeout(interval, session.ping()).await {
+                Ok(Ok(_)) => continue,
+                // a query took the socket before the ping did
+                Err(_) if session.inner.stream.try_lock().is_none() => continue,
+                _ => session.inner.mark_broken(),
             }
-            Ok(info)
+        }
+
+        trace!("ping failed");
+        if let Err(error) = session.inner.command.reopen(&session.inner).await {
+            trace!("reconnection failed; error: {}", error);
         }
     }
+}
 
-    fn server_final(scram: ServerFinal, resp: &[u8]) -> Result<()> {
-        let info = AuthResponse::from_slice(resp)?;
-        if let Some(auth) = info.authentication {
-            if let Err(error) = scram.handle_server_final(&auth) {
-                return Err(err::ReqlDriverError::Other(error.to_string()).into());
-            }
+impl Default for ConnectionCommand {
+    fn default() -> Self {
+        Self {
+            host: DEFAULT_RETHINKDB_HOSTNAME.static_string(),
+            port: DEFAULT_RETHINKDB_PORT,
+            db: DEFAULT_RETHINKDB_DBNAME.static_string(),
+            user: DEFAULT_RETHINKDB_USER.static_string(),
+            password: DEFAULT_RETHINKDB_PASSWORD.static_string(),
+            timeout: None,
+            keepalive: None,
+            ping_interval: None,
+            table_prefix: None,
+            tls: None,
+            observer: None,
+            logger: None,
+            max_term_depth: None,
+            max_query_size: None,
+            retry_policy: None,
+            index_advisor: None,
         }
-        Ok(())
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use crate::err::{ReqlDriverError, ReqlError};
+    use crate::r;
 
     use super::ConnectionCommand;
 
@@ -430,6 +562,31 @@ mod test {
         execute_test(connection_command).await
     }
 
+    #[tokio::test]
+    async fn test_keepalive_connection() {
+        let connection_command = ConnectionCommand::default()
+            .keepalive(Duration::from_secs(60))
+            .ping_interval(Duration::from_secs(30));
+
+        execute_test(connection_command).await
+    }
+
+    #[test]
+    fn test_query_limits() {
+        let query = r.expr([[1, 2], [3, 4]]);
+        let too_large = |command: ConnectionCommand| {
+            matches!(
+                command.check_query(&query),
+                Err(ReqlError::Driver(ReqlDriverError::QueryTooLarge(_)))
+            )
+        };
+
+        assert!(ConnectionCommand::default().check_query(&query).is_ok());
+        assert!(too_large(ConnectionCommand::default().max_term_depth(2)));
+        assert!(!too_large(ConnectionCommand::default().max_term_depth(3)));
+        assert!(too_large(ConnectionCommand::default().max_query_size(10)));
+    }
+
     async fn execute_test(connection_command: ConnectionCommand) {
         let db_expected = connection_command.db.clone();
 
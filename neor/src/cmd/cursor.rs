@@ -0,0 +1,120 @@
+use std::future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::vec;
+
+use futures::stream::{BoxStream, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::arguments::{Args, ReadMode};
+use crate::cmd::run::{self, RunArg};
+use crate::{err, Command, Result};
+
+pub(crate) fn new<T>(query: &Command, arg: impl RunArg) -> Result<Cursor<T>> {
+    let (conn, opts) = arg.into_run_opts()?;
+
+    Ok(Cursor {
+        read_mode: opts.read_mode.or(query.read_mode()),
+        stream: run::new(query.clone(), Args((conn, opts))).boxed(),
+        batch: Vec::new().into_iter(),
+    })
+}
+
+/// The results of a query, received batch by batch,
+/// see [cursor](crate::Command::cursor)
+///
+/// The cursor is a stream of the documents. Only the current batch is
+/// kept in memory, so the helpers below run in constant memory
+/// whatever the number of documents.
+pub struct Cursor<T> {
+    stream: BoxStream<'static, Result<Value>>,
+    batch: vec::IntoIter<T>,
+    read_mode: Option<ReadMode>,
+}
+
+impl<T> Cursor<T> {
+    /// The read mode sent with the query, from the run options or
+    /// commands like [outdated](crate::Command::outdated)
+    ///
+    /// `None` when the query uses the read mode of its tables.
+    pub fn read_mode(&self) -> Option<ReadMode> {
+        self.read_mode
+    }
+}
+
+impl<T> Cursor<T>
+where
+    T: Unpin + DeserializeOwned,
+{
+    /// Combine the documents with `f`, starting from `init`
+    pub async fn fold_stream<B, F>(self, init: B, mut f: F) -> Result<B>
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.try_fold(init, |acc, doc| future::ready(Ok(f(acc, doc))))
+            .await
+    }
+
+    /// Number of documents
+    pub async fn count(self) -> Result<usize> {
+        self.fold_stream(0, |count, _| count + 1).await
+    }
+}
+
+impl Cursor<Value> {
+    /// Sum of the field `field` of the documents
+    ///
+    /// Documents without the field are skipped, like with
+    /// the `sum` command of the server.
+    pub async fn sum_field(self, field: &str) -> Result<f64> {
+        self.try_fold(0., |sum, doc| {
+            future::ready(match doc.get(field) {
+                None => Ok(sum),
+                Some(value) => value.as_f64().map(|value| sum + value).ok_or_else(|| {
+                    err::ReqlDriverError::Other(format!(
+                        "expected a number in field `{}`, found {}",
+                        field, value
+                    ))
+                    .into()
+                }),
+            })
+        })
+        .await
+    }
+}
+
+impl<T> Stream for Cursor<T>
+where
+    T: Unpin + DeserializeOwned,
+{
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(doc) = this.batch.next() {
+                return Poll::Ready(Some(Ok(doc)));
+            }
+
+            let batch = match this.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(batch))) => batch,
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // A sequence comes in arrays, a single document as is
+            let batch: serde_json::Result<Vec<T>> = match batch {
+                Value::Array(_) => serde_json::from_value(batch),
+                doc => serde_json::from_value(doc).map(|doc| vec![doc]),
+            };
+
+            match batch {
+                Ok(batch) => this.batch = batch.into_iter(),
+                Err(error) => return Poll::Ready(Some(Err(error.into()))),
+            }
+        }
+    }
+}
@@ -1,7 +1,41 @@
 use ql2::term::TermType;
+use serde_json::Value;
 
-use crate::{Command, CommandArg};
+use crate::arguments::DefaultOption;
+use crate::{var_counter, Command, CommandArg, Func};
 
-pub(crate) fn new(default_value: impl Into<CommandArg>) -> Command {
-    default_value.into().add_to_cmd(TermType::Default)
+pub(crate) fn new(args: impl DefaultArg) -> Command {
+    args.into_default_opts().add_to_cmd(TermType::Default)
+}
+
+pub trait DefaultArg {
+    fn into_default_opts(self) -> CommandArg;
+}
+
+impl<T> DefaultArg for T
+where
+    T: Into<CommandArg>,
+{
+    fn into_default_opts(self) -> CommandArg {
+        self.into()
+    }
+}
+
+impl DefaultArg for DefaultOption {
+    fn into_default_opts(self) -> CommandArg {
+        if self.error_to_null.unwrap_or_default() {
+            return Value::Null.into();
+        }
+
+        // The function receives the message of the error,
+        // or `null` when the value itself is `null`
+        let id = var_counter();
+        let error = Command::var(id);
+        let body = Command::new(TermType::Branch)
+            .with_arg(error.eq(Value::Null))
+            .with_arg(Command::from_json(Value::Null))
+            .with_arg(Command::new(TermType::Error).with_arg(error));
+
+        Func::new(vec![id], body).into()
+    }
 }
@@ -29,3 +29,15 @@ impl<T: Geometry> DistanceArg for Args<(T, DistanceOption)> {
         (self.0 .0.into(), None, self.0 .1)
     }
 }
+
+impl DistanceArg for Command {
+    fn into_distance_opts(self) -> (Command, Option<Command>, DistanceOption) {
+        (self, None, Default::default())
+    }
+}
+
+impl DistanceArg for Args<(Command, DistanceOption)> {
+    fn into_distance_opts(self) -> (Command, Option<Command>, DistanceOption) {
+        (self.0 .0, None, self.0 .1)
+    }
+}
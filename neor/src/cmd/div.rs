@@ -42,3 +42,5 @@ where
         arg.into().add_to_cmd(TermType::Div).with_parent(&self)
     }
 }
+
+impl_reflected_op!(Div, div, i64, f64);
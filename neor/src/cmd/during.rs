@@ -1,8 +1,10 @@
+use std::ops::{Bound, RangeBounds};
+
 use ql2::term::TermType;
+use time::OffsetDateTime;
 
-use crate::arguments::{Args, DuringOption};
-use crate::types::DateTime;
-use crate::Command;
+use crate::arguments::{Args, DuringOption, Status};
+use crate::{r, Command};
 
 pub(crate) fn new(args: impl DuringArg) -> Command {
     let (arg1, arg2, opts) = args.into_during_opts();
@@ -17,19 +19,32 @@ pub trait DuringArg {
     fn into_during_opts(self) -> (Command, Command, DuringOption);
 }
 
-impl DuringArg for Args<(DateTime, DateTime)> {
+// Each end is a time or a command, e.g. `r::min_val()` or `r::max_val()`
+impl<S, E> DuringArg for Args<(S, E)>
+where
+    S: Into<Command>,
+    E: Into<Command>,
+{
     fn into_during_opts(self) -> (Command, Command, DuringOption) {
         (self.0 .0.into(), self.0 .1.into(), Default::default())
     }
 }
 
-impl DuringArg for Args<(DateTime, DateTime, DuringOption)> {
+impl<S, E> DuringArg for Args<(S, E, DuringOption)>
+where
+    S: Into<Command>,
+    E: Into<Command>,
+{
     fn into_during_opts(self) -> (Command, Command, DuringOption) {
         (self.0 .0.into(), self.0 .1.into(), self.0 .2)
     }
 }
 
-impl DuringArg for Args<(DateTime, DateTime, Option<DuringOption>)> {
+impl<S, E> DuringArg for Args<(S, E, Option<DuringOption>)>
+where
+    S: Into<Command>,
+    E: Into<Command>,
+{
     fn into_during_opts(self) -> (Command, Command, DuringOption) {
         (
             self.0 .0.into(),
@@ -39,14 +54,31 @@ impl DuringArg for Args<(DateTime, DateTime, Option<DuringOption>)> {
     }
 }
 
-impl DuringArg for Args<(Command, Command)> {
+// `start..end`, `start..=end`, `start..` or `(Bound, Bound)`,
+// an unbounded end being `r::min_val()` or `r::max_val()`
+impl<T> DuringArg for T
+where
+    T: RangeBounds<OffsetDateTime>,
+{
     fn into_during_opts(self) -> (Command, Command, DuringOption) {
-        (self.0 .0, self.0 .1, Default::default())
+        let (start, left_bound) = bound(self.start_bound(), r::min_val());
+        let (end, right_bound) = bound(self.end_bound(), r::max_val());
+
+        (
+            start,
+            end,
+            DuringOption {
+                left_bound,
+                right_bound,
+            },
+        )
     }
 }
 
-impl DuringArg for Args<(Command, Command, DuringOption)> {
-    fn into_during_opts(self) -> (Command, Command, DuringOption) {
-        (self.0 .0, self.0 .1, self.0 .2)
+fn bound(bound: Bound<&OffsetDateTime>, unbounded: Command) -> (Command, Option<Status>) {
+    match bound {
+        Bound::Included(time) => ((*time).into(), Some(Status::Closed)),
+        Bound::Excluded(time) => ((*time).into(), Some(Status::Open)),
+        Bound::Unbounded => (unbounded, None),
     }
 }
@@ -1,7 +1,35 @@
 use ql2::term::TermType;
 
+use crate::arguments::{Args, FoldOption};
 use crate::{Command, CommandArg, Func};
 
-pub(crate) fn new(base: impl Into<CommandArg>, func: Func) -> Command {
-    base.into().add_to_cmd(TermType::Fold).with_arg(func.0)
+pub(crate) fn new(base: impl Into<CommandArg>, args: impl FoldArg) -> Command {
+    let (func, opts) = args.into_fold_opts();
+    let mut command = base.into().add_to_cmd(TermType::Fold).with_arg(func.0);
+
+    if let Some(emit) = opts.emit {
+        command = command.with_term_opt("emit", emit.0);
+    }
+
+    if let Some(final_emit) = opts.final_emit {
+        command = command.with_term_opt("final_emit", final_emit.0);
+    }
+
+    command
+}
+
+pub trait FoldArg {
+    fn into_fold_opts(self) -> (Func, FoldOption);
+}
+
+impl FoldArg for Func {
+    fn into_fold_opts(self) -> (Func, FoldOption) {
+        (self, Default::default())
+    }
+}
+
+impl FoldArg for Args<(Func, FoldOption)> {
+    fn into_fold_opts(self) -> (Func, FoldOption) {
+        (self.0 .0, self.0 .1)
+    }
 }
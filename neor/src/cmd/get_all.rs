@@ -1,4 +1,5 @@
 use ql2::term::TermType;
+use serde::Serialize;
 
 use crate::arguments::{Args, GetAllOption};
 use crate::command_tools::CmdOpts;
@@ -15,15 +16,17 @@ pub trait GetAllArg {
     fn into_get_all_opts(self) -> (CmdOpts, GetAllOption);
 }
 
+// Each key is serialized, so that a tuple or an array is the key
+// of a compound index
 impl<S, T> GetAllArg for T
 where
-    S: Into<String>,
+    S: Serialize,
     T: IntoIterator<Item = S>,
 {
     fn into_get_all_opts(self) -> (CmdOpts, GetAllOption) {
         let keys = self
             .into_iter()
-            .map(|key| Command::from_json(key.into()))
+            .map(|key| Command::from_json(key))
             .collect();
 
         (CmdOpts::Many(keys), Default::default())
@@ -38,7 +41,7 @@ impl GetAllArg for Command {
 
 impl<S, T> GetAllArg for Args<(T, GetAllOption)>
 where
-    S: Into<String>,
+    S: Serialize,
     T: IntoIterator<Item = S>,
 {
     fn into_get_all_opts(self) -> (CmdOpts, GetAllOption) {
@@ -46,7 +49,7 @@ where
             .0
              .0
             .into_iter()
-            .map(|key| Command::from_json(key.into()))
+            .map(|key| Command::from_json(key))
             .collect();
 
         (CmdOpts::Many(keys), self.0 .1)
@@ -1,9 +1,9 @@
 use ql2::term::TermType;
 
 use crate::arguments::GetIntersectingOption;
-use crate::{Command, Geometry};
+use crate::{Command, Geometry, StaticString};
 
-pub(crate) fn new(args: impl GetIntersectingArg, index: &'static str) -> Command {
+pub(crate) fn new(args: impl GetIntersectingArg, index: impl StaticString) -> Command {
     let opts = GetIntersectingOption::default().index(index);
 
     Command::new(TermType::GetIntersecting)
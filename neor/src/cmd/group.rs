@@ -45,6 +45,25 @@ impl<const N: usize> GroupArg for [Func; N] {
     }
 }
 
+impl GroupArg for Command {
+    fn into_group_opts(self) -> (CmdOpts, GroupOption) {
+        (CmdOpts::Single(self), Default::default())
+    }
+}
+
+impl<const N: usize> GroupArg for [Command; N] {
+    fn into_group_opts(self) -> (CmdOpts, GroupOption) {
+        (CmdOpts::Many(self.into()), Default::default())
+    }
+}
+
+/// Group by the `index` of the options alone
+impl GroupArg for GroupOption {
+    fn into_group_opts(self) -> (CmdOpts, GroupOption) {
+        (CmdOpts::Many(Vec::new()), self)
+    }
+}
+
 impl GroupArg for Args<(&str, GroupOption)> {
     fn into_group_opts(self) -> (CmdOpts, GroupOption) {
         let arg = Command::from_json(self.0 .0);
@@ -76,3 +95,15 @@ impl<const N: usize> GroupArg for Args<([Func; N], GroupOption)> {
         (CmdOpts::Many(funcs), self.0 .1)
     }
 }
+
+impl GroupArg for Args<(Command, GroupOption)> {
+    fn into_group_opts(self) -> (CmdOpts, GroupOption) {
+        (CmdOpts::Single(self.0 .0), self.0 .1)
+    }
+}
+
+impl<const N: usize> GroupArg for Args<([Command; N], GroupOption)> {
+    fn into_group_opts(self) -> (CmdOpts, GroupOption) {
+        (CmdOpts::Many(self.0 .0.into()), self.0 .1)
+    }
+}
@@ -1,17 +1,113 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use ql2::term::TermType;
+use serde::Serialize;
+use serde_json::Value;
 
+use crate::arguments::Args;
+use crate::err::{ReqlDriverError, ReqlError};
 use crate::{Command, CommandArg};
 
-pub(crate) fn new<K, V>(args: HashMap<K, V>) -> Command
+pub(crate) fn new(args: impl HashMapArg) -> Command {
+    let args = args.into_hash_map_opts();
+    // Sent as a JSON object when the keys are known, which lets
+    // `merge` and `update` read the `literal` terms of its values
+    let typ = if args.iter().all(|(key, _)| key.as_str().is_some()) {
+        TermType::MakeObj
+    } else {
+        TermType::Object
+    };
+
+    args.into_iter()
+        .flat_map(|(key, value)| [key, value])
+        .fold(Command::new(typ), |command, arg| command.with_arg(arg))
+}
+
+pub trait HashMapArg {
+    fn into_hash_map_opts(self) -> Vec<(Command, Command)>;
+}
+
+fn pairs<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> Vec<(Command, Command)>
 where
     K: Into<CommandArg>,
     V: Into<CommandArg>,
 {
-    args.into_iter()
-        .flat_map(|(key, value)| [key.into().to_cmd(), value.into().to_cmd()])
-        .fold(Command::new(TermType::Object), |command, arg| {
-            command.with_arg(arg)
-        })
+    pairs
+        .into_iter()
+        .map(|(key, value)| (key.into().to_cmd(), value.into().to_cmd()))
+        .collect()
+}
+
+impl<K, V> HashMapArg for HashMap<K, V>
+where
+    K: Into<CommandArg>,
+    V: Into<CommandArg>,
+{
+    fn into_hash_map_opts(self) -> Vec<(Command, Command)> {
+        pairs(self)
+    }
+}
+
+impl<K, V> HashMapArg for BTreeMap<K, V>
+where
+    K: Into<CommandArg>,
+    V: Into<CommandArg>,
+{
+    fn into_hash_map_opts(self) -> Vec<(Command, Command)> {
+        pairs(self)
+    }
+}
+
+impl<K, V> HashMapArg for Vec<(K, V)>
+where
+    K: Into<CommandArg>,
+    V: Into<CommandArg>,
+{
+    fn into_hash_map_opts(self) -> Vec<(Command, Command)> {
+        pairs(self)
+    }
+}
+
+impl<K, V, const N: usize> HashMapArg for [(K, V); N]
+where
+    K: Into<CommandArg>,
+    V: Into<CommandArg>,
+{
+    fn into_hash_map_opts(self) -> Vec<(Command, Command)> {
+        pairs(self)
+    }
+}
+
+/// The fields of a serializable struct or map,
+/// replaced or completed by the entries of the map
+impl<T, M> HashMapArg for Args<(T, M)>
+where
+    T: Serialize,
+    M: HashMapArg,
+{
+    fn into_hash_map_opts(self) -> Vec<(Command, Command)> {
+        let (value, overrides) = self.0;
+        let overrides = overrides.into_hash_map_opts();
+        let fields = match serde_json::to_value(value).map_err(ReqlError::from) {
+            Ok(Value::Object(fields)) => fields,
+            result => {
+                let error: Result<Value, ReqlError> = result.and(Err(ReqlDriverError::Other(
+                    "expected a struct or a map".to_owned(),
+                )
+                .into()));
+                return vec![(Command::from(error), Command::from(Value::Null))];
+            }
+        };
+        let overridden: Vec<_> = overrides
+            .iter()
+            .filter_map(|(key, _)| key.as_str().map(str::to_owned))
+            .collect();
+
+        fields
+            .into_iter()
+            .filter(|(key, _)| !overridden.contains(key))
+            .map(|(key, value)| (Command::from_json(key), Command::from(value)))
+            .chain(overrides)
+            .collect()
+    }
 }
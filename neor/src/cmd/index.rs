@@ -1,6 +1,3 @@
-use serde::Serialize;
-
-use crate::proto::Query;
 use crate::Command;
 
 pub fn new(args: impl IndexArg) -> Index {
@@ -10,11 +7,6 @@ pub fn new(args: impl IndexArg) -> Index {
 #[derive(Debug, Clone)]
 pub struct Index(pub(crate) Command);
 
-#[derive(Serialize)]
-struct Inner<'a> {
-    index: Query<'a>,
-}
-
 pub trait IndexArg {
     fn into_index_opts(self) -> Index;
 }
@@ -1,13 +1,14 @@
 use ql2::term::TermType;
 
 use crate::arguments::{Args, IndexCreateOption};
+use crate::types::Binary;
 use crate::{Command, CommandArg, Func};
 
 pub(crate) fn new(args: impl IndexCreateArg) -> Command {
     let (arg, func, opts) = args.into_table_create_opts();
     let mut command = arg.add_to_cmd(TermType::IndexCreate);
 
-    if let Some(Func(func)) = func {
+    if let Some(func) = func {
         command = command.with_arg(func);
     }
 
@@ -15,14 +16,14 @@ pub(crate) fn new(args: impl IndexCreateArg) -> Command {
 }
 
 pub trait IndexCreateArg {
-    fn into_table_create_opts(self) -> (CommandArg, Option<Func>, IndexCreateOption);
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption);
 }
 
 impl<T> IndexCreateArg for T
 where
     T: Into<CommandArg>,
 {
-    fn into_table_create_opts(self) -> (CommandArg, Option<Func>, IndexCreateOption) {
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption) {
         (self.into(), None, Default::default())
     }
 }
@@ -31,8 +32,8 @@ impl<T> IndexCreateArg for Args<(T, Func)>
 where
     T: Into<CommandArg>,
 {
-    fn into_table_create_opts(self) -> (CommandArg, Option<Func>, IndexCreateOption) {
-        (self.0 .0.into(), Some(self.0 .1), Default::default())
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption) {
+        (self.0 .0.into(), Some(self.0 .1 .0), Default::default())
     }
 }
 
@@ -40,7 +41,7 @@ impl<T> IndexCreateArg for Args<(T, IndexCreateOption)>
 where
     T: Into<CommandArg>,
 {
-    fn into_table_create_opts(self) -> (CommandArg, Option<Func>, IndexCreateOption) {
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption) {
         (self.0 .0.into(), None, self.0 .1)
     }
 }
@@ -49,7 +50,47 @@ impl<T> IndexCreateArg for Args<(T, Func, IndexCreateOption)>
 where
     T: Into<CommandArg>,
 {
-    fn into_table_create_opts(self) -> (CommandArg, Option<Func>, IndexCreateOption) {
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption) {
+        (self.0 .0.into(), Some(self.0 .1 .0), self.0 .2)
+    }
+}
+
+impl<T> IndexCreateArg for Args<(T, Command)>
+where
+    T: Into<CommandArg>,
+{
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption) {
+        (self.0 .0.into(), Some(self.0 .1), Default::default())
+    }
+}
+
+impl<T> IndexCreateArg for Args<(T, Command, IndexCreateOption)>
+where
+    T: Into<CommandArg>,
+{
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption) {
         (self.0 .0.into(), Some(self.0 .1), self.0 .2)
     }
 }
+
+impl<T> IndexCreateArg for Args<(T, Binary)>
+where
+    T: Into<CommandArg>,
+{
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption) {
+        let func = Command::from_json(self.0 .1);
+
+        (self.0 .0.into(), Some(func), Default::default())
+    }
+}
+
+impl<T> IndexCreateArg for Args<(T, Binary, IndexCreateOption)>
+where
+    T: Into<CommandArg>,
+{
+    fn into_table_create_opts(self) -> (CommandArg, Option<Command>, IndexCreateOption) {
+        let func = Command::from_json(self.0 .1);
+
+        (self.0 .0.into(), Some(func), self.0 .2)
+    }
+}
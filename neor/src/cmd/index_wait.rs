@@ -1,34 +1,47 @@
 use ql2::term::TermType;
 
-use crate::arguments::Args;
+use super::wait::client_timeout;
+use crate::arguments::{Args, WaitOption};
 use crate::command_tools::CmdOpts;
 use crate::{Command, CommandArg};
 
 pub(crate) fn new(args: impl IndexWaitArg) -> Command {
+    let (args, opts) = args.into_index_wait_opts();
     let mut command = Command::new(TermType::IndexWait);
 
-    if let Some(args) = args.into_index_wait_opts() {
+    if let Some(args) = args {
         command = args.add_to_cmd(command)
     }
 
-    command
+    // The server has no timeout for `index_wait`,
+    // the query is stopped by the driver instead
+    match client_timeout(&opts) {
+        Some(timeout) => command.with_timeout(timeout),
+        None => command,
+    }
 }
 
 pub trait IndexWaitArg {
-    fn into_index_wait_opts(self) -> Option<CmdOpts>;
+    fn into_index_wait_opts(self) -> (Option<CmdOpts>, WaitOption);
 }
 
 impl IndexWaitArg for () {
-    fn into_index_wait_opts(self) -> Option<CmdOpts> {
-        None
+    fn into_index_wait_opts(self) -> (Option<CmdOpts>, WaitOption) {
+        (None, Default::default())
+    }
+}
+
+impl IndexWaitArg for WaitOption {
+    fn into_index_wait_opts(self) -> (Option<CmdOpts>, WaitOption) {
+        (None, self)
     }
 }
 
 impl IndexWaitArg for &str {
-    fn into_index_wait_opts(self) -> Option<CmdOpts> {
+    fn into_index_wait_opts(self) -> (Option<CmdOpts>, WaitOption) {
         let arg = Command::from_json(self);
 
-        Some(CmdOpts::Single(arg))
+        (Some(CmdOpts::Single(arg)), Default::default())
     }
 }
 
@@ -37,9 +50,9 @@ where
     C: Into<CommandArg>,
     T: IntoIterator<Item = C>,
 {
-    fn into_index_wait_opts(self) -> Option<CmdOpts> {
-        Some(CmdOpts::Many(
-            self.0.into_iter().map(|cmd| cmd.into().to_cmd()).collect(),
-        ))
+    fn into_index_wait_opts(self) -> (Option<CmdOpts>, WaitOption) {
+        let indexes = self.0.into_iter().map(|cmd| cmd.into().to_cmd()).collect();
+
+        (Some(CmdOpts::Many(indexes)), Default::default())
     }
 }
@@ -2,8 +2,30 @@ use ql2::term::TermType;
 
 use crate::{Command, Func};
 
-pub(crate) fn new(other_table: Command, func: Func) -> Command {
+pub(crate) fn new(other_table: Command, predicate: impl JoinPredicate) -> Command {
     Command::new(TermType::InnerJoin)
         .with_arg(other_table)
-        .with_arg(func.0)
+        .with_arg(predicate.into_join_predicate())
+}
+
+/// The predicate of [inner_join](crate::Command::inner_join)
+/// and [outer_join](crate::Command::outer_join)
+///
+/// Implemented by [Func], and by a [Command] evaluating to a function,
+/// e.g. a [Func] built beforehand from values known at run time.
+pub trait JoinPredicate {
+    fn into_join_predicate(self) -> Command;
+}
+
+impl JoinPredicate for Func {
+    fn into_join_predicate(self) -> Command {
+        self.0
+    }
+}
+
+// a function built beforehand, e.g. `Func::new(..).into()`
+impl JoinPredicate for Command {
+    fn into_join_predicate(self) -> Command {
+        self
+    }
 }
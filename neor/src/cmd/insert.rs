@@ -1,24 +1,34 @@
 use ql2::term::TermType;
 
 use crate::arguments::{Args, InsertOption};
-use crate::{Command, CommandArg};
+use crate::{Command, CommandArg, Func};
 
 pub(crate) fn new(args: impl InsertArg) -> Command {
-    let (arg, opts) = args.into_insert_opts();
+    let (arg, opts, conflict) = args.into_insert_opts();
+    let command = arg.add_to_cmd(TermType::Insert);
 
-    arg.add_to_cmd(TermType::Insert).with_opts(opts)
+    match conflict {
+        // The function resolving the conflicts is sent as the `conflict` option
+        Some(func) => command
+            .with_opts(InsertOption {
+                conflict: None,
+                ..opts
+            })
+            .with_term_opt("conflict", func.0),
+        None => command.with_opts(opts),
+    }
 }
 
 pub trait InsertArg {
-    fn into_insert_opts(self) -> (CommandArg, InsertOption);
+    fn into_insert_opts(self) -> (CommandArg, InsertOption, Option<Func>);
 }
 
 impl<T> InsertArg for T
 where
     T: Into<CommandArg>,
 {
-    fn into_insert_opts(self) -> (CommandArg, InsertOption) {
-        (self.into(), Default::default())
+    fn into_insert_opts(self) -> (CommandArg, InsertOption, Option<Func>) {
+        (self.into(), Default::default(), None)
     }
 }
 
@@ -26,7 +36,16 @@ impl<T> InsertArg for Args<(T, InsertOption)>
 where
     T: Into<CommandArg>,
 {
-    fn into_insert_opts(self) -> (CommandArg, InsertOption) {
-        (self.0 .0.into(), self.0 .1)
+    fn into_insert_opts(self) -> (CommandArg, InsertOption, Option<Func>) {
+        (self.0 .0.into(), self.0 .1, None)
+    }
+}
+
+impl<T> InsertArg for Args<(T, InsertOption, Func)>
+where
+    T: Into<CommandArg>,
+{
+    fn into_insert_opts(self) -> (CommandArg, InsertOption, Option<Func>) {
+        (self.0 .0.into(), self.0 .1, Some(self.0 .2))
     }
 }
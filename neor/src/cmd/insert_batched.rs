@@ -0,0 +1,102 @@
+use std::ops::Range;
+
+use futures::stream::{self, StreamExt};
+use serde::Serialize;
+
+use crate::arguments::{Args, BatchOption, InsertOption};
+use crate::cmd::run::RunArg;
+use crate::types::MutationResponse;
+use crate::{Command, Converter, Result};
+
+const DEFAULT_CHUNK_SIZE: usize = 200;
+const DEFAULT_PARALLELISM: usize = 4;
+
+pub(crate) async fn new<I, T>(
+    table: &Command,
+    documents: I,
+    options: BatchOption,
+    args: impl RunArg,
+) -> Result<BatchResponse>
+where
+    I: IntoIterator<Item = T>,
+    T: Serialize,
+{
+    let (conn, run_options) = args.into_run_opts()?;
+    let chunk_size = options.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE).max(1);
+    let parallelism = options.parallelism.unwrap_or(DEFAULT_PARALLELISM).max(1);
+    let mut insert_options = InsertOption::default();
+
+    if let Some(conflict) = options.conflict {
+        insert_options = insert_options.conflict(conflict);
+    }
+
+    // `buffered` keeps the order of the chunks, and so of the generated keys
+    let mut chunks = stream::iter(documents)
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let start = index * chunk_size;
+            let documents = start..start + chunk.len();
+            let query = table.insert(Args((chunk, insert_options)));
+            let args = Args((conn.clone(), run_options.clone()));
+
+            async move {
+                let response = query.run(args).await;
+                (documents, response)
+            }
+        })
+        .buffered(parallelism);
+
+    let mut batch = BatchResponse::default();
+
+    while let Some((documents, response)) = chunks.next().await {
+        let response =
+            response.and_then(|response| response.unwrap_or_default().parse::<MutationResponse>());
+
+        match response {
+            Ok(response) => batch.merge(documents, response),
+            Err(error) => {
+                batch.mutation.errors += documents.len();
+                batch.errors.push(BatchError {
+                    documents,
+                    message: error.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(batch)
+}
+
+/// Result of [insert_batched](crate::Command::insert_batched)
+#[derive(Debug, Default, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct BatchResponse {
+    /// Counters summed over the chunks
+    pub mutation: MutationResponse,
+    /// Errors of the chunks, in the order of the documents
+    pub errors: Vec<BatchError>,
+}
+
+/// Error of a chunk of documents
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BatchError {
+    /// Positions of the documents of the chunk in the inserted sequence
+    pub documents: Range<usize>,
+    /// The query error, or the first error reported by the server
+    pub message: String,
+}
+
+impl BatchResponse {
+    fn merge(&mut self, documents: Range<usize>, response: MutationResponse) {
+        if let Some(message) = &response.first_error {
+            self.errors.push(BatchError {
+                documents,
+                message: message.clone(),
+            });
+        }
+
+        self.mutation.merge(response);
+    }
+}
@@ -0,0 +1,47 @@
+use serde_json::json;
+
+use crate::arguments::Args;
+use crate::{r, var_counter, Command, Func};
+
+pub(crate) fn new(args: impl LimitChangesArg) -> Func {
+    let (max_changes, primary_key) = args.into_limit_changes_opts();
+    let (result_id, change_id) = (var_counter(), var_counter());
+    let result = Command::var(result_id);
+    let change = Command::var(change_id);
+
+    let changes = result.g("changes").default(json!([]));
+    let changed_key = change
+        .g("new_val")
+        .g(primary_key.as_str())
+        .default(change.g("old_val").g(primary_key.as_str()));
+    let limited = r.object([
+        r.expr("changes"),
+        changes.limit(max_changes),
+        r.expr("changed_keys"),
+        changes.map(Func::new(vec![change_id], changed_key)),
+    ]);
+
+    Func::new(vec![result_id], result.merge(limited))
+}
+
+pub trait LimitChangesArg {
+    /// Returns the maximum number of changes and the primary key
+    fn into_limit_changes_opts(self) -> (usize, String);
+}
+
+impl LimitChangesArg for usize {
+    fn into_limit_changes_opts(self) -> (usize, String) {
+        (self, "id".to_owned())
+    }
+}
+
+impl<T> LimitChangesArg for Args<(usize, T)>
+where
+    T: Into<String>,
+{
+    fn into_limit_changes_opts(self) -> (usize, String) {
+        let Args((max_changes, primary_key)) = self;
+
+        (max_changes, primary_key.into())
+    }
+}
@@ -1,8 +1,9 @@
 use ql2::term::TermType;
 use serde::{Deserialize, Serialize};
 
-use crate::types::{GeoType, ReqlType};
-use crate::{Command, Geometry};
+use crate::err::{ReqlDriverError, ReqlError};
+use crate::types::{GeoJson, GeoType, ReqlType};
+use crate::{Command, Geometry, Result};
 
 use super::point::Point;
 
@@ -16,14 +17,42 @@ pub struct Line {
 }
 
 impl Line {
-    pub fn new(points: &[Point]) -> Self {
-        assert!(points.len() >= 2);
+    /// Build a line from its points,
+    /// given as [Point], `(longitude, latitude)` or `[longitude, latitude]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than two points, see [try_new](Self::try_new)
+    pub fn new<P>(points: impl IntoIterator<Item = P>) -> Self
+    where
+        P: Into<Point>,
+    {
+        match Self::try_new(points) {
+            Ok(line) => line,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Build a line from its points, failing if there are less than two
+    pub fn try_new<P>(points: impl IntoIterator<Item = P>) -> Result<Self>
+    where
+        P: Into<Point>,
+    {
+        let coordinates: Vec<[f64; 2]> = points
+            .into_iter()
+            .map(|point| point.into().coordinates)
+            .collect();
 
-        Self {
+        if coordinates.len() < 2 {
+            let msg = format!("a line needs at least 2 points, got {}", coordinates.len());
+            return Err(ReqlDriverError::Other(msg).into());
+        }
+
+        Ok(Self {
             reql_type: ReqlType::Geometry,
             typ: GeoType::LineString,
-            coordinates: points.iter().map(|point| point.coordinates).collect(),
-        }
+            coordinates,
+        })
     }
 
     /// Convert a Line object into a Polygon object.
@@ -88,6 +117,35 @@ impl Line {
     }
 }
 
+impl TryFrom<GeoJson<Vec<[f64; 2]>>> for Line {
+    type Error = ReqlError;
+
+    fn try_from(geojson: GeoJson<Vec<[f64; 2]>>) -> Result<Self> {
+        if geojson.typ != GeoType::LineString {
+            let msg = format!("expected a LineString, got {:?}", geojson.typ);
+            return Err(ReqlDriverError::Other(msg).into());
+        }
+
+        Self::try_new(geojson.coordinates)
+    }
+}
+
+#[cfg(feature = "geo")]
+impl TryFrom<geo_types::LineString<f64>> for Line {
+    type Error = ReqlError;
+
+    fn try_from(line: geo_types::LineString<f64>) -> Result<Self> {
+        Self::try_new(line)
+    }
+}
+
+#[cfg(feature = "geo")]
+impl From<Line> for geo_types::LineString<f64> {
+    fn from(line: Line) -> Self {
+        line.coordinates.into_iter().map(|[x, y]| (x, y)).collect()
+    }
+}
+
 impl From<Line> for Command {
     fn from(line: Line) -> Self {
         line.coordinates
@@ -25,6 +25,13 @@ impl MapArg for Func {
     }
 }
 
+// a function of `r.row()`
+impl MapArg for Command {
+    fn into_map_opts(self) -> (Option<CmdOpts>, Command) {
+        (None, self)
+    }
+}
+
 impl MapArg for Args<(Command, Func)> {
     fn into_map_opts(self) -> (Option<CmdOpts>, Command) {
         let Func(func) = self.0 .1;
@@ -0,0 +1,66 @@
+use ql2::term::TermType;
+
+use crate::types::TypeOf;
+use crate::{r, var_counter, Command, Func};
+
+type Case = Box<dyn FnOnce(Command) -> Command>;
+
+pub(crate) fn new(value: &Command, cases: TypeMatch) -> Command {
+    let id = var_counter();
+    let var = Command::var(id);
+    let typ = var.type_of();
+    let default = match cases.default {
+        Some(default) => default(var.clone()),
+        None => r.error(r.expr("unexpected type ") + typ.clone()),
+    };
+    let body = if cases.cases.is_empty() {
+        default
+    } else {
+        cases
+            .cases
+            .into_iter()
+            .fold(
+                Command::new(TermType::Branch),
+                |command, (type_of, case)| {
+                    command
+                        .with_arg(typ.eq(r.expr(type_of)))
+                        .with_arg(case(var.clone()))
+                },
+            )
+            .with_arg(default)
+    };
+
+    value.do_(Func::new(vec![id], body))
+}
+
+/// The cases of [match_type](crate::Command::match_type)
+#[derive(Default)]
+pub struct TypeMatch {
+    cases: Vec<(TypeOf, Case)>,
+    default: Option<Case>,
+}
+
+impl TypeMatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the result with `case` when the value has the type `type_of`
+    pub fn case<F>(mut self, type_of: TypeOf, case: F) -> Self
+    where
+        F: FnOnce(Command) -> Command + 'static,
+    {
+        self.cases.push((type_of, Box::new(case)));
+        self
+    }
+
+    /// Build the result with `default` when no case matches,
+    /// otherwise an error is thrown
+    pub fn otherwise<F>(mut self, default: F) -> Self
+    where
+        F: FnOnce(Command) -> Command + 'static,
+    {
+        self.default = Some(Box::new(default));
+        self
+    }
+}
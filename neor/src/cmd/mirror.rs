@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::arguments::{Args, ChangesOption};
+use crate::cmd::run::{self, RunArg};
+use crate::types::{ChangeEvent, ChangesResponse, ChangesState};
+use crate::{err, Command, Connection, Result};
+
+pub(crate) fn new<K, T>(query: &Command, arg: impl RunArg) -> Result<ChangesMirror<K, T>> {
+    let opts = ChangesOption::default()
+        .include_initial(true)
+        .include_states(true)
+        .include_types(true);
+    let query = query.changes(opts);
+    let (conn, opts) = arg.into_run_opts()?;
+
+    Ok(ChangesMirror {
+        stream: run::new(query, Args((conn.clone(), opts))).boxed(),
+        conn,
+        primary_key: "id".into(),
+        docs: HashMap::new(),
+        ready: false,
+    })
+}
+
+/// Documents of a selection kept up to date by a changefeed,
+/// see [mirror](crate::Command::mirror)
+pub struct ChangesMirror<K, T> {
+    stream: BoxStream<'static, Result<Value>>,
+    conn: Connection,
+    primary_key: String,
+    docs: HashMap<K, T>,
+    ready: bool,
+}
+
+impl<K, T> ChangesMirror<K, T>
+where
+    K: Eq + Hash + DeserializeOwned,
+    T: DeserializeOwned,
+{
+    /// Name of the primary key of the table (default: `id`)
+    pub fn primary_key(mut self, primary_key: impl Into<String>) -> Self {
+        self.primary_key = primary_key.into();
+        self
+    }
+
+    /// Wait for the next batch of notifications and apply it
+    ///
+    /// Returns `false` once the changefeed is closed.
+    pub async fn next(&mut self) -> Result<bool> {
+        let batch = match self.stream.try_next().await? {
+            Some(batch) => batch,
+            None => return Ok(false),
+        };
+
+        for change in serde_json::from_value::<Vec<ChangesResponse<Value>>>(batch)? {
+            self.apply(change)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Apply batches until every initial document is received
+    pub async fn wait_ready(&mut self) -> Result<()> {
+        while !self.ready {
+            if !self.next().await? {
+                return Err(err::ReqlDriverError::Other(
+                    "changefeed closed before being ready".to_owned(),
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether every initial document was received
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    pub fn get(&self, key: &K) -> Option<&T> {
+        self.docs.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.docs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.docs.is_empty()
+    }
+
+    /// The mirrored documents, by primary key
+    pub fn docs(&self) -> &HashMap<K, T> {
+        &self.docs
+    }
+
+    pub fn into_docs(self) -> HashMap<K, T> {
+        self.docs
+    }
+
+    /// Stop the changefeed on the server, waiting for its response
+    pub async fn close(self) -> Result<()> {
+        self.conn.stop_stream(self.stream).await
+    }
+
+    fn apply(&mut self, change: ChangesResponse<Value>) -> Result<()> {
+        match change.event() {
+            Some(ChangeEvent::Initial(doc) | ChangeEvent::Add(doc)) => self.insert(doc)?,
+            Some(ChangeEvent::Change { new_val, .. }) => self.insert(new_val)?,
+            Some(ChangeEvent::Remove(doc) | ChangeEvent::Uninitial(doc)) => {
+                self.docs.remove(&self.key(&doc)?);
+            }
+            Some(ChangeEvent::State(state)) => self.ready = state == ChangesState::Ready,
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    fn insert(&mut self, doc: Value) -> Result<()> {
+        let key = self.key(&doc)?;
+
+        self.docs.insert(key, serde_json::from_value(doc)?);
+        Ok(())
+    }
+
+    fn key(&self, doc: &Value) -> Result<K> {
+        let key = doc.get(&self.primary_key).cloned().ok_or_else(|| {
+            err::ReqlDriverError::Other(format!(
+                "document without primary key `{}`",
+                self.primary_key
+            ))
+        })?;
+
+        Ok(serde_json::from_value(key)?)
+    }
+}
@@ -51,3 +51,5 @@ where
         arg.into().add_to_cmd(TermType::Mul).with_parent(&self)
     }
 }
+
+impl_reflected_op!(Mul, mul, i64, f64);
@@ -4,10 +4,14 @@ use ql2::term::TermType;
 
 use crate::Command;
 
+pub(crate) fn new() -> Command {
+    Command::new(TermType::Not)
+}
+
 impl Not for Command {
     type Output = Self;
 
     fn not(self) -> Self::Output {
-        Command::new(TermType::Not).with_arg(self)
+        new().with_parent(&self)
     }
 }
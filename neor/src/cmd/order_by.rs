@@ -16,8 +16,8 @@ pub(crate) fn new(args: impl OrderByArg) -> Command {
         command = args.add_to_cmd(command)
     }
 
-    if let Some(index) = index {
-        command = command.with_arg(index.0)
+    if let Some(Index(index)) = index {
+        command = command.with_term_opt("index", index)
     }
 
     command
@@ -1,11 +1,10 @@
 use ql2::term::TermType;
 
-use crate::{Command, Func};
-
-pub(crate) fn new(other_table: Command, func: Func) -> Command {
-    let Func(func) = func;
+use super::inner_join::JoinPredicate;
+use crate::Command;
 
+pub(crate) fn new(other_table: Command, predicate: impl JoinPredicate) -> Command {
     Command::new(TermType::OuterJoin)
         .with_arg(other_table)
-        .with_arg(func)
+        .with_arg(predicate.into_join_predicate())
 }
@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::arguments::{Args, BetweenOption, Status};
+use crate::cmd::run::RunArg;
+use crate::{err, r, Command, Result};
+
+pub(crate) fn new(table: &Command, index: String, page_size: usize) -> Paginator {
+    Paginator {
+        table: table.clone(),
+        key_fields: vec![Cow::Owned(index.clone())],
+        index: Cow::Owned(index),
+        page_size,
+        descending: false,
+    }
+}
+
+/// Pages of a table in the order of a secondary index,
+/// see [paginate](crate::Command::paginate)
+#[derive(Debug, Clone)]
+pub struct Paginator {
+    table: Command,
+    index: Cow<'static, str>,
+    key_fields: Vec<Cow<'static, str>>,
+    page_size: usize,
+    descending: bool,
+}
+
+/// A page of documents and the cursor of the next page
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Index key of the last document, `None` on the last page
+    pub next_cursor: Option<Value>,
+}
+
+impl Paginator {
+    /// Browse the index from the greatest key to the smallest
+    pub fn desc(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+
+    /// Fields making the key of a compound index, in order
+    ///
+    /// By default, the key of a document is the field named like the index.
+    pub fn key_fields<T, S>(mut self, fields: T) -> Self
+    where
+        T: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.key_fields = fields
+            .into_iter()
+            .map(|field| Cow::Owned(field.into()))
+            .collect();
+        self
+    }
+
+    /// The `between`, `order_by` and `limit` chain reading the page after `cursor`
+    ///
+    /// One more document than the page size is read, to know
+    /// whether a next page exists.
+    pub fn query(&self, cursor: Option<&Value>) -> Command {
+        let after = || match cursor {
+            Some(cursor) => r.expr(cursor),
+            None if self.descending => r::max_val(),
+            None => r::min_val(),
+        };
+        let options = BetweenOption::default().index(&self.index);
+
+        let (selection, order) = if self.descending {
+            (
+                self.table.between(Args((
+                    r::min_val(),
+                    after(),
+                    options.right_bound(Status::Open),
+                ))),
+                r.desc(self.index.as_ref()),
+            )
+        } else {
+            (
+                self.table.between(Args((
+                    after(),
+                    r::max_val(),
+                    options.left_bound(Status::Open),
+                ))),
+                r.asc(self.index.as_ref()),
+            )
+        };
+
+        selection.order_by(r.index(order)).limit(self.page_size + 1)
+    }
+
+    /// Read the page after `cursor`, or the first page when `None`
+    pub async fn page<T>(&self, args: impl RunArg, cursor: Option<&Value>) -> Result<Page<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let docs = self.query(cursor).run(args).await?.unwrap_or_default();
+        // the keys of the cursor keep their TIME pseudo-types
+        let mut docs: Vec<Value> = serde_json::from_value(docs)?;
+
+        let next_cursor = if docs.len() > self.page_size {
+            docs.truncate(self.page_size);
+            docs.last().map(|doc| self.key(doc)).transpose()?
+        } else {
+            None
+        };
+
+        Ok(Page {
+            items: serde_json::from_value(Value::Array(docs))?,
+            next_cursor,
+        })
+    }
+
+    fn key(&self, doc: &Value) -> Result<Value> {
+        let mut key = Vec::with_capacity(self.key_fields.len());
+
+        for field in &self.key_fields {
+            let value = doc.get(field.as_ref()).cloned().ok_or_else(|| {
+                err::ReqlDriverError::Other(format!("document without key field `{}`", field))
+            })?;
+            key.push(value);
+        }
+
+        Ok(match key.len() {
+            1 => key.remove(0),
+            _ => Value::Array(key),
+        })
+    }
+}
@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::arguments::Args;
+use crate::err::ReqlDriverError;
+use crate::{r, var_counter, Command, Func, Result};
+
+// An operation of a JSON Patch document, see RFC 6902
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum Operation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+// The function replacing a document with the patched one,
+// or the error of an invalid patch, sent with the query
+pub(crate) fn new(json_patch: impl Serialize) -> Command {
+    match func(json_patch) {
+        Ok(func) => func.0,
+        Err(error) => (Err(error) as Result<Value>).into(),
+    }
+}
+
+fn func(json_patch: impl Serialize) -> Result<Func> {
+    let operations: Vec<Operation> = serde_json::from_value(serde_json::to_value(json_patch)?)?;
+    let root = var_counter();
+    let mut doc = Command::var(root);
+
+    for (index, operation) in operations.into_iter().enumerate() {
+        // each operation reads the document left by the previous one once,
+        // so that the query does not repeat the whole patch at each step
+        doc = match index {
+            0 => operation.apply(doc)?,
+            _ => {
+                let id = var_counter();
+
+                doc.do_(Func::new(vec![id], operation.apply(Command::var(id))?))
+            }
+        };
+    }
+
+    Ok(Func::new(vec![root], doc))
+}
+
+impl Operation {
+    fn apply(self, doc: Command) -> Result<Command> {
+        match self {
+            Self::Add { path, value } => add(doc, &pointer(&path)?, value.into()),
+            Self::Remove { path } => remove(doc, &pointer(&path)?),
+            Self::Replace { path, value } => Ok(set(doc, &pointer(&path)?, value.into())),
+            Self::Move { from, path } => {
+                let from = pointer(&from)?;
+                let value = get(&doc, &from);
+
+                add(remove(doc, &from)?, &pointer(&path)?, value)
+            }
+            Self::Copy { from, path } => {
+                let value = get(&doc, &pointer(&from)?);
+
+                add(doc, &pointer(&path)?, value)
+            }
+            Self::Test { path, value } => {
+                let message = format!("JSON Patch test failed at `{}`", path);
+                let current = get(&doc, &pointer(&path)?);
+
+                Ok(r.branch(current.eq(value), Args((doc, r.error(message)))))
+            }
+        }
+    }
+}
+
+// Insert `value`, shifting the elements of an array
+fn add(doc: Command, path: &[String], value: Command) -> Result<Command> {
+    if path.is_empty() {
+        return Ok(value);
+    }
+
+    Ok(at(
+        doc,
+        path,
+        &|parent, token| match (token, index(token)) {
+            ("-", _) => by_type(
+                parent,
+                |array| array.append(value.clone()),
+                |object| set_field(object, token, value.clone()),
+            ),
+            (_, Some(index)) => by_type(
+                parent,
+                |array| array.insert_at(index, value.clone()),
+                |object| set_field(object, token, value.clone()),
+            ),
+            _ => set_field(parent, token, value.clone()),
+        },
+    ))
+}
+
+fn remove(doc: Command, path: &[String]) -> Result<Command> {
+    if path.is_empty() {
+        return Err(invalid("cannot remove the whole document"));
+    }
+
+    Ok(at(doc, path, &|parent, token| match index(token) {
+        Some(index) => by_type(
+            parent,
+            |array| array.delete_at(index),
+            |object| existing(object, token, |object| object.without(token)),
+        ),
+        None => bind(parent, |object| {
+            existing(object, token, |object| object.without(token))
+        }),
+    }))
+}
+
+// Replace the value at `path`, which must exist
+fn set(doc: Command, path: &[String], value: Command) -> Command {
+    let replace = |object, token: &str| {
+        existing(object, token, |object| {
+            set_field(object, token, value.clone())
+        })
+    };
+
+    match path.is_empty() {
+        true => value.clone(),
+        false => at(doc, path, &|parent, token| match index(token) {
+            Some(index) => by_type(
+                parent,
+                |array| array.change_at(index, value.clone()),
+                |object| replace(object, token),
+            ),
+            None => bind(parent, |object| replace(object, token)),
+        }),
+    }
+}
+
+// Change the parent of the last token of `path` with `leaf`,
+// and the ancestors of the parent with the changed children
+fn at(doc: Command, path: &[String], leaf: &dyn Fn(Command, &str) -> Command) -> Command {
+    match path {
+        [] => doc,
+        [token] => leaf(doc, token),
+        [token, rest @ ..] => bind(doc, |doc| {
+            let child = at(child(&doc, token), rest, leaf);
+
+            set_child(doc, token, child)
+        }),
+    }
+}
+
+fn get(doc: &Command, path: &[String]) -> Command {
+    path.iter()
+        .fold(doc.clone(), |doc, token| child(&doc, token))
+}
+
+fn child(doc: &Command, token: &str) -> Command {
+    match index(token) {
+        Some(index) => by_type(
+            doc.clone(),
+            |array| array.nth(index),
+            |object| object.g(token),
+        ),
+        None => doc.g(token),
+    }
+}
+
+fn set_child(doc: Command, token: &str, value: Command) -> Command {
+    match index(token) {
+        Some(index) => by_type(
+            doc,
+            |array| array.change_at(index, value.clone()),
+            |object| set_field(object, token, value.clone()),
+        ),
+        None => set_field(doc, token, value),
+    }
+}
+
+// A literal, so that an object value replaces the field
+// instead of being merged into it, in the `MAKE_OBJ` object
+// where `merge` accepts it
+fn set_field(doc: Command, token: &str, value: Command) -> Command {
+    doc.merge(r.hash_map([(token, r.literal(value))]))
+}
+
+// The change of `doc` by `then`, or an error when `doc` has no `token` field
+fn existing(doc: Command, token: &str, then: impl FnOnce(Command) -> Command) -> Command {
+    let message = format!("JSON Patch target `{}` does not exist", token);
+
+    r.branch(doc.has_fields(token), Args((then(doc), r.error(message))))
+}
+
+// A token made of digits addresses the element of an array,
+// or the field of an object, only known when the query runs
+fn by_type(
+    doc: Command,
+    array: impl FnOnce(Command) -> Command,
+    object: impl FnOnce(Command) -> Command,
+) -> Command {
+    bind(doc, |doc| {
+        r.branch(
+            doc.type_of().eq("ARRAY"),
+            Args((array(doc.clone()), object(doc))),
+        )
+    })
+}
+
+// `doc` read once in `body`, which may repeat it
+fn bind(doc: Command, body: impl FnOnce(Command) -> Command) -> Command {
+    let id = var_counter();
+
+    doc.do_(Func::new(vec![id], body(Command::var(id))))
+}
+
+fn index(token: &str) -> Option<usize> {
+    match token.bytes().all(|byte| byte.is_ascii_digit()) {
+        true => token.parse().ok(),
+        false => None,
+    }
+}
+
+// The tokens of a JSON Pointer, see RFC 6901
+fn pointer(path: &str) -> Result<Vec<String>> {
+    if path.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    match path.strip_prefix('/') {
+        Some(path) => Ok(path
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect()),
+        None => Err(invalid(&format!("`{}` is not a JSON Pointer", path))),
+    }
+}
+
+fn invalid(message: &str) -> crate::err::ReqlError {
+    ReqlDriverError::Other(format!("invalid JSON Patch; {}", message)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::pointer;
+    use crate::r;
+
+    #[test]
+    fn json_pointer() {
+        let tokens = pointer("/tags/0/a~1b~0c").unwrap();
+
+        assert_eq!(tokens, ["tags", "0", "a/b~c"]);
+        assert!(pointer("").unwrap().is_empty());
+        assert!(pointer("tags").is_err());
+    }
+
+    #[test]
+    fn invalid_patch() {
+        let patch = |ops| r.table("posts").get(1).patch(ops).to_wire_json();
+
+        assert!(patch(json!([{"op": "add", "path": "/tags/-", "value": 1}])).is_ok());
+        assert!(patch(json!([{"op": "remove", "path": ""}])).is_err());
+        assert!(patch(json!([{"op": "rename", "path": "/title"}])).is_err());
+        assert!(patch(json!({"op": "remove", "path": "/title"})).is_err());
+    }
+
+    #[test]
+    fn numeric_tokens() {
+        let patch = |path| {
+            r.table("posts")
+                .get(1)
+                .patch(json!([{"op": "remove", "path": path}]))
+                .to_wire_json()
+                .unwrap()
+                .to_string()
+        };
+
+        // the type of the parent is only known when the query runs
+        assert!(patch("/tags/0").contains(r#""ARRAY""#));
+        assert!(!patch("/tags/first").contains(r#""ARRAY""#));
+    }
+}
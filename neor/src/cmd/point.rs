@@ -27,6 +27,49 @@ impl Point {
     }
 }
 
+impl From<&Point> for Point {
+    fn from(point: &Point) -> Self {
+        point.clone()
+    }
+}
+
+/// `(longitude, latitude)`
+impl From<(f64, f64)> for Point {
+    fn from((longitude, latitude): (f64, f64)) -> Self {
+        Self::new(longitude, latitude)
+    }
+}
+
+/// `[longitude, latitude]`, as in GeoJSON
+impl From<[f64; 2]> for Point {
+    fn from([longitude, latitude]: [f64; 2]) -> Self {
+        Self::new(longitude, latitude)
+    }
+}
+
+#[cfg(feature = "geo")]
+impl From<geo_types::Coord<f64>> for Point {
+    fn from(coord: geo_types::Coord<f64>) -> Self {
+        Self::new(coord.x, coord.y)
+    }
+}
+
+#[cfg(feature = "geo")]
+impl From<geo_types::Point<f64>> for Point {
+    fn from(point: geo_types::Point<f64>) -> Self {
+        point.0.into()
+    }
+}
+
+#[cfg(feature = "geo")]
+impl From<Point> for geo_types::Point<f64> {
+    fn from(point: Point) -> Self {
+        let [longitude, latitude] = point.coordinates;
+
+        Self::new(longitude, latitude)
+    }
+}
+
 impl From<Point> for Command {
     fn from(point: Point) -> Self {
         point
@@ -1,8 +1,9 @@
 use ql2::term::TermType;
 use serde::{Deserialize, Serialize};
 
-use crate::types::{GeoType, ReqlType};
-use crate::{Command, Geometry};
+use crate::err::{ReqlDriverError, ReqlError};
+use crate::types::{GeoJson, GeoType, ReqlType};
+use crate::{Command, Geometry, Result};
 
 use super::point::Point;
 use super::polygon_sub;
@@ -17,14 +18,40 @@ pub struct Polygon {
 }
 
 impl Polygon {
-    pub fn new(points: &[Point]) -> Self {
-        assert!(points.len() >= 3);
+    /// Build a polygon from its vertices,
+    /// given as [Point], `(longitude, latitude)` or `[longitude, latitude]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than three vertices, see [try_new](Self::try_new)
+    pub fn new<P>(points: impl IntoIterator<Item = P>) -> Self
+    where
+        P: Into<Point>,
+    {
+        match Self::try_new(points) {
+            Ok(polygon) => polygon,
+            Err(err) => panic!("{}", err),
+        }
+    }
 
-        Self {
-            reql_type: ReqlType::Geometry,
-            typ: GeoType::Polygon,
-            coordinates: vec![points.iter().map(|point| point.coordinates).collect()],
+    /// Build a polygon from its vertices, failing if there are less than three.
+    ///
+    /// The ring is closed by repeating the first vertex at the end,
+    /// unless the last vertex already is the first one.
+    pub fn try_new<P>(points: impl IntoIterator<Item = P>) -> Result<Self>
+    where
+        P: Into<Point>,
+    {
+        let mut ring: Vec<[f64; 2]> = points
+            .into_iter()
+            .map(|point| point.into().coordinates)
+            .collect();
+
+        if ring.len() > 1 && ring.first() != ring.last() {
+            ring.push(ring[0]);
         }
+
+        Ok(Self::new_from_vec(vec![check_ring(ring)?]))
     }
 
     pub fn new_from_vec(coordinates: Vec<Vec<[f64; 2]>>) -> Self {
@@ -111,15 +138,142 @@ impl Polygon {
     }
 }
 
+// A closed ring of at least three distinct vertices
+fn check_ring(ring: Vec<[f64; 2]>) -> Result<Vec<[f64; 2]>> {
+    if ring.len() > 1 && ring.first() != ring.last() {
+        let msg = "the ring of a polygon must end with its first vertex";
+        return Err(ReqlDriverError::Other(msg.to_owned()).into());
+    }
+
+    if ring.len() < 4 {
+        let vertices = ring.len().saturating_sub(1);
+        let msg = format!("a polygon needs at least 3 vertices, got {}", vertices);
+        return Err(ReqlDriverError::Other(msg).into());
+    }
+
+    Ok(ring)
+}
+
+/// A GeoJSON polygon: a closed exterior ring followed by the closed rings of its holes
+impl TryFrom<GeoJson<Vec<Vec<[f64; 2]>>>> for Polygon {
+    type Error = ReqlError;
+
+    fn try_from(geojson: GeoJson<Vec<Vec<[f64; 2]>>>) -> Result<Self> {
+        if geojson.typ != GeoType::Polygon {
+            let msg = format!("expected a Polygon, got {:?}", geojson.typ);
+            return Err(ReqlDriverError::Other(msg).into());
+        }
+
+        if geojson.coordinates.is_empty() {
+            let msg = "a polygon needs an exterior ring";
+            return Err(ReqlDriverError::Other(msg.to_owned()).into());
+        }
+
+        let rings = geojson.coordinates.into_iter().map(check_ring);
+
+        Ok(Self::new_from_vec(rings.collect::<Result<_>>()?))
+    }
+}
+
+#[cfg(feature = "geo")]
+impl TryFrom<geo_types::Polygon<f64>> for Polygon {
+    type Error = ReqlError;
+
+    fn try_from(polygon: geo_types::Polygon<f64>) -> Result<Self> {
+        let (exterior, interiors) = polygon.into_inner();
+        let ring = |line: geo_types::LineString<f64>| {
+            check_ring(line.into_iter().map(|coord| [coord.x, coord.y]).collect())
+        };
+        let rings = std::iter::once(exterior).chain(interiors).map(ring);
+
+        Ok(Self::new_from_vec(rings.collect::<Result<_>>()?))
+    }
+}
+
+#[cfg(feature = "geo")]
+impl From<Polygon> for geo_types::Polygon<f64> {
+    fn from(polygon: Polygon) -> Self {
+        let mut rings = polygon
+            .coordinates
+            .into_iter()
+            .map(|ring| ring.into_iter().map(|[x, y]| (x, y)).collect());
+        let exterior = rings
+            .next()
+            .unwrap_or_else(|| Vec::<(f64, f64)>::new().into());
+
+        Self::new(exterior, rings.collect())
+    }
+}
+
+// The holes are punched out of the exterior ring with `polygon_sub`
 impl From<Polygon> for Command {
     fn from(polygon: Polygon) -> Self {
-        polygon.coordinates.iter().flatten().fold(
-            Command::new(TermType::Polygon),
-            |command, coord| {
-                let point: Command = Point::new(coord[0], coord[1]).into();
-
-                command.with_arg(point)
-            },
-        )
+        let mut rings = polygon.coordinates.iter().map(|ring| {
+            ring.iter()
+                .fold(Command::new(TermType::Polygon), |command, coord| {
+                    let point: Command = Point::new(coord[0], coord[1]).into();
+
+                    command.with_arg(point)
+                })
+        });
+        let exterior = rings
+            .next()
+            .unwrap_or_else(|| Command::new(TermType::Polygon));
+
+        rings.fold(exterior, |polygon, hole| {
+            Command::new(TermType::PolygonSub)
+                .with_arg(polygon)
+                .with_arg(hole)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polygon;
+    use crate::proto::Query;
+    use crate::types::{GeoJson, GeoType, Line};
+    use crate::Command;
+
+    #[test]
+    fn polygon_ring() {
+        let square = [(0., 0.), (0., 1.), (1., 1.), (1., 0.)];
+        let polygon = Polygon::new(square);
+
+        assert_eq!(polygon.coordinates[0].len(), 5);
+        assert_eq!(polygon.coordinates[0][4], [0., 0.]);
+        assert_eq!(
+            Polygon::new(polygon.coordinates[0].iter().copied()),
+            polygon
+        );
+        assert!(Polygon::try_new([(0., 0.), (0., 1.), (0., 0.)]).is_err());
+        assert!(Line::try_new([[0., 0.]]).is_err());
+
+        let open = GeoJson::new(GeoType::Polygon, vec![square.map(|(x, y)| [x, y]).to_vec()]);
+
+        assert!(Polygon::try_from(open).is_err());
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let outer = [[0., 0.], [0., 4.], [4., 4.], [4., 0.], [0., 0.]];
+        let hole = [[1., 1.], [1., 2.], [2., 2.], [2., 1.], [1., 1.]];
+        let geojson = GeoJson::new(GeoType::Polygon, vec![outer.to_vec(), hole.to_vec()]);
+        let query: Command = Polygon::try_from(geojson).unwrap().into();
+        let query = serde_json::to_string(&Query(&query)).unwrap();
+
+        // polygon_sub(polygon(outer), polygon(hole))
+        assert!(query.starts_with("[171,[[161,[[159,[0.0,0.0]]"));
+        assert!(query.contains("]]]],[161,[[159,[1.0,1.0]]"));
+    }
+
+    #[cfg(feature = "geo")]
+    #[test]
+    fn geo_types_polygon() {
+        let exterior = geo_types::LineString::from(vec![(0., 0.), (0., 1.), (1., 1.), (0., 0.)]);
+        let polygon = geo_types::Polygon::new(exterior, Vec::new());
+        let converted = Polygon::try_from(polygon.clone()).unwrap();
+
+        assert_eq!(geo_types::Polygon::from(converted), polygon);
     }
 }
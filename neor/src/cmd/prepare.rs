@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use async_stream::try_stream;
+use dashmap::DashMap;
+use futures::stream::{Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::value::RawValue;
+use serde_json::Value;
+
+use crate::arguments::Args;
+use crate::cmd::run::{self, RunArg};
+use crate::proto::{Datum, Segment};
+use crate::{err, Command, Result};
+
+pub(crate) fn param(name: String) -> Command {
+    Command::from(Datum::Param(name))
+}
+
+pub(crate) fn new(query: &Command) -> Result<PreparedQuery> {
+    let query = query.clone().with_query_vars();
+    let templates = DashMap::new();
+    // the query without table prefix, which also checks that it serializes
+    templates.insert(String::new(), Arc::new(query.template()?));
+
+    Ok(PreparedQuery {
+        query,
+        templates: Arc::new(templates),
+    })
+}
+
+/// A query serialized once, whose `r.param` placeholders are
+/// bound on each run, see [prepare](crate::Command::prepare)
+#[derive(Debug, Clone)]
+pub struct PreparedQuery {
+    query: Command,
+    // serialized query by table prefix, shared by the clones
+    templates: Arc<DashMap<String, Arc<Vec<Segment>>>>,
+}
+
+impl PreparedQuery {
+    /// Run the query with the values of its parameters, given as
+    /// an object or a struct whose fields are named like the parameters
+    ///
+    /// Like [run](crate::Command::run), the query runs again under the
+    /// retry policy of the session.
+    pub async fn run<P>(&self, args: impl RunArg, params: P) -> Result<Option<Value>>
+    where
+        P: Serialize,
+    {
+        self.run_typed(args, params).await
+    }
+
+    /// Run the query with the values of its parameters, deserializing
+    /// the result straight into a type, like [run_typed](crate::Command::run_typed)
+    pub async fn run_typed<P, T>(&self, args: impl RunArg, params: P) -> Result<Option<T>>
+    where
+        P: Serialize,
+        T: Unpin + DeserializeOwned,
+    {
+        let params = &params;
+
+        run::retried(&self.query, args, |args| async move {
+            Box::pin(self.build_query(args, params)).try_next().await
+        })
+        .await
+    }
+
+    /// Stream the responses of the query, like [build_query](crate::Command::build_query)
+    pub fn build_query<P, T>(&self, args: impl RunArg, params: P) -> impl Stream<Item = Result<T>>
+    where
+        P: Serialize,
+        T: Unpin + DeserializeOwned,
+    {
+        let bound = args.into_run_opts().and_then(|(conn, opts)| {
+            let prefix = match &conn.session.inner.table_prefix {
+                Some(prefix) if opts.table_prefix.unwrap_or(true) => prefix.as_ref(),
+                _ => "",
+            };
+            Ok((self.bind(prefix, params)?, Args((conn, opts))))
+        });
+
+        try_stream! {
+            let (query, args) = bound?;
+            let mut responses = Box::pin(run::new(query, args));
+
+            while let Some(response) = responses.try_next().await? {
+                yield response;
+            }
+        }
+    }
+
+    pub(crate) fn bind<P>(&self, prefix: &str, params: P) -> Result<Command>
+    where
+        P: Serialize,
+    {
+        let params = match serde_json::to_value(params)? {
+            Value::Object(params) => params,
+            Value::Null => Default::default(),
+            _ => {
+                let msg = "the parameters of a prepared query must be an object";
+                return Err(err::ReqlDriverError::Other(msg.to_owned()).into());
+            }
+        };
+        let template = self.template(prefix)?;
+        let mut json = String::new();
+
+        for segment in template.iter() {
+            match segment {
+                Segment::Json(part) => json.push_str(part),
+                Segment::Param(name) => {
+                    let value = params.get(name).cloned().ok_or_else(|| {
+                        err::ReqlDriverError::Other(format!("parameter `{}` is not bound", name))
+                    })?;
+                    json.push_str(&serde_json::to_string(&Datum::from(value))?);
+                }
+            }
+        }
+
+        Ok(self.query.prepared(RawValue::from_string(json)?))
+    }
+
+    fn template(&self, prefix: &str) -> Result<Arc<Vec<Segment>>> {
+        if let Some(template) = self.templates.get(prefix) {
+            return Ok(template.clone());
+        }
+
+        let template = self.query.clone().with_table_prefix(prefix).template()?;
+        let template = Arc::new(template);
+        self.templates.insert(prefix.to_owned(), template.clone());
+        Ok(template)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use crate::arguments::{Args, InsertOption};
+    use crate::{r, Command, Func};
+
+    #[test]
+    fn prepared_query() {
+        let query = |id, tags| {
+            r.table("users")
+                .get(id)
+                .merge(r.hash_map(HashMap::from([("tags", tags)])))
+        };
+        let prepared = query(r.param("id"), r.param("tags")).prepare().unwrap();
+        let bound = prepared
+            .bind("staging_", json!({"id": "a", "tags": ["b"]}))
+            .unwrap();
+        let expected = query(r.expr("a"), r.expr(["b"])).with_table_prefix("staging_");
+
+        assert_eq!(
+            bound.to_wire_json().unwrap(),
+            expected.to_wire_json().unwrap()
+        );
+        assert_eq!(bound.term_name(), "merge");
+        assert!(prepared.bind("", json!({"id": "a"})).is_err());
+        assert!(prepared.bind("", json!(["a"])).is_err());
+        assert!(query(r.param("id"), r.param("tags"))
+            .to_wire_json()
+            .is_err());
+    }
+
+    #[test]
+    fn param_in_option_term() {
+        let query = |status: Command| {
+            let resolver = Func::new(vec![1, 2, 3], Command::var(3).merge(status));
+
+            r.table("users")
+                .insert(Args((json!({"id": 1}), InsertOption::default(), resolver)))
+        };
+        let prepared = query(r.param("status")).prepare().unwrap();
+        let bound = prepared.bind("", json!({"status": "merged"})).unwrap();
+
+        assert_eq!(
+            bound.to_wire_json().unwrap(),
+            query(r.expr("merged")).to_wire_json().unwrap()
+        );
+    }
+}
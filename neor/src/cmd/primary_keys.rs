@@ -0,0 +1,41 @@
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::arguments::Args;
+use crate::cmd::run::{self, RunArg};
+use crate::{var_counter, Command, Func, Result};
+
+/// Rows per batch when the run options do not set `max_batch_rows`
+pub(crate) const BATCH_ROWS: usize = 1000;
+
+pub(crate) fn new<K>(table: &Command, arg: impl RunArg) -> impl Stream<Item = Result<K>>
+where
+    K: Unpin + DeserializeOwned,
+{
+    let primary_key = var_counter();
+    let doc = var_counter();
+
+    // table.info()("primary_key").do(pk => table.map(doc => doc(pk)))
+    let keys = table.map(Func::new(
+        vec![doc],
+        Command::var(doc).g(Command::var(primary_key)),
+    ));
+    let query = table
+        .info()
+        .g("primary_key")
+        .do_(Func::new(vec![primary_key], keys));
+
+    try_stream! {
+        let (conn, mut opts) = arg.into_run_opts()?;
+        opts.max_batch_rows.get_or_insert(BATCH_ROWS);
+
+        let mut batches = Box::pin(run::new::<_, Vec<K>>(query, Args((conn, opts))));
+
+        while let Some(batch) = batches.try_next().await? {
+            for key in batch {
+                yield key;
+            }
+        }
+    }
+}
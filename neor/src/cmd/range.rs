@@ -1,3 +1,5 @@
+use std::ops::{Range, RangeFull};
+
 use ql2::term::TermType;
 
 use crate::{arguments::Args, Command, CommandArg};
@@ -27,6 +29,12 @@ impl RangeArg for () {
     }
 }
 
+impl RangeArg for RangeFull {
+    fn into_range_opts(self) -> (Option<Command>, Option<Command>) {
+        (None, None)
+    }
+}
+
 impl RangeArg for i8 {
     fn into_range_opts(self) -> (Option<Command>, Option<Command>) {
         (None, Some(Command::from_json(self)))
@@ -117,3 +125,15 @@ where
         )
     }
 }
+
+impl<T> RangeArg for Range<T>
+where
+    T: Into<CommandArg>,
+{
+    fn into_range_opts(self) -> (Option<Command>, Option<Command>) {
+        (
+            Some(self.start.into().to_cmd()),
+            Some(self.end.into().to_cmd()),
+        )
+    }
+}
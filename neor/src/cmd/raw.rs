@@ -0,0 +1,40 @@
+use ql2::term::TermType;
+use serde_json::{json, Value};
+
+use crate::proto::{Datum, Query};
+use crate::{Command, CommandArg, Result};
+
+pub(crate) fn new(term: Value) -> Command {
+    Command::from(Datum::Raw(term))
+}
+
+pub(crate) fn term<T>(term_type: i32, args: T, opts: Option<Value>) -> Command
+where
+    T: IntoIterator,
+    T::Item: Into<CommandArg>,
+{
+    let mut change_feed = term_type == TermType::Changes as i32;
+    let args: Result<Vec<Value>> = args
+        .into_iter()
+        .map(|arg| {
+            let arg = arg.into().to_cmd();
+            change_feed = change_feed || arg.change_feed();
+
+            Ok(serde_json::to_value(Query(&arg))?)
+        })
+        .collect();
+
+    let command = match args {
+        Ok(args) => match opts {
+            Some(opts) => new(json!([term_type, args, opts])),
+            None => new(json!([term_type, args])),
+        },
+        Err(error) => Command::from(Err(error) as Result<Datum>),
+    };
+
+    if change_feed {
+        command.mark_change_feed()
+    } else {
+        command
+    }
+}
@@ -0,0 +1,136 @@
+use std::borrow::Cow;
+
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::arguments::{Args, BetweenOption};
+use crate::cmd::run::{self, RunArg};
+use crate::{r, var_counter, Command, Func, Result, Session};
+
+/// Documents sampled per partition to choose the bounds of the partitions
+const SAMPLES_PER_PARTITION: usize = 100;
+
+pub(crate) fn new(table: &Command, partitions: usize, index: String) -> PartitionedRead {
+    PartitionedRead {
+        table: table.clone(),
+        key_fields: vec![Cow::Owned(index.clone())],
+        index: Cow::Owned(index),
+        partitions: partitions.max(1),
+        sample_size: partitions.max(1) * SAMPLES_PER_PARTITION,
+    }
+}
+
+/// Ranges of a secondary index read in parallel,
+/// see [read_partitioned](crate::Command::read_partitioned)
+#[derive(Debug, Clone)]
+pub struct PartitionedRead {
+    table: Command,
+    index: Cow<'static, str>,
+    key_fields: Vec<Cow<'static, str>>,
+    partitions: usize,
+    sample_size: usize,
+}
+
+impl PartitionedRead {
+    /// Fields making the key of a compound index, in order
+    ///
+    /// By default, the key of a document is the field named like the index.
+    pub fn key_fields<T, S>(mut self, fields: T) -> Self
+    where
+        T: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.key_fields = fields
+            .into_iter()
+            .map(|field| Cow::Owned(field.into()))
+            .collect();
+        self
+    }
+
+    /// Documents sampled to choose the bounds of the partitions,
+    /// 100 per partition by default
+    pub fn sample_size(mut self, sample_size: usize) -> Self {
+        self.sample_size = sample_size;
+        self
+    }
+
+    /// Index keys splitting the table in partitions of about the same size
+    ///
+    /// There are fewer bounds than partitions minus one when
+    /// the sampled documents have fewer distinct keys.
+    pub async fn bounds(&self, args: impl RunArg) -> Result<Vec<Value>> {
+        let doc = var_counter();
+        let key = match self.key_fields.as_slice() {
+            [field] => Command::var(doc).g(field.as_ref()),
+            fields => r.array(
+                fields
+                    .iter()
+                    .map(|field| Command::var(doc).g(field.as_ref())),
+            ),
+        };
+        let fields: Vec<_> = self.key_fields.iter().map(|field| field.as_ref()).collect();
+
+        // `distinct` sorts the keys like the index
+        let keys = self
+            .table
+            .sample(self.sample_size)
+            .has_fields(fields)
+            .map(Func::new(vec![doc], key))
+            .distinct(())
+            .run(args)
+            .await?
+            .unwrap_or_default();
+        // the keys keep their TIME pseudo-types
+        let keys: Vec<Value> = serde_json::from_value(keys)?;
+
+        let mut bounds: Vec<Value> = (1..self.partitions)
+            .filter_map(|i| keys.get(i * keys.len() / self.partitions))
+            .cloned()
+            .collect();
+        bounds.dedup();
+        Ok(bounds)
+    }
+
+    /// The `between` queries reading the partitions delimited by `bounds`
+    pub fn queries(&self, bounds: &[Value]) -> Vec<Command> {
+        let lower = std::iter::once(r::min_val()).chain(bounds.iter().map(|bound| r.expr(bound)));
+        let upper = bounds
+            .iter()
+            .map(|bound| r.expr(bound))
+            .chain(std::iter::once(r::max_val()));
+        let options = BetweenOption::default().index(&self.index);
+
+        lower
+            .zip(upper)
+            .map(|(lower, upper)| self.table.between(Args((lower, upper, options.clone()))))
+            .collect()
+    }
+
+    /// One stream of documents per partition, each on its own connection
+    /// of the session, to be consumed on separate tasks
+    pub async fn streams<T>(&self, session: &Session) -> Result<Vec<impl Stream<Item = Result<T>>>>
+    where
+        T: Unpin + DeserializeOwned,
+    {
+        let bounds = self.bounds(session).await?;
+
+        self.queries(&bounds)
+            .into_iter()
+            .map(|query| {
+                let conn = session.connection()?;
+
+                Ok(try_stream! {
+                    let mut batches = Box::pin(run::new::<_, Vec<T>>(query, conn));
+
+                    while let Some(batch) = batches.try_next().await? {
+                        for doc in batch {
+                            yield doc;
+                        }
+                    }
+                })
+            })
+            .collect()
+    }
+}
@@ -42,3 +42,5 @@ where
         arg.into().add_to_cmd(TermType::Mod).with_parent(&self)
     }
 }
+
+impl_reflected_op!(Rem, rem, i64, f64);
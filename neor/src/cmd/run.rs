@@ -1,8 +1,12 @@
 use std::borrow::Cow;
+use std::future::{self, Future};
 use std::sync::atomic::Ordering;
-use std::{mem, str};
+use std::time::Duration;
+use std::{io, str};
 
 use async_stream::try_stream;
+use futures::channel::mpsc::UnboundedReceiver;
+use futures::channel::oneshot;
 use futures::io::{AsyncReadExt, AsyncWriteExt};
 use futures::stream::{Stream, StreamExt};
 use futures::{AsyncRead, AsyncWrite};
@@ -10,20 +14,26 @@ use ql2::query::QueryType;
 use ql2::response::{ErrorType, ResponseType};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use serde_json::value::RawValue;
 use serde_json::Value;
+use tokio::runtime::Handle;
+use tokio::time::{self, Instant};
 use tracing::trace;
 
 use crate::arguments::{Args, RunOption};
 use crate::constants::{DATA_SIZE, HEADER_SIZE, TOKEN_SIZE};
+use crate::observer::Observation;
 use crate::proto::{Payload, Query};
-use crate::{err, Command, Connection, Result, Session};
+use crate::{err, Command, Connection, Result, Session, StaticString};
 
 #[derive(Deserialize, Debug)]
 #[allow(dead_code)]
 pub(crate) struct Response {
     t: i32,
     e: Option<i32>,
-    pub(crate) r: Value,
+    // deserialized straight into the type asked by the caller,
+    // without building a `Value` first
+    pub(crate) r: Box<RawValue>,
     b: Option<Value>,
     p: Option<Value>,
     n: Option<Value>,
@@ -34,7 +44,7 @@ impl Response {
         Self {
             t: ResponseType::SuccessAtom as i32,
             e: None,
-            r: Value::Array(Vec::new()),
+            r: RawValue::from_string("[]".to_owned()).unwrap(),
             b: None,
             p: None,
             n: None,
@@ -45,6 +55,35 @@ impl Response {
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Db(pub Cow<'static, str>);
 
+impl<T: StaticString> From<T> for Db {
+    fn from(name: T) -> Self {
+        Self(name.static_string())
+    }
+}
+
+impl TryFrom<&Command> for Db {
+    type Error = err::ReqlError;
+
+    fn try_from(db: &Command) -> Result<Self> {
+        match db.db_name() {
+            Some(name) => Ok(Self(Cow::Owned(name.to_owned()))),
+            None => Err(err::ReqlDriverError::Other(format!(
+                "expected `r.db(name)` as database, got `{}`",
+                db.to_reql_string()?
+            ))
+            .into()),
+        }
+    }
+}
+
+impl TryFrom<Command> for Db {
+    type Error = err::ReqlError;
+
+    fn try_from(db: Command) -> Result<Self> {
+        Self::try_from(&db)
+    }
+}
+
 pub trait RunArg {
     fn into_run_opts(self) -> Result<(Connection, RunOption)>;
 }
@@ -66,14 +105,19 @@ impl RunArg for Args<(&Session, RunOption)> {
     fn into_run_opts(self) -> Result<(Connection, RunOption)> {
         let Args((session, options)) = self;
         let conn = session.connection()?;
-        Ok((conn, options))
+
+        Args((conn, options)).into_run_opts()
     }
 }
 
 impl RunArg for Args<(Connection, RunOption)> {
     fn into_run_opts(self) -> Result<(Connection, RunOption)> {
-        let Args(arg) = self;
-        Ok(arg)
+        let Args((conn, options)) = self;
+
+        match options.invalid_db {
+            Some(error) => Err(err::ReqlDriverError::Other(error).into()),
+            None => Ok((conn, options)),
+        }
     }
 }
 
@@ -92,6 +136,21 @@ impl RunArg for Args<(&mut Session, RunOption)> {
     }
 }
 
+// Read the responses of `query` with `read`, again when it fails under
+// the retry policy of the session, see `retry::run`
+pub(crate) async fn retried<T, F, Fut>(query: &Command, arg: impl RunArg, read: F) -> Result<T>
+where
+    F: Fn(Args<(Connection, RunOption)>) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let (conn, opts) = arg.into_run_opts()?;
+
+    match conn.session.inner.command.retries().cloned() {
+        Some(policy) => crate::retry::run(query, conn, opts, &policy, read).await,
+        None => read(Args((conn, opts))).await,
+    }
+}
+
 pub(crate) fn new<A, T>(query: Command, arg: A) -> impl Stream<Item = Result<T>>
 where
     A: RunArg,
@@ -100,26 +159,54 @@ where
     try_stream! {
         let (mut conn, mut opts) = arg.into_run_opts()?;
         opts = opts.default_db(&conn.session).await;
+        opts.read_mode = opts.read_mode.or(query.read_mode());
+        if let Some(advisor) = conn.session.inner.command.advisor() {
+            advisor.inspect(&query);
+        }
+        let query = match &conn.session.inner.table_prefix {
+            Some(prefix) if opts.table_prefix.unwrap_or(true) => query.with_table_prefix(prefix),
+            _ => query,
+        }
+        .with_query_vars();
+        #[cfg(debug_assertions)]
+        query.check_literals()?;
+        conn.session.inner.command.check_query(&query)?;
+        if let Some(logger) = &conn.session.inner.logger {
+            logger.log(&query);
+        }
         let change_feed = query.change_feed();
         if change_feed {
             conn.session.inner.mark_change_feed();
         }
         let noreply = opts.noreply.unwrap_or_default();
+        let timeout = opts.timeout.into_iter().chain(query.timeout()).min();
+        let timeout = timeout.map(|timeout| (Instant::now() + timeout, timeout));
+        let mut cancel = conn.session.inner.cancellation(conn.token);
+        let mut stop = StopGuard::new(&conn, noreply);
         let mut payload = Payload(QueryType::Start, Some(Query(&query)), opts);
 
         loop {
-            let (response_type, resp) = conn.request(&payload, noreply).await?;
+            let response = interruptible(conn.request(&payload, noreply), timeout, &mut cancel).await;
+            if response.is_err() {
+                stop.stop().await;
+            }
+            // the query is over on the server, unless it has more batches to send
+            let response = response?;
+            if !matches!(response, Ok((ResponseType::SuccessPartial, _))) {
+                stop.disarm();
+            }
+            let (response_type, resp) = response.map_err(|err| with_query(err, &query))?;
             trace!("yielding response; token: {}", conn.token);
 
             match response_type {
                 ResponseType::SuccessAtom | ResponseType::ServerInfo => {
-                    for val in serde_json::from_value::<Vec<T>>(resp.r)? {
+                    for val in from_rows::<Vec<T>>(&resp.r)? {
                         yield val;
                     }
                     break;
                 }
                 ResponseType::SuccessSequence => {
-                    yield serde_json::from_value::<T>(resp.r)?;
+                    yield from_rows::<T>(&resp.r)?;
                     break;
                 }
                 ResponseType::SuccessPartial => {
@@ -130,19 +217,19 @@ where
                         break;
                     }
                     payload = Payload(QueryType::Continue, None, RunOption::default());
-                    // for val in serde_json::from_value::<Vec<T>>(resp.r)? {
+                    // for val in from_rows::<Vec<T>>(&resp.r)? {
                     //     yield val;
                     // }
-                    yield serde_json::from_value::<T>(resp.r)?;
+                    yield from_rows::<T>(&resp.r)?;
                     continue;
                 }
                 ResponseType::WaitComplete => { break; }
                 typ => {
-                    let msg = error_message(resp.r)?;
+                    let msg = error_message(&resp.r)?;
                     match typ {
                         // This feed has been closed by conn.close().
                         ResponseType::ClientError if change_feed && msg.contains("not in stream cache") => { break; }
-                        _ => Err(response_error(typ, resp.e, msg))?,
+                        _ => Err(with_query(response_error(typ, resp.e, msg, resp.b), &query))?,
                     }
                 }
             }
@@ -150,6 +237,75 @@ where
     }
 }
 
+// Wait for `future`, unless the query times out or is cancelled first
+async fn interruptible<F>(
+    future: F,
+    timeout: Option<(Instant, Duration)>,
+    cancel: &mut oneshot::Receiver<()>,
+) -> Result<F::Output>
+where
+    F: Future,
+{
+    let cancelled = async {
+        // the query can no longer be cancelled once its sender is dropped
+        if cancel.await.is_err() {
+            future::pending::<()>().await;
+        }
+    };
+    let expired = async {
+        match timeout {
+            Some((deadline, _)) => time::sleep_until(deadline).await,
+            None => future::pending().await,
+        }
+    };
+
+    tokio::select! {
+        output = future => Ok(output),
+        _ = cancelled => Err(err::ReqlDriverError::Cancelled.into()),
+        _ = expired => {
+            let timeout = timeout.map(|(_, timeout)| timeout).unwrap_or_default();
+            let msg = format!("the query did not complete in {} seconds", timeout.as_secs_f32());
+            Err(err::ReqlDriverError::Timeout(msg).into())
+        }
+    }
+}
+
+/// Stops the query on the server when it is abandoned before its
+/// last response, e.g. when a cursor is dropped before its end,
+/// so that the server does not keep computing it.
+struct StopGuard {
+    conn: Option<Connection>,
+}
+
+impl StopGuard {
+    fn new(conn: &Connection, noreply: bool) -> Self {
+        Self {
+            conn: (!noreply).then(|| conn.clone()),
+        }
+    }
+
+    fn disarm(&mut self) {
+        self.conn = None;
+    }
+
+    async fn stop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            conn.stop().await;
+        }
+    }
+}
+
+impl Drop for StopGuard {
+    fn drop(&mut self) {
+        // a closed connection already stopped its query
+        if let Some(conn) = self.conn.take().filter(|conn| !conn.closed()) {
+            if let Ok(runtime) = Handle::try_current() {
+                runtime.spawn(async move { conn.stop().await });
+            }
+        }
+    }
+}
+
 impl Payload<'_> {
     fn encode(&self, token: u64) -> Result<Vec<u8>> {
         let bytes = self.to_bytes()?;
@@ -185,29 +341,86 @@ impl Connection {
         }
     }
 
+    async fn stop(&self) {
+        let payload = Payload(QueryType::Stop, None, RunOption::default());
+        trace!("stopping an abandoned query; token: {}", self.token);
+
+        if let Err(error) = self.exec(&payload, true).await {
+            trace!("query not stopped; token: {}, error: {}", self.token, error);
+        }
+    }
+
     async fn submit<'a>(&self, query: &'a Payload<'a>, noreply: bool) {
-        let mut db_token = self.token;
-        let result = self.exec(query, noreply, &mut db_token).await;
-        self.send_response(db_token, result);
+        let result = match self.exec(query, noreply).await {
+            Ok(Some(response)) => Ok(response),
+            // the reader of the session delivers the response
+            Ok(None) if !noreply => return,
+            Ok(None) => Ok((ResponseType::SuccessAtom, Response::new())),
+            Err(error) => Err(error),
+        };
+        self.send_response(self.token, result);
     }
 
     async fn exec<'a>(
         &self,
         query: &'a Payload<'a>,
         noreply: bool,
-        db_token: &mut u64,
-    ) -> Result<(ResponseType, Response)> {
+    ) -> Result<Option<(ResponseType, Response)>> {
+        let future = self.send(query, noreply);
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, query_span(self.token, query));
+
+        future.await
+    }
+
+    // `None` when the frame is written by the reader of a feed hub
+    async fn send<'a>(
+        &self,
+        query: &'a Payload<'a>,
+        noreply: bool,
+    ) -> Result<Option<(ResponseType, Response)>> {
         let buf = query.encode(self.token)?;
+
+        if let Some(outbox) = self.session.inner.outbox() {
+            trace!("queuing query; token: {}, payload: {}", self.token, query);
+            // before sending, so that a reader stopping meanwhile fails the query
+            if !noreply {
+                self.session.inner.queued.insert(self.token);
+            }
+            return match outbox.unbounded_send(buf) {
+                Ok(()) => Ok(None),
+                Err(_) => {
+                    self.session.inner.queued.remove(&self.token);
+                    Err(err::ReqlDriverError::ConnectionBroken.into())
+                }
+            };
+        }
+
         let mut stream = self.session.inner.stream.lock().await;
-        let tls_stream = mem::take(&mut stream.tls_stream);
+        let stream = &mut *stream;
+        let observer = self.session.inner.observer.as_deref();
+        let observation = Observation::start(observer, self.token, query, buf.len());
+        let mut received = 0;
 
         trace!("sending query; token: {}, payload: {}", self.token, query);
-        if let Some(tcp_stream) = tls_stream {
-            self.tcp_ops(tcp_stream, buf, noreply, db_token).await
-        } else {
-            self.tcp_ops(stream.stream.clone(), buf, noreply, db_token)
-                .await
+        let result = match &mut stream.tls_stream {
+            Some(tls_stream) => self.tcp_ops(tls_stream, buf, noreply, &mut received).await,
+            None => {
+                self.tcp_ops(&mut stream.stream, buf, noreply, &mut received)
+                    .await
+            }
+        };
+
+        if let Some(observation) = observation {
+            observation.end(received, &result);
+        }
+
+        // the socket can no longer be trusted, e.g. the server went away
+        if let Err(err::ReqlError::Driver(err::ReqlDriverError::Io(..))) = result {
+            self.session.inner.mark_broken();
         }
+
+        result.map(Some)
     }
 
     async fn tcp_ops<T>(
@@ -215,25 +428,69 @@ impl Connection {
         mut stream: T,
         buf: Vec<u8>,
         noreply: bool,
-        db_token: &mut u64,
+        received: &mut usize,
     ) -> Result<(ResponseType, Response)>
     where
-        T: Unpin + AsyncWrite + AsyncReadExt + AsyncRead + AsyncReadExt,
+        T: Unpin + AsyncWrite + AsyncRead,
     {
+        let frame = FrameGuard::new(&self.session);
         stream.write_all(&buf).await?;
+        frame.done();
         trace!("query sent; token: {}", self.token);
 
         if noreply {
             return Ok((ResponseType::SuccessAtom, Response::new()));
         }
 
+        // Responses of queries whose future was dropped before reading
+        // them are still pending on the socket, skip them.
+        loop {
+            let (db_token, body) = self.read_frame(&mut stream).await?;
+
+            if db_token == self.token {
+                *received = HEADER_SIZE + body.len();
+                return self.parse_response(&body);
+            }
+
+            trace!(
+                "skipping the response of a dropped query; token: {}, db_token: {}",
+                self.token,
+                db_token
+            );
+            self.send_response(db_token, self.parse_response(&body));
+        }
+    }
+
+    async fn read_frame<T>(&self, stream: &mut T) -> Result<(u64, Vec<u8>)>
+    where
+        T: Unpin + AsyncRead,
+    {
         trace!("reading header; token: {}", self.token);
+        let mut first = [0u8; 1];
+
+        // Nothing is read while waiting for the first byte, so the query
+        // can still be abandoned without breaking the session
+        if stream.read(&mut first).await? == 0 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+
+        self.read_frame_rest(stream, first[0]).await
+    }
+
+    // The rest of a frame whose first byte is read
+    async fn read_frame_rest<T>(&self, stream: &mut T, first: u8) -> Result<(u64, Vec<u8>)>
+    where
+        T: Unpin + AsyncRead,
+    {
         let mut header = [0u8; HEADER_SIZE];
-        stream.read_exact(&mut header).await?;
+        header[0] = first;
+
+        let frame = FrameGuard::new(&self.session);
+        stream.read_exact(&mut header[1..]).await?;
 
         let mut buf = [0u8; TOKEN_SIZE];
         buf.copy_from_slice(&header[..TOKEN_SIZE]);
-        *db_token = {
+        let db_token = {
             let token = u64::from_le_bytes(buf);
             trace!("db_token: {}", token);
             if token > self.session.inner.token.load(Ordering::SeqCst) {
@@ -256,6 +513,7 @@ impl Connection {
         trace!("reading body; token: {}", self.token);
         let mut buf = vec![0u8; len];
         stream.read_exact(&mut buf).await?;
+        frame.done();
 
         trace!(
             "body read; token: {}, db_token: {}, body: {}",
@@ -264,7 +522,81 @@ impl Connection {
             super::bytes_to_string(&buf),
         );
 
-        let resp = serde_json::from_slice::<Response>(&buf)?;
+        Ok((db_token, buf))
+    }
+
+    // Own the socket for a feed hub: write the frames of `outbox` and
+    // deliver each response read in the meantime to the channel of its
+    // token, until the outbox is closed or the socket fails
+    pub(crate) async fn read_responses(&self, mut outbox: UnboundedReceiver<Vec<u8>>) {
+        let mut stream = self.session.inner.stream.lock().await;
+        let stream = &mut *stream;
+        let result = match &mut stream.tls_stream {
+            Some(tls_stream) => self.pump(tls_stream, &mut outbox).await,
+            None => self.pump(&mut stream.stream, &mut outbox).await,
+        };
+
+        self.session.inner.detach_reader();
+        outbox.close();
+
+        // the queries waiting for a response would wait forever
+        match result {
+            Err(error) => {
+                trace!("feed hub reader stopped; error: {}", error);
+                self.session.inner.mark_broken();
+
+                for channel in self.session.inner.channels.iter() {
+                    let _ = channel.unbounded_send(Err(error.clone()));
+                }
+            }
+            // e.g. the session is closed
+            Ok(()) => {
+                let error: err::ReqlError = err::ReqlDriverError::ConnectionClosed.into();
+
+                for token in self.session.inner.queued.iter() {
+                    self.send_response(*token, Err(error.clone()));
+                }
+            }
+        }
+        self.session.inner.queued.clear();
+    }
+
+    async fn pump<T>(&self, mut stream: T, outbox: &mut UnboundedReceiver<Vec<u8>>) -> Result<()>
+    where
+        T: Unpin + AsyncWrite + AsyncRead,
+    {
+        loop {
+            let mut first = [0u8; 1];
+            // waiting for the first byte of a response reads nothing,
+            // so a frame can be written in the meantime
+            let event = tokio::select! {
+                buf = outbox.next() => match buf {
+                    Some(buf) => PumpEvent::Write(buf),
+                    None => return Ok(()),
+                },
+                read = stream.read(&mut first) => PumpEvent::Read(read?),
+            };
+
+            match event {
+                PumpEvent::Write(buf) => {
+                    let frame = FrameGuard::new(&self.session);
+                    stream.write_all(&buf).await?;
+                    frame.done();
+                }
+                PumpEvent::Read(0) => {
+                    return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into())
+                }
+                PumpEvent::Read(_) => {
+                    let (db_token, body) = self.read_frame_rest(&mut stream, first[0]).await?;
+                    self.session.inner.queued.remove(&db_token);
+                    self.send_response(db_token, self.parse_response(&body));
+                }
+            }
+        }
+    }
+
+    fn parse_response(&self, buf: &[u8]) -> Result<(ResponseType, Response)> {
+        let resp = serde_json::from_slice::<Response>(buf)?;
         trace!("response successfully parsed; token: {}", self.token,);
 
         let response_type = ResponseType::from_i32(resp.t).ok_or_else(|| {
@@ -272,43 +604,165 @@ impl Connection {
         })?;
 
         if let Some(error_type) = resp.e {
-            let msg = error_message(resp.r)?;
-            return Err(response_error(response_type, Some(error_type), msg));
+            let msg = error_message(&resp.r)?;
+            return Err(response_error(response_type, Some(error_type), msg, resp.b));
         }
 
         Ok((response_type, resp))
     }
 }
 
-fn error_message(response: Value) -> Result<String> {
-    let messages = serde_json::from_value::<Vec<String>>(response)?;
+enum PumpEvent {
+    Write(Vec<u8>),
+    Read(usize),
+}
+
+// Span of a request, with the text of the query when the
+// `trace` level is enabled, like the events of the request
+#[cfg(feature = "tracing")]
+fn query_span(token: u64, payload: &Payload) -> tracing::Span {
+    use crate::constants::MAX_TRACED_QUERY_LEN;
+    use crate::observer::QueryKind;
+
+    let kind = QueryKind::of(payload.0);
+    let span = tracing::debug_span!("query", token, ?kind, query = tracing::field::Empty);
+
+    if payload.1.is_some() && tracing::enabled!(tracing::Level::TRACE) {
+        let mut query = payload.to_string();
+
+        if query.len() > MAX_TRACED_QUERY_LEN {
+            let mut len = MAX_TRACED_QUERY_LEN;
+            while !query.is_char_boundary(len) {
+                len -= 1;
+            }
+            query.truncate(len);
+            query.push('…');
+        }
+
+        span.record("query", query.as_str());
+    }
+
+    span
+}
+
+/// Marks the session broken when dropped in the middle of a frame,
+/// e.g. when the `run` future is cancelled while writing a query
+/// or reading a response, since the socket is then left mid-frame.
+struct FrameGuard<'a> {
+    session: Option<&'a Session>,
+}
+
+impl<'a> FrameGuard<'a> {
+    fn new(session: &'a Session) -> Self {
+        Self {
+            session: Some(session),
+        }
+    }
+
+    fn done(mut self) {
+        self.session = None;
+    }
+}
+
+impl Drop for FrameGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(session) = self.session {
+            trace!("frame interrupted, marking the session broken");
+            session.inner.mark_broken();
+        }
+    }
+}
+
+// The results of a response, parsed by simd-json with the `simd` feature
+fn from_rows<T>(rows: &RawValue) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    #[cfg(feature = "simd")]
+    {
+        // simd-json parses in place
+        let mut rows = rows.get().as_bytes().to_vec();
+        Ok(simd_json::serde::from_slice(&mut rows)?)
+    }
+    #[cfg(not(feature = "simd"))]
+    Ok(serde_json::from_str(rows.get())?)
+}
+
+fn error_message(response: &RawValue) -> Result<String> {
+    let messages = serde_json::from_str::<Vec<String>>(response.get())?;
     Ok(messages.join(" "))
 }
 
+fn with_query(err: err::ReqlError, query: &Command) -> err::ReqlError {
+    match serde_json::to_value(Query(query)) {
+        Ok(query) => err.with_query(&query),
+        Err(_) => err,
+    }
+}
+
 fn response_error(
     response_type: ResponseType,
     error_type: Option<i32>,
     msg: String,
+    backtrace: Option<Value>,
 ) -> err::ReqlError {
+    let backtrace: Vec<err::Frame> = backtrace
+        .and_then(|backtrace| serde_json::from_value(backtrace).ok())
+        .unwrap_or_default();
+    let server_msg = err::ErrorMessage::new(msg.as_str(), backtrace);
+
     match response_type {
         ResponseType::ClientError => err::ReqlDriverError::Other(msg).into(),
-        ResponseType::CompileError => err::ReqlError::Compile(msg),
-        ResponseType::RuntimeError => match error_type.map(ErrorType::from_i32).ok_or_else(|| {
-            err::ReqlDriverError::Other(format!("unexpected runtime error: {}", msg))
-        }) {
-            Ok(Some(ErrorType::Internal)) => err::ReqlRuntimeError::Internal(msg).into(),
-            Ok(Some(ErrorType::ResourceLimit)) => err::ReqlRuntimeError::ResourceLimit(msg).into(),
-            Ok(Some(ErrorType::QueryLogic)) => err::ReqlRuntimeError::QueryLogic(msg).into(),
-            Ok(Some(ErrorType::NonExistence)) => err::ReqlRuntimeError::NonExistence(msg).into(),
-            Ok(Some(ErrorType::OpFailed)) => err::ReqlAvailabilityError::OpFailed(msg).into(),
-            Ok(Some(ErrorType::OpIndeterminate)) => {
-                err::ReqlAvailabilityError::OpIndeterminate(msg).into()
+        ResponseType::CompileError => err::ReqlError::Compile(server_msg),
+        ResponseType::RuntimeError => match error_type.map(ErrorType::from_i32) {
+            Some(Some(ErrorType::Internal)) => err::ReqlRuntimeError::Internal(server_msg).into(),
+            Some(Some(ErrorType::ResourceLimit)) => {
+                err::ReqlRuntimeError::ResourceLimit(server_msg).into()
+            }
+            Some(Some(ErrorType::QueryLogic)) => {
+                err::ReqlRuntimeError::QueryLogic(server_msg).into()
+            }
+            Some(Some(ErrorType::NonExistence)) => {
+                err::ReqlRuntimeError::NonExistence(server_msg).into()
+            }
+            Some(Some(ErrorType::OpFailed)) => {
+                err::ReqlAvailabilityError::OpFailed(server_msg).into()
+            }
+            Some(Some(ErrorType::OpIndeterminate)) => {
+                err::ReqlAvailabilityError::OpIndeterminate(server_msg).into()
+            }
+            Some(Some(ErrorType::User)) => err::ReqlRuntimeError::User(server_msg).into(),
+            Some(Some(ErrorType::PermissionError)) => {
+                err::ReqlRuntimeError::Permission(server_msg).into()
             }
-            Ok(Some(ErrorType::User)) => err::ReqlRuntimeError::User(msg).into(),
-            Ok(Some(ErrorType::PermissionError)) => err::ReqlRuntimeError::Permission(msg).into(),
-            Err(error) => error.into(),
             _ => err::ReqlDriverError::Other(format!("unexpected runtime error: {}", msg)).into(),
         },
         _ => err::ReqlDriverError::Other(format!("unexpected response: {}", msg)).into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::arguments::ReadMode;
+    use crate::r;
+
+    #[test]
+    fn read_mode() {
+        let posts = r.table("posts").outdated();
+
+        assert_eq!(posts.count(()).read_mode(), Some(ReadMode::Outdated));
+        assert_eq!(
+            posts.majority().g("a").read_mode(),
+            Some(ReadMode::Majority)
+        );
+        assert_eq!(r.table("posts").count(()).read_mode(), None);
+        assert_eq!(
+            r.table("users").union(posts.clone()).read_mode(),
+            Some(ReadMode::Outdated)
+        );
+        assert_eq!(
+            posts.to_wire_json().unwrap(),
+            r.table("posts").to_wire_json().unwrap()
+        );
+    }
+}
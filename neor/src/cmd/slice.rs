@@ -37,6 +37,17 @@ where
     }
 }
 
+// The `Copy` bound above keeps `SliceOption` apart from the end offset,
+// a subquery is accepted through this impl
+impl<S> SliceArg for Args<(S, Command)>
+where
+    S: Into<CommandArg>,
+{
+    fn into_slice_opts(self) -> (CommandArg, Option<CommandArg>, SliceOption) {
+        (self.0 .0.into(), Some(self.0 .1.into()), Default::default())
+    }
+}
+
 impl<T> SliceArg for Args<(T, SliceOption)>
 where
     T: Into<CommandArg>,
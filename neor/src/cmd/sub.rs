@@ -54,3 +54,5 @@ where
         arg.into().add_to_cmd(TermType::Sub).with_parent(&self)
     }
 }
+
+impl_reflected_op!(Sub, sub, i64, f64);
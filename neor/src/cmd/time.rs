@@ -32,6 +32,15 @@ pub trait TimeArg {
     fn into_time_opts(self) -> (OffsetDateTime, String, bool);
 }
 
+impl TimeArg for OffsetDateTime {
+    fn into_time_opts(self) -> (OffsetDateTime, String, bool) {
+        let (offset_datetime, timezone_formated) =
+            make_time(self.date(), self.time(), self.offset());
+
+        (offset_datetime, timezone_formated, true)
+    }
+}
+
 impl TimeArg for Args<(Date, UtcOffset)> {
     fn into_time_opts(self) -> (OffsetDateTime, String, bool) {
         let (offset_datetime, timezone_formated) =
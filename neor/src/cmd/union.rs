@@ -1,14 +1,26 @@
 use ql2::term::TermType;
 
-use crate::arguments::{Args, UnionOption};
+use crate::arguments::{Args, Interleave, UnionOption};
 use crate::command_tools::CmdOpts;
 use crate::Command;
 
 pub(crate) fn new(args: impl UnionArg) -> Command {
     let (args, opts) = args.into_union_opts();
+    let change_feed = match &args {
+        CmdOpts::Single(arg) => arg.change_feed(),
+        CmdOpts::Many(args) => args.iter().any(Command::change_feed),
+    };
+    let command = args.add_to_cmd(Command::new(TermType::Union));
+    let command = match opts.interleave {
+        Some(Interleave::Func(func)) => command.with_term_opt("interleave", func.0),
+        _ => command.with_opts(opts),
+    };
 
-    args.add_to_cmd(Command::new(TermType::Union))
-        .with_opts(opts)
+    if change_feed {
+        command.mark_change_feed()
+    } else {
+        command
+    }
 }
 
 pub trait UnionArg {
@@ -47,3 +59,34 @@ where
         (CmdOpts::Many(self.0 .0.into_iter().collect()), self.0 .1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::arguments::{Interleave, UnionOption};
+    use crate::{args, r, Command, Func};
+
+    #[test]
+    fn union_interleave() {
+        let opts = UnionOption::default().interleave(Interleave::Func(Func::new(
+            vec![1],
+            Command::var(1).g("id"),
+        )));
+        let query = r.union(args!([r.expr([1]), r.table("users").changes(())], opts));
+        let wire = query.to_wire_json().unwrap();
+
+        assert!(query.change_feed());
+        assert_eq!(wire[2]["interleave"][0], json!(69));
+        assert_eq!(wire[1][0], json!([2, [1]]));
+
+        let opts = UnionOption::default().interleave(Interleave::FieldName("id"));
+
+        let query = r.expr([1]).union(args!(r.expr([2]), opts));
+
+        assert_eq!(
+            query.to_wire_json().unwrap()[2],
+            json!({"interleave": "id"})
+        );
+    }
+}
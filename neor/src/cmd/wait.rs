@@ -1,8 +1,14 @@
+use std::time::Duration;
+
 use ql2::term::TermType;
 
 use crate::arguments::{Args, WaitOption};
 use crate::Command;
 
+// Time given to the server to report its own timeout
+// before giving up on the client side
+const GRACE_PERIOD: Duration = Duration::from_secs(1);
+
 pub(crate) fn new(args: impl WaitArg) -> Command {
     let (args, opts) = args.into_wait_opts();
     let mut command = Command::new(TermType::Wait);
@@ -11,7 +17,17 @@ pub(crate) fn new(args: impl WaitArg) -> Command {
         command = command.with_arg(arg)
     }
 
-    command.with_opts(opts)
+    match client_timeout(&opts) {
+        Some(timeout) => command.with_opts(opts).with_timeout(timeout + GRACE_PERIOD),
+        None => command.with_opts(opts),
+    }
+}
+
+// `timeout` in seconds, where 0 means no timeout
+pub(crate) fn client_timeout(opts: &WaitOption) -> Option<Duration> {
+    opts.timeout
+        .filter(|timeout| *timeout > 0.)
+        .and_then(|timeout| Duration::try_from_secs_f64(timeout).ok())
 }
 
 pub trait WaitArg {
@@ -41,3 +57,28 @@ impl WaitArg for Args<(Command, WaitOption)> {
         (Some(self.0 .0), self.0 .1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::arguments::WaitOption;
+    use crate::r;
+
+    #[test]
+    fn wait_timeout() {
+        let opts = WaitOption::default().timeout(2.);
+        let query = r.table("users").index_wait(opts).nth(0);
+
+        assert_eq!(query.timeout(), Some(Duration::from_secs(2)));
+        assert_eq!(
+            r.table("posts").union(query).timeout(),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            r.table("users").wait(opts).timeout(),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(r.table("users").wait(opts.timeout(0.)).timeout(), None);
+    }
+}
@@ -1,7 +1,32 @@
+use std::collections::{BTreeMap, HashMap};
+
 use ql2::term::TermType;
 use serde::Serialize;
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::constants::USER_ERROR_MARKER;
+use crate::err::UserError;
+use crate::types::DateTime;
+use crate::{r, Command, Func};
 
-use crate::{Command, Func};
+// Implement `value op command` for plain values, e.g. `1 + r.expr(2)`,
+// as the term of `r.expr(value) op command`.
+// Only one integer and one float type are given, otherwise
+// the type of literals such as `1` could not be inferred
+macro_rules! impl_reflected_op {
+    ($op:ident, $method:ident, $($typ:ty),+ $(,)?) => {
+        $(
+            impl std::ops::$op<crate::Command> for $typ {
+                type Output = crate::Command;
+
+                fn $method(self, command: crate::Command) -> crate::Command {
+                    std::ops::$op::$method(crate::Command::from_json(self), command)
+                }
+            }
+        )+
+    };
+}
 
 pub enum CmdOpts {
     Single(Command),
@@ -42,7 +67,7 @@ impl CommandArg {
 
 impl<T: Serialize> From<T> for CommandArg {
     fn from(arg: T) -> Self {
-        Command::from_json(arg).into()
+        CommandArg(Command::from_json(arg))
     }
 }
 
@@ -63,3 +88,184 @@ impl From<Func> for CommandArg {
         CommandArg(arg.0)
     }
 }
+
+impl<T> From<UserError<T>> for CommandArg
+where
+    T: Into<CommandArg>,
+{
+    fn from(error: UserError<T>) -> Self {
+        let payload = error.payload.into().to_cmd().to_json();
+
+        CommandArg(r.expr(USER_ERROR_MARKER) + payload)
+    }
+}
+
+/// A Rust value holding commands, sent as the terms building it
+///
+/// Vectors, arrays and tuples are sent as arrays, maps with string keys
+/// as objects and `None` as `null`, whether their values are commands,
+/// plain values or other values holding commands, e.g.
+/// `vec![("all", r.table("posts").count(()))].into_term()`.
+/// Values holding no command are given as they are to [expr](crate::r::expr).
+pub trait IntoTerm {
+    fn into_term(self) -> Command;
+}
+
+impl IntoTerm for Command {
+    fn into_term(self) -> Command {
+        self
+    }
+}
+
+impl IntoTerm for &Command {
+    fn into_term(self) -> Command {
+        self.clone()
+    }
+}
+
+impl IntoTerm for Func {
+    fn into_term(self) -> Command {
+        self.0
+    }
+}
+
+macro_rules! impl_datum_term {
+    ($($typ:ty),+ $(,)?) => {
+        $(
+            impl IntoTerm for $typ {
+                fn into_term(self) -> Command {
+                    self.into()
+                }
+            }
+        )+
+    };
+}
+
+impl_datum_term!(DateTime, OffsetDateTime);
+
+macro_rules! impl_json_term {
+    ($($typ:ty),+ $(,)?) => {
+        $(
+            impl IntoTerm for $typ {
+                fn into_term(self) -> Command {
+                    Command::from_json(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_json_term!(
+    bool, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64, char, &str, String, Value,
+);
+
+impl<T: IntoTerm> IntoTerm for Option<T> {
+    fn into_term(self) -> Command {
+        match self {
+            Some(value) => value.into_term(),
+            None => Command::from(Value::Null),
+        }
+    }
+}
+
+impl<T: IntoTerm> IntoTerm for Vec<T> {
+    fn into_term(self) -> Command {
+        r.array(self.into_iter().map(IntoTerm::into_term))
+    }
+}
+
+impl<T: IntoTerm, const N: usize> IntoTerm for [T; N] {
+    fn into_term(self) -> Command {
+        r.array(self.into_iter().map(IntoTerm::into_term))
+    }
+}
+
+impl<K: Into<String>, T: IntoTerm> IntoTerm for HashMap<K, T> {
+    fn into_term(self) -> Command {
+        object_term(self)
+    }
+}
+
+impl<K: Into<String>, T: IntoTerm> IntoTerm for BTreeMap<K, T> {
+    fn into_term(self) -> Command {
+        object_term(self)
+    }
+}
+
+fn object_term<K, T>(map: impl IntoIterator<Item = (K, T)>) -> Command
+where
+    K: Into<String>,
+    T: IntoTerm,
+{
+    let fields: Vec<_> = map
+        .into_iter()
+        .map(|(key, value)| (key.into(), value.into_term()))
+        .collect();
+
+    r.hash_map(fields)
+}
+
+macro_rules! impl_tuple_term {
+    ($(($($name:ident),+)),+ $(,)?) => {
+        $(
+            impl<$($name: IntoTerm),+> IntoTerm for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn into_term(self) -> Command {
+                    let ($($name,)+) = self;
+
+                    r.array([$($name.into_term()),+])
+                }
+            }
+        )+
+    };
+}
+
+impl_tuple_term!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::IntoTerm;
+    use crate::{r, Command};
+
+    #[test]
+    fn nested_commands() {
+        let post = BTreeMap::from([
+            ("title", r.expr("title")),
+            ("tags", vec![vec![r.expr("a"), r.expr(1) + 2]].into_term()),
+            ("author", Some(r.table("users").get(1)).into_term()),
+        ]);
+        let counts = BTreeMap::from([("a", (r.expr(1), None::<Command>))]);
+
+        assert_eq!(
+            post.into_term().to_wire_json().unwrap(),
+            json!({
+                "title": "title",
+                "tags": [2, [[2, ["a", [24, [1, 2]]]]]],
+                "author": [16, [[15, ["users"], {}], 1]],
+            })
+        );
+        assert_eq!(
+            counts.into_term().to_wire_json().unwrap(),
+            json!({"a": [2, [1, null]]})
+        );
+        assert_eq!(
+            vec![vec![1, 2]].into_term().to_wire_json().unwrap(),
+            r.expr(vec![vec![1, 2]]).to_wire_json().unwrap()
+        );
+        assert_eq!(
+            r.expr(json!({"$reql_command$": 0})).to_wire_json().unwrap(),
+            json!({"$reql_command$": 0})
+        );
+    }
+}
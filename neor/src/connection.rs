@@ -1,12 +1,14 @@
 use std::borrow::Cow;
+use std::net::Shutdown;
 use std::ops::Drop;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use async_native_tls::TlsStream;
 use async_net::TcpStream;
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures::channel::oneshot;
 use futures::lock::Mutex;
 use futures::TryFutureExt;
 use ql2::query::QueryType;
@@ -16,8 +18,11 @@ use tokio::time;
 use tracing::trace;
 
 use super::cmd::run::Response;
+use crate::cmd::connect::ConnectionCommand;
+use crate::observer::{QueryLogger, QueryObserver};
 use crate::proto::{Payload, Query};
-use crate::types::ServerInfoResponse;
+use crate::tls::TlsStream;
+use crate::types::{Capabilities, ServerInfoResponse};
 use crate::{err, r, Result, StaticString};
 
 type Sender = UnboundedSender<Result<(ResponseType, Response)>>;
@@ -28,9 +33,22 @@ pub(crate) struct InnerSession {
     pub(crate) db: Mutex<Cow<'static, str>>,
     pub(crate) stream: Mutex<TcpStreamConnection>,
     pub(crate) channels: DashMap<u64, Sender>,
+    pub(crate) cancels: DashMap<u64, oneshot::Sender<()>>,
     pub(crate) token: AtomicU64,
     pub(crate) broken: AtomicBool,
+    pub(crate) closed: AtomicBool,
     pub(crate) change_feed: AtomicBool,
+    // The frames to write, while the socket is read by a feed hub
+    pub(crate) outbox: std::sync::Mutex<Option<UnboundedSender<Vec<u8>>>>,
+    // The tokens of the frames of the outbox waiting for their response
+    pub(crate) queued: DashSet<u64>,
+    pub(crate) table_prefix: Option<Cow<'static, str>>,
+    // Version of the server of the current socket
+    pub(crate) server_version: std::sync::Mutex<String>,
+    pub(crate) observer: Option<Arc<dyn QueryObserver>>,
+    pub(crate) logger: Option<QueryLogger>,
+    // Options of the session, to open a new socket
+    pub(crate) command: ConnectionCommand,
 }
 
 impl InnerSession {
@@ -45,6 +63,13 @@ impl InnerSession {
         token
     }
 
+    // Receive the cancellation of the query running with `token`
+    pub(crate) fn cancellation(&self, token: u64) -> oneshot::Receiver<()> {
+        let (tx, rx) = oneshot::channel();
+        self.cancels.insert(token, tx);
+        rx
+    }
+
     pub(crate) fn mark_broken(&self) {
         self.broken.store(true, Ordering::SeqCst);
     }
@@ -56,6 +81,13 @@ impl InnerSession {
         Ok(())
     }
 
+    pub(crate) fn closed(&self) -> Result<()> {
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(err::ReqlDriverError::ConnectionClosed.into());
+        }
+        Ok(())
+    }
+
     pub(crate) fn mark_change_feed(&self) {
         self.change_feed.store(true, Ordering::SeqCst);
     }
@@ -69,11 +101,21 @@ impl InnerSession {
     }
 
     pub(crate) fn change_feed(&self) -> Result<()> {
-        if self.change_feed.load(Ordering::SeqCst) {
+        // a session read by a feed hub runs several changefeeds at once
+        if self.change_feed.load(Ordering::SeqCst) && self.outbox().is_none() {
             return Err(err::ReqlDriverError::ConnectionLocked.into());
         }
         Ok(())
     }
+
+    pub(crate) fn outbox(&self) -> Option<UnboundedSender<Vec<u8>>> {
+        self.outbox.lock().unwrap().clone()
+    }
+
+    // Stop the reader of a feed hub, which releases the socket
+    pub(crate) fn detach_reader(&self) {
+        self.outbox.lock().unwrap().take();
+    }
 }
 
 /// The connection object returned by `r.connection()`
@@ -113,6 +155,7 @@ impl Session {
     /// }
     /// ```
     pub fn connection(&self) -> Result<Connection> {
+        self.inner.closed()?;
         self.inner.broken()?;
         self.inner.change_feed()?;
         let token = self.inner.token();
@@ -173,10 +216,15 @@ impl Session {
     ) -> Result<()> {
         let future = self
             .close(noreply_wait)
-            .and_then(|_| async { self.connection() });
+            .and_then(|_| self.inner.command.reopen(&self.inner));
 
         if let Some(timeout) = timeout {
-            time::timeout(timeout, future).await.unwrap()?;
+            time::timeout(timeout, future).await.map_err(|_| {
+                err::ReqlDriverError::Timeout(format!(
+                    "could not reconnect in {} seconds",
+                    timeout.as_secs_f32()
+                ))
+            })??;
         } else {
             future.await?;
         }
@@ -215,9 +263,57 @@ impl Session {
     ///
     /// # Related commands
     /// - [connection](crate::r::connection)
+    /// - [use_db](Self::use_db)
     /// - [close](Self::close)
     /// - [reconnect](Self::reconnect)
     pub async fn use_(&mut self, db_name: impl Into<String>) -> Result<()> {
+        self.use_db(db_name).await
+    }
+
+    /// Change the default database of a shared session.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// conn.use_db(db_name)
+    /// ```
+    ///
+    /// Where
+    /// - db_name: `impl Into<String>`
+    ///
+    /// # Description
+    ///
+    /// Like [use_](Self::use_), but the session does not need to be mutable,
+    /// so the database can be switched on a session cloned across tasks.
+    /// The new database applies to all the queries run afterwards on the
+    /// session, except those given a [db](crate::arguments::RunOption::db)
+    /// option or naming their database with `r.db()`.
+    ///
+    /// ## Examples
+    ///
+    /// Switch to the `analytics` database, but run one query on `test`.
+    ///
+    /// ```
+    /// use neor::arguments::RunOption;
+    /// use neor::{args, r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().dbname("shop").connect().await?;
+    ///     conn.use_db("analytics").await?;
+    ///
+    ///     r.table("visits").count(()).run(&conn).await?;
+    ///
+    ///     let opts = RunOption::default().db("test");
+    ///     r.table("visits").count(()).run(args!(&conn, opts)).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [use_](Self::use_)
+    /// - [connection](crate::r::connection)
+    pub async fn use_db(&self, db_name: impl Into<String>) -> Result<()> {
         *self.inner.db.lock().await = db_name.into().static_string();
 
         Ok(())
@@ -277,11 +373,11 @@ impl Session {
     /// # Command syntax
     ///
     /// ```text
-    /// result.server() -> response
+    /// session.server() → response
     /// ```
     ///
     /// Where:
-    /// - server: [ServerInfoResponse](crate::types::ServerInfoResponse)
+    /// - response: [ServerInfoResponse](crate::types::ServerInfoResponse)
     ///
     /// ## Examples
     ///
@@ -312,45 +408,247 @@ impl Session {
             conn.token,
             typ,
         );
-        let mut vec = serde_json::from_value::<Vec<ServerInfoResponse>>(resp.r)?;
+        let mut vec = serde_json::from_str::<Vec<ServerInfoResponse>>(resp.r.get())?;
         let info = vec
             .pop()
             .ok_or_else(|| err::ReqlDriverError::Other("server info is empty".into()))?;
         Ok(info)
     }
 
-    /// Close a cursor.
+    /// Return the optional features supported by the server being used by a connection.
     ///
     /// # Command syntax
     ///
     /// ```text
-    /// result.close()
+    /// session.capabilities() -> capabilities
     /// ```
     ///
+    /// Where:
+    /// - capabilities: [Capabilities](crate::types::Capabilities)
+    ///
     /// # Description
     ///
-    /// Closing a result cancels the corresponding query and
-    /// frees the memory associated with the open request.
+    /// The capabilities are derived from the version announced by the
+    /// server in the handshake of the session, without running a query.
     ///
     /// ## Examples
     ///
-    /// Close a result.
+    /// Use write hooks when the server supports them.
     ///
     /// ```
-    /// use neor::{r, Converter, Result};
+    /// use neor::{r, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     
-    ///     conn.close(false).await?;
-    ///     
+    ///     let capabilities = conn.capabilities().await?;
+    ///
+    ///     if capabilities.write_hooks {
+    ///         r.table("comments").get_write_hook().run(&conn).await?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn capabilities(&self) -> Result<Capabilities> {
+        let version = self.inner.server_version.lock().unwrap().parse()?;
+
+        Ok(Capabilities::new(version))
+    }
+
+    /// Close the session.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// session.close(noreply_wait)
+    /// ```
+    ///
+    /// Where:
+    /// - noreply_wait: bool
+    ///
+    /// # Description
+    ///
+    /// The session is closed in this order:
+    /// 1. with `noreply_wait`, wait for the queries run with `noreply`
+    /// 2. let the queries waiting for a response read it
+    /// 3. shut down the socket
+    ///
+    /// When `noreply_wait` is `false`, the writes run with `noreply`
+    /// may be aborted, see [noreply_wait](Self::noreply_wait).
+    /// The queries waiting for a response hold the socket until then.
+    ///
+    /// The queries run afterwards fail with a
+    /// [ConnectionClosed](crate::err::ReqlDriverError::ConnectionClosed) error,
+    /// until the session is [reconnected](Self::reconnect).
+    /// A changefeed holds the socket until its next batch,
+    /// close it with [Connection::close] first.
+    /// Closing a closed session does nothing.
+    ///
+    /// ## Examples
+    ///
+    /// Close a session after its last writes.
+    ///
+    /// ```
+    /// use neor::arguments::{Args, RunOption};
+    /// use neor::{r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let options = RunOption::default().noreply(true);
+    ///
+    ///     r.table("logs")
+    ///         .insert(json!({"message": "bye"}))
+    ///         .run(Args((&conn, options)))
+    ///         .await?;
+    ///
+    ///     conn.close(true).await?;
+    ///
+    ///     assert!(!conn.is_open());
+    ///
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Related commands
+    /// - [noreply_wait](Self::noreply_wait)
+    /// - [reconnect](Self::reconnect)
     pub async fn close(&self, noreply_wait: bool) -> Result<()> {
-        self.connection()?.close(noreply_wait).await
+        if self.inner.closed().is_err() {
+            return Ok(());
+        }
+
+        if noreply_wait && self.inner.broken().is_ok() && !self.inner.is_change_feed() {
+            self.noreply_wait().await?;
+        }
+
+        self.inner.closed.store(true, Ordering::SeqCst);
+        self.inner.detach_reader();
+
+        let stream = self.inner.stream.lock().await;
+        trace!("closing the session");
+        // the socket may already be gone, e.g. with a broken session
+        let _ = stream.stream.shutdown(Shutdown::Both);
+
+        Ok(())
+    }
+
+    /// Cancel a running query.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// conn.cancel(token)
+    /// ```
+    ///
+    /// Where:
+    /// - token: the [token](Connection::token) of the connection running the query
+    ///
+    /// # Description
+    ///
+    /// The query is stopped on the server and its `run` returns
+    /// a [Cancelled](crate::err::ReqlDriverError::Cancelled) error.
+    /// Returns `false` when no query is running with this token,
+    /// e.g. when it is already over or has not been polled yet.
+    ///
+    /// ## Examples
+    ///
+    /// Stop reading an infinite stream from another task.
+    ///
+    /// ```
+    /// use futures::TryStreamExt;
+    /// use neor::{r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let session = r.connection().connect().await?;
+    ///     let conn = session.connection()?;
+    ///     let token = conn.token();
+    ///
+    ///     let task = tokio::spawn(async move {
+    ///         let mut stream = r.range(()).build_query(conn);
+    ///         while stream.try_next().await?.is_some() {}
+    ///         Ok::<_, neor::err::ReqlError>(())
+    ///     });
+    ///
+    ///     tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    ///     session.cancel(token);
+    ///
+    ///     assert!(task.await.unwrap().is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [connection](Self::connection)
+    pub fn cancel(&self, token: u64) -> bool {
+        match self.inner.cancels.remove(&token) {
+            Some((_, cancel)) => cancel.send(()).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Check that the server answers, returning the round-trip time.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// session.ping() → duration
+    /// ```
+    ///
+    /// Where:
+    /// - duration: [Duration](std::time::Duration)
+    ///
+    /// # Description
+    ///
+    /// A `SERVER_INFO` query is sent, which doesn't touch any table.
+    /// When the socket fails, an error is returned and
+    /// the session is no longer [open](Self::is_open).
+    ///
+    /// ## Examples
+    ///
+    /// Check a session taken from a pool.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use neor::{r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let latency = conn.ping().await?;
+    ///
+    ///     assert!(conn.is_open());
+    ///     assert!(latency < Duration::from_secs(1));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [server](Self::server)
+    /// - [is_open](Self::is_open)
+    pub async fn ping(&self) -> Result<Duration> {
+        let start = Instant::now();
+        self.server().await?;
+        Ok(start.elapsed())
+    }
+
+    /// Whether the session can still run queries
+    ///
+    /// A session is no longer open once [closed](Self::close),
+    /// or when its socket failed, until it is [reconnected](Self::reconnect).
+    /// This doesn't contact the server, see [ping](Self::ping).
+    pub fn is_open(&self) -> bool {
+        self.inner.closed().is_ok() && !self.is_broken()
+    }
+
+    /// The prefix added to the table names of the queries, set with
+    /// [table_prefix](crate::types::ConnectionCommand::table_prefix)
+    ///
+    /// The server lists the tables with their prefixed names.
+    pub fn table_prefix(&self) -> Option<&str> {
+        self.inner.table_prefix.as_deref()
     }
 
     #[doc(hidden)]
@@ -377,6 +675,12 @@ impl Connection {
         }
     }
 
+    /// Token identifying the queries run with this connection,
+    /// see [cancel](Session::cancel)
+    pub fn token(&self) -> u64 {
+        self.token
+    }
+
     pub async fn close(&mut self, noreply_wait: bool) -> Result<()> {
         if !self.session.inner.is_change_feed() {
             trace!(
@@ -406,6 +710,29 @@ impl Connection {
         Ok(())
     }
 
+    // Stop the changefeed read by `stream` and wait for the server to confirm,
+    // instead of leaving the `STOP` to the stream when it is dropped
+    pub(crate) async fn stop_stream<S>(&self, stream: S) -> Result<()> {
+        // the stream of a closed connection does not stop it when dropped
+        self.set_closed(true);
+        drop(stream);
+
+        // dropping the stream unregistered the token, the response
+        // of the `STOP` goes to a new receiver
+        let (tx, rx) = mpsc::unbounded();
+        self.session.inner.channels.insert(self.token, tx);
+        let mut conn = Connection::new(self.session.clone(), rx, self.token);
+        let payload = Payload(QueryType::Stop, None, Default::default());
+        trace!("stopping a changefeed; token: {}", self.token);
+        let (typ, _) = conn.request(&payload, false).await?;
+        trace!(
+            "changefeed stopped; token: {}, response type: {:?}",
+            self.token,
+            typ
+        );
+        Ok(())
+    }
+
     pub(crate) fn closed(&self) -> bool {
         self.closed.load(Ordering::SeqCst)
     }
@@ -418,6 +745,7 @@ impl Connection {
 impl Drop for Connection {
     fn drop(&mut self) {
         self.session.inner.channels.remove(&self.token);
+        self.session.inner.cancels.remove(&self.token);
         if self.session.inner.is_change_feed() {
             self.session.inner.unmark_change_feed();
         }
@@ -427,5 +755,7 @@ impl Drop for Connection {
 #[derive(Debug)]
 pub(crate) struct TcpStreamConnection {
     pub(crate) stream: TcpStream,
-    pub(crate) tls_stream: Option<TlsStream<TcpStream>>,
+    pub(crate) tls_stream: Option<TlsStream>,
+    // Announced by the server in the handshake
+    pub(crate) server_version: String,
 }
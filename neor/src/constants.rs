@@ -10,6 +10,9 @@ pub(crate) const NANOS_PER_MSEC: i128 = 1_000_000;
 pub(crate) const TIMEZONE_FORMAT: &str = "[offset_hour sign:mandatory]:[offset_minute]";
 pub(crate) const MINUTE: f64 = 60.;
 pub(crate) const HOUR: f64 = 60. * MINUTE;
+pub(crate) const USER_ERROR_MARKER: &str = "neor:user_error:";
+#[cfg(feature = "tracing")]
+pub(crate) const MAX_TRACED_QUERY_LEN: usize = 512;
 
 pub const RETHINKDB_DRIVER_NAME: &str = "rethinkdb";
 pub const DEFAULT_RETHINKDB_HOSTNAME: &str = "localhost";
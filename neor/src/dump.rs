@@ -0,0 +1,352 @@
+//! Export tables to newline-delimited JSON and restore them
+//!
+//! A dump holds, for each table, a header line describing the table
+//! (primary key and secondary indexes) followed by one line per document.
+//! Documents are read and inserted in batches, and the secondary indexes are
+//! recreated from the functions returned by `index_status`.
+//!
+//! Any `futures::io` writer or reader can be used,
+//! so a dump can be compressed by wrapping them in an encoder.
+//!
+//! ```
+//! use futures::io::{BufReader, Cursor};
+//! use neor::dump::{Export, Import};
+//! use neor::{r, Result};
+//!
+//! async fn example() -> Result<()> {
+//!     let conn = r.connection().connect().await?;
+//!     let mut dump = Vec::new();
+//!
+//!     Export::db("blog").write(&conn, &mut dump).await?;
+//!
+//!     let summary = Import::new()
+//!         .db("blog_copy")
+//!         .read(&conn, BufReader::new(Cursor::new(dump)))
+//!         .await?;
+//!
+//!     assert!(summary.tables > 0);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::borrow::Cow;
+
+use futures::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::arguments::{Args, RunOption, TableCreateOption};
+use crate::types::{Binary, IndexStatusResponse, InfoResponse, MutationResponse};
+use crate::{err, r, Command, Converter, Result, Session};
+
+/// Key of the header line written before the documents of a table
+pub const DUMP_MARKER: &str = "$neor_dump$";
+
+const DEFAULT_BATCH_SIZE: usize = 200;
+
+/// Header line of a table in a dump
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TableDump {
+    pub db: Cow<'static, str>,
+    pub table: Cow<'static, str>,
+    pub primary_key: Cow<'static, str>,
+    pub indexes: Vec<IndexDump>,
+}
+
+/// A secondary index of a dumped table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IndexDump {
+    pub index: Cow<'static, str>,
+    /// the index function, as returned by `index_status`
+    pub function: Binary,
+}
+
+/// What was exported or imported
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DumpSummary {
+    pub tables: usize,
+    pub documents: usize,
+    pub indexes: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Header {
+    #[serde(rename = "$neor_dump$")]
+    table: TableDump,
+}
+
+/// Export the tables of a database
+#[derive(Debug, Clone)]
+pub struct Export {
+    db: Cow<'static, str>,
+    tables: Option<Vec<Cow<'static, str>>>,
+}
+
+impl Export {
+    /// Export every table of `db`
+    pub fn db(db: impl Into<String>) -> Self {
+        Self {
+            db: Cow::Owned(db.into()),
+            tables: None,
+        }
+    }
+
+    /// Export a single table of `db`
+    pub fn table(db: impl Into<String>, table: impl Into<String>) -> Self {
+        Self::db(db).tables([table])
+    }
+
+    /// Only export the given tables
+    pub fn tables<T: Into<String>>(mut self, tables: impl IntoIterator<Item = T>) -> Self {
+        let tables = tables.into_iter().map(|table| Cow::Owned(table.into()));
+
+        self.tables = Some(tables.collect());
+        self
+    }
+
+    /// Write the dump to `writer`, the writer is flushed but not closed
+    pub async fn write<W>(&self, session: &Session, writer: &mut W) -> Result<DumpSummary>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let db = r.db(self.db.as_ref());
+        let tables: Vec<Cow<'static, str>> = match &self.tables {
+            Some(tables) => tables.clone(),
+            None => run(&db.table_list(), session).await?,
+        };
+        let mut summary = DumpSummary::default();
+
+        for table in tables {
+            let query = db.table(table.as_ref());
+            let header = Header {
+                table: table_dump(&query, session).await?,
+            };
+
+            summary.tables += 1;
+            summary.indexes += header.table.indexes.len();
+            write_line(writer, &header).await?;
+
+            let mut batches = query.build_query(args(session)).boxed();
+
+            while let Some(batch) = batches.try_next().await? {
+                // the documents keep their TIME pseudo-types
+                let documents: Vec<Value> = serde_json::from_value(batch)?;
+
+                for document in &documents {
+                    write_line(writer, document).await?;
+                }
+
+                summary.documents += documents.len();
+            }
+        }
+
+        writer.flush().await?;
+
+        Ok(summary)
+    }
+}
+
+/// Restore a dump written by [Export]
+#[derive(Debug, Clone)]
+pub struct Import {
+    db: Option<Cow<'static, str>>,
+    batch_size: usize,
+}
+
+impl Default for Import {
+    fn default() -> Self {
+        Self {
+            db: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+}
+
+impl Import {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restore the tables into `db` instead of their original database
+    pub fn db(mut self, db: impl Into<String>) -> Self {
+        self.db = Some(Cow::Owned(db.into()));
+        self
+    }
+
+    /// Number of documents inserted by each query (default: `200`)
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Read the dump from `reader`
+    ///
+    /// Missing databases and tables are created, existing documents
+    /// with the same primary key are an error. The secondary indexes
+    /// are created once the documents of their table are inserted.
+    pub async fn read<R>(&self, session: &Session, reader: R) -> Result<DumpSummary>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut lines = reader.lines();
+        let mut summary = DumpSummary::default();
+        let mut current: Option<(TableDump, Command)> = None;
+        let mut batch = Vec::with_capacity(self.batch_size);
+
+        while let Some(line) = lines.try_next().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut value: Value = serde_json::from_str(&line)?;
+
+            if let Some(table) = value.get_mut(DUMP_MARKER).map(Value::take) {
+                if let Some((table, query)) = current.take() {
+                    summary.documents += insert(&query, &mut batch, session).await?;
+                    summary.indexes += create_indexes(&table, &query, session).await?;
+                }
+
+                let table: TableDump = serde_json::from_value(table)?;
+                let query = self.create_table(&table, session).await?;
+
+                summary.tables += 1;
+                current = Some((table, query));
+                continue;
+            }
+
+            let query = match &current {
+                Some((_, query)) => query,
+                None => {
+                    return Err(err::ReqlDriverError::Other(
+                        "dump document found before any table header".to_owned(),
+                    )
+                    .into())
+                }
+            };
+
+            batch.push(value);
+
+            if batch.len() >= self.batch_size {
+                summary.documents += insert(query, &mut batch, session).await?;
+            }
+        }
+
+        if let Some((table, query)) = current {
+            summary.documents += insert(&query, &mut batch, session).await?;
+            summary.indexes += create_indexes(&table, &query, session).await?;
+        }
+
+        Ok(summary)
+    }
+
+    async fn create_table(&self, table: &TableDump, session: &Session) -> Result<Command> {
+        let db_name = self.db.as_ref().unwrap_or(&table.db);
+        let dbs: Vec<Cow<'static, str>> = run(&r.db_list(), session).await?;
+
+        if !dbs.contains(db_name) {
+            r.db_create(db_name.as_ref()).run(args(session)).await?;
+        }
+
+        let db = r.db(db_name.as_ref());
+        let tables: Vec<Cow<'static, str>> = run(&db.table_list(), session).await?;
+
+        if !tables.contains(&table.table) {
+            let opts = TableCreateOption::default().primary_key(table.primary_key.to_string());
+
+            db.table_create(Args((table.table.as_ref(), opts)))
+                .run(args(session))
+                .await?;
+        }
+
+        Ok(db.table(table.table.as_ref()))
+    }
+}
+
+async fn table_dump(query: &Command, session: &Session) -> Result<TableDump> {
+    let info: InfoResponse = run(&query.info(), session).await?;
+    let indexes: Vec<IndexStatusResponse> = run(&query.index_status(()), session).await?;
+    let indexes = indexes
+        .into_iter()
+        .map(|status| IndexDump {
+            index: status.index,
+            function: status.function,
+        })
+        .collect();
+
+    Ok(TableDump {
+        db: info.db.map(|db| db.name).unwrap_or_default(),
+        table: info.name.unwrap_or_default(),
+        primary_key: info.primary_key.unwrap_or(Cow::Borrowed("id")),
+        indexes,
+    })
+}
+
+async fn insert(query: &Command, batch: &mut Vec<Value>, session: &Session) -> Result<usize> {
+    if batch.is_empty() {
+        return Ok(0);
+    }
+
+    let documents = std::mem::take(batch);
+    let response: MutationResponse = run(&query.insert(documents), session).await?;
+
+    match response.first_error {
+        Some(error) => Err(err::ReqlDriverError::Other(error).into()),
+        None => Ok(response.inserted),
+    }
+}
+
+async fn create_indexes(table: &TableDump, query: &Command, session: &Session) -> Result<usize> {
+    let existing: Vec<Cow<'static, str>> = run(&query.index_list(), session).await?;
+    let mut created = 0;
+
+    for index in &table.indexes {
+        if existing.contains(&index.index) {
+            continue;
+        }
+
+        query
+            .index_create(Args((index.index.as_ref(), index.function.clone())))
+            .run(args(session))
+            .await?;
+        created += 1;
+    }
+
+    if created > 0 {
+        query.index_wait(()).run(args(session)).await?;
+    }
+
+    Ok(created)
+}
+
+// Dumps use the stored table names, whatever the session prefix
+fn args(session: &Session) -> Args<(&Session, RunOption)> {
+    Args((session, RunOption::default().table_prefix(false)))
+}
+
+async fn run<T>(query: &Command, session: &Session) -> Result<T>
+where
+    T: Unpin + Serialize + serde::de::DeserializeOwned,
+{
+    match query.run(args(session)).await? {
+        Some(value) => value.parse(),
+        None => Err(err::ReqlDriverError::Other("empty response".to_owned()).into()),
+    }
+}
+
+async fn write_line<W, T>(writer: &mut W, value: &T) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let mut line = serde_json::to_vec(value)?;
+
+    line.push(b'\n');
+    writer.write_all(&line).await?;
+
+    Ok(())
+}
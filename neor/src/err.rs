@@ -1,16 +1,82 @@
+use std::ops::Deref;
 use std::sync::Arc;
 use std::{error, fmt, io};
 
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::USER_ERROR_MARKER;
+
 /// The most generic error message in ReQL
 #[derive(Debug, Clone)]
 pub enum ReqlError {
-    Compile(String),
+    Compile(ErrorMessage),
     Runtime(ReqlRuntimeError),
     Driver(ReqlDriverError),
 }
 
 impl error::Error for ReqlError {}
 
+impl ReqlError {
+    /// Decode the payload of an error thrown with
+    /// [r.error(UserError::new(payload))](crate::r::error)
+    ///
+    /// Returns `None` when this is not a user error or when
+    /// its payload can't be deserialized into `T`.
+    pub fn user_error<T: DeserializeOwned>(&self) -> Option<UserError<T>> {
+        match self {
+            Self::Runtime(ReqlRuntimeError::User(msg)) => msg
+                .strip_prefix(USER_ERROR_MARKER)
+                .and_then(|payload| serde_json::from_str(payload).ok())
+                .map(UserError::new),
+            _ => None,
+        }
+    }
+
+    // Attach the annotated query to the message of a server error
+    pub(crate) fn with_query(mut self, query: &serde_json::Value) -> Self {
+        let msg = match &mut self {
+            Self::Compile(msg) => msg,
+            Self::Runtime(ReqlRuntimeError::Availability(
+                ReqlAvailabilityError::OpFailed(msg) | ReqlAvailabilityError::OpIndeterminate(msg),
+            )) => msg,
+            Self::Runtime(
+                ReqlRuntimeError::QueryLogic(msg)
+                | ReqlRuntimeError::NonExistence(msg)
+                | ReqlRuntimeError::ResourceLimit(msg)
+                | ReqlRuntimeError::User(msg)
+                | ReqlRuntimeError::Internal(msg)
+                | ReqlRuntimeError::Permission(msg),
+            ) => msg,
+            Self::Driver(_) => return self,
+        };
+
+        msg.query = Some(crate::printer::annotate(query, &msg.backtrace));
+        self
+    }
+
+    /// Path of the term which raised a server error, see [Frame]
+    ///
+    /// Returns `None` for the driver errors.
+    pub fn backtrace(&self) -> Option<&[Frame]> {
+        match self {
+            Self::Compile(msg) => Some(&msg.backtrace),
+            Self::Runtime(err) => Some(&err.message().backtrace),
+            Self::Driver(_) => None,
+        }
+    }
+
+    /// The query which raised a server error, with carets under
+    /// the faulty term, see [ErrorMessage::query]
+    pub fn query(&self) -> Option<&str> {
+        match self {
+            Self::Compile(msg) => msg.query.as_deref(),
+            Self::Runtime(err) => err.message().query.as_deref(),
+            Self::Driver(_) => None,
+        }
+    }
+}
+
 impl fmt::Display for ReqlError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -28,13 +94,33 @@ impl fmt::Display for ReqlError {
 #[derive(Debug, Clone)]
 pub enum ReqlRuntimeError {
     /// The query contains a logical impossibility, such as adding a number to a string.
-    QueryLogic(String),
-    NonExistence(String),
-    ResourceLimit(String),
-    User(String),
-    Internal(String),
+    QueryLogic(ErrorMessage),
+    /// A `QueryLogic` error from accessing a non-existent field or something similar.
+    NonExistence(ErrorMessage),
+    /// The query exceeded a resource limit, such as the array size limit.
+    ResourceLimit(ErrorMessage),
+    /// An error thrown by [r.error](crate::r::error).
+    User(ErrorMessage),
+    /// The query triggered an internal error of the server.
+    Internal(ErrorMessage),
     Availability(ReqlAvailabilityError),
-    Permission(String),
+    /// The user is not allowed to run the query.
+    Permission(ErrorMessage),
+}
+
+impl ReqlRuntimeError {
+    /// The message of the server
+    pub fn message(&self) -> &ErrorMessage {
+        match self {
+            Self::QueryLogic(msg)
+            | Self::NonExistence(msg)
+            | Self::ResourceLimit(msg)
+            | Self::User(msg)
+            | Self::Internal(msg)
+            | Self::Permission(msg) => msg,
+            Self::Availability(err) => err.message(),
+        }
+    }
 }
 
 impl From<ReqlRuntimeError> for ReqlError {
@@ -57,6 +143,112 @@ impl fmt::Display for ReqlRuntimeError {
     }
 }
 
+/// The message of an error raised by the server
+///
+/// The message dereferences and compares to a `str`, and comes with
+/// the backtrace of the term which raised the error.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ErrorMessage {
+    pub message: String,
+    /// Path from the root of the query to the faulty term,
+    /// empty when the server does not point to a term
+    pub backtrace: Vec<Frame>,
+    /// The query, with carets under the faulty term
+    ///
+    /// ```text
+    /// r.table("posts").get(1).get_field("views").add(r.expr(2).add("a"))
+    ///                                                ^^^^^^^^^^^^^^^^^^
+    /// ```
+    ///
+    /// It is left out of the `Display` output, as it holds the whole query
+    /// with its documents.
+    pub query: Option<String>,
+}
+
+impl ErrorMessage {
+    pub fn new(message: impl Into<String>, backtrace: Vec<Frame>) -> Self {
+        Self {
+            message: message.into(),
+            backtrace,
+            query: None,
+        }
+    }
+}
+
+impl Deref for ErrorMessage {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ErrorMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<String> for ErrorMessage {
+    fn from(message: String) -> Self {
+        Self::new(message, Vec::new())
+    }
+}
+
+impl From<&str> for ErrorMessage {
+    fn from(message: &str) -> Self {
+        Self::new(message, Vec::new())
+    }
+}
+
+impl PartialEq<str> for ErrorMessage {
+    fn eq(&self, other: &str) -> bool {
+        self.message == other
+    }
+}
+
+impl PartialEq<&str> for ErrorMessage {
+    fn eq(&self, other: &&str) -> bool {
+        self.message == *other
+    }
+}
+
+impl PartialEq<String> for ErrorMessage {
+    fn eq(&self, other: &String) -> bool {
+        &self.message == other
+    }
+}
+
+/// A step of a backtrace
+///
+/// Each frame selects an argument of the current term, starting
+/// with the root term of the query.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Frame {
+    /// Position of a positional argument
+    Pos(usize),
+    /// Name of an optional argument
+    Opt(String),
+}
+
+/// A user error carrying a structured payload
+///
+/// The payload is JSON encoded in the error message when thrown
+/// with [r.error](crate::r::error) and decoded back
+/// by [ReqlError::user_error].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserError<T> {
+    pub payload: T,
+}
+
+impl<T> UserError<T> {
+    pub fn new(payload: T) -> Self {
+        Self { payload }
+    }
+}
+
 /// A server in the cluster is unavailable
 ///
 /// The parent class of `OpFailedError` and `OpIndeterminateError`. Programs may use this
@@ -64,8 +256,19 @@ impl fmt::Display for ReqlRuntimeError {
 /// children.
 #[derive(Debug, Clone)]
 pub enum ReqlAvailabilityError {
-    OpFailed(String),
-    OpIndeterminate(String),
+    /// The operation has failed and has not been applied.
+    OpFailed(ErrorMessage),
+    /// The operation may or may not have been applied.
+    OpIndeterminate(ErrorMessage),
+}
+
+impl ReqlAvailabilityError {
+    /// The message of the server
+    pub fn message(&self) -> &ErrorMessage {
+        match self {
+            Self::OpFailed(msg) | Self::OpIndeterminate(msg) => msg,
+        }
+    }
 }
 
 impl From<ReqlAvailabilityError> for ReqlError {
@@ -92,6 +295,10 @@ impl fmt::Display for ReqlAvailabilityError {
 pub enum ReqlDriverError {
     Auth(String),
     ConnectionBroken,
+    /// The session was closed with [close](crate::connection::Session::close)
+    ConnectionClosed,
+    /// A changefeed runs on the session, see [FeedHub](crate::hub::FeedHub)
+    /// to run several of them on one connection
     ConnectionLocked,
     Io(io::ErrorKind, String),
     Json(Arc<serde_json::Error>),
@@ -99,6 +306,13 @@ pub enum ReqlDriverError {
     Time(String),
     Tls(String),
     DriverUrl(String),
+    /// The connection or a query took longer than the given timeout
+    Timeout(String),
+    /// The query was cancelled with [cancel](crate::connection::Session::cancel)
+    Cancelled,
+    /// The query exceeds the `max_term_depth` or `max_query_size`
+    /// of the session, see [ConnectionCommand](crate::types::ConnectionCommand)
+    QueryTooLarge(String),
 }
 
 impl From<ReqlDriverError> for ReqlError {
@@ -112,6 +326,7 @@ impl fmt::Display for ReqlDriverError {
         match self {
             Self::Auth(msg) => write!(f, "auth error; {}", msg),
             Self::ConnectionBroken => write!(f, "connection broken"),
+            Self::ConnectionClosed => write!(f, "the connection has been closed"),
             Self::ConnectionLocked => write!(
                 f,
                 "another query is running a changefeed on this connection"
@@ -122,6 +337,9 @@ impl fmt::Display for ReqlDriverError {
             Self::Time(error) => write!(f, "{}", error),
             Self::Tls(error) => write!(f, "{}", error),
             Self::DriverUrl(error) => write!(f, "{}", error),
+            Self::Timeout(msg) => write!(f, "timeout; {}", msg),
+            Self::Cancelled => write!(f, "the query has been cancelled"),
+            Self::QueryTooLarge(msg) => write!(f, "query too large; {}", msg),
         }
     }
 }
@@ -138,6 +356,14 @@ impl From<serde_json::Error> for ReqlError {
     }
 }
 
+#[cfg(feature = "simd")]
+impl From<simd_json::Error> for ReqlError {
+    fn from(err: simd_json::Error) -> ReqlError {
+        <serde_json::Error as serde::de::Error>::custom(err).into()
+    }
+}
+
+#[cfg(feature = "tls-native")]
 impl From<async_native_tls::Error> for ReqlError {
     fn from(err: async_native_tls::Error) -> Self {
         ReqlDriverError::Tls(err.to_string()).into()
@@ -168,6 +394,12 @@ impl From<time::error::Format> for ReqlError {
     }
 }
 
+impl From<base64::DecodeError> for ReqlError {
+    fn from(err: base64::DecodeError) -> Self {
+        ReqlDriverError::Other(err.to_string()).into()
+    }
+}
+
 impl From<url::ParseError> for ReqlError {
     fn from(err: url::ParseError) -> Self {
         ReqlDriverError::DriverUrl(err.to_string()).into()
@@ -0,0 +1,443 @@
+//! Handshake of the RethinkDB protocol
+//!
+//! [client] authenticates a stream to a RethinkDB server, like
+//! [connect](crate::cmd::connect::ConnectionCommand::connect) does,
+//! and [server] answers the handshake of a client. Together, they
+//! are the building blocks of a proxy or a load balancer speaking
+//! the RethinkDB protocol on both sides.
+//!
+//! Both sides authenticate with SCRAM-SHA-256. The server side checks the
+//! credentials with an [AuthenticationProvider].
+//!
+//! ```
+//! use std::num::NonZeroU32;
+//!
+//! use async_net::{TcpListener, TcpStream};
+//! use neor::handshake::{self, AuthenticationProvider, PasswordInfo};
+//! use neor::Result;
+//!
+//! struct Users;
+//!
+//! impl AuthenticationProvider for Users {
+//!     fn get_password_for(&self, user: &str) -> Option<PasswordInfo> {
+//!         let iterations = NonZeroU32::new(4096).unwrap();
+//!         let salt = b"proxy salt".to_vec();
+//!         let password = handshake::hash_password("secret", iterations, &salt);
+//!
+//!         (user == "reader").then(|| PasswordInfo::new(password.to_vec(), 4096, salt))
+//!     }
+//! }
+//!
+//! async fn example() -> Result<()> {
+//!     let listener = TcpListener::bind("127.0.0.1:28016").await?;
+//!     let (client, _) = listener.accept().await?;
+//!     let (client, user) = handshake::server(client, Users, "2.4.4").await?;
+//!
+//!     let upstream = TcpStream::connect("127.0.0.1:28015").await?;
+//!     let upstream = handshake::client(upstream, "admin", "").await?;
+//!
+//!     // Forward the queries of `user` from `client` to `upstream`
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use futures::io::{AsyncReadExt, AsyncWriteExt};
+use futures::{AsyncRead, AsyncWrite};
+use ql2::version_dummy::Version;
+use scram::client::{ScramClient, ServerFinal, ServerFirst};
+use scram::server::{AuthenticationStatus, ScramServer};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+use crate::cmd::bytes_to_string;
+use crate::constants::{BUFFER_SIZE, DEFAULT_AUTHENTICATION_METHOD, NULL_BYTE, PROTOCOL_VERSION};
+use crate::{err, Result};
+
+pub use scram::hash_password;
+pub use scram::server::{AuthenticationProvider, PasswordInfo};
+
+// Error codes of the authentication failures, see `AuthResponse::from_slice`
+const PROTOCOL_ERROR_CODE: usize = 10;
+const AUTH_ERROR_CODE: usize = 12;
+
+/// Authenticate `stream` to a RethinkDB server as `user`
+///
+/// This method optimises message exchange as suggested in the RethinkDB
+/// documentation by sending message 3 right after message 1, without waiting
+/// for message 2 first.
+pub async fn client<T>(stream: T, user: &str, password: &str) -> Result<T>
+where
+    T: Unpin + AsyncRead + AsyncWrite,
+{
+    Ok(authenticate(stream, user, password).await?.0)
+}
+
+// The handshake of `client`, also returning the version announced by the server
+pub(crate) async fn authenticate<T>(
+    mut stream: T,
+    user: &str,
+    password: &str,
+) -> Result<(T, String)>
+where
+    T: Unpin + AsyncRead + AsyncWrite,
+{
+    trace!("sending supported version to RethinkDB");
+
+    stream
+        .write_all(&(Version::V10 as i32).to_le_bytes())
+        .await?; // message 1
+
+    let scram = ScramClient::new(user, password, None);
+    let (scram, msg) = client_first(scram)?;
+    trace!("sending client first message");
+    stream.write_all(&msg).await?; // message 3
+
+    trace!("receiving message(s) from RethinkDB");
+    let resp = read_bytes(&mut stream).await?; // message 2
+    trace!("received server info; info: {}", bytes_to_string(&resp));
+    let server_version = ServerInfo::validate(&resp)?;
+
+    trace!("reading auth response");
+    let resp = read_bytes(&mut stream).await?; // message 4
+    trace!("received auth response");
+    let info = AuthResponse::from_slice(&resp)?;
+    let auth = match info.authentication {
+        Some(auth) => auth,
+        None => {
+            let msg = String::from("server did not send authentication info");
+            return Err(err::ReqlDriverError::Other(msg).into());
+        }
+    };
+
+    let (scram, msg) = client_final(scram, &auth)?;
+    trace!("sending client final message");
+    stream.write_all(&msg).await?; // message 5
+
+    trace!("reading server final message");
+    let resp = read_bytes(&mut stream).await?; // message 6
+    trace!("received server final message");
+    server_final(scram, &resp)?;
+
+    trace!("client connected successfully");
+
+    Ok((stream, server_version))
+}
+
+/// Answer the handshake of a client connected to `stream`
+///
+/// The credentials of the client are checked by `provider`, and
+/// `server_version` is announced to the client. Returns the stream,
+/// ready for the queries, and the name of the authenticated user.
+/// When the authentication fails, the client is told why before
+/// the error is returned.
+pub async fn server<T, P>(mut stream: T, provider: P, server_version: &str) -> Result<(T, String)>
+where
+    T: Unpin + AsyncRead + AsyncWrite,
+    P: AuthenticationProvider,
+{
+    trace!("receiving protocol version");
+    let mut version = [0u8; 4];
+    stream.read_exact(&mut version).await?; // message 1
+
+    if i32::from_le_bytes(version) != Version::V10 as i32 {
+        let msg = "ERROR: Received an unsupported protocol version.";
+        stream.write_all(msg.as_bytes()).await?;
+        stream.write_all(&[NULL_BYTE]).await?;
+        return Err(err::ReqlDriverError::Other(msg.to_owned()).into());
+    }
+
+    let info = ServerInfo {
+        success: true,
+        min_protocol_version: PROTOCOL_VERSION,
+        max_protocol_version: PROTOCOL_VERSION,
+        server_version,
+    };
+    trace!("sending server info");
+    write_message(&mut stream, &info).await?; // message 2
+
+    trace!("reading client first message");
+    let request: AuthRequest = read_message(&mut stream).await?; // message 3
+
+    if request.protocol_version != PROTOCOL_VERSION
+        || request.authentication_method != DEFAULT_AUTHENTICATION_METHOD
+    {
+        let msg = format!(
+            "unsupported authentication method {} for protocol version {}",
+            request.authentication_method, request.protocol_version,
+        );
+        return reject(stream, PROTOCOL_ERROR_CODE, msg).await;
+    }
+
+    let user = match user_name(&request.authentication) {
+        Some(user) => user,
+        None => {
+            let msg = String::from("client did not send a user name");
+            return reject(stream, PROTOCOL_ERROR_CODE, msg).await;
+        }
+    };
+
+    let scram = ScramServer::new(provider);
+    let (scram, server_first) = match scram.handle_client_first(&request.authentication) {
+        Ok(scram) => scram.server_first(),
+        Err(error) => return reject(stream, AUTH_ERROR_CODE, error.to_string()).await,
+    };
+    trace!("sending server first message");
+    write_message(&mut stream, &AuthResponse::success(server_first)).await?; // message 4
+
+    trace!("reading client final message");
+    let conf: AuthConfirmation = read_message(&mut stream).await?; // message 5
+    let (status, server_final) = match scram.handle_client_final(&conf.authentication) {
+        Ok(scram) => scram.server_final(),
+        Err(error) => return reject(stream, AUTH_ERROR_CODE, error.to_string()).await,
+    };
+
+    if !matches!(status, AuthenticationStatus::Authenticated) {
+        let msg = String::from("Wrong password");
+        return reject(stream, AUTH_ERROR_CODE, msg).await;
+    }
+
+    trace!("sending server final message");
+    write_message(&mut stream, &AuthResponse::success(server_final)).await?; // message 6
+
+    trace!("client {} authenticated successfully", user);
+
+    Ok((stream, user))
+}
+
+async fn read_message<T, M>(stream: &mut T) -> Result<M>
+where
+    T: Unpin + AsyncRead,
+    M: DeserializeOwned,
+{
+    Ok(serde_json::from_slice(&read_bytes(stream).await?)?)
+}
+
+// A peer may send its next message without waiting for the answer,
+// so the messages are read byte by byte to not read past their end
+async fn read_bytes<T>(stream: &mut T) -> Result<Vec<u8>>
+where
+    T: Unpin + AsyncRead,
+{
+    let mut msg = Vec::new();
+    let mut byte = [0u8];
+
+    loop {
+        stream.read_exact(&mut byte).await?;
+
+        match byte[0] {
+            NULL_BYTE => break,
+            _ if msg.len() == BUFFER_SIZE => {
+                let msg = String::from("handshake message too long");
+                return Err(err::ReqlDriverError::Other(msg).into());
+            }
+            byte => msg.push(byte),
+        }
+    }
+
+    Ok(msg)
+}
+
+async fn write_message<T, M>(stream: &mut T, msg: &M) -> Result<()>
+where
+    T: Unpin + AsyncWrite,
+    M: Serialize,
+{
+    let mut msg = serde_json::to_vec(msg)?;
+    msg.push(NULL_BYTE);
+    stream.write_all(&msg).await?;
+    Ok(())
+}
+
+async fn reject<T, R>(mut stream: T, error_code: usize, msg: String) -> Result<R>
+where
+    T: Unpin + AsyncWrite,
+{
+    let resp = AuthResponse {
+        success: false,
+        authentication: None,
+        error_code: Some(error_code),
+        error: Some(msg.clone()),
+    };
+    write_message(&mut stream, &resp).await?;
+
+    Err(err::ReqlDriverError::Auth(msg).into())
+}
+
+// The user name is the `n` attribute of the client first message,
+// after the GS2 header, with `,` and `=` escaped as `=2C` and `=3D`
+fn user_name(client_first: &str) -> Option<String> {
+    let user = client_first
+        .splitn(3, ',')
+        .nth(2)?
+        .split(',')
+        .find_map(|attr| attr.strip_prefix("n="))?;
+
+    Some(user.replace("=2C", ",").replace("=3D", "="))
+}
+
+// We are going to use &str for `server_version` because it is safe to do so.
+// Unfortunately, the other fields that are using String, are doing so because
+// because they can potentially contain an escaped double quote which is not
+// supported by serde in &str.
+#[derive(Serialize, Deserialize, Debug)]
+struct ServerInfo<'a> {
+    success: bool,
+    min_protocol_version: usize,
+    max_protocol_version: usize,
+    server_version: &'a str,
+}
+
+impl ServerInfo<'_> {
+    // The version of the server, when it speaks the protocol of the driver
+    fn validate(resp: &[u8]) -> Result<String> {
+        let info = serde_json::from_slice::<ServerInfo>(resp)?;
+        if !info.success {
+            return Err(err::ReqlRuntimeError::Internal(bytes_to_string(resp).into()).into());
+        }
+        #[allow(clippy::absurd_extreme_comparisons)]
+        if PROTOCOL_VERSION < info.min_protocol_version
+            || info.max_protocol_version < PROTOCOL_VERSION
+        {
+            let msg = format!(
+                "unsupported protocol version {version}, expected between {min} and {max}",
+                version = PROTOCOL_VERSION,
+                min = info.min_protocol_version,
+                max = info.max_protocol_version,
+            );
+            return Err(err::ReqlDriverError::Other(msg).into());
+        }
+        Ok(info.server_version.to_owned())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AuthRequest {
+    protocol_version: usize,
+    authentication_method: String,
+    authentication: String,
+}
+
+fn client_first(scram: ScramClient<'_>) -> Result<(ServerFirst<'_>, Vec<u8>)> {
+    let (scram, client_first) = scram.client_first();
+    let ar = AuthRequest {
+        protocol_version: PROTOCOL_VERSION,
+        authentication_method: DEFAULT_AUTHENTICATION_METHOD.to_owned(),
+        authentication: client_first,
+    };
+    let mut msg = serde_json::to_vec(&ar)?;
+    msg.push(NULL_BYTE);
+    Ok((scram, msg))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AuthConfirmation {
+    authentication: String,
+}
+
+fn client_final(scram: ServerFirst<'_>, auth: &str) -> Result<(ServerFinal, Vec<u8>)> {
+    let scram = scram
+        .handle_server_first(auth)
+        .map_err(|x| x.to_string())
+        .map_err(err::ReqlDriverError::Other)?;
+    let (scram, client_final) = scram.client_final();
+    let conf = AuthConfirmation {
+        authentication: client_final,
+    };
+    let mut msg = serde_json::to_vec(&conf)?;
+    msg.push(NULL_BYTE);
+    Ok((scram, msg))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AuthResponse {
+    success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authentication: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_code: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl AuthResponse {
+    fn success(authentication: String) -> Self {
+        Self {
+            success: true,
+            authentication: Some(authentication),
+            error_code: None,
+            error: None,
+        }
+    }
+
+    fn from_slice(resp: &[u8]) -> Result<Self> {
+        let info = serde_json::from_slice::<AuthResponse>(resp)?;
+        if !info.success {
+            // If error code is between 10 and 20, this is an auth error
+            if let Some(10..=20) = info.error_code {
+                if let Some(msg) = info.error {
+                    return Err(err::ReqlDriverError::Auth(msg).into());
+                }
+            }
+            return Err(err::ReqlRuntimeError::Internal(bytes_to_string(resp).into()).into());
+        }
+        Ok(info)
+    }
+}
+
+fn server_final(scram: ServerFinal, resp: &[u8]) -> Result<()> {
+    let info = AuthResponse::from_slice(resp)?;
+    if let Some(auth) = info.authentication {
+        if let Err(error) = scram.handle_server_final(&auth) {
+            return Err(err::ReqlDriverError::Other(error.to_string()).into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use async_net::{TcpListener, TcpStream};
+
+    use super::{AuthenticationProvider, PasswordInfo};
+
+    struct Users;
+
+    impl AuthenticationProvider for Users {
+        fn get_password_for(&self, user: &str) -> Option<PasswordInfo> {
+            let iterations = NonZeroU32::new(4096).unwrap();
+            let salt = b"salt".to_vec();
+            let password = super::hash_password("secret", iterations, &salt);
+
+            (user == "reader").then(|| PasswordInfo::new(password.to_vec(), 4096, salt))
+        }
+    }
+
+    #[test]
+    fn user_name() {
+        assert!(super::user_name("n,,n=a=2Cb=3Dc,r=nonce") == Some("a,b=c".to_owned()));
+        assert!(super::user_name("n,a=admin,n=user,r=nonce") == Some("user".to_owned()));
+        assert!(super::user_name("n,,r=nonce").is_none());
+    }
+
+    #[tokio::test]
+    async fn handshake() -> crate::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            super::server(stream, Users, "2.4.4").await
+        });
+
+        let stream = TcpStream::connect(addr).await?;
+        super::client(stream, "reader", "secret").await?;
+        let (_, user) = server.await.unwrap()?;
+
+        assert!(user == "reader");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,352 @@
+//! Run many changefeeds over a single connection
+//!
+//! A session runs one changefeed at a time: the response of a changefeed
+//! comes when a document changes, and the session waits for it before
+//! sending the next query. A [FeedHub] reads the responses of its session
+//! in a background task and routes them by query token, so that a service
+//! watching many documents, e.g. with point changefeeds, opens a single
+//! connection for all of them.
+//!
+//! Each [HubFeed] buffers its notifications, up to a [limit](FeedHub::buffer).
+//! A feed whose buffer is full stops asking the server for changes, which
+//! queues them as for any changefeed read too slowly, while the other feeds
+//! go on. Closing or dropping a feed stops its changefeed on the server.
+//!
+//! ```
+//! use futures::TryStreamExt;
+//! use neor::hub::FeedHub;
+//! use neor::types::FeedItem;
+//! use neor::{r, Result};
+//! use serde_json::Value;
+//!
+//! async fn example() -> Result<()> {
+//!     let session = r.connection().connect().await?;
+//!     let hub = FeedHub::new(session)?.buffer(64);
+//!     let mut orders = hub.subscribe::<Value>(&r.table("orders").get(1).changes(()))?;
+//!     let mut users = hub.subscribe::<Value>(&r.table("users").get("malik").changes(()))?;
+//!
+//!     if let Some(FeedItem::Change { new, .. }) = orders.try_next().await? {
+//!         assert!(new.is_some());
+//!     }
+//!
+//!     users.close().await;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::{SinkExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+
+use crate::cmd::changes;
+use crate::types::FeedItem;
+use crate::{err, Command, Result, Session};
+
+const DEFAULT_BUFFER: usize = 32;
+
+/// Changefeeds sharing the connection of a session
+///
+/// The hub owns the socket of its session until the hub and all its
+/// feeds are dropped, or until the session is closed. Other queries
+/// can still run on the session meanwhile.
+#[derive(Debug, Clone)]
+pub struct FeedHub {
+    reader: Arc<Reader>,
+    buffer: usize,
+    // Runs the reader of the session and the feeds
+    runtime: Handle,
+}
+
+// Stops the reader of the session once the hub and its feeds are dropped
+#[derive(Debug)]
+struct Reader {
+    session: Session,
+}
+
+impl Drop for Reader {
+    fn drop(&mut self) {
+        self.session.inner.detach_reader();
+    }
+}
+
+impl FeedHub {
+    /// Start reading the responses of `session`
+    ///
+    /// The responses are read by a task of the current Tokio runtime,
+    /// which also runs the feeds of the hub.
+    ///
+    /// Fails when the session is closed or broken, when it already
+    /// runs a changefeed or a hub, or outside of a Tokio runtime.
+    pub fn new(session: Session) -> Result<Self> {
+        let runtime = Handle::try_current().map_err(|_| {
+            err::ReqlDriverError::Other("a feed hub can only run in a Tokio runtime".to_owned())
+        })?;
+        let conn = session.connection()?;
+        let (outbox, frames) = mpsc::unbounded();
+
+        {
+            let mut current = session.inner.outbox.lock().unwrap();
+
+            if current.is_some() {
+                return Err(err::ReqlDriverError::ConnectionLocked.into());
+            }
+
+            *current = Some(outbox);
+        }
+
+        runtime.spawn(async move { conn.read_responses(frames).await });
+
+        Ok(Self {
+            reader: Arc::new(Reader { session }),
+            buffer: DEFAULT_BUFFER,
+            runtime,
+        })
+    }
+
+    /// Number of notifications each feed keeps until they are read
+    /// (default: 32)
+    pub fn buffer(mut self, notifications: usize) -> Self {
+        self.buffer = notifications.max(1);
+        self
+    }
+
+    pub fn session(&self) -> &Session {
+        &self.reader.session
+    }
+
+    /// Start the changefeed `query`, with the table prefix
+    /// and the default database of the session
+    pub fn subscribe<T>(&self, query: &Command) -> Result<HubFeed<T>>
+    where
+        T: Unpin + DeserializeOwned + Send + 'static,
+    {
+        if !query.change_feed() {
+            return Err(err::ReqlDriverError::Other(
+                "subscribe can only be used on a changefeed".to_owned(),
+            )
+            .into());
+        }
+
+        let conn = self.reader.session.connection()?;
+        let token = conn.token();
+        let (mut sender, items) = mpsc::channel(self.buffer - 1);
+        let reader = self.reader.clone();
+        let mut feed = Box::pin(changes::feed::<T>(query.clone(), conn));
+
+        let task = self.runtime.spawn(async move {
+            while let Some(item) = feed.next().await {
+                let failed = item.is_err();
+
+                if sender.send(item).await.is_err() || failed {
+                    break;
+                }
+            }
+
+            drop(reader);
+        });
+
+        Ok(HubFeed {
+            items,
+            token,
+            session: self.reader.session.clone(),
+            task,
+        })
+    }
+}
+
+/// A changefeed of a [FeedHub], a stream of its notifications
+#[derive(Debug)]
+pub struct HubFeed<T> {
+    items: mpsc::Receiver<Result<FeedItem<T>>>,
+    token: u64,
+    session: Session,
+    task: JoinHandle<()>,
+}
+
+impl<T> HubFeed<T> {
+    /// Token of the query of the changefeed, see [cancel](Session::cancel)
+    pub fn token(&self) -> u64 {
+        self.token
+    }
+
+    /// Stop the changefeed on the server,
+    /// the other feeds of the hub keep running
+    pub async fn close(mut self) {
+        self.items.close();
+        self.session.cancel(self.token);
+
+        // the feed stops as soon as it is cancelled, or when it finds
+        // its buffer closed if it was waiting for room in it
+        let _ = (&mut self.task).await;
+    }
+}
+
+impl<T> Stream for HubFeed<T> {
+    type Item = Result<FeedItem<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.items.poll_next_unpin(cx)
+    }
+}
+
+impl<T> Drop for HubFeed<T> {
+    fn drop(&mut self) {
+        // the query is stopped on the server when its stream is dropped
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::time::Duration;
+
+    use async_net::{TcpListener, TcpStream};
+    use futures::io::{AsyncReadExt, AsyncWriteExt};
+    use futures::TryStreamExt;
+    use ql2::query::QueryType;
+    use ql2::response::ResponseType;
+    use serde_json::{json, Value};
+
+    use super::FeedHub;
+    use crate::cmd::connect::ConnectionCommand;
+    use crate::constants::{HEADER_SIZE, TOKEN_SIZE};
+    use crate::err::{ReqlDriverError, ReqlError};
+    use crate::types::FeedItem;
+    use crate::{r, Result, Session, TcpStreamConnection};
+
+    // A server holding the `CONTINUE` of each changefeed, but answering
+    // the one of the last feed once two feeds wait for a change
+    async fn serve(mut stream: TcpStream) -> Result<()> {
+        let mut waiting = BTreeSet::new();
+
+        loop {
+            let mut header = [0u8; HEADER_SIZE];
+            stream.read_exact(&mut header).await?;
+
+            let (token, len) = header.split_at(TOKEN_SIZE);
+            let token = u64::from_le_bytes(token.try_into().unwrap());
+            let mut body = vec![0u8; u32::from_le_bytes(len.try_into().unwrap()) as usize];
+            stream.read_exact(&mut body).await?;
+
+            let query: Value = serde_json::from_slice(&body)?;
+            let partial = ResponseType::SuccessPartial as i32;
+            let response = match query[0].as_i64().unwrap() as i32 {
+                typ if typ == QueryType::Continue as i32 => {
+                    waiting.insert(token);
+
+                    match waiting.len() {
+                        2 => {
+                            let last = waiting.pop_last().unwrap();
+                            let change = json!({"old_val": last, "new_val": last + 1});
+
+                            Some((last, json!({"t": partial, "r": [change]})))
+                        }
+                        _ => None,
+                    }
+                }
+                typ if typ == QueryType::Stop as i32 => {
+                    waiting.remove(&token);
+                    None
+                }
+                _ if query[1][0] == json!(152) => Some((token, json!({"t": partial, "r": []}))),
+                _ => Some((
+                    token,
+                    json!({"t": ResponseType::SuccessAtom as i32, "r": [1]}),
+                )),
+            };
+
+            if let Some((token, response)) = response {
+                let response = response.to_string();
+                let mut frame = token.to_le_bytes().to_vec();
+                frame.extend_from_slice(&(response.len() as u32).to_le_bytes());
+                frame.extend_from_slice(response.as_bytes());
+                stream.write_all(&frame).await?;
+            }
+        }
+    }
+
+    async fn served_session() -> Result<Session> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let stream = TcpStream::connect(listener.local_addr()?).await?;
+        let (server, _) = listener.accept().await?;
+        tokio::spawn(serve(server));
+
+        Ok(ConnectionCommand::default().session(TcpStreamConnection {
+            stream,
+            tls_stream: None,
+            server_version: String::new(),
+        }))
+    }
+
+    #[tokio::test]
+    async fn feeds_share_session() -> Result<()> {
+        let session = served_session().await?;
+        let hub = FeedHub::new(session.clone())?;
+        let first = hub.subscribe::<u64>(&r.table("posts").get(1).changes(()))?;
+        let mut second = hub.subscribe::<u64>(&r.table("posts").get(2).changes(()))?;
+
+        // the change of the second feed does not wait for the first one
+        let change = tokio::time::timeout(Duration::from_secs(5), second.try_next())
+            .await
+            .unwrap()?;
+        let change = match change {
+            Some(FeedItem::Change { new, .. }) => new,
+            _ => None,
+        };
+
+        assert_eq!(change, Some(second.token() + 1));
+        assert_eq!(r.expr(1).run(&session).await?, Some(json!(1)));
+        assert!(FeedHub::new(session.clone()).is_err());
+
+        second.close().await;
+        drop(first);
+        drop(hub);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn closed_session_fails_feeds() -> Result<()> {
+        let session = served_session().await?;
+        let hub = FeedHub::new(session.clone())?;
+        let mut feed = hub.subscribe::<u64>(&r.table("posts").get(1).changes(()))?;
+
+        // the server holds the `CONTINUE` of the feed
+        while session.inner.queued.is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        session.close(false).await?;
+        let item = tokio::time::timeout(Duration::from_secs(5), feed.try_next())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            item,
+            Err(ReqlError::Driver(ReqlDriverError::ConnectionClosed))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn hub_outside_runtime() {
+        let session = ConnectionCommand::default().session(TcpStreamConnection {
+            stream: std::net::TcpListener::bind("127.0.0.1:0")
+                .and_then(|listener| std::net::TcpStream::connect(listener.local_addr()?))
+                .and_then(TcpStream::try_from)
+                .unwrap(),
+            tls_stream: None,
+            server_version: String::new(),
+        });
+
+        assert!(FeedHub::new(session).is_err());
+    }
+}
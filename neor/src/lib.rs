@@ -1,27 +1,49 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-pub use neor_macros::{func, Geometry};
+pub use neor_macros::{func, path, sel, Fields, Geometry, ReqlModel};
 use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
 
 use arguments::Permission;
 use err::ReqlError;
 use types::{Binary, DateTime, GeoJson};
 
 pub use cmd::func::Func;
-pub use command_tools::CommandArg;
+pub use command_tools::{CommandArg, IntoTerm};
 pub use connection::*;
 pub use proto::Command;
 
+#[macro_use]
 mod command_tools;
 mod constants;
+mod printer;
 mod proto;
 
+pub mod advisor;
 pub mod arguments;
 pub mod cmd;
 pub mod connection;
+pub mod dump;
 pub mod err;
+pub mod handshake;
+pub mod hub;
+pub mod migrate;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod observer;
+pub mod partitions;
+pub mod retry;
+pub mod saga;
+pub mod schema;
+#[doc(hidden)]
+pub mod sel;
+#[cfg(feature = "tower")]
+pub mod service;
+pub mod system;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod tls;
 pub mod types;
 
 pub type Result<T> = std::result::Result<T, ReqlError>;
@@ -737,8 +759,8 @@ impl r {
     /// - [concat_map](crate::Command::concat_map)
     /// - [reduce](Self::reduce)
     /// - [do_](Self::do_)
-    pub fn map(&self, sequence: Command, args: impl cmd::map::MapArg) -> Command {
-        sequence.map(args)
+    pub fn map(&self, sequence: impl Into<CommandArg>, args: impl cmd::map::MapArg) -> Command {
+        sequence.into().to_cmd().map(args)
     }
 
     /// Merge two or more sequences.
@@ -746,14 +768,11 @@ impl r {
     /// # Command syntax
     ///
     /// ```text
-    /// stream.union(sequence) → stream
-    /// stream.union(sequences) → stream
-    /// stream.union(args!(sequence, options)) → stream
-    /// stream.union(args!(sequences, options)) → stream
+    /// r.union(sequences) → stream
+    /// r.union(args!(sequences, options)) → stream
     /// ```
     ///
     /// Where:
-    /// - sequence: [Command](crate::Command)
     /// - sequences: `impl IntoIterator<Command>`
     /// - options: [UnionOption](crate::arguments::UnionOption)
     ///
@@ -766,8 +785,7 @@ impl r {
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response = r.table("simbad")
-    ///         .union(r.table("kirikou"))
+    ///     let response = r.union([r.table("simbad"), r.table("kirikou"), r.table("zemba")])
     ///         .run(&conn)
     ///         .await?;
     ///
@@ -776,8 +794,39 @@ impl r {
     ///     Ok(())
     /// }
     /// ```
-    pub fn union(&self, stream: Command, args: impl cmd::union::UnionArg) -> Command {
-        stream.union(args)
+    ///
+    /// ## Examples
+    ///
+    /// Merge the changes of two tables, ordered by the time of the documents.
+    ///
+    /// ```
+    /// use futures::TryStreamExt;
+    /// use neor::arguments::{Interleave, UnionOption};
+    /// use neor::{args, func, r, Result};
+    /// use serde_json::Value;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let opts = UnionOption::default()
+    ///         .interleave(Interleave::Func(func!(|change| change.g("new_val").g("time"))));
+    ///     let mut feed = r.union(args!([
+    ///             r.table("simbad").changes(()),
+    ///             r.table("kirikou").changes(()),
+    ///         ], opts))
+    ///         .run_changes::<Value>(&conn);
+    ///
+    ///     while let Some(item) = feed.try_next().await? {
+    ///         println!("{:?}", item);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [union](crate::Command::union)
+    pub fn union(&self, args: impl cmd::union::UnionArg) -> Command {
+        cmd::union::new(args)
     }
 
     /// Takes a stream and partitions it into multiple
@@ -794,13 +843,15 @@ impl r {
     /// r.group(sequence, func) → grouped_stream
     /// r.group(sequence, args!(field, options)) → grouped_stream
     /// r.group(sequence, args!(func, options)) → grouped_stream
+    /// r.group(sequence, options) → grouped_stream
     /// ```
     ///
     /// Where:
-    /// - field: &str | [&str; N]
+    /// - field: &str | [&str; N] | [Command](crate::Command) | [Command; N]
     /// - func: func!(...) | [func!(...); N]
+    /// - options: [GroupOption](crate::arguments::GroupOption), grouping by its `index` alone
     /// - grouped_stream: [GroupedStream](crate::types::GroupedStream)
-    /// - sequence: [Command](crate::Command)
+    /// - sequence: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -890,8 +941,12 @@ impl r {
     /// - [avg](Self::avg)
     /// - [min](Self::min)
     /// - [max](Self::max)
-    pub fn group(&self, sequence: Command, args: impl cmd::group::GroupArg) -> Command {
-        sequence.group(args)
+    pub fn group(
+        &self,
+        sequence: impl Into<CommandArg>,
+        args: impl cmd::group::GroupArg,
+    ) -> Command {
+        sequence.into().to_cmd().group(args)
     }
 
     /// Produce a single value from a sequence through
@@ -906,7 +961,7 @@ impl r {
     /// Where:
     /// - base: `impl Serialize` | [Command](crate::Command)
     /// - func: [Func](crate::Func)
-    /// - sequence: [Command](crate::Command)
+    /// - sequence: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -988,8 +1043,8 @@ impl r {
     /// - [avg](Self::avg)
     /// - [min](Self::min)
     /// - [max](Self::max)
-    pub fn reduce(&self, sequence: Command, func: Func) -> Command {
-        sequence.reduce(func)
+    pub fn reduce(&self, sequence: impl Into<CommandArg>, func: Func) -> Command {
+        sequence.into().to_cmd().reduce(func)
     }
 
     /// Count the number of elements in sequence or key/value pairs in an object,
@@ -1012,7 +1067,7 @@ impl r {
     /// Where:
     /// - value: `impl Serialize`
     /// - func: [Func](crate::Func)
-    /// - sequence, binary, string, object, query_cmd: [Command](crate::Command)
+    /// - sequence, binary, string, object, query_cmd: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -1137,8 +1192,8 @@ impl r {
     /// - [min](Self::min)
     /// - [max](Self::max)
     /// - [group](crate::Command::group)
-    pub fn count(&self, query: Command, args: impl cmd::count::CountArg) -> Command {
-        query.count(args)
+    pub fn count(&self, query: impl Into<CommandArg>, args: impl cmd::count::CountArg) -> Command {
+        query.into().to_cmd().count(args)
     }
 
     /// Sum all the elements of sequence.
@@ -1157,7 +1212,7 @@ impl r {
     /// Where:
     /// - field: `&str` | `String` | [Command](crate::Command)
     /// - func: [Func](crate::Func)
-    /// - sequence: [Command](crate::Command)
+    /// - sequence: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -1239,8 +1294,8 @@ impl r {
     /// - [min](Self::min)
     /// - [max](Self::max)
     /// - [group](crate::Command::group)
-    pub fn sum(&self, sequence: Command, args: impl cmd::sum::SumArg) -> Command {
-        sequence.sum(args)
+    pub fn sum(&self, sequence: impl Into<CommandArg>, args: impl cmd::sum::SumArg) -> Command {
+        sequence.into().to_cmd().sum(args)
     }
 
     /// Averages all the elements of sequence.
@@ -1259,7 +1314,7 @@ impl r {
     /// Where:
     /// - field: `&str` | `String` | [Command](crate::Command)
     /// - func: [Func](crate::Func)
-    /// - sequence: [Command](crate::Command)
+    /// - sequence: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -1342,8 +1397,8 @@ impl r {
     /// - [min](Self::min)
     /// - [max](Self::max)
     /// - [group](crate::Command::group)
-    pub fn avg(&self, sequence: Command, args: impl cmd::avg::AvgArg) -> Command {
-        sequence.avg(args)
+    pub fn avg(&self, sequence: impl Into<CommandArg>, args: impl cmd::avg::AvgArg) -> Command {
+        sequence.into().to_cmd().avg(args)
     }
 
     /// Finds the minimum element of a sequence.
@@ -1365,7 +1420,7 @@ impl r {
     /// - field: `&str` | `String` | [Command](crate::Command)
     /// - func: [Func](crate::Func)
     /// - options: [MinOption](crate::arguments::MinOption)
-    /// - sequence: [Command](crate::Command)
+    /// - sequence: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -1500,8 +1555,8 @@ impl r {
     /// - [avg](Self::avg)
     /// - [max](Self::max)
     /// - [group](crate::Command::group)
-    pub fn min(&self, sequence: Command, args: impl cmd::min::MinArg) -> Command {
-        sequence.min(args)
+    pub fn min(&self, sequence: impl Into<CommandArg>, args: impl cmd::min::MinArg) -> Command {
+        sequence.into().to_cmd().min(args)
     }
 
     /// Finds the maximum element of a sequence.
@@ -1523,7 +1578,7 @@ impl r {
     /// - field: &str, String, Cow<'static, str>
     /// - func: func!(...)
     /// - options: [MaxOption](crate::arguments::MaxOption)
-    /// - sequence: [Command](crate::Command)
+    /// - sequence: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -1658,8 +1713,8 @@ impl r {
     /// - [avg](Self::avg)
     /// - [min](Self::min)
     /// - [group](crate::Command::group)
-    pub fn max(&self, sequence: Command, args: impl cmd::max::MaxArg) -> Command {
-        sequence.max(args)
+    pub fn max(&self, sequence: impl Into<CommandArg>, args: impl cmd::max::MaxArg) -> Command {
+        sequence.into().to_cmd().max(args)
     }
 
     /// Removes duplicate elements from a sequence.
@@ -1675,7 +1730,7 @@ impl r {
     ///
     /// Where:
     /// - options: [DistinctOption](crate::arguments::DistinctOption)
-    /// - sequence: [Command](crate::Command)
+    /// - sequence: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -1759,10 +1814,10 @@ impl r {
     /// - [group](crate::Command::group)
     pub fn distinct(
         &self,
-        seq_or_table: Command,
+        seq_or_table: impl Into<CommandArg>,
         args: impl cmd::distinct::DistinctArg,
     ) -> Command {
-        seq_or_table.distinct(args)
+        seq_or_table.into().to_cmd().distinct(args)
     }
 
     /// When called with values, returns `true`
@@ -1780,7 +1835,7 @@ impl r {
     /// Where:
     /// - value: `impl Serialize` | [Command](crate::Command) | [Func](crate::Func)
     /// - values: `impl IntoIterator<Item = T>` | `impl IntoIterator<Item = Command>`
-    /// - sequence: [Command](crate::Command)
+    /// - sequence: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -1889,12 +1944,64 @@ impl r {
     /// - [map](Self::map)
     /// - [concat_map](crate::Command::concat_map)
     /// - [group](crate::Command::group)
-    pub fn contains(&self, sequence: Command, value: impl Into<CommandArg>) -> Command {
-        sequence.contains(value)
+    pub fn contains(
+        &self,
+        sequence: impl Into<CommandArg>,
+        args: impl cmd::contains::ContainsArg,
+    ) -> Command {
+        sequence.into().to_cmd().contains(args)
     }
 
-    /// TODO Write docs
-    #[doc(hidden)]
+    /// Replace an object in a field instead of merging it
+    /// with an existing object in a `merge` or `update` operation.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// r.literal(object) → special
+    /// ```
+    ///
+    /// Where:
+    /// - object: `impl Serialize` | [Command](crate::Command)
+    ///
+    /// # Description
+    ///
+    /// `literal` is only legal as the object passed to [merge](crate::Command::merge)
+    /// or [update](crate::Command::update), or as a value of such an object built
+    /// with [hash_map](Self::hash_map) and string keys. It cannot nest inside another `literal`.
+    /// In debug builds, the driver rejects a query using `literal` elsewhere
+    /// before sending it.
+    ///
+    /// ## Examples
+    ///
+    /// Replace one nested document with another rather than merging the fields.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::{r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let mut user = HashMap::new();
+    ///     user.insert("data", r.literal(json!({"age": 19, "job": "Engineer"})));
+    ///
+    ///     let response = r.table("users")
+    ///         .get(1)
+    ///         .update(r.hash_map(user))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [merge](crate::Command::merge)
+    /// - [update](crate::Command::update)
     pub fn literal(&self, value: impl Into<CommandArg>) -> Command {
         cmd::literal::new(value)
     }
@@ -2550,11 +2657,14 @@ impl r {
     /// ```text
     /// !cmd_bool
     /// cmd_bool.not() → bool
+    /// cmd_bool.not_() → bool
     /// r.not(cmd_bool) → bool
+    /// r.not_(value) → bool
     /// ```
     ///
     /// Where:
     /// - cmd_bool: [Command](crate::Command)
+    /// - value: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -2610,6 +2720,32 @@ impl r {
         !cmd_bool
     }
 
+    /// Compute the logical inverse (not) of a value or an expression.
+    ///
+    /// See [not](Self::not) for more information.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response: bool = r.not_(false).run(&conn).await?.unwrap().parse()?;
+    ///
+    ///     assert!(response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [not](Self::not)
+    /// - [not_](crate::Command::not_)
+    pub fn not_(&self, value: impl Into<CommandArg>) -> Command {
+        value.into().to_cmd().not_()
+    }
+
     /// Generate a random number between given (or implied) bounds.
     ///
     /// # Command syntax
@@ -2654,13 +2790,13 @@ impl r {
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.random(())
+    ///     let response: f64 = r.random(())
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 3);
+    ///     assert!((0. ..1.).contains(&response));
     ///     
     ///     Ok(())
     /// }
@@ -2675,13 +2811,13 @@ impl r {
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
-    ///     let response: u8 = r.random(100.)
+    ///     let response: u8 = r.random(100)
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 3);
+    ///     assert!(response < 100);
     ///     
     ///     Ok(())
     /// }
@@ -2706,7 +2842,7 @@ impl r {
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 0.);
+    ///     assert!(response > -2.24 && response <= 1.59);
     ///     
     ///     Ok(())
     /// }
@@ -2728,7 +2864,7 @@ impl r {
     /// ```
     ///
     /// Where:
-    /// - param_number: `f32` | `f64` | [Command](crate::Command)
+    /// - param_number: `impl Serialize` | [Command](crate::Command)
     ///
     /// # Description
     ///
@@ -2784,8 +2920,8 @@ impl r {
     /// # Related commands
     /// - [ceil](Self::ceil)
     /// - [round](Self::round)
-    pub fn round(&self, args: impl cmd::round::RoundArg) -> Command {
-        cmd::round::new(args)
+    pub fn round(&self, number: impl Into<CommandArg>) -> Command {
+        number.into().to_cmd().round()
     }
 
     /// Rounds the given value up, returning the smallest integer value
@@ -2799,7 +2935,7 @@ impl r {
     /// ```
     ///
     /// Where:
-    /// - param_number: `f32` | `f64` | [Command](crate::Command)
+    /// - param_number: `impl Serialize` | [Command](crate::Command)
     ///
     /// ## Examples
     ///
@@ -2849,8 +2985,8 @@ impl r {
     /// # Related commands
     /// - [floor](Self::floor)
     /// - [round](Self::round)
-    pub fn ceil(&self, args: impl cmd::ceil::CeilArg) -> Command {
-        cmd::ceil::new(args)
+    pub fn ceil(&self, number: impl Into<CommandArg>) -> Command {
+        number.into().to_cmd().ceil()
     }
 
     /// Rounds the given value down, returning the largest integer
@@ -2864,7 +3000,7 @@ impl r {
     /// ```
     ///
     /// Where:
-    /// - param_number: `f32` | `f64` | [Command](crate::Command)
+    /// - param_number: `impl Serialize` | [Command](crate::Command)
     ///
     /// ## Examples
     ///
@@ -2881,7 +3017,7 @@ impl r {
     ///         .unwrap()
     ///         .parse()?;
     ///
-    ///     assert!(response == 13);
+    ///     assert!(response == 12);
     ///     
     ///     Ok(())
     /// }
@@ -2914,8 +3050,8 @@ impl r {
     /// # Related commands
     /// - [ceil](Self::ceil)
     /// - [round](Self::round)
-    pub fn floor(&self, args: impl cmd::floor::FloorArg) -> Command {
-        cmd::floor::new(args)
+    pub fn floor(&self, number: impl Into<CommandArg>) -> Command {
+        number.into().to_cmd().floor()
     }
 
     /// Compute the arithmetic "and" of one or more values.
@@ -3418,12 +3554,14 @@ impl r {
     /// ```text
     /// r.time(args!(date, timezone)) → time
     /// r.time(args!(date, time_, timezone)) → time
+    /// r.time(offset_datetime) → time
     /// ```
     ///
     /// Where:
     /// - date: [time::Date](time::Date)
     /// - time_: [time::Time](time::Time)
     /// - timezone: [time::UtcOffset](time::UtcOffset)
+    /// - offset_datetime: [time::OffsetDateTime](time::OffsetDateTime)
     /// - time: [Time](crate::types::Time)
     ///
     /// ## Examples
@@ -3455,6 +3593,33 @@ impl r {
     /// }
     /// ```
     ///
+    /// ## Examples
+    ///
+    /// Create a time from an `OffsetDateTime` and read it back.
+    ///
+    /// ```
+    /// use neor::types::DateTime;
+    /// use neor::{r, Converter, Result};
+    /// use time::macros::datetime;
+    /// use time::OffsetDateTime;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let offset_datetime = datetime!(1986-11-03 09:30:40 +01:00);
+    ///
+    ///     let response: DateTime = r.time(offset_datetime)
+    ///         .cmd()
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(OffsetDateTime::from(response) == offset_datetime);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
     /// # Related commands
     /// - [now](Self::now)
     /// - [time](Self::time)
@@ -3611,13 +3776,15 @@ impl r {
     /// # Command syntax
     ///
     /// ```text
-    /// r.hash_map(value) -> number
+    /// r.hash_map(value) -> object
+    /// r.hash_map(args!(document, value)) -> object
     /// ```
     ///
     /// Where:
-    /// - value: `HashMap<Key, Value>`
+    /// - value: `HashMap<Key, Value>` | `BTreeMap<Key, Value>` | `Vec<(Key, Value)>` | `[(Key, Value); N]`
     /// - Key: `impl Into<String>` | [Command](crate::Command)
     /// - Value: `impl Into<Serialize>` | [Command](crate::Command)
+    /// - document: `impl Serialize`, a struct or a map completed by the entries of `value`
     ///
     /// ## Examples
     ///
@@ -3651,13 +3818,33 @@ impl r {
     /// }
     /// ```
     ///
+    /// ## Examples
+    ///
+    /// Complete a document with the result of a subquery.
+    ///
+    /// ```
+    /// use neor::{args, r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let post = json!({"id": 1, "title": "post 1"});
+    ///     let comments = r.table("comments").filter(json!({"post_id": 1})).count(());
+    ///
+    ///     let response = r.table("posts")
+    ///         .insert(r.hash_map(args!(post, [("comments", comments)])))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
     /// # Related commands
     /// - [array](Self::array)
-    pub fn hash_map<K, V>(&self, value: HashMap<K, V>) -> Command
-    where
-        K: Into<CommandArg>,
-        V: Into<CommandArg>,
-    {
+    pub fn hash_map(&self, value: impl cmd::hash_map::HashMapArg) -> Command {
         cmd::hash_map::new(value)
     }
 
@@ -3670,7 +3857,7 @@ impl r {
     /// r.args(array) → special
     /// ```
     /// Where:
-    /// - array: `IntoIterator<Item = impl Into<String>>`
+    /// - array: `IntoIterator<Item = impl Into<CommandArg>>`
     ///
     /// # Description
     ///
@@ -3703,10 +3890,36 @@ impl r {
     ///     Ok(())
     /// }
     /// ```
-    pub fn args<T, S>(&self, values: T) -> Command
+    ///
+    /// ## Examples
+    ///
+    /// Get the posts of keys known at runtime, some of them computed by the server.
+    ///
+    /// ```
+    /// use neor::{r, Command, Converter, Result};
+    /// use serde_json::Value;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let mut keys: Vec<Command> = vec![r.expr(1), r.expr(2)];
+    ///     keys.push(r.table("authors").get("william").g("last_post"));
+    ///
+    ///     let response: Vec<Value> = r.table("posts")
+    ///         .get_all(r.args(keys))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.len() <= 3);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn args<S, T>(&self, values: T) -> Command
     where
-        S: Serialize,
-        T: IntoIterator<Item = S> + Serialize,
+        S: Into<CommandArg>,
+        T: IntoIterator<Item = S>,
     {
         cmd::args::new(values)
     }
@@ -3784,6 +3997,34 @@ impl r {
     /// }
     /// ```
     ///
+    /// ## Examples
+    ///
+    /// Read an avatar image back as bytes.
+    ///
+    /// ```
+    /// use neor::types::Binary;
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     let response: Binary = r.table("images")
+    ///         .get(100)
+    ///         .g("avatar")
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     std::fs::write("avatar.png", response.bytes()?)?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Use [as_binary](crate::types::as_binary) to store a `Vec<u8>` field
+    /// of a document as a binary object.
+    ///
     /// Read more details about RethinkDB’s binary object support:
     /// [Storing binary objects]("https://rethinkdb.com/docs/storing-binary/python/").
     pub fn binary(&self, data: &[u8]) -> Binary {
@@ -4058,6 +4299,8 @@ impl r {
     /// r.range(()) → stream
     /// r.range(end_value) → stream
     /// r.range(args!(start_value, end_value)) → stream
+    /// r.range(start_value..end_value) → stream
+    /// r.range(..) → stream
     /// ```
     ///
     /// Where
@@ -4073,6 +4316,9 @@ impl r {
     /// - With two arguments, `range` returns a stream from
     /// the start value up to but not including the end value.
     ///
+    /// The Rust range `start_value..end_value` is the two-argument form,
+    /// with the same bounds, and `..` is the no-argument form.
+    ///
     /// Note that the left bound (including the implied left
     /// bound of 0 in the 0- and 1-argument form)
     /// is always closed and the right bound is always open:
@@ -4149,26 +4395,272 @@ impl r {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Write the same range with the Rust syntax.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     let response: Vec<i8> = r.range(-5..6)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == (-5..6).collect::<Vec<_>>());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn range(&self, args: impl cmd::range::RangeArg) -> Command {
         cmd::range::new(args)
     }
 
+    /// Construct a ReQL term from its term type, arguments and options.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// r.raw_term(term_type, args, opts) → any
+    /// ```
+    ///
+    /// Where:
+    /// - term_type: i32
+    /// - args: `impl IntoIterator<Item = impl Serialize | Command>`
+    /// - opts: `Option<Value>`
+    ///
+    /// # Description
+    ///
+    /// This is an escape hatch for the terms not wrapped by the driver yet.
+    /// `term_type` is the number of the term in the ReQL protocol.
+    /// The arguments are converted like the argument of [expr](Self::expr),
+    /// so they can be values or other queries.
+    ///
+    /// The server checks the term when running the query,
+    /// the driver does not. The term and its arguments are sent as built,
+    /// so the [table prefix](crate::cmd::connect::ConnectionCommand::table_prefix)
+    /// of the session does not apply to it.
+    ///
+    /// ## Examples
+    ///
+    /// Add two numbers with the `ADD` term (24).
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     let response: u8 = r.raw_term(24, [r.expr(2), r.expr(3)], None)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == 5);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Give options to the term, here `TABLE` (15) with a `read_mode`.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::{json, Value};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let table = r.raw_term(15, ["posts"], Some(json!({"read_mode": "outdated"})));
+    ///
+    ///     let response: Vec<Value> = table
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.iter().all(|post| post.get("id").is_some()));
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [raw](Self::raw)
+    /// - [expr](Self::expr)
+    pub fn raw_term<T>(&self, term_type: i32, args: T, opts: Option<Value>) -> Command
+    where
+        T: IntoIterator,
+        T::Item: Into<CommandArg>,
+    {
+        cmd::raw::term(term_type, args, opts)
+    }
+
+    /// Placeholder of a parameter of a prepared query.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// r.param(name) → any
+    /// ```
+    ///
+    /// Where:
+    /// - name: &str, String
+    ///
+    /// # Description
+    ///
+    /// The placeholder is replaced by the value of the parameter
+    /// each time the query prepared with [prepare](crate::Command::prepare)
+    /// is run. A query with placeholders can only be run once prepared.
+    ///
+    /// ## Examples
+    ///
+    /// Get a user by id.
+    ///
+    /// ```
+    /// use neor::{r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let user = r.table("users").get(r.param("id")).prepare()?;
+    ///
+    ///     user.run(&conn, json!({"id": 1})).await?;
+    ///     user.run(&conn, json!({"id": 2})).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [prepare](crate::Command::prepare)
+    pub fn param(&self, name: impl Into<String>) -> Command {
+        cmd::prepare::param(name.into())
+    }
+
+    /// The document a command is evaluating, as an implicit function parameter.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// r.row() → any
+    /// ```
+    ///
+    /// # Description
+    ///
+    /// An argument using `r.row()` is sent as a function of one parameter
+    /// standing for the document, when it is given to commands taking a function
+    /// such as [filter](crate::Command::filter), [map](crate::Command::map),
+    /// [update](crate::Command::update) or [order_by](crate::Command::order_by).
+    ///
+    /// `r.row()` always refers to the document of the outermost of these commands,
+    /// so nested queries need [func](crate::func) to name their parameters.
+    /// The predicates of [inner_join](crate::Command::inner_join) and
+    /// [outer_join](crate::Command::outer_join) take two documents and
+    /// cannot use `r.row()`.
+    ///
+    /// ## Examples
+    ///
+    /// Get the users older than 18.
+    ///
+    /// ```
+    /// use neor::{r, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let response = r.table("users")
+    ///         .filter(r.row().g("age").gt(18))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     assert!(response.is_some());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [func](crate::func)
+    pub fn row(&self) -> Command {
+        Command::new(ql2::term::TermType::ImplicitVar)
+    }
+
+    /// Construct a ReQL query from its JSON wire format.
+    ///
+    /// # Command syntax
+    ///
+    /// ```text
+    /// r.raw(term) → any
+    /// ```
+    ///
+    /// Where:
+    /// - term: Value
+    ///
+    /// # Description
+    ///
+    /// The JSON is sent to the server as is: arrays are terms
+    /// `[term_type, [args...], {opts}]`, so a literal array
+    /// must be wrapped in a `MAKE_ARRAY` (2) term.
+    /// See [raw_term](Self::raw_term) to build one term from queries.
+    ///
+    /// ## Examples
+    ///
+    /// Add the elements of an array with `ADD` (24) and `ARGS` (154).
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     let response: u8 = r.raw(json!([24, [[154, [[2, [1, 2, 3]]]]]]))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == 6);
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [raw_term](Self::raw_term)
+    pub fn raw(&self, term: Value) -> Command {
+        cmd::raw::new(term)
+    }
+
     /// Throw a runtime error.
     ///
     /// # Command syntax
     ///
     /// ```text
     /// r.error(message) → value
+    /// r.error(user_error) → value
     /// ```
     ///
     /// Where:
     /// - message: `impl Into<String>` | [Command](crate::Command)
+    /// - user_error: [UserError](crate::err::UserError)
     ///
     /// # Description
     ///
     /// If called with no arguments inside the second
     /// argument to default, re-throw the current error.
     ///
+    /// A [UserError](crate::err::UserError) carries a structured payload,
+    /// JSON encoded in the error message, that can be decoded
+    /// on the client with [user_error](crate::err::ReqlError::user_error).
+    ///
     /// ## Examples
     ///
     /// Get Error
@@ -4196,6 +4688,40 @@ impl r {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Reject an invalid document with a structured validation error.
+    ///
+    /// ```
+    /// use neor::err::UserError;
+    /// use neor::{r, Result};
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct ValidationError {
+    ///     field: String,
+    ///     reason: String,
+    /// }
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let payload = json!({"field": "age", "reason": "must be positive"});
+    ///
+    ///     let err = r.error(UserError::new(payload))
+    ///         .run(&conn)
+    ///         .await
+    ///         .err()
+    ///         .unwrap();
+    ///     let err = err.user_error::<ValidationError>().unwrap();
+    ///
+    ///     assert!(err.payload.field == "age");
+    ///     assert!(err.payload.reason == "must be positive");
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn error(&self, message: impl Into<CommandArg>) -> Command {
         cmd::error::new(message)
     }
@@ -4217,6 +4743,10 @@ impl r {
     /// then expr will return a binary object.
     /// See [binary](Self::binary) for more information.
     ///
+    /// Commands held in a `Vec`, an array, a tuple, an `Option` or a map
+    /// are sent with [into_term](crate::IntoTerm::into_term), which builds
+    /// the arrays and objects holding them as terms evaluated by the server.
+    ///
     /// ## Examples
     ///
     /// Objects wrapped with `expr` can then be manipulated by ReQL API functions.
@@ -4239,6 +4769,36 @@ impl r {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Build a document whose fields are computed by the server.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use neor::{r, Converter, IntoTerm, Result};
+    /// use serde_json::Value;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let report = HashMap::from([
+    ///         ("title", r.expr("posts")),
+    ///         ("counts", vec![("all", r.table("posts").count(()))].into_term()),
+    ///         ("created_at", Some(r.now().cmd()).into_term()),
+    ///     ]);
+    ///
+    ///     let response: Value = r.expr(report.into_term())
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response["counts"][0][1].is_u64());
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn expr(&self, value: impl Into<CommandArg>) -> Command {
         cmd::expr::new(value)
     }
@@ -4349,6 +4909,30 @@ impl r {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Get information about a value.
+    ///
+    /// ```
+    /// use neor::types::{InfoResponse, TypeOf};
+    /// use neor::{r, Converter, Result};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///
+    ///     let response: InfoResponse = r.info(r.expr(1))
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response.typ == TypeOf::Number);
+    ///     assert!(response.value == Some("1".into()));
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn info(&self, any: Command) -> Command {
         any.info()
     }
@@ -4385,6 +4969,34 @@ impl r {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// ## Examples
+    ///
+    /// Parse a JSON string computed by another query.
+    ///
+    /// ```
+    /// use neor::{r, Converter, Result};
+    /// use serde_json::{json, Value};
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let json_string = r.expr(json!({"name": "Moussa"})).to_json();
+    ///
+    ///     let response: Value = r.json(json_string)
+    ///         .run(&conn)
+    ///         .await?
+    ///         .unwrap()
+    ///         .parse()?;
+    ///
+    ///     assert!(response == json!({"name": "Moussa"}));
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Related commands
+    /// - [to_json](crate::Command::to_json)
+    /// - [http](Self::http)
     pub fn json(&self, value: impl Into<CommandArg>) -> Command {
         cmd::json::new(value)
     }
@@ -4668,8 +5280,8 @@ impl r {
     /// ```text
     /// geometry.distance(geometry) → f64
     /// geometry.distance(args!(geometry, options)) → f64
-    /// r.distance(geometry.cmd(), geometry) → f64
-    /// r.distance(geometry.cmd(), args!(geometry, options)) → f64
+    /// r.distance(geometry, geometry) → f64
+    /// r.distance(geometry, args!(geometry, options)) → f64
     /// ```
     ///
     /// Where:
@@ -4689,13 +5301,16 @@ impl r {
     ///  WGS84 model, the results of `distance` should be considered approximate
     /// due to the deviation between the ellipsoid and spherical models.
     ///
+    /// The distance is returned in meters unless `unit` is set,
+    /// [Unit::convert](crate::types::Unit::convert) converts it afterwards.
+    ///
     /// ## Examples
     ///
     /// Compute the distance between two points on the Earth in kilometers.
     ///
     /// ```
     /// use neor::arguments::{DistanceOption, Unit};
-    /// use neor::{args, r, Converter, Geometry, Result};
+    /// use neor::{args, r, Converter, Result};
     ///
     /// async fn example() -> Result<()> {
     ///     let conn = r.connection().connect().await?;
@@ -4703,7 +5318,8 @@ impl r {
     ///     let point2 = r.point(-117.220406, 32.719464);
     ///     let distance_option = DistanceOption::default().unit(Unit::Kilometer);
     ///
-    ///     let response: f64 = r.distance(point1.cmd(), point2)
+    ///     let response: f64 = r
+    ///         .distance(point1, args!(point2, distance_option))
     ///         .run(&conn)
     ///         .await?
     ///         .unwrap()
@@ -4718,8 +5334,12 @@ impl r {
     /// # Related commands
     /// - [polygon](crate::r::polygon)
     /// - [line](crate::r::line)
-    pub fn distance(&self, geometry: Command, args: impl cmd::distance::DistanceArg) -> Command {
-        geometry.distance(args)
+    pub fn distance(
+        &self,
+        geometry: impl Into<Command>,
+        args: impl cmd::distance::DistanceArg,
+    ) -> Command {
+        geometry.into().distance(args)
     }
 
     /// Convert a [GeoJSON](https://geojson.org/) object to a ReQL geometry object.
@@ -4793,17 +5413,22 @@ impl r {
     /// ```
     ///
     /// Where:
-    /// - points: &[[Point](crate::types::Point)]
-    /// - line: [Polygon](crate::types::Line)
+    /// - points: `&[Point]` | `[(f64, f64); N]` | `Vec<[f64; 2]>` | `impl IntoIterator<Item = impl Into<Point>>`
+    /// - line: [Line](crate::types::Line)
     ///
     /// # Description
     ///
     /// The line can be specified in one of two ways:
-    /// - Two or more two-item arrays, specifying latitude
-    /// and longitude numbers of the line’s vertices;
+    /// - Two or more `(longitude, latitude)` tuples or two-item arrays,
+    /// specifying the coordinates of the line’s vertices;
     /// - Two or more [Point](crate::types::Point)
     /// objects specifying the line’s vertices.
     ///
+    /// With less than two points, `line` panics; use
+    /// [Line::try_new](crate::types::Line::try_new) to get an error instead.
+    /// A line can also be converted from a [GeoJson](crate::types::GeoJson)
+    /// LineString, or from a `geo_types::LineString` with the `geo` feature.
+    ///
     /// Longitude (−180 to 180) and latitude (−90 to 90)
     /// of vertices are plotted on a perfect sphere.
     /// See [Geospatial support](https://rethinkdb.com/docs/geo-support/python/)
@@ -4837,11 +5462,35 @@ impl r {
     /// }
     /// ```
     ///
+    /// ## Examples
+    ///
+    /// Define a line from `(longitude, latitude)` tuples.
+    ///
+    /// ```
+    /// use neor::{r, Result};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let conn = r.connection().connect().await?;
+    ///     let route = r.line([(-122.423246, 37.779388), (-121.886420, 37.329898)]);
+    ///
+    ///     r.table("geo")
+    ///         .insert(json!({ "id": 102, "route": route }))
+    ///         .run(&conn)
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
     /// # Related commands
     /// - [point](Self::point)
     /// - [polygon](Self::polygon)
     /// - [circle](Self::circle)
-    pub fn line(&self, points: &[cmd::point::Point]) -> cmd::line::Line {
+    pub fn line<P>(&self, points: impl IntoIterator<Item = P>) -> cmd::line::Line
+    where
+        P: Into<cmd::point::Point>,
+    {
         cmd::line::Line::new(points)
     }
 
@@ -4908,17 +5557,23 @@ impl r {
     /// ```
     ///
     /// Where:
-    /// - points: &[[Point](crate::types::Point)]
+    /// - points: `&[Point]` | `[(f64, f64); N]` | `Vec<[f64; 2]>` | `impl IntoIterator<Item = impl Into<Point>>`
     /// - polygon: [Polygon](crate::types::Polygon)
     ///
     /// # Description
     ///
     /// The Polygon can be specified in one of two ways:
-    /// - Three or more two-item arrays, specifying latitude
-    /// and longitude numbers of the polygon’s vertices;
+    /// - Three or more `(longitude, latitude)` tuples or two-item arrays,
+    /// specifying the coordinates of the polygon’s vertices;
     /// - Three or more [Point](crate::types::Point)
     /// objects specifying the polygon’s vertices.
     ///
+    /// With less than three vertices, `polygon` panics; use
+    /// [Polygon::try_new](crate::types::Polygon::try_new) to get an error instead.
+    /// A polygon, holes included, can also be converted from a
+    /// [GeoJson](crate::types::GeoJson) Polygon, whose rings must be closed,
+    /// or from a `geo_types::Polygon` with the `geo` feature.
+    ///
     /// Longitude (−180 to 180) and latitude (−90 to 90)
     /// of vertices are plotted on a perfect sphere.
     /// See [Geospatial support](https://rethinkdb.com/docs/geo-support/python/)
@@ -4964,7 +5619,10 @@ impl r {
     /// - [point](Self::point)
     /// - [line](Self::line)
     /// - [circle](Self::circle)
-    pub fn polygon(&self, points: &[cmd::point::Point]) -> cmd::polygon::Polygon {
+    pub fn polygon<P>(&self, points: impl IntoIterator<Item = P>) -> cmd::polygon::Polygon
+    where
+        P: Into<cmd::point::Point>,
+    {
         cmd::polygon::Polygon::new(points)
     }
 
@@ -5282,7 +5940,7 @@ impl r {
         cmd::wait::new(args)
     }
 
-    /// To specify the descending ordering.
+    /// To specify the ascending ordering.
     ///
     /// # Command syntax
     /// ```text
@@ -5461,8 +6119,10 @@ pub trait Converter {
 }
 
 impl Converter for serde_json::Value {
+    /// Read the value as `T`, converting the TIME pseudo-types
+    /// of its time fields, see [reql_time](types::reql_time)
     fn parse<T: Unpin + Serialize + DeserializeOwned>(self) -> Result<T> {
-        Ok(serde_json::from_value(self)?)
+        Ok(types::reql_time::from_value(self)?)
     }
 }
 
@@ -5470,6 +6130,12 @@ pub trait Geometry: Into<Command> {
     fn cmd(self) -> Command {
         self.into()
     }
+
+    /// Shortcut for `self.cmd().distance(args)`,
+    /// see [distance](crate::Command::distance)
+    fn distance(self, args: impl cmd::distance::DistanceArg) -> Command {
+        self.cmd().distance(args)
+    }
 }
 
 pub trait StaticString {
@@ -0,0 +1,304 @@
+//! Apply ordered schema migrations to a database
+//!
+//! Each [Migration] has a version and a list of steps (table or index
+//! creation, document transformations...). The applied versions are
+//! recorded in the `__migrations` table of the database, so running the
+//! same [Migrator] again only applies the new migrations.
+//!
+//! ```
+//! use neor::migrate::{Migration, Migrator};
+//! use neor::{func, r, Result};
+//!
+//! async fn example() -> Result<()> {
+//!     let conn = r.connection().connect().await?;
+//!     let applied = Migrator::new("blog")
+//!         .migration(
+//!             Migration::new(1, "create users")
+//!                 .table_create("users")
+//!                 .index_create("users", "email"),
+//!         )
+//!         .migration(
+//!             Migration::new(2, "lowercase emails").query(
+//!                 r.table("users")
+//!                     .update(func!(|user| r.object([r.expr("email"), user.g("email").downcase()]))),
+//!             ),
+//!         )
+//!         .run(&conn)
+//!         .await?;
+//!
+//!     assert!(applied.len() <= 2);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arguments::{Args, RunOption};
+use crate::cmd::index_create::IndexCreateArg;
+use crate::partitions::created;
+use crate::types::{DateTime, MutationResponse};
+use crate::{err, r, Command, Converter, Result, Session};
+
+/// Name of the table recording the applied migrations,
+/// never prefixed by the table prefix of the session
+pub const MIGRATIONS_TABLE: &str = "__migrations";
+
+/// An ordered set of changes applied to a database
+#[derive(Debug, Clone)]
+pub struct Migration {
+    version: u64,
+    name: Cow<'static, str>,
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Query(Command),
+    // Skipped when the table exists, e.g. created by a failed run
+    TableCreate(Cow<'static, str>),
+    // An index created by a failed run is no error
+    IndexCreate(Command),
+}
+
+impl Migration {
+    pub fn new(version: u64, name: impl Into<String>) -> Self {
+        Self {
+            version,
+            name: Cow::Owned(name.into()),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Create a table in the database of the migrator, unless it exists
+    pub fn table_create(mut self, table: impl Into<String>) -> Self {
+        let table = table.into();
+
+        self.steps
+            .push(Step::TableCreate(Cow::Owned(table.clone())));
+        self.query(r.table(table.as_str()).wait(()))
+    }
+
+    /// Create a secondary index, unless it exists, and wait for it to be ready
+    pub fn index_create(mut self, table: impl Into<String>, args: impl IndexCreateArg) -> Self {
+        let table = r.table(table.into().as_str());
+
+        self.steps.push(Step::IndexCreate(table.index_create(args)));
+        self.query(table.index_wait(()))
+    }
+
+    /// Run any query, e.g. an `update` transforming the documents
+    ///
+    /// The query is run with the database of the migrator as default database.
+    pub fn query(mut self, query: Command) -> Self {
+        self.steps.push(Step::Query(query));
+        self
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A row of the `__migrations` table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AppliedMigration {
+    #[serde(rename = "id")]
+    pub version: u64,
+    pub name: Cow<'static, str>,
+    pub applied_at: DateTime,
+}
+
+/// Apply the migrations of a database
+#[derive(Debug, Clone)]
+pub struct Migrator {
+    db: Cow<'static, str>,
+    migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    pub fn new(db: impl Into<String>) -> Self {
+        Self {
+            db: Cow::Owned(db.into()),
+            migrations: Vec::new(),
+        }
+    }
+
+    pub fn migration(mut self, migration: Migration) -> Self {
+        self.migrations.push(migration);
+        self
+    }
+
+    /// Apply the pending migrations in version order
+    ///
+    /// Returns the versions applied by this call. The database and the
+    /// `__migrations` table are created when missing. A migration is
+    /// recorded once all its steps succeed, so a failing migration is
+    /// attempted again by the next run.
+    pub async fn run(&self, session: &Session) -> Result<Vec<u64>> {
+        let mut migrations: Vec<&Migration> = self.migrations.iter().collect();
+        migrations.sort_by_key(|migration| migration.version);
+
+        if let Some(pair) = migrations
+            .windows(2)
+            .find(|pair| pair[0].version == pair[1].version)
+        {
+            return Err(err::ReqlDriverError::Other(format!(
+                "duplicate migration version {}",
+                pair[0].version
+            ))
+            .into());
+        }
+
+        self.create_migrations_table(session).await?;
+
+        let applied: HashSet<u64> = self
+            .applied(session)
+            .await?
+            .into_iter()
+            .map(|migration| migration.version)
+            .collect();
+        let mut versions = Vec::new();
+
+        for migration in migrations {
+            if applied.contains(&migration.version) {
+                continue;
+            }
+
+            for step in &migration.steps {
+                match step {
+                    Step::Query(query) => {
+                        query.run(self.args(session)).await?;
+                    }
+                    Step::TableCreate(table) => self.table_create(session, table).await?,
+                    Step::IndexCreate(query) => created(query.run(self.args(session)).await)?,
+                }
+            }
+
+            let response: MutationResponse = self
+                .migrations_table()
+                .insert(r.object([
+                    r.expr("id"),
+                    r.expr(migration.version),
+                    r.expr("name"),
+                    r.expr(migration.name.as_ref()),
+                    r.expr("applied_at"),
+                    r.now().into(),
+                ]))
+                .run(self.history_args(session))
+                .await?
+                .unwrap_or_default()
+                .parse()?;
+
+            if response.errors > 0 {
+                let error = response.first_error.unwrap_or_default();
+                return Err(err::ReqlDriverError::Other(format!(
+                    "migration {} applied but not recorded: {}",
+                    migration.version, error
+                ))
+                .into());
+            }
+            versions.push(migration.version);
+        }
+
+        Ok(versions)
+    }
+
+    /// The migrations already applied, in version order
+    pub async fn applied(&self, session: &Session) -> Result<Vec<AppliedMigration>> {
+        if !self.has_migrations_table(session).await? {
+            return Ok(Vec::new());
+        }
+
+        self.migrations_table()
+            .order_by("id")
+            .run(self.history_args(session))
+            .await?
+            .unwrap_or_default()
+            .parse()
+    }
+
+    async fn create_migrations_table(&self, session: &Session) -> Result<()> {
+        let dbs: Vec<String> = r
+            .db_list()
+            .run(session)
+            .await?
+            .unwrap_or_default()
+            .parse()?;
+
+        if !dbs.iter().any(|db| db == self.db.as_ref()) {
+            r.db_create(self.db.as_ref()).run(session).await?;
+        }
+
+        if !self.has_migrations_table(session).await? {
+            self.db()
+                .table_create(MIGRATIONS_TABLE)
+                .run(self.history_args(session))
+                .await?;
+            self.migrations_table()
+                .wait(())
+                .run(self.history_args(session))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn table_create(&self, session: &Session, table: &str) -> Result<()> {
+        // the server lists the names with the session prefix
+        let prefix = session.table_prefix().unwrap_or_default();
+
+        if !self
+            .has_table(session, &format!("{}{}", prefix, table))
+            .await?
+        {
+            r.table_create(table).run(self.args(session)).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn has_migrations_table(&self, session: &Session) -> Result<bool> {
+        self.has_table(session, MIGRATIONS_TABLE).await
+    }
+
+    async fn has_table(&self, session: &Session, name: &str) -> Result<bool> {
+        let tables: Vec<String> = self
+            .db()
+            .table_list()
+            .run(self.history_args(session))
+            .await?
+            .unwrap_or_default()
+            .parse()?;
+
+        Ok(tables.iter().any(|table| table == name))
+    }
+
+    fn db(&self) -> Command {
+        r.db(self.db.as_ref())
+    }
+
+    fn migrations_table(&self) -> Command {
+        self.db().table(MIGRATIONS_TABLE)
+    }
+
+    fn args<'a>(&self, session: &'a Session) -> Args<(&'a Session, RunOption)> {
+        Args((session, RunOption::default().db(&self.db)))
+    }
+
+    // The `__migrations` table keeps its name whatever the session prefix
+    fn history_args<'a>(&self, session: &'a Session) -> Args<(&'a Session, RunOption)> {
+        Args((
+            session,
+            RunOption::default().db(&self.db).table_prefix(false),
+        ))
+    }
+}
@@ -0,0 +1,173 @@
+//! A session answering queries with canned responses, with the `mock` feature
+//!
+//! [MockSession] runs the queries of an application through the driver, as
+//! a real session does, to a fake server of the same process. The server records
+//! the terms of the queries and answers them in order with the responses queued
+//! by the test, so query logic can be unit tested without a RethinkDB instance.
+
+use std::collections::VecDeque;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+use async_net::{TcpListener, TcpStream};
+use futures::io::{AsyncReadExt, AsyncWriteExt};
+use ql2::query::QueryType;
+use ql2::response::{ErrorType, ResponseType};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tokio::task;
+
+use crate::arguments::RunOption;
+use crate::cmd::connect::ConnectionCommand;
+use crate::cmd::run::RunArg;
+use crate::constants::{HEADER_SIZE, TOKEN_SIZE};
+use crate::{Connection, Result, Session, TcpStreamConnection};
+
+/// A [Session] whose queries are answered with canned responses
+///
+/// A query answered by no queued response returns `null`.
+///
+/// ## Examples
+///
+/// ```
+/// use neor::mock::MockSession;
+/// use neor::{r, Converter, Result};
+/// use serde_json::json;
+///
+/// async fn example() -> Result<()> {
+///     let mock = MockSession::new().await?;
+///     let query = r.table("users").get(1).g("name");
+///
+///     mock.respond("Alice")?;
+///     let name: String = query.run(&mock).await?.unwrap().parse()?;
+///
+///     assert_eq!(name, "Alice");
+///     assert_eq!(mock.queries(), [query.to_wire_json()?]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockSession {
+    session: Session,
+    state: Arc<MockState>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    queries: Mutex<Vec<Value>>,
+    // the result of the next queries, or the message of their error
+    responses: Mutex<VecDeque<std::result::Result<Value, String>>>,
+}
+
+impl MockSession {
+    /// Open a session to a new fake server
+    pub async fn new() -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let stream = TcpStream::connect(listener.local_addr()?).await?;
+        let (server, _) = listener.accept().await?;
+        let state = Arc::new(MockState::default());
+
+        task::spawn(serve(server, state.clone()));
+
+        let session = ConnectionCommand::default().session(TcpStreamConnection {
+            stream,
+            tls_stream: None,
+            server_version: "rethinkdb 2.4.4 (mock)".to_owned(),
+        });
+
+        Ok(Self { session, state })
+    }
+
+    /// Queue the result of the next query
+    pub fn respond(&self, response: impl Serialize) -> Result<()> {
+        let response = serde_json::to_value(response)?;
+        self.state.responses.lock().unwrap().push_back(Ok(response));
+        Ok(())
+    }
+
+    /// Make the next query fail with a
+    /// [QueryLogic](crate::err::ReqlRuntimeError::QueryLogic) error
+    pub fn fail(&self, message: impl Into<String>) {
+        let mut responses = self.state.responses.lock().unwrap();
+        responses.push_back(Err(message.into()));
+    }
+
+    /// The terms of the queries run so far, as sent to the server,
+    /// see [to_wire_json](crate::Command::to_wire_json)
+    pub fn queries(&self) -> Vec<Value> {
+        self.state.queries.lock().unwrap().clone()
+    }
+}
+
+impl Deref for MockSession {
+    type Target = Session;
+
+    fn deref(&self) -> &Session {
+        &self.session
+    }
+}
+
+impl RunArg for &MockSession {
+    fn into_run_opts(self) -> Result<(Connection, RunOption)> {
+        self.session.connection()?.into_run_opts()
+    }
+}
+
+impl MockState {
+    // The response to a query, `None` for the queries run with `noreply`
+    fn answer(&self, query: &Value) -> Option<Value> {
+        let query_type = query[0].as_i64().unwrap_or_default() as i32;
+
+        if query_type == QueryType::Start as i32 {
+            self.queries.lock().unwrap().push(query[1].clone());
+
+            if query[2]["noreply"] == json!(true) {
+                return None;
+            }
+
+            let response = self.responses.lock().unwrap().pop_front();
+            return Some(match response.unwrap_or(Ok(Value::Null)) {
+                Ok(value) => json!({"t": ResponseType::SuccessAtom as i32, "r": [value]}),
+                Err(message) => json!({
+                    "t": ResponseType::RuntimeError as i32,
+                    "e": ErrorType::QueryLogic as i32,
+                    "r": [message],
+                    "b": [],
+                }),
+            });
+        }
+
+        Some(match query_type {
+            typ if typ == QueryType::NoreplyWait as i32 => {
+                json!({"t": ResponseType::WaitComplete as i32, "r": []})
+            }
+            typ if typ == QueryType::ServerInfo as i32 => json!({
+                "t": ResponseType::ServerInfo as i32,
+                "r": [{"id": uuid::Uuid::nil(), "name": "mock", "proxy": false}],
+            }),
+            // `CONTINUE` and `STOP`, as the sequences are sent whole
+            _ => json!({"t": ResponseType::SuccessSequence as i32, "r": []}),
+        })
+    }
+}
+
+// Answer the frames of the session until it closes
+async fn serve(mut stream: TcpStream, state: Arc<MockState>) -> Result<()> {
+    loop {
+        let mut header = [0u8; HEADER_SIZE];
+        stream.read_exact(&mut header).await?;
+
+        let (token, len) = header.split_at(TOKEN_SIZE);
+        let mut body = vec![0u8; u32::from_le_bytes(len.try_into().unwrap()) as usize];
+        stream.read_exact(&mut body).await?;
+
+        if let Some(response) = state.answer(&serde_json::from_slice(&body)?) {
+            let response = response.to_string();
+            let mut frame = token.to_vec();
+            frame.extend_from_slice(&(response.len() as u32).to_le_bytes());
+            frame.extend_from_slice(response.as_bytes());
+            stream.write_all(&frame).await?;
+        }
+    }
+}
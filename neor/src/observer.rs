@@ -0,0 +1,204 @@
+//! Observe the requests sent to the server
+//!
+//! A [QueryObserver] set with
+//! [ConnectionCommand::observer](crate::types::ConnectionCommand::observer)
+//! is called before each request is written on the socket of the session
+//! and once its response is read, e.g. to export metrics or open spans.
+//! The observer is called from the task running the query,
+//! so it must not block.
+//!
+//! A [QueryLogger] receives instead the text of each query,
+//! printed by [to_reql_string](crate::Command::to_reql_string).
+//!
+//! With the `tracing` feature, the driver also opens `connect` and
+//! `handshake` spans when a session is opened, and a `query` span for each
+//! request, with its `token` and `kind`. The text of the query, truncated
+//! to 512 bytes, is recorded in the `query` field when the `trace` level
+//! is enabled.
+//!
+//! ```
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! use neor::observer::{QueryEnd, QueryObserver};
+//! use neor::{r, Result};
+//!
+//! #[derive(Debug, Default)]
+//! struct Errors(AtomicUsize);
+//!
+//! impl QueryObserver for Errors {
+//!     fn on_end(&self, event: &QueryEnd) {
+//!         if event.error.is_some() {
+//!             self.0.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//! }
+//!
+//! async fn example() -> Result<()> {
+//!     let conn = r.connection().observer(Errors::default()).connect().await?;
+//!
+//!     r.table("users").count(()).run(&conn).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ql2::query::QueryType;
+
+use crate::arguments::ReadMode;
+use crate::err::ReqlError;
+use crate::proto::Payload;
+use crate::Command;
+
+/// Hooks called around each request of a session
+///
+/// Both methods do nothing by default.
+pub trait QueryObserver: Debug + Send + Sync {
+    /// The request is about to be sent
+    fn on_start(&self, _event: &QueryStart) {}
+
+    /// The response of the request was read, or the request failed
+    ///
+    /// Not called when the query is dropped while its request is pending.
+    fn on_end(&self, _event: &QueryEnd) {}
+}
+
+/// Kind of a request sent to the server
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum QueryKind {
+    /// First request of a query
+    Start,
+    /// Request of the next batch of a stream
+    Continue,
+    /// Stop a stream or a changefeed
+    Stop,
+    NoreplyWait,
+    ServerInfo,
+}
+
+impl QueryKind {
+    pub(crate) fn of(typ: QueryType) -> Self {
+        match typ {
+            QueryType::Start => Self::Start,
+            QueryType::Continue => Self::Continue,
+            QueryType::Stop => Self::Stop,
+            QueryType::NoreplyWait => Self::NoreplyWait,
+            QueryType::ServerInfo => Self::ServerInfo,
+        }
+    }
+}
+
+/// A request about to be sent
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct QueryStart {
+    /// Token of the query, shared by its `Continue` and `Stop` requests
+    pub token: u64,
+    pub kind: QueryKind,
+    /// Name of the outermost term of a `Start` request, e.g. `count`
+    pub term: Option<String>,
+    /// Size of the request, header included
+    pub bytes_sent: usize,
+    /// Read mode of the run options of a `Start` request,
+    /// see [outdated](crate::Command::outdated)
+    pub read_mode: Option<ReadMode>,
+}
+
+/// A request answered by the server, or which failed
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct QueryEnd {
+    pub token: u64,
+    pub kind: QueryKind,
+    pub term: Option<String>,
+    pub bytes_sent: usize,
+    pub read_mode: Option<ReadMode>,
+    /// Size of the response, header included, `0` for a `noreply` request
+    pub bytes_received: usize,
+    /// Time from the start of the request to the end of its response
+    pub duration: Duration,
+    /// The error of the request, including the errors reported by the server
+    pub error: Option<ReqlError>,
+}
+
+// A request being reported to the observer of its session
+pub(crate) struct Observation<'a> {
+    observer: &'a dyn QueryObserver,
+    start: QueryStart,
+    instant: Instant,
+}
+
+impl<'a> Observation<'a> {
+    pub(crate) fn start(
+        observer: Option<&'a dyn QueryObserver>,
+        token: u64,
+        payload: &Payload,
+        bytes_sent: usize,
+    ) -> Option<Self> {
+        let observer = observer?;
+        let Payload(typ, query, opts) = payload;
+        let start = QueryStart {
+            token,
+            kind: QueryKind::of(*typ),
+            term: query.as_ref().map(|query| query.0.term_name()),
+            bytes_sent,
+            read_mode: opts.read_mode,
+        };
+
+        observer.on_start(&start);
+
+        Some(Self {
+            observer,
+            start,
+            instant: Instant::now(),
+        })
+    }
+
+    pub(crate) fn end<T>(self, bytes_received: usize, result: &Result<T, ReqlError>) {
+        let QueryStart {
+            token,
+            kind,
+            term,
+            bytes_sent,
+            read_mode,
+        } = self.start;
+
+        self.observer.on_end(&QueryEnd {
+            token,
+            kind,
+            term,
+            bytes_sent,
+            read_mode,
+            bytes_received,
+            duration: self.instant.elapsed(),
+            error: result.as_ref().err().cloned(),
+        });
+    }
+}
+
+/// Receives the text of each query run by a session,
+/// see [query_logger](crate::types::ConnectionCommand::query_logger)
+#[derive(Clone)]
+pub struct QueryLogger(Arc<dyn Fn(&str) + Send + Sync>);
+
+impl QueryLogger {
+    pub fn new(logger: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(logger))
+    }
+
+    pub(crate) fn log(&self, query: &Command) {
+        if let Ok(query) = query.to_reql_string() {
+            (self.0)(&query);
+        }
+    }
+}
+
+impl Debug for QueryLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("QueryLogger")
+    }
+}
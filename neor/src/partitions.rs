@@ -0,0 +1,290 @@
+//! Route time-series documents to one table per month
+//!
+//! Documents written at a given time go to the table of their month,
+//! e.g. `events_2024_05`, created on demand with a secondary index on
+//! the time field. Reads over a time span are a `union` of the existing
+//! tables of the span, each one restricted with `between` on that index.
+//!
+//! ```
+//! use neor::partitions::MonthlyPartitions;
+//! use neor::{r, Converter, Result};
+//! use serde_json::{json, Value};
+//! use time::macros::datetime;
+//!
+//! async fn example() -> Result<()> {
+//!     let conn = r.connection().connect().await?;
+//!     let events = MonthlyPartitions::new("events", "created_at");
+//!     let created_at = datetime!(2024-05-12 10:00 UTC);
+//!
+//!     events
+//!         .insert(&conn, created_at, json!({"kind": "login"}))
+//!         .await?;
+//!
+//!     let logins: Vec<Value> = events
+//!         .between(&conn, datetime!(2024-04-01 0:00 UTC), datetime!(2024-06-01 0:00 UTC))
+//!         .await?
+//!         .run(&conn)
+//!         .await?
+//!         .unwrap()
+//!         .parse()?;
+//!
+//!     assert!(!logins.is_empty());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::borrow::Cow;
+
+use serde_json::Value;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::arguments::{Args, BetweenOption};
+use crate::err::{ReqlAvailabilityError, ReqlError, ReqlRuntimeError};
+use crate::types::MutationResponse;
+use crate::{r, Command, CommandArg, Converter, Result, Session};
+
+/// Monthly tables sharing a name prefix
+#[derive(Debug, Clone)]
+pub struct MonthlyPartitions {
+    prefix: Cow<'static, str>,
+    time_field: Cow<'static, str>,
+    db: Option<Cow<'static, str>>,
+    indexes: Vec<Cow<'static, str>>,
+}
+
+impl MonthlyPartitions {
+    /// Tables named `<prefix>_<year>_<month>`, indexed on `time_field`
+    pub fn new(prefix: impl Into<String>, time_field: impl Into<String>) -> Self {
+        Self {
+            prefix: Cow::Owned(prefix.into()),
+            time_field: Cow::Owned(time_field.into()),
+            db: None,
+            indexes: Vec::new(),
+        }
+    }
+
+    /// Use the tables of `db` instead of the default database
+    pub fn db(mut self, db: impl Into<String>) -> Self {
+        self.db = Some(Cow::Owned(db.into()));
+        self
+    }
+
+    /// Also create a simple secondary index on `field` in each new table
+    pub fn index(mut self, field: impl Into<String>) -> Self {
+        self.indexes.push(Cow::Owned(field.into()));
+        self
+    }
+
+    /// Name of the table holding the documents of the month of `time`,
+    /// in UTC
+    pub fn table_name(&self, time: OffsetDateTime) -> String {
+        let time = time.to_offset(UtcOffset::UTC);
+
+        format!(
+            "{}_{:04}_{:02}",
+            self.prefix,
+            time.year(),
+            time.month() as u8
+        )
+    }
+
+    /// Names of the tables of every month from `start` to `end`, included
+    pub fn table_names(&self, start: OffsetDateTime, end: OffsetDateTime) -> Vec<String> {
+        let month = |time: OffsetDateTime| {
+            let time = time.to_offset(UtcOffset::UTC);
+            time.year() * 12 + time.month() as i32 - 1
+        };
+
+        (month(start)..=month(end))
+            .map(|month| {
+                format!(
+                    "{}_{:04}_{:02}",
+                    self.prefix,
+                    month.div_euclid(12),
+                    month.rem_euclid(12) + 1
+                )
+            })
+            .collect()
+    }
+
+    /// The table of the month of `time`, which may not exist yet
+    pub fn table(&self, time: OffsetDateTime) -> Command {
+        self.table_by_name(&self.table_name(time))
+    }
+
+    /// The table of the month of `time`, created with its indexes when missing
+    ///
+    /// Another client may create the same table at the same time,
+    /// the table and the indexes it already created are kept.
+    pub async fn partition(&self, session: &Session, time: OffsetDateTime) -> Result<Command> {
+        let name = self.table_name(time);
+
+        if !self.existing_tables(session).await?.contains(&name) {
+            self.create_table(session, &name).await?;
+        }
+
+        Ok(self.table_by_name(&name))
+    }
+
+    /// Insert `document` in the table of the month of `time`
+    ///
+    /// The time field of the document is set to `time`.
+    pub async fn insert(
+        &self,
+        session: &Session,
+        time: OffsetDateTime,
+        document: impl Into<CommandArg>,
+    ) -> Result<MutationResponse> {
+        let document = r
+            .expr(document)
+            .merge(r.object([r.expr(self.time_field.as_ref()), Command::from(time)]));
+
+        self.partition(session, time)
+            .await?
+            .insert(document)
+            .run(session)
+            .await?
+            .unwrap_or_default()
+            .parse()
+    }
+
+    /// Select the documents whose time field is between `start` (included)
+    /// and `end` (excluded), over the existing tables of the span
+    pub async fn between(
+        &self,
+        session: &Session,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> Result<Command> {
+        let existing = self.existing_tables(session).await?;
+        let tables: Vec<Command> = self
+            .table_names(start, end)
+            .into_iter()
+            .filter(|name| existing.contains(name))
+            .map(|name| {
+                self.table_by_name(&name).between(Args((
+                    Command::from(start),
+                    Command::from(end),
+                    BetweenOption::default().index(&self.time_field),
+                )))
+            })
+            .collect();
+
+        Ok(match tables.len() {
+            0 => r.expr(Vec::<Value>::new()),
+            1 => tables.into_iter().next().unwrap(),
+            _ => {
+                let mut tables = tables.into_iter();
+                let first = tables.next().unwrap();
+
+                first.union(tables.collect::<Vec<_>>())
+            }
+        })
+    }
+
+    async fn create_table(&self, session: &Session, name: &str) -> Result<()> {
+        let table = self.table_by_name(name);
+        let table_create = match &self.db {
+            Some(db) => r.db(db.as_ref()).table_create(name),
+            None => r.table_create(name),
+        };
+
+        created(table_create.run(session).await)?;
+        created(
+            table
+                .index_create(self.time_field.as_ref())
+                .run(session)
+                .await,
+        )?;
+
+        for index in &self.indexes {
+            created(table.index_create(index.as_ref()).run(session).await)?;
+        }
+
+        table.index_wait(()).run(session).await?;
+
+        Ok(())
+    }
+
+    async fn existing_tables(&self, session: &Session) -> Result<Vec<String>> {
+        let table_list = match &self.db {
+            Some(db) => r.db(db.as_ref()).table_list(),
+            None => r.table_list(),
+        };
+
+        let tables: Vec<String> = table_list.run(session).await?.unwrap_or_default().parse()?;
+
+        // The server returns the names with the session prefix
+        Ok(match session.table_prefix() {
+            Some(prefix) => tables
+                .into_iter()
+                .filter_map(|table| table.strip_prefix(prefix).map(String::from))
+                .collect(),
+            None => tables,
+        })
+    }
+
+    fn table_by_name(&self, name: &str) -> Command {
+        match &self.db {
+            Some(db) => r.db(db.as_ref()).table(name),
+            None => r.table(name),
+        }
+    }
+}
+
+// A table or an index created meanwhile by another client is no error
+pub(crate) fn created(result: Result<Option<Value>>) -> Result<()> {
+    match result {
+        Err(ReqlError::Runtime(ReqlRuntimeError::Availability(
+            ReqlAvailabilityError::OpFailed(msg),
+        ))) if msg.contains("already exists") => Ok(()),
+        result => result.map(|_| ()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::{created, MonthlyPartitions};
+    use crate::err::{ReqlAvailabilityError, ReqlError, ReqlRuntimeError};
+
+    #[test]
+    fn created_meanwhile() {
+        let op_failed = |msg: &str| {
+            Err(ReqlError::Runtime(ReqlRuntimeError::Availability(
+                ReqlAvailabilityError::OpFailed(msg.into()),
+            )))
+        };
+
+        assert!(created(op_failed("Table `test.events_2024_05` already exists.")).is_ok());
+        assert!(created(op_failed(
+            "Index `created_at` already exists on table `test.events`."
+        ))
+        .is_ok());
+        assert!(created(op_failed("Database `test` does not exist.")).is_err());
+    }
+
+    #[test]
+    fn table_names() {
+        let events = MonthlyPartitions::new("events", "created_at");
+
+        assert_eq!(
+            events.table_name(datetime!(2024-05-31 23:30 -02:00)),
+            "events_2024_06"
+        );
+        assert_eq!(
+            events.table_names(
+                datetime!(2023-11-15 0:00 UTC),
+                datetime!(2024-02-01 0:00 UTC)
+            ),
+            vec![
+                "events_2023_11",
+                "events_2023_12",
+                "events_2024_01",
+                "events_2024_02"
+            ]
+        );
+    }
+}
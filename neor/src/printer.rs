@@ -0,0 +1,285 @@
+use ql2::term::TermType;
+use serde_json::{Map, Value};
+
+use crate::err::Frame;
+
+/// Print the wire representation of a query in the syntax of the driver
+pub(crate) fn print(query: &Value) -> String {
+    let mut printer = Printer::default();
+    printer.term(query, None);
+    printer.out
+}
+
+/// Print the wire representation of a query in the syntax of the driver,
+/// followed by a line of carets under the term pointed by `backtrace`
+pub(crate) fn annotate(query: &Value, backtrace: &[Frame]) -> String {
+    let mut printer = Printer::default();
+    printer.term(query, Some(backtrace));
+
+    let (start, end) = printer.span.unwrap_or((0, printer.out.len()));
+    let offset = printer.out[..start].chars().count();
+    let width = printer.out[start..end].chars().count();
+
+    format!(
+        "{}\n{}{}",
+        printer.out,
+        " ".repeat(offset),
+        "^".repeat(width.max(1))
+    )
+}
+
+#[derive(Default)]
+struct Printer {
+    out: String,
+    span: Option<(usize, usize)>,
+}
+
+impl Printer {
+    fn term(&mut self, term: &Value, path: Option<&[Frame]>) {
+        let start = self.out.len();
+
+        match term {
+            Value::Array(term) => self.compound(term, path),
+            Value::Object(obj) => self.object(obj, path),
+            datum => self.out.push_str(&datum.to_string()),
+        }
+
+        if let Some([]) = path {
+            self.span = Some((start, self.out.len()));
+        }
+    }
+
+    fn compound(&mut self, term: &[Value], path: Option<&[Frame]>) {
+        let typ = term
+            .first()
+            .and_then(Value::as_i64)
+            .and_then(|typ| TermType::from_i32(typ as i32));
+        let args = match term.get(1) {
+            Some(Value::Array(args)) => &args[..],
+            _ => &[],
+        };
+        let opts = match term.get(2) {
+            Some(Value::Object(opts)) => Some(opts),
+            _ => None,
+        };
+        let arg = |i: usize| pos(path, i);
+
+        let typ = match typ {
+            Some(typ) => typ,
+            None => return self.out.push_str(&Value::Array(term.to_vec()).to_string()),
+        };
+
+        match typ {
+            TermType::MakeArray => {
+                self.out.push('[');
+                self.list(args, 0, path);
+                self.out.push(']');
+            }
+            TermType::Var => {
+                self.out.push_str("var_");
+                self.list(args, 0, path);
+            }
+            TermType::ImplicitVar => self.out.push_str("r.row"),
+            TermType::Func => {
+                let ids = match args.first() {
+                    Some(Value::Array(ids)) => ids.get(1).and_then(Value::as_array),
+                    _ => None,
+                };
+                let ids = ids.map(|ids| ids.iter().map(|id| format!("var_{}", id)));
+
+                self.out.push('|');
+                self.out
+                    .push_str(&ids.into_iter().flatten().collect::<Vec<_>>().join(", "));
+                self.out.push_str("| ");
+
+                if let Some(body) = args.get(1) {
+                    self.term(body, arg(1));
+                }
+            }
+            // The function comes first on the wire, last in the driver
+            TermType::Funcall => {
+                self.out.push_str("r.do_(");
+                self.list(args.get(1..).unwrap_or_default(), 1, path);
+
+                if let Some(func) = args.first() {
+                    if args.len() > 1 {
+                        self.out.push_str(", ");
+                    }
+                    self.term(func, arg(0));
+                }
+                self.out.push(')');
+            }
+            _ if is_top_level(typ, args) => {
+                self.out.push_str("r.");
+                self.out.push_str(&name(typ));
+                self.out.push('(');
+                self.list(args, 0, path);
+                self.options(opts, !args.is_empty(), path);
+                self.out.push(')');
+            }
+            _ => {
+                let (receiver, rest) = match args.split_first() {
+                    Some(split) => split,
+                    None => (&Value::Null, &[][..]),
+                };
+
+                if receiver.is_array() {
+                    self.term(receiver, arg(0));
+                } else {
+                    let start = self.out.len();
+                    self.out.push_str("r.expr(");
+                    self.term(receiver, None);
+                    self.out.push(')');
+
+                    if let Some([Frame::Pos(0)]) = path {
+                        self.span = Some((start, self.out.len()));
+                    }
+                }
+
+                self.out.push('.');
+                self.out.push_str(&name(typ));
+                self.out.push('(');
+                self.list(rest, 1, path);
+                self.options(opts, !rest.is_empty(), path);
+                self.out.push(')');
+            }
+        }
+    }
+
+    // `args` start at the position `offset` of the term
+    fn list(&mut self, args: &[Value], offset: usize, path: Option<&[Frame]>) {
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                self.out.push_str(", ");
+            }
+            self.term(arg, pos(path, offset + i));
+        }
+    }
+
+    fn options(&mut self, opts: Option<&Map<String, Value>>, comma: bool, path: Option<&[Frame]>) {
+        if let Some(opts) = opts.filter(|opts| !opts.is_empty()) {
+            if comma {
+                self.out.push_str(", ");
+            }
+            self.object(opts, path);
+        }
+    }
+
+    fn object(&mut self, obj: &Map<String, Value>, path: Option<&[Frame]>) {
+        self.out.push('{');
+
+        for (i, (key, value)) in obj.iter().enumerate() {
+            if i > 0 {
+                self.out.push_str(", ");
+            }
+            self.out.push_str(&Value::String(key.clone()).to_string());
+            self.out.push_str(": ");
+            self.term(value, opt(path, key));
+        }
+
+        self.out.push('}');
+    }
+}
+
+fn pos(path: Option<&[Frame]>, i: usize) -> Option<&[Frame]> {
+    match path? {
+        [Frame::Pos(n), rest @ ..] if *n == i => Some(rest),
+        _ => None,
+    }
+}
+
+fn opt<'a>(path: Option<&'a [Frame]>, key: &str) -> Option<&'a [Frame]> {
+    match path? {
+        [Frame::Opt(name), rest @ ..] if name == key => Some(rest),
+        _ => None,
+    }
+}
+
+// Terms called on `r`, unless chained on a database
+fn is_top_level(typ: TermType, args: &[Value]) -> bool {
+    use TermType::*;
+
+    match typ {
+        Table | TableCreate | TableDrop | TableList | Grant | Wait | Config | Status
+        | Reconfigure | Rebalance => !matches!(
+            args.first().and_then(|arg| arg.get(0)).and_then(Value::as_i64),
+            Some(typ) if typ == Db as i64 || typ == Table as i64
+        ),
+        Db | DbCreate | DbDrop | DbList | Javascript | Uuid | Http | Error | Branch | Asc
+        | Desc | Json | Iso8601 | EpochTime | Now | Time | Monday | Tuesday | Wednesday
+        | Thursday | Friday | Saturday | Sunday | January | February | March | April | May
+        | June | July | August | September | October | November | December | Literal | Object
+        | Random | Range | Args | Binary | Geojson | Point | Line | Polygon | Circle | Minval
+        | Maxval => true,
+        _ => false,
+    }
+}
+
+// Name of the method of the driver running a term
+pub(crate) fn name(typ: TermType) -> String {
+    match typ {
+        TermType::Javascript => return "js".to_owned(),
+        TermType::Match => return "match_".to_owned(),
+        TermType::Mod => return "rem".to_owned(),
+        TermType::ToJsonString => return "to_json".to_owned(),
+        TermType::Minval => return "min_val".to_owned(),
+        TermType::Maxval => return "max_val".to_owned(),
+        _ => {}
+    }
+
+    let mut name = String::new();
+
+    for (i, c) in format!("{:?}", typ).chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{annotate, print};
+    use crate::err::Frame;
+    use crate::proto::Query;
+    use crate::r;
+
+    #[test]
+    fn annotate_backtrace() {
+        let query = r.table("posts").get(1).g("views") + (r.expr(2) + "a");
+        let query = serde_json::to_value(Query(&query)).unwrap();
+
+        assert_eq!(
+            annotate(&query, &[Frame::Pos(1)]),
+            concat!(
+                "r.table(\"posts\").get(1).get_field(\"views\").add(r.expr(2).add(\"a\"))\n",
+                "                                               ^^^^^^^^^^^^^^^^^^",
+            )
+        );
+        assert_eq!(
+            annotate(&json!([24, [1, 2]]), &[Frame::Pos(0)]),
+            "r.expr(1).add(2)\n^^^^^^^^^"
+        );
+        assert_eq!(
+            annotate(&json!([24, [1, 2]]), &[]),
+            "r.expr(1).add(2)\n^^^^^^^^^^^^^^^^"
+        );
+    }
+    #[test]
+    fn print_query() {
+        let query = r.db("blog").table("posts").limit(2).count(());
+
+        assert_eq!(
+            print(&serde_json::to_value(Query(&query)).unwrap()),
+            r#"r.db("blog").table("posts").limit(2).count()"#
+        );
+    }
+}
@@ -1,16 +1,19 @@
 use std::collections::{HashMap, VecDeque};
-use std::{fmt, str};
+use std::time::Duration;
+use std::{fmt, io, str};
 
 use ql2::query::QueryType;
 use ql2::term::TermType;
-use serde::ser::{self, Serialize, Serializer};
-use serde_json::value::{Number, Value};
+use serde::ser::{self, Serialize, SerializeMap, Serializer};
+use serde_json::value::{Number, RawValue, Value};
 
-use crate::arguments::RunOption;
+use crate::arguments::{ReadMode, RunOption};
 use crate::cmd::run::Db;
-use crate::{err, r};
+use crate::system::SYSTEM_DB;
+use crate::types::{Diagnostic, DiagnosticKind};
+use crate::{err, r, var_counter, Func};
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone)]
 pub(crate) enum Datum {
     Null,
     Bool(bool),
@@ -18,6 +21,14 @@ pub(crate) enum Datum {
     String(String),
     Array(Vec<Datum>),
     Object(HashMap<String, Datum>),
+    // Wire JSON sent as is, see `r.raw`
+    Raw(Value),
+    // Placeholder of `r.param`, bound by a prepared query
+    Param(String),
+    // Prepared query bound to its parameters
+    Prepared(Box<RawValue>),
+    // Option holding a term, e.g. the function of `fold`
+    Term(Box<Command>),
 }
 
 impl Default for Datum {
@@ -38,6 +49,25 @@ impl Serialize for Datum {
             Self::String(string) => string.serialize(serializer),
             Self::Array(arr) => (TermType::MakeArray as i32, arr).serialize(serializer),
             Self::Object(map) => map.serialize(serializer),
+            Self::Raw(value) => value.serialize(serializer),
+            Self::Param(name) => Err(ser::Error::custom(format!(
+                "parameter `{}` can only be bound by a prepared query",
+                name
+            ))),
+            Self::Prepared(json) => json.serialize(serializer),
+            Self::Term(term) => Query(term).serialize(serializer),
+        }
+    }
+}
+
+impl Datum {
+    fn depth(&self) -> usize {
+        match self {
+            Self::Array(values) => 1 + values.iter().map(Self::depth).max().unwrap_or_default(),
+            Self::Object(map) => 1 + map.values().map(Self::depth).max().unwrap_or_default(),
+            Self::Raw(value) => wire_depth(value),
+            Self::Term(term) => term.depth(),
+            _ => 1,
         }
     }
 }
@@ -68,6 +98,9 @@ pub struct Command {
     pub args: VecDeque<super::Result<Command>>,
     opts: Option<super::Result<Datum>>,
     change_feed: bool,
+    timeout: Option<Duration>,
+    read_mode: Option<ReadMode>,
+    idempotent: bool,
 }
 
 impl Command {
@@ -79,6 +112,9 @@ impl Command {
             args: VecDeque::new(),
             opts: None,
             change_feed: false,
+            timeout: None,
+            read_mode: None,
+            idempotent: false,
         }
     }
 
@@ -90,6 +126,7 @@ impl Command {
 
     pub(crate) fn with_parent(mut self, parent: &Command) -> Self {
         self.change_feed = self.change_feed || parent.change_feed;
+        self.idempotent = self.idempotent || parent.idempotent;
         self.args.push_front(Ok(parent.to_owned()));
         self
     }
@@ -112,6 +149,63 @@ impl Command {
         self
     }
 
+    // Option holding a term, e.g. a function, added to the other options
+    pub(crate) fn with_term_opt(mut self, name: &str, term: Command) -> Self {
+        let mut opts = match self.opts.take() {
+            Some(Ok(Datum::Object(opts))) => opts,
+            Some(Err(error)) => {
+                self.opts = Some(Err(error));
+                return self;
+            }
+            _ => HashMap::new(),
+        };
+        let opt = match term {
+            Command {
+                typ: TermType::Datum,
+                datum: Some(Ok(datum)),
+                ..
+            } => datum,
+            term => Datum::Term(Box::new(term)),
+        };
+
+        opts.insert(name.to_owned(), opt);
+        self.opts = Some(Ok(Datum::Object(opts)));
+        self
+    }
+
+    // The terms of the options, see `with_term_opt`
+    fn opt_terms(&self) -> impl Iterator<Item = &Command> {
+        let opts = match &self.opts {
+            Some(Ok(Datum::Object(opts))) => Some(opts.values()),
+            _ => None,
+        };
+
+        opts.into_iter().flatten().filter_map(|opt| match opt {
+            Datum::Term(term) => Some(term.as_ref()),
+            _ => None,
+        })
+    }
+
+    // Replaces the terms of the options, in the order of their names
+    fn map_opt_terms(mut self, mut f: impl FnMut(Command) -> Command) -> Self {
+        if let Some(Ok(Datum::Object(opts))) = &mut self.opts {
+            let mut terms: Vec<_> = opts
+                .iter_mut()
+                .filter_map(|(name, opt)| match opt {
+                    Datum::Term(term) => Some((name, term)),
+                    _ => None,
+                })
+                .collect();
+
+            terms.sort_by_key(|(name, _)| *name);
+            for (_, term) in terms {
+                let taken = std::mem::replace(term.as_mut(), Command::new(TermType::Datum));
+                **term = f(taken);
+            }
+        }
+        self
+    }
+
     #[doc(hidden)]
     pub fn from_json<T>(arg: T) -> Self
     where
@@ -131,6 +225,559 @@ impl Command {
         self.change_feed
     }
 
+    // Client-side limit on the run of the query, see `RunOption::timeout`
+    pub(crate) fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    // The smallest limit of the terms of the query, wherever they are
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.args
+            .iter()
+            .flatten()
+            .filter_map(Command::timeout)
+            .chain(self.timeout)
+            .min()
+    }
+
+    // Read mode used when the run options do not set one, see `Command::outdated`
+    pub(crate) fn with_read_mode(mut self, read_mode: ReadMode) -> Self {
+        self.read_mode = Some(read_mode);
+        self
+    }
+
+    // The read mode of the outermost term setting one
+    pub(crate) fn read_mode(&self) -> Option<ReadMode> {
+        self.read_mode
+            .or_else(|| self.args.iter().flatten().find_map(Command::read_mode))
+    }
+
+    // Writes which can be retried, see `Command::idempotent`
+    pub(crate) fn with_idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    pub(crate) fn is_idempotent(&self) -> bool {
+        self.idempotent
+    }
+
+    // Whether a term of the query may change the data or the
+    // configuration of the server, see `retry::Idempotency::of`
+    pub(crate) fn has_write(&self) -> bool {
+        // the terms of raw and prepared queries are only known as JSON
+        let json_has_write = |datum: &Option<super::Result<Datum>>| match datum {
+            Some(Ok(Datum::Raw(value))) => wire_has_write(value),
+            Some(Ok(Datum::Prepared(json))) => {
+                serde_json::from_str(json.get()).map_or(true, |value: Value| wire_has_write(&value))
+            }
+            _ => false,
+        };
+
+        is_write(self.typ)
+            || json_has_write(&self.datum)
+            || json_has_write(&self.opts)
+            || self.args.iter().flatten().any(Self::has_write)
+            || self.opt_terms().any(Self::has_write)
+    }
+
+    pub(crate) fn with_table_prefix(mut self, prefix: &str) -> Self {
+        let names_table = matches!(
+            self.typ,
+            TermType::Table | TermType::TableCreate | TermType::TableDrop
+        );
+
+        if names_table && !self.in_system_db() {
+            if let Some(Ok(Datum::String(name))) = self
+                .args
+                .back_mut()
+                .and_then(|arg| arg.as_mut().ok())
+                .and_then(|arg| arg.datum.as_mut())
+            {
+                name.insert_str(0, prefix);
+            }
+        }
+
+        self.args = self
+            .args
+            .into_iter()
+            .map(|arg| arg.map(|arg| arg.with_table_prefix(prefix)))
+            .collect();
+        self.map_opt_terms(|term| term.with_table_prefix(prefix))
+    }
+
+    // `r.db("rethinkdb").table(..)`
+    fn in_system_db(&self) -> bool {
+        match self.args.front() {
+            Some(Ok(db)) if self.args.len() > 1 => db.db_name() == Some(SYSTEM_DB),
+            _ => false,
+        }
+    }
+
+    // The string of a datum, e.g. a table name
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match &self.datum {
+            Some(Ok(Datum::String(string))) if self.typ == TermType::Datum => Some(string),
+            _ => None,
+        }
+    }
+
+    // `literal` is only legal in the object passed to `merge` or `update`,
+    // where the server evaluates it with its `LITERAL_OK` flag
+    pub(crate) fn check_literals(&self) -> super::Result<()> {
+        self.literals_in(false)
+    }
+
+    fn literals_in(&self, literal_ok: bool) -> super::Result<()> {
+        if self.typ == TermType::Literal && !literal_ok {
+            return Err(err::ReqlDriverError::Other(
+                "`literal` is only legal inside of the object passed to `merge` or `update`, \
+                 through `r.hash_map` with string keys, and cannot nest inside other literals"
+                    .to_owned(),
+            )
+            .into());
+        }
+
+        for (i, arg) in self.args.iter().enumerate() {
+            let literal_ok = match self.typ {
+                TermType::Update | TermType::Merge => literal_ok || i > 0,
+                TermType::MakeObj | TermType::Func | TermType::Branch | TermType::Funcall => {
+                    literal_ok
+                }
+                _ => false,
+            };
+
+            if let Ok(arg) = arg {
+                arg.literals_in(literal_ok)?;
+            }
+        }
+
+        for term in self.opt_terms() {
+            term.literals_in(false)?;
+        }
+
+        Ok(())
+    }
+
+    // Length of the JSON of the query, see `Command::estimate_wire_size`
+    pub(crate) fn wire_size(&self) -> super::Result<usize> {
+        let mut counter = ByteCounter(0);
+        serde_json::to_writer(&mut counter, &Query(self))?;
+        Ok(counter.0)
+    }
+
+    // Nesting of the terms and datums of the query, see `Command::term_depth`
+    pub(crate) fn depth(&self) -> usize {
+        let datum = match &self.datum {
+            Some(Ok(datum)) => datum.depth(),
+            _ => 0,
+        };
+        let opts = match &self.opts {
+            Some(Ok(Datum::Raw(opts))) => wire_depth(opts),
+            Some(Ok(opts)) if self.opt_terms().next().is_some() => opts.depth(),
+            _ => 0,
+        };
+        let args = self
+            .args
+            .iter()
+            .flatten()
+            .map(Self::depth)
+            .chain([opts])
+            .max()
+            .unwrap_or_default();
+
+        datum.max(args + 1)
+    }
+
+    // Mistakes the server would report on running the query, see `Command::validate`
+    pub(crate) fn diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        self.push_diagnostics(&mut diagnostics);
+        diagnostics
+    }
+
+    fn push_diagnostics(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let sequence = match self.args.front() {
+            Some(Ok(sequence)) => Some(sequence),
+            _ => None,
+        };
+
+        match self.typ {
+            typ if is_aggregation(typ) && sequence.is_some_and(Self::is_changefeed) => {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::AggregationOfChangefeed,
+                    self.term_name(),
+                    "cannot be called on the infinite stream of `changes`",
+                ));
+            }
+            TermType::OrderBy => {
+                let between_index = sequence
+                    .filter(|sequence| sequence.typ == TermType::Between)
+                    .and_then(Self::index_opt);
+
+                if let (Some(between_index), Some(index)) = (between_index, self.index_opt()) {
+                    if between_index != index {
+                        diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::OrderByIndexAfterBetween,
+                            self.term_name(),
+                            format!(
+                                "cannot order by index `{}` after `between` on index `{}`",
+                                index, between_index
+                            ),
+                        ));
+                    }
+                }
+            }
+            TermType::GetIntersecting | TermType::GetNearest => {
+                if let Some(Ok(geometry)) = self.args.get(1) {
+                    if let Some(field) = geometry.as_str() {
+                        diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::GeometryAsString,
+                            self.term_name(),
+                            format!(
+                                "expects a geometry, not the string `{}`, \
+                                 the field is given by the `index`",
+                                field
+                            ),
+                        ));
+                    }
+                }
+                if !matches!(self.index_opt(), Some(index) if !index.is_empty()) {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::MissingGeoIndex,
+                        self.term_name(),
+                        "requires the geospatial `index` to read",
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        for arg in self.args.iter().flatten().chain(self.opt_terms()) {
+            arg.push_diagnostics(diagnostics);
+        }
+    }
+
+    // A stream of `changes`, possibly transformed document by document
+    fn is_changefeed(&self) -> bool {
+        match self.typ {
+            TermType::Changes => true,
+            TermType::Filter
+            | TermType::Map
+            | TermType::ConcatMap
+            | TermType::Pluck
+            | TermType::Without
+            | TermType::WithFields
+            | TermType::HasFields
+            | TermType::Merge
+            | TermType::GetField
+            | TermType::Bracket
+            | TermType::Skip => {
+                matches!(self.args.front(), Some(Ok(sequence)) if sequence.is_changefeed())
+            }
+            _ => false,
+        }
+    }
+
+    // The name of the `index` option, of `r.asc(index)` or `r.desc(index)`
+    fn index_opt(&self) -> Option<&str> {
+        match self.opts.as_ref()? {
+            Ok(Datum::Object(opts)) => match opts.get("index")? {
+                Datum::String(index) => Some(index),
+                // `r.asc(index)` or `r.desc(index)`
+                Datum::Term(order) => order.args.front()?.as_ref().ok()?.as_str(),
+                _ => None,
+            },
+            Ok(Datum::Raw(opts)) => match opts.get("index")? {
+                Value::String(index) => Some(index),
+                Value::Array(order) => order.get(1)?.get(0)?.as_str(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    // Numbers the variables of the functions from 1 in the order they are
+    // bound, so the query sent or printed does not depend on `var_counter`
+    pub(crate) fn with_query_vars(self) -> Self {
+        self.number_vars(&mut HashMap::new(), &mut 0)
+    }
+
+    fn number_vars(mut self, scope: &mut HashMap<u64, u64>, next: &mut u64) -> Self {
+        if self.typ == TermType::Var {
+            if let Some(Ok(index)) = self.args.front_mut() {
+                if let Some(id) = index.var_id().and_then(|id| scope.get(&id)) {
+                    *index = Self::from_json(id);
+                }
+            }
+            return self;
+        }
+
+        let bound = match self.typ {
+            TermType::Func => {
+                let ids = match self.args.front() {
+                    Some(Ok(ids)) => ids.var_ids(),
+                    _ => Vec::new(),
+                };
+                let bound = bind_vars(&ids, scope, next);
+                let numbered: Vec<_> = ids.iter().map(|id| scope[id]).collect();
+                if let Some(Ok(ids)) = self.args.front_mut() {
+                    *ids = Self::from_json(numbered);
+                }
+                bound
+            }
+            _ => Vec::new(),
+        };
+
+        if let Some(first) = implicit_var_args(self.typ) {
+            self.args = self
+                .args
+                .into_iter()
+                .enumerate()
+                .map(|(i, arg)| match arg {
+                    Ok(arg) if i >= first && arg.wraps_implicit_var() => {
+                        let id = var_counter();
+                        Ok(Func::new(vec![id], arg.replace_implicit_var(id)).0)
+                    }
+                    arg => arg,
+                })
+                .collect();
+        }
+
+        self.args = self
+            .args
+            .into_iter()
+            .map(|arg| arg.map(|arg| arg.number_vars(scope, next)))
+            .collect();
+        for raw in [&mut self.datum, &mut self.opts] {
+            if let Some(Ok(Datum::Raw(value))) = raw {
+                number_wire_vars(value, scope, next);
+            }
+        }
+        self = self.map_opt_terms(|term| term.number_vars(scope, next));
+        unbind_vars(bound, scope);
+        self
+    }
+
+    // An argument using `r.row()`, which is not already a function
+    // or a sort order wrapping its own
+    fn wraps_implicit_var(&self) -> bool {
+        !matches!(self.typ, TermType::Func | TermType::Asc | TermType::Desc)
+            && self.has_implicit_var()
+    }
+
+    fn has_implicit_var(&self) -> bool {
+        self.typ == TermType::ImplicitVar
+            || self
+                .args
+                .iter()
+                .any(|arg| matches!(arg, Ok(arg) if arg.has_implicit_var()))
+    }
+
+    fn replace_implicit_var(mut self, id: u64) -> Self {
+        if self.typ == TermType::ImplicitVar {
+            return Self::var(id);
+        }
+
+        self.args = self
+            .args
+            .into_iter()
+            .map(|arg| arg.map(|arg| arg.replace_implicit_var(id)))
+            .collect();
+        self
+    }
+
+    fn var_id(&self) -> Option<u64> {
+        match &self.datum {
+            Some(Ok(Datum::Number(id))) => id.as_u64(),
+            _ => None,
+        }
+    }
+
+    // The parameters of a function, built by `Func::new` or read from `r.raw`
+    fn var_ids(&self) -> Vec<u64> {
+        match &self.datum {
+            Some(Ok(Datum::Array(ids))) => ids
+                .iter()
+                .filter_map(|id| match id {
+                    Datum::Number(id) => id.as_u64(),
+                    _ => None,
+                })
+                .collect(),
+            _ => self
+                .args
+                .iter()
+                .filter_map(|id| id.as_ref().ok().and_then(Self::var_id))
+                .collect(),
+        }
+    }
+
+    // The query with its JSON replaced by `json`, keeping what is needed to run it
+    pub(crate) fn prepared(&self, json: Box<RawValue>) -> Self {
+        Self {
+            typ: self.typ,
+            datum: Some(Ok(Datum::Prepared(json))),
+            args: VecDeque::new(),
+            opts: None,
+            change_feed: self.change_feed,
+            timeout: self.timeout,
+            read_mode: self.read_mode,
+            idempotent: self.idempotent,
+        }
+    }
+
+    // The JSON of the query split around its `r.param` placeholders
+    pub(crate) fn template(&self) -> super::Result<Vec<Segment>> {
+        let mut segments = Vec::new();
+        self.write_template(&mut segments)?;
+        Ok(segments)
+    }
+
+    fn write_template(&self, segments: &mut Vec<Segment>) -> super::Result<()> {
+        if let Some(Ok(Datum::Param(name))) = &self.datum {
+            segments.push(Segment::Param(name.clone()));
+            return Ok(());
+        }
+        if !self.has_params() {
+            let json = serde_json::to_string(&Query(self))?;
+            push_json(segments, &json);
+            return Ok(());
+        }
+
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.as_ref().map_err(Clone::clone))
+            .collect::<super::Result<Vec<_>>>()?;
+
+        if self.typ == TermType::MakeObj {
+            push_json(segments, "{");
+            for (i, pair) in args.chunks(2).enumerate() {
+                let key = pair[0].as_str().ok_or_else(|| {
+                    err::ReqlDriverError::Other("object keys must be strings".to_owned())
+                })?;
+                if i > 0 {
+                    push_json(segments, ",");
+                }
+                push_json(segments, &format!("{}:", serde_json::to_string(key)?));
+                if let Some(value) = pair.get(1) {
+                    value.write_template(segments)?;
+                }
+            }
+            push_json(segments, "}");
+            return Ok(());
+        }
+
+        push_json(segments, &format!("[{},[", self.typ as i32));
+        for (i, arg) in args.into_iter().enumerate() {
+            if i > 0 {
+                push_json(segments, ",");
+            }
+            arg.write_template(segments)?;
+        }
+        push_json(segments, "]");
+        match &self.opts {
+            Some(Ok(Datum::Object(opts))) if self.opt_terms().any(Self::has_params) => {
+                push_json(segments, ",{");
+                for (i, (name, opt)) in opts.iter().enumerate() {
+                    if i > 0 {
+                        push_json(segments, ",");
+                    }
+                    push_json(segments, &format!("{}:", serde_json::to_string(name)?));
+                    match opt {
+                        Datum::Term(term) => term.write_template(segments)?,
+                        opt => push_json(segments, &serde_json::to_string(opt)?),
+                    }
+                }
+                push_json(segments, "}");
+            }
+            Some(Ok(opts)) => push_json(segments, &format!(",{}", serde_json::to_string(opts)?)),
+            Some(Err(error)) => return Err(error.clone()),
+            None => {}
+        }
+        push_json(segments, "]");
+        Ok(())
+    }
+
+    fn has_params(&self) -> bool {
+        matches!(self.datum, Some(Ok(Datum::Param(_))))
+            || self
+                .args
+                .iter()
+                .any(|arg| matches!(arg, Ok(arg) if arg.has_params()))
+            || self.opt_terms().any(Self::has_params)
+    }
+
+    // Name of the method of the outermost term, e.g. `count`
+    pub(crate) fn term_name(&self) -> String {
+        crate::printer::name(self.typ)
+    }
+
+    // The name of `r.db("name")`
+    pub(crate) fn db_name(&self) -> Option<&str> {
+        match self.args.front() {
+            Some(Ok(Command {
+                datum: Some(Ok(Datum::String(name))),
+                ..
+            })) if self.typ == TermType::Db && self.args.len() == 1 => Some(name),
+            _ => None,
+        }
+    }
+
+    // `[type, [args...], {opts}]` for terms, anything else for datums
+    pub(crate) fn from_wire(value: Value) -> super::Result<Self> {
+        let term = match value {
+            Value::Array(term) => term,
+            // Object values are terms, keep them as sent
+            Value::Object(map) if map.values().any(has_term) => {
+                return Ok(Datum::Raw(Value::Object(map)).into());
+            }
+            value => return Ok(value.into()),
+        };
+
+        let invalid = || err::ReqlDriverError::Other(format!("invalid ReQL term: {:?}", term));
+        let mut parts = term.iter().cloned();
+        let typ = parts
+            .next()
+            .and_then(|typ| typ.as_i64())
+            .and_then(|typ| TermType::from_i32(typ as i32))
+            .filter(|typ| *typ != TermType::Datum)
+            .ok_or_else(invalid)?;
+        let mut command = Self::new(typ);
+
+        match parts.next() {
+            Some(Value::Array(args)) => {
+                for arg in args {
+                    let arg = Self::from_wire(arg)?;
+                    command.change_feed = command.change_feed || arg.change_feed;
+                    command.args.push_back(Ok(arg));
+                }
+            }
+            None => {}
+            Some(_) => return Err(invalid().into()),
+        }
+
+        match parts.next() {
+            Some(opts @ Value::Object(_)) if has_term(&opts) => {
+                command.opts = Some(Ok(Datum::Raw(opts)));
+            }
+            Some(opts @ Value::Object(_)) => command.opts = Some(Ok(opts.into())),
+            None => {}
+            Some(_) => return Err(invalid().into()),
+        }
+
+        if parts.next().is_some() {
+            return Err(invalid().into());
+        }
+
+        if typ == TermType::Changes {
+            command.change_feed = true;
+        }
+
+        Ok(command)
+    }
+
     // pub(crate) fn into_arg(&self) -> Self {
     //     Command::new(TermType::Datum).with_arg(self.to_owned())
     // }
@@ -167,6 +814,208 @@ impl From<super::Result<Value>> for Command {
     }
 }
 
+fn has_term(value: &Value) -> bool {
+    match value {
+        Value::Array(_) => true,
+        Value::Object(map) => map.values().any(has_term),
+        _ => false,
+    }
+}
+
+// Part of the JSON of a prepared query
+#[derive(Debug, Clone)]
+pub(crate) enum Segment {
+    Json(String),
+    Param(String),
+}
+
+fn push_json(segments: &mut Vec<Segment>, json: &str) {
+    match segments.last_mut() {
+        Some(Segment::Json(last)) => last.push_str(json),
+        _ => segments.push(Segment::Json(json.to_owned())),
+    }
+}
+
+// The terms reading a whole sequence, which cannot end on a changefeed
+fn is_aggregation(typ: TermType) -> bool {
+    matches!(
+        typ,
+        TermType::Reduce
+            | TermType::Count
+            | TermType::Sum
+            | TermType::Avg
+            | TermType::Min
+            | TermType::Max
+            | TermType::Group
+            | TermType::Distinct
+            | TermType::OrderBy
+            | TermType::IsEmpty
+            | TermType::Contains
+            | TermType::CoerceTo
+    )
+}
+
+fn is_write(typ: TermType) -> bool {
+    matches!(
+        typ,
+        TermType::Insert
+            | TermType::Update
+            | TermType::Replace
+            | TermType::Delete
+            | TermType::DbCreate
+            | TermType::DbDrop
+            | TermType::TableCreate
+            | TermType::TableDrop
+            | TermType::IndexCreate
+            | TermType::IndexDrop
+            | TermType::IndexRename
+            | TermType::Reconfigure
+            | TermType::Rebalance
+            | TermType::Grant
+            | TermType::SetWriteHook
+            | TermType::Javascript
+            | TermType::Http
+    )
+}
+
+// Whether a term of a query serialized as JSON is a write
+fn wire_has_write(value: &Value) -> bool {
+    match value {
+        Value::Array(term) => {
+            term.first()
+                .and_then(Value::as_i64)
+                .and_then(|typ| TermType::from_i32(typ as i32))
+                .is_some_and(is_write)
+                || term.iter().skip(1).any(wire_has_write)
+        }
+        Value::Object(map) => map.values().any(wire_has_write),
+        _ => false,
+    }
+}
+
+// The index of the first argument of a command which may be a
+// function of `r.row()`, after the sequence it is called on.
+// Join predicates take two documents, so they are never wrapped.
+fn implicit_var_args(typ: TermType) -> Option<usize> {
+    match typ {
+        TermType::Asc | TermType::Desc => Some(0),
+        TermType::Filter
+        | TermType::Map
+        | TermType::ConcatMap
+        | TermType::OrderBy
+        | TermType::Update
+        | TermType::Replace
+        | TermType::ForEach
+        | TermType::Merge
+        | TermType::Contains
+        | TermType::Count
+        | TermType::Group
+        | TermType::Sum
+        | TermType::Avg
+        | TermType::Min
+        | TermType::Max
+        | TermType::IndexCreate => Some(1),
+        _ => None,
+    }
+}
+
+// Gives the next numbers to the parameters of a function, returning
+// the numbers they shadow
+fn bind_vars(
+    ids: &[u64],
+    scope: &mut HashMap<u64, u64>,
+    next: &mut u64,
+) -> Vec<(u64, Option<u64>)> {
+    ids.iter()
+        .map(|&id| {
+            *next += 1;
+            (id, scope.insert(id, *next))
+        })
+        .collect()
+}
+
+fn unbind_vars(bound: Vec<(u64, Option<u64>)>, scope: &mut HashMap<u64, u64>) {
+    for (id, shadowed) in bound.into_iter().rev() {
+        match shadowed {
+            Some(number) => scope.insert(id, number),
+            None => scope.remove(&id),
+        };
+    }
+}
+
+// `Command::depth` of wire JSON
+fn wire_depth(value: &Value) -> usize {
+    let children = match value {
+        Value::Array(term) => {
+            let args = term.get(1).and_then(Value::as_array).into_iter().flatten();
+            let opts = term.get(2).and_then(Value::as_object).into_iter();
+            args.chain(opts.flat_map(|opts| opts.values()))
+                .map(wire_depth)
+                .max()
+        }
+        Value::Object(map) => map.values().map(wire_depth).max(),
+        _ => return 1,
+    };
+
+    1 + children.unwrap_or_default()
+}
+
+// Counts the bytes of the JSON of a query without writing it
+struct ByteCounter(usize);
+
+impl io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// `Command::number_vars` over wire JSON, e.g. functions in options
+fn number_wire_vars(value: &mut Value, scope: &mut HashMap<u64, u64>, next: &mut u64) {
+    let term = match value {
+        Value::Array(term) => term,
+        Value::Object(map) => {
+            map.values_mut()
+                .for_each(|value| number_wire_vars(value, scope, next));
+            return;
+        }
+        _ => return,
+    };
+    let typ = term.first().and_then(Value::as_i64);
+
+    if typ == Some(TermType::Var as i64) {
+        if let Some(index) = term.get_mut(1).and_then(|args| args.get_mut(0)) {
+            if let Some(id) = index.as_u64().and_then(|id| scope.get(&id)) {
+                *index = (*id).into();
+            }
+        }
+        return;
+    }
+
+    let mut bound = Vec::new();
+    if typ == Some(TermType::Func as i64) {
+        // [FUNC, [[MAKE_ARRAY, [ids..]], body]]
+        if let Some(Value::Array(ids)) = term.get_mut(1).and_then(|args| args.pointer_mut("/0/1")) {
+            let old: Vec<_> = ids.iter().filter_map(Value::as_u64).collect();
+            bound = bind_vars(&old, scope, next);
+            *ids = old.iter().map(|id| scope[id].into()).collect();
+        }
+    }
+
+    if let Some(Value::Array(args)) = term.get_mut(1) {
+        args.iter_mut()
+            .for_each(|arg| number_wire_vars(arg, scope, next));
+    }
+    if let Some(opts) = term.get_mut(2) {
+        number_wire_vars(opts, scope, next);
+    }
+    unbind_vars(bound, scope);
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Query<'a>(pub(crate) &'a Command);
 
@@ -176,12 +1025,31 @@ impl Serialize for Query<'_> {
         S: Serializer,
     {
         let Query(cmd) = self;
+        if let Some(Ok(Datum::Prepared(json))) = &cmd.datum {
+            return json.serialize(serializer);
+        }
         match cmd.typ {
             TermType::Datum => match &cmd.datum {
                 Some(Ok(datum)) => datum.serialize(serializer),
                 Some(Err(error)) => Err(ser::Error::custom(error)),
                 _ => (None as Option<Datum>).serialize(serializer),
             },
+            // `{key: value}` objects, whose values may be terms
+            TermType::MakeObj => {
+                let mut map = serializer.serialize_map(Some(cmd.args.len() / 2))?;
+                let mut args = cmd.args.iter();
+
+                while let (Some(key), Some(value)) = (args.next(), args.next()) {
+                    let key = key.as_ref().map_err(ser::Error::custom)?;
+                    let value = value.as_ref().map_err(ser::Error::custom)?;
+                    let key = key
+                        .as_str()
+                        .ok_or_else(|| ser::Error::custom("object keys must be strings"))?;
+                    map.serialize_entry(key, &Query(value))?;
+                }
+
+                map.end()
+            }
             _ => {
                 let typ = cmd.typ as i32;
                 match &cmd.opts {
@@ -260,3 +1128,176 @@ impl Serialize for Db {
         Query(&cmd).serialize(serializer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Query;
+    use crate::arguments::{InsertOption, Interleave, UnionOption};
+    use crate::{args, r, Func};
+
+    fn serialize(query: crate::Command) -> String {
+        serde_json::to_string(&Query(&query.with_table_prefix("staging_"))).unwrap()
+    }
+
+    #[test]
+    fn table_prefix() {
+        let query = r.db("shop").table("users").get_all(["a"]);
+        let system_query = r.db("rethinkdb").table("jobs");
+
+        assert!(serialize(query).contains("\"staging_users\""));
+        assert!(serialize(r.table_create("users")).contains("\"staging_users\""));
+        assert!(!serialize(system_query).contains("staging_"));
+    }
+
+    #[test]
+    fn term_opts() {
+        let likes = Func::new(vec![1], r.table("likes").get(crate::Command::var(1)));
+        let opts = UnionOption::default().interleave(Interleave::Func(likes));
+        let query = r.table("users").union(args!([r.table("posts")], opts));
+        let literal = Func::new(vec![1, 2, 3], r.literal(json!({"a": 1})));
+        let insert = r
+            .table("users")
+            .insert(args!(json!({}), InsertOption::default(), literal));
+
+        assert!(serialize(query.clone()).contains("\"staging_likes\""));
+        assert!(insert.check_literals().is_err());
+    }
+
+    #[test]
+    fn wire_json_round_trip() {
+        let queries = [
+            r.db("shop")
+                .table("users")
+                .get_all(["a", "b"])
+                .pluck(["name"]),
+            r.expr(json!({"tags": ["a"], "nested": {"n": 1}})),
+            r.table("users").changes(()),
+            r.table("users").index_create("name"),
+        ];
+
+        for query in queries {
+            let wire = query.to_wire_json().unwrap();
+            let command = crate::Command::from_wire_json(wire.clone()).unwrap();
+
+            assert_eq!(command.to_wire_json().unwrap(), wire);
+            assert_eq!(command.change_feed(), query.change_feed());
+        }
+
+        assert!(crate::Command::from_wire_json(json!([1000, []])).is_err());
+        assert!(crate::Command::from_wire_json(json!([15, "users"])).is_err());
+    }
+
+    #[test]
+    fn implicit_var() {
+        let query = r
+            .table("users")
+            .filter(r.row().g("age").gt(18))
+            .map(r.row().g("name"));
+
+        assert_eq!(
+            query.to_wire_json().unwrap(),
+            json!([
+                38,
+                [
+                    [
+                        39,
+                        [
+                            [15, ["users"], {}],
+                            [69, [[2, [1]], [21, [[31, [[10, [1]], "age"]], 18]]]]
+                        ],
+                        {}
+                    ],
+                    [69, [[2, [2]], [31, [[10, [2]], "name"]]]]
+                ]
+            ])
+        );
+
+        let join = r
+            .table("users")
+            .inner_join(r.table("posts"), r.row().g("id"));
+
+        assert_eq!(
+            join.with_query_vars().to_wire_json().unwrap()[1][2],
+            json!([31, [[13, []], "id"]])
+        );
+    }
+
+    #[test]
+    fn query_vars() {
+        use crate::arguments::FoldOption;
+        use crate::{args, var_counter, Command, Func};
+
+        // `|user| table.filter(|post| post("author").eq(user("id")))`
+        let query = || {
+            let (user, post) = (var_counter(), var_counter());
+            let posts = r.table("posts").filter(Func::new(
+                vec![post],
+                Command::var(post)
+                    .g("author")
+                    .eq(Command::var(user).g("id")),
+            ));
+            let emit = Func::new(vec![user], Command::var(user));
+            let opts = FoldOption::default().final_emit(emit);
+
+            r.table("users")
+                .map(Func::new(vec![user], posts))
+                .fold(0, args!(Func::new(vec![post], Command::var(post)), opts))
+        };
+        let wire = query().to_wire_json().unwrap();
+
+        assert_eq!(query().to_wire_json().unwrap(), wire);
+        assert_eq!(
+            wire[1][0][1][1],
+            json!([
+                69,
+                [
+                    [2, [1]],
+                    [
+                        39,
+                        [
+                            [15, ["posts"], {}],
+                            [
+                                69,
+                                [
+                                    [2, [2]],
+                                    [17, [[31, [[10, [2]], "author"]], [31, [[10, [1]], "id"]]]]
+                                ]
+                            ],
+                        ],
+                        {}
+                    ]
+                ]
+            ])
+        );
+        assert_eq!(wire[1][2], json!([69, [[2, [3]], [10, [3]]]]));
+        assert_eq!(wire[2], json!({"final_emit": [69, [[2, [4]], [10, [4]]]]}));
+    }
+
+    #[test]
+    fn literal_positions() {
+        use std::collections::HashMap;
+
+        let value = || r.literal(json!({"age": 19}));
+        let object = || r.hash_map(HashMap::from([("data", value())]));
+
+        assert_eq!(
+            object().to_wire_json().unwrap(),
+            json!({"data": [137, [{"age": 19}]]})
+        );
+        assert!(r.table("users").update(object()).check_literals().is_ok());
+        assert!(r.expr(json!({})).merge(value()).check_literals().is_ok());
+        assert!(r.table("users").insert(object()).check_literals().is_err());
+        assert!(r
+            .literal(value())
+            .merge(json!({}))
+            .check_literals()
+            .is_err());
+        assert!(r
+            .table("users")
+            .update(r.object([r.expr("data"), value()]))
+            .check_literals()
+            .is_err());
+    }
+}
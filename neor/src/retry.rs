@@ -0,0 +1,331 @@
+//! Run queries again when the server is unavailable
+//!
+//! A [RetryPolicy] runs a query again, after a growing delay, when it fails
+//! because a replica or the server was unavailable. Only the queries which
+//! can run twice without harm are retried after an outcome which may have
+//! been applied, see [Idempotency].
+//!
+//! The policy is used by [run_with_retry](crate::Command::run_with_retry),
+//! and for all the queries of a session opened with
+//! [retry_policy](crate::types::ConnectionCommand::retry_policy) by the
+//! methods reading a whole result: [run](crate::Command::run),
+//! [run_typed](crate::Command::run_typed),
+//! [run_joined](crate::Command::run_joined),
+//! [insert_batched](crate::Command::insert_batched) for each chunk, and
+//! [PreparedQuery::run](crate::cmd::prepare::PreparedQuery::run).
+//! The streams of [build_query](crate::Command::build_query) and the change
+//! feeds are never retried, as their first responses were already read.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use neor::retry::RetryPolicy;
+//! use neor::{r, Result};
+//! use serde_json::json;
+//!
+//! async fn example() -> Result<()> {
+//!     let conn = r.connection().connect().await?;
+//!     let policy = RetryPolicy::default()
+//!         .max_retries(5)
+//!         .backoff(Duration::from_millis(50));
+//!
+//!     // a read, always retried
+//!     r.table("posts").count(()).run_with_retry(&conn, &policy).await?;
+//!
+//!     // a write replacing a document with the same one, marked idempotent
+//!     r.table("posts")
+//!         .get(1)
+//!         .replace(json!({"id": 1, "title": "title"}))
+//!         .idempotent()
+//!         .run_with_retry(&conn, &policy)
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::fmt::{self, Debug};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::time;
+use tracing::trace;
+
+use crate::arguments::{Args, RunOption};
+use crate::err::{ReqlAvailabilityError, ReqlDriverError, ReqlError, ReqlRuntimeError};
+use crate::{Command, Connection, Result, Session};
+
+type Classifier = Arc<dyn Fn(&Command) -> Idempotency + Send + Sync>;
+
+/// Whether a query can be run twice without harm
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Idempotency {
+    /// The query only reads
+    Read,
+    /// The query writes, with the same outcome when it runs twice,
+    /// e.g. a `replace` marked with [idempotent](crate::Command::idempotent)
+    IdempotentWrite,
+    /// The query writes, e.g. `insert` or `update` with `r.row().add(1)`
+    Write,
+}
+
+impl Idempotency {
+    /// Classify a query by its terms, the default of [RetryPolicy]
+    ///
+    /// A query is a `Write` when one of its terms, even in a function,
+    /// changes documents, tables, indexes or permissions, or runs JavaScript
+    /// or an HTTP request, unless it was marked with
+    /// [idempotent](crate::Command::idempotent). The terms of
+    /// [raw](crate::r::raw) queries are read from their JSON.
+    pub fn of(query: &Command) -> Self {
+        match query.has_write() {
+            false => Self::Read,
+            true if query.is_idempotent() => Self::IdempotentWrite,
+            true => Self::Write,
+        }
+    }
+
+    /// Whether a query of this kind can run again after failing with `error`
+    ///
+    /// An `OpFailed` query was not applied, so it can always run again.
+    /// After an `OpIndeterminate` error or a broken connection, the query
+    /// may have been applied, so only reads and idempotent writes run again.
+    pub fn can_retry(self, error: &ReqlError) -> bool {
+        match error {
+            ReqlError::Runtime(ReqlRuntimeError::Availability(error)) => match error {
+                ReqlAvailabilityError::OpFailed(_) => true,
+                ReqlAvailabilityError::OpIndeterminate(_) => self != Self::Write,
+            },
+            ReqlError::Driver(ReqlDriverError::ConnectionBroken | ReqlDriverError::Io(..)) => {
+                self != Self::Write
+            }
+            _ => false,
+        }
+    }
+}
+
+/// When and how often a failed query runs again
+///
+/// By default a query runs again up to 3 times, waiting 100 milliseconds
+/// before the first retry, twice as long before each next one, and at
+/// most 2 seconds.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+    classifier: Option<Classifier>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+            classifier: None,
+        }
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("backoff", &self.backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("classifier", &self.classifier.is_some())
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+    /// Run a failed query again up to `retries` times
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Wait `delay` before the first retry, doubled before each next one
+    pub fn backoff(mut self, delay: Duration) -> Self {
+        self.backoff = delay;
+        self
+    }
+
+    /// Never wait longer than `delay` before a retry
+    pub fn max_backoff(mut self, delay: Duration) -> Self {
+        self.max_backoff = delay;
+        self
+    }
+
+    /// Classify the queries with `classifier` instead of [Idempotency::of]
+    ///
+    /// ```
+    /// use neor::retry::{Idempotency, RetryPolicy};
+    ///
+    /// // never run a query twice after an indeterminate outcome
+    /// let policy = RetryPolicy::default().classify(|_| Idempotency::Write);
+    /// ```
+    pub fn classify<F>(mut self, classifier: F) -> Self
+    where
+        F: Fn(&Command) -> Idempotency + Send + Sync + 'static,
+    {
+        self.classifier = Some(Arc::new(classifier));
+        self
+    }
+
+    /// The kind of `query`, as classified by the policy
+    pub fn idempotency(&self, query: &Command) -> Idempotency {
+        match &self.classifier {
+            Some(classifier) => classifier(query),
+            None => Idempotency::of(query),
+        }
+    }
+
+    // The wait before the retry following `attempt` failed attempts
+    fn delay(&self, attempt: u32) -> Duration {
+        self.backoff
+            .saturating_mul(1 << attempt.min(31))
+            .min(self.max_backoff)
+    }
+}
+
+// Run `query` on `conn` with `attempt`, and again on new connections of its session
+pub(crate) async fn run<T, F, Fut>(
+    query: &Command,
+    conn: Connection,
+    opts: RunOption,
+    policy: &RetryPolicy,
+    attempt: F,
+) -> Result<T>
+where
+    F: Fn(Args<(Connection, RunOption)>) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let idempotency = policy.idempotency(query);
+    let session = conn.session.clone();
+    let mut conn = Some(conn);
+    let mut attempts = 0;
+
+    loop {
+        let conn = match conn.take() {
+            Some(conn) => Ok(conn),
+            None => reconnect(&session).await,
+        };
+        let result = match conn {
+            Ok(conn) => attempt(Args((conn, opts.clone()))).await,
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Err(error) if attempts < policy.max_retries && idempotency.can_retry(&error) => {
+                trace!(
+                    "retrying query; attempt: {}, error: {}",
+                    attempts + 1,
+                    error
+                );
+                time::sleep(policy.delay(attempts)).await;
+                attempts += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+async fn reconnect(session: &Session) -> Result<Connection> {
+    // as the `ping_interval` task does, a broken session is reconnected
+    if session.inner.broken().is_err() && session.inner.closed().is_ok() {
+        session.inner.command.reopen(&session.inner).await?;
+    }
+
+    session.connection()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::{Idempotency, RetryPolicy};
+    use crate::err::{
+        ErrorMessage, ReqlAvailabilityError, ReqlDriverError, ReqlError, ReqlRuntimeError,
+    };
+    use crate::{r, Func};
+
+    #[test]
+    fn idempotency() {
+        let posts = r.table("posts");
+        let insert = posts.insert(json!({"id": 1}));
+        let nested = r
+            .expr([1, 2])
+            .for_each(Func::new(vec![1], r.table("logs").delete(())));
+
+        assert_eq!(Idempotency::of(&posts.count(())), Idempotency::Read);
+        assert_eq!(Idempotency::of(&insert), Idempotency::Write);
+        assert_eq!(Idempotency::of(&insert.g("inserted")), Idempotency::Write);
+        assert_eq!(Idempotency::of(&nested), Idempotency::Write);
+        assert_eq!(
+            Idempotency::of(&insert.idempotent()),
+            Idempotency::IdempotentWrite
+        );
+        assert_eq!(
+            Idempotency::of(&insert.idempotent().g("inserted")),
+            Idempotency::IdempotentWrite
+        );
+
+        let policy = RetryPolicy::default().classify(|_| Idempotency::Read);
+        assert_eq!(policy.idempotency(&insert), Idempotency::Read);
+    }
+
+    #[test]
+    fn idempotency_of_side_effects_and_raw_terms() {
+        let raw_insert = r.raw(json!([56, [[15, ["posts"]], {"id": 1}]]));
+        let raw_count = r.raw(json!([43, [[15, ["posts"]]]]));
+        let raw_term = r.raw_term(56, [r.table("posts"), r.expr(json!({"id": 1}))], None);
+
+        assert_eq!(Idempotency::of(&raw_insert), Idempotency::Write);
+        assert_eq!(Idempotency::of(&raw_term), Idempotency::Write);
+        assert_eq!(Idempotency::of(&raw_count), Idempotency::Read);
+        assert_eq!(Idempotency::of(&r.js("1 + 1")), Idempotency::Write);
+        assert_eq!(
+            Idempotency::of(&r.http("http://example.com")),
+            Idempotency::Write
+        );
+    }
+
+    #[test]
+    fn retryable_errors() {
+        let message = || ErrorMessage::from("unavailable".to_owned());
+        let availability = |error| ReqlError::Runtime(ReqlRuntimeError::Availability(error));
+        let op_failed = availability(ReqlAvailabilityError::OpFailed(message()));
+        let indeterminate = availability(ReqlAvailabilityError::OpIndeterminate(message()));
+        let broken = ReqlError::Driver(ReqlDriverError::ConnectionBroken);
+        let logic = ReqlError::Runtime(ReqlRuntimeError::QueryLogic(message()));
+
+        for idempotency in [Idempotency::Read, Idempotency::IdempotentWrite] {
+            assert!(idempotency.can_retry(&op_failed));
+            assert!(idempotency.can_retry(&indeterminate));
+            assert!(idempotency.can_retry(&broken));
+            assert!(!idempotency.can_retry(&logic));
+        }
+
+        assert!(Idempotency::Write.can_retry(&op_failed));
+        assert!(!Idempotency::Write.can_retry(&indeterminate));
+        assert!(!Idempotency::Write.can_retry(&broken));
+    }
+
+    #[test]
+    fn retry_delay() {
+        let policy = RetryPolicy::default()
+            .backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(500));
+
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(400));
+        assert_eq!(policy.delay(3), Duration::from_millis(500));
+        assert_eq!(policy.delay(40), Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,156 @@
+//! Run a sequence of writes, undoing the applied ones when one fails
+//!
+//! ReQL has no transactions: a write changing several tables is a sequence
+//! of queries, and a failure leaves the first ones applied. A [Saga] runs
+//! the [steps](Saga::step) of such a sequence in order. When a step fails,
+//! the compensations of the applied steps, e.g. a `delete` undoing an
+//! `insert`, are run in reverse order before the error is returned.
+//!
+//! ```
+//! use neor::saga::Saga;
+//! use neor::{r, Result};
+//! use serde_json::json;
+//!
+//! async fn example() -> Result<()> {
+//!     let conn = r.connection().connect().await?;
+//!     let response = Saga::new()
+//!         .step(
+//!             r.table("orders").insert(json!({"id": 1, "item": "book"})),
+//!             r.table("orders").get(1).delete(()),
+//!         )
+//!         .step(
+//!             r.table("stock").get("book").update(json!({"count": 9})),
+//!             r.table("stock").get("book").update(json!({"count": 10})),
+//!         )
+//!         .run(&conn)
+//!         .await?;
+//!
+//!     assert_eq!(response.mutation().inserted, 1);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use serde_json::Value;
+
+use crate::types::MutationResponse;
+use crate::{err, Command, Converter, Result, Session};
+
+/// A sequence of writes with the queries undoing them
+#[derive(Debug, Clone, Default)]
+pub struct Saga {
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone)]
+struct Step {
+    action: Command,
+    compensation: Option<Command>,
+}
+
+/// The responses of the steps of a [Saga] which succeeded
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct SagaResponse {
+    /// The response of each step, in order
+    pub responses: Vec<Value>,
+}
+
+impl SagaResponse {
+    /// The counters of the writes of all the steps,
+    /// see [merge](MutationResponse::merge)
+    pub fn mutation(&self) -> MutationResponse {
+        self.responses
+            .iter()
+            .filter_map(|response| response.clone().parse::<MutationResponse>().ok())
+            .fold(MutationResponse::default(), |mut total, response| {
+                total.merge(response);
+                total
+            })
+    }
+}
+
+impl Saga {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `action`, undone by `compensation` if a later step fails
+    pub fn step(mut self, action: Command, compensation: Command) -> Self {
+        self.steps.push(Step {
+            action,
+            compensation: Some(compensation),
+        });
+        self
+    }
+
+    /// Run `action`, which needs no undoing, e.g. a read checking a
+    /// precondition or the last write of the saga
+    pub fn action(mut self, action: Command) -> Self {
+        self.steps.push(Step {
+            action,
+            compensation: None,
+        });
+        self
+    }
+
+    /// Run the steps in order
+    ///
+    /// A step fails when its query fails or when its response reports
+    /// `errors`, e.g. an `insert` of an existing primary key. The
+    /// compensations of the steps applied before it then run in reverse
+    /// order, and the error of the step is returned. When a compensation
+    /// fails too, the others still run, and the returned error lists the
+    /// steps which could not be undone.
+    pub async fn run(&self, session: &Session) -> Result<SagaResponse> {
+        let mut responses = Vec::with_capacity(self.steps.len());
+
+        for (index, step) in self.steps.iter().enumerate() {
+            match run(&step.action, session).await {
+                Ok(response) => responses.push(response),
+                Err(error) => return Err(self.compensate(index, error, session).await),
+            }
+        }
+
+        Ok(SagaResponse { responses })
+    }
+
+    // Undo the steps before `failed`, and return the error of the saga
+    async fn compensate(
+        &self,
+        failed: usize,
+        error: err::ReqlError,
+        session: &Session,
+    ) -> err::ReqlError {
+        let mut failures = Vec::new();
+
+        for (index, step) in self.steps[..failed].iter().enumerate().rev() {
+            if let Some(compensation) = &step.compensation {
+                if let Err(error) = run(compensation, session).await {
+                    failures.push(format!("step {}: {}", index, error));
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            return error;
+        }
+
+        err::ReqlDriverError::Other(format!(
+            "step {} failed: {}; compensations failed: {}",
+            failed,
+            error,
+            failures.join(", ")
+        ))
+        .into()
+    }
+}
+
+async fn run(query: &Command, session: &Session) -> Result<Value> {
+    let response = query.run(session).await?.unwrap_or_default();
+
+    match response.get("first_error").and_then(Value::as_str) {
+        Some(error) => Err(err::ReqlDriverError::Other(error.to_owned()).into()),
+        None => Ok(response),
+    }
+}
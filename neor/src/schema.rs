@@ -0,0 +1,394 @@
+//! Capture the schema of a server and reconcile it with another one
+//!
+//! A [SchemaSnapshot] lists the databases and tables of a server, with
+//! the primary key, the secondary indexes and the write hook of each
+//! table. It serializes to JSON, so the expected schema of an application
+//! can be kept in its repository. [diff] returns the commands changing a
+//! schema into another one, e.g. to deploy the expected schema from CI.
+//! It only drops databases and tables when asked to with [DiffOption].
+//!
+//! The indexes and write hooks are compared by their functions, as
+//! returned by `index_status` and `get_write_hook`.
+//!
+//! ```
+//! use neor::schema::{self, DiffOption, SchemaSnapshot};
+//! use neor::{r, Result};
+//!
+//! async fn example(expected: &str) -> Result<()> {
+//!     let conn = r.connection().connect().await?;
+//!     let expected: SchemaSnapshot = serde_json::from_str(expected)?;
+//!     let current = schema::snapshot(&conn).await?;
+//!
+//!     for command in schema::diff(&current, &expected, DiffOption::default())? {
+//!         command.run(&conn).await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::arguments::{Args, RunOption, TableCreateOption};
+use crate::system::SYSTEM_DB;
+use crate::types::{Binary, GetWriteHookResponse, IndexStatusResponse, InfoResponse};
+use crate::{err, r, Command, Converter, Result, Session};
+
+/// The databases of a server, sorted by name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SchemaSnapshot {
+    pub dbs: Vec<DbSnapshot>,
+}
+
+/// A database and its tables, sorted by name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DbSnapshot {
+    pub name: Cow<'static, str>,
+    pub tables: Vec<TableSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TableSnapshot {
+    pub name: Cow<'static, str>,
+    pub primary_key: Cow<'static, str>,
+    /// The secondary indexes, sorted by name
+    pub indexes: Vec<IndexSnapshot>,
+    /// the write hook function, as returned by `get_write_hook`
+    pub write_hook: Option<Binary>,
+}
+
+/// A secondary index of a table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IndexSnapshot {
+    pub name: Cow<'static, str>,
+    /// the index function, as returned by `index_status`
+    pub function: Binary,
+    pub multi: bool,
+    pub geo: bool,
+}
+
+/// How [diff] reconciles two schemas
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DiffOption {
+    /// Drop the databases and tables missing from the expected schema,
+    /// with their documents, instead of keeping them
+    pub drop_extra: bool,
+}
+
+impl DiffOption {
+    pub fn drop_extra(mut self, drop_extra: bool) -> Self {
+        self.drop_extra = drop_extra;
+        self
+    }
+}
+
+impl SchemaSnapshot {
+    pub fn db(&self, name: &str) -> Option<&DbSnapshot> {
+        self.dbs.iter().find(|db| db.name == name)
+    }
+}
+
+impl DbSnapshot {
+    pub fn table(&self, name: &str) -> Option<&TableSnapshot> {
+        self.tables.iter().find(|table| table.name == name)
+    }
+}
+
+impl TableSnapshot {
+    pub fn index(&self, name: &str) -> Option<&IndexSnapshot> {
+        self.indexes.iter().find(|index| index.name == name)
+    }
+}
+
+/// Capture the schema of the databases of the server,
+/// but the `rethinkdb` system database
+///
+/// The stored table names are captured, whatever the table prefix of the session.
+pub async fn snapshot(session: &Session) -> Result<SchemaSnapshot> {
+    let mut names: Vec<Cow<'static, str>> = run(&r.db_list(), session).await?;
+    let mut dbs = Vec::new();
+
+    names.retain(|name| name != SYSTEM_DB);
+    names.sort();
+
+    for name in names {
+        let db = r.db(name.as_ref());
+        let mut tables: Vec<Cow<'static, str>> = run(&db.table_list(), session).await?;
+        let mut snapshots = Vec::with_capacity(tables.len());
+
+        tables.sort();
+
+        for table in tables {
+            snapshots.push(table_snapshot(&db.table(table.as_ref()), session).await?);
+        }
+
+        dbs.push(DbSnapshot {
+            name,
+            tables: snapshots,
+        });
+    }
+
+    Ok(SchemaSnapshot { dbs })
+}
+
+/// The commands changing the schema `from` into the schema `to`
+///
+/// The missing databases, tables and indexes are created, waiting for
+/// the new indexes to be ready, then the write hooks are set and the
+/// extra indexes are dropped. An index whose function changed is dropped
+/// and created again. The extra tables and databases are only dropped with
+/// [drop_extra](DiffOption::drop_extra), and they can be checked first,
+/// e.g. with [to_reql_string](crate::Command::to_reql_string).
+///
+/// A table whose primary key changed has to be rebuilt with its documents,
+/// which is reported as an error rather than dropping the table.
+pub fn diff(
+    from: &SchemaSnapshot,
+    to: &SchemaSnapshot,
+    options: DiffOption,
+) -> Result<Vec<Command>> {
+    let mut commands = Vec::new();
+
+    for db in &to.dbs {
+        let current = from.db(&db.name);
+
+        if current.is_none() {
+            commands.push(r.db_create(db.name.as_ref()));
+        }
+
+        for table in &db.tables {
+            let current = current.and_then(|current| current.table(&table.name));
+
+            diff_table(&db.name, current, table, &mut commands)?;
+        }
+    }
+
+    if !options.drop_extra {
+        return Ok(commands);
+    }
+
+    for db in &from.dbs {
+        match to.db(&db.name) {
+            // the tables are dropped with their database
+            None => commands.push(r.db_drop(db.name.as_ref())),
+            Some(expected) => {
+                for table in &db.tables {
+                    if expected.table(&table.name).is_none() {
+                        commands.push(r.db(db.name.as_ref()).table_drop(table.name.as_ref()));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(commands)
+}
+
+fn diff_table(
+    db: &str,
+    from: Option<&TableSnapshot>,
+    to: &TableSnapshot,
+    commands: &mut Vec<Command>,
+) -> Result<()> {
+    let query = r.db(db).table(to.name.as_ref());
+
+    match from {
+        Some(from) if from.primary_key != to.primary_key => {
+            return Err(err::ReqlDriverError::Other(format!(
+                "the primary key of `{}.{}` changed from `{}` to `{}`, \
+                 the table has to be rebuilt with its documents",
+                db, to.name, from.primary_key, to.primary_key
+            ))
+            .into());
+        }
+        Some(_) => {}
+        None => {
+            let opts = TableCreateOption::default().primary_key(to.primary_key.to_string());
+
+            commands.push(r.db(db).table_create(Args((to.name.as_ref(), opts))));
+        }
+    }
+
+    let current_index = |name: &str| from.and_then(|from| from.index(name));
+    let mut created = false;
+
+    for index in &to.indexes {
+        match current_index(&index.name) {
+            Some(current) if current.function == index.function => continue,
+            Some(_) => commands.push(query.index_drop(index.name.as_ref())),
+            None => {}
+        }
+
+        commands.push(query.index_create(Args((index.name.as_ref(), index.function.clone()))));
+        created = true;
+    }
+
+    if created {
+        commands.push(query.index_wait(()));
+    }
+
+    let write_hook = from.and_then(|from| from.write_hook.as_ref());
+
+    if write_hook != to.write_hook.as_ref() {
+        commands.push(match &to.write_hook {
+            Some(function) => query.set_write_hook(function.clone()),
+            None => query.set_write_hook(Value::Null),
+        });
+    }
+
+    for index in from.map(|from| from.indexes.as_slice()).unwrap_or_default() {
+        if to.index(&index.name).is_none() {
+            commands.push(query.index_drop(index.name.as_ref()));
+        }
+    }
+
+    Ok(())
+}
+
+async fn table_snapshot(query: &Command, session: &Session) -> Result<TableSnapshot> {
+    let info: InfoResponse = run(&query.info(), session).await?;
+    let mut indexes: Vec<IndexStatusResponse> = run(&query.index_status(()), session).await?;
+    let write_hook: Option<GetWriteHookResponse> = run(&query.get_write_hook(), session).await?;
+
+    indexes.sort_by(|a, b| a.index.cmp(&b.index));
+
+    Ok(TableSnapshot {
+        name: info.name.unwrap_or_default(),
+        primary_key: info.primary_key.unwrap_or(Cow::Borrowed("id")),
+        indexes: indexes
+            .into_iter()
+            .map(|status| IndexSnapshot {
+                name: status.index,
+                function: status.function,
+                multi: status.multi,
+                geo: status.geo,
+            })
+            .collect(),
+        write_hook: write_hook.map(|hook| hook.function),
+    })
+}
+
+// Schemas use the stored table names, whatever the session prefix
+async fn run<T>(query: &Command, session: &Session) -> Result<T>
+where
+    T: Unpin + Serialize + serde::de::DeserializeOwned,
+{
+    let args = Args((session, RunOption::default().table_prefix(false)));
+
+    match query.run(args).await? {
+        Some(value) => value.parse(),
+        None => Err(err::ReqlDriverError::Other("empty response".to_owned()).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{diff, DbSnapshot, DiffOption, IndexSnapshot, SchemaSnapshot, TableSnapshot};
+    use crate::types::Binary;
+
+    fn index(name: &'static str, function: &[u8]) -> IndexSnapshot {
+        IndexSnapshot {
+            name: Cow::Borrowed(name),
+            function: Binary::new(function),
+            multi: false,
+            geo: false,
+        }
+    }
+
+    fn schema(tables: Vec<TableSnapshot>) -> SchemaSnapshot {
+        SchemaSnapshot {
+            dbs: vec![DbSnapshot {
+                name: Cow::Borrowed("blog"),
+                tables,
+            }],
+        }
+    }
+
+    fn table(name: &'static str, indexes: Vec<IndexSnapshot>) -> TableSnapshot {
+        TableSnapshot {
+            name: Cow::Borrowed(name),
+            primary_key: Cow::Borrowed("id"),
+            indexes,
+            write_hook: None,
+        }
+    }
+
+    fn reql(from: &SchemaSnapshot, to: &SchemaSnapshot, drop_extra: bool) -> Vec<String> {
+        let options = DiffOption::default().drop_extra(drop_extra);
+
+        diff(from, to, options)
+            .unwrap()
+            .iter()
+            .map(|command| command.to_reql_string().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn diff_same_schema() {
+        let schema = schema(vec![table("posts", vec![index("title", b"a")])]);
+
+        assert!(reql(&schema, &schema, true).is_empty());
+    }
+
+    #[test]
+    fn diff_schemas() {
+        let from = schema(vec![
+            table("posts", vec![index("date", b"a"), index("title", b"a")]),
+            table("tags", Vec::new()),
+        ]);
+        let to = schema(vec![
+            table("posts", vec![index("title", b"b")]),
+            table("users", Vec::new()),
+        ]);
+
+        let commands = reql(&from, &to, false);
+        let dropped = reql(&from, &to, true);
+        let created = reql(&SchemaSnapshot::default(), &to, false);
+
+        assert_eq!(commands.len(), 5);
+        assert!(commands[0].contains("index_drop(\"title\")"));
+        assert!(commands[1].contains("index_create"));
+        assert!(commands[2].contains("index_wait"));
+        assert!(commands[3].contains("index_drop(\"date\")"));
+        assert!(commands[4].contains("table_create"));
+        assert_eq!(dropped[..5], commands[..]);
+        assert!(dropped[5].contains("table_drop(\"tags\")"));
+        assert!(created[0].contains("db_create(\"blog\")"));
+        assert!(reql(&from, &SchemaSnapshot::default(), false).is_empty());
+        assert_eq!(
+            reql(&from, &SchemaSnapshot::default(), true),
+            ["r.db_drop(\"blog\")"]
+        );
+    }
+
+    #[test]
+    fn diff_primary_key() {
+        let mut users = table("users", Vec::new());
+        let from = schema(vec![users.clone()]);
+        users.primary_key = Cow::Borrowed("email");
+
+        let created = reql(
+            &SchemaSnapshot::default(),
+            &schema(vec![users.clone()]),
+            false,
+        );
+
+        assert!(created[1].contains("table_create") && created[1].contains("email"));
+        assert!(diff(
+            &from,
+            &schema(vec![users]),
+            DiffOption::default().drop_extra(true)
+        )
+        .is_err());
+    }
+}
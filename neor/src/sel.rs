@@ -0,0 +1,110 @@
+//! Support of the `sel!` macro, reading the stored name of the
+//! selected fields from the `Fields` derive when the struct has it.
+//!
+//! The fields skipped by serde are never stored, selecting one fails to compile:
+//!
+//! ```compile_fail
+//! use neor::{sel, Fields};
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize, Fields)]
+//! struct User {
+//!     id: String,
+//!     #[serde(skip)]
+//!     password: String,
+//! }
+//!
+//! let selector = sel!(User { id, password });
+//! ```
+
+use std::marker::PhantomData;
+
+/// Stored names of the fields of a struct, implemented by `#[derive(Fields)]`
+pub trait SerdeFields {
+    /// The fields skipped by serde
+    const SKIPPED: &'static [&'static str];
+
+    /// Stored name of `field`
+    fn serde_field(field: &'static str) -> &'static str;
+}
+
+/// A field selected by `sel!`
+pub trait SelectedField {
+    const NAME: &'static str;
+}
+
+/// The type of the fields selected at one level of `sel!`
+pub struct FieldNames<T>(PhantomData<T>);
+
+impl<T> FieldNames<T> {
+    /// Infer the type from an accessor such as `|doc: &User| &doc.name`
+    pub fn of<P, F>(_: F) -> Self
+    where
+        F: Fn(&P) -> &T,
+    {
+        Self(PhantomData)
+    }
+}
+
+pub trait StoredName {
+    fn stored_name<F: SelectedField>(&self) -> &'static str;
+}
+
+impl<T: SerdeFields> StoredName for FieldNames<T> {
+    fn stored_name<F: SelectedField>(&self) -> &'static str {
+        let () = Selectable::<T, F>::STORED;
+
+        T::serde_field(F::NAME)
+    }
+}
+
+// Taken when the struct does not derive `Fields`, its fields keep their names
+pub trait DefaultName {
+    fn stored_name<F: SelectedField>(&self) -> &'static str;
+}
+
+impl<T> DefaultName for &FieldNames<T> {
+    fn stored_name<F: SelectedField>(&self) -> &'static str {
+        F::NAME
+    }
+}
+
+// Evaluated when `sel!` is compiled, failing for the skipped fields
+struct Selectable<T, F>(PhantomData<(T, F)>);
+
+impl<T: SerdeFields, F: SelectedField> Selectable<T, F> {
+    const STORED: () = assert!(
+        !contains(T::SKIPPED, F::NAME),
+        "`sel!` selects a field skipped by serde"
+    );
+}
+
+const fn contains(fields: &[&str], field: &str) -> bool {
+    let mut i = 0;
+
+    while i < fields.len() {
+        if eq(fields[i].as_bytes(), field.as_bytes()) {
+            return true;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+const fn eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
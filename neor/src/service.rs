@@ -0,0 +1,103 @@
+//! Run queries through a [tower](https://docs.rs/tower) service
+//!
+//! [QueryService] implements `Service<Command>`, so the middlewares of
+//! tower (timeouts, rate limits, load shedding, retries...) apply to
+//! the database calls like to the rest of a service stack.
+//! This module requires the `tower` feature.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use neor::service::QueryService;
+//! use neor::{r, Result};
+//! use tower::{ServiceBuilder, ServiceExt};
+//!
+//! async fn example() -> Result<()> {
+//!     let sessions = vec![
+//!         r.connection().connect().await?,
+//!         r.connection().connect().await?,
+//!     ];
+//!     let service = ServiceBuilder::new()
+//!         .timeout(Duration::from_secs(5))
+//!         .concurrency_limit(64)
+//!         .service(QueryService::pool(sessions));
+//!
+//!     let response = service
+//!         .oneshot(r.table("users").count(()))
+//!         .await
+//!         .expect("query failed or timed out");
+//!
+//!     assert!(response.is_some());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use serde_json::Value;
+use tower_service::Service;
+
+use crate::arguments::{Args, RunOption};
+use crate::err::{ReqlDriverError, ReqlError};
+use crate::{Command, Result, Session};
+
+/// A service running each query on one of its sessions
+#[derive(Debug, Clone)]
+pub struct QueryService {
+    sessions: Arc<[Session]>,
+    next: Arc<AtomicUsize>,
+    options: RunOption,
+}
+
+impl QueryService {
+    pub fn new(session: Session) -> Self {
+        Self::pool([session])
+    }
+
+    /// Spread the queries over `sessions` in turn, skipping the broken ones
+    pub fn pool(sessions: impl IntoIterator<Item = Session>) -> Self {
+        Self {
+            sessions: sessions.into_iter().collect(),
+            next: Arc::new(AtomicUsize::new(0)),
+            options: RunOption::default(),
+        }
+    }
+
+    /// Options used to run every query
+    pub fn options(mut self, options: RunOption) -> Self {
+        self.options = options;
+        self
+    }
+
+    fn session(&self) -> Result<Session> {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        (0..self.sessions.len())
+            .map(|i| &self.sessions[(start + i) % self.sessions.len()])
+            .find(|session| session.is_open())
+            .cloned()
+            .ok_or_else(|| ReqlDriverError::ConnectionBroken.into())
+    }
+}
+
+impl Service<Command> for QueryService {
+    type Response = Option<Value>;
+    type Error = ReqlError;
+    type Future = BoxFuture<'static, Result<Option<Value>>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, query: Command) -> Self::Future {
+        let session = self.session();
+        let options = self.options.clone();
+
+        async move { query.run(Args((&session?, options))).await }.boxed()
+    }
+}
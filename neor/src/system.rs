@@ -0,0 +1,173 @@
+//! Typed access to the system tables of the `rethinkdb` database
+//!
+//! Each function returns the query selecting a system table,
+//! its rows can be parsed into the matching structure.
+//!
+//! ```
+//! use neor::system::{self, ServerStatus};
+//! use neor::{r, Converter, Result};
+//!
+//! async fn example() -> Result<()> {
+//!     let conn = r.connection().connect().await?;
+//!     let servers: Vec<ServerStatus> = system::server_status()
+//!         .run(&conn)
+//!         .await?
+//!         .unwrap()
+//!         .parse()?;
+//!
+//!     assert!(servers.len() > 0);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::{ConfigResponse, StatusResponse, Time};
+use crate::{r, Command};
+
+/// The database holding the system tables
+pub const SYSTEM_DB: &str = "rethinkdb";
+
+/// A row of the `table_config` table
+pub type TableConfig = ConfigResponse;
+
+/// A row of the `table_status` table
+pub type TableStatus = StatusResponse;
+
+/// Select the `table_config` table, its rows are [TableConfig]
+pub fn table_config() -> Command {
+    system_table("table_config")
+}
+
+/// Select the `table_status` table, its rows are [TableStatus]
+pub fn table_status() -> Command {
+    system_table("table_status")
+}
+
+/// Select the `server_status` table, its rows are [ServerStatus]
+pub fn server_status() -> Command {
+    system_table("server_status")
+}
+
+/// Select the `jobs` table, its rows are [Job]
+pub fn jobs() -> Command {
+    system_table("jobs")
+}
+
+/// Select the `stats` table, its rows are [Stats]
+pub fn stats() -> Command {
+    system_table("stats")
+}
+
+/// Select the `current_issues` table, its rows are [Issue]
+pub fn current_issues() -> Command {
+    system_table("current_issues")
+}
+
+fn system_table(name: &str) -> Command {
+    r.db(SYSTEM_DB).table(name)
+}
+
+/// A row of the `server_status` table
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ServerStatus {
+    pub id: Cow<'static, str>,
+    pub name: Cow<'static, str>,
+    pub network: ServerNetwork,
+    pub process: ServerProcess,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ServerNetwork {
+    pub canonical_addresses: Vec<ServerAddress>,
+    pub cluster_port: u16,
+    /// whether the server is connected to each other server of the cluster
+    pub connected_to: HashMap<Cow<'static, str>, bool>,
+    pub hostname: Cow<'static, str>,
+    /// the port number, or `"<no http admin>"` when the web UI is disabled
+    pub http_admin_port: Value,
+    pub reql_port: u16,
+    pub time_connected: Time,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct ServerAddress {
+    pub host: Cow<'static, str>,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ServerProcess {
+    pub argv: Vec<Cow<'static, str>>,
+    pub cache_size_mb: f64,
+    pub pid: u32,
+    pub time_started: Time,
+    pub version: Cow<'static, str>,
+}
+
+/// A row of the `jobs` table
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[non_exhaustive]
+pub struct Job {
+    /// the job type and its UUID, e.g. `["query", "..."]`
+    pub id: Vec<Value>,
+    /// `query`, `disk_compaction`, `index_construction` or `backfill`
+    #[serde(rename = "type")]
+    pub typ: Cow<'static, str>,
+    pub duration_sec: f64,
+    /// details depending on the job type
+    pub info: Value,
+    pub servers: Vec<Cow<'static, str>>,
+}
+
+/// A row of the `stats` table
+///
+/// Fields are set depending on the kind of statistics
+/// given by the first element of `id`: `cluster`, `server`,
+/// `table` or `table_server`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[non_exhaustive]
+pub struct Stats {
+    pub id: Vec<Value>,
+    pub server: Option<Cow<'static, str>>,
+    pub db: Option<Cow<'static, str>>,
+    pub table: Option<Cow<'static, str>>,
+    pub query_engine: StatsQueryEngine,
+    /// set for `table_server` statistics
+    pub storage_engine: Option<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[non_exhaustive]
+pub struct StatsQueryEngine {
+    pub queries_per_sec: Option<f64>,
+    pub read_docs_per_sec: f64,
+    pub written_docs_per_sec: f64,
+    pub client_connections: Option<usize>,
+    pub clients_active: Option<usize>,
+    pub queries_total: Option<usize>,
+    pub read_docs_total: Option<usize>,
+    pub written_docs_total: Option<usize>,
+}
+
+/// A row of the `current_issues` table
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[non_exhaustive]
+pub struct Issue {
+    pub id: Cow<'static, str>,
+    /// e.g. `log_write_error`, `outdated_index`, `table_availability`
+    #[serde(rename = "type")]
+    pub typ: Cow<'static, str>,
+    pub critical: bool,
+    pub description: Cow<'static, str>,
+    /// details depending on the issue type
+    pub info: Value,
+}
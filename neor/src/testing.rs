@@ -0,0 +1,183 @@
+//! Databases created for a test and dropped after it, with the `testing` feature
+//!
+//! A [TempDatabase] gives each test a database of its own, with a unique name,
+//! so tests can run in parallel without sharing tables nor a preloaded server.
+//! With the `testing-docker` feature, [TempDatabase::docker] also starts the
+//! RethinkDB server in a Docker container for the test.
+
+use std::thread;
+#[cfg(feature = "testing-docker")]
+use testcontainers::{
+    core::{IntoContainerPort, WaitFor},
+    runners::AsyncRunner,
+    ContainerAsync, GenericImage,
+};
+
+use tokio::runtime::Builder;
+use uuid::Uuid;
+
+use crate::{r, Command, Result, Session};
+
+/// The image started by [TempDatabase::docker]
+#[cfg(feature = "testing-docker")]
+pub const DOCKER_IMAGE: (&str, &str) = ("rethinkdb", "2.4");
+
+#[cfg(feature = "testing-docker")]
+const DRIVER_PORT: u16 = 28015;
+
+/// A database with a unique name, dropped with its tables when it goes out of scope
+///
+/// Dropping it only starts dropping the database, on a thread of its own with
+/// a new connection, without waiting for the server: the database may still
+/// be listed for a moment, or be left if the process exits first.
+/// Call [drop_database](TempDatabase::drop_database) to wait for the server
+/// and get the error, if any.
+///
+/// ## Examples
+///
+/// ```
+/// use neor::testing::TempDatabase;
+/// use neor::{r, Result};
+/// use serde_json::json;
+///
+/// async fn example() -> Result<()> {
+///     let session = r.connection().connect().await?;
+///     let db = TempDatabase::new(&session, ["posts"]).await?;
+///
+///     db.table("posts")
+///         .insert(json!({"id": 1, "title": "title"}))
+///         .run(&session)
+///         .await?;
+///
+///     db.drop_database().await
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TempDatabase {
+    session: Session,
+    name: String,
+    dropped: bool,
+    #[cfg(feature = "testing-docker")]
+    container: Option<ContainerAsync<GenericImage>>,
+}
+
+impl TempDatabase {
+    /// Create a database named `test_<uuid>` and its `tables`
+    pub async fn new<T, S>(session: &Session, tables: T) -> Result<Self>
+    where
+        T: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let name = format!("test_{}", Uuid::new_v4().simple());
+        r.db_create(name.as_str()).run(session).await?;
+
+        // dropped with the database if a table cannot be created
+        let db = Self {
+            session: session.clone(),
+            name,
+            dropped: false,
+            #[cfg(feature = "testing-docker")]
+            container: None,
+        };
+
+        for table in tables {
+            db.db().table_create(table.into()).run(session).await?;
+        }
+
+        Ok(db)
+    }
+
+    /// Start a RethinkDB server in a Docker container, see [DOCKER_IMAGE],
+    /// and create the database and its `tables` in it, with the `testing-docker` feature
+    ///
+    /// The container is removed when the database is dropped.
+    #[cfg(feature = "testing-docker")]
+    pub async fn docker<T, S>(tables: T) -> Result<Self>
+    where
+        T: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let (name, tag) = DOCKER_IMAGE;
+        let container = GenericImage::new(name, tag)
+            .with_exposed_port(DRIVER_PORT.tcp())
+            .with_wait_for(WaitFor::message_on_stdout("Server ready"))
+            .start()
+            .await
+            .map_err(docker_error)?;
+        let host = container.get_host().await.map_err(docker_error)?;
+        let port = container
+            .get_host_port_ipv4(DRIVER_PORT)
+            .await
+            .map_err(docker_error)?;
+
+        let session = r
+            .connection()
+            .host(host.to_string())
+            .port(port)
+            .connect()
+            .await?;
+        let mut db = Self::new(&session, tables).await?;
+        db.container = Some(container);
+
+        Ok(db)
+    }
+
+    /// The name of the database
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The session the database was created with
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// The `r.db` command of the database
+    pub fn db(&self) -> Command {
+        r.db(self.name.as_str())
+    }
+
+    /// The `table` command of a table of the database
+    pub fn table(&self, table_name: &str) -> Command {
+        self.db().table(table_name)
+    }
+
+    /// Drop the database and its tables, waiting for the server to do it
+    pub async fn drop_database(mut self) -> Result<()> {
+        self.dropped = true;
+        r.db_drop(self.name.as_str()).run(&self.session).await?;
+        Ok(())
+    }
+}
+
+impl Drop for TempDatabase {
+    fn drop(&mut self) {
+        if self.dropped {
+            return;
+        }
+
+        let query = r.db_drop(self.name.as_str());
+        let command = self.session.inner.command.clone();
+        #[cfg(feature = "testing-docker")]
+        let container = self.container.take();
+
+        // not joined, dropping must not block the runtime of the test
+        thread::spawn(move || {
+            let runtime = Builder::new_current_thread().enable_all().build();
+            if let Ok(runtime) = runtime {
+                runtime.block_on(async move {
+                    if let Ok(session) = command.connect().await {
+                        let _ = query.run(&session).await;
+                    }
+                    #[cfg(feature = "testing-docker")]
+                    drop(container);
+                });
+            }
+        });
+    }
+}
+
+#[cfg(feature = "testing-docker")]
+fn docker_error(error: testcontainers::TestcontainersError) -> crate::err::ReqlError {
+    crate::err::ReqlDriverError::Other(error.to_string()).into()
+}
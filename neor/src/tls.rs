@@ -0,0 +1,311 @@
+//! TLS configuration of the connections
+//!
+//! Two backends are available, selected with a cargo feature:
+//! - `tls-native` (default) uses the TLS stack of the platform
+//!   (OpenSSL, Secure Transport or SChannel) through `native-tls`
+//! - `tls-rustls` uses `rustls`, trusting the Mozilla roots of `webpki-roots`
+//!
+//! When both features are enabled, `rustls` is used. Disable the default
+//! features to build without `native-tls`:
+//!
+//! ```toml
+//! neor = { version = "*", default-features = false, features = ["tls-rustls"] }
+//! ```
+//!
+//! Both backends are configured with a [TlsConfig] and connect
+//! without ALPN, as the RethinkDB protocol does not negotiate one.
+//!
+//! ```
+//! use neor::tls::TlsConfig;
+//! use neor::{r, Result};
+//!
+//! async fn example() -> Result<()> {
+//!     let tls = TlsConfig::new()
+//!         .ca_bundle_file("/etc/rethinkdb/ca.pem")?
+//!         .client_identity(
+//!             std::fs::read("/etc/rethinkdb/client.pem")?,
+//!             std::fs::read("/etc/rethinkdb/client.key")?,
+//!         );
+//!     let conn = r.connection().tls(tls).connect().await?;
+//!
+//!     r.db_list().run(&conn).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_net::TcpStream;
+use futures::{AsyncRead, AsyncWrite};
+
+use crate::Result;
+
+/// Certificates used by the TLS connections
+#[derive(Clone, Default)]
+pub struct TlsConfig {
+    ca_certs: Vec<Vec<u8>>,
+    identity: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+impl TlsConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trust the certificates of `certs`, in addition to the default roots
+    ///
+    /// `certs` is a PEM bundle or a single DER certificate.
+    pub fn ca_bundle(mut self, certs: impl Into<Vec<u8>>) -> Self {
+        self.ca_certs.push(certs.into());
+        self
+    }
+
+    /// Trust the certificates of the PEM or DER file at `path`
+    pub fn ca_bundle_file(self, path: impl AsRef<Path>) -> Result<Self> {
+        Ok(self.ca_bundle(std::fs::read(path)?))
+    }
+
+    /// Authenticate with a client certificate
+    ///
+    /// `cert_chain` is the PEM certificate chain, starting with the client
+    /// certificate, and `key` its PEM private key in PKCS#8 format.
+    pub fn client_identity(
+        mut self,
+        cert_chain: impl Into<Vec<u8>>,
+        key: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.identity = Some((cert_chain.into(), key.into()));
+        self
+    }
+
+    #[cfg(feature = "tls-rustls")]
+    pub(crate) fn connector(&self) -> Result<TlsConnector> {
+        use std::sync::Arc;
+
+        use futures_rustls::rustls::{
+            Certificate, ClientConfig, OwnedTrustAnchor, PrivateKey, RootCertStore,
+        };
+        use rustls_pemfile::Item;
+
+        let tls_error =
+            |error: &dyn fmt::Display| crate::err::ReqlDriverError::Tls(error.to_string());
+        let pem_certs = |pem: &[u8]| -> Result<Vec<Certificate>> {
+            let certs = rustls_pemfile::certs(&mut &*pem)?;
+            Ok(certs.into_iter().map(Certificate).collect())
+        };
+
+        let mut roots = RootCertStore::empty();
+        roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        }));
+
+        for bundle in &self.ca_certs {
+            let mut certs = pem_certs(bundle)?;
+            if certs.is_empty() {
+                certs.push(Certificate(bundle.clone()));
+            }
+
+            for cert in certs {
+                roots.add(&cert).map_err(|error| tls_error(&error))?;
+            }
+        }
+
+        let builder = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots);
+        let config = match &self.identity {
+            Some((cert_chain, key)) => {
+                let key = rustls_pemfile::read_all(&mut &key[..])?
+                    .into_iter()
+                    .find_map(|item| match item {
+                        Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => {
+                            Some(PrivateKey(key))
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| tls_error(&"no private key in the client identity"))?;
+
+                builder
+                    .with_client_auth_cert(pem_certs(cert_chain)?, key)
+                    .map_err(|error| tls_error(&error))?
+            }
+            None => builder.with_no_client_auth(),
+        };
+
+        Ok(TlsConnector::Rustls(Arc::new(config).into()))
+    }
+
+    #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+    pub(crate) fn connector(&self) -> Result<TlsConnector> {
+        use async_native_tls::{Certificate, Identity};
+
+        let mut connector = async_native_tls::TlsConnector::new();
+
+        for bundle in &self.ca_certs {
+            let certs = match Certificate::stack_from_pem(bundle) {
+                Ok(certs) if !certs.is_empty() => certs,
+                _ => vec![Certificate::from_der(bundle)?],
+            };
+
+            for cert in certs {
+                connector = connector.add_root_certificate(cert);
+            }
+        }
+
+        if let Some((cert_chain, key)) = &self.identity {
+            connector = connector.identity(Identity::from_pkcs8(cert_chain, key)?);
+        }
+
+        Ok(TlsConnector::Native(connector))
+    }
+
+    #[cfg(not(any(feature = "tls-native", feature = "tls-rustls")))]
+    pub(crate) fn connector(&self) -> Result<TlsConnector> {
+        Err(crate::err::ReqlDriverError::Tls(
+            "TLS requires the `tls-native` or `tls-rustls` feature".to_owned(),
+        )
+        .into())
+    }
+}
+
+// The private key is not printed
+impl fmt::Debug for TlsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TlsConfig")
+            .field("ca_certs", &self.ca_certs.len())
+            .field("client_identity", &self.identity.is_some())
+            .finish()
+    }
+}
+
+pub(crate) enum TlsConnector {
+    #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+    Native(async_native_tls::TlsConnector),
+    #[cfg(feature = "tls-rustls")]
+    Rustls(futures_rustls::TlsConnector),
+}
+
+impl TlsConnector {
+    pub(crate) async fn connect(&self, host: &str, stream: TcpStream) -> Result<TlsStream> {
+        match self {
+            #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+            Self::Native(connector) => {
+                Ok(TlsStream::Native(connector.connect(host, stream).await?))
+            }
+            #[cfg(feature = "tls-rustls")]
+            Self::Rustls(connector) => {
+                let server_name = host.try_into().map_err(|_| {
+                    crate::err::ReqlDriverError::Tls(format!("invalid server name `{}`", host))
+                })?;
+
+                Ok(TlsStream::Rustls(
+                    connector.connect(server_name, stream).await?,
+                ))
+            }
+            #[cfg(not(any(feature = "tls-native", feature = "tls-rustls")))]
+            _ => {
+                let _ = (host, stream);
+                unreachable!()
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum TlsStream {
+    #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+    Native(async_native_tls::TlsStream<TcpStream>),
+    #[cfg(feature = "tls-rustls")]
+    Rustls(futures_rustls::client::TlsStream<TcpStream>),
+}
+
+impl AsyncRead for TlsStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+            Self::Native(stream) => Pin::new(stream).poll_read(cx, buf),
+            #[cfg(feature = "tls-rustls")]
+            Self::Rustls(stream) => Pin::new(stream).poll_read(cx, buf),
+            #[cfg(not(any(feature = "tls-native", feature = "tls-rustls")))]
+            _ => {
+                let _ = (cx, buf);
+                unreachable!()
+            }
+        }
+    }
+}
+
+impl AsyncWrite for TlsStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+            Self::Native(stream) => Pin::new(stream).poll_write(cx, buf),
+            #[cfg(feature = "tls-rustls")]
+            Self::Rustls(stream) => Pin::new(stream).poll_write(cx, buf),
+            #[cfg(not(any(feature = "tls-native", feature = "tls-rustls")))]
+            _ => {
+                let _ = (cx, buf);
+                unreachable!()
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+            Self::Native(stream) => Pin::new(stream).poll_flush(cx),
+            #[cfg(feature = "tls-rustls")]
+            Self::Rustls(stream) => Pin::new(stream).poll_flush(cx),
+            #[cfg(not(any(feature = "tls-native", feature = "tls-rustls")))]
+            _ => {
+                let _ = cx;
+                unreachable!()
+            }
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+            Self::Native(stream) => Pin::new(stream).poll_close(cx),
+            #[cfg(feature = "tls-rustls")]
+            Self::Rustls(stream) => Pin::new(stream).poll_close(cx),
+            #[cfg(not(any(feature = "tls-native", feature = "tls-rustls")))]
+            _ => {
+                let _ = cx;
+                unreachable!()
+            }
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "tls-native", feature = "tls-rustls")))]
+mod tests {
+    use super::TlsConfig;
+
+    #[test]
+    fn invalid_ca_bundle() {
+        let tls = TlsConfig::new().ca_bundle(b"not a certificate".to_vec());
+
+        assert!(TlsConfig::new().connector().is_ok());
+        assert!(tls.connector().is_err());
+        assert!(!format!("{:?}", tls).contains("not a certificate"));
+    }
+}
@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::ReqlType;
+use crate::err::ReqlError;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Binary {
     #[serde(rename = "$reql_type$")]
     reql_type: ReqlType,
@@ -16,4 +17,64 @@ impl Binary {
             data: base64::encode(bytes),
         }
     }
+
+    /// Decode the base64 data
+    pub fn bytes(&self) -> crate::Result<Vec<u8>> {
+        Ok(base64::decode(&self.data)?)
+    }
+}
+
+impl From<&[u8]> for Binary {
+    fn from(bytes: &[u8]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<Vec<u8>> for Binary {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(&bytes)
+    }
+}
+
+impl TryFrom<Binary> for Vec<u8> {
+    type Error = ReqlError;
+
+    fn try_from(binary: Binary) -> crate::Result<Self> {
+        binary.bytes()
+    }
+}
+
+/// Store a `Vec<u8>` field as a binary object
+///
+/// ```
+/// use neor::types::as_binary;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     id: u8,
+///     #[serde(with = "as_binary")]
+///     avatar: Vec<u8>,
+/// }
+/// ```
+pub mod as_binary {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Binary;
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Binary::new(bytes).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let binary = Binary::deserialize(deserializer)?;
+
+        base64::decode(&binary.data).map_err(de::Error::custom)
+    }
 }
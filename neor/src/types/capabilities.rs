@@ -0,0 +1,107 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::err::{ReqlDriverError, ReqlError};
+
+/// Optional features supported by a server, see
+/// [capabilities](crate::connection::Session::capabilities)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// Version of the RethinkDB server
+    pub version: ServerVersion,
+    /// Users, `grant` and the SCRAM authentication, since 2.3
+    pub permissions: bool,
+    /// The `fold` command, since 2.3
+    pub fold: bool,
+    /// `set_write_hook` and `get_write_hook`, since 2.4
+    pub write_hooks: bool,
+    /// `bit_and`, `bit_or`, `bit_xor`, `bit_not`, `bit_sal` and `bit_sar`, since 2.4
+    pub bit_operators: bool,
+}
+
+impl Capabilities {
+    pub fn new(version: ServerVersion) -> Self {
+        let since = |major, minor| version >= ServerVersion::new(major, minor, 0);
+
+        Self {
+            version,
+            permissions: since(2, 3),
+            fold: since(2, 3),
+            write_hooks: since(2, 4),
+            bit_operators: since(2, 4),
+        }
+    }
+}
+
+/// Version of a RethinkDB server, e.g. `2.4.1`
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ServerVersion {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Parse the version reported by a server,
+/// such as `rethinkdb 2.4.1~0bionic (GCC 7.3.0)` or `2.4.1`
+impl FromStr for ServerVersion {
+    type Err = ReqlError;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        let invalid = || ReqlDriverError::Other(format!("invalid server version `{}`", version));
+        let number = version
+            .trim_start_matches("rethinkdb")
+            .trim_start()
+            .split(|c: char| !c.is_ascii_digit() && c != '.')
+            .next()
+            .unwrap_or_default();
+        let mut parts = number.split('.').map(u32::from_str);
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor)), patch) => Ok(Self::new(
+                major,
+                minor,
+                patch.and_then(Result::ok).unwrap_or_default(),
+            )),
+            _ => Err(invalid().into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Capabilities, ServerVersion};
+
+    #[test]
+    fn server_version() {
+        let version: ServerVersion = "rethinkdb 2.4.1~0bionic (GCC 7.3.0)".parse().unwrap();
+
+        assert!(version == ServerVersion::new(2, 4, 1));
+        assert!("2.3".parse::<ServerVersion>().unwrap() == ServerVersion::new(2, 3, 0));
+        assert!("rethinkdb".parse::<ServerVersion>().is_err());
+
+        let capabilities = Capabilities::new(ServerVersion::new(2, 3, 6));
+
+        assert!(capabilities.fold && !capabilities.write_hooks);
+    }
+}
@@ -0,0 +1,213 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use super::{ConfigChange, ConfigResponse, ReconfigureResponse, ShardType};
+
+type Shards = Vec<ShardType<Cow<'static, str>>>;
+
+/// Shard and replica movements of one table,
+/// see [ReconfigureResponse::diff]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TableConfigDiff {
+    pub db: Option<Cow<'static, str>>,
+    pub table: Cow<'static, str>,
+    pub old_shards: usize,
+    pub new_shards: usize,
+    /// one entry for each shard which is moved
+    pub shards: Vec<ShardDiff>,
+}
+
+/// Movements of a shard, see [TableConfigDiff]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ShardDiff {
+    /// position of the shard in the table configuration
+    pub shard: usize,
+    pub old_primary_replica: Option<Cow<'static, str>>,
+    pub new_primary_replica: Option<Cow<'static, str>>,
+    pub added_replicas: Vec<Cow<'static, str>>,
+    pub removed_replicas: Vec<Cow<'static, str>>,
+}
+
+impl ReconfigureResponse {
+    /// Summarize the shard and replica movements of each table
+    ///
+    /// Mostly useful with `ReconfigureOption::dry_run(true)`,
+    /// to review a configuration before applying it.
+    /// The summary is rendered by the `Display` implementation.
+    pub fn diff(&self) -> Vec<TableConfigDiff> {
+        self.config_changes
+            .iter()
+            .filter_map(TableConfigDiff::new)
+            .collect()
+    }
+}
+
+impl TableConfigDiff {
+    fn new(change: &ConfigChange<ConfigResponse>) -> Option<Self> {
+        let config = change.new_val.as_ref().or(change.old_val.as_ref())?;
+        let no_shards = Shards::new();
+        let old_shards = shards(&change.old_val).unwrap_or(&no_shards);
+        let new_shards = shards(&change.new_val).unwrap_or(&no_shards);
+
+        let shards = (0..old_shards.len().max(new_shards.len()))
+            .map(|shard| ShardDiff::new(shard, old_shards.get(shard), new_shards.get(shard)))
+            .filter(|diff| !diff.is_empty())
+            .collect();
+
+        Some(Self {
+            db: config.db.clone(),
+            table: config.name.clone(),
+            old_shards: old_shards.len(),
+            new_shards: new_shards.len(),
+            shards,
+        })
+    }
+
+    /// Whether the table configuration is left unchanged
+    pub fn is_empty(&self) -> bool {
+        self.old_shards == self.new_shards && self.shards.is_empty()
+    }
+}
+
+impl ShardDiff {
+    fn new(
+        shard: usize,
+        old_shard: Option<&ShardType<Cow<'static, str>>>,
+        new_shard: Option<&ShardType<Cow<'static, str>>>,
+    ) -> Self {
+        let no_replicas = Vec::new();
+        let old_replicas = old_shard.map_or(&no_replicas, |shard| &shard.replicas);
+        let new_replicas = new_shard.map_or(&no_replicas, |shard| &shard.replicas);
+
+        Self {
+            shard,
+            old_primary_replica: old_shard.and_then(|shard| shard.primary_replica.clone()),
+            new_primary_replica: new_shard.and_then(|shard| shard.primary_replica.clone()),
+            added_replicas: difference(new_replicas, old_replicas),
+            removed_replicas: difference(old_replicas, new_replicas),
+        }
+    }
+
+    /// Whether the shard is left unchanged
+    pub fn is_empty(&self) -> bool {
+        self.old_primary_replica == self.new_primary_replica
+            && self.added_replicas.is_empty()
+            && self.removed_replicas.is_empty()
+    }
+}
+
+fn shards(config: &Option<ConfigResponse>) -> Option<&Shards> {
+    config.as_ref().and_then(|config| config.shards.as_ref())
+}
+
+fn difference(
+    replicas: &[Cow<'static, str>],
+    other: &[Cow<'static, str>],
+) -> Vec<Cow<'static, str>> {
+    replicas
+        .iter()
+        .filter(|replica| !other.contains(replica))
+        .cloned()
+        .collect()
+}
+
+impl fmt::Display for TableConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.db {
+            Some(db) => write!(f, "{}.{}", db, self.table)?,
+            None => write!(f, "{}", self.table)?,
+        }
+
+        if self.is_empty() {
+            return write!(f, ": unchanged");
+        }
+
+        write!(f, ": {} -> {} shards", self.old_shards, self.new_shards)?;
+
+        for shard in &self.shards {
+            write!(f, "\n  {}", shard)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ShardDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let none = Cow::Borrowed("none");
+
+        write!(f, "shard {}:", self.shard)?;
+
+        if self.old_primary_replica != self.new_primary_replica {
+            write!(
+                f,
+                " primary {} -> {}",
+                self.old_primary_replica.as_ref().unwrap_or(&none),
+                self.new_primary_replica.as_ref().unwrap_or(&none)
+            )?;
+        }
+
+        if !self.added_replicas.is_empty() {
+            write!(f, " +[{}]", self.added_replicas.join(", "))?;
+        }
+
+        if !self.removed_replicas.is_empty() {
+            write!(f, " -[{}]", self.removed_replicas.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::types::{ConfigChange, ConfigResponse, ReconfigureResponse, ShardType};
+
+    fn config(shards: Vec<(&'static str, Vec<&'static str>)>) -> ConfigResponse {
+        ConfigResponse {
+            id: Cow::Borrowed("31c92680-f70c-4a4b-a49e-b238eb12c023"),
+            name: Cow::Borrowed("simbad"),
+            db: Some(Cow::Borrowed("test")),
+            durability: None,
+            indexes: None,
+            primary_key: None,
+            shards: Some(
+                shards
+                    .into_iter()
+                    .map(|(primary, replicas)| ShardType {
+                        primary_replica: Some(Cow::Borrowed(primary)),
+                        primary_replicas: None,
+                        replicas: replicas.into_iter().map(Cow::Borrowed).collect(),
+                        nonvoting_replicas: None,
+                    })
+                    .collect(),
+            ),
+            write_acks: None,
+            write_hook: None,
+        }
+    }
+
+    #[test]
+    fn reconfigure_diff() {
+        let response = ReconfigureResponse {
+            reconfigured: 0,
+            config_changes: vec![ConfigChange {
+                old_val: Some(config(vec![("a", vec!["a", "b"])])),
+                new_val: Some(config(vec![("b", vec!["b"]), ("c", vec!["c"])])),
+            }],
+            status_changes: Vec::new(),
+        };
+        let diff = response.diff();
+
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].shards.len(), 2);
+        assert_eq!(
+            diff[0].to_string(),
+            "test.simbad: 1 -> 2 shards\n  shard 0: primary a -> b -[a]\n  shard 1: primary none -> c +[c]"
+        );
+    }
+}
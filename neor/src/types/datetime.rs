@@ -350,9 +350,46 @@ impl From<DateTime> for OffsetDateTime {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for DateTime {
+    type Error = crate::ReqlError;
+
+    fn try_from(date_time: chrono::DateTime<chrono::Utc>) -> crate::Result<Self> {
+        let timestamp = i128::from(date_time.timestamp()) * NANOS_PER_SEC
+            + i128::from(date_time.timestamp_subsec_nanos());
+
+        Ok(Self(
+            OffsetDateTime::from_unix_timestamp_nanos(timestamp)?,
+            None,
+        ))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime> for chrono::DateTime<chrono::Utc> {
+    type Error = crate::ReqlError;
+
+    fn try_from(DateTime(date_time, _): DateTime) -> crate::Result<Self> {
+        Self::from_timestamp(date_time.unix_timestamp(), date_time.nanosecond()).ok_or_else(|| {
+            crate::err::ReqlDriverError::Time("time out of the range of chrono".to_owned()).into()
+        })
+    }
+}
+
 impl From<DateTime> for Command {
     fn from(date_time: DateTime) -> Self {
-        date_time.1.unwrap()
+        match date_time.1 {
+            Some(command) => command,
+            // Values built from an `OffsetDateTime` or read back from the
+            // server are sent as a TIME pseudo-type
+            None => Command::from_json(Time::from(date_time.0)),
+        }
+    }
+}
+
+impl From<OffsetDateTime> for Command {
+    fn from(dt: OffsetDateTime) -> Self {
+        DateTime::from(dt).cmd()
     }
 }
 
@@ -0,0 +1,136 @@
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use rust_decimal::prelude::ToPrimitive;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A `rust_decimal::Decimal` sent to and read from RethinkDB as a number,
+/// with the `decimal` feature
+///
+/// The server stores every number as a 64-bit float, whose integers are exact
+/// up to 2^53 and whose decimals keep about 15 significant digits. A decimal
+/// is only serialized when the float holds it exactly, so a query with
+/// `r.expr(Decimal(..))` fails instead of rounding it.
+/// Numbers read back, e.g. with `parse::<Decimal>()`, take the shortest
+/// decimal writing of the float, so `0.1` reads as `0.1`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal(pub rust_decimal::Decimal);
+
+impl Decimal {
+    /// The float sent to the server, when it holds the decimal exactly
+    pub fn to_f64(&self) -> Option<f64> {
+        let float = self.0.to_f64()?;
+
+        match rust_decimal::Decimal::from_str(&float.to_string()) {
+            Ok(decimal) if decimal == self.0 => Some(float),
+            _ => None,
+        }
+    }
+}
+
+impl Deref for Decimal {
+    type Target = rust_decimal::Decimal;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(decimal: rust_decimal::Decimal) -> Self {
+        Self(decimal)
+    }
+}
+
+impl From<Decimal> for rust_decimal::Decimal {
+    fn from(Decimal(decimal): Decimal) -> Self {
+        decimal
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.to_f64() {
+            Some(float) => serializer.serialize_f64(float),
+            None => Err(ser::Error::custom(format!(
+                "{} cannot be represented by the 64-bit float numbers of RethinkDB",
+                self.0
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+}
+
+struct DecimalVisitor;
+
+impl<'de> de::Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a number")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Decimal(value.into()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Decimal(value.into()))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        self.visit_str(&value.to_string())
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        rust_decimal::Decimal::from_str(value)
+            .map(Decimal)
+            .map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::Decimal;
+
+    fn decimal(value: &str) -> Decimal {
+        Decimal(rust_decimal::Decimal::from_str(value).unwrap())
+    }
+
+    #[test]
+    fn decimal_round_trip() {
+        for value in ["0.1", "-12.75", "9007199254740992", "0"] {
+            let json = serde_json::to_value(decimal(value)).unwrap();
+
+            assert_eq!(json, json!(value.parse::<f64>().unwrap()));
+            assert_eq!(
+                serde_json::from_value::<Decimal>(json).unwrap(),
+                decimal(value)
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_rounded_by_server() {
+        for value in ["9007199254740993", "0.12345678901234567891"] {
+            assert_eq!(decimal(value).to_f64(), None);
+            assert!(serde_json::to_value(decimal(value)).is_err());
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+/// A mistake found in a query before running it,
+/// see [validate](crate::Command::validate)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// name of the method of the faulty term, e.g. `count`
+    pub term: String,
+    pub message: String,
+}
+
+/// The kinds of mistakes found by [validate](crate::Command::validate)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiagnosticKind {
+    /// An aggregation, e.g. `count`, of the infinite stream of `changes`
+    AggregationOfChangefeed,
+    /// `order_by` on another index than the `between` it is chained to
+    OrderByIndexAfterBetween,
+    /// A string, e.g. a field name, instead of the geometry
+    /// of `get_intersecting` or `get_nearest`
+    GeometryAsString,
+    /// `get_intersecting` or `get_nearest` without the geospatial index to read
+    MissingGeoIndex,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(kind: DiagnosticKind, term: String, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            term,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.term, self.message)
+    }
+}
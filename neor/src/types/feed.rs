@@ -0,0 +1,118 @@
+use serde::{Deserialize, Deserializer};
+
+use super::{ChangesState, ChangesType};
+
+/// State of a changefeed, sent with `include_states`
+pub type FeedState = ChangesState;
+
+/// A changefeed notification, see [run_changes](crate::Command::run_changes)
+///
+/// Unlike [ChangeEvent](super::ChangeEvent), the kind of the notification
+/// is read from the fields of the document, so `include_types` is not needed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum FeedItem<T> {
+    /// A document of the initial result set, with `include_initial`
+    Initial(T),
+    /// `old` is `None` for an insertion and `new` is `None` for a deletion
+    Change { old: Option<T>, new: Option<T> },
+    /// With `include_states`
+    State(FeedState),
+    /// An error reported in the feed, e.g. when changes were skipped
+    Error(String),
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+struct RawItem<T> {
+    // `Some(None)` when `old_val` is null, `None` when it is missing,
+    // which is how the initial results are told apart from insertions
+    #[serde(default, deserialize_with = "present")]
+    old_val: Option<Option<T>>,
+    new_val: Option<T>,
+    state: Option<FeedState>,
+    error: Option<String>,
+    #[serde(rename = "type")]
+    typ: Option<ChangesType>,
+}
+
+fn present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for FeedItem<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let item = RawItem::deserialize(deserializer)?;
+
+        if let Some(error) = item.error {
+            return Ok(Self::Error(error));
+        }
+
+        if let Some(state) = item.state {
+            return Ok(Self::State(state));
+        }
+
+        let item = match (item.typ, item.old_val, item.new_val) {
+            (Some(ChangesType::Initial), _, Some(new)) | (None, None, Some(new)) => {
+                Self::Initial(new)
+            }
+            (_, old, new) => Self::Change {
+                old: old.flatten(),
+                new,
+            },
+        };
+
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{FeedItem, FeedState};
+
+    #[test]
+    fn feed_item() {
+        let items: Vec<FeedItem<u8>> = serde_json::from_value(json!([
+            {"state": "initializing"},
+            {"new_val": 1},
+            {"old_val": null, "new_val": 2},
+            {"old_val": 1, "new_val": 3},
+            {"old_val": 2, "new_val": null},
+            {"new_val": 4, "type": "initial"},
+            {"error": "Changefeed cache over array size limit, skipped 2 elements."},
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            items,
+            [
+                FeedItem::State(FeedState::Initializing),
+                FeedItem::Initial(1),
+                FeedItem::Change {
+                    old: None,
+                    new: Some(2)
+                },
+                FeedItem::Change {
+                    old: Some(1),
+                    new: Some(3)
+                },
+                FeedItem::Change {
+                    old: Some(2),
+                    new: None
+                },
+                FeedItem::Initial(4),
+                FeedItem::Error(
+                    "Changefeed cache over array size limit, skipped 2 elements.".to_owned()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::{err, var_counter, Command, Func};
+
+/// A path to a nested field, such as `author.name.first`
+///
+/// Build it with the [path](crate::path) macro, which checks the path
+/// at compile time, or with [FieldPath::try_new].
+///
+/// A path serializes to the nested selector `{"author": {"name": {"first": true}}}`,
+/// so it can be given to `pluck`, `without` and `has_fields`.
+/// [get](Self::get) composes the `get_field` calls reading the field
+/// and [func](Self::func) wraps them in a function, e.g. for `order_by`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldPath(Vec<Cow<'static, str>>);
+
+impl FieldPath {
+    /// Split `path` on dots
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path has an empty field, e.g. `author..name`,
+    /// see [try_new](Self::try_new) for paths which are not known in advance.
+    pub fn new(path: &str) -> Self {
+        match Self::try_new(path) {
+            Ok(path) => path,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Split `path` on dots, failing if the path has an empty field,
+    /// e.g. `author..name`
+    pub fn try_new(path: &str) -> crate::Result<Self> {
+        let fields = path.split('.').map(|field| match field.is_empty() {
+            true => Err(err::ReqlDriverError::Other(format!(
+                "empty field in path `{}`",
+                path
+            ))),
+            false => Ok(Cow::Owned(field.to_owned())),
+        });
+
+        Ok(Self(fields.collect::<Result<_, _>>()?))
+    }
+
+    #[doc(hidden)]
+    pub fn from_static(fields: &[&'static str]) -> Self {
+        Self(fields.iter().copied().map(Cow::Borrowed).collect())
+    }
+
+    /// The path of the field `field` of this one
+    pub fn child(mut self, field: impl Into<String>) -> Self {
+        self.0.push(Cow::Owned(field.into()));
+        self
+    }
+
+    pub fn fields(&self) -> &[Cow<'static, str>] {
+        &self.0
+    }
+
+    /// Read the field from `doc`, i.e. `doc.g("author").g("name").g("first")`
+    pub fn get(&self, doc: impl Into<Command>) -> Command {
+        self.0
+            .iter()
+            .fold(doc.into(), |doc, field| doc.g(field.as_ref()))
+    }
+
+    /// The function reading the field from its argument
+    pub fn func(&self) -> Func {
+        let id = var_counter();
+
+        Func::new(vec![id], self.get(Command::var(id)))
+    }
+}
+
+impl Serialize for FieldPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Selector<'a>(&'a [Cow<'static, str>]);
+
+        impl Serialize for Selector<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self.0.split_first() {
+                    Some((field, rest)) => {
+                        let mut map = serializer.serialize_map(Some(1))?;
+                        map.serialize_entry(field, &Selector(rest))?;
+                        map.end()
+                    }
+                    None => serializer.serialize_bool(true),
+                }
+            }
+        }
+
+        Selector(&self.0).serialize(serializer)
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.join("."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::FieldPath;
+
+    #[test]
+    fn field_path_empty_field() {
+        assert!(FieldPath::try_new("author..name").is_err());
+        assert!(FieldPath::try_new("").is_err());
+        assert_eq!(
+            FieldPath::try_new("author.name").unwrap(),
+            FieldPath::new("author").child("name")
+        );
+    }
+
+    #[test]
+    fn field_path_selector() {
+        let path = FieldPath::new("author.name").child("first");
+
+        assert_eq!(path.to_string(), "author.name.first");
+        assert_eq!(
+            serde_json::to_value(&path).unwrap(),
+            json!({"author": {"name": {"first": true}}})
+        );
+    }
+}
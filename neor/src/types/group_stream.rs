@@ -1,7 +1,10 @@
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::{slice, vec};
 
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use serde_json::Value;
+use serde::de::{self, DeserializeOwned};
+use serde::{Deserialize, Serialize};
 
 use super::ReqlType;
 
@@ -16,11 +19,103 @@ pub struct GroupedItem<G, V> {
     pub values: Vec<V>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
-struct InnerGroup {
+/// The `GROUPED_DATA` pseudo-type, i.e. a value for each group
+///
+/// `V` is the type of the documents of a group, e.g. `Vec<Post>`
+/// after `group`, or of the result of a reduction, e.g. `usize`
+/// after `group(..).count(())`.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct GroupedData<G, V>(Vec<(G, V)>);
+
+#[derive(Serialize, Deserialize)]
+struct InnerGroup<T> {
     #[serde(rename = "$reql_type$")]
     reql_type: ReqlType,
-    data: Vec<[Value; 2]>,
+    data: T,
+}
+
+impl<'de, G, V> Deserialize<'de> for GroupedData<G, V>
+where
+    G: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner: InnerGroup<Vec<(G, V)>> = Deserialize::deserialize(deserializer)?;
+
+        if inner.reql_type != ReqlType::GroupedData {
+            return Err(de::Error::custom(format!(
+                "expected GROUPED_DATA, found {:?}",
+                inner.reql_type
+            )));
+        }
+
+        Ok(Self(inner.data))
+    }
+}
+
+impl<G, V> Serialize for GroupedData<G, V>
+where
+    G: Serialize,
+    V: Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        InnerGroup {
+            reql_type: ReqlType::GroupedData,
+            data: &self.0,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<G, V> GroupedData<G, V> {
+    /// The groups and their values, in the order of the server
+    pub fn iter(&self) -> slice::Iter<'_, (G, V)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn into_vec(self) -> Vec<(G, V)> {
+        self.0
+    }
+
+    pub fn into_hash_map(self) -> HashMap<G, V>
+    where
+        G: Eq + Hash,
+    {
+        self.0.into_iter().collect()
+    }
+
+    pub fn into_btree_map(self) -> BTreeMap<G, V>
+    where
+        G: Ord,
+    {
+        self.0.into_iter().collect()
+    }
+}
+
+impl<G, V> IntoIterator for GroupedData<G, V> {
+    type Item = (G, V);
+    type IntoIter = vec::IntoIter<(G, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, G, V> IntoIterator for &'a GroupedData<G, V> {
+    type Item = &'a (G, V);
+    type IntoIter = slice::Iter<'a, (G, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
 }
 
 impl<'de, G, V> Deserialize<'de> for GroupedStream<G, V>
@@ -29,22 +124,12 @@ where
     V: DeserializeOwned + Serialize,
 {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Deserialize::deserialize(deserializer).map(|item| {
-            let inner: InnerGroup = item;
-
-            let data: Vec<GroupedItem<G, V>> = inner
-                .data
-                .into_iter()
-                .map(|item| {
-                    let group: G = serde_json::from_value(item[0].clone()).unwrap();
-                    let values: Vec<V> = serde_json::from_value(item[1].clone()).unwrap();
+        let data: GroupedData<G, Vec<V>> = Deserialize::deserialize(deserializer)?;
+        let items = data
+            .into_iter()
+            .map(|(group, values)| GroupedItem { group, values });
 
-                    GroupedItem { group, values }
-                })
-                .collect();
-
-            GroupedStream(data)
-        })
+        Ok(GroupedStream(items.collect()))
     }
 }
 
@@ -56,14 +141,25 @@ where
     pub fn collect(self) -> Vec<GroupedItem<G, V>> {
         self.0
     }
-}
 
-impl Default for InnerGroup {
-    fn default() -> Self {
-        Self {
-            reql_type: ReqlType::GroupedData,
-            data: Default::default(),
-        }
+    pub fn into_hash_map(self) -> HashMap<G, Vec<V>>
+    where
+        G: Eq + Hash,
+    {
+        self.0
+            .into_iter()
+            .map(|item| (item.group, item.values))
+            .collect()
+    }
+
+    pub fn into_btree_map(self) -> BTreeMap<G, Vec<V>>
+    where
+        G: Ord,
+    {
+        self.0
+            .into_iter()
+            .map(|item| (item.group, item.values))
+            .collect()
     }
 }
 
@@ -71,7 +167,9 @@ impl Default for InnerGroup {
 mod tests {
     use serde::{Deserialize, Serialize};
 
-    use super::GroupedStream;
+    use serde_json::json;
+
+    use super::{GroupedData, GroupedStream};
 
     #[derive(Serialize, Deserialize, Debug)]
     struct Posts {
@@ -134,4 +232,24 @@ mod tests {
         let elememt: GroupedStream<u8, Posts> = serde_json::from_str(data).unwrap();
         dbg!(elememt);
     }
+
+    #[test]
+    fn test_grouped_data() {
+        let data = json!({
+            "$reql_type$": "GROUPED_DATA",
+            "data": [[["a", 1], 2], [["b", 2], 1]]
+        });
+
+        let grouped: GroupedData<(String, u8), usize> =
+            serde_json::from_value(data.clone()).unwrap();
+
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped.clone().into_hash_map()[&("a".to_owned(), 1)], 2);
+        assert_eq!(
+            grouped.clone().into_btree_map().into_keys().next(),
+            Some(("a".to_owned(), 1))
+        );
+        assert_eq!(serde_json::to_value(&grouped).unwrap(), data);
+        assert!(serde_json::from_value::<GroupedData<u8, usize>>(data).is_err());
+    }
 }
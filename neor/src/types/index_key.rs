@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Number;
+
+use super::DateTime;
+
+/// A key of a secondary index, to mix keys of several types in one
+/// [get_all](crate::Command::get_all)
+///
+/// Keys of a single type can be given as they are: a compound key is
+/// any serializable sequence, e.g. a tuple `("Smith", "John")`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+pub enum IndexKey {
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Time(DateTime),
+    /// The key of a compound index, one value for each field
+    Compound(Vec<IndexKey>),
+}
+
+impl From<bool> for IndexKey {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for IndexKey {
+    fn from(value: i64) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<u64> for IndexKey {
+    fn from(value: u64) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<i32> for IndexKey {
+    fn from(value: i32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<&str> for IndexKey {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for IndexKey {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<DateTime> for IndexKey {
+    fn from(value: DateTime) -> Self {
+        Self::Time(value)
+    }
+}
+
+impl<T: Into<IndexKey>> From<Vec<T>> for IndexKey {
+    fn from(values: Vec<T>) -> Self {
+        Self::Compound(values.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::IndexKey;
+
+    #[test]
+    fn index_key_serialize() {
+        let keys = [
+            IndexKey::from("Smith"),
+            IndexKey::from(7),
+            IndexKey::from(vec![IndexKey::from("Welles"), IndexKey::from(true)]),
+        ];
+
+        assert_eq!(
+            serde_json::to_value(keys).unwrap(),
+            json!(["Smith", 7, ["Welles", true]])
+        );
+    }
+}
@@ -10,20 +10,40 @@ pub use crate::cmd::geojson::ReqlGeoJson;
 pub use crate::cmd::line::Line;
 pub use crate::cmd::point::Point;
 pub use crate::cmd::polygon::Polygon;
-pub use binary::Binary;
+pub use binary::{as_binary, Binary};
+pub use capabilities::{Capabilities, ServerVersion};
+pub use config_diff::{ShardDiff, TableConfigDiff};
 pub use datetime::DateTime;
-pub use group_stream::{GroupedItem, GroupedStream};
+#[cfg(feature = "decimal")]
+pub use decimal::Decimal;
+pub use diagnostic::{Diagnostic, DiagnosticKind};
+pub use feed::{FeedItem, FeedState};
+pub use field_path::FieldPath;
+pub use group_stream::{GroupedData, GroupedItem, GroupedStream};
+pub use index_key::IndexKey;
 pub use time_::Time;
+pub use unit::Unit;
 
 pub(crate) use datetime::timezone_to_string;
 
 pub use crate::Command;
 
 mod binary;
+mod capabilities;
+mod config_diff;
 mod datetime;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod diagnostic;
+mod feed;
+mod field_path;
 mod group_stream;
+mod index_key;
+pub mod reql_time;
 mod response_with_cmd;
+mod table_status;
 mod time_;
+mod unit;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd, Eq, Ord)]
 #[non_exhaustive]
@@ -66,9 +86,16 @@ pub struct DbResponse {
 }
 
 /// Structure of return data in `db` table
-#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq)]
+///
+/// The missing counters are read as `0`, e.g. in the response of
+/// [for_each](crate::Command::for_each) over an empty sequence.
+///
+/// `T` is the type of the documents in `changes`, returned with the
+/// `return_changes` option, e.g. `MutationResponse<Post>`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
 #[non_exhaustive]
-pub struct MutationResponse {
+pub struct MutationResponse<T = Value> {
     pub inserted: usize,
     pub replaced: usize,
     pub unchanged: usize,
@@ -78,7 +105,72 @@ pub struct MutationResponse {
     pub first_error: Option<String>,
     pub generated_keys: Option<Vec<Uuid>>,
     pub warnings: Option<Vec<String>>,
-    pub changes: Option<Vec<ConfigChange<Value>>>,
+    pub changes: Option<Vec<ConfigChange<T>>>,
+    /// Primary keys of the changed documents, see
+    /// [limit_changes](crate::Command::limit_changes)
+    pub changed_keys: Option<Vec<Value>>,
+}
+
+// not derived, so that `T` needs no `Default` to deserialize the response
+impl<T> Default for MutationResponse<T> {
+    fn default() -> Self {
+        Self {
+            inserted: 0,
+            replaced: 0,
+            unchanged: 0,
+            skipped: 0,
+            deleted: 0,
+            errors: 0,
+            first_error: None,
+            generated_keys: None,
+            warnings: None,
+            changes: None,
+            changed_keys: None,
+        }
+    }
+}
+
+impl<T> MutationResponse<T> {
+    /// Split the keys recorded by [limit_changes](crate::Command::limit_changes)
+    /// in pages of `page_size` keys
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is 0.
+    pub fn changed_keys_pages(&self, page_size: usize) -> impl Iterator<Item = &[Value]> {
+        self.changed_keys
+            .as_deref()
+            .unwrap_or_default()
+            .chunks(page_size)
+    }
+
+    /// Add the counters of `other` to this response,
+    /// like the server does for the writes of [for_each](crate::Command::for_each)
+    ///
+    /// The lists are appended and the first error is kept.
+    pub fn merge(&mut self, other: MutationResponse<T>) {
+        self.inserted += other.inserted;
+        self.replaced += other.replaced;
+        self.unchanged += other.unchanged;
+        self.skipped += other.skipped;
+        self.deleted += other.deleted;
+        self.errors += other.errors;
+
+        if self.first_error.is_none() {
+            self.first_error = other.first_error;
+        }
+
+        extend(&mut self.generated_keys, other.generated_keys);
+        extend(&mut self.warnings, other.warnings);
+        extend(&mut self.changes, other.changes);
+        extend(&mut self.changed_keys, other.changed_keys);
+    }
+}
+
+fn extend<T>(total: &mut Option<Vec<T>>, part: Option<Vec<T>>) {
+    if let Some(part) = part {
+        total.get_or_insert_with(Vec::new).extend(part);
+    }
 }
 
 /// Structure of return data in `index` table
@@ -136,7 +228,7 @@ pub struct WaitResponse {
     /// The value is an integer indicating the number of tables waited for.
     /// It will always be `1` when `wait` is called on a table,
     /// and the total number of tables when called on a database.
-    pub ready: u8,
+    pub ready: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
@@ -157,7 +249,7 @@ pub struct GrantResponse {
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
 pub struct RebalanceResponse {
     /// the number of tables rebalanced.
-    pub rebalanced: u8,
+    pub rebalanced: usize,
     /// a list of new and old table status values.
     /// Each element of the list will be an object with two fields:
     /// - `old_val`: The table’s [status](crate::Command::status)
@@ -171,7 +263,7 @@ pub struct RebalanceResponse {
 pub struct ReconfigureResponse {
     /// the number of tables reconfigured.
     /// This will be `0` if `dry_run` is `true`.
-    pub reconfigured: u8,
+    pub reconfigured: usize,
     /// a list of new and old table configuration values.
     /// Each element of the list will be an object with two fields
     /// - `old_val`: The table’s [config](crate::Command::config)
@@ -192,20 +284,32 @@ pub struct ConfigChange<T> {
     pub old_val: Option<T>,
 }
 
+/// The configuration of a table or a database,
+/// see [config](crate::Command::config)
+///
+/// Only `id` and `name` are set for a database.
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ConfigResponse {
+    /// the UUID of the table or database.
     pub id: Cow<'static, str>,
+    /// the name of the table or database.
     pub name: Cow<'static, str>,
 
+    /// the database the table is in.
     pub db: Option<Cow<'static, str>>,
     pub durability: Option<Durability>,
+    /// the secondary indexes of the table.
     pub indexes: Option<Vec<Cow<'static, str>>>,
     pub primary_key: Option<Cow<'static, str>>,
+    /// one entry for each shard, naming the servers of its replicas.
     pub shards: Option<Vec<ShardType<Cow<'static, str>>>>,
     pub write_acks: Option<ReadMode>,
     pub write_hook: Option<Cow<'static, str>>,
 }
 
+/// The configuration of a table, see [config](crate::Command::config)
+pub type TableConfigResponse = ConfigResponse;
+
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct GrantChangeValue {
     pub write: Option<bool>,
@@ -214,6 +318,10 @@ pub struct GrantChangeValue {
     pub config: Option<bool>,
 }
 
+/// The status of a table, see [status](crate::Command::status)
+///
+/// See [all_replicas_ready](Self::all_replicas_ready) and the other
+/// methods to check the readiness of the table.
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct StatusResponse {
     /// the UUID of the table.
@@ -230,16 +338,31 @@ pub struct StatusResponse {
     pub raft_leader: Option<Cow<'static, str>>,
 }
 
+/// The status of a table, see [status](crate::Command::status)
+pub type TableStatusResponse = StatusResponse;
+
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct InfoResponse {
-    pub db: DbInfo,
-    pub doc_count_estimates: Vec<usize>,
-    pub id: Cow<'static, str>,
-    pub indexes: Vec<Cow<'static, str>>,
-    pub name: Cow<'static, str>,
-    pub primary_key: Cow<'static, str>,
     #[serde(rename = "type")]
     pub typ: TypeOf,
+    /// Set for tables and databases
+    pub id: Option<Cow<'static, str>>,
+    /// Set for tables and databases
+    pub name: Option<Cow<'static, str>>,
+    /// Set for tables
+    pub db: Option<DbInfo>,
+    /// Set for tables
+    pub primary_key: Option<Cow<'static, str>>,
+    /// Set for tables
+    #[serde(default)]
+    pub indexes: Vec<Cow<'static, str>>,
+    /// Set for tables
+    #[serde(default)]
+    pub doc_count_estimates: Vec<usize>,
+    /// The printed value, set for datums
+    pub value: Option<Cow<'static, str>>,
+    /// The table a selection comes from
+    pub table: Option<Box<InfoResponse>>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -249,6 +372,51 @@ pub struct ChangesResponse<T> {
     pub state: Option<ChangesState>,
     #[serde(rename = "type")]
     pub typ: Option<ChangesType>,
+    /// Position of the removed `old_val`, with `ChangesOption::include_offsets(true)`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_offset: Option<usize>,
+    /// Position of the inserted `new_val`, with `ChangesOption::include_offsets(true)`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_offset: Option<usize>,
+}
+
+impl<T> ChangesResponse<T> {
+    /// The kind of notification, read from its `type` field
+    ///
+    /// Returns `None` when the changefeed was run without
+    /// `ChangesOption::include_types(true)`.
+    pub fn event(self) -> Option<ChangeEvent<T>> {
+        let event = match (self.typ?, self.old_val, self.new_val) {
+            (ChangesType::Initial, _, Some(new_val)) => ChangeEvent::Initial(new_val),
+            (ChangesType::Add, _, Some(new_val)) => ChangeEvent::Add(new_val),
+            (ChangesType::Change, Some(old_val), Some(new_val)) => {
+                ChangeEvent::Change { old_val, new_val }
+            }
+            (ChangesType::Remove, Some(old_val), _) => ChangeEvent::Remove(old_val),
+            (ChangesType::Uninitial, Some(old_val), _) => ChangeEvent::Uninitial(old_val),
+            (ChangesType::State, _, _) => ChangeEvent::State(self.state?),
+            _ => return None,
+        };
+
+        Some(event)
+    }
+}
+
+/// A changefeed notification, see [ChangesResponse::event]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ChangeEvent<T> {
+    /// A document of the initial result set, with `include_initial`
+    Initial(T),
+    /// A document removed from the unsent part of the initial result set
+    Uninitial(T),
+    Add(T),
+    Change {
+        old_val: T,
+        new_val: T,
+    },
+    Remove(T),
+    /// With `include_states`
+    State(ChangesState),
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -271,36 +439,56 @@ pub enum ChangesType {
 
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct DbInfo {
-    id: Cow<'static, str>,
-    name: Cow<'static, str>,
+    pub id: Cow<'static, str>,
+    pub name: Cow<'static, str>,
     #[serde(rename = "type")]
     pub typ: TypeOf,
 }
 
+/// Readiness of all the shards of a table, see [StatusResponse]
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct StatusResponseStatus {
+    /// every replica of every shard is ready.
     pub all_replicas_ready: Option<bool>,
+    /// reads with `ReadMode::Outdated` can be run.
     pub ready_for_outdated_reads: Option<bool>,
+    /// reads with the default `ReadMode::Single` can be run.
     pub ready_for_reads: Option<bool>,
     pub ready_for_writes: Option<bool>,
 }
 
+/// Item of the array returned by [get_nearest](crate::Command::get_nearest)
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd)]
-pub struct ClosestDocumentResponse<T> {
+pub struct NearestResponse<T> {
+    /// Distance to the document, in the `unit` of the query (default: meters)
     pub dist: f64,
-    pub doc: Option<T>,
+    /// The document
+    pub doc: T,
 }
 
+/// Former name of [NearestResponse], with an optional document
+pub type ClosestDocumentResponse<T> = NearestResponse<Option<T>>;
+
+/// A shard of the [config](ConfigResponse), whose replicas are server names,
+/// or of the [status](StatusResponse) of a table
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
 pub struct ShardType<R> {
+    /// Set in the config
     pub primary_replica: Option<Cow<'static, str>>,
+    /// Set in the status, usually to a single server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_replicas: Option<Vec<Cow<'static, str>>>,
     pub replicas: Vec<R>,
     pub nonvoting_replicas: Option<Vec<Cow<'static, str>>>,
 }
 
+/// A replica of the [status](StatusResponse) of a table
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ShardReplicasType {
     pub server: Cow<'static, str>,
+    /// `ready`, `transitioning`, `backfilling`, `disconnected`,
+    /// `waiting_for_primary` or `waiting_for_quorum`
     pub state: Cow<'static, str>,
 }
 
@@ -343,7 +531,9 @@ pub enum TypeOf {
     Bool,
     Db,
     Function,
+    #[serde(rename = "GROUPED_DATA")]
     GroupedData,
+    #[serde(rename = "GROUPED_STREAM")]
     GroupedStream,
     Maxval,
     Minval,
@@ -366,6 +556,46 @@ pub enum TypeOf {
 
     Stream,
     String,
+    #[serde(rename = "TABLE_SLICE")]
     TableSlice,
     Table,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::TypeOf;
+
+    #[test]
+    fn type_of_names() {
+        let names = [
+            (TypeOf::Array, "ARRAY"),
+            (TypeOf::Bool, "BOOL"),
+            (TypeOf::Db, "DB"),
+            (TypeOf::Function, "FUNCTION"),
+            (TypeOf::GroupedData, "GROUPED_DATA"),
+            (TypeOf::GroupedStream, "GROUPED_STREAM"),
+            (TypeOf::Maxval, "MAXVAL"),
+            (TypeOf::Minval, "MINVAL"),
+            (TypeOf::Null, "NULL"),
+            (TypeOf::Number, "NUMBER"),
+            (TypeOf::Object, "OBJECT"),
+            (TypeOf::PtypeBinary, "PTYPE<BINARY>"),
+            (TypeOf::PtypeGeometry, "PTYPE<GEOMETRY>"),
+            (TypeOf::PtypeTime, "PTYPE<TIME>"),
+            (TypeOf::SelectionArray, "SELECTION<ARRAY>"),
+            (TypeOf::SelectionObject, "SELECTION<OBJECT>"),
+            (TypeOf::SelectionStream, "SELECTION<STREAM>"),
+            (TypeOf::Stream, "STREAM"),
+            (TypeOf::String, "STRING"),
+            (TypeOf::TableSlice, "TABLE_SLICE"),
+            (TypeOf::Table, "TABLE"),
+        ];
+
+        for (typ, name) in names {
+            assert_eq!(serde_json::to_value(typ).unwrap(), json!(name));
+            assert_eq!(serde_json::from_value::<TypeOf>(json!(name)).unwrap(), typ);
+        }
+    }
+}
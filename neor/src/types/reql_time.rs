@@ -0,0 +1,557 @@
+//! Serde `with` module storing a time as a TIME pseudo-type,
+//! the format of the times read from and written to RethinkDB
+//!
+//! [DateTime](super::DateTime) fields are read and written in this
+//! format as they are. Responses read with [parse](crate::Converter::parse)
+//! also convert the TIME pseudo-types to `OffsetDateTime` fields, with the
+//! `serde-human-readable` feature of `time`, and to `chrono::DateTime<Utc>`
+//! ones, with the `serde` feature of `chrono`. Writing them in this format,
+//! or reading them without `parse`, needs `#[serde(with = "reql_time")]`,
+//! or `#[serde(with = "reql_time::option")]` when they are optional.
+//!
+//! `parse` reads a TIME as a string wherever a string or any value is
+//! expected, e.g. in a `serde_json::Value`; read the response with
+//! `serde_json::from_value` to keep the pseudo-types.
+//!
+//! ```
+//! use neor::types::reql_time;
+//! use serde::{Deserialize, Serialize};
+//! use time::OffsetDateTime;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Post {
+//!     title: String,
+//!     #[serde(with = "reql_time")]
+//!     published_at: OffsetDateTime,
+//!     #[serde(default, with = "reql_time::option")]
+//!     updated_at: Option<OffsetDateTime>,
+//! }
+//! ```
+
+use std::fmt::Display;
+
+use serde::de::value::StringDeserializer;
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    Unexpected, VariantAccess, Visitor,
+};
+use serde::{
+    de, forward_to_deserialize_any, ser, Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::Value;
+use time::format_description::well_known::Rfc3339;
+use time::format_description::FormatItem;
+use time::macros::format_description;
+use time::OffsetDateTime;
+
+use super::DateTime;
+
+// The format read by `OffsetDateTime` with the `serde-human-readable` feature
+const HUMAN_READABLE: &[FormatItem<'_>] = format_description!(
+    "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond] [offset_hour sign:mandatory]:[offset_minute]:[offset_second]"
+);
+
+pub fn serialize<T, S>(time: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Clone + TryInto<DateTime>,
+    T::Error: Display,
+    S: Serializer,
+{
+    let time: DateTime = time.clone().try_into().map_err(ser::Error::custom)?;
+
+    time.serialize(serializer)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TryFrom<DateTime>,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    let time = DateTime::deserialize(deserializer)?;
+
+    T::try_from(time).map_err(de::Error::custom)
+}
+
+/// The [reql_time](self) format of an optional time, `null` being `None`
+pub mod option {
+    use std::fmt::Display;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::DateTime;
+
+    pub fn serialize<T, S>(time: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Clone + TryInto<DateTime>,
+        T::Error: Display,
+        S: Serializer,
+    {
+        match time {
+            Some(time) => super::serialize(time, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: TryFrom<DateTime>,
+        T::Error: Display,
+        D: Deserializer<'de>,
+    {
+        match Option::<DateTime>::deserialize(deserializer)? {
+            Some(time) => T::try_from(time).map(Some).map_err(de::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+/// `serde_json::from_value`, reading the TIME pseudo-types as the string
+/// `OffsetDateTime` is read from, or as the RFC 3339 string of `chrono`
+pub(crate) fn from_value<T: DeserializeOwned>(value: Value) -> serde_json::Result<T> {
+    T::deserialize(Converted(value))
+}
+
+// A value whose TIME pseudo-types, at any depth, are read as a time when
+// a string or any value is expected, and as an object otherwise
+struct Converted(Value);
+
+impl Converted {
+    fn time(&self) -> Option<serde_json::Result<OffsetDateTime>> {
+        match self.0.get("$reql_type$") {
+            Some(Value::String(reql_type)) if reql_type == "TIME" => {
+                Some(DateTime::deserialize(&self.0).map(|time| time.0))
+            }
+            _ => None,
+        }
+    }
+}
+
+macro_rules! forward_to_value {
+    ($($method:ident)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+            self.0.$method(visitor)
+        }
+    )*};
+}
+
+impl<'de> Deserializer<'de> for Converted {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        if let Some(time) = self.time() {
+            let time = time?.format(HUMAN_READABLE).map_err(de::Error::custom)?;
+
+            return visitor.visit_string(time);
+        }
+
+        match self.0 {
+            Value::Array(items) => visit_array(items, visitor),
+            Value::Object(entries) => visit_object(entries, visitor),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.time() {
+            Some(time) => visitor.visit_string(time?.format(&Rfc3339).map_err(de::Error::custom)?),
+            None => self.0.deserialize_str(visitor),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Array(items) => visit_array(items, visitor),
+            value => value.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Object(entries) => visit_object(entries, visitor),
+            value => value.deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Array(items) => visit_array(items, visitor),
+            Value::Object(entries) => visit_object(entries, visitor),
+            value => value.deserialize_struct(name, fields, visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        // `RawValue` is read by `serde_json` itself
+        if name.starts_with("$serde_json::") {
+            return self.0.deserialize_newtype_struct(name, visitor);
+        }
+
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Object(entries) if entries.len() == 1 => {
+                let (variant, value) = entries.into_iter().next().unwrap();
+
+                visitor.visit_enum(Enum(variant, value))
+            }
+            value => value.deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.0.deserialize_unit_struct(name, visitor)
+    }
+
+    forward_to_value! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_bytes
+        deserialize_byte_buf deserialize_unit deserialize_identifier deserialize_ignored_any
+    }
+}
+
+fn visit_array<'de, V: Visitor<'de>>(
+    items: Vec<Value>,
+    visitor: V,
+) -> serde_json::Result<V::Value> {
+    let len = items.len();
+    let mut seq = Seq(items.into_iter());
+    let value = visitor.visit_seq(&mut seq)?;
+
+    match seq.0.len() {
+        0 => Ok(value),
+        _ => Err(de::Error::invalid_length(len, &"fewer elements in array")),
+    }
+}
+
+fn visit_object<'de, V: Visitor<'de>>(
+    entries: serde_json::Map<String, Value>,
+    visitor: V,
+) -> serde_json::Result<V::Value> {
+    let len = entries.len();
+    let mut map = Map(entries.into_iter(), None);
+    let value = visitor.visit_map(&mut map)?;
+
+    match map.0.len() {
+        0 => Ok(value),
+        _ => Err(de::Error::invalid_length(len, &"fewer elements in map")),
+    }
+}
+
+struct Seq(std::vec::IntoIter<Value>);
+
+impl<'de> SeqAccess<'de> for Seq {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> serde_json::Result<Option<T::Value>> {
+        self.0
+            .next()
+            .map(|item| seed.deserialize(Converted(item)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+// The entries left and the value of the last key read
+struct Map(serde_json::map::IntoIter, Option<Value>);
+
+impl<'de> MapAccess<'de> for Map {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> serde_json::Result<Option<K::Value>> {
+        match self.0.next() {
+            Some((key, value)) => {
+                self.1 = Some(value);
+                seed.deserialize(Key(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> serde_json::Result<V::Value> {
+        match self.1.take() {
+            Some(value) => seed.deserialize(Converted(value)),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+// A key of an object, which may be read as a number like in `serde_json`
+struct Key(String);
+
+macro_rules! parse_key {
+    ($($method:ident $visit:ident)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+            match self.0.parse() {
+                Ok(number) => visitor.$visit(number),
+                Err(_) => visitor.visit_string(self.0),
+            }
+        }
+    )*};
+}
+
+impl<'de> Deserializer<'de> for Key {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        visitor.visit_string(self.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        let variant: StringDeserializer<serde_json::Error> = self.0.into_deserializer();
+
+        visitor.visit_enum(variant)
+    }
+
+    parse_key! {
+        deserialize_i8 visit_i8 deserialize_i16 visit_i16 deserialize_i32 visit_i32
+        deserialize_i64 visit_i64 deserialize_u8 visit_u8 deserialize_u16 visit_u16
+        deserialize_u32 visit_u32 deserialize_u64 visit_u64
+    }
+
+    forward_to_deserialize_any! {
+        bool i128 u128 f32 f64 char str string bytes byte_buf unit unit_struct
+        seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+// The variant of an enum written as `{"variant": value}`
+struct Enum(String, Value);
+
+impl<'de> EnumAccess<'de> for Enum {
+    type Error = serde_json::Error;
+    type Variant = Converted;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> serde_json::Result<(V::Value, Converted)> {
+        let variant: StringDeserializer<serde_json::Error> = self.0.into_deserializer();
+
+        Ok((seed.deserialize(variant)?, Converted(self.1)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Converted {
+    type Error = serde_json::Error;
+
+    fn unit_variant(self) -> serde_json::Result<()> {
+        <()>::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> serde_json::Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Object(entries) => visit_object(entries, visitor),
+            _ => Err(de::Error::invalid_type(
+                Unexpected::Other("non-object"),
+                &"struct variant",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use time::macros::datetime;
+    use time::OffsetDateTime;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Post {
+        #[serde(with = "super")]
+        published_at: OffsetDateTime,
+        #[serde(default, with = "super::option")]
+        updated_at: Option<OffsetDateTime>,
+    }
+
+    #[test]
+    fn reql_time_round_trip() {
+        let post = Post {
+            published_at: datetime!(2022-09-01 12:30:00.250 +02:00),
+            updated_at: None,
+        };
+        let value = serde_json::to_value(&post).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "published_at": {"$reql_type$": "TIME", "epoch_time": 1662028200.25, "timezone": "+02:00"},
+                "updated_at": null
+            })
+        );
+        assert_eq!(serde_json::from_value::<Post>(value).unwrap(), post);
+    }
+
+    // with the `serde-human-readable` feature of `time` of the dev-dependencies
+    #[test]
+    fn parse_reql_time() {
+        use std::collections::HashMap;
+
+        use crate::Converter;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum Event {
+            Published(OffsetDateTime),
+            Edited { at: Option<OffsetDateTime> },
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Post {
+            published_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+            history: HashMap<u32, Event>,
+            raw: serde_json::Value,
+        }
+
+        let time =
+            json!({"$reql_type$": "TIME", "epoch_time": 1662028200.25, "timezone": "+02:00"});
+        let value = json!({
+            "published_at": time,
+            "updated_at": null,
+            "history": {"1": {"Published": time}, "2": {"Edited": {"at": time}}},
+            "raw": time
+        });
+        let post: Post = value.parse().unwrap();
+        let published_at = datetime!(2022-09-01 12:30:00.250 +02:00);
+
+        assert_eq!(post.published_at, published_at);
+        assert_eq!(post.updated_at, None);
+        assert_eq!(post.history[&1], Event::Published(published_at));
+        assert_eq!(
+            post.history[&2],
+            Event::Edited {
+                at: Some(published_at)
+            }
+        );
+        assert_eq!(post.raw, json!("2022-09-01 12:30:00.25 +02:00:00"));
+        assert!(time.parse::<Vec<i64>>().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn reql_time_chrono() {
+        #[derive(Serialize, Deserialize)]
+        struct Post {
+            #[serde(with = "super")]
+            published_at: chrono::DateTime<chrono::Utc>,
+        }
+
+        let mut value = json!({
+            "published_at": {"$reql_type$": "TIME", "epoch_time": 1662028200.25, "timezone": "+02:00"}
+        });
+        let post: Post = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(post.published_at.timestamp_millis(), 1662028200250);
+        assert_eq!(
+            serde_json::to_value(&post).unwrap()["published_at"]["epoch_time"],
+            json!(1662028200.25)
+        );
+
+        let published_at: chrono::DateTime<chrono::Utc> =
+            crate::Converter::parse(value["published_at"].take()).unwrap();
+
+        assert_eq!(published_at.timestamp_millis(), 1662028200250);
+    }
+}
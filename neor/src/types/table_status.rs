@@ -0,0 +1,98 @@
+use super::{ShardReplicasType, StatusResponse, StatusResponseStatus};
+
+impl StatusResponse {
+    /// Whether every replica of every shard is ready,
+    /// which [wait](crate::Command::wait) waits for by default
+    pub fn all_replicas_ready(&self) -> bool {
+        self.ready(|status| status.all_replicas_ready)
+    }
+
+    /// Whether the table can run reads with the default `ReadMode::Single`
+    pub fn ready_for_reads(&self) -> bool {
+        self.ready(|status| status.ready_for_reads)
+    }
+
+    /// Whether the table can run reads with `ReadMode::Outdated`
+    pub fn ready_for_outdated_reads(&self) -> bool {
+        self.ready(|status| status.ready_for_outdated_reads)
+    }
+
+    /// Whether the table can run writes
+    pub fn ready_for_writes(&self) -> bool {
+        self.ready(|status| status.ready_for_writes)
+    }
+
+    /// The replicas which are not ready, with the position of their shard
+    pub fn unready_replicas(&self) -> Vec<(usize, &ShardReplicasType)> {
+        self.shards
+            .iter()
+            .flatten()
+            .enumerate()
+            .flat_map(|(shard, shard_type)| {
+                shard_type
+                    .replicas
+                    .iter()
+                    .filter(|replica| !replica.is_ready())
+                    .map(move |replica| (shard, replica))
+            })
+            .collect()
+    }
+
+    fn ready(&self, flag: impl Fn(&StatusResponseStatus) -> Option<bool>) -> bool {
+        self.status.as_ref().and_then(flag).unwrap_or_default()
+    }
+}
+
+impl ShardReplicasType {
+    /// Whether the replica is in the `ready` state
+    pub fn is_ready(&self) -> bool {
+        self.state == "ready"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::types::TableStatusResponse;
+
+    #[test]
+    fn table_status_readiness() {
+        let status: TableStatusResponse = serde_json::from_value(json!({
+            "db": "test",
+            "id": "5cb35225-81b2-4cec-9eef-bfad15481265",
+            "name": "superstars",
+            "raft_leader": "jeeves",
+            "shards": [
+                {
+                    "primary_replicas": ["jeeves"],
+                    "replicas": [{"server": "jeeves", "state": "ready"}]
+                },
+                {
+                    "primary_replicas": ["alfred"],
+                    "replicas": [
+                        {"server": "alfred", "state": "ready"},
+                        {"server": "jeeves", "state": "backfilling"}
+                    ]
+                }
+            ],
+            "status": {
+                "all_replicas_ready": false,
+                "ready_for_outdated_reads": true,
+                "ready_for_reads": true,
+                "ready_for_writes": true
+            }
+        }))
+        .unwrap();
+        let unready = status.unready_replicas();
+
+        assert!(!status.all_replicas_ready());
+        assert!(status.ready_for_reads() && status.ready_for_writes());
+        assert_eq!(unready.len(), 1);
+        assert_eq!((unready[0].0, unready[0].1.server.as_ref()), (1, "jeeves"));
+        assert_eq!(
+            status.shards.unwrap()[1].primary_replicas,
+            Some(vec!["alfred".into()])
+        );
+    }
+}
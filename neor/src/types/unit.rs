@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// Unit of the distances of the geospatial commands
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Unit {
+    #[serde(rename = "m")]
+    Meter,
+    #[serde(rename = "km")]
+    Kilometer,
+    #[serde(rename = "mi")]
+    InternationalMile,
+    #[serde(rename = "nm")]
+    NauticalMile,
+    #[serde(rename = "ft")]
+    InternationalFoot,
+}
+
+impl Unit {
+    /// Length of one unit in meters
+    pub fn meters(self) -> f64 {
+        match self {
+            Self::Meter => 1.,
+            Self::Kilometer => 1000.,
+            Self::InternationalMile => 1609.344,
+            Self::NauticalMile => 1852.,
+            Self::InternationalFoot => 0.3048,
+        }
+    }
+
+    /// Convert a distance `value` expressed in this unit to the unit `to`,
+    /// e.g. to post-process the results of
+    /// [distance](crate::Command::distance) or
+    /// [get_nearest](crate::Command::get_nearest)
+    pub fn convert(self, value: f64, to: Unit) -> f64 {
+        if self == to {
+            return value;
+        }
+
+        value * self.meters() / to.meters()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Unit;
+
+    #[test]
+    fn unit_convert() {
+        assert!(Unit::Kilometer.convert(1.5, Unit::Meter) == 1500.);
+        assert!(Unit::NauticalMile.convert(2., Unit::Meter) == 3704.);
+        assert!(Unit::Meter.convert(0.3048, Unit::InternationalFoot) == 1.);
+        assert!((Unit::InternationalMile.convert(1., Unit::Kilometer) - 1.609344).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,148 @@
+// @generated by `cases_are_generated` in tests/spec/generator.rs, do not edit
+
+#![allow(unused_imports)]
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use neor::{args, r, Result};
+use serde_json::json;
+
+use super::{check, Expected};
+
+// datum_object: Tests of objects, 0 of 6 cases skipped
+
+#[tokio::test]
+async fn datum_object_0() -> Result<()> {
+    check(r.expr(json!({"a": 1, "b": 2})).keys(), Expected::Bag("[\"a\",\"b\"]")).await
+}
+
+#[tokio::test]
+async fn datum_object_1() -> Result<()> {
+    check(r.expr(json!({"a": 1, "b": 2})).values(), Expected::Bag("[1,2]")).await
+}
+
+#[tokio::test]
+async fn datum_object_2() -> Result<()> {
+    check(r.expr(json!({"a": 1})).merge(json!({"b": 2})), Expected::Value("{\"a\":1,\"b\":2}")).await
+}
+
+#[tokio::test]
+async fn datum_object_3() -> Result<()> {
+    check(r.expr(json!({"a": {"b": 1}})).g("a").g("b"), Expected::Value("1")).await
+}
+
+#[tokio::test]
+async fn datum_object_4() -> Result<()> {
+    check(r.expr(json!({"a": 1})).has_fields("a"), Expected::Value("true")).await
+}
+
+#[tokio::test]
+async fn datum_object_5() -> Result<()> {
+    check(r.expr(json!({"a": 1})).coerce_to("array"), Expected::Value("[[\"a\",1]]")).await
+}
+
+// datum_string: Tests of string manipulation, 0 of 6 cases skipped
+
+#[tokio::test]
+async fn datum_string_0() -> Result<()> {
+    check(r.expr("abc").upcase(), Expected::Value("\"ABC\"")).await
+}
+
+#[tokio::test]
+async fn datum_string_1() -> Result<()> {
+    check(r.expr("ABC").downcase(), Expected::Value("\"abc\"")).await
+}
+
+#[tokio::test]
+async fn datum_string_2() -> Result<()> {
+    check(r.expr("a,b,,c").split(","), Expected::Value("[\"a\",\"b\",\"\",\"c\"]")).await
+}
+
+#[tokio::test]
+async fn datum_string_3() -> Result<()> {
+    check(r.expr("abc").type_of(), Expected::Value("\"STRING\"")).await
+}
+
+#[tokio::test]
+async fn datum_string_4() -> Result<()> {
+    check(r.expr("abc").count(()), Expected::Value("3")).await
+}
+
+#[tokio::test]
+async fn datum_string_5() -> Result<()> {
+    check(r.expr("abc").upcase() + 1, Expected::Error("ReqlQueryLogicError", "Expected type STRING but found NUMBER.")).await
+}
+
+// math_logic_add: Tests for basic usage of the add operation, 1 of 9 cases skipped
+
+#[tokio::test]
+async fn math_logic_add_0() -> Result<()> {
+    check(r.expr(1) + 1, Expected::Value("2")).await
+}
+
+#[tokio::test]
+async fn math_logic_add_1() -> Result<()> {
+    check(r.expr(1).add(1), Expected::Value("2")).await
+}
+
+#[tokio::test]
+async fn math_logic_add_2() -> Result<()> {
+    check(1 + r.expr(1), Expected::Value("2")).await
+}
+
+#[tokio::test]
+async fn math_logic_add_3() -> Result<()> {
+    check(r.expr(-1) + 1.5, Expected::Value("0.5")).await
+}
+
+#[tokio::test]
+async fn math_logic_add_4() -> Result<()> {
+    check(r.expr("a") + "b", Expected::Value("\"ab\"")).await
+}
+
+#[tokio::test]
+async fn math_logic_add_5() -> Result<()> {
+    check(r.expr([1, 2]) + [3], Expected::Value("[1,2,3]")).await
+}
+
+#[tokio::test]
+async fn math_logic_add_6() -> Result<()> {
+    check(r.expr(1) + "a", Expected::Error("ReqlQueryLogicError", "Expected type NUMBER but found STRING.")).await
+}
+
+#[tokio::test]
+async fn math_logic_add_7() -> Result<()> {
+    check(r.expr("a") + 1, Expected::Error("ReqlQueryLogicError", "Expected type STRING but found NUMBER.")).await
+}
+
+// math_logic_comparison: Tests of comparison operators, 1 of 7 cases skipped
+
+#[tokio::test]
+async fn math_logic_comparison_0() -> Result<()> {
+    check(r.expr(1).lt(2), Expected::Value("true")).await
+}
+
+#[tokio::test]
+async fn math_logic_comparison_1() -> Result<()> {
+    check(r.expr(2).le(2), Expected::Value("true")).await
+}
+
+#[tokio::test]
+async fn math_logic_comparison_2() -> Result<()> {
+    check(r.expr("a").gt("b"), Expected::Value("false")).await
+}
+
+#[tokio::test]
+async fn math_logic_comparison_3() -> Result<()> {
+    check(r.expr(1).eq(1.0), Expected::Value("true")).await
+}
+
+#[tokio::test]
+async fn math_logic_comparison_4() -> Result<()> {
+    check(r.expr([1, 2]).ne([1, 2]), Expected::Value("false")).await
+}
+
+#[tokio::test]
+async fn math_logic_comparison_5() -> Result<()> {
+    check(r.expr(1).lt("a"), Expected::Value("true")).await
+}
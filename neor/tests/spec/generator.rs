@@ -0,0 +1,352 @@
+//! Generates `cases.rs` from the YAML files of `yaml/`
+//!
+//! A case is ported when it has a `rs` source, or a list of them, with an
+//! `ot` output written as a value, `err(type, message, frames)` or `bag(array)`.
+//! The other cases are counted as skipped at the top of their file's tests.
+//!
+//! Whole files are skipped when listed in `skipped.txt`, or when their cases
+//! need the tables of `table_variable_name`, which the harness doesn't create.
+
+use std::fmt::Write;
+use std::fs;
+use std::iter::Peekable;
+use std::path::{Path, PathBuf};
+use std::str::Chars;
+
+use serde_json::{Number, Value};
+use serde_yaml::Value as Yaml;
+
+const SPEC_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/spec");
+
+const HEADER: &str = "\
+// @generated by `cases_are_generated` in tests/spec/generator.rs, do not edit
+
+#![allow(unused_imports)]
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use neor::{args, r, Result};
+use serde_json::json;
+
+use super::{check, Expected};
+";
+
+#[test]
+fn cases_are_generated() {
+    let cases = generate();
+    let path = Path::new(SPEC_DIR).join("cases.rs");
+
+    if std::env::var_os("UPDATE_SPEC").is_some() {
+        fs::write(&path, cases).unwrap();
+    } else {
+        assert!(
+            fs::read_to_string(&path).unwrap_or_default() == cases,
+            "tests/spec/cases.rs is out of date, regenerate it with \
+             `UPDATE_SPEC=1 cargo test --test test_spec cases_are_generated`"
+        );
+    }
+}
+
+fn generate() -> String {
+    let root = Path::new(SPEC_DIR).join("yaml");
+    let skipped = skipped_files();
+    let mut files = Vec::new();
+    let mut cases = String::from(HEADER);
+
+    spec_files(&root, &mut files);
+    files.sort();
+
+    for file in files {
+        let path = file
+            .strip_prefix(&root)
+            .unwrap()
+            .with_extension("")
+            .to_string_lossy()
+            .replace('\\', "/");
+        let name = path.replace(['/', '-'], "_");
+        let spec: Yaml = serde_yaml::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+        let skip = match skipped.iter().find(|(skipped, _)| *skipped == path) {
+            Some((_, reason)) => Some(reason.as_str()),
+            None if spec.get("table_variable_name").is_some() => {
+                Some("needs the tables of `table_variable_name`")
+            }
+            None => None,
+        };
+
+        match skip {
+            Some(reason) => {
+                writeln!(cases).unwrap();
+                writeln!(
+                    cases,
+                    "// {}: {}, skipped: {}",
+                    name,
+                    spec["desc"].as_str().unwrap_or_default(),
+                    reason
+                )
+                .unwrap();
+            }
+            None => generate_file(&name, &spec, &mut cases),
+        }
+    }
+
+    cases
+}
+
+// The `path  # reason` lines of `skipped.txt`, paths being relative to `yaml/`
+fn skipped_files() -> Vec<(String, String)> {
+    let skipped = fs::read_to_string(Path::new(SPEC_DIR).join("skipped.txt")).unwrap_or_default();
+
+    skipped
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (path, reason) = line.split_once('#').unwrap_or((line, "unsupported"));
+            let path = path.trim().trim_end_matches(".yaml");
+
+            (path.to_owned(), reason.trim().to_owned())
+        })
+        .collect()
+}
+
+fn spec_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.is_dir() {
+            spec_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "yaml") {
+            files.push(path);
+        }
+    }
+}
+
+fn generate_file(name: &str, spec: &Yaml, cases: &mut String) {
+    let tests = spec["tests"].as_sequence().cloned().unwrap_or_default();
+    let mut tests_rs = String::new();
+    let mut skipped = 0;
+
+    for (i, test) in tests.iter().enumerate() {
+        let sources = match &test["rs"] {
+            Yaml::String(source) => vec![source.as_str()],
+            Yaml::Sequence(sources) => sources.iter().filter_map(Yaml::as_str).collect(),
+            _ => Vec::new(),
+        };
+        let ot = match &test["ot"] {
+            Yaml::Mapping(outputs) => outputs
+                .get("rs")
+                .or_else(|| outputs.get("cd"))
+                .cloned()
+                .unwrap_or(Yaml::Null),
+            ot => ot.clone(),
+        };
+
+        let expected = match expected(&ot) {
+            Some(expected) if !sources.is_empty() => expected,
+            _ => {
+                skipped += 1;
+                continue;
+            }
+        };
+
+        for (j, source) in sources.iter().enumerate() {
+            let suffix = match sources.len() {
+                1 => String::new(),
+                _ => format!("_{}", j),
+            };
+
+            writeln!(tests_rs).unwrap();
+            writeln!(tests_rs, "#[tokio::test]").unwrap();
+            writeln!(
+                tests_rs,
+                "async fn {}_{}{}() -> Result<()> {{",
+                name, i, suffix
+            )
+            .unwrap();
+            writeln!(tests_rs, "    check({}, {}).await", source.trim(), expected).unwrap();
+            writeln!(tests_rs, "}}").unwrap();
+        }
+    }
+
+    writeln!(cases).unwrap();
+    writeln!(
+        cases,
+        "// {}: {}, {} of {} cases skipped",
+        name,
+        spec["desc"].as_str().unwrap_or_default(),
+        skipped,
+        tests.len()
+    )
+    .unwrap();
+    cases.push_str(&tests_rs);
+}
+
+// The `Expected` built from the `ot` output of a case
+fn expected(ot: &Yaml) -> Option<String> {
+    let source = match ot {
+        Yaml::String(source) => source.trim(),
+        Yaml::Null => return None,
+        ot => {
+            let value = serde_json::to_value(ot).ok()?;
+            return Some(format!("Expected::Value({:?})", value.to_string()));
+        }
+    };
+
+    if let Some(args) = call(source, "err") {
+        match parse_python(&format!("[{}]", args))? {
+            Value::Array(args) => match args.as_slice() {
+                [Value::String(kind), Value::String(message), ..] => {
+                    Some(format!("Expected::Error({:?}, {:?})", kind, message))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    } else if let Some(array) = call(source, "bag") {
+        let value = parse_python(array)?;
+        Some(format!("Expected::Bag({:?})", value.to_string()))
+    } else {
+        let value = parse_python(source)?;
+        Some(format!("Expected::Value({:?})", value.to_string()))
+    }
+}
+
+// The arguments of `name(args)`
+fn call<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    source
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+// A Python literal, the language of the outputs of the spec
+fn parse_python(source: &str) -> Option<Value> {
+    let mut parser = PythonLiteral(source.chars().peekable());
+    let value = parser.value()?;
+
+    parser.skip_whitespace();
+    parser.0.peek().is_none().then_some(value)
+}
+
+struct PythonLiteral<'a>(Peekable<Chars<'a>>);
+
+impl PythonLiteral<'_> {
+    fn value(&mut self) -> Option<Value> {
+        self.skip_whitespace();
+
+        match *self.0.peek()? {
+            '[' => self.items(']').map(Value::Array),
+            '{' => {
+                let mut object = serde_json::Map::new();
+
+                for pair in self.items('}')? {
+                    if let Value::Array(mut pair) = pair {
+                        if let (Some(value), Some(Value::String(key))) = (pair.pop(), pair.pop()) {
+                            object.insert(key, value);
+                            continue;
+                        }
+                    }
+                    return None;
+                }
+
+                Some(Value::Object(object))
+            }
+            '(' => {
+                self.0.next();
+                let value = self.value()?;
+                self.skip_whitespace();
+                (self.0.next()? == ')').then_some(value)
+            }
+            '\'' | '"' => self.string().map(Value::String),
+            '-' | '0'..='9' => self.number(),
+            _ => {
+                let word = self.take_while(|c| c.is_alphanumeric() || c == '_');
+
+                match word.as_str() {
+                    "True" | "true" => Some(Value::Bool(true)),
+                    "False" | "false" => Some(Value::Bool(false)),
+                    "None" | "null" => Some(Value::Null),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    // The items of a list, or the `key: value` pairs of a dict
+    fn items(&mut self, close: char) -> Option<Vec<Value>> {
+        let mut items = Vec::new();
+        self.0.next();
+
+        loop {
+            self.skip_whitespace();
+            if self.0.peek() == Some(&close) {
+                self.0.next();
+                return Some(items);
+            }
+
+            let item = self.value()?;
+            self.skip_whitespace();
+
+            if close == '}' {
+                if self.0.next()? != ':' {
+                    return None;
+                }
+                items.push(Value::Array(vec![item, self.value()?]));
+                self.skip_whitespace();
+            } else {
+                items.push(item);
+            }
+
+            match self.0.next()? {
+                ',' => continue,
+                c if c == close => return Some(items),
+                _ => return None,
+            }
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let quote = self.0.next()?;
+        let mut string = String::new();
+
+        loop {
+            match self.0.next()? {
+                '\\' => match self.0.next()? {
+                    'n' => string.push('\n'),
+                    't' => string.push('\t'),
+                    c => string.push(c),
+                },
+                c if c == quote => return Some(string),
+                c => string.push(c),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Option<Value> {
+        let number = self.take_while(|c| c.is_ascii_digit() || "-+.eE".contains(c));
+
+        match number.parse::<i64>() {
+            Ok(int) => Some(int.into()),
+            Err(_) => Number::from_f64(number.parse().ok()?).map(Value::Number),
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut taken = String::new();
+
+        while let Some(&c) = self.0.peek() {
+            if !predicate(c) {
+                break;
+            }
+            taken.push(c);
+            self.0.next();
+        }
+
+        taken
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+}
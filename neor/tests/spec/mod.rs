@@ -0,0 +1,89 @@
+//! A harness for the polyglot ReQL tests of RethinkDB, run by every official driver
+//!
+//! `yaml/` mirrors `test/rql_test/src` of the RethinkDB repository, whose files
+//! are copied as they are. It only holds a sample of it so far: a few cases of
+//! `datum/object`, `datum/string`, `math_logic/add` and `math_logic/comparison`,
+//! with a `rs` translation of each case the driver can express. The cases
+//! without `rs` are counted as skipped, and so are the files listed in
+//! `skipped.txt` or needing the tables of `table_variable_name`.
+//! `generator` turns them into the tests of `cases.rs`, which run the query
+//! against the server and compare the response to the `ot` output of the case.
+
+use neor::err::{ReqlAvailabilityError, ReqlError, ReqlRuntimeError};
+use neor::{r, Command, Result};
+use serde_json::Value;
+
+#[rustfmt::skip]
+mod cases;
+mod generator;
+
+/// The `ot` output of a case, its values being written in JSON
+pub enum Expected {
+    Value(&'static str),
+    /// An array compared regardless of its order
+    Bag(&'static str),
+    /// The error type, named like in the other drivers, and its message
+    Error(&'static str, &'static str),
+}
+
+pub async fn check(query: Command, expected: Expected) -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response = query.run(&conn).await;
+
+    match expected {
+        Expected::Value(json) => {
+            assert_eq!(response?.unwrap_or_default(), parse(json));
+        }
+        Expected::Bag(json) => {
+            assert_eq!(bag(response?.unwrap_or_default()), bag(parse(json)));
+        }
+        Expected::Error(kind, message) => match response {
+            Err(err) => assert_eq!(error(&err), (kind, message.to_owned())),
+            Ok(value) => panic!("expected {} `{}`, got {:?}", kind, message, value),
+        },
+    }
+
+    Ok(())
+}
+
+fn parse(json: &str) -> Value {
+    serde_json::from_str(json).unwrap()
+}
+
+fn bag(value: Value) -> Vec<String> {
+    let mut items: Vec<_> = match value {
+        Value::Array(items) => items.iter().map(Value::to_string).collect(),
+        value => vec![value.to_string()],
+    };
+
+    items.sort();
+    items
+}
+
+fn error(err: &ReqlError) -> (&'static str, String) {
+    let kind = match err {
+        ReqlError::Compile(_) => "ReqlCompileError",
+        ReqlError::Runtime(err) => match err {
+            ReqlRuntimeError::QueryLogic(_) => "ReqlQueryLogicError",
+            ReqlRuntimeError::NonExistence(_) => "ReqlNonExistenceError",
+            ReqlRuntimeError::ResourceLimit(_) => "ReqlResourceLimitError",
+            ReqlRuntimeError::User(_) => "ReqlUserError",
+            ReqlRuntimeError::Internal(_) => "ReqlInternalError",
+            ReqlRuntimeError::Availability(ReqlAvailabilityError::OpFailed(_)) => {
+                "ReqlOpFailedError"
+            }
+            ReqlRuntimeError::Availability(ReqlAvailabilityError::OpIndeterminate(_)) => {
+                "ReqlOpIndeterminateError"
+            }
+            ReqlRuntimeError::Permission(_) => "ReqlPermissionError",
+        },
+        ReqlError::Driver(_) => "ReqlDriverError",
+    };
+    let message = match err {
+        ReqlError::Compile(msg) => msg.message.clone(),
+        ReqlError::Runtime(err) => err.message().message.clone(),
+        ReqlError::Driver(err) => err.to_string(),
+    };
+
+    (kind, message)
+}
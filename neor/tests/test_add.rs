@@ -1,5 +1,6 @@
 use neor::types::Time;
 use neor::{r, Converter, Result};
+use serde_json::json;
 
 #[tokio::test]
 async fn test_add_ops() -> Result<()> {
@@ -28,3 +29,28 @@ async fn test_add_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_add_reflected() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response1: u8 = (2 + r.expr(2)).run(&conn).await?.unwrap().parse()?;
+    let response2: String = ("foo" + r.expr("bar")).run(&conn).await?.unwrap().parse()?;
+
+    assert_eq!(response1, 4);
+    assert_eq!(response2, "foobar");
+
+    Ok(())
+}
+
+#[test]
+fn test_add_reflected_operators() -> Result<()> {
+    let query = 10 - (2 * r.expr(3)) + (1.5 / (7 % r.expr(4)));
+
+    assert_eq!(
+        query.to_wire_json()?,
+        json!([24, [[25, [10, [26, [2, 3]]]], [27, [1.5, [28, [7, 4]]]]]])
+    );
+    assert_eq!(("a" + r.expr("b")).to_wire_json()?, json!([24, ["a", "b"]]));
+
+    Ok(())
+}
@@ -10,3 +10,19 @@ async fn test_args_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_args_of_commands() -> Result<()> {
+    let query = r.table("posts").get_all(r.args([r.expr(1), r.expr(1) + 2]));
+
+    assert_eq!(
+        query.to_wire_json()?,
+        serde_json::json!([
+            78,
+            [[15, ["posts"], {}], [154, [[2, [1, [24, [1, 2]]]]]]],
+            {}
+        ])
+    );
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
-use neor::arguments::{BetweenOption, Status};
-use neor::{args, r, Converter, Result};
+use neor::arguments::{BetweenOption, Bound, Status};
+use neor::{args, r, Converter, IntoTerm, Result};
 
 use common::{set_up, tear_down, Post};
 
@@ -99,3 +99,57 @@ async fn test_get_data_between_by_minval_and_max_val_with_opts() -> Result<()> {
 
     tear_down(conn, table_name.as_str()).await
 }
+
+#[tokio::test]
+async fn test_get_data_between_bounds() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    let mut data_get: Vec<Post> = table
+        .between(Bound::open(2)..Bound::closed(4))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    data_get.sort_by_key(|post| post.id);
+
+    assert!(data_get.len() == 2);
+    assert!(data_get.first() == data.get(2));
+    assert!(data_get.last() == data.get(3));
+
+    tear_down(conn, table_name.as_str()).await
+}
+
+#[tokio::test]
+async fn test_get_data_between_bounds_with_opts() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    let between_option = BetweenOption::default()
+        .right_bound(Status::Open)
+        .index("title");
+    let data_get: Vec<Post> = table
+        .between(args!(Bound::min(), Bound::max(), between_option))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(data_get.len() == data.len());
+
+    tear_down(conn, table_name.as_str()).await
+}
+
+#[test]
+fn test_between_min_max_val_keys() -> Result<()> {
+    let query = r
+        .table("posts")
+        .between(args!(("Smith", r::min_val()).into_term(), r::max_val()));
+    let wire = query.to_wire_json()?;
+
+    assert_eq!(
+        wire[1][1],
+        serde_json::json!([2, ["Smith", r::min_val().to_wire_json()?]])
+    );
+    assert_eq!(wire[1][2], r::max_val().to_wire_json()?);
+
+    Ok(())
+}
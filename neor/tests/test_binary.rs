@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use neor::types::Binary;
+use neor::types::{as_binary, Binary};
 use neor::{r, Converter, Result};
 
 use common::{set_up, tear_down};
@@ -14,6 +14,13 @@ struct User {
     avatar: Binary,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct File {
+    id: u8,
+    #[serde(with = "as_binary")]
+    content: Vec<u8>,
+}
+
 #[tokio::test]
 async fn test_binary_ops() -> Result<()> {
     let avatar_img = std::fs::read("../logo.png")?;
@@ -29,7 +36,32 @@ async fn test_binary_ops() -> Result<()> {
 
     assert!(response.id == user.id);
     assert!(response.name == user.name);
-    assert!(!response.avatar.data.is_empty());
+    assert!(response.avatar.bytes()? == avatar_img);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_binary_as_bytes() -> Result<()> {
+    let file = File {
+        id: 1,
+        content: vec![0, 1, 2, 254, 255],
+    };
+
+    let (conn, table, table_name) = set_up(false).await?;
+    table.insert(&file).run(&conn).await?;
+    let response: File = table.get(1).run(&conn).await?.unwrap().parse()?;
+    let count: usize = table
+        .get(1)
+        .g("content")
+        .count(())
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.content == file.content);
+    assert!(count == file.content.len());
 
     tear_down(conn, &table_name).await
 }
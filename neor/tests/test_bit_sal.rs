@@ -24,3 +24,13 @@ async fn test_bit_sal_ops_with_command() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_bit_sal_ops_with_syntax() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: u8 = (r.expr(5) << 4).run(&conn).await?.unwrap().parse()?;
+
+    assert!(response == 80);
+
+    Ok(())
+}
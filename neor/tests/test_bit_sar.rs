@@ -24,3 +24,13 @@ async fn test_bit_sar_ops_with_command() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_bit_sar_ops_with_syntax() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: u8 = (r.expr(32) >> 3).run(&conn).await?.unwrap().parse()?;
+
+    assert!(response == 4);
+
+    Ok(())
+}
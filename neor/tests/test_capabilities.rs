@@ -0,0 +1,13 @@
+use neor::types::ServerVersion;
+use neor::{r, Result};
+
+#[tokio::test]
+async fn test_capabilities() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let capabilities = conn.capabilities().await?;
+
+    assert!(capabilities.version >= ServerVersion::new(2, 3, 0));
+    assert!(capabilities.permissions);
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
+use std::time::Duration;
+
 use futures::TryStreamExt;
-use neor::arguments::ChangesOption;
-use neor::types::ChangesResponse;
-use neor::{Converter, Result};
+use neor::arguments::{ChangesOption, Squash};
+use neor::types::{ChangesResponse, FeedItem, FeedState};
+use neor::{r, Converter, Result};
 
 use common::{set_up, tear_down, Post};
 
@@ -42,3 +44,99 @@ async fn test_limit_data() -> Result<()> {
 
     tear_down(session, &table_name).await
 }
+
+#[tokio::test]
+async fn test_collect_changes_for() -> Result<()> {
+    let data = Post::get_many_data();
+    let (session, table, table_name) = set_up(true).await?;
+    let changes_options = ChangesOption::default().include_initial(true);
+
+    let response: Vec<ChangesResponse<Post>> = table
+        .changes(changes_options)
+        .collect_changes_for(&session, Duration::from_millis(500))
+        .await?;
+    let mut response: Vec<Post> = response
+        .into_iter()
+        .filter_map(|resp| resp.new_val)
+        .collect();
+
+    response.sort_by_key(|post| post.id);
+
+    assert_eq!(response, data);
+
+    tear_down(session, &table_name).await
+}
+
+#[tokio::test]
+async fn test_changes_offsets() -> Result<()> {
+    let (session, table, table_name) = set_up(true).await?;
+    let changes_options = ChangesOption::default()
+        .squash(Squash::Duration(Duration::from_millis(100)))
+        .changefeed_queue_size(1000)
+        .include_initial(true)
+        .include_offsets(true);
+
+    let response: Vec<ChangesResponse<Post>> = table
+        .order_by(r.index("id"))
+        .limit(2)
+        .changes(changes_options)
+        .collect_changes_for(&session, Duration::from_millis(500))
+        .await?;
+
+    assert!(response.len() == 2);
+    assert!(response.iter().all(|change| change.new_offset.is_some()));
+
+    tear_down(session, &table_name).await
+}
+
+#[tokio::test]
+async fn test_run_changes() -> Result<()> {
+    let data = Post::get_many_data();
+    let (session, table, table_name) = set_up(true).await?;
+    let changes_options = ChangesOption::default()
+        .include_initial(true)
+        .include_states(true);
+    let mut response = Vec::new();
+
+    {
+        let mut feed = table.changes(changes_options).run_changes::<Post>(&session);
+
+        while let Some(item) = feed.try_next().await? {
+            match item {
+                FeedItem::Initial(post) => response.push(post),
+                FeedItem::State(FeedState::Ready) => break,
+                _ => {}
+            }
+        }
+    }
+
+    response.sort_by_key(|post| post.id);
+
+    assert_eq!(response, data);
+    assert!(table
+        .run_changes::<Post>(&session)
+        .try_next()
+        .await
+        .is_err());
+
+    tear_down(session, &table_name).await
+}
+
+#[test]
+fn test_changes_squash_wire_format() -> Result<()> {
+    let opts = ChangesOption::default().squash(Squash::Duration(Duration::from_millis(1500)));
+
+    assert_eq!(
+        r.table("posts").changes(opts).to_wire_json()?[2],
+        serde_json::json!({"squash": 1.5})
+    );
+
+    let opts = ChangesOption::default().squash(Squash::Bool(true));
+
+    assert_eq!(
+        r.table("posts").changes(opts).to_wire_json()?[2],
+        serde_json::json!({"squash": true})
+    );
+
+    Ok(())
+}
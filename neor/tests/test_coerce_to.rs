@@ -36,3 +36,41 @@ async fn test_coerce_to_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_coerce_to_shorthands() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: Vec<u8> = r.range(3).to_array().run(&conn).await?.unwrap().parse()?;
+    let response2: f64 = r
+        .expr("1.5")
+        .to_number()
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let response3: String = r.expr(12).to_string_().run(&conn).await?.unwrap().parse()?;
+
+    assert!(response == [0, 1, 2]);
+    assert!(response2 == 1.5);
+    assert!(response3 == "12");
+
+    Ok(())
+}
+
+#[test]
+fn test_coerce_to_shorthands_wire_format() -> Result<()> {
+    assert_eq!(
+        r.expr("1").to_number().to_wire_json()?,
+        r.expr("1").coerce_to("number").to_wire_json()?
+    );
+    assert_eq!(
+        r.range(2).to_array().to_wire_json()?,
+        json!([51, [[173, [2]], "array"]])
+    );
+    assert_eq!(
+        r.expr(1).to_string_().to_wire_json()?[1][1],
+        json!("string")
+    );
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use neor::{r, Converter, Result};
+use neor::{args, r, Converter, Result};
 
 #[tokio::test]
 async fn test_contains_ops() -> Result<()> {
@@ -15,3 +15,18 @@ async fn test_contains_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_contains_top_level() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: bool = r
+        .contains(["red", "green", "blue"], args!(["red", "blue"]))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use common::{set_up, tear_down, Post};
-use neor::{Converter, Result};
+use neor::{r, Converter, Result};
 
 mod common;
 
@@ -13,3 +13,19 @@ async fn test_count_data() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_count_top_level() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let count: usize = r.count([1, 2, 3], ()).run(&conn).await?.unwrap().parse()?;
+    let sum: u8 = r.sum([1, 2, 3], ()).run(&conn).await?.unwrap().parse()?;
+    let avg: f64 = r.avg([1, 2, 3], ()).run(&conn).await?.unwrap().parse()?;
+    let max: u8 = r.max([1, 2, 3], ()).run(&conn).await?.unwrap().parse()?;
+
+    assert!(count == 3);
+    assert!(sum == 6);
+    assert!(avg == 2.);
+    assert!(max == 3);
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use futures::TryStreamExt;
+use neor::arguments::{ReadMode, RunOption};
+use neor::{args, r, Result};
+use serde_json::Value;
+
+use common::{set_up, tear_down, Post};
+
+mod common;
+
+#[tokio::test]
+async fn test_cursor_ops() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let mut posts: Vec<Post> = table.cursor(&conn)?.try_collect().await?;
+    posts.sort_by_key(|post| post.id);
+
+    assert!(posts == Post::get_many_data());
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_cursor_aggregations() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let options = RunOption::default().max_batch_rows(2);
+    let count = table
+        .cursor::<Value>(args!(&conn, options))?
+        .count()
+        .await?;
+    let views = table.cursor::<Value>(&conn)?.sum_field("view").await?;
+    let max_view = table
+        .cursor::<Post>(&conn)?
+        .fold_stream(0, |max, post| max.max(post.view))
+        .await?;
+
+    assert!(count == 5);
+    assert!(views == 14.);
+    assert!(max_view == 10);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_cursor_single_document() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let count = r.expr(1).cursor::<u8>(&conn)?.count().await?;
+
+    assert!(count == 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cursor_read_mode() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let options = RunOption::default().read_mode(ReadMode::Majority);
+    let outdated = table.outdated().cursor::<Value>(&conn)?;
+    let majority = table.outdated().cursor::<Value>(args!(&conn, options))?;
+    let default = table.cursor::<Value>(&conn)?;
+
+    assert_eq!(outdated.read_mode(), Some(ReadMode::Outdated));
+    assert_eq!(majority.read_mode(), Some(ReadMode::Majority));
+    assert_eq!(default.read_mode(), None);
+    assert_eq!(outdated.count().await?, Post::get_many_data().len());
+
+    tear_down(conn, &table_name).await
+}
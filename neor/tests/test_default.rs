@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
-use neor::{func, r, Converter, Result};
+use neor::arguments::DefaultOption;
+use neor::{args, func, r, Converter, Result};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 use common::{set_up, tear_down, Post};
 
@@ -44,3 +46,45 @@ async fn test_default_ops() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_default_error_modes() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let missing = r.expr(json!({})).g("author");
+
+    let response: String = missing
+        .default(func!(|error| r.branch(error.eq(()), args!("null", error))))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.contains("author"));
+
+    let response: Option<String> = missing
+        .default(DefaultOption::default().error_to_null(true))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.is_none());
+
+    let response: Option<String> = r
+        .expr(json!({ "author": null }))
+        .g("author")
+        .default(DefaultOption::default())
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.is_none());
+    assert!(missing
+        .default(DefaultOption::default().error_to_null(false))
+        .run(&conn)
+        .await
+        .is_err());
+
+    Ok(())
+}
@@ -19,3 +19,34 @@ async fn test_distance_data() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_distance_unit_convert() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let point1 = r.point(-122.423246, 37.779388);
+    let point2 = r.point(-117.220406, 32.719464);
+
+    let meters: f64 = point1
+        .clone()
+        .distance(point2.clone())
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let miles: f64 = r
+        .distance(
+            point2,
+            args!(
+                point1,
+                DistanceOption::default().unit(Unit::InternationalMile)
+            ),
+        )
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!((Unit::Meter.convert(meters, Unit::InternationalMile) - miles).abs() < 1e-6);
+
+    Ok(())
+}
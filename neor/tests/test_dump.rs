@@ -0,0 +1,38 @@
+use futures::io::{BufReader, Cursor};
+use neor::dump::{Export, Import};
+use neor::{r, Converter, Result};
+use uuid::Uuid;
+
+use common::{set_up, tear_down, Post};
+
+mod common;
+
+#[tokio::test]
+async fn test_dump_export_import() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, _, table_name) = set_up(true).await?;
+    let db_name = Uuid::new_v4().simple().to_string();
+    let mut dump = Vec::new();
+
+    let exported = Export::table("test", table_name.as_str())
+        .write(&conn, &mut dump)
+        .await?;
+    let imported = Import::new()
+        .db(db_name.as_str())
+        .batch_size(2)
+        .read(&conn, BufReader::new(Cursor::new(dump)))
+        .await?;
+
+    let restored = r.db(db_name.as_str()).table(table_name.as_str());
+    let posts: Vec<Post> = restored.order_by("id").run(&conn).await?.unwrap().parse()?;
+    let indexes: Vec<String> = restored.index_list().run(&conn).await?.unwrap().parse()?;
+
+    assert_eq!(exported.tables, 1);
+    assert_eq!(exported.documents, data.len());
+    assert_eq!(exported, imported);
+    assert_eq!(posts, data);
+    assert_eq!(indexes, vec!["title".to_owned()]);
+
+    r.db_drop(db_name.as_str()).run(&conn).await?;
+    tear_down(conn, &table_name).await
+}
@@ -1,5 +1,6 @@
 use neor::{args, r, Converter, Result};
-use time::macros::{date, offset};
+use serde_json::json;
+use time::macros::{date, datetime, offset};
 
 #[tokio::test]
 async fn test_during_ops() -> Result<()> {
@@ -23,3 +24,64 @@ async fn test_during_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_during_range() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let (start, end) = (
+        datetime!(2022-08-01 0:00 UTC),
+        datetime!(2022-09-01 0:00 UTC),
+    );
+    let datetime = r.epoch_time(1661990400)?.cmd();
+
+    let exclusive: bool = datetime
+        .during(start..end)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let inclusive: bool = datetime
+        .during(start..=end)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let unbounded: bool = datetime
+        .during(start..)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(!exclusive && inclusive && unbounded);
+
+    Ok(())
+}
+
+#[test]
+fn test_during_range_wire_format() -> Result<()> {
+    let (start, end) = (
+        datetime!(2013-12-01 0:00 UTC),
+        datetime!(2014-01-01 0:00 UTC),
+    );
+
+    assert_eq!(
+        r.now().cmd().during(start..=end).to_wire_json()?[2],
+        json!({"left_bound": "closed", "right_bound": "closed"})
+    );
+
+    let from = r.now().cmd().during(start..).to_wire_json()?;
+
+    assert_eq!(from[1][2], r::max_val().to_wire_json()?);
+    assert_eq!(from[2], json!({"left_bound": "closed"}));
+
+    let until_max = r
+        .now()
+        .cmd()
+        .during(args!(datetime!(2013-12-01 0:00 UTC), r::max_val()))
+        .to_wire_json()?;
+
+    assert_eq!(until_max[1][2], r::max_val().to_wire_json()?);
+
+    Ok(())
+}
@@ -35,3 +35,23 @@ pub async fn test_eq_join_ops() -> Result<()> {
 
     Comment::own_tear_down(conn, comment_tablename, post_tablename).await
 }
+
+#[tokio::test]
+pub async fn test_eq_join_run_joined() -> Result<()> {
+    let (conn, comment_table, post_table, comment_tablename, post_tablename) =
+        Comment::own_set_up().await?;
+
+    let response = comment_table
+        .eq_join(args!("post_id", post_table))
+        .run_joined::<Comment, Post>(&conn)
+        .await?;
+
+    assert!(response.len() == Comment::get_many_data().len());
+    assert!(response.iter().all(|pair| {
+        let (comment, post) = (pair.left.as_ref().unwrap(), pair.right.as_ref().unwrap());
+
+        comment.post_id == post.id
+    }));
+
+    Comment::own_tear_down(conn, comment_tablename, post_tablename).await
+}
@@ -1,5 +1,6 @@
-use neor::err::{ReqlError, ReqlRuntimeError};
+use neor::err::{Frame, ReqlError, ReqlRuntimeError, UserError};
 use neor::{r, Result};
+use serde_json::{json, Value};
 
 #[tokio::test]
 async fn test_error_ops() -> Result<()> {
@@ -19,3 +20,45 @@ async fn test_error_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_user_error_payload() -> Result<()> {
+    let payload = json!({"field": "age", "reason": "must be positive"});
+    let conn = r.connection().connect().await?;
+    let err = r
+        .error(UserError::new(payload.clone()))
+        .run(&conn)
+        .await
+        .err()
+        .unwrap();
+
+    assert_eq!(err.user_error::<Value>(), Some(UserError::new(payload)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_error_backtrace() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let err = (r.expr(1) + (r.expr(2) + "a"))
+        .run(&conn)
+        .await
+        .err()
+        .unwrap();
+
+    assert!(matches!(
+        err,
+        ReqlError::Runtime(ReqlRuntimeError::QueryLogic(_))
+    ));
+    assert!(err.backtrace() == Some(&[Frame::Pos(1)][..]));
+    assert!(!err.to_string().contains("r.expr"));
+    assert_eq!(
+        err.query(),
+        Some(concat!(
+            "r.expr(1).add(r.expr(2).add(\"a\"))\n",
+            "              ^^^^^^^^^^^^^^^^^^",
+        ))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use neor::{r, var_counter, Command, Func, Result};
+
+#[test]
+fn test_estimate_wire_size() -> Result<()> {
+    let id = var_counter();
+    let query = r
+        .table("posts")
+        .filter(Func::new(vec![id], Command::var(id).g("views").gt(10)));
+    let json = query.to_wire_json()?.to_string();
+
+    assert_eq!(query.estimate_wire_size()?, json.len());
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use neor::types::FieldPath;
+use neor::{path, r, Converter, Result};
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_field_path() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let first = path!("author.name.first");
+    let docs = r.expr(json!([
+        {"id": 1, "author": {"name": {"first": "Zoe", "last": "Doe"}, "age": 30}},
+        {"id": 2, "author": {"name": {"first": "Ada", "last": "Lee"}, "age": 40}},
+        {"id": 3, "author": {"age": 50}}
+    ]));
+
+    let plucked: Vec<Value> = docs
+        .filter(first.func())
+        .order_by(first.func())
+        .pluck(vec![first.clone(), FieldPath::new("id")])
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let without: Value = docs
+        .nth(0)
+        .without(path!("author.name"))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let with_name: usize = docs
+        .has_fields(first.clone())
+        .count(())
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let name: String = first.get(docs.nth(1)).run(&conn).await?.unwrap().parse()?;
+
+    assert_eq!(
+        plucked,
+        vec![
+            json!({"id": 2, "author": {"name": {"first": "Ada"}}}),
+            json!({"id": 1, "author": {"name": {"first": "Zoe"}}})
+        ]
+    );
+    assert_eq!(without, json!({"id": 1, "author": {"age": 30}}));
+    assert_eq!(with_name, 2);
+    assert_eq!(name, "Ada");
+
+    Ok(())
+}
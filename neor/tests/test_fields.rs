@@ -0,0 +1,50 @@
+use neor::Fields;
+use serde::{Deserialize, Serialize};
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Fields)]
+#[serde(rename_all = "camelCase")]
+struct User {
+    id: String,
+    email_address: String,
+    #[serde(rename = "fullName")]
+    name: String,
+    #[serde(skip)]
+    password: Option<String>,
+}
+
+#[test]
+fn test_fields_naming_convention() {
+    let fields = User::fields();
+
+    assert_eq!(fields.id(), "id");
+    assert_eq!(fields.email_address(), "emailAddress");
+    assert_eq!(fields.name(), "fullName");
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Fields)]
+struct Profile {
+    #[serde(rename = "displayName")]
+    name: String,
+    user: User,
+}
+
+#[test]
+fn test_fields_selector_renamed() -> neor::Result<()> {
+    let selector = neor::sel!(Profile { name, user { email_address, name } });
+
+    assert_eq!(
+        selector.to_wire_json()?,
+        serde_json::json!([
+            143,
+            [
+                "displayName",
+                true,
+                "user",
+                [143, ["emailAddress", true, "fullName", true]]
+            ]
+        ])
+    );
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use neor::{args, func, r, Converter, Result};
+use neor::arguments::FoldOption;
+use neor::{args, func, r, Command, Converter, Func, Result};
 
 use common::*;
 
@@ -29,3 +30,40 @@ async fn test_fold_ops() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_fold_emit() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let opts = FoldOption::default()
+        .emit(func!(|_old_acc, _row, acc| r.array([acc])))
+        .final_emit(func!(|acc| r.array([acc * 10])));
+    let response: Vec<u8> = r
+        .expr([1, 2, 3])
+        .fold(0, args!(func!(|acc, row| acc + row), opts))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == [1, 3, 6, 60]);
+
+    Ok(())
+}
+
+#[test]
+fn test_fold_final_emit_wire_format() -> Result<()> {
+    let func = || Func::new(vec![1], Command::var(1));
+    let query = r.expr([1, 2]).fold(0, func());
+
+    assert_eq!(query.to_wire_json()?.get(2), None);
+
+    let opts = FoldOption::default().final_emit(func());
+    let wire = r.expr([1, 2]).fold(0, args!(func(), opts)).to_wire_json()?;
+
+    assert_eq!(
+        wire[2],
+        serde_json::json!({"final_emit": [69, [[2, [2]], [10, [2]]]]})
+    );
+
+    Ok(())
+}
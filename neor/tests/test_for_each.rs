@@ -1,5 +1,6 @@
 use neor::types::MutationResponse;
-use neor::{func, Converter, Result};
+use neor::{func, r, Converter, Result};
+use serde_json::json;
 
 use common::{set_up, tear_down};
 
@@ -19,3 +20,33 @@ async fn test_for_each_opts() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_for_each_many_writes() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let mut response: MutationResponse = table
+        .for_each(func!(|doc| r.array([
+            table.get(doc.g("id")).update(json!({"view": 0})),
+            table.get(doc.g("id")).delete(()),
+        ])))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let empty: MutationResponse = table
+        .for_each(func!(|doc| table.get(doc.g("id")).delete(())))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.replaced + response.unchanged == 5);
+    assert!(response.deleted == 5);
+    assert!(empty.deleted == 0);
+
+    response.merge(empty);
+
+    assert!(response.deleted == 5);
+
+    tear_down(conn, &table_name).await
+}
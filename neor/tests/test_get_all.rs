@@ -1,5 +1,6 @@
 use neor::arguments::GetAllOption;
-use neor::{args, Converter, Result};
+use neor::types::IndexKey;
+use neor::{args, func, r, Command, Converter, IntoTerm, Result};
 
 use common::{set_up, tear_down, Post};
 
@@ -25,3 +26,83 @@ async fn test_get_all() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_get_all_args() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    let keys: Vec<Command> = (1..3).map(|id| r.expr(id) * 2 - 1).collect();
+
+    let mut data_get: Vec<Post> = table
+        .get_all(r.args(keys))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    data_get.sort_by_key(|post| post.id);
+
+    assert!(data_get.len() == 2);
+    assert!(data_get.first() == data.first());
+    assert!(data_get.last() == data.get(2));
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_get_all_compound_keys() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    table
+        .index_create(args!(
+            "title_view",
+            func!(|post| r.array([post.g("title"), post.g("view")]))
+        ))
+        .run(&conn)
+        .await?;
+    table.index_wait(()).run(&conn).await?;
+
+    let mut data_get: Vec<Post> = table
+        .get_all(args!(
+            [("title1", 10), ("title4", 0)],
+            GetAllOption::default().index("title_view")
+        ))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    data_get.sort_by_key(|post| post.id);
+
+    assert!(data_get.len() == 2);
+    assert!(data_get.first() == data.first());
+    assert!(data_get.last() == data.last());
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_get_all_mixed_keys() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    let keys = [IndexKey::from(2), IndexKey::from("title3")];
+
+    let data_get: Vec<Post> = table.get_all(keys).run(&conn).await?.unwrap().parse()?;
+
+    assert!(data_get.len() == 1);
+    assert!(data_get.first() == data.get(1));
+
+    tear_down(conn, &table_name).await
+}
+
+#[test]
+fn test_get_all_min_val_key() -> Result<()> {
+    let query = r
+        .table("posts")
+        .get_all(r.args([("Smith", r::min_val()).into_term()]));
+
+    assert_eq!(
+        query.to_wire_json()?[1][1][1][0][1][0],
+        serde_json::json!([2, ["Smith", r::min_val().to_wire_json()?]])
+    );
+
+    Ok(())
+}
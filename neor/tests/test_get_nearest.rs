@@ -1,5 +1,6 @@
 use neor::arguments::IndexCreateOption;
-use neor::types::{ClosestDocumentResponse, Point};
+use neor::arguments::{GetNearestOption, Unit};
+use neor::types::{NearestResponse, Point};
 use neor::{args, r, Converter, Result};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -36,14 +37,26 @@ async fn test_get_nearest_ops() -> Result<()> {
     table.insert(&data).run(&conn).await?;
 
     let secret_base = r.point(-122.422876, 37.777128);
-    let response: Vec<ClosestDocumentResponse<Park>> = table
-        .get_nearest(args!(secret_base, "area"))
+    let response: Vec<NearestResponse<Park>> = table
+        .get_nearest(args!(secret_base.clone(), "area"))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let opts = GetNearestOption::default()
+        .max_dist(1.5)
+        .unit(Unit::Kilometer);
+    let nearby: Vec<NearestResponse<Park>> = table
+        .get_nearest(args!(secret_base, "area", opts))
         .run(&conn)
         .await?
         .unwrap()
         .parse()?;
 
-    assert!(response.len() > 0);
+    assert!(response.len() == 3);
+    assert!(response[0].doc.id == 3 && response[0].dist == 0.);
+    assert!(nearby.len() == 2);
+    assert!(nearby.iter().all(|park| park.dist <= 1.5));
 
     r.table_drop(table_name.as_str()).run(&conn).await?;
     Ok(())
@@ -1,5 +1,7 @@
-use neor::types::GroupedStream;
-use neor::{Converter, Result};
+use neor::arguments::GroupOption;
+use neor::types::{GroupedData, GroupedStream};
+use neor::{r, Converter, Result};
+use serde_json::json;
 
 use common::{set_up, tear_down, Post};
 
@@ -17,3 +19,74 @@ async fn test_group_data() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_group_into_map() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let response: GroupedStream<String, Post> =
+        table.group("title").run(&conn).await?.unwrap().parse()?;
+
+    let response = response.into_btree_map();
+
+    assert!(response["title4"].len() == 2);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_group_reduction() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let response: GroupedData<(String, u8), usize> = table
+        .group(["title", "view"])
+        .count(())
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    let response = response.into_hash_map();
+
+    assert!(response.len() == 5);
+    assert!(response[&("title4".to_owned(), 2)] == 1);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_group_by_index_and_commands() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let by_index: GroupedStream<String, Post> = table
+        .group(GroupOption::default().index("title"))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let by_fields: GroupedStream<(String, u8), Post> = table
+        .group([r.expr("title"), r.expr("view")])
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(by_index.collect().len() == 4);
+    assert!(by_fields.collect().len() == 5);
+
+    tear_down(conn, &table_name).await
+}
+
+#[test]
+fn test_group_wire_format() -> Result<()> {
+    let opts = GroupOption::default().index("author");
+    let fields = [r.expr("author"), r.expr("year")];
+
+    assert_eq!(
+        r.table("posts").group(opts).to_wire_json()?,
+        json!([144, [[15, ["posts"], {}]], {"index": "author"}])
+    );
+    assert_eq!(
+        r.expr([1]).group(fields).to_wire_json()?,
+        json!([144, [[2, [1]], "author", "year"], {}])
+    );
+
+    Ok(())
+}
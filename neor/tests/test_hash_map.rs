@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use neor::{r, Converter, Result};
+use neor::{args, r, Converter, Result};
+use serde_json::json;
 
 use common::Post;
 
@@ -23,3 +24,40 @@ async fn test_hash_map_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_hash_map_document() -> Result<()> {
+    let mut expected_post = Post::get_one_data();
+    let conn = r.connection().connect().await?;
+
+    expected_post.view = 3;
+
+    let response: Post = r
+        .hash_map(args!(Post::get_one_data(), [("view", r.expr(1) + 2)]))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert_eq!(response, expected_post);
+
+    Ok(())
+}
+
+#[test]
+fn test_hash_map_document_wire_format() -> Result<()> {
+    let overrides = BTreeMap::from([("title", r.expr("b").upcase()), ("views", r.expr(2))]);
+    let query = r.hash_map(args!(json!({"id": 1, "title": "a"}), overrides));
+
+    assert_eq!(
+        query.to_wire_json()?,
+        json!({"id": 1, "title": [141, ["b"]], "views": 2})
+    );
+    assert!(r.hash_map(args!(1, [("a", 1)])).to_wire_json().is_err());
+    assert_eq!(
+        r.hash_map([(r.expr("a") + "b", 1)]).to_wire_json()?,
+        json!([143, [[24, ["a", "b"]], 1]])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use futures::TryStreamExt;
+use neor::hub::FeedHub;
+use neor::types::FeedItem;
+use neor::{r, Result};
+
+use common::{set_up, tear_down, Post};
+
+mod common;
+
+#[tokio::test]
+async fn test_hub_point_feeds() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let writer = r.connection().connect().await?;
+    let hub = FeedHub::new(conn.clone())?.buffer(8);
+    let mut first = hub.subscribe::<Post>(&table.get(1).changes(()))?;
+    let mut second = hub.subscribe::<Post>(&table.get(2).changes(()))?;
+
+    // the session still runs other queries
+    let count = table.count(()).run(&conn).await?;
+    assert_eq!(count, Some(5.into()));
+
+    table
+        .get(2)
+        .update(Post::new(2, "title2", None, 3))
+        .run(&writer)
+        .await?;
+    table
+        .get(1)
+        .update(Post::new(1, "title1", None, 7))
+        .run(&writer)
+        .await?;
+
+    let (first_change, second_change) = tokio::time::timeout(Duration::from_secs(5), async {
+        Result::Ok((first.try_next().await?, second.try_next().await?))
+    })
+    .await
+    .unwrap()?;
+
+    assert!(matches!(
+        first_change,
+        Some(FeedItem::Change { new: Some(post), .. }) if post.view == 7
+    ));
+    assert!(matches!(
+        second_change,
+        Some(FeedItem::Change { new: Some(post), .. }) if post.view == 3
+    ));
+
+    first.close().await;
+    table
+        .get(2)
+        .update(Post::new(2, "title2", None, 4))
+        .run(&writer)
+        .await?;
+
+    let change = tokio::time::timeout(Duration::from_secs(5), second.try_next())
+        .await
+        .unwrap()?;
+
+    assert!(matches!(
+        change,
+        Some(FeedItem::Change { new: Some(post), .. }) if post.view == 4
+    ));
+
+    drop(second);
+    drop(hub);
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_hub_not_a_changefeed() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let hub = FeedHub::new(conn)?;
+
+    assert!(hub.subscribe::<Post>(&r.table("posts").get(1)).is_err());
+
+    Ok(())
+}
@@ -59,3 +59,18 @@ async fn setup(table_name: &str, index_created: Command, conn: &Session) -> Resu
     r.table_drop(table_name).run(conn).await?;
     Ok(())
 }
+
+#[test]
+fn test_index_create_wire_format() -> Result<()> {
+    let opts = IndexCreateOption::default().geo(true);
+    let index = r.expr("location").downcase();
+    let query = r
+        .table("places")
+        .index_create(args!("location", index, opts));
+    let wire = query.to_wire_json()?;
+
+    assert_eq!(wire[1][2], serde_json::json!([142, ["location"]]));
+    assert_eq!(wire[2], serde_json::json!({"geo": true}));
+
+    Ok(())
+}
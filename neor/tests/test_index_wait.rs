@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use neor::arguments::{RunOption, WaitOption};
+use neor::err::{ReqlDriverError, ReqlError};
 use neor::types::IndexStatusResponse;
 use neor::{args, Command, Converter, Result, Session};
 
@@ -61,6 +65,35 @@ async fn test_get_index_status_with_params() -> Result<()> {
     tear_down(conn, &table_name).await
 }
 
+#[tokio::test]
+async fn test_index_wait_timeout() -> Result<()> {
+    let (conn, table, table_name) = set_up(false).await?;
+    generate_data(&conn, &table).await?;
+
+    let wait_option = WaitOption::default().timeout(30.);
+    let indexes_waited: Vec<IndexStatusResponse> = table
+        .index_wait(wait_option)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let run_option = RunOption::default().timeout(Duration::from_nanos(1));
+    let err = table
+        .index_wait("author")
+        .run(args!(&conn, run_option))
+        .await
+        .err()
+        .unwrap();
+
+    assert!(indexes_waited.len() == 3);
+    assert!(matches!(
+        err,
+        ReqlError::Driver(ReqlDriverError::Timeout(_))
+    ));
+
+    tear_down(conn, &table_name).await
+}
+
 async fn generate_data(conn: &Session, table: &Command) -> Result<()> {
     table.index_create("author").run(conn).await?;
     table.index_create("name").run(conn).await?;
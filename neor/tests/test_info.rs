@@ -1,5 +1,5 @@
 use neor::types::{InfoResponse, TypeOf};
-use neor::{Converter, Result};
+use neor::{r, Converter, Result};
 
 use common::{set_up, tear_down};
 
@@ -11,6 +11,19 @@ async fn test_info_table() -> Result<()> {
     let response: InfoResponse = table.info().run(&conn).await?.unwrap().parse()?;
 
     assert!(response.typ == TypeOf::Table);
+    assert!(response.name.as_deref() == Some(table_name.as_str()));
+    assert!(response.primary_key.as_deref() == Some("id"));
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_info_value() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: InfoResponse = r.info(r.expr("neor")).run(&conn).await?.unwrap().parse()?;
+
+    assert!(response.typ == TypeOf::String);
+    assert!(response.db.is_none());
+
+    Ok(())
+}
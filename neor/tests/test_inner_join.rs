@@ -38,3 +38,24 @@ pub async fn test_inner_join_ops() -> Result<()> {
 
     Comment::own_tear_down(conn, comment_tablename, post_tablename).await
 }
+
+#[tokio::test]
+pub async fn test_inner_join_captured_fields() -> Result<()> {
+    let (conn, comment_table, post_table, comment_tablename, post_tablename) =
+        Comment::own_set_up().await?;
+    let (left_field, right_field) = ("post_id".to_owned(), "id".to_owned());
+    let predicate = func!(move |comment, post| comment
+        .g(left_field.as_str())
+        .eq(post.g(right_field.as_str())));
+
+    let response: Vec<JoinResponse<Comment, Post>> = comment_table
+        .inner_join(post_table, predicate)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.len() == Comment::get_many_data().len());
+
+    Comment::own_tear_down(conn, comment_tablename, post_tablename).await
+}
@@ -1,6 +1,7 @@
-use neor::arguments::{InsertOption, ReturnChanges};
+use neor::arguments::{BatchOption, Conflict, InsertOption, ReturnChanges};
 use neor::types::MutationResponse;
-use neor::{args, r, Converter, Result};
+use neor::{args, func, r, Command, Converter, Func, Result};
+use serde_json::json;
 use uuid::Uuid;
 
 use common::{set_up, tear_down, Post};
@@ -78,3 +79,67 @@ async fn test_insert_data_with_opts() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_insert_conflict_function() -> Result<()> {
+    let data = Post::get_one_data();
+    let (conn, table, table_name) = set_up(false).await?;
+    let conflict = func!(|_id, old_doc, new_doc| {
+        new_doc.merge(r.hash_map([("view", old_doc.g("view") + new_doc.g("view"))]))
+    });
+
+    table.insert(&data).run(&conn).await?;
+    let response: MutationResponse = table
+        .insert(args!(&data, InsertOption::default(), conflict))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let post: Post = table.get(data.id).run(&conn).await?.unwrap().parse()?;
+
+    assert!(response.replaced == 1);
+    assert!(post.view == data.view * 2);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_insert_batched() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(false).await?;
+    let options = BatchOption::default().chunk_size(2).parallelism(2);
+    let response = table.insert_batched(&data, options, &conn).await?;
+
+    assert!(response.mutation.inserted == data.len());
+    assert!(response.errors.is_empty());
+
+    let response = table.insert_batched(&data, options, &conn).await?;
+
+    assert!(response.mutation.errors == data.len());
+    assert!(response.errors.len() == 3);
+    assert!(response.errors[2].documents == (4..5));
+
+    tear_down(conn, &table_name).await
+}
+
+#[test]
+fn test_insert_conflict_wire_format() -> Result<()> {
+    let options = InsertOption::default().conflict(Conflict::Update);
+    let resolver = Func::new(vec![1, 2, 3], Command::var(3));
+    let users = r.table("users");
+
+    assert_eq!(
+        users
+            .insert(args!(json!({"id": 1}), options))
+            .to_wire_json()?[2],
+        json!({"conflict": "update"})
+    );
+    assert_eq!(
+        users
+            .insert(args!(json!({"id": 1}), options, resolver))
+            .to_wire_json()?[2],
+        json!({"conflict": [69, [[2, [1, 2, 3]], [10, [3]]]]})
+    );
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use neor::{r, Converter, Result};
+use serde_json::{json, Value};
 
 #[tokio::test]
 async fn test_json_table() -> Result<()> {
@@ -10,3 +11,15 @@ async fn test_json_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_json_from_command() -> Result<()> {
+    let data = json!({"name": "Moussa"});
+    let conn = r.connection().connect().await?;
+    let json_string = r.expr(&data).to_json();
+    let response: Value = r.json(json_string).run(&conn).await?.unwrap().parse()?;
+
+    assert!(response == data);
+
+    Ok(())
+}
@@ -20,3 +20,21 @@ async fn test_limit_data() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_limit_subquery() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    let response: Vec<Post> = table
+        .order_by(r.index("id"))
+        .skip(table.get(4).g("view") - 1)
+        .limit(table.get(2).g("view"))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == data[1..3]);
+
+    tear_down(conn, &table_name).await
+}
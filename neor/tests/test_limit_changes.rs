@@ -0,0 +1,42 @@
+use neor::arguments::{ReturnChanges, UpdateOption};
+use neor::types::MutationResponse;
+use neor::{args, r, Converter, Result};
+use serde_json::{json, Value};
+
+use common::*;
+
+mod common;
+
+#[tokio::test]
+async fn test_limit_changes() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    let update_option = UpdateOption::default().return_changes(ReturnChanges::Bool(true));
+    let response: MutationResponse = table
+        .update(args!(json!({"view": 99}), update_option))
+        .limit_changes(2)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.changes.as_ref().unwrap().len() == 2);
+    assert!(response.changed_keys.as_ref().unwrap().len() == data.len());
+
+    let mut posts = Vec::new();
+
+    for keys in response.changed_keys_pages(2) {
+        let page: Vec<Value> = table
+            .get_all(r.args(keys))
+            .run(&conn)
+            .await?
+            .unwrap()
+            .parse()?;
+
+        posts.extend(page);
+    }
+
+    assert!(posts.len() == data.len());
+
+    tear_down(conn, &table_name).await
+}
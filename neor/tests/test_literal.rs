@@ -1,15 +1,13 @@
-// FIX Not working
 use std::collections::HashMap;
 
-use neor::{r, Result};
-use serde_json::json;
+use neor::{r, Converter, Result};
+use serde_json::{json, Value};
 
 use common::{set_up, tear_down};
 
 mod common;
 
 #[tokio::test]
-#[ignore = "not work"]
 async fn test_literal_ops() -> Result<()> {
     let data = json!([
         {
@@ -41,14 +39,15 @@ async fn test_literal_ops() -> Result<()> {
 
     let (conn, table, table_name) = set_up(false).await?;
     table.insert(data).run(&conn).await?;
-    let response = r
-        .table(&table_name)
+    r.table(&table_name)
         .get(1)
         .update(r.hash_map(dt))
         .run(&conn)
         .await?;
+    let response: Value = table.get(1).g("data").run(&conn).await?.unwrap().parse()?;
 
-    dbg!(&response);
+    assert!(response == json!({"age": 19, "job": "Engineer"}));
+    assert!(table.insert(r.literal(json!({}))).run(&conn).await.is_err());
 
     tear_down(conn, &table_name).await
 }
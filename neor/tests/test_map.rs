@@ -1,4 +1,5 @@
 use neor::{func, r, Converter, Result};
+use serde_json::{json, Value};
 
 #[tokio::test]
 async fn test_map_ops() -> Result<()> {
@@ -15,3 +16,38 @@ async fn test_map_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_map_capturing_outer_func() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let offset = r.expr(10);
+    let response: Vec<Vec<u8>> = r
+        .expr([1, 2])
+        .map(func!(|row| r
+            .expr([3, 4])
+            .map(func!(move |val| val * row.clone() + offset.clone()))))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == vec![vec![13, 14], vec![16, 18]]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_map_with_index() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: Vec<Value> = r
+        .expr(json!(["a", 2, {"b": 3}]))
+        .map_with_index(func!(|val, index| r.array([index, val])))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == vec![json!([0, "a"]), json!([1, 2]), json!([2, {"b": 3}])]);
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use neor::cmd::match_type::TypeMatch;
+use neor::types::TypeOf;
+use neor::{func, r, Converter, Result};
+use serde_json::json;
+
+fn price_cases() -> TypeMatch {
+    TypeMatch::new()
+        .case(TypeOf::Number, |price| price)
+        .case(TypeOf::String, |price| price.coerce_to("number"))
+        .case(TypeOf::Array, |prices| prices.nth(0))
+        .otherwise(|_| r.expr(0))
+}
+
+#[tokio::test]
+async fn test_match_type_ops() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let prices: Vec<f64> = r
+        .expr(json!([10, "2.5", [4, 5], null, {"amount": 1}]))
+        .map(func!(|price| price.match_type(price_cases())))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert_eq!(prices, vec![10., 2.5, 4., 0., 0.]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_match_type_without_default() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response = r
+        .expr(true)
+        .match_type(TypeMatch::new().case(TypeOf::Number, |number| number))
+        .run(&conn)
+        .await;
+
+    assert!(response.is_err());
+
+    Ok(())
+}
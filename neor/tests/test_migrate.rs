@@ -0,0 +1,148 @@
+use neor::migrate::{AppliedMigration, Migration, Migrator, MIGRATIONS_TABLE};
+use neor::{func, r, Converter, Result};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_migrate() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let db_name = Uuid::new_v4().simple().to_string();
+    let migrator = Migrator::new(db_name.as_str())
+        .migration(
+            Migration::new(2, "rename title").query(
+                r.table("posts")
+                    .update(func!(|post| r.object([r.expr("name"), post.g("title")]))),
+            ),
+        )
+        .migration(
+            Migration::new(1, "create posts")
+                .table_create("posts")
+                .index_create("posts", "title")
+                .query(r.table("posts").insert(r.object(["title", "hello"]))),
+        );
+
+    // a table created by an earlier, failed run of the migration
+    r.db_create(db_name.as_str()).run(&conn).await?;
+    r.db(db_name.as_str())
+        .table_create("posts")
+        .run(&conn)
+        .await?;
+
+    let applied = migrator.run(&conn).await?;
+    let applied_again = migrator.run(&conn).await?;
+    let history: Vec<AppliedMigration> = migrator.applied(&conn).await?;
+    let names: Vec<String> = r
+        .db(db_name.as_str())
+        .table("posts")
+        .g("name")
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let indexes: Vec<String> = r
+        .db(db_name.as_str())
+        .table("posts")
+        .index_list()
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert_eq!(applied, vec![1, 2]);
+    assert!(applied_again.is_empty());
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].name, "rename title");
+    assert_eq!(names, vec!["hello".to_owned()]);
+    assert_eq!(indexes, vec!["title".to_owned()]);
+
+    r.db_drop(db_name.as_str()).run(&conn).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_duplicate_version() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let result = Migrator::new("test")
+        .migration(Migration::new(1, "first"))
+        .migration(Migration::new(1, "second"))
+        .run(&conn)
+        .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_prefixed_session() -> Result<()> {
+    let conn = r.connection().table_prefix("staging_").connect().await?;
+    let db_name = Uuid::new_v4().simple().to_string();
+    let migrator = Migrator::new(db_name.as_str())
+        .migration(Migration::new(1, "create posts").table_create("posts"));
+
+    // a table created by an earlier, failed run of the migration
+    r.db_create(db_name.as_str()).run(&conn).await?;
+    r.db(db_name.as_str())
+        .table_create("posts")
+        .run(&conn)
+        .await?;
+
+    let applied = migrator.run(&conn).await?;
+    let applied_again = migrator.run(&conn).await?;
+    let history = migrator.applied(&conn).await?;
+    let tables: Vec<String> = r
+        .db(db_name.as_str())
+        .table_list()
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert_eq!(applied, vec![1]);
+    assert!(applied_again.is_empty());
+    assert_eq!(history.len(), 1);
+    assert_eq!(
+        tables,
+        vec![MIGRATIONS_TABLE.to_owned(), "staging_posts".to_owned()]
+    );
+
+    r.db_drop(db_name.as_str()).run(&conn).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_after_failed_run() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let db_name = Uuid::new_v4().simple().to_string();
+    let migrator = Migrator::new(db_name.as_str()).migration(
+        Migration::new(1, "copy authors")
+            .table_create("posts")
+            .index_create("posts", "author")
+            .query(r.table("posts").insert(r.table("authors"))),
+    );
+
+    // the first run fails once the index is created
+    let failed = migrator.run(&conn).await;
+    r.db(db_name.as_str())
+        .table_create("authors")
+        .run(&conn)
+        .await?;
+    let applied = migrator.run(&conn).await?;
+    let indexes: Vec<String> = r
+        .db(db_name.as_str())
+        .table("posts")
+        .index_list()
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(failed.is_err());
+    assert_eq!(applied, vec![1]);
+    assert_eq!(indexes, vec!["author".to_owned()]);
+
+    r.db_drop(db_name.as_str()).run(&conn).await?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use neor::arguments::GetAllOption;
+use neor::{args, r, Result};
+
+use common::{set_up, tear_down, Post};
+
+mod common;
+
+#[tokio::test]
+async fn test_mirror_get_all() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let writer = r.connection().connect().await?;
+    let get_all_option = GetAllOption::default().index("title");
+    let mut posts = table
+        .get_all(args!(["title4"], get_all_option))
+        .mirror::<u8, Post>(&conn)?;
+
+    posts.wait_ready().await?;
+
+    assert_eq!(posts.len(), 2);
+    assert_eq!(
+        posts.get(&4),
+        Some(&Post::new(4, "title4", Some("content4"), 2))
+    );
+
+    table
+        .insert(Post::new(6, "title4", None, 1))
+        .run(&writer)
+        .await?;
+    table.get(5).delete(()).run(&writer).await?;
+    table
+        .get(4)
+        .update(r.object([r.expr("view"), r.expr(3)]))
+        .run(&writer)
+        .await?;
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while posts.get(&5).is_some() || posts.get(&4).map(|post| post.view) != Some(3) {
+            posts.next().await?;
+        }
+
+        Result::Ok(())
+    })
+    .await
+    .unwrap()?;
+
+    let docs = posts.docs();
+
+    assert_eq!(docs.len(), 2);
+    assert_eq!(docs[&6], Post::new(6, "title4", None, 1));
+
+    posts.close().await?;
+    tear_down(conn, &table_name).await
+}
@@ -0,0 +1,71 @@
+use neor::arguments::{Args, RunOption};
+use neor::err::{ReqlError, ReqlRuntimeError};
+use neor::mock::MockSession;
+use neor::{r, Converter, Result};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_mock_responses() -> Result<()> {
+    let mock = MockSession::new().await?;
+    let query = r.table("posts").filter(json!({"view": 2})).count(());
+
+    mock.respond(2)?;
+    let count: u8 = query.run(&mock).await?.unwrap().parse()?;
+    let unanswered = r.table("posts").run(&mock).await?;
+
+    assert!(count == 2);
+    assert!(unanswered == Some(json!(null)));
+    assert!(mock.queries() == [query.to_wire_json()?, r.table("posts").to_wire_json()?]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_failure() -> Result<()> {
+    let mock = MockSession::new().await?;
+
+    mock.fail("Expected type NUMBER but found STRING.");
+    let response = (r.expr(1) + "a").run(&mock).await;
+
+    assert!(matches!(
+        response,
+        Err(ReqlError::Runtime(ReqlRuntimeError::QueryLogic(msg)))
+            if msg == *"Expected type NUMBER but found STRING."
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_session() -> Result<()> {
+    let mock = MockSession::new().await?;
+    let options = RunOption::default().noreply(true);
+
+    r.table("logs")
+        .insert(json!({"message": "hello"}))
+        .run(Args((&*mock, options)))
+        .await?;
+    mock.noreply_wait().await?;
+
+    assert!(mock.server().await?.name.as_deref() == Some("mock"));
+    assert!(mock.queries().len() == 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_patch() -> Result<()> {
+    let mock = MockSession::new().await?;
+    let query = r
+        .table("posts")
+        .get(1)
+        .patch(json!([{"op": "replace", "path": "/view", "value": 11}]));
+
+    mock.respond(json!({"replaced": 1}))?;
+    let response = query.run(&mock).await?;
+
+    assert!(response == Some(json!({"replaced": 1})));
+    assert!(mock.queries().len() == 1);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use neor::{r, Converter, Fields, ReqlModel, Result};
+use serde::{Deserialize, Serialize};
+
+use common::{set_up, tear_down};
+
+mod common;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Fields, ReqlModel)]
+#[reql(table = "users", db = "app")]
+struct User {
+    id: u8,
+    #[serde(rename = "mail")]
+    email: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Fields, ReqlModel)]
+struct BlogPost {
+    id: u8,
+    title: String,
+}
+
+#[test]
+fn test_model_table() {
+    assert_eq!(User::TABLE, "users");
+    assert_eq!(BlogPost::TABLE, "blog_post");
+    assert_eq!(User::fields().email(), "mail");
+}
+
+#[tokio::test]
+async fn test_model_queries() -> Result<()> {
+    let (conn, _, table_name) = set_up(false).await?;
+    let post = BlogPost {
+        id: 1,
+        title: "title1".to_owned(),
+    };
+
+    r.table_create(BlogPost::TABLE).run(&conn).await?;
+    post.insert().run(&conn).await?;
+
+    let saved: BlogPost = BlogPost::get(1).run(&conn).await?.unwrap().parse()?;
+    let title: String = BlogPost::get(1)
+        .g(BlogPost::fields().title())
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert_eq!(saved, post);
+    assert_eq!(title, post.title);
+
+    r.table_drop(BlogPost::TABLE).run(&conn).await?;
+    tear_down(conn, &table_name).await
+}
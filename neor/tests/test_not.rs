@@ -29,3 +29,15 @@ async fn test_not_data() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_not_chained_and_top_level() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: bool = r.expr(true).not_().run(&conn).await?.unwrap().parse()?;
+    let response2: bool = r.not_(false).run(&conn).await?.unwrap().parse()?;
+    let response3: bool = (!r.expr(true)).run(&conn).await?.unwrap().parse()?;
+
+    assert!(!response && response2 && !response3);
+
+    Ok(())
+}
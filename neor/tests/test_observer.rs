@@ -0,0 +1,89 @@
+use std::sync::{Arc, Mutex};
+
+use neor::arguments::{ReadMode, RunOption};
+use neor::observer::{QueryEnd, QueryKind, QueryObserver, QueryStart};
+use neor::{args, r, Result};
+
+#[derive(Debug, Default)]
+struct Recorder {
+    started: Mutex<Vec<QueryStart>>,
+    ended: Mutex<Vec<QueryEnd>>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct SharedRecorder(Arc<Recorder>);
+
+impl QueryObserver for SharedRecorder {
+    fn on_start(&self, event: &QueryStart) {
+        self.0.started.lock().unwrap().push(event.clone());
+    }
+
+    fn on_end(&self, event: &QueryEnd) {
+        self.0.ended.lock().unwrap().push(event.clone());
+    }
+}
+
+#[tokio::test]
+async fn test_observer() -> Result<()> {
+    let recorder = SharedRecorder::default();
+    let conn = r.connection().observer(recorder.clone()).connect().await?;
+
+    (r.expr(1) + 2).run(&conn).await?;
+    assert!((r.expr(1) + "a").run(&conn).await.is_err());
+
+    let started = recorder.0.started.lock().unwrap();
+    let ended = recorder.0.ended.lock().unwrap();
+
+    assert!(started.len() == 2 && ended.len() == 2);
+    assert!(started[0].kind == QueryKind::Start);
+    assert!(started[0].term.as_deref() == Some("add"));
+    assert!(ended[0].token == started[0].token);
+    assert!(ended[0].bytes_sent > 0 && ended[0].bytes_received > 0);
+    assert!(ended[0].error.is_none());
+    assert!(ended[1].error.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_observer_read_mode() -> Result<()> {
+    let recorder = SharedRecorder::default();
+    let conn = r.connection().observer(recorder.clone()).connect().await?;
+    let query = r.db("rethinkdb").table("users").outdated().count(());
+    let run_option = RunOption::default().read_mode(ReadMode::Majority);
+
+    query.run(&conn).await?;
+    query.run(args!(&conn, run_option)).await?;
+    r.expr(1).run(&conn).await?;
+
+    let started = recorder.0.started.lock().unwrap();
+    let ended = recorder.0.ended.lock().unwrap();
+
+    assert!(started[0].read_mode == Some(ReadMode::Outdated));
+    assert!(started[1].read_mode == Some(ReadMode::Majority));
+    assert!(started[2].read_mode.is_none());
+    assert!(ended[0].read_mode == Some(ReadMode::Outdated));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_logger() -> Result<()> {
+    let logged = Arc::new(Mutex::new(Vec::new()));
+    let queries = logged.clone();
+    let conn = r
+        .connection()
+        .table_prefix("staging_")
+        .query_logger(move |query| queries.lock().unwrap().push(query.to_owned()))
+        .connect()
+        .await?;
+    let query = r.table("posts").count(());
+
+    assert!(query.to_reql_string()? == r#"r.table("posts").count()"#);
+
+    let _ = query.run(&conn).await;
+
+    assert!(*logged.lock().unwrap() == [r#"r.table("staging_posts").count()"#]);
+
+    Ok(())
+}
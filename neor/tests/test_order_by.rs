@@ -36,3 +36,15 @@ async fn test_order_by_title_with_opts() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[test]
+fn test_order_by_index_wire_format() -> Result<()> {
+    let query = r.table("posts").order_by(args!("id", r.index("title")));
+
+    assert_eq!(
+        query.to_wire_json()?,
+        serde_json::json!([41, [[15, ["posts"], {}], "id"], {"index": "title"}])
+    );
+
+    Ok(())
+}
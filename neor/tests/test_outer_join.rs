@@ -1,5 +1,5 @@
 use neor::types::JoinResponse;
-use neor::{func, Converter, Result};
+use neor::{func, Command, Converter, Result};
 
 use common::{Comment, Post};
 
@@ -38,3 +38,21 @@ pub async fn test_outer_join_ops() -> Result<()> {
 
     Comment::own_tear_down(conn, comment_tablename, post_tablename).await
 }
+
+#[tokio::test]
+pub async fn test_outer_join_command() -> Result<()> {
+    let (conn, comment_table, post_table, comment_tablename, post_tablename) =
+        Comment::own_set_up().await?;
+    let predicate: Command = func!(|comment, post| comment.g("post_id").eq(post.g("id"))).into();
+
+    let response: Vec<JoinResponse<Comment, Post>> = comment_table
+        .outer_join(post_table, predicate)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.len() == Comment::get_many_data().len());
+
+    Comment::own_tear_down(conn, comment_tablename, post_tablename).await
+}
@@ -0,0 +1,58 @@
+use neor::{args, func, r, Result};
+use serde_json::json;
+
+use common::{set_up, tear_down, Post};
+
+mod common;
+
+#[tokio::test]
+async fn test_paginate_ops() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let posts = table.paginate("id", 2);
+    let mut cursor = None;
+    let mut ids = Vec::new();
+
+    loop {
+        let page = posts.page::<Post>(&conn, cursor.as_ref()).await?;
+        assert!(page.items.len() <= 2);
+        ids.extend(page.items.into_iter().map(|post| post.id));
+
+        match page.next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    assert!(ids == [1, 2, 3, 4, 5]);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_paginate_compound_index_desc() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    table
+        .index_create(args!(
+            "title_id",
+            func!(|post| r.array([post.g("title"), post.g("id")]))
+        ))
+        .run(&conn)
+        .await?;
+    table.index_wait(()).run(&conn).await?;
+
+    let posts = table
+        .paginate("title_id", 3)
+        .key_fields(["title", "id"])
+        .desc();
+    let first = posts.page::<Post>(&conn, None).await?;
+    let second = posts
+        .page::<Post>(&conn, first.next_cursor.as_ref())
+        .await?;
+
+    assert!(first.next_cursor == Some(json!(["title3", 3])));
+    assert!(first.items.iter().map(|post| post.id).collect::<Vec<_>>() == [5, 4, 3]);
+    assert!(second.items.iter().map(|post| post.id).collect::<Vec<_>>() == [2, 1]);
+    assert!(second.next_cursor.is_none());
+
+    tear_down(conn, &table_name).await
+}
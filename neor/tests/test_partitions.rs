@@ -0,0 +1,77 @@
+use neor::partitions::MonthlyPartitions;
+use neor::{r, Converter, Result};
+use serde_json::{json, Value};
+use time::macros::datetime;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_monthly_partitions() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let prefix = format!("events_{}", Uuid::new_v4().simple());
+    let events = MonthlyPartitions::new(prefix.as_str(), "created_at").index("kind");
+
+    events
+        .insert(
+            &conn,
+            datetime!(2024-04-30 12:00 UTC),
+            json!({"kind": "login"}),
+        )
+        .await?;
+    events
+        .insert(
+            &conn,
+            datetime!(2024-05-02 12:00 UTC),
+            json!({"kind": "logout"}),
+        )
+        .await?;
+    events
+        .insert(
+            &conn,
+            datetime!(2024-05-20 12:00 UTC),
+            json!({"kind": "login"}),
+        )
+        .await?;
+
+    let span: Vec<Value> = events
+        .between(
+            &conn,
+            datetime!(2024-04-15 0:00 UTC),
+            datetime!(2024-05-10 0:00 UTC),
+        )
+        .await?
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let empty: Vec<Value> = events
+        .between(
+            &conn,
+            datetime!(2023-01-01 0:00 UTC),
+            datetime!(2023-03-01 0:00 UTC),
+        )
+        .await?
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let indexes: Vec<String> = events
+        .table(datetime!(2024-05-01 0:00 UTC))
+        .index_list()
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert_eq!(span.len(), 2);
+    assert!(empty.is_empty());
+    assert_eq!(indexes, vec!["created_at".to_owned(), "kind".to_owned()]);
+
+    for table in events.table_names(
+        datetime!(2024-04-01 0:00 UTC),
+        datetime!(2024-05-01 0:00 UTC),
+    ) {
+        r.table_drop(table.as_str()).run(&conn).await?;
+    }
+
+    Ok(())
+}
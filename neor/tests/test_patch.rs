@@ -0,0 +1,115 @@
+use neor::types::MutationResponse;
+use neor::{Converter, Result};
+use serde_json::{json, Value};
+
+use common::*;
+
+mod common;
+
+#[tokio::test]
+async fn test_patch_doc() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let response: MutationResponse = table
+        .get(1)
+        .patch(json!([
+            {"op": "test", "path": "/title", "value": "title1"},
+            {"op": "add", "path": "/tags", "value": ["a", "c"]},
+            {"op": "add", "path": "/tags/1", "value": "b"},
+            {"op": "move", "from": "/content", "path": "/body"},
+            {"op": "replace", "path": "/view", "value": 11},
+            {"op": "remove", "path": "/tags/0"}
+        ]))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let post: Value = table.get(1).run(&conn).await?.unwrap().parse()?;
+
+    assert!(response.replaced == 1);
+    assert!(
+        post == json!({"id": 1, "title": "title1", "body": "content1", "view": 11, "tags": ["b", "c"]})
+    );
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_patch_failed_test() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let response: MutationResponse = table
+        .get(1)
+        .patch(json!([
+            {"op": "test", "path": "/view", "value": 0},
+            {"op": "remove", "path": "/content"}
+        ]))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let post: Post = table.get(1).run(&conn).await?.unwrap().parse()?;
+
+    assert!(response.errors == 1);
+    assert!(post == Post::get_many_data()[0]);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_patch_numeric_object_keys() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    table
+        .get(1)
+        .update(json!({"scores": {"2024": 1, "2025": 2}}))
+        .run(&conn)
+        .await?;
+    let response: MutationResponse = table
+        .get(1)
+        .patch(json!([
+            {"op": "test", "path": "/scores/2024", "value": 1},
+            {"op": "add", "path": "/scores/2026", "value": 3},
+            {"op": "replace", "path": "/scores/2025", "value": 4},
+            {"op": "remove", "path": "/scores/2024"}
+        ]))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let scores: Value = table
+        .get(1)
+        .g("scores")
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.replaced == 1);
+    assert!(scores == json!({"2025": 4, "2026": 3}));
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_patch_missing_path() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let remove: MutationResponse = table
+        .get(1)
+        .patch(json!([{"op": "remove", "path": "/missing"}]))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let replace: MutationResponse = table
+        .get(1)
+        .patch(json!([{"op": "replace", "path": "/missing", "value": 1}]))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let post: Post = table.get(1).run(&conn).await?.unwrap().parse()?;
+
+    assert!(remove.errors == 1);
+    assert!(replace.errors == 1);
+    assert!(post == Post::get_many_data()[0]);
+
+    tear_down(conn, &table_name).await
+}
@@ -1,4 +1,4 @@
-use neor::{Converter, Result};
+use neor::{sel, Converter, Result};
 use serde::{Deserialize, Serialize};
 
 use common::{set_up, tear_down, Post};
@@ -31,3 +31,24 @@ async fn test_pluck_data() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_pluck_selector() -> Result<()> {
+    let data = Post::get_one_data();
+    let data = InnerPost {
+        id: data.id,
+        title: data.title,
+    };
+    let (conn, table, table_name) = set_up(true).await?;
+    let response: InnerPost = table
+        .get(1)
+        .pluck(sel!(Post { id, title }))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == data);
+
+    tear_down(conn, &table_name).await
+}
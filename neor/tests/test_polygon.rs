@@ -31,3 +31,15 @@ async fn test_polygon_data() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_polygon_from_tuples() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let polygon = r.polygon([(-122.4, 37.7), (-122.4, 37.3), (-121.8, 37.3)]);
+    let response: Polygon = r.expr(&polygon).run(&conn).await?.unwrap().parse()?;
+
+    assert!(response == polygon);
+    assert!(polygon.coordinates[0].first() == polygon.coordinates[0].last());
+
+    Ok(())
+}
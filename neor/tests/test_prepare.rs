@@ -0,0 +1,60 @@
+use neor::{r, Converter, Result};
+use serde::Serialize;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_prepare_ops() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let sum = (r.param("a") + r.param("b")).prepare()?;
+
+    let response: u8 = sum
+        .run(&conn, json!({"a": 1, "b": 2}))
+        .await?
+        .unwrap()
+        .parse()?;
+    assert!(response == 3);
+
+    let response: u8 = sum
+        .run(&conn, json!({"a": 3, "b": 4}))
+        .await?
+        .unwrap()
+        .parse()?;
+    assert!(response == 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prepare_with_struct_params() -> Result<()> {
+    #[derive(Serialize)]
+    struct Params {
+        values: Vec<u8>,
+        min: u8,
+    }
+
+    let conn = r.connection().connect().await?;
+    let count = r
+        .param("values")
+        .filter(neor::func!(|value| value.ge(r.param("min"))))
+        .count(())
+        .prepare()?;
+    let params = Params {
+        values: vec![1, 2, 3],
+        min: 2,
+    };
+    let response: u8 = count.run(&conn, params).await?.unwrap().parse()?;
+
+    assert!(response == 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prepare_unbound_param() -> Result<()> {
+    let conn = r.connection().connect().await?;
+
+    assert!(r.param("a").run(&conn).await.is_err());
+    assert!(r.param("a").prepare()?.run(&conn, json!({})).await.is_err());
+
+    Ok(())
+}
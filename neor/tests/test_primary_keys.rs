@@ -0,0 +1,32 @@
+use futures::TryStreamExt;
+use neor::arguments::RunOption;
+use neor::{args, Result};
+
+mod common;
+
+use common::{set_up, tear_down};
+
+#[tokio::test]
+async fn test_primary_keys() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let mut keys: Vec<u8> = table.primary_keys::<u8>(&conn).try_collect().await?;
+    keys.sort_unstable();
+
+    assert!(keys == [1, 2, 3, 4, 5]);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_primary_keys_small_batches() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let options = RunOption::default().max_batch_rows(2);
+    let keys: Vec<u8> = table
+        .primary_keys::<u8>(args!(&conn, options))
+        .try_collect()
+        .await?;
+
+    assert!(keys.len() == 5);
+
+    tear_down(conn, &table_name).await
+}
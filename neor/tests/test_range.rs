@@ -8,10 +8,25 @@ async fn test_range_data() -> Result<()> {
     let response: [isize; 4] = r.range(4).run(&conn).await?.unwrap().parse()?;
     let response2: [isize; 4] = r.range(()).limit(4).run(&conn).await?.unwrap().parse()?;
     let response3: [isize; 11] = r.range(args!(-5, 6)).run(&conn).await?.unwrap().parse()?;
+    let response4: [isize; 11] = r.range(-5..6).run(&conn).await?.unwrap().parse()?;
+    let response5: [isize; 4] = r.range(..).limit(4).run(&conn).await?.unwrap().parse()?;
 
     assert!(response == data);
     assert!(response2 == data);
     assert!(response3 == data2);
+    assert!(response4 == data2);
+    assert!(response5 == data);
+
+    Ok(())
+}
+
+#[test]
+fn test_range_syntax() -> Result<()> {
+    assert_eq!(
+        r.range(-5..6).to_wire_json()?,
+        r.range(args!(-5, 6)).to_wire_json()?
+    );
+    assert_eq!(r.range(..).to_wire_json()?, r.range(()).to_wire_json()?);
 
     Ok(())
 }
@@ -0,0 +1,66 @@
+use neor::{r, Converter, Result};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_raw_term_ops() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: u8 = r
+        .raw_term(24, [r.expr(2), r.expr(3)], None)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_raw_term_with_opts() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: String = r
+        .raw_term(
+            99,
+            ["2021-01-01T12:00"],
+            Some(json!({"default_timezone": "Z"})),
+        )
+        .to_iso8601()
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == "2021-01-01T12:00:00+00:00");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_raw_ops() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: u8 = r
+        .raw(json!([24, [[154, [[2, [1, 2, 3]]]]]]))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == 6);
+
+    Ok(())
+}
+
+#[test]
+fn test_raw_term_wire_format() -> Result<()> {
+    let query = r.raw_term(24, [r.expr(2), r.expr([3, 4]).count(())], None);
+    let table = r.raw_term(15, ["users"], Some(json!({"read_mode": "outdated"})));
+
+    assert_eq!(query.to_wire_json()?, json!([24, [2, [43, [[2, [3, 4]]]]]]));
+    assert_eq!(
+        table.to_wire_json()?,
+        json!([15, ["users"], {"read_mode": "outdated"}])
+    );
+
+    Ok(())
+}
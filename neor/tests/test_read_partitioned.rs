@@ -0,0 +1,58 @@
+use futures::TryStreamExt;
+use neor::arguments::BetweenOption;
+use neor::{args, r, Result};
+use serde_json::json;
+
+use common::{set_up, tear_down, Post};
+
+mod common;
+
+#[tokio::test]
+async fn test_read_partitioned_ops() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let streams = table
+        .read_partitioned(3, "id")
+        .streams::<Post>(&conn)
+        .await?;
+    let mut posts = Vec::new();
+
+    assert!(!streams.is_empty() && streams.len() <= 3);
+
+    for stream in streams {
+        let mut partition: Vec<Post> = stream.try_collect().await?;
+        posts.append(&mut partition);
+    }
+    posts.sort_by_key(|post| post.id);
+
+    assert!(posts == Post::get_many_data());
+
+    tear_down(conn, &table_name).await
+}
+
+#[test]
+fn test_read_partitioned_queries() -> Result<()> {
+    let partitions = r.table("users").read_partitioned(3, "age");
+    let options = BetweenOption::default().index("age");
+    let between = |lower, upper| {
+        r.table("users")
+            .between(args!(lower, upper, options.clone()))
+            .to_wire_json()
+    };
+    let queries = partitions
+        .queries(&[json!(20), json!(40)])
+        .iter()
+        .map(|query| query.to_wire_json())
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(
+        queries,
+        [
+            between(r::min_val(), r.expr(20))?,
+            between(r.expr(20), r.expr(40))?,
+            between(r.expr(40), r::max_val())?,
+        ]
+    );
+    assert_eq!(partitions.queries(&[]).len(), 1);
+
+    Ok(())
+}
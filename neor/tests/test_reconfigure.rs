@@ -1,6 +1,6 @@
 use neor::arguments::{ReconfigureOption, Replicas};
 use neor::types::ReconfigureResponse;
-use neor::{Converter, Result};
+use neor::{r, Converter, Result};
 
 use common::{set_up, tear_down};
 
@@ -23,3 +23,46 @@ async fn test_reconfigure_table() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_reconfigure_dry_run_diff() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let reconfigure_option = ReconfigureOption::default()
+        .shards(2)
+        .replicas(Replicas::Int(1))
+        .dry_run(true);
+    let response: ReconfigureResponse = table
+        .reconfigure(reconfigure_option)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let diff = response.diff();
+
+    assert!(response.reconfigured == 0);
+    assert!(diff.len() == 1);
+    assert!(diff[0].old_shards == 1 && diff[0].new_shards == 2);
+
+    tear_down(conn, &table_name).await
+}
+
+#[tokio::test]
+async fn test_reconfigure_db_dry_run() -> Result<()> {
+    let (conn, _, table_name) = set_up(false).await?;
+    let reconfigure_option = ReconfigureOption::default()
+        .shards(1)
+        .replicas(Replicas::Int(1))
+        .dry_run(true);
+    let response: ReconfigureResponse = r
+        .db("test")
+        .reconfigure(reconfigure_option)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.reconfigured == 0);
+    assert!(!response.config_changes.is_empty());
+
+    tear_down(conn, &table_name).await
+}
@@ -6,8 +6,10 @@ async fn test_round_data() -> Result<()> {
     let response: u8 = r.round(12.345).run(&conn).await?.unwrap().parse()?;
     let data_obtained2: u8 = r.expr(12.345).round().run(&conn).await?.unwrap().parse()?;
     let data_obtained3: u8 = r.round(r.expr(12.345)).run(&conn).await?.unwrap().parse()?;
+    let data_obtained4: u8 = r.round(12).run(&conn).await?.unwrap().parse()?;
 
     assert!(response == 12 && response == data_obtained2 && response == data_obtained3);
+    assert!(data_obtained4 == 12);
 
     Ok(())
 }
@@ -0,0 +1,29 @@
+use neor::{r, Converter, Result};
+
+use common::{set_up, tear_down, Post};
+
+mod common;
+
+#[tokio::test]
+async fn test_row_filter_and_map() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    let mut titles: Vec<String> = table
+        .filter(r.row().g("view").eq(2))
+        .map(r.row().g("title"))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let mut expected: Vec<String> = data
+        .into_iter()
+        .filter(|post| post.view == 2)
+        .map(|post| post.title)
+        .collect();
+
+    titles.sort();
+    expected.sort();
+    assert!(titles == expected);
+
+    tear_down(conn, &table_name).await
+}
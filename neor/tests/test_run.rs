@@ -1,5 +1,10 @@
-use neor::arguments::{ReadMode, RunOption};
-use neor::{args, r, Result};
+use std::time::Duration;
+
+use futures::TryStreamExt;
+use neor::arguments::{Durability, Format, ReadMode, RunOption};
+use neor::err::{ReqlDriverError, ReqlError};
+use neor::{args, r, Converter, Result};
+use serde_json::json;
 
 #[tokio::test]
 async fn test_run_ops() -> Result<()> {
@@ -15,3 +20,201 @@ async fn test_run_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_run_cancelled() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let query = r.range(10_000_000).count(());
+
+    for _ in 0..3 {
+        tokio::select! {
+            _ = query.run(&conn) => {}
+            _ = tokio::time::sleep(Duration::from_millis(10)) => {}
+        }
+    }
+
+    let response: u8 = r.expr(1).run(&conn).await?.unwrap().parse()?;
+
+    assert_eq!(response, 1);
+    assert!(!conn.is_broken());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_timeout() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let query = r.range(10_000_000).count(());
+    let timeout = tokio::time::timeout(Duration::from_millis(10), query.run(&conn)).await;
+    let response: Vec<String> = r.db_list().run(&conn).await?.unwrap().parse()?;
+
+    assert!(timeout.is_err());
+    assert!(!response.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_timeout_option() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let run_option = RunOption::default().timeout(Duration::from_millis(10));
+    let err = r
+        .range(10_000_000)
+        .count(())
+        .run(args!(&conn, run_option))
+        .await
+        .err()
+        .unwrap();
+    let response: u8 = r.expr(1).run(&conn).await?.unwrap().parse()?;
+
+    assert!(matches!(
+        err,
+        ReqlError::Driver(ReqlDriverError::Timeout(_))
+    ));
+    assert_eq!(response, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_cancel() -> Result<()> {
+    let session = r.connection().connect().await?;
+    let conn = session.connection()?;
+    let token = conn.token();
+    let task = tokio::spawn(async move {
+        let mut stream = r.range(()).build_query(conn);
+        while stream.try_next().await?.is_some() {}
+        Ok(())
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(session.cancel(token));
+    assert!(matches!(
+        task.await.unwrap(),
+        Err(ReqlError::Driver(ReqlDriverError::Cancelled))
+    ));
+    assert!(!session.cancel(token));
+    assert!(!session.is_broken());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_array_limit() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let query = r.range(200_000).coerce_to("array").count(());
+    let run_option = RunOption::default().array_limit(300_000);
+    let response: usize = query
+        .run(args!(&conn, run_option))
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(query.run(&conn).await.is_err());
+    assert_eq!(response, 200_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_db_command() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let run_option = RunOption::default().db(r.db("rethinkdb"));
+    let response: Vec<String> = r
+        .table_list()
+        .run(args!(&conn, run_option))
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.iter().any(|table| table == "users"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_use_db() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    conn.use_db("rethinkdb").await?;
+
+    let tables: Vec<String> = r.table_list().run(&conn).await?.unwrap().parse()?;
+    let run_option = RunOption::default().db("test");
+    let response = r.table("users").run(args!(&conn, run_option)).await;
+
+    assert!(tables.iter().any(|table| table == "users"));
+    assert!(response.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_typed() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let atom = r.expr(1).run_typed::<u8>(&conn).await?;
+    let sequence = r.range(3).run_typed::<Vec<u8>>(&conn).await?;
+
+    assert!(atom == Some(1));
+    assert!(sequence == Some(vec![0, 1, 2]));
+    assert!(r.expr("a").run_typed::<u8>(&conn).await.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_run_option_db() -> Result<()> {
+    let by_name = RunOption::default().db("blog");
+    let by_command = RunOption::default().db(r.db("blog"));
+
+    assert_eq!(by_name, by_command);
+    assert_eq!(
+        serde_json::to_value(&by_command)?,
+        json!({"db": [14, ["blog"]]})
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_run_option_global_optargs() -> Result<()> {
+    let options = RunOption::default()
+        .read_mode(ReadMode::Majority)
+        .time_format(Format::Raw)
+        .durability(Durability::Soft)
+        .group_format(Format::Raw)
+        .binary_format(Format::Native)
+        .array_limit(200_000)
+        .min_batch_rows(8)
+        .max_batch_rows(64)
+        .table_prefix(false);
+
+    assert_eq!(
+        serde_json::to_value(&options)?,
+        json!({
+            "read_mode": "majority",
+            "time_format": "raw",
+            "durability": "soft",
+            "group_format": "raw",
+            "binary_format": "native",
+            "array_limit": 200_000,
+            "min_batch_rows": 8,
+            "max_batch_rows": 64,
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_option_invalid_db() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let opts = RunOption::default().db(r.table("posts"));
+    let result = r.expr(1).run(args!(&conn, opts)).await;
+
+    assert!(matches!(
+        result,
+        Err(ReqlError::Driver(ReqlDriverError::Other(_)))
+    ));
+
+    Ok(())
+}
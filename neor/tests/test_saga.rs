@@ -0,0 +1,79 @@
+use neor::err::{ReqlDriverError, ReqlError};
+use neor::mock::MockSession;
+use neor::saga::Saga;
+use neor::{r, Result};
+use serde_json::json;
+
+fn saga() -> Saga {
+    Saga::new()
+        .step(
+            r.table("orders").insert(json!({"id": 1})),
+            r.table("orders").get(1).delete(()),
+        )
+        .step(
+            r.table("stock").get("book").update(json!({"count": 9})),
+            r.table("stock").get("book").update(json!({"count": 10})),
+        )
+        .action(r.table("logs").insert(json!({"order": 1})))
+}
+
+#[tokio::test]
+async fn test_saga() -> Result<()> {
+    let mock = MockSession::new().await?;
+
+    mock.respond(json!({"inserted": 1}))?;
+    mock.respond(json!({"replaced": 1}))?;
+    mock.respond(json!({"inserted": 1}))?;
+    let response = saga().run(&mock).await?;
+
+    assert!(response.responses.len() == 3);
+    assert!(response.mutation().inserted == 2);
+    assert!(response.mutation().replaced == 1);
+    assert!(mock.queries().len() == 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_saga_compensation() -> Result<()> {
+    let mock = MockSession::new().await?;
+
+    mock.respond(json!({"inserted": 1}))?;
+    mock.respond(json!({"replaced": 1}))?;
+    mock.respond(json!({"errors": 1, "first_error": "Duplicate primary key `id`"}))?;
+    let response = saga().run(&mock).await;
+    let compensations = [
+        r.table("stock")
+            .get("book")
+            .update(json!({"count": 10}))
+            .to_wire_json()?,
+        r.table("orders").get(1).delete(()).to_wire_json()?,
+    ];
+
+    assert!(matches!(
+        response,
+        Err(ReqlError::Driver(ReqlDriverError::Other(msg))) if msg == "Duplicate primary key `id`"
+    ));
+    assert!(mock.queries()[3..] == compensations);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_saga_failed_compensation() -> Result<()> {
+    let mock = MockSession::new().await?;
+
+    mock.respond(json!({"inserted": 1}))?;
+    mock.fail("Table `test.stock` does not exist.");
+    mock.fail("Table `test.orders` does not exist.");
+    let response = saga().run(&mock).await;
+
+    assert!(matches!(
+        response,
+        Err(ReqlError::Driver(ReqlDriverError::Other(msg)))
+            if msg.starts_with("step 1 failed") && msg.contains("step 0: ")
+    ));
+    assert!(mock.queries().len() == 3);
+
+    Ok(())
+}
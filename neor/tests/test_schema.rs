@@ -0,0 +1,52 @@
+use neor::arguments::{Args, TableCreateOption};
+use neor::schema::{self, DiffOption};
+use neor::{func, r, Result};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_schema_snapshot() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let db_name = Uuid::new_v4().simple().to_string();
+    let db = r.db(db_name.as_str());
+    let table_create_option = TableCreateOption::default().primary_key("email".to_owned());
+
+    r.db_create(db_name.as_str()).run(&conn).await?;
+    db.table_create(Args(("users", table_create_option)))
+        .run(&conn)
+        .await?;
+    db.table("users").index_create("name").run(&conn).await?;
+    db.table("users")
+        .set_write_hook(func!(|_, _, new_val| new_val))
+        .run(&conn)
+        .await?;
+
+    let snapshot = schema::snapshot(&conn).await?;
+    let users = snapshot
+        .db(&db_name)
+        .and_then(|db| db.table("users"))
+        .unwrap();
+    let copy_name = Uuid::new_v4().simple().to_string();
+    // only the database of the test is reconciled, not the others of the server
+    let mut current = snapshot.clone();
+    current.dbs.retain(|db| db.name == db_name);
+    let mut expected = current.clone();
+    expected.dbs[0].name = copy_name.clone().into();
+
+    let options = DiffOption::default().drop_extra(true);
+
+    for command in schema::diff(&current, &expected, options)? {
+        command.run(&conn).await?;
+    }
+
+    let applied = schema::snapshot(&conn).await?;
+
+    assert!(users.primary_key == "email");
+    assert!(users.index("name").is_some());
+    assert!(users.write_hook.is_some());
+    assert!(applied.db(&db_name).is_none());
+    assert!(applied.db(&copy_name).unwrap().tables == expected.dbs[0].tables);
+
+    r.db_drop(copy_name.as_str()).run(&conn).await?;
+
+    Ok(())
+}
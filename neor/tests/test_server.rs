@@ -0,0 +1,42 @@
+use neor::arguments::{Args, RunOption};
+use neor::err::{ReqlDriverError, ReqlError};
+use neor::{r, Result};
+
+#[tokio::test]
+async fn test_server_ping() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let server = conn.server().await?;
+
+    assert!(!server.proxy);
+    assert!(server.name.is_some());
+    assert!(conn.is_open());
+
+    conn.ping().await?;
+
+    assert!(conn.is_open());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_reconnect() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let options = RunOption::default().noreply(true);
+
+    r.expr(1).run(Args((&conn, options))).await?;
+    conn.close(true).await?;
+    conn.close(false).await?;
+
+    assert!(!conn.is_open());
+    assert!(matches!(
+        r.expr(1).run(&conn).await,
+        Err(ReqlError::Driver(ReqlDriverError::ConnectionClosed))
+    ));
+
+    conn.reconnect(false, None).await?;
+
+    assert!(conn.is_open());
+    assert!(r.expr(1).run(&conn).await?.is_some());
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "tower")]
+
+use std::time::Duration;
+
+use neor::service::QueryService;
+use neor::{r, Converter, Result};
+use tower::{Service, ServiceBuilder, ServiceExt};
+
+use common::{set_up, tear_down};
+
+mod common;
+
+#[tokio::test]
+async fn test_query_service() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let second = r.connection().connect().await?;
+    let mut service = ServiceBuilder::new()
+        .timeout(Duration::from_secs(5))
+        .service(QueryService::pool([conn.clone(), second]));
+
+    for _ in 0..3 {
+        let count: usize = service
+            .ready()
+            .await
+            .unwrap()
+            .call(table.clone().count(()))
+            .await
+            .unwrap()
+            .unwrap()
+            .parse()?;
+
+        assert!(count == 5);
+    }
+
+    tear_down(conn, &table_name).await
+}
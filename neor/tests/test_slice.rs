@@ -1,3 +1,4 @@
+use neor::arguments::SliceOption;
 use neor::{args, r, Converter, Result};
 
 use common::{set_up, tear_down, Post};
@@ -20,3 +21,33 @@ async fn test_slice_data() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_slice_subquery_offsets() -> Result<()> {
+    let data = Post::get_many_data();
+    let (conn, table, table_name) = set_up(true).await?;
+    let response: Vec<Post> = table
+        .order_by(r.index("id"))
+        .slice(args!(table.get(2).g("view"), table.count(()) - 1))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == data[2..4]);
+
+    tear_down(conn, &table_name).await
+}
+
+#[test]
+fn test_slice_min_max_val() -> Result<()> {
+    let query = r
+        .table("posts")
+        .slice(args!(r::min_val(), r::max_val(), SliceOption::default()));
+    let wire = query.to_wire_json()?;
+
+    assert_eq!(wire[1][1], r::min_val().to_wire_json()?);
+    assert_eq!(wire[1][2], r::max_val().to_wire_json()?);
+
+    Ok(())
+}
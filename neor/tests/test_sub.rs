@@ -16,3 +16,17 @@ async fn test_sub_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_sub_reflected() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: f64 = ((10 - r.expr(4)) * 2. / 3 % 3)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert_eq!(response, 1.);
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use neor::system::{self, Issue, Job, ServerStatus, Stats, TableConfig};
+use neor::{Converter, Result};
+
+use common::{set_up, tear_down};
+
+mod common;
+
+#[tokio::test]
+async fn test_system_tables() -> Result<()> {
+    let (conn, _, table_name) = set_up(false).await?;
+
+    let tables: Vec<TableConfig> = system::table_config().run(&conn).await?.unwrap().parse()?;
+    let servers: Vec<ServerStatus> = system::server_status().run(&conn).await?.unwrap().parse()?;
+    let jobs: Vec<Job> = system::jobs().run(&conn).await?.unwrap().parse()?;
+    let stats: Vec<Stats> = system::stats().run(&conn).await?.unwrap().parse()?;
+    let _: Vec<Issue> = system::current_issues()
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(tables.iter().any(|table| table.name == table_name));
+    assert!(!servers.is_empty());
+    assert!(jobs.iter().any(|job| job.typ == "query"));
+    assert!(!stats.is_empty());
+
+    tear_down(conn, &table_name).await
+}
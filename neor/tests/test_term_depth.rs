@@ -0,0 +1,11 @@
+use neor::r;
+use serde_json::json;
+
+#[test]
+fn test_term_depth() {
+    let folded = (0..50).fold(r.expr(0), |sum, i| sum + i);
+
+    assert_eq!(r.expr(json!({"a": [1, {"b": 2}]})).term_depth(), 4);
+    assert_eq!(r.table("posts").get_all(["a"]).term_depth(), 3);
+    assert_eq!(folded.term_depth(), 51);
+}
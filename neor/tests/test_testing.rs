@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use neor::testing::TempDatabase;
+use neor::{r, Converter, Result};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_temp_database() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let db = TempDatabase::new(&conn, ["posts", "users"]).await?;
+    let name = db.name().to_owned();
+
+    db.table("posts")
+        .insert(json!({"id": 1}))
+        .run(&conn)
+        .await?;
+    let tables: Vec<String> = db.db().table_list().run(&conn).await?.unwrap().parse()?;
+    let count: u8 = db
+        .table("posts")
+        .count(())
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(name.starts_with("test_"));
+    assert!(tables == ["posts", "users"]);
+    assert!(count == 1);
+
+    db.drop_database().await?;
+    let dbs: Vec<String> = r.db_list().run(&conn).await?.unwrap().parse()?;
+
+    assert!(!dbs.contains(&name));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_temp_database_dropped_out_of_scope() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let db = TempDatabase::new(&conn, ["posts"]).await?;
+    let name = db.name().to_owned();
+
+    drop(db);
+
+    // the database is dropped in the background
+    for _ in 0..50 {
+        let dbs: Vec<String> = r.db_list().run(&conn).await?.unwrap().parse()?;
+
+        if !dbs.contains(&name) {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("the database {} was not dropped", name)
+}
@@ -1,6 +1,7 @@
-use neor::types::Time;
+use neor::types::{DateTime, Time};
 use neor::{args, r, Converter, Result};
-use time::macros::{date, offset, time};
+use time::macros::{date, datetime, offset, time};
+use time::OffsetDateTime;
 
 #[tokio::test]
 async fn test_time_ops() -> Result<()> {
@@ -17,3 +18,28 @@ async fn test_time_ops() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_time_from_offset_datetime() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let offset_datetime = datetime!(1986-11-03 09:30:40 +01:00);
+
+    let time1: DateTime = r
+        .time(offset_datetime)
+        .cmd()
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let time2: DateTime = r
+        .expr(DateTime::from(offset_datetime))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(OffsetDateTime::from(time1) == offset_datetime);
+    assert!(OffsetDateTime::from(time2) == offset_datetime);
+
+    Ok(())
+}
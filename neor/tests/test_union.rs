@@ -1,4 +1,5 @@
-use neor::{r, Converter, Result};
+use neor::arguments::{Interleave, UnionOption};
+use neor::{args, func, r, Converter, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use uuid::Uuid;
@@ -45,3 +46,36 @@ async fn test_union_data() -> Result<()> {
     r.table_drop(table_name2.as_str()).run(&conn).await?;
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_union_interleave() -> Result<()> {
+    let conn = r.connection().connect().await?;
+    let response: Vec<u8> = r
+        .union([r.expr([1, 4]), r.expr([2]), r.expr([3, 5])])
+        .order_by(func!(|value| value))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == [1, 2, 3, 4, 5]);
+
+    let opts = UnionOption::default().interleave(Interleave::Func(func!(|doc| doc.g("n"))));
+    let response: Vec<u8> = r
+        .union(args!(
+            [
+                r.expr(json!([{"n": 1}, {"n": 4}])),
+                r.expr(json!([{"n": 2}, {"n": 3}])),
+            ],
+            opts
+        ))
+        .g("n")
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response == [1, 2, 3, 4]);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
+use neor::arguments::{ReturnChanges, UpdateOption};
 use neor::types::MutationResponse;
-use neor::{Converter, Result};
+use neor::{args, Converter, Result};
 use serde_json::json;
 
 use common::*;
@@ -21,3 +22,24 @@ async fn test_update_docs() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_update_return_changes_always() -> Result<()> {
+    let (conn, table, table_name) = set_up(true).await?;
+    let update_option = UpdateOption::default().return_changes(ReturnChanges::Always);
+    let response: MutationResponse<Post> = table
+        .update(args!(json!({"view": 0}), update_option))
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+    let changes = response.changes.unwrap_or_default();
+
+    // unchanged documents are returned too
+    assert!(changes.len() == Post::get_many_data().len());
+    assert!(changes
+        .iter()
+        .all(|change| change.new_val.as_ref().map(|post| post.view) == Some(0)));
+
+    tear_down(conn, &table_name).await
+}
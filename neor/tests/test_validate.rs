@@ -0,0 +1,43 @@
+use neor::arguments::BetweenOption;
+use neor::types::DiagnosticKind;
+use neor::{args, r, Command};
+use serde_json::json;
+
+fn kinds(query: Command) -> Vec<DiagnosticKind> {
+    query.validate().into_iter().map(|diag| diag.kind).collect()
+}
+
+fn between(index: &'static str) -> Command {
+    r.table("posts")
+        .between(args!(1, 10, BetweenOption::default().index(index)))
+}
+
+#[test]
+fn test_validate_aggregation_of_changefeed() {
+    assert_eq!(
+        kinds(r.table("posts").changes(()).g("new_val").count(())),
+        [DiagnosticKind::AggregationOfChangefeed]
+    );
+    assert!(kinds(r.table("posts").changes(()).limit(2).count(())).is_empty());
+}
+
+#[test]
+fn test_validate_order_by_index_after_between() {
+    assert_eq!(
+        kinds(between("title").order_by(r.index(r.desc("date")))),
+        [DiagnosticKind::OrderByIndexAfterBetween]
+    );
+    assert!(kinds(between("date").order_by(r.index(r.desc("date")))).is_empty());
+}
+
+#[test]
+fn test_validate_geometry() {
+    assert_eq!(
+        kinds(r.table("places").get_intersecting(r.expr("location"), "")),
+        [
+            DiagnosticKind::GeometryAsString,
+            DiagnosticKind::MissingGeoIndex
+        ]
+    );
+    assert!(kinds(r.table("posts").filter(json!({"id": 1})).count(())).is_empty());
+}
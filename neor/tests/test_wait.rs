@@ -1,5 +1,6 @@
+use neor::arguments::{WaitFor, WaitOption};
 use neor::types::WaitResponse;
-use neor::{Converter, Result};
+use neor::{r, Converter, Result};
 
 use common::{set_up, tear_down};
 
@@ -14,3 +15,20 @@ async fn test_wait_table() -> Result<()> {
 
     tear_down(conn, &table_name).await
 }
+
+#[tokio::test]
+async fn test_wait_db() -> Result<()> {
+    let (conn, _, table_name) = set_up(false).await?;
+    let wait_option = WaitOption::default().wait_for(WaitFor::AllReplicasReady);
+    let response: WaitResponse = r
+        .db("test")
+        .wait(wait_option)
+        .run(&conn)
+        .await?
+        .unwrap()
+        .parse()?;
+
+    assert!(response.ready >= 1);
+
+    tear_down(conn, &table_name).await
+}